@@ -130,7 +130,7 @@ pub(crate) fn compute_config_enrichment(cfg: &CloudConfig) -> ConfigEnrichment {
     let pipeline = ColorPipeline::detect(cfg.color_mode);
     let color_pipeline_label = pipeline.label();
     let chroma_in_benchmark: &'static str = if !pipeline.is_chroma() {
-        "legacy fallback (color mode lacks truecolor; no chroma engine in benchmark either)"
+        "legacy fallback (mono color mode; no chroma engine in benchmark either)"
     } else if cfg.crystal_dragon {
         "chroma enabled (crystal_dragon OFF for determinism, climate_drift active)"
     } else {
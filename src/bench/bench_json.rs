@@ -305,6 +305,24 @@ pub(crate) fn build_json_string(data: &BenchReportData) -> String {
         }
     });
 
+    // ── redraw (full-vs-diff cost model) ──
+    json_object(&mut out, "redraw", |o| match &data.redraw {
+        Some(r) => {
+            o.push_kv("samples", r.samples);
+            o.push_kv("full_frames", r.full_frames);
+            o.push_kv("diff_frames", r.diff_frames);
+            o.push_kv("threshold_disagreements", r.threshold_disagreements);
+            o.push_kv("avg_full_bytes", r.avg_full_bytes);
+            o.push_kv("avg_diff_bytes", r.avg_diff_bytes);
+            o.push_kv("avg_chosen_bytes", r.avg_chosen_bytes);
+            o.push_kv("avg_saved_bytes_vs_threshold", r.avg_saved_bytes);
+            o.push_kv("repeat_char", r.repeat_char);
+        }
+        None => {
+            o.push_kv("available", false);
+        }
+    });
+
     // Remove trailing comma from the last section.
     if out.ends_with(',') {
         out.pop();
@@ -426,7 +444,7 @@ where
 }
 
 /// Push a JSON-escaped string value into the buffer.
-fn push_json_string(out: &mut String, s: &str) {
+pub(crate) fn push_json_string(out: &mut String, s: &str) {
     out.push('"');
     for c in s.chars() {
         match c {
@@ -0,0 +1,117 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Redraw-path metrics — which render path the cost model would pick.
+//!
+//! `Terminal::draw` chooses between a full redraw and a differential
+//! update per frame by estimating both paths' bytes (see
+//! `terminal/redraw_cost.rs`). The benchmark has no terminal, so this
+//! sampler runs the same estimate on every Nth frame and reports the
+//! chosen path and the bytes saved against the old fixed
+//! `DIRTY_THRESHOLD_RATIO` rule.
+//!
+//! Dirty-all frames are skipped (both the old rule and the cost model
+//! force a full redraw there), and every dirty cell counts as changed:
+//! the sampler keeps no copy of what a terminal would already show.
+
+use crate::frame::Frame;
+use crate::terminal::redraw_cost::{estimate_redraw, threshold_path, RedrawPath};
+
+/// Redraw decision totals over the sampled frames.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RedrawMetrics {
+    /// Frames the estimate ran on.
+    pub samples: u32,
+    /// Samples where the full redraw was cheaper (or tied).
+    pub full_frames: u32,
+    /// Samples where the differential path was cheaper.
+    pub diff_frames: u32,
+    /// Samples where the cost model and the threshold rule disagree.
+    pub threshold_disagreements: u32,
+    pub avg_full_bytes: f64,
+    pub avg_diff_bytes: f64,
+    /// Average bytes of the chosen path.
+    pub avg_chosen_bytes: f64,
+    /// Average bytes saved per sample against the threshold rule.
+    pub avg_saved_bytes: f64,
+    /// Whether the estimate assumed REP support (`TerminalCaps::repeat_char`).
+    pub repeat_char: bool,
+}
+
+/// Accumulator — call `sample()` every frame, `finalize()` at the end.
+pub(crate) struct RedrawSampler {
+    sample_interval: u32,
+    frame_counter: u32,
+    repeat_char: bool,
+    /// Reused sorted copy of the frame's dirty indices.
+    dirty: Vec<usize>,
+    samples: u32,
+    full_frames: u32,
+    threshold_disagreements: u32,
+    full_sum: u64,
+    diff_sum: u64,
+    chosen_sum: u64,
+    saved_sum: u64,
+}
+
+impl RedrawSampler {
+    pub(crate) fn new(sample_interval: u32, repeat_char: bool) -> Self {
+        Self {
+            sample_interval: sample_interval.max(1),
+            frame_counter: 0,
+            repeat_char,
+            dirty: Vec::new(),
+            samples: 0,
+            full_frames: 0,
+            threshold_disagreements: 0,
+            full_sum: 0,
+            diff_sum: 0,
+            chosen_sum: 0,
+            saved_sum: 0,
+        }
+    }
+
+    /// Call every frame. Only samples every N frames to reduce overhead.
+    pub(crate) fn sample(&mut self, frame: &Frame) {
+        self.frame_counter += 1;
+        if !self.frame_counter.is_multiple_of(self.sample_interval) {
+            return;
+        }
+        let dirty = frame.dirty_indices();
+        if dirty.is_empty() || frame.is_dirty_all() {
+            return;
+        }
+        self.dirty.clear();
+        self.dirty.extend_from_slice(dirty);
+        self.dirty.sort_unstable();
+
+        let est = estimate_redraw(frame, &self.dirty, None, self.repeat_char);
+        let total_cells = frame.width as usize * frame.height as usize;
+        self.samples += 1;
+        if est.path() == RedrawPath::Full {
+            self.full_frames += 1;
+        }
+        if est.path() != threshold_path(self.dirty.len(), total_cells) {
+            self.threshold_disagreements += 1;
+        }
+        self.full_sum += est.full_bytes as u64;
+        self.diff_sum += est.diff_bytes as u64;
+        self.chosen_sum += est.chosen_bytes() as u64;
+        self.saved_sum += est.saved_vs_threshold(self.dirty.len(), total_cells) as u64;
+    }
+
+    pub(crate) fn finalize(&self) -> RedrawMetrics {
+        let n = f64::from(self.samples.max(1));
+        RedrawMetrics {
+            samples: self.samples,
+            full_frames: self.full_frames,
+            diff_frames: self.samples - self.full_frames,
+            threshold_disagreements: self.threshold_disagreements,
+            avg_full_bytes: self.full_sum as f64 / n,
+            avg_diff_bytes: self.diff_sum as f64 / n,
+            avg_chosen_bytes: self.chosen_sum as f64 / n,
+            avg_saved_bytes: self.saved_sum as f64 / n,
+            repeat_char: self.repeat_char,
+        }
+    }
+}
@@ -198,6 +198,9 @@ pub(crate) struct BenchReportData {
     // Phase 6: Visual objective metrics
     pub visual: Option<crate::bench_visual::VisualMetrics>,
 
+    // Redraw cost model: full-vs-diff choice (premium benchmark only)
+    pub redraw: Option<crate::bench_redraw::RedrawMetrics>,
+
     // Throughput
     // v50 LTS audit (Issue 2): renamed `glyphs_per_second` →
     // `glyphs_per_second_theoretical` because the value is the
@@ -170,6 +170,7 @@ mod tests {
             perf: None,
             allocator: None,
             visual: None,
+            redraw: None,
             glyphs_per_second_theoretical: 200_000,
             dirty_glyphs_per_second: 150_000,
             ansi_bytes_per_second: 3_000_000,
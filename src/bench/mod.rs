@@ -41,6 +41,7 @@ pub mod bench_mem;
 pub mod bench_meta;
 pub mod bench_perf;
 pub mod bench_progress;
+pub mod bench_redraw;
 pub mod bench_report;
 #[cfg(test)]
 pub mod bench_report_tests;
@@ -291,6 +292,9 @@ pub(crate) fn run_premium_benchmark(cfg: &CloudConfig) -> std::io::Result<()> {
     // measurement window — matching total_elapsed_s and total_frames scope.
     let perf_handle = crate::bench_perf::open_counters();
     let mut visual_sampler = crate::bench_visual::VisualSampler::new(10);
+    // Redraw cost model (full vs diff), sampled like the visual metrics.
+    let mut redraw_sampler =
+        crate::bench_redraw::RedrawSampler::new(10, crate::termdetect::detect().repeat_char);
 
     // ── Warmup phase ─────────────────────────────────────────────────────
     progress.warmup_start();
@@ -451,6 +455,7 @@ pub(crate) fn run_premium_benchmark(cfg: &CloudConfig) -> std::io::Result<()> {
 
         // Phase 6: visual objective metrics sampling
         visual_sampler.sample(&frame);
+        redraw_sampler.sample(&frame);
 
         frame.clear_dirty();
 
@@ -602,6 +607,7 @@ pub(crate) fn run_premium_benchmark(cfg: &CloudConfig) -> std::io::Result<()> {
     let energy_after = crate::bench_energy::EnergySnapshot::now();
     let perf_after = perf_handle.as_ref().map(|h| h.read()).unwrap_or_default();
     let visual_metrics = visual_sampler.finalize();
+    let redraw_metrics = redraw_sampler.finalize();
 
     let mut alloc_metrics = alloc_after.delta(&alloc_before);
     alloc_metrics.alloc_calls_per_frame = if total_frames > 0 {
@@ -894,6 +900,7 @@ pub(crate) fn run_premium_benchmark(cfg: &CloudConfig) -> std::io::Result<()> {
         perf: Some(perf_metrics),
         allocator: Some(alloc_metrics),
         visual: Some(visual_metrics),
+        redraw: Some(redraw_metrics),
         glyphs_per_second_theoretical,
         dirty_glyphs_per_second,
         ansi_bytes_per_second,
@@ -1268,6 +1275,7 @@ fn run_premium_benchmark_silent(cfg: &CloudConfig) -> std::io::Result<BenchRepor
         perf: Some(perf_metrics),
         allocator: Some(alloc_metrics),
         visual: Some(visual_metrics),
+        redraw: None,
         glyphs_per_second_theoretical: 0,
         dirty_glyphs_per_second: 0,
         ansi_bytes_per_second: 0,
@@ -193,7 +193,7 @@ fn audit_thermal_sampler_contract() {
     // Sampler: must not panic on missing sysfs. In a container without
     // /sys/class/thermal, this returns None. On real Linux with thermal
     // zones, it returns Some(0.0..=1.0). Both are valid.
-    let result = sample_thermal_pressure();
+    let result = sample_thermal_pressure(&PowerThresholds::defaults());
     if let Some(p) = result {
         assert!(
             (0.0..=1.0).contains(&p),
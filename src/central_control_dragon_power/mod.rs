@@ -92,6 +92,15 @@
 //! | xterm.js Tier 2                | Byte budget, window, RIS reset, hard ceiling      |
 //! | PowerThresholds struct         | Grouped thresholds for PowerManager                |
 //!
+//! Packaged binaries can override most of these per machine without a
+//! rebuild via the `[power-dragon]` config section (`tuning.rs`):
+//!
+//! ```toml
+//! [power-dragon]
+//! thermal-one-c = 80            # this laptop throttles at 80 °C
+//! self-heal-downgrade = false   # kiosk: never shed load automatically
+//! ```
+//!
 //! ## Calibration history
 //!
 //! - **(power audit consolidation)**: extracted all power management
@@ -125,6 +134,7 @@ mod power_manager;
 mod reclaim_state;
 mod self_healer;
 mod thermal_sampler;
+mod tuning;
 
 pub(crate) use endurance_health::*;
 pub(crate) use phase_predictor::*;
@@ -132,6 +142,11 @@ pub(crate) use power_manager::*;
 pub(crate) use reclaim_state::*;
 pub(crate) use self_healer::*;
 pub(crate) use thermal_sampler::*;
+pub(crate) use tuning::{
+    is_power_dragon_key, power_dragon_field, power_dragon_field_names,
+    power_thresholds_from_config, validate_power_dragon_value, validate_power_dragon_with_cfg,
+    POWER_DRAGON_PREFIX,
+};
 
 #[cfg(test)]
 mod audit_tests;
@@ -400,7 +415,12 @@ pub(crate) const XTERMJS_HARD_CEILING_BYTES: u64 = 200 * 1024 * 1024;
 /// standalone constants. The struct is now the sole consumer-facing
 /// API for these thresholds; the standalone constants remain as the
 /// canonical values that `defaults()` copies into the struct.
-#[derive(Clone, Copy, Debug)]
+///
+/// `[power-dragon]` in config.toml overrides any subset of the fields
+/// per machine (see `tuning.rs`); the event loop pushes the resolved
+/// struct into `PowerManager`, the self-healer, the thermal sampler and
+/// the terminal's xterm.js byte window at startup and on live reload.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub(crate) struct PowerThresholds {
     /// perf_pressure threshold for P1 downgrade trigger (0.6).
     pub pressure_high: f32,
@@ -422,6 +442,27 @@ pub(crate) struct PowerThresholds {
     pub pressure_increment: f32,
     /// perf_pressure decay per normal frame (0.02).
     pub pressure_decay: f32,
+    /// Whether the P1 downgrade may fire at all (true). `false` keeps
+    /// the self-healer to P2 mitigations only (kiosk installs).
+    pub self_heal_downgrade: bool,
+    /// Idle resync interval for the first idle hour (20.0).
+    pub idle_resync_secs: f64,
+    /// Idle resync interval for 1–4 idle hours (60.0).
+    pub idle_resync_tier_2_secs: f64,
+    /// Idle resync interval past 4 idle hours (120.0).
+    pub idle_resync_tier_3_secs: f64,
+    /// Temperature (°C) mapped to thermal_pressure 0.0 (50).
+    pub thermal_zero_c: i32,
+    /// Temperature (°C) mapped to thermal_pressure 1.0 (90).
+    pub thermal_one_c: i32,
+    /// xterm.js rolling byte budget per window (40 MiB).
+    pub xtermjs_byte_budget: u64,
+    /// Frames in the xterm.js byte-budget window (600).
+    pub xtermjs_window_frames: u64,
+    /// Cumulative bytes since last RIS that trigger ESC c (50 MiB).
+    pub xtermjs_ris_reset_bytes: u64,
+    /// Forced-RIS ceiling regardless of window state (200 MiB).
+    pub xtermjs_hard_ceiling_bytes: u64,
 }
 
 impl PowerThresholds {
@@ -441,6 +482,16 @@ impl PowerThresholds {
             idle_fps_factor: IDLE_FPS_FACTOR,
             pressure_increment: PERF_PRESSURE_INCREMENT,
             pressure_decay: PERF_PRESSURE_DECAY,
+            self_heal_downgrade: true,
+            idle_resync_secs: IDLE_REDRAW_RESYNC_INTERVAL_SECS,
+            idle_resync_tier_2_secs: IDLE_RESYNC_TIER_2_SECS,
+            idle_resync_tier_3_secs: IDLE_RESYNC_TIER_3_SECS,
+            thermal_zero_c: THERMAL_PRESSURE_ZERO_C,
+            thermal_one_c: THERMAL_PRESSURE_ONE_C,
+            xtermjs_byte_budget: XTERMJS_BYTE_BUDGET_PER_WINDOW,
+            xtermjs_window_frames: XTERMJS_BYTE_BUDGET_WINDOW_FRAMES,
+            xtermjs_ris_reset_bytes: XTERMJS_RIS_RESET_BYTES,
+            xtermjs_hard_ceiling_bytes: XTERMJS_HARD_CEILING_BYTES,
         }
     }
 }
@@ -471,6 +522,16 @@ mod tests {
         assert!((t.idle_fps_factor - IDLE_FPS_FACTOR).abs() < 1e-6);
         assert!((t.pressure_increment - PERF_PRESSURE_INCREMENT).abs() < 1e-6);
         assert!((t.pressure_decay - PERF_PRESSURE_DECAY).abs() < 1e-6);
+        assert!(t.self_heal_downgrade);
+        assert!((t.idle_resync_secs - IDLE_REDRAW_RESYNC_INTERVAL_SECS).abs() < 1e-6);
+        assert!((t.idle_resync_tier_2_secs - IDLE_RESYNC_TIER_2_SECS).abs() < 1e-6);
+        assert!((t.idle_resync_tier_3_secs - IDLE_RESYNC_TIER_3_SECS).abs() < 1e-6);
+        assert_eq!(t.thermal_zero_c, THERMAL_PRESSURE_ZERO_C);
+        assert_eq!(t.thermal_one_c, THERMAL_PRESSURE_ONE_C);
+        assert_eq!(t.xtermjs_byte_budget, XTERMJS_BYTE_BUDGET_PER_WINDOW);
+        assert_eq!(t.xtermjs_window_frames, XTERMJS_BYTE_BUDGET_WINDOW_FRAMES);
+        assert_eq!(t.xtermjs_ris_reset_bytes, XTERMJS_RIS_RESET_BYTES);
+        assert_eq!(t.xtermjs_hard_ceiling_bytes, XTERMJS_HARD_CEILING_BYTES);
     }
 
     #[test]
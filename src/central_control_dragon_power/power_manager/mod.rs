@@ -140,13 +140,27 @@ impl PowerManager {
     }
 
     /// Override the default thresholds. Test-only — production code
-    /// uses the constants via [`PowerThresholds::defaults`].
+    /// uses [`set_thresholds`](Self::set_thresholds).
     #[cfg(test)]
     pub(crate) fn with_thresholds(mut self, thresholds: PowerThresholds) -> Self {
         self.thresholds = thresholds;
         self
     }
 
+    /// Install the `[power-dragon]` thresholds resolved from config. Called
+    /// at startup and after every live reload; takes effect on the next
+    /// `begin_frame()` / `effective_fps()` call.
+    pub(crate) fn set_thresholds(&mut self, thresholds: PowerThresholds) {
+        self.thresholds = thresholds;
+    }
+
+    /// The thresholds currently in force. The event loop reads the idle
+    /// resync tiers and thermal ramp from here.
+    #[must_use]
+    pub(crate) fn thresholds(&self) -> PowerThresholds {
+        self.thresholds
+    }
+
     /// User input arrived — reset the idle timer and record the phase
     /// transition for the predictor.
     ///
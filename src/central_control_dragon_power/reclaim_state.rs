@@ -47,16 +47,27 @@ use crate::constants::*;
 /// constants in `constants.rs` (`SECS_PER_HOUR`, `SECS_PER_4_HOURS`,
 /// `IDLE_RESYNC_TIER_2_SECS`, `IDLE_RESYNC_TIER_3_SECS`). Previously these
 /// were four magic numbers inline.
+#[cfg(test)]
 pub(crate) fn adaptive_resync_interval(idle_duration_secs: f64) -> f64 {
+    adaptive_resync_interval_with(idle_duration_secs, &PowerThresholds::defaults())
+}
+
+/// [`adaptive_resync_interval`] with the tier intervals taken from
+/// `thresholds` (the `[power-dragon]` idle-resync keys). The tier
+/// boundaries (1h / 4h) stay fixed.
+pub(crate) fn adaptive_resync_interval_with(
+    idle_duration_secs: f64,
+    thresholds: &PowerThresholds,
+) -> f64 {
     if idle_duration_secs < SECS_PER_HOUR {
         // < 1 hour idle: standard interval (20s).
-        IDLE_REDRAW_RESYNC_INTERVAL_SECS
+        thresholds.idle_resync_secs
     } else if idle_duration_secs < SECS_PER_4_HOURS {
         // 1–4 hours idle: 60s interval (3× reduction).
-        IDLE_RESYNC_TIER_2_SECS
+        thresholds.idle_resync_tier_2_secs
     } else {
         // > 4 hours idle: 120s interval (6× reduction).
-        IDLE_RESYNC_TIER_3_SECS
+        thresholds.idle_resync_tier_3_secs
     }
 }
 
@@ -155,6 +166,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn resync_interval_with_uses_configured_tiers() {
+        let t = PowerThresholds {
+            idle_resync_secs: 5.0,
+            idle_resync_tier_2_secs: 30.0,
+            idle_resync_tier_3_secs: 300.0,
+            ..PowerThresholds::defaults()
+        };
+        assert_eq!(adaptive_resync_interval_with(10.0, &t), 5.0);
+        assert_eq!(adaptive_resync_interval_with(3600.0, &t), 30.0);
+        assert_eq!(adaptive_resync_interval_with(14400.0, &t), 300.0);
+    }
+
     #[test]
     fn resync_interval_60s_after_1h() {
         assert_eq!(adaptive_resync_interval(3600.0), 60.0);
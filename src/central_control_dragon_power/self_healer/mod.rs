@@ -154,6 +154,14 @@ impl PerformanceSelfHealer {
         self
     }
 
+    /// Install the `[power-dragon]` thresholds resolved from config. The
+    /// event loop calls this at startup and after every live reload (the
+    /// reload also calls [`reset`](Self::reset), so streaks restart under
+    /// the new windows).
+    pub(crate) fn set_thresholds(&mut self, thresholds: PowerThresholds) {
+        self.thresholds = thresholds;
+    }
+
     /// Read-only access to the thresholds. Test-only — production code
     /// interacts with the healer through `observe()` + `record_downgrade()`
     /// + `take_pre_degraded_scene()` + `reset()`.
@@ -209,7 +217,9 @@ impl PerformanceSelfHealer {
             // Any high-pressure frame breaks the low-pressure recovery streak.
             self.low_pressure_since = None;
 
-            if !self.is_downgraded {
+            // `self-heal-downgrade = false` keeps the streak bookkeeping
+            // but never fires — P2 mitigations above still run.
+            if !self.is_downgraded && self.thresholds.self_heal_downgrade {
                 let since = self.high_pressure_since.unwrap_or(now);
                 let elapsed = now.saturating_duration_since(since).as_secs_f64();
                 if elapsed >= self.thresholds.downgrade_secs {
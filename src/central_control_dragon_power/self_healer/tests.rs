@@ -341,3 +341,20 @@ fn self_healer_respects_overridden_thresholds_in_observe() {
     assert_eq!(action, SelfHealAction::DowngradeScene);
     assert!(h.is_downgraded());
 }
+
+#[test]
+fn self_healer_downgrade_disabled_never_fires_but_p2_still_runs() {
+    // [power-dragon] self-heal-downgrade = false (kiosk installs).
+    let mut custom = PowerThresholds::defaults();
+    custom.self_heal_downgrade = false;
+    let mut h = PerformanceSelfHealer::new().with_thresholds(custom);
+    let t0 = Instant::now();
+    for i in 0..120 {
+        let t = t0 + Duration::from_secs(i);
+        let action = h.observe(1.0, t, Some(95.0));
+        assert_eq!(action, SelfHealAction::None, "fired at t={i}");
+    }
+    assert!(!h.is_downgraded());
+    let action = h.observe(1.0, t0 + Duration::from_secs(121), Some(10.0));
+    assert_eq!(action, SelfHealAction::TriggerHealthMitigation);
+}
@@ -31,7 +31,9 @@
 //! junction-temperature throttle band of x86_64 mobile and desktop
 //! SoCs. Below 50 °C the device is cool enough that no throttling is
 //! expected; above 90 °C the device is at or past the throttle
-//! threshold and the renderer should shed maximum load.
+//! threshold and the renderer should shed maximum load. Machines that
+//! throttle earlier move the window with `[power-dragon]`
+//! `thermal-zero-c` / `thermal-one-c`.
 //!
 //! ## Cadence
 //!
@@ -76,8 +78,11 @@ use crate::constants::*;
 /// # Platform support
 ///
 /// Linux only. On non-Linux platforms this returns `None` unconditionally.
+///
+/// The ramp endpoints come from `thresholds` (`[power-dragon]`
+/// `thermal-zero-c` / `thermal-one-c`).
 #[cfg(target_os = "linux")]
-pub(crate) fn sample_thermal_pressure() -> Option<f32> {
+pub(crate) fn sample_thermal_pressure(thresholds: &PowerThresholds) -> Option<f32> {
     let entries = std::fs::read_dir("/sys/class/thermal").ok()?;
     let mut hottest: Option<i64> = None;
     for entry in entries.flatten() {
@@ -109,7 +114,13 @@ pub(crate) fn sample_thermal_pressure() -> Option<f32> {
         }
         hottest = Some(highest(hottest, millideg));
     }
-    hottest.map(normalize_celsius)
+    hottest.map(|millideg| {
+        normalize_celsius_with(
+            millideg,
+            thresholds.thermal_zero_c,
+            thresholds.thermal_one_c,
+        )
+    })
 }
 
 /// Linear normalization from degrees Celsius to 0.0–1.0 pressure.
@@ -125,20 +136,36 @@ pub(crate) fn sample_thermal_pressure() -> Option<f32> {
 // those targets (FreeBSD clippy enforces `-D warnings`) would otherwise
 // flag this as dead code. The function itself is intentionally
 // cross-platform so the math tests run on every target.
-#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+#[cfg(test)]
 #[must_use]
 pub(crate) fn normalize_celsius(celsius_millideg: i64) -> f32 {
+    normalize_celsius_with(
+        celsius_millideg,
+        THERMAL_PRESSURE_ZERO_C,
+        THERMAL_PRESSURE_ONE_C,
+    )
+}
+
+/// [`normalize_celsius`] over an explicit `zero_c..one_c` ramp.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+#[must_use]
+pub(crate) fn normalize_celsius_with(celsius_millideg: i64, zero_c: i32, one_c: i32) -> f32 {
     let celsius = celsius_millideg as f32 / 1000.0;
-    let lo = THERMAL_PRESSURE_ZERO_C as f32;
-    let hi = THERMAL_PRESSURE_ONE_C as f32;
-    // hi > lo is enforced by the constant sanity test in mod.rs.
+    let lo = zero_c as f32;
+    let hi = one_c as f32;
+    // hi > lo is enforced by the constant sanity test in mod.rs and by
+    // the `[power-dragon]` ordering check; guard anyway so a degenerate
+    // ramp reads as a step instead of NaN.
+    if hi <= lo {
+        return if celsius >= hi { 1.0 } else { 0.0 };
+    }
     let pressure = (celsius - lo) / (hi - lo);
     pressure.clamp(0.0, 1.0)
 }
 
 /// Non-Linux stub: no thermal sampling available.
 #[cfg(not(target_os = "linux"))]
-pub(crate) fn sample_thermal_pressure() -> Option<f32> {
+pub(crate) fn sample_thermal_pressure(_thresholds: &PowerThresholds) -> Option<f32> {
     None
 }
 
@@ -205,6 +232,16 @@ mod tests {
         assert!((p - 0.75).abs() < 1e-6, "expected 0.75, got {p}");
     }
 
+    #[test]
+    fn normalize_with_custom_ramp_saturates_at_one_c() {
+        // A laptop that throttles at 80 °C: [power-dragon] thermal-one-c = 80.
+        assert_eq!(normalize_celsius_with(80_000, 50, 80), 1.0);
+        let p = normalize_celsius_with(65_000, 50, 80);
+        assert!((p - 0.5).abs() < 1e-6, "expected 0.5, got {p}");
+        // Degenerate ramp must not produce NaN.
+        assert_eq!(normalize_celsius_with(60_000, 70, 70), 0.0);
+    }
+
     #[test]
     fn normalize_is_monotonically_increasing() {
         // Sample every 5 °C across the ramp window and verify
@@ -235,7 +272,7 @@ mod tests {
         // On a real Linux box with thermal zones, this returns Some.
         // In a container without thermal sysfs, this returns None.
         // Both outcomes are valid — we just verify no panic.
-        let _ = sample_thermal_pressure();
+        let _ = sample_thermal_pressure(&PowerThresholds::defaults());
     }
 
     #[cfg(target_os = "linux")]
@@ -245,7 +282,7 @@ mod tests {
         // unreadable. read_dir returns Err, which propagates as None
         // via the `?` operator. Verify by calling the function in any
         // environment — if it panics, the test fails.
-        let result = sample_thermal_pressure();
+        let result = sample_thermal_pressure(&PowerThresholds::defaults());
         assert!(result.is_none() || result.unwrap() >= 0.0);
     }
 
@@ -0,0 +1,333 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Runtime power thresholds — the `[power-dragon]` config section.
+//!
+//! The constants in `mod.rs` stay the calibrated defaults;
+//! [`PowerThresholds::defaults`] copies them, so an empty section behaves
+//! exactly like the compiled-in stack. Machines that throttle earlier, or
+//! installs that must never shed load, override a subset:
+//!
+//! ```toml
+//! [power-dragon]
+//! thermal-one-c = 80            # full thermal pressure at 80 °C
+//! self-heal-downgrade = false   # kiosk: P1 downgrade never fires
+//! idle-fps-factor = 0.25
+//! ```
+//!
+//! Keys are stored as `power-dragon.<field>` — distinct from the bare
+//! `power-dragon = true|false` toggle, which still switches the whole
+//! adaptive layer on or off. Every numeric field has a hard range
+//! ([`POWER_DRAGON_FIELDS`]), the switches take true/false, and the
+//! paired thresholds must stay ordered
+//! (low < high, zero < one, tier 1 ≤ tier 2 ≤ tier 3, RIS < ceiling).
+//! A pair that ends up out of order falls back to its defaults as a
+//! whole. Live reload re-parses the whole section, same as
+//! `[rain-tuning]`.
+//!
+//! Byte budgets are given in MiB; everything else uses the unit of the
+//! constant it overrides.
+
+use super::PowerThresholds;
+use crate::config_schema::{ConfigMap, ConfigValue, ValueKind};
+use crate::validation::parse_field_number;
+
+/// Config key prefix for the `[power-dragon]` section.
+pub(crate) const POWER_DRAGON_PREFIX: &str = "power-dragon.";
+
+const MIB: u64 = 1024 * 1024;
+
+enum Slot<'a> {
+    Secs(&'a mut f64),
+    Factor(&'a mut f64),
+    Pressure(&'a mut f32),
+    Celsius(&'a mut i32),
+    Frames(&'a mut u64),
+    Mib(&'a mut u64),
+    Flag(&'a mut bool),
+}
+
+/// One `[power-dragon]` key: its name, hard range and the value it sets.
+pub(crate) struct PowerDragonField {
+    pub(crate) name: &'static str,
+    pub(crate) min: f64,
+    pub(crate) max: f64,
+    slot: fn(&mut PowerThresholds) -> Slot<'_>,
+}
+
+const fn field(
+    name: &'static str,
+    min: f64,
+    max: f64,
+    slot: fn(&mut PowerThresholds) -> Slot<'_>,
+) -> PowerDragonField {
+    PowerDragonField {
+        name,
+        min,
+        max,
+        slot,
+    }
+}
+
+/// A true/false switch; the range is unused.
+const fn flag(name: &'static str, slot: fn(&mut PowerThresholds) -> Slot<'_>) -> PowerDragonField {
+    field(name, 0.0, 1.0, slot)
+}
+
+impl PowerDragonField {
+    /// TOML type of the key, read off the slot it writes.
+    pub(crate) fn kind(&self) -> ValueKind {
+        let mut scratch = PowerThresholds::defaults();
+        match (self.slot)(&mut scratch) {
+            Slot::Secs(_) | Slot::Factor(_) | Slot::Pressure(_) => ValueKind::Number,
+            Slot::Celsius(_) | Slot::Frames(_) | Slot::Mib(_) => ValueKind::Integer,
+            Slot::Flag(_) => ValueKind::Bool,
+        }
+    }
+}
+
+/// Every `[power-dragon]` key, numeric fields first.
+pub(crate) const POWER_DRAGON_FIELDS: &[PowerDragonField] = &[
+    field("idle-threshold-secs", 5.0, 3600.0, |t| {
+        Slot::Secs(&mut t.idle_threshold_secs)
+    }),
+    field("idle-fps-factor", 0.05, 1.0, |t| {
+        Slot::Factor(&mut t.idle_fps_factor)
+    }),
+    field("idle-resync-secs", 1.0, 3600.0, |t| {
+        Slot::Secs(&mut t.idle_resync_secs)
+    }),
+    field("idle-resync-tier-2-secs", 1.0, 3600.0, |t| {
+        Slot::Secs(&mut t.idle_resync_tier_2_secs)
+    }),
+    field("idle-resync-tier-3-secs", 1.0, 3600.0, |t| {
+        Slot::Secs(&mut t.idle_resync_tier_3_secs)
+    }),
+    field("self-heal-pressure-high", 0.05, 1.0, |t| {
+        Slot::Pressure(&mut t.pressure_high)
+    }),
+    field("self-heal-pressure-low", 0.0, 0.95, |t| {
+        Slot::Pressure(&mut t.pressure_low)
+    }),
+    field("self-heal-downgrade-secs", 1.0, 3600.0, |t| {
+        Slot::Secs(&mut t.downgrade_secs)
+    }),
+    field("self-heal-restore-secs", 1.0, 3600.0, |t| {
+        Slot::Secs(&mut t.restore_secs)
+    }),
+    field("thermal-zero-c", 0.0, 100.0, |t| {
+        Slot::Celsius(&mut t.thermal_zero_c)
+    }),
+    field("thermal-one-c", 30.0, 150.0, |t| {
+        Slot::Celsius(&mut t.thermal_one_c)
+    }),
+    field("xtermjs-byte-budget-mib", 1.0, 1024.0, |t| {
+        Slot::Mib(&mut t.xtermjs_byte_budget)
+    }),
+    field("xtermjs-window-frames", 100.0, 10_000.0, |t| {
+        Slot::Frames(&mut t.xtermjs_window_frames)
+    }),
+    field("xtermjs-ris-reset-mib", 1.0, 4096.0, |t| {
+        Slot::Mib(&mut t.xtermjs_ris_reset_bytes)
+    }),
+    field("xtermjs-hard-ceiling-mib", 1.0, 4096.0, |t| {
+        Slot::Mib(&mut t.xtermjs_hard_ceiling_bytes)
+    }),
+    flag("self-heal-downgrade", |t| {
+        Slot::Flag(&mut t.self_heal_downgrade)
+    }),
+];
+
+/// Every `[power-dragon]` field name, in table order.
+pub(crate) fn power_dragon_field_names() -> impl Iterator<Item = &'static str> {
+    POWER_DRAGON_FIELDS.iter().map(|f| f.name)
+}
+
+/// The field behind `power-dragon.<field>`, if `key` names one.
+#[must_use]
+pub(crate) fn power_dragon_field(key: &str) -> Option<&'static PowerDragonField> {
+    let field = key.strip_prefix(POWER_DRAGON_PREFIX)?;
+    find_field(field)
+}
+
+fn find_field(field: &str) -> Option<&'static PowerDragonField> {
+    POWER_DRAGON_FIELDS.iter().find(|f| f.name == field)
+}
+
+/// `true` for `power-dragon.<field>` with a known field.
+#[must_use]
+pub(crate) fn is_power_dragon_key(key: &str) -> bool {
+    power_dragon_field(key).is_some()
+}
+
+/// Parse `raw` for `field` and store it in `t`. Errors use the
+/// `--testconf` wording ("out of range [a, b], got n").
+pub(crate) fn apply_power_dragon_value(
+    t: &mut PowerThresholds,
+    field: &str,
+    raw: &str,
+) -> Result<(), String> {
+    let Some(spec) = find_field(field) else {
+        return Err(format!("unknown [power-dragon] field '{field}'"));
+    };
+    let (min, max) = (spec.min, spec.max);
+    let raw = raw.trim();
+    match (spec.slot)(t) {
+        Slot::Secs(out) | Slot::Factor(out) => *out = parse_field_number(raw, min, max)?,
+        Slot::Pressure(out) => *out = parse_field_number(raw, min, max)? as f32,
+        Slot::Celsius(out) => *out = parse_integer(raw, min, max)? as i32,
+        Slot::Frames(out) => *out = parse_integer(raw, min, max)? as u64,
+        Slot::Mib(out) => *out = parse_integer(raw, min, max)? as u64 * MIB,
+        Slot::Flag(out) => *out = parse_flag(raw)?,
+    }
+    Ok(())
+}
+
+fn parse_flag(raw: &str) -> Result<bool, String> {
+    match raw.to_ascii_lowercase().as_str() {
+        "true" | "yes" | "on" | "1" => Ok(true),
+        "false" | "no" | "off" | "0" => Ok(false),
+        _ => Err(format!(
+            "expected true/false (or yes/no, on/off, 1/0), got '{raw}'"
+        )),
+    }
+}
+
+fn parse_integer(raw: &str, min: f64, max: f64) -> Result<i64, String> {
+    let n: i64 = raw
+        .parse()
+        .map_err(|_| format!("expected integer in [{min}, {max}], got '{raw}'"))?;
+    if !(min..=max).contains(&(n as f64)) {
+        return Err(format!("out of range [{min}, {max}], got {n}"));
+    }
+    Ok(n)
+}
+
+/// Ordering rules between paired fields. Each entry names the fields
+/// involved (reverted together on violation) and the message.
+fn ordering_violations(t: &PowerThresholds) -> Vec<(&'static [&'static str], String)> {
+    let mut out: Vec<(&'static [&'static str], String)> = Vec::new();
+    if t.pressure_low >= t.pressure_high {
+        out.push((
+            &["self-heal-pressure-low", "self-heal-pressure-high"],
+            format!(
+                "self-heal-pressure-low ({}) must be below self-heal-pressure-high ({})",
+                t.pressure_low, t.pressure_high
+            ),
+        ));
+    }
+    if t.thermal_zero_c >= t.thermal_one_c {
+        out.push((
+            &["thermal-zero-c", "thermal-one-c"],
+            format!(
+                "thermal-zero-c ({}) must be below thermal-one-c ({})",
+                t.thermal_zero_c, t.thermal_one_c
+            ),
+        ));
+    }
+    if t.idle_resync_secs > t.idle_resync_tier_2_secs
+        || t.idle_resync_tier_2_secs > t.idle_resync_tier_3_secs
+    {
+        out.push((
+            &[
+                "idle-resync-secs",
+                "idle-resync-tier-2-secs",
+                "idle-resync-tier-3-secs",
+            ],
+            format!(
+                "idle resync tiers must not shrink ({} → {} → {})",
+                t.idle_resync_secs, t.idle_resync_tier_2_secs, t.idle_resync_tier_3_secs
+            ),
+        ));
+    }
+    if t.xtermjs_ris_reset_bytes >= t.xtermjs_hard_ceiling_bytes {
+        out.push((
+            &["xtermjs-ris-reset-mib", "xtermjs-hard-ceiling-mib"],
+            format!(
+                "xtermjs-ris-reset-mib ({}) must be below xtermjs-hard-ceiling-mib ({})",
+                t.xtermjs_ris_reset_bytes / MIB,
+                t.xtermjs_hard_ceiling_bytes / MIB
+            ),
+        ));
+    }
+    out
+}
+
+/// Validate one `power-dragon.<field>` value in isolation (used by the
+/// `--testconf` field table).
+#[must_use]
+pub(crate) fn validate_power_dragon_value(key: &str, raw: &str) -> Option<String> {
+    let field = key.strip_prefix(POWER_DRAGON_PREFIX).unwrap_or(key);
+    let mut scratch = PowerThresholds::defaults();
+    apply_power_dragon_value(&mut scratch, field, raw).err()
+}
+
+/// Validate one `power-dragon.<field>` value plus the ordering rules it
+/// takes part in, against the rest of the section in `cfg`.
+#[must_use]
+pub(crate) fn validate_power_dragon_with_cfg(
+    key: &str,
+    raw: &str,
+    cfg: &ConfigMap,
+) -> Option<String> {
+    if let Some(msg) = validate_power_dragon_value(key, raw) {
+        return Some(msg);
+    }
+    let field = key.strip_prefix(POWER_DRAGON_PREFIX).unwrap_or(key);
+    let (t, _) = apply_all(cfg);
+    ordering_violations(&t)
+        .into_iter()
+        .find(|(fields, _)| fields.contains(&field))
+        .map(|(_, msg)| msg)
+}
+
+fn apply_all(cfg: &ConfigMap) -> (PowerThresholds, Vec<(String, String)>) {
+    let mut t = PowerThresholds::defaults();
+    let mut errors = Vec::new();
+    for field in power_dragon_field_names() {
+        let key = format!("{POWER_DRAGON_PREFIX}{field}");
+        if let Some(raw) = cfg.get(&key).and_then(ConfigValue::as_str) {
+            if let Err(msg) = apply_power_dragon_value(&mut t, field, raw) {
+                errors.push((key, msg));
+            }
+        }
+    }
+    (t, errors)
+}
+
+/// Build the thresholds from a parsed config map. Absent fields keep
+/// their constant; invalid values are skipped and out-of-order pairs
+/// fall back to their defaults. Problems come back as `(key, message)`.
+pub(crate) fn power_thresholds_from_config(
+    cfg: &ConfigMap,
+) -> (PowerThresholds, Vec<(String, String)>) {
+    let (mut t, mut errors) = apply_all(cfg);
+    let defaults = PowerThresholds::defaults();
+    for (fields, msg) in ordering_violations(&t) {
+        let key = fields
+            .iter()
+            .map(|f| format!("{POWER_DRAGON_PREFIX}{f}"))
+            .find(|k| cfg.contains_key(k))
+            .unwrap_or_else(|| format!("{POWER_DRAGON_PREFIX}{}", fields[0]));
+        for spec in fields.iter().filter_map(|field| find_field(field)) {
+            let mut source = defaults;
+            restore((spec.slot)(&mut t), (spec.slot)(&mut source));
+        }
+        errors.push((key, format!("{msg}; falling back to the defaults")));
+    }
+    (t, errors)
+}
+
+fn restore(dst: Slot<'_>, src: Slot<'_>) {
+    match (dst, src) {
+        (Slot::Secs(d), Slot::Secs(s)) | (Slot::Factor(d), Slot::Factor(s)) => *d = *s,
+        (Slot::Pressure(d), Slot::Pressure(s)) => *d = *s,
+        (Slot::Celsius(d), Slot::Celsius(s)) => *d = *s,
+        (Slot::Frames(d), Slot::Frames(s)) | (Slot::Mib(d), Slot::Mib(s)) => *d = *s,
+        (Slot::Flag(d), Slot::Flag(s)) => *d = *s,
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,88 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! `[power-dragon]` parsing tests.
+
+use super::*;
+use crate::config_schema::{value_from_text, ValueKind};
+
+fn cfg(pairs: &[(&str, &str)]) -> ConfigMap {
+    pairs
+        .iter()
+        .map(|(k, v)| ((*k).to_string(), value_from_text(k, v)))
+        .collect()
+}
+
+#[test]
+fn empty_section_is_the_compiled_calibration() {
+    let (t, errors) = power_thresholds_from_config(&ConfigMap::new());
+    assert!(errors.is_empty());
+    assert_eq!(t, PowerThresholds::defaults());
+}
+
+#[test]
+fn every_field_is_a_key_and_writes_its_own_slot() {
+    let d = PowerThresholds::defaults();
+    for spec in POWER_DRAGON_FIELDS {
+        let field = spec.name;
+        assert!(is_power_dragon_key(&format!(
+            "{POWER_DRAGON_PREFIX}{field}"
+        )));
+        // The minimum differs from every default; flags default to on.
+        let raw = match spec.kind() {
+            ValueKind::Bool => "false".to_string(),
+            _ => format!("{}", spec.min),
+        };
+        let mut t = d;
+        apply_power_dragon_value(&mut t, field, &raw)
+            .unwrap_or_else(|e| panic!("{field} = {raw}: {e}"));
+        assert_ne!(t, d, "{field} must write its own slot");
+    }
+    assert!(!is_power_dragon_key("power-dragon"));
+    assert!(!is_power_dragon_key("power-dragon.turbo"));
+}
+
+#[test]
+fn laptop_and_kiosk_overrides_apply() {
+    let (t, errors) = power_thresholds_from_config(&cfg(&[
+        ("power-dragon.thermal-one-c", "80"),
+        ("power-dragon.self-heal-downgrade", "false"),
+        ("power-dragon.xtermjs-byte-budget-mib", "16"),
+    ]));
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(t.thermal_one_c, 80);
+    assert!(!t.self_heal_downgrade);
+    assert_eq!(t.xtermjs_byte_budget, 16 * MIB);
+    assert_eq!(t.thermal_zero_c, PowerThresholds::defaults().thermal_zero_c);
+}
+
+#[test]
+fn out_of_order_pair_falls_back_to_defaults() {
+    let (t, errors) = power_thresholds_from_config(&cfg(&[
+        ("power-dragon.self-heal-pressure-low", "0.8"),
+        ("power-dragon.idle-fps-factor", "0.25"),
+    ]));
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert_eq!(errors[0].0, "power-dragon.self-heal-pressure-low");
+    let d = PowerThresholds::defaults();
+    assert_eq!(t.pressure_low, d.pressure_low);
+    assert_eq!(t.pressure_high, d.pressure_high);
+    assert_eq!(t.idle_fps_factor, 0.25);
+}
+
+#[test]
+fn validate_with_cfg_reports_ordering_for_both_sides() {
+    let c = cfg(&[
+        ("power-dragon.xtermjs-ris-reset-mib", "300"),
+        ("power-dragon.xtermjs-hard-ceiling-mib", "250"),
+    ]);
+    for key in [
+        "power-dragon.xtermjs-ris-reset-mib",
+        "power-dragon.xtermjs-hard-ceiling-mib",
+    ] {
+        let err = validate_power_dragon_with_cfg(key, &c[key].to_string(), &c).unwrap();
+        assert!(err.contains("must be below"), "{err}");
+    }
+    let err = validate_power_dragon_value("power-dragon.idle-threshold-secs", "1").unwrap();
+    assert!(err.starts_with("out of range [5, 3600]"), "{err}");
+}
@@ -0,0 +1,254 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Cinematic event knobs — the `[events]` config section.
+//!
+//! Every event the `GhostEventScheduler` can spawn has a per-tick chance
+//! and a cooldown (minimum gap between two spawns of that kind). The
+//! decrypt event also takes the word list it resolves into; with no words
+//! it never fires.
+//!
+//! ```toml
+//! [events.decrypt]
+//! words = ["WAKE UP", "FOLLOW THE RAIN"]
+//! cooldown-secs = 30
+//!
+//! [events.tear]
+//! chance = 0              # never
+//! ```
+//!
+//! Keys are stored as `events.<kind>.<field>`. Ranges are checked by
+//! `--testconf` and startup validation. On live reload the new knobs only
+//! affect later spawns: an event already on screen plays out, and each
+//! kind's cooldown keeps counting from its last spawn.
+
+use std::time::Duration;
+
+use unicode_width::UnicodeWidthChar;
+
+use super::{
+    CASCADE_COOLDOWN, CASCADE_SPAWN_CHANCE_PER_TICK, DECRYPT_COOLDOWN, DECRYPT_MAX_WORD_LEN,
+    DECRYPT_SPAWN_CHANCE_PER_TICK, GHOST_SPAWN_CHANCE_PER_TICK, SCANLINE_COOLDOWN,
+    SCANLINE_SPAWN_CHANCE_PER_TICK, TEAR_COOLDOWN, TEAR_SPAWN_CHANCE_PER_TICK,
+};
+use crate::config_schema::{ConfigMap, ConfigValue, ValueKind};
+use crate::validation::parse_field_number;
+
+/// Config key prefix for the `[events]` section.
+pub(crate) const EVENTS_PREFIX: &str = "events.";
+
+/// `events.decrypt.<field>` holding the word list.
+pub(crate) const DECRYPT_WORDS_FIELD: &str = "words";
+
+/// Cinematic event types, in the order the scheduler rolls them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum EventKind {
+    /// Halfwidth kana fading in and out on dim rain cells.
+    Ghost,
+    /// Scrambled glyphs resolving into a word from `events.decrypt.words`.
+    Decrypt,
+    /// A bright line sweeping down the screen.
+    Scanline,
+    /// A fast burst of glyphs falling down one to three columns.
+    Cascade,
+    /// A band of rows shifted sideways for a moment.
+    Tear,
+}
+
+impl EventKind {
+    pub(crate) const ALL: [EventKind; 5] = [
+        EventKind::Ghost,
+        EventKind::Decrypt,
+        EventKind::Scanline,
+        EventKind::Cascade,
+        EventKind::Tear,
+    ];
+
+    /// Section name (`events.<name>.*`).
+    #[must_use]
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            EventKind::Ghost => "ghost",
+            EventKind::Decrypt => "decrypt",
+            EventKind::Scanline => "scanline",
+            EventKind::Cascade => "cascade",
+            EventKind::Tear => "tear",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.name() == name)
+    }
+
+    /// Position in [`EventKind::ALL`].
+    #[must_use]
+    pub(crate) fn index(self) -> usize {
+        self as usize
+    }
+
+    const fn default_knobs(self) -> EventKnobs {
+        let (chance, cooldown) = match self {
+            EventKind::Ghost => (GHOST_SPAWN_CHANCE_PER_TICK, Duration::ZERO),
+            EventKind::Decrypt => (DECRYPT_SPAWN_CHANCE_PER_TICK, DECRYPT_COOLDOWN),
+            EventKind::Scanline => (SCANLINE_SPAWN_CHANCE_PER_TICK, SCANLINE_COOLDOWN),
+            EventKind::Cascade => (CASCADE_SPAWN_CHANCE_PER_TICK, CASCADE_COOLDOWN),
+            EventKind::Tear => (TEAR_SPAWN_CHANCE_PER_TICK, TEAR_COOLDOWN),
+        };
+        EventKnobs { chance, cooldown }
+    }
+}
+
+/// Spawn knobs shared by every event kind.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct EventKnobs {
+    /// Chance per tick (0 = never).
+    pub chance: f64,
+    /// Minimum time from one spawn of this kind to the next.
+    pub cooldown: Duration,
+}
+
+/// Event knobs, resolved from `[events]` over the compiled-in defaults.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct EventTuning {
+    knobs: [EventKnobs; EventKind::ALL.len()],
+    /// Words the decrypt event resolves into; empty disables it.
+    pub decrypt_words: Vec<String>,
+}
+
+impl EventTuning {
+    #[must_use]
+    pub(crate) fn knobs(&self, kind: EventKind) -> EventKnobs {
+        self.knobs[kind.index()]
+    }
+}
+
+impl Default for EventTuning {
+    fn default() -> Self {
+        Self {
+            knobs: EventKind::ALL.map(EventKind::default_knobs),
+            decrypt_words: Vec::new(),
+        }
+    }
+}
+
+/// `(field, min, max)` for the per-kind numeric keys.
+pub(crate) const EVENT_FIELDS: &[(&str, f64, f64)] =
+    &[("chance", 0.0, 1.0), ("cooldown-secs", 0.0, 3600.0)];
+
+fn split_key(key: &str) -> Option<(EventKind, &str)> {
+    let (kind, field) = key.strip_prefix(EVENTS_PREFIX)?.split_once('.')?;
+    Some((EventKind::from_name(kind)?, field))
+}
+
+fn is_known_field(kind: EventKind, field: &str) -> bool {
+    EVENT_FIELDS.iter().any(|(f, _, _)| *f == field)
+        || (kind == EventKind::Decrypt && field == DECRYPT_WORDS_FIELD)
+}
+
+/// `true` for `events.<kind>.<field>` with a known kind and field.
+#[must_use]
+pub(crate) fn is_event_key(key: &str) -> bool {
+    split_key(key).is_some_and(|(kind, field)| is_known_field(kind, field))
+}
+
+/// TOML type of `events.<kind>.<field>`: the word list is an array, the
+/// [`EVENT_FIELDS`] are numbers.
+#[must_use]
+pub(crate) fn event_value_kind(key: &str) -> Option<ValueKind> {
+    let (kind, field) = split_key(key).filter(|&(kind, field)| is_known_field(kind, field))?;
+    Some(
+        if kind == EventKind::Decrypt && field == DECRYPT_WORDS_FIELD {
+            ValueKind::List
+        } else {
+            ValueKind::Number
+        },
+    )
+}
+
+/// Check `value` for `events.<kind>.<field>` and store it in `tuning`.
+pub(crate) fn apply_event_value(
+    tuning: &mut EventTuning,
+    kind: EventKind,
+    field: &str,
+    value: &ConfigValue,
+) -> Result<(), String> {
+    if kind == EventKind::Decrypt && field == DECRYPT_WORDS_FIELD {
+        let items = value
+            .items()
+            .ok_or_else(|| format!("expected an array of words, got {value}"))?;
+        tuning.decrypt_words = parse_words(items)?;
+        return Ok(());
+    }
+    let Some(&(_, min, max)) = EVENT_FIELDS.iter().find(|(f, _, _)| *f == field) else {
+        return Err(format!("unknown [events.{}] field '{field}'", kind.name()));
+    };
+    let n = value
+        .as_str()
+        .ok_or_else(|| format!("expected number in [{min}, {max}], got {value}"))
+        .and_then(|raw| parse_field_number(raw, min, max))?;
+    let knobs = &mut tuning.knobs[kind.index()];
+    match field {
+        "chance" => knobs.chance = n,
+        _ => knobs.cooldown = Duration::from_secs_f64(n),
+    }
+    Ok(())
+}
+
+/// The items of `["A", "B"]` (each element one word, commas and all) or
+/// `"A, B"`. Each word is 1..=`DECRYPT_MAX_WORD_LEN` single-cell
+/// characters; empty items are skipped and an empty list turns decrypt
+/// off.
+fn parse_words(items: &[String]) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    for word in items {
+        if word.is_empty() {
+            continue;
+        }
+        if word.chars().count() > DECRYPT_MAX_WORD_LEN {
+            return Err(format!(
+                "word '{word}' is longer than {DECRYPT_MAX_WORD_LEN} characters"
+            ));
+        }
+        if let Some(c) = word
+            .chars()
+            .find(|c| c.is_control() || UnicodeWidthChar::width(*c) != Some(1))
+        {
+            return Err(format!(
+                "word '{word}' contains {c:?}, which is not a single-cell character"
+            ));
+        }
+        words.push(word.clone());
+    }
+    Ok(words)
+}
+
+/// Validate one `events.<kind>.<field>` value without building a tuning
+/// (used by `--testconf` / startup validation).
+#[must_use]
+pub(crate) fn validate_event_value(key: &str, value: &ConfigValue) -> Option<String> {
+    let Some((kind, field)) = split_key(key) else {
+        return Some(format!("unknown [events] key '{key}'"));
+    };
+    let mut scratch = EventTuning::default();
+    apply_event_value(&mut scratch, kind, field, value).err()
+}
+
+/// Build the tuning from a parsed config map. Absent keys keep their
+/// default; invalid values are skipped and returned as `(key, message)`.
+pub(crate) fn event_tuning_from_config(cfg: &ConfigMap) -> (EventTuning, Vec<(String, String)>) {
+    let mut tuning = EventTuning::default();
+    let mut errors = Vec::new();
+    for kind in EventKind::ALL {
+        let fields = EVENT_FIELDS.iter().map(|(f, _, _)| *f);
+        let words = (kind == EventKind::Decrypt).then_some(DECRYPT_WORDS_FIELD);
+        for field in fields.chain(words) {
+            let key = format!("{EVENTS_PREFIX}{}.{field}", kind.name());
+            if let Some(value) = cfg.get(&key) {
+                if let Err(msg) = apply_event_value(&mut tuning, kind, field, value) {
+                    errors.push((key, msg));
+                }
+            }
+        }
+    }
+    (tuning, errors)
+}
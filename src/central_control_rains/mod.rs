@@ -22,6 +22,11 @@
 //! 2. Change the value(s).
 //! 3. `cargo build --release` — that's it.
 //!
+//! Packaged builds can override the per-frame subset (parallax layers,
+//! phosphor decay, head bloom, fog, vignettes) at runtime through the
+//! `[rain-tuning]` config section — see `tuning.rs`. The constants here
+//! remain the defaults.
+//!
 //! All consumers reference `crate::constants::*` which re-exports
 //! everything from this module via `pub use central_control_rains::*;`.
 //! No call-site changes needed when tuning.
@@ -157,6 +162,20 @@
 
 use std::time::Duration;
 
+mod event_tuning;
+#[cfg(test)]
+mod tests;
+mod tuning;
+
+pub(crate) use event_tuning::{
+    event_tuning_from_config, event_value_kind, is_event_key, validate_event_value, EventKind,
+    EventTuning, DECRYPT_WORDS_FIELD, EVENTS_PREFIX, EVENT_FIELDS,
+};
+pub(crate) use tuning::{
+    is_rain_tuning_key, rain_tuning_field, rain_tuning_from_config, validate_rain_tuning_value,
+    RainTuning, CRT_VIGNETTE_HEIGHT_MAX, RAIN_TUNING_FIELDS, RAIN_TUNING_PREFIX,
+};
+
 // ─── Parallax depth layers ─────────────────────────────────────────────────
 //
 // The rain is rendered in 3 parallax layers (back/mid/front = far/mid/near).
@@ -860,6 +879,47 @@ pub(crate) const GHOST_SPAWN_CHANCE_PER_TICK: f64 = 0.003;
 /// Maximum simultaneously-active phosphor ghosts.
 pub(crate) const GHOST_MAX_ACTIVE: usize = 1;
 
+// ─── Decrypt / scanline / cascade / tear events ───────────────────────────
+//
+// Defaults for the `[events]` section. Chances are per tick like the ghost;
+// the cooldown runs from the previous spawn of the same kind. Every kind
+// but the ghost is opt-in: decrypt has no default word list, and scanline,
+// cascade and tear default to chance 0 (a zero chance never draws from the
+// event RNG, so the ghost stream is the same as before they existed).
+
+/// Chance per tick of a decrypt word resolving out of scrambled glyphs.
+pub(crate) const DECRYPT_SPAWN_CHANCE_PER_TICK: f64 = 0.0008;
+
+/// Minimum gap between two decrypt events.
+pub(crate) const DECRYPT_COOLDOWN: Duration = Duration::from_secs(40);
+
+/// Longest decrypt word, in characters.
+pub(crate) const DECRYPT_MAX_WORD_LEN: usize = 32;
+
+/// Chance per tick of a horizontal scanline sweep (off; ~0.0005 is a
+/// sweep every half minute at 60 FPS).
+pub(crate) const SCANLINE_SPAWN_CHANCE_PER_TICK: f64 = 0.0;
+
+/// Minimum gap between two scanline sweeps.
+pub(crate) const SCANLINE_COOLDOWN: Duration = Duration::from_secs(45);
+
+/// Chance per tick of a column cascade burst (off; ~0.0015 is a burst
+/// every ten seconds or so at 60 FPS).
+pub(crate) const CASCADE_SPAWN_CHANCE_PER_TICK: f64 = 0.0;
+
+/// Minimum gap between two cascade bursts.
+pub(crate) const CASCADE_COOLDOWN: Duration = Duration::from_secs(15);
+
+/// Chance per tick of a glitch tear (off; ~0.0006 is a tear every half
+/// minute at 60 FPS).
+pub(crate) const TEAR_SPAWN_CHANCE_PER_TICK: f64 = 0.0;
+
+/// Minimum gap between two glitch tears.
+pub(crate) const TEAR_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Largest horizontal offset of a torn band, in columns.
+pub(crate) const TEAR_MAX_OFFSET: u16 = 8;
+
 // ─── Cloud internals ───────────────────────────────────────────────────────
 //
 // These tune the droplet pool sizing and RNG behavior — affect rain
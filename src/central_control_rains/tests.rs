@@ -0,0 +1,194 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! `[rain-tuning]` and `[events]` parsing and validation tests.
+
+use crate::config_schema::{value_from_text, ConfigMap, ValueKind};
+
+use super::event_tuning::*;
+use super::tuning::*;
+use super::*;
+
+fn cfg(pairs: &[(&str, &str)]) -> ConfigMap {
+    pairs
+        .iter()
+        .map(|(k, v)| ((*k).to_string(), value_from_text(k, v)))
+        .collect()
+}
+
+#[test]
+fn empty_section_is_the_compiled_calibration() {
+    let (tuning, errors) = rain_tuning_from_config(&ConfigMap::new());
+    assert!(errors.is_empty());
+    assert_eq!(tuning, RainTuning::DEFAULT);
+    assert_eq!(tuning.phosphor_decay, PHOSPHOR_DECAY_RATE);
+    assert_eq!(tuning.parallax_brightness, PARALLAX_BRIGHTNESS_MULT);
+    assert_eq!(tuning.crt_vignette_height, CRT_VIGNETTE_HEIGHT);
+}
+
+#[test]
+fn every_field_accepts_a_midrange_value() {
+    let d = RainTuning::DEFAULT;
+    for spec in RAIN_TUNING_FIELDS {
+        let field = spec.name;
+        let mut t = d;
+        let mid = (spec.min + spec.max) / 2.0;
+        let raw = match spec.kind() {
+            ValueKind::Integer => format!("{}", mid.round() as u16),
+            ValueKind::Numbers => format!("[{mid}, {mid}, {mid}]"),
+            _ => format!("{mid}"),
+        };
+        apply_rain_tuning_value(
+            &mut t,
+            field,
+            &value_from_text(&format!("{RAIN_TUNING_PREFIX}{field}"), &raw),
+        )
+        .unwrap_or_else(|e| panic!("{field} = {raw}: {e}"));
+        assert_ne!(t, d, "{field} must write its own slot");
+        assert!(is_rain_tuning_key(&format!("{RAIN_TUNING_PREFIX}{field}")));
+    }
+}
+
+#[test]
+fn overrides_apply_and_others_keep_constants() {
+    let (tuning, errors) = rain_tuning_from_config(&cfg(&[
+        ("rain-tuning.phosphor-decay", "3.5"),
+        ("rain-tuning.parallax-brightness", "[0.4, 0.9, 1.2]"),
+        ("rain-tuning.crt-vignette-height", "5"),
+    ]));
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(tuning.phosphor_decay, 3.5);
+    assert_eq!(tuning.parallax_brightness, [0.4, 0.9, 1.2]);
+    assert_eq!(tuning.crt_vignette_height, 5);
+    assert_eq!(tuning.head_bloom_sigma, HEAD_BLOOM_SIGMA);
+}
+
+#[test]
+fn invalid_values_are_reported_and_skipped() {
+    let (tuning, errors) = rain_tuning_from_config(&cfg(&[
+        ("rain-tuning.phosphor-decay", "0.1"),
+        ("rain-tuning.parallax-speed", "[1.0, 1.0]"),
+        ("rain-tuning.fog-rows", "2.5"),
+    ]));
+    assert_eq!(errors.len(), 3, "{errors:?}");
+    assert_eq!(tuning, RainTuning::DEFAULT);
+}
+
+#[test]
+fn validate_messages_match_testconf_wording() {
+    let err = validate_rain_tuning_value("rain-tuning.vignette-intensity", &"1.5".into()).unwrap();
+    assert!(err.starts_with("out of range [0, 1]"), "{err}");
+    let err = validate_rain_tuning_value("rain-tuning.head-bloom-sigma", &"wide".into()).unwrap();
+    assert!(err.starts_with("expected number"), "{err}");
+    let err = validate_rain_tuning_value(
+        "rain-tuning.parallax-density",
+        &value_from_text("rain-tuning.parallax-density", "0.5"),
+    )
+    .unwrap();
+    assert!(err.contains("[back, mid, front]"), "{err}");
+    assert!(validate_rain_tuning_value(
+        "rain-tuning.parallax-density",
+        &value_from_text("rain-tuning.parallax-density", "[0.5,0.5,0.5]")
+    )
+    .is_none());
+    assert!(validate_rain_tuning_value("rain-tuning.phosphor-decay", &"NaN".into()).is_some());
+}
+
+#[test]
+fn unknown_fields_are_not_rain_tuning_keys() {
+    assert!(!is_rain_tuning_key("rain-tuning.gravity"));
+    assert!(!is_rain_tuning_key("phosphor-decay"));
+    assert!(is_rain_tuning_key("rain-tuning.fog-min-factor"));
+}
+
+// ── [events] ──
+
+#[test]
+fn empty_events_section_uses_compiled_knobs() {
+    let (tuning, errors) = event_tuning_from_config(&ConfigMap::new());
+    assert!(errors.is_empty());
+    assert_eq!(tuning, EventTuning::default());
+    let ghost = tuning.knobs(EventKind::Ghost);
+    assert_eq!(ghost.chance, GHOST_SPAWN_CHANCE_PER_TICK);
+    assert_eq!(ghost.cooldown, Duration::ZERO);
+    assert_eq!(tuning.knobs(EventKind::Tear).cooldown, TEAR_COOLDOWN);
+    assert!(tuning.decrypt_words.is_empty(), "decrypt is off by default");
+}
+
+#[test]
+fn event_overrides_apply_per_kind() {
+    let (tuning, errors) = event_tuning_from_config(&cfg(&[
+        ("events.cascade.chance", "0.5"),
+        ("events.cascade.cooldown-secs", "2.5"),
+        ("events.tear.chance", "0"),
+        ("events.decrypt.words", "[\"WAKE UP\", \"NEO\"]"),
+    ]));
+    assert!(errors.is_empty(), "{errors:?}");
+    let cascade = tuning.knobs(EventKind::Cascade);
+    assert_eq!(cascade.chance, 0.5);
+    assert_eq!(cascade.cooldown, Duration::from_millis(2500));
+    assert_eq!(tuning.knobs(EventKind::Tear).chance, 0.0);
+    assert_eq!(tuning.decrypt_words, ["WAKE UP", "NEO"]);
+    assert_eq!(
+        tuning.knobs(EventKind::Scanline),
+        EventTuning::default().knobs(EventKind::Scanline)
+    );
+}
+
+#[test]
+fn decrypt_words_keep_commas_inside_array_elements() {
+    let parsed = crate::configfile::parse_config_text(
+        "[events.decrypt]\nwords = [\"HELLO, WORLD\", \"SAY \\\"HI\\\"\"]\n",
+    );
+    let (tuning, errors) = event_tuning_from_config(&parsed.values);
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(tuning.decrypt_words, ["HELLO, WORLD", "SAY \"HI\""]);
+}
+
+#[test]
+fn invalid_event_values_are_reported_and_skipped() {
+    let (tuning, errors) = event_tuning_from_config(&cfg(&[
+        ("events.ghost.chance", "1.5"),
+        ("events.scanline.cooldown-secs", "-1"),
+        ("events.decrypt.words", "[\"雨\"]"),
+        ("events.decrypt.words-typo", "x"),
+    ]));
+    assert_eq!(errors.len(), 3, "{errors:?}");
+    assert_eq!(tuning, EventTuning::default());
+}
+
+#[test]
+fn decrypt_words_must_fit_single_cells() {
+    let long = "X".repeat(DECRYPT_MAX_WORD_LEN + 1);
+    let err = validate_event_value("events.decrypt.words", &long.into()).unwrap();
+    assert!(err.contains("longer than"), "{err}");
+    let err = validate_event_value(
+        "events.decrypt.words",
+        &value_from_text("events.decrypt.words", "[\"OK\", \"雨\"]"),
+    )
+    .unwrap();
+    assert!(err.contains("single-cell"), "{err}");
+    assert!(validate_event_value(
+        "events.decrypt.words",
+        &value_from_text("events.decrypt.words", "NEO, TRINITY")
+    )
+    .is_none());
+    assert!(validate_event_value(
+        "events.decrypt.words",
+        &value_from_text("events.decrypt.words", "[]")
+    )
+    .is_none());
+}
+
+#[test]
+fn event_keys_cover_kinds_and_fields() {
+    for kind in EventKind::ALL {
+        for (field, _, _) in EVENT_FIELDS {
+            assert!(is_event_key(&format!("events.{}.{field}", kind.name())));
+        }
+    }
+    assert!(is_event_key("events.decrypt.words"));
+    assert!(!is_event_key("events.tear.words"), "only decrypt has words");
+    assert!(!is_event_key("events.meteor.chance"));
+    assert!(!is_event_key("events.chance"));
+}
@@ -0,0 +1,266 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Runtime rain tuning — the `[rain-tuning]` config section.
+//!
+//! The constants in `central_control_rains/mod.rs` stay the calibrated
+//! defaults; [`RainTuning::DEFAULT`] is built from them, so an empty
+//! section renders bit-identically to the compiled-in look. Users on
+//! packaged binaries override a subset without rebuilding:
+//!
+//! ```toml
+//! [rain-tuning]
+//! phosphor-decay = 3.5                      # longer afterglow
+//! parallax-brightness = [0.45, 0.80, 1.15]  # back, mid, front
+//! vignette-intensity = 0.0                  # no corner darkening
+//! ```
+//!
+//! Keys are stored as `rain-tuning.<field>`. Every field has a hard range
+//! ([`RAIN_TUNING_FIELDS`]); out-of-range values fail `--testconf` and
+//! startup validation like any other key. Live reload re-parses the whole
+//! section (a removed key falls back to its constant), same as
+//! `[color.tune]`.
+//!
+//! Only the knobs that are read per frame are exposed. Layer assignment,
+//! spawn pacing and the monolith geometry stay compile-time.
+
+use super::{
+    CRT_VIGNETTE_EDGE_FACTOR, CRT_VIGNETTE_HEIGHT, FOG_MIN_FACTOR, FOG_ROWS, HEAD_BLOOM_INTENSITY,
+    HEAD_BLOOM_SIGMA, PARALLAX_BRIGHTNESS_MULT, PARALLAX_DENSITY_MULT, PARALLAX_HEAD_BLOOM_MULT,
+    PARALLAX_LAYERS, PARALLAX_SATURATION_MULT, PARALLAX_SPEED_MULT, PHOSPHOR_DECAY_RATE,
+    PHOSPHOR_LAYER_DECAY_MULT, VIGNETTE_INNER_RADIUS, VIGNETTE_INTENSITY,
+};
+use crate::config_schema::{ConfigMap, ConfigValue, ValueKind};
+use crate::validation::{field_in_range, parse_field_number};
+
+/// Config key prefix for the `[rain-tuning]` section.
+pub(crate) const RAIN_TUNING_PREFIX: &str = "rain-tuning.";
+
+/// Upper bound of `crt-vignette-height`; sizes the per-frame row-factor
+/// table in `apply_crt_vignette`.
+pub(crate) const CRT_VIGNETTE_HEIGHT_MAX: u16 = 8;
+
+/// Per-frame rain look, resolved from `[rain-tuning]` over the constants.
+///
+/// Per-layer arrays are `[back, mid, front]`, like the `PARALLAX_*`
+/// constants they replace.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct RainTuning {
+    pub parallax_speed: [f32; PARALLAX_LAYERS],
+    pub parallax_brightness: [f32; PARALLAX_LAYERS],
+    pub parallax_saturation: [f32; PARALLAX_LAYERS],
+    pub parallax_density: [f32; PARALLAX_LAYERS],
+    pub parallax_head_bloom: [f32; PARALLAX_LAYERS],
+    pub phosphor_decay: f32,
+    pub phosphor_layer_decay: [f32; PARALLAX_LAYERS],
+    pub head_bloom_sigma: f32,
+    pub head_bloom_intensity: f32,
+    pub fog_rows: u16,
+    pub fog_min_factor: f32,
+    pub crt_vignette_height: u16,
+    pub crt_vignette_edge: f32,
+    pub vignette_intensity: f32,
+    pub vignette_inner_radius: f32,
+}
+
+impl RainTuning {
+    /// The compiled-in calibration.
+    pub(crate) const DEFAULT: RainTuning = RainTuning {
+        parallax_speed: PARALLAX_SPEED_MULT,
+        parallax_brightness: PARALLAX_BRIGHTNESS_MULT,
+        parallax_saturation: PARALLAX_SATURATION_MULT,
+        parallax_density: PARALLAX_DENSITY_MULT,
+        parallax_head_bloom: PARALLAX_HEAD_BLOOM_MULT,
+        phosphor_decay: PHOSPHOR_DECAY_RATE,
+        phosphor_layer_decay: PHOSPHOR_LAYER_DECAY_MULT,
+        head_bloom_sigma: HEAD_BLOOM_SIGMA,
+        head_bloom_intensity: HEAD_BLOOM_INTENSITY,
+        fog_rows: FOG_ROWS,
+        fog_min_factor: FOG_MIN_FACTOR,
+        crt_vignette_height: CRT_VIGNETTE_HEIGHT,
+        crt_vignette_edge: CRT_VIGNETTE_EDGE_FACTOR,
+        vignette_intensity: VIGNETTE_INTENSITY,
+        vignette_inner_radius: VIGNETTE_INNER_RADIUS,
+    };
+}
+
+impl Default for RainTuning {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+enum Slot<'a> {
+    Number(&'a mut f32),
+    Layers(&'a mut [f32; PARALLAX_LAYERS]),
+    Rows(&'a mut u16),
+}
+
+/// One `[rain-tuning]` key: its name, hard range and the value it sets.
+pub(crate) struct RainTuningField {
+    pub(crate) name: &'static str,
+    pub(crate) min: f64,
+    pub(crate) max: f64,
+    slot: fn(&mut RainTuning) -> Slot<'_>,
+}
+
+const fn field(
+    name: &'static str,
+    min: f64,
+    max: f64,
+    slot: fn(&mut RainTuning) -> Slot<'_>,
+) -> RainTuningField {
+    RainTuningField {
+        name,
+        min,
+        max,
+        slot,
+    }
+}
+
+impl RainTuningField {
+    /// TOML type of the key, read off the slot it writes.
+    pub(crate) fn kind(&self) -> ValueKind {
+        let mut scratch = RainTuning::DEFAULT;
+        match (self.slot)(&mut scratch) {
+            Slot::Number(_) => ValueKind::Number,
+            Slot::Layers(_) => ValueKind::Numbers,
+            Slot::Rows(_) => ValueKind::Integer,
+        }
+    }
+}
+
+/// Every `[rain-tuning]` key, in documentation order. Per-layer fields
+/// apply the range to each element.
+pub(crate) const RAIN_TUNING_FIELDS: &[RainTuningField] = &[
+    field("parallax-speed", 0.05, 4.0, |t| {
+        Slot::Layers(&mut t.parallax_speed)
+    }),
+    field("parallax-brightness", 0.0, 2.0, |t| {
+        Slot::Layers(&mut t.parallax_brightness)
+    }),
+    field("parallax-saturation", 0.0, 2.0, |t| {
+        Slot::Layers(&mut t.parallax_saturation)
+    }),
+    field("parallax-density", 0.0, 2.0, |t| {
+        Slot::Layers(&mut t.parallax_density)
+    }),
+    field("parallax-head-bloom", 0.0, 3.0, |t| {
+        Slot::Layers(&mut t.parallax_head_bloom)
+    }),
+    field("phosphor-decay", 0.5, 50.0, |t| {
+        Slot::Number(&mut t.phosphor_decay)
+    }),
+    field("phosphor-layer-decay", 0.0, 5.0, |t| {
+        Slot::Layers(&mut t.phosphor_layer_decay)
+    }),
+    field("head-bloom-sigma", 0.1, 5.0, |t| {
+        Slot::Number(&mut t.head_bloom_sigma)
+    }),
+    field("head-bloom-intensity", 0.0, 1.0, |t| {
+        Slot::Number(&mut t.head_bloom_intensity)
+    }),
+    field("fog-rows", 0.0, 12.0, |t| Slot::Rows(&mut t.fog_rows)),
+    field("fog-min-factor", 0.0, 1.0, |t| {
+        Slot::Number(&mut t.fog_min_factor)
+    }),
+    field(
+        "crt-vignette-height",
+        0.0,
+        CRT_VIGNETTE_HEIGHT_MAX as f64,
+        |t| Slot::Rows(&mut t.crt_vignette_height),
+    ),
+    field("crt-vignette-edge", 0.0, 1.0, |t| {
+        Slot::Number(&mut t.crt_vignette_edge)
+    }),
+    field("vignette-intensity", 0.0, 1.0, |t| {
+        Slot::Number(&mut t.vignette_intensity)
+    }),
+    field("vignette-inner-radius", 0.0, 0.95, |t| {
+        Slot::Number(&mut t.vignette_inner_radius)
+    }),
+];
+
+/// The field behind `rain-tuning.<field>`, if `key` names one.
+#[must_use]
+pub(crate) fn rain_tuning_field(key: &str) -> Option<&'static RainTuningField> {
+    let field = key.strip_prefix(RAIN_TUNING_PREFIX)?;
+    RAIN_TUNING_FIELDS.iter().find(|f| f.name == field)
+}
+
+/// `true` for `rain-tuning.<field>` with a known field.
+#[must_use]
+pub(crate) fn is_rain_tuning_key(key: &str) -> bool {
+    rain_tuning_field(key).is_some()
+}
+
+/// Check `value` for `field` and store it in `tuning`. Errors use the
+/// `--testconf` wording ("out of range [a, b], got n").
+pub(crate) fn apply_rain_tuning_value(
+    tuning: &mut RainTuning,
+    field: &str,
+    value: &ConfigValue,
+) -> Result<(), String> {
+    let Some(spec) = RAIN_TUNING_FIELDS.iter().find(|f| f.name == field) else {
+        return Err(format!("unknown [rain-tuning] field '{field}'"));
+    };
+    let (min, max) = (spec.min, spec.max);
+    match (spec.slot)(tuning) {
+        Slot::Number(out) => {
+            let raw = value
+                .as_str()
+                .ok_or_else(|| format!("expected number in [{min}, {max}], got {value}"))?;
+            *out = parse_field_number(raw, min, max)? as f32;
+        }
+        Slot::Rows(out) => {
+            let raw = value.as_str().map_or("", str::trim);
+            let n: u16 = raw
+                .parse()
+                .map_err(|_| format!("expected integer in [{min}, {max}], got {value}"))?;
+            if !(min..=max).contains(&f64::from(n)) {
+                return Err(format!("out of range [{min}, {max}], got {n}"));
+            }
+            *out = n;
+        }
+        Slot::Layers(out) => {
+            let expected =
+                || format!("expected [back, mid, front] with each in [{min}, {max}], got {value}");
+            let items = value
+                .numbers()
+                .filter(|items| items.len() == PARALLAX_LAYERS)
+                .ok_or_else(expected)?;
+            let mut layers = [0.0; PARALLAX_LAYERS];
+            for (slot, &n) in layers.iter_mut().zip(items) {
+                *slot = field_in_range(n, min, max)? as f32;
+            }
+            *out = layers;
+        }
+    }
+    Ok(())
+}
+
+/// Validate one `rain-tuning.<field>` value without building a tuning
+/// (used by `--testconf` / startup validation).
+#[must_use]
+pub(crate) fn validate_rain_tuning_value(key: &str, value: &ConfigValue) -> Option<String> {
+    let field = key.strip_prefix(RAIN_TUNING_PREFIX).unwrap_or(key);
+    let mut scratch = RainTuning::DEFAULT;
+    apply_rain_tuning_value(&mut scratch, field, value).err()
+}
+
+/// Build the tuning from a parsed config map. Fields that are absent keep
+/// their constant; invalid values are skipped and returned as
+/// `(key, message)` so the caller can decide how loud to be.
+pub(crate) fn rain_tuning_from_config(cfg: &ConfigMap) -> (RainTuning, Vec<(String, String)>) {
+    let mut tuning = RainTuning::DEFAULT;
+    let mut errors = Vec::new();
+    for field in RAIN_TUNING_FIELDS {
+        let key = format!("{RAIN_TUNING_PREFIX}{}", field.name);
+        if let Some(value) = cfg.get(&key) {
+            if let Err(msg) = apply_rain_tuning_value(&mut tuning, field.name, value) {
+                errors.push((key, msg));
+            }
+        }
+    }
+    (tuning, errors)
+}
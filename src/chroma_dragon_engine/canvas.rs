@@ -0,0 +1,170 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! # Light canvas: rain on light terminal backgrounds.
+//!
+//! Every palette, the Phase 7 floor, ghost glyphs and phosphor decay
+//! assume a dark canvas: brightness means emphasis, and fading means
+//! sinking toward black. With `color-bg = default-background` on a light
+//! terminal (Solarized Light, GitHub Light) that inverts — white-hot heads
+//! vanish into the page and only the dim trails show.
+//!
+//! When the terminal reports a light background (OSC 11, see
+//! `termdetect::query_background`), `Terminal::draw` hands each frame to a
+//! [`LightCanvas`] before any quantizer. It maps every color's OKLab
+//! lightness from "distance above black" to "distance below the
+//! background":
+//!
+//! ```text
+//! L' = L_bg − L^γ · (L_bg − L_ink)
+//! ```
+//!
+//! so black lands on the background, the brightest head on
+//! `LIGHT_CANVAS_INK_L`, and the order in between reverses — heads darker
+//! than bodies darker than tails. `γ = LIGHT_CANVAS_GAMMA < 1` is the
+//! inverted brightness floor: it pushes dim colors (trail stops, ghosts,
+//! phosphor afterglow) away from the background. Hue is kept; chroma is
+//! reduced only as far as the new lightness needs to stay in sRGB.
+//! Named colors (mono mode) swap their light and dark grays.
+//!
+//! Like the quantizer, the canvas maps a mirror of the frame and never
+//! writes into the simulation's frame. It only engages while the frame
+//! has no background of its own (`frame.blank.bg == None`); `color-bg =
+//! black` or a custom palette `bg` draws as before, so live reload can
+//! switch between them freely.
+
+use crossterm::style::Color;
+
+use crate::cell::Cell;
+use crate::chroma_dragon_engine::gradient::{oklab_to_linear, oklab_to_srgb, srgb_to_oklab};
+use crate::chroma_dragon_engine::quantize::sync_mirror;
+use crate::chroma_dragon_engine::tuning::{
+    LIGHT_CANVAS_GAMMA, LIGHT_CANVAS_INK_L, LIGHT_CANVAS_MIN_BG_L,
+};
+use crate::frame::Frame;
+use crate::palette::{color_to_rgb, Palette};
+
+/// Direct-mapped cache slots (a frame uses a few hundred distinct colors).
+const CACHE_BITS: u32 = 12;
+const CACHE_VALID: u32 = 1 << 31;
+
+/// Maps dark-canvas colors onto a light terminal background.
+pub(crate) struct LightCanvas {
+    /// OKLab lightness of the background.
+    bg_l: f32,
+    /// `(packed rgb | CACHE_VALID, mapped rgb)`.
+    cache: Vec<(u32, (u8, u8, u8))>,
+    /// Mapped copy of the frame, drawn in place of the source.
+    out: Option<Frame>,
+}
+
+impl LightCanvas {
+    /// Canvas for a terminal background of `rgb`, or `None` when that
+    /// background is dark and the palettes fit it as they are.
+    #[must_use]
+    pub(crate) fn for_background(rgb: (u8, u8, u8)) -> Option<Self> {
+        is_light_background(rgb).then(|| Self {
+            bg_l: srgb_to_oklab(rgb.0, rgb.1, rgb.2).0,
+            cache: vec![(0, (0, 0, 0)); 1 << CACHE_BITS],
+            out: None,
+        })
+    }
+
+    /// `color` as it should be drawn on the light background. `Reset`
+    /// and the saturated named colors pass through.
+    #[must_use]
+    pub(crate) fn map_color(&mut self, color: Color) -> Color {
+        let (r, g, b) = match color {
+            Color::Rgb { r, g, b } => (r, g, b),
+            Color::AnsiValue(_) => color_to_rgb(color),
+            Color::White => return Color::Black,
+            Color::Black => return Color::White,
+            Color::Grey => return Color::DarkGrey,
+            Color::DarkGrey => return Color::Grey,
+            _ => return color,
+        };
+        let (r, g, b) = self.map_rgb(r, g, b);
+        Color::Rgb { r, g, b }
+    }
+
+    /// `palette` with every color mapped, for the terminal's color cache.
+    /// A palette with its own background is not drawn on the canvas and
+    /// comes back unchanged.
+    #[must_use]
+    pub(crate) fn map_palette(&mut self, palette: &Palette) -> Palette {
+        if palette.bg.is_some() {
+            return palette.clone();
+        }
+        Palette {
+            colors: palette.colors.iter().map(|&c| self.map_color(c)).collect(),
+            bg: None,
+        }
+    }
+
+    /// The frame to draw: `src` itself when it paints its own
+    /// background, else the mapped mirror, brought up to date.
+    pub(crate) fn sync<'a>(&'a mut self, src: &'a mut Frame) -> &'a mut Frame {
+        if src.blank.bg.is_some() {
+            // Drop the mirror so re-engaging starts with a full copy.
+            self.out = None;
+            return src;
+        }
+        let out = sync_mirror(self.out.take(), src, |cell, _, _| self.map_cell(cell));
+        self.out.insert(out)
+    }
+
+    fn map_cell(&mut self, cell: Cell) -> Cell {
+        Cell {
+            fg: cell.fg.map(|c| self.map_color(c)),
+            bg: cell.bg.map(|c| self.map_color(c)),
+            ..cell
+        }
+    }
+
+    fn map_rgb(&mut self, r: u8, g: u8, b: u8) -> (u8, u8, u8) {
+        let packed = (u32::from(r) << 16) | (u32::from(g) << 8) | u32::from(b);
+        let slot = (packed.wrapping_mul(0x9e37_79b1) >> (32 - CACHE_BITS)) as usize;
+        let (key, mapped) = self.cache[slot];
+        if key == packed | CACHE_VALID {
+            return mapped;
+        }
+        let mapped = self.map_uncached(r, g, b);
+        self.cache[slot] = (packed | CACHE_VALID, mapped);
+        mapped
+    }
+
+    fn map_uncached(&self, r: u8, g: u8, b: u8) -> (u8, u8, u8) {
+        let (l, a, bb) = srgb_to_oklab(r, g, b);
+        let lift = l.clamp(0.0, 1.0).powf(LIGHT_CANVAS_GAMMA);
+        let l = self.bg_l - lift * (self.bg_l - LIGHT_CANVAS_INK_L);
+        // Dark saturated colors are out of gamut at the lightness of their
+        // bright originals; bisect the chroma scale instead of clipping
+        // channels, which would shift the hue.
+        if in_gamut(l, a, bb) {
+            return oklab_to_srgb(l, a, bb);
+        }
+        let (mut lo, mut hi) = (0.0f32, 1.0f32);
+        for _ in 0..8 {
+            let mid = (lo + hi) * 0.5;
+            if in_gamut(l, a * mid, bb * mid) {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        oklab_to_srgb(l, a * lo, bb * lo)
+    }
+}
+
+/// `true` when rain on a `rgb` background needs the light canvas.
+#[must_use]
+pub(crate) fn is_light_background(rgb: (u8, u8, u8)) -> bool {
+    srgb_to_oklab(rgb.0, rgb.1, rgb.2).0 >= LIGHT_CANVAS_MIN_BG_L
+}
+
+#[inline]
+fn in_gamut(l: f32, a: f32, b: f32) -> bool {
+    const EPS: f32 = 1e-4;
+    let (r, g, b) = oklab_to_linear(l, a, b);
+    [r, g, b].iter().all(|c| (-EPS..=1.0 + EPS).contains(c))
+}
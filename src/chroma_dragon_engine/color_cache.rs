@@ -30,6 +30,7 @@ use std::sync::atomic::{AtomicU64, Ordering};
 use crossterm::style::Color;
 
 use crate::palette::Palette;
+use crate::sgr_format::named_fg_code;
 
 /// Pre-formatted ANSI SGR byte sequences for palette colors.
 ///
@@ -103,6 +104,15 @@ impl ColorCache {
         }
     }
 
+    /// The palette this cache was built from.
+    #[must_use]
+    pub(crate) fn palette(&self) -> Palette {
+        Palette {
+            colors: self.colors.clone(),
+            bg: self.bg,
+        }
+    }
+
     /// Look up the pre-formatted SGR bytes for a palette color index.
     /// `idx` must be in `0..num_colors`; panics otherwise (debug only).
     /// For `None` / blank / reset cells, use `reset_sgr()`.
@@ -253,19 +263,15 @@ fn push_sgr_fg_bg(buf: &mut Vec<u8>, fg: Color, bg: Option<Color>) {
             push_u8(buf, v);
             semi = true;
         }
-        Color::Reset | Color::Black => {
+        Color::Reset => {
             buf.extend_from_slice(b"39");
             semi = true;
         }
         _ => {
-            // Named colors: decode to RGB and format
-            let (r, g, b) = crate::palette::color_to_rgb(fg);
-            buf.extend_from_slice(b"38;2;");
-            push_u8(buf, r);
-            buf.push(b';');
-            push_u8(buf, g);
-            buf.push(b';');
-            push_u8(buf, b);
+            // Named colors (16-color quantizer output): 30–37 / 90–97.
+            if let Some(code) = named_fg_code(fg) {
+                push_u8(buf, code);
+            }
             semi = true;
         }
     }
@@ -294,17 +300,13 @@ fn push_sgr_fg_bg(buf: &mut Vec<u8>, fg: Color, bg: Option<Color>) {
             }
             buf.extend_from_slice(b"49");
         }
-        _ => {
-            let (r, g, b) = crate::palette::color_to_rgb(bg.unwrap_or(Color::Reset));
-            if semi {
-                buf.push(b';');
+        Some(c) => {
+            if let Some(code) = named_fg_code(c) {
+                if semi {
+                    buf.push(b';');
+                }
+                push_u8(buf, code + 10);
             }
-            buf.extend_from_slice(b"48;2;");
-            push_u8(buf, r);
-            buf.push(b';');
-            push_u8(buf, g);
-            buf.push(b';');
-            push_u8(buf, b);
         }
     }
     buf.extend_from_slice(b"m");
@@ -326,9 +328,13 @@ fn push_sgr_reset_bg(buf: &mut Vec<u8>, bg: Option<Color>) {
             buf.extend_from_slice(b";48;5;");
             push_u8(buf, v);
         }
-        _ => {
-            buf.extend_from_slice(b";49");
-        }
+        _ => match bg.and_then(named_fg_code) {
+            Some(code) => {
+                buf.push(b';');
+                push_u8(buf, code + 10);
+            }
+            None => buf.extend_from_slice(b";49"),
+        },
     }
     buf.extend_from_slice(b"m");
 }
@@ -114,9 +114,10 @@ pub(crate) fn parse_color_tune(s: &str) -> Result<ColorTune, String> {
 }
 
 /// v17: Build ColorTune from [color.tune] config section.
-pub(crate) fn color_tune_from_config(cfg: &std::collections::HashMap<String, String>) -> ColorTune {
+pub(crate) fn color_tune_from_config(cfg: &crate::config_schema::ConfigMap) -> ColorTune {
     let get = |key: &str| -> f32 {
         cfg.get(key)
+            .and_then(crate::config_schema::ConfigValue::as_str)
             .and_then(|v| v.parse::<f32>().ok())
             .filter(|&v| (0.0..=3.0).contains(&v))
             .unwrap_or(1.0)
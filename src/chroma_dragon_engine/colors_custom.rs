@@ -16,11 +16,12 @@
 //! ambient.22-00 = sunset
 //! ```
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::BTreeMap;
 
 use crossterm::style::Color;
 
 use crate::chroma_dragon_engine::palette::colors_from_stops;
+use crate::config_schema::ConfigMap;
 use crate::palette::Palette;
 use crate::runtime::ColorMode;
 
@@ -113,9 +114,7 @@ pub(crate) fn parse_hex_color(s: &str) -> Result<Color, String> {
 
 /// Collect all custom color palette definitions from the config HashMap.
 #[must_use]
-pub(crate) fn collect_colors_custom(
-    cfg: &HashMap<String, String>,
-) -> BTreeMap<String, CustomPaletteDef> {
+pub(crate) fn collect_colors_custom(cfg: &ConfigMap) -> BTreeMap<String, CustomPaletteDef> {
     let mut palettes: BTreeMap<String, CustomPaletteDef> = BTreeMap::new();
 
     for (key, value) in cfg {
@@ -130,7 +129,7 @@ pub(crate) fn collect_colors_custom(
 
         match field {
             "bg" => {
-                if let Ok(color) = parse_hex_color(value) {
+                if let Some(Ok(color)) = value.as_str().map(parse_hex_color) {
                     palette.bg = Some(color);
                 }
             }
@@ -154,15 +153,11 @@ pub(crate) fn collect_colors_custom(
                     );
                 }
                 // v25 masterclass: support both CSV string and TOML array format.
-                // CSV: "#1a0033", "#4d0080", "#9933ff"
+                // CSV: "#1a0033, #4d0080, #9933ff"
                 // Array: ["#1a0033", "#4d0080", "#9933ff", ...] (7-stop)
-                let stops = if value.trim_start().starts_with('[') {
-                    parse_rain_array(value)
-                } else {
-                    value.split(',').map(|s| s.trim()).collect()
-                };
-                for stop in &stops {
-                    if let Ok(color) = parse_hex_color(stop) {
+                // config_schema splits the CSV form, so both arrive as items.
+                for stop in value.items().unwrap_or_default() {
+                    if let Ok(color) = parse_hex_color(stop.trim()) {
                         palette.rain.push(color);
                     }
                 }
@@ -174,24 +169,8 @@ pub(crate) fn collect_colors_custom(
     palettes
 }
 
-/// Parse a TOML array-style rain value: `["#1a0033", "#4d0080", ...]`.
-/// Strips brackets, splits by comma, trims whitespace + quotes from each element.
-/// Returns the list of stop strings (caller parses hex).
-fn parse_rain_array(value: &str) -> Vec<&str> {
-    let s = value.trim();
-    let s = s.strip_prefix('[').unwrap_or(s);
-    let s = s.strip_suffix(']').unwrap_or(s);
-    s.split(',')
-        .map(|e| e.trim().trim_matches('"').trim())
-        .filter(|e| !e.is_empty())
-        .collect()
-}
-
 /// Look up a custom palette by name and convert it to a cosmostrix Palette.
-pub(crate) fn load_custom_palette(
-    cfg: &HashMap<String, String>,
-    name: &str,
-) -> Result<Palette, String> {
+pub(crate) fn load_custom_palette(cfg: &ConfigMap, name: &str) -> Result<Palette, String> {
     let palettes = collect_colors_custom(cfg);
     let normalized = name.trim().to_ascii_lowercase();
     let def = palettes.get(&normalized).ok_or_else(|| {
@@ -214,7 +193,7 @@ pub(crate) fn load_custom_palette(
 /// layers to resolve custom color names (matching top-level config_apply
 /// behavior which resolves via `parse_color_scheme || colors-custom lookup`).
 #[must_use]
-pub(crate) fn is_colors_custom_name(cfg: &HashMap<String, String>, name: &str) -> bool {
+pub(crate) fn is_colors_custom_name(cfg: &ConfigMap, name: &str) -> bool {
     let palettes = collect_colors_custom(cfg);
     palettes.contains_key(&name.trim().to_ascii_lowercase())
 }
@@ -263,15 +242,15 @@ mod tests {
 
     #[test]
     fn collect_colors_custom_rain_mode() {
-        let mut cfg = HashMap::new();
+        let mut cfg = ConfigMap::new();
         cfg.insert(
             "colors-custom.mytheme.rain".to_string(),
-            "#1a0033, #4d0080, #9933ff, #cc66ff, #ffffff".to_string(),
-        );
-        cfg.insert(
-            "colors-custom.mytheme.bg".to_string(),
-            "#0a0a12".to_string(),
+            crate::config_schema::value_from_text(
+                "colors-custom.mytheme.rain",
+                "#1a0033, #4d0080, #9933ff, #cc66ff, #ffffff",
+            ),
         );
+        cfg.insert("colors-custom.mytheme.bg".to_string(), "#0a0a12".into());
 
         let palettes = collect_colors_custom(&cfg);
         assert!(palettes.contains_key("mytheme"));
@@ -334,16 +313,16 @@ mod tests {
 
     #[test]
     fn load_custom_palette_not_found() {
-        let cfg = HashMap::new();
+        let cfg = ConfigMap::new();
         assert!(load_custom_palette(&cfg, "nonexistent").is_err());
     }
 
     #[test]
     fn load_custom_palette_found() {
-        let mut cfg = HashMap::new();
+        let mut cfg = ConfigMap::new();
         cfg.insert(
             "colors-custom.mytheme.rain".to_string(),
-            "#000000, #ffffff".to_string(),
+            crate::config_schema::value_from_text("colors-custom.mytheme.rain", "#000000, #ffffff"),
         );
         let palette = load_custom_palette(&cfg, "mytheme").unwrap();
         // masterclass: 2 CSV stops expand to 9 OKLab-polar samples.
@@ -352,10 +331,10 @@ mod tests {
 
     #[test]
     fn load_custom_palette_case_insensitive() {
-        let mut cfg = HashMap::new();
+        let mut cfg = ConfigMap::new();
         cfg.insert(
             "colors-custom.MyTheme.rain".to_string(),
-            "#000000, #ffffff".to_string(),
+            crate::config_schema::value_from_text("colors-custom.MyTheme.rain", "#000000, #ffffff"),
         );
         let palette = load_custom_palette(&cfg, "mytheme").unwrap();
         // masterclass: 2 CSV stops expand to 9 OKLab-polar samples.
@@ -365,12 +344,8 @@ mod tests {
     /// v25 masterclass: TOML array format for rain field.
     #[test]
     fn rain_array_format_parses_7_stops() {
-        let mut cfg = HashMap::new();
-        cfg.insert(
-            "colors-custom.mythme.rain".to_string(),
-            "[\"#1a0033\", \"#4d0080\", \"#9933ff\", \"#cc66ff\", \"#e6b3ff\", \"#f2ccff\", \"#ffffff\"]"
-                .to_string(),
-        );
+        let mut cfg = ConfigMap::new();
+        cfg.insert("colors-custom.mythme.rain".to_string(), crate::config_schema::value_from_text("colors-custom.mythme.rain", "[\"#1a0033\", \"#4d0080\", \"#9933ff\", \"#cc66ff\", \"#e6b3ff\", \"#f2ccff\", \"#ffffff\"]"));
         // Verify the 7 stops were parsed correctly by inspecting the raw
         // CustomPaletteDef before to_palette() expands them.
         let collected = collect_colors_custom(&cfg);
@@ -389,10 +364,13 @@ mod tests {
     /// v25 masterclass: CSV format still works (backward compat).
     #[test]
     fn rain_csv_format_still_works() {
-        let mut cfg = HashMap::new();
+        let mut cfg = ConfigMap::new();
         cfg.insert(
             "colors-custom.oldstyle.rain".to_string(),
-            "#000000, #ffffff".to_string(),
+            crate::config_schema::value_from_text(
+                "colors-custom.oldstyle.rain",
+                "#000000, #ffffff",
+            ),
         );
         let palette = load_custom_palette(&cfg, "oldstyle").unwrap();
         // masterclass: 2 CSV stops expand to 9 OKLab-polar samples.
@@ -111,7 +111,7 @@ fn linear_to_oklab(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
 
 /// Convert OKLab back to linear-light sRGB (each channel 0.0–1.0).
 #[inline]
-fn oklab_to_linear(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
+pub(crate) fn oklab_to_linear(l: f32, a: f32, b: f32) -> (f32, f32, f32) {
     let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
     let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
     let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
@@ -7,8 +7,9 @@
 //! rgb/srgb". This module houses the raw per-channel RGB math that the
 //! legacy fallback uses when the Chroma Dragon engine is not active
 //! (i.e. when `ColorPipeline::detect(color_mode)` returns
-//! `ColorPipeline::LegacyRgb`, which happens for `ColorMode::{Color256,
-//! Color16, Mono}`).
+//! `ColorPipeline::LegacyRgb`, which happens for `ColorMode::Mono`;
+//! 256/16-color output runs the chroma engine and is quantized by
+//! `chroma::quantize`).
 //!
 //! ## Why a separate module (not inlined at call sites)?
 //!
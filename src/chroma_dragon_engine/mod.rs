@@ -42,15 +42,19 @@
 //! | `gradient`   | new in Phase 3-A                         | OKLab polar interpolation (sole production path since v30) |
 //! | `legacy`     | current                             | Explicit sRGB-linear fallback math (`scale_rgb`, `blend_toward_rgb`, `boost_rgb`) used when `ColorPipeline::LegacyRgb` is active |
 //! | `intro_colors` | new in Intro Integration audit    | Cinematic brand color constants (cosmic burst, logo, singularity) — single source of truth for intro colors |
+//! | `quantize`   | current                                  | Nearest-OKLab mapping (+ ordered/temporal dither) of truecolor frames onto xterm-256 / ANSI-16 |
+//! | `canvas`     | current                                  | OKLab lightness inversion of frames for light terminal backgrounds (`color-bg = default-background`) |
 //!
 //! Modules `palette`, `catalog`, `shaders`, `gradient`, `legacy`, `post`,
-//! `tuning`, `intro_colors` cover all chroma concerns; no further sub-modules are planned.
+//! `tuning`, `intro_colors`, `quantize`, `canvas` cover all chroma concerns; no further sub-modules are planned.
 
+pub(crate) mod canvas;
 pub mod catalog;
 pub(crate) mod gradient;
 pub(crate) mod legacy;
 pub mod palette;
 pub(crate) mod post;
+pub(crate) mod quantize;
 pub(crate) mod shaders;
 pub(crate) mod tuning;
 
@@ -1069,3 +1069,130 @@ fn phase7b_print_gap_ratio_sweep_audit() {
         super::super::tuning::BODY_TAIL_MAX_GAP_RATIO
     );
 }
+
+// ── Light canvas: the floor, inverted ──
+//
+// On a light default background (`chroma::canvas`) the Phase 7 floor's
+// job flips: instead of keeping dim stops above black, every stop must
+// stay far enough *below* the background to read, and the hierarchy
+// runs the other way (heads darkest).
+
+/// Solarized Light base3 and plain white.
+const LIGHT_BACKGROUNDS: [(u8, u8, u8); 2] = [(253, 246, 227), (255, 255, 255)];
+
+/// OKLab L of a color.
+fn oklab_l(c: Color) -> f32 {
+    let (r, g, b) = color_to_rgb(c);
+    crate::chroma_dragon_engine::gradient::srgb_to_oklab(r, g, b).0
+}
+
+fn light_canvas(bg: (u8, u8, u8)) -> crate::chroma_dragon_engine::canvas::LightCanvas {
+    crate::chroma_dragon_engine::canvas::LightCanvas::for_background(bg).expect("light background")
+}
+
+/// Light canvas: every stop of every theme keeps at least 0.2 OKLab L
+/// of contrast below the background — the inverted `ABSOLUTE_MIN_FLOOR`.
+#[test]
+fn light_canvas_all_themes_stops_keep_contrast() {
+    use crate::runtime::ColorMode;
+    let mut failures = Vec::new();
+    for bg in LIGHT_BACKGROUNDS {
+        let bg_l = oklab_l(Color::Rgb {
+            r: bg.0,
+            g: bg.1,
+            b: bg.2,
+        });
+        let mut canvas = light_canvas(bg);
+        for theme in crate::catalog::THEMES {
+            let p = build_palette(theme.scheme, ColorMode::TrueColor, true);
+            for (i, &c) in p.colors.iter().enumerate() {
+                let contrast = bg_l - oklab_l(canvas.map_color(c));
+                if contrast < 0.2 {
+                    failures.push(format!(
+                        "{:?} stop {i} on {bg:?}: contrast {contrast:.3}",
+                        theme.scheme
+                    ));
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Light canvas: the brightness order of every theme reverses — the
+/// brighter a stop was on black, the darker it is on the light page, so
+/// heads come out darker than tails.
+#[test]
+fn light_canvas_heads_darker_than_tails() {
+    use crate::runtime::ColorMode;
+    let mut canvas = light_canvas(LIGHT_BACKGROUNDS[0]);
+    for theme in crate::catalog::THEMES {
+        let p = build_palette(theme.scheme, ColorMode::TrueColor, true);
+        let mut stops: Vec<(f32, f32)> = p
+            .colors
+            .iter()
+            .map(|&c| (oklab_l(c), oklab_l(canvas.map_color(c))))
+            .collect();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for pair in stops.windows(2) {
+            // 0.01 absorbs 8-bit rounding of near-equal stops.
+            assert!(
+                pair[1].1 <= pair[0].1 + 0.01,
+                "{:?}: source L {:.3} → {:.3} but {:.3} → {:.3}",
+                theme.scheme,
+                pair[0].0,
+                pair[0].1,
+                pair[1].0,
+                pair[1].1
+            );
+        }
+        let (head, tail) = (*p.colors.last().unwrap(), p.colors[0]);
+        if oklab_l(head) > oklab_l(tail) {
+            assert!(
+                oklab_l(canvas.map_color(head)) < oklab_l(canvas.map_color(tail)),
+                "{:?}: head not darker than tail",
+                theme.scheme
+            );
+        }
+    }
+}
+
+/// Light canvas: ghost glyphs (darkest stop × 0.2) stay visible, and
+/// phosphor afterglow fades monotonically toward the page instead of
+/// jumping to it.
+#[test]
+fn light_canvas_ghost_and_phosphor_stay_visible() {
+    use crate::runtime::ColorMode;
+    let bg = LIGHT_BACKGROUNDS[0];
+    let bg_l = oklab_l(Color::Rgb {
+        r: bg.0,
+        g: bg.1,
+        b: bg.2,
+    });
+    let mut canvas = light_canvas(bg);
+    for theme in crate::catalog::THEMES {
+        let p = build_palette(theme.scheme, ColorMode::TrueColor, true);
+        let (r, g, b) = crate::chroma_dragon_engine::post::ghost::ghost_base_color(&p.colors);
+        let ghost = canvas.map_color(Color::Rgb { r, g, b });
+        let contrast = bg_l - oklab_l(ghost);
+        assert!(
+            contrast >= 0.08,
+            "{:?}: ghost contrast {contrast:.3}",
+            theme.scheme
+        );
+
+        let (r, g, b) = color_to_rgb(*p.colors.last().unwrap());
+        let mut prev = 0.0;
+        for step in (1..=20).rev() {
+            let glow = apply_brightness_rgb(r, g, b, step as f32 / 20.0);
+            let l = oklab_l(canvas.map_color(glow));
+            assert!(
+                l + 0.01 >= prev,
+                "{:?}: afterglow darkens while fading",
+                theme.scheme
+            );
+            assert!(l < bg_l, "{:?}: afterglow reached the page", theme.scheme);
+            prev = l;
+        }
+    }
+}
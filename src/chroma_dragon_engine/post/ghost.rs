@@ -90,6 +90,18 @@ pub(crate) fn ghost_base_color(palette_colors: &[Color]) -> (u8, u8, u8) {
     )
 }
 
+/// Brightest palette stop as `(r, g, b)`, the draw color of the decrypt,
+/// scanline and cascade events. White when the palette is empty or the
+/// stop is `Color::Reset`.
+#[inline]
+#[must_use]
+pub(crate) fn event_highlight_color(palette_colors: &[Color]) -> (u8, u8, u8) {
+    match palette_colors.last() {
+        Some(&c) if !matches!(c, Color::Reset) => color_to_rgb(c),
+        _ => (255, 255, 255),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,299 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! # Perceptual quantization for 256- and 16-color terminals.
+//!
+//! The Chroma Dragon computes every frame in truecolor. On terminals that
+//! only understand the xterm-256 cube or the 16 ANSI colors, `Terminal::draw`
+//! hands the frame to a [`ColorQuantizer`], which maps each `Rgb` color to
+//! the palette entry nearest in OKLab — so a teal gradient lands on teals
+//! instead of whatever the per-channel RGB distance happens to favour.
+//!
+//! ## Targets
+//!
+//! - `Color256`: indices 16..=255 (the 6×6×6 cube and the gray ramp). The
+//!   first 16 are left out because terminals theme them freely.
+//! - `Color16`: the 16 named colors, at the xterm defaults `color_to_rgb`
+//!   decodes them to — or, after `--probe-terminal`, at the theme colors
+//!   the terminal reported over OSC 4. They are emitted as `30–37`/`90–97`.
+//!
+//! ## Lookup table
+//!
+//! Nearest-entry search over 240 targets is too slow per cell, so a lazily
+//! filled 6-bit-per-channel table (2^18 entries, 1 MiB) memoises the three
+//! targets nearest each bucket. A lookup then converts the exact color to
+//! OKLab and ranks just those three, which also yields the runner-up and
+//! how far the color sits toward it (`mix`, 0–255) for dithering.
+//!
+//! ## Dithering (`--color-dither`)
+//!
+//! Foregrounds only; backgrounds always take the nearest entry so blank
+//! areas stay flat. A cell switches to the second entry when `mix` exceeds
+//! its threshold from a 4×4 Bayer matrix. `Temporal` rotates the threshold
+//! every frame, so a cell that keeps being redrawn alternates between the
+//! two entries and averages out over time.
+//!
+//! ## Frame mirror
+//!
+//! The quantizer never writes into the simulation's frame — the cloud reads
+//! cell colors back for post-FX, and quantized values would feed back into
+//! the shading. [`ColorQuantizer::sync`] keeps a quantized copy instead and
+//! re-maps only the cells dirtied this frame, so the diff renderer still
+//! sees sparse updates (and cells whose quantized color did not change are
+//! not dirty at all).
+
+use crossterm::style::Color;
+
+use crate::cell::Cell;
+use crate::chroma_dragon_engine::gradient::srgb_to_oklab;
+use crate::config::ColorDither;
+use crate::frame::Frame;
+use crate::palette::{color_to_rgb, Palette};
+use crate::runtime::ColorMode;
+
+/// The 16 named colors in SGR order (30–37, then 90–97).
+const ANSI16: [Color; 16] = [
+    Color::Black,
+    Color::DarkRed,
+    Color::DarkGreen,
+    Color::DarkYellow,
+    Color::DarkBlue,
+    Color::DarkMagenta,
+    Color::DarkCyan,
+    Color::Grey,
+    Color::DarkGrey,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+];
+
+/// 4×4 Bayer matrix, ranks 0..16.
+const BAYER4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Bits per channel of the lookup table.
+const LUT_BITS: u32 = 6;
+const LUT_VALID: u32 = 1 << 31;
+
+/// Maps truecolor frames onto a 256- or 16-entry terminal palette.
+pub(crate) struct ColorQuantizer {
+    dither: ColorDither,
+    /// Palette entries with their OKLab coordinates.
+    targets: Vec<(Color, [f32; 3])>,
+    /// Memoised `nearest_three` results; 0 = not computed yet.
+    lut: Vec<u32>,
+    /// Frames synced so far; drives the temporal dither phase.
+    frame_no: u32,
+    /// Quantized copy of the frame, drawn in place of the source.
+    out: Option<Frame>,
+}
+
+impl ColorQuantizer {
+    /// Quantizer for `mode`, or `None` when the terminal takes the colors
+    /// as they are (`TrueColor`) or has none to map to (`Mono`).
+    #[must_use]
+    pub(crate) fn new(mode: ColorMode, dither: ColorDither) -> Option<Self> {
+        let entries: Vec<Color> = match mode {
+            ColorMode::Color256 => (16..=255).map(Color::AnsiValue).collect(),
+            ColorMode::Color16 => ANSI16.to_vec(),
+            ColorMode::TrueColor | ColorMode::Mono => return None,
+        };
+        let themed = match mode {
+            ColorMode::Color16 => crate::termdetect::probe_report().map(|p| p.palette),
+            _ => None,
+        };
+        let targets = entries
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let (r, g, b) = themed.and_then(|p| p[i]).unwrap_or_else(|| color_to_rgb(c));
+                let (l, a, bb) = srgb_to_oklab(r, g, b);
+                (c, [l, a, bb])
+            })
+            .collect();
+        Some(Self {
+            dither,
+            targets,
+            lut: vec![0; 1 << (3 * LUT_BITS)],
+            frame_no: 0,
+            out: None,
+        })
+    }
+
+    /// Nearest palette entry for `color`. Named colors and `Reset` pass
+    /// through unchanged.
+    #[must_use]
+    pub(crate) fn nearest(&mut self, color: Color) -> Color {
+        match rgb_of(color) {
+            Some((r, g, b)) => {
+                let (first, _, _) = self.resolve(r, g, b);
+                self.targets[first].0
+            }
+            None => color,
+        }
+    }
+
+    /// Foreground color for the cell at `(x, y)`, dithered per the mode.
+    #[must_use]
+    pub(crate) fn dithered(&mut self, color: Color, x: u16, y: u16) -> Color {
+        let Some((r, g, b)) = rgb_of(color) else {
+            return color;
+        };
+        let (first, second, mix) = self.resolve(r, g, b);
+        let bayer = u32::from(BAYER4[(y & 3) as usize][(x & 3) as usize]);
+        let rank = match self.dither {
+            ColorDither::Off => return self.targets[first].0,
+            ColorDither::Ordered => bayer,
+            ColorDither::Temporal => (bayer + self.frame_no.wrapping_mul(7)) & 15,
+        };
+        let pick = if mix > rank * 16 + 8 { second } else { first };
+        self.targets[pick].0
+    }
+
+    /// `palette` with every color mapped to its nearest entry (no dither).
+    /// Feeds `ColorCache` so cached SGR entries match the quantized frame.
+    #[must_use]
+    pub(crate) fn quantize_palette(&mut self, palette: &Palette) -> Palette {
+        Palette {
+            colors: palette.colors.iter().map(|&c| self.nearest(c)).collect(),
+            bg: palette.bg.map(|c| self.nearest(c)),
+        }
+    }
+
+    /// Bring the quantized mirror up to date with `src` and return it for
+    /// drawing. Clears `src`'s dirty state, as drawing it would have.
+    pub(crate) fn sync(&mut self, src: &mut Frame) -> &mut Frame {
+        self.frame_no = self.frame_no.wrapping_add(1);
+        let out = sync_mirror(self.out.take(), src, |cell, x, y| {
+            self.quantize_cell(cell, x, y)
+        });
+        self.out.insert(out)
+    }
+
+    fn quantize_cell(&mut self, cell: Cell, x: u16, y: u16) -> Cell {
+        Cell {
+            fg: cell.fg.map(|c| self.dithered(c, x, y)),
+            bg: cell.bg.map(|c| self.nearest(c)),
+            ..cell
+        }
+    }
+
+    /// Nearest and second-nearest target for an sRGB color, and how far
+    /// (0–255) it sits from the first toward the second. The table narrows
+    /// the search to three candidates; the exact color decides among them.
+    fn resolve(&mut self, r: u8, g: u8, b: u8) -> (usize, usize, u32) {
+        let packed = self.candidates(r, g, b);
+        let (l, a, bb) = srgb_to_oklab(r, g, b);
+        let p = [l, a, bb];
+        let mut ranked = [16, 8, 0].map(|shift| {
+            let i = (packed >> shift & 0xff) as usize;
+            (i, dist2(p, self.targets[i].1))
+        });
+        ranked.sort_unstable_by(|x, y| x.1.total_cmp(&y.1));
+        let (first, second) = (ranked[0].0, ranked[1].0);
+        // Position of `p` projected onto the segment first → second.
+        let (t0, t1) = (self.targets[first].1, self.targets[second].1);
+        let len2 = dist2(t0, t1);
+        let mix = if len2 > 0.0 {
+            let dot = (p[0] - t0[0]) * (t1[0] - t0[0])
+                + (p[1] - t0[1]) * (t1[1] - t0[1])
+                + (p[2] - t0[2]) * (t1[2] - t0[2]);
+            (dot / len2).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        (first, second, (mix * 255.0).round() as u32)
+    }
+
+    /// The three nearest targets to the color's table bucket, packed as
+    /// `a << 16 | b << 8 | c`.
+    fn candidates(&mut self, r: u8, g: u8, b: u8) -> u32 {
+        let shift = 8 - LUT_BITS;
+        let idx = (usize::from(r >> shift) << (2 * LUT_BITS))
+            | (usize::from(g >> shift) << LUT_BITS)
+            | usize::from(b >> shift);
+        let cached = self.lut[idx];
+        if cached & LUT_VALID != 0 {
+            return cached;
+        }
+        // Evaluate at the bucket's representative color so the table does
+        // not depend on which member happened to fill it.
+        let expand = |v: u8| {
+            let v = v >> shift;
+            (v << shift) | (v >> (2 * LUT_BITS - 8))
+        };
+        let entry = LUT_VALID | self.nearest_three(expand(r), expand(g), expand(b));
+        self.lut[idx] = entry;
+        entry
+    }
+
+    /// Brute-force three nearest targets by OKLab distance.
+    fn nearest_three(&self, r: u8, g: u8, b: u8) -> u32 {
+        let (l, a, bb) = srgb_to_oklab(r, g, b);
+        let p = [l, a, bb];
+        let mut best = [(0usize, f32::MAX); 3];
+        for (i, (_, t)) in self.targets.iter().enumerate() {
+            let d = dist2(p, *t);
+            if d < best[2].1 {
+                best[2] = (i, d);
+                best.sort_unstable_by(|x, y| x.1.total_cmp(&y.1));
+            }
+        }
+        ((best[0].0 as u32) << 16) | ((best[1].0 as u32) << 8) | best[2].0 as u32
+    }
+}
+
+/// Update `out`, a color-mapped copy of `src`, by running `map` over the
+/// cells dirtied since the last sync (over every cell on the first sync,
+/// a resize or a full invalidation). Clears `src`'s dirty state, as
+/// drawing it would have; cells whose mapped value did not change stay
+/// clean in the copy. Shared by the quantizer and `canvas::LightCanvas`.
+pub(crate) fn sync_mirror(
+    out: Option<Frame>,
+    src: &mut Frame,
+    mut map: impl FnMut(Cell, u16, u16) -> Cell,
+) -> Frame {
+    let fresh = out.is_none();
+    let mut out = out.unwrap_or_else(|| src.clone());
+    let rebuild = fresh || src.is_dirty_all() || out.width != src.width || out.height != src.height;
+    if rebuild {
+        // Resize or full invalidation: copy everything (the clone keeps
+        // `dirty_all`, so the terminal redraws it in full).
+        out.clone_from(src);
+        out.blank = map(out.blank, 0, 0);
+        let width = out.width as usize;
+        for i in 0..out.cells.len() {
+            let cell = src.cell_at_index(i);
+            out.cells[i] = map(cell, (i % width) as u16, (i / width) as u16);
+        }
+    } else {
+        out.semantic_gen = src.semantic_gen;
+        let width = src.width as usize;
+        for &i in src.dirty_indices() {
+            let (x, y) = ((i % width) as u16, (i / width) as u16);
+            out.set(x, y, map(src.cell_at_index(i), x, y));
+        }
+    }
+    src.clear_dirty();
+    out
+}
+
+/// sRGB of colors the quantizer maps; `None` for named colors and `Reset`,
+/// which already fit every palette.
+#[inline]
+fn rgb_of(color: Color) -> Option<(u8, u8, u8)> {
+    match color {
+        Color::Rgb { r, g, b } => Some((r, g, b)),
+        Color::AnsiValue(_) => Some(color_to_rgb(color)),
+        _ => None,
+    }
+}
+
+#[inline]
+fn dist2(p: [f32; 3], q: [f32; 3]) -> f32 {
+    let (dl, da, db) = (p[0] - q[0], p[1] - q[1], p[2] - q[2]);
+    dl * dl + da * da + db * db
+}
@@ -0,0 +1,150 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Light canvas tests (`chroma::canvas`).
+//!
+//! Pins which backgrounds count as light, the lightness mapping's end
+//! points, and that the canvas only takes over frames drawn on the
+//! terminal's default background. Per-theme contrast lives with the floor
+//! tests in `palette/tests_floor.rs`.
+
+use crossterm::style::Color;
+
+use crate::cell::Cell;
+use crate::chroma_dragon_engine::canvas::{is_light_background, LightCanvas};
+use crate::chroma_dragon_engine::gradient::srgb_to_oklab;
+use crate::chroma_dragon_engine::tuning::LIGHT_CANVAS_INK_L;
+use crate::frame::Frame;
+use crate::palette::{build_palette, color_to_rgb};
+use crate::runtime::{ColorMode, ColorScheme};
+use crate::termdetect::TerminalCaps;
+use crate::terminal::sink::MemorySink;
+use crate::terminal::Terminal;
+
+const SOLARIZED_LIGHT: (u8, u8, u8) = (253, 246, 227);
+
+fn rgb(r: u8, g: u8, b: u8) -> Color {
+    Color::Rgb { r, g, b }
+}
+
+fn oklab(c: Color) -> (f32, f32, f32) {
+    let (r, g, b) = color_to_rgb(c);
+    srgb_to_oklab(r, g, b)
+}
+
+fn glyph(fg: Color) -> Cell {
+    Cell {
+        ch: 'x',
+        fg: Some(fg),
+        bg: None,
+        bold: false,
+    }
+}
+
+#[test]
+fn only_light_backgrounds_get_a_canvas() {
+    for light in [SOLARIZED_LIGHT, (255, 255, 255), (238, 232, 213)] {
+        assert!(is_light_background(light), "{light:?}");
+        assert!(LightCanvas::for_background(light).is_some());
+    }
+    // Black, Solarized Dark base03, a dark gray and a mid gray.
+    for dark in [(0, 0, 0), (0, 43, 54), (40, 40, 40), (110, 110, 110)] {
+        assert!(!is_light_background(dark), "{dark:?}");
+        assert!(LightCanvas::for_background(dark).is_none());
+    }
+}
+
+#[test]
+fn lightness_maps_between_background_and_ink() {
+    let mut canvas = LightCanvas::for_background(SOLARIZED_LIGHT).unwrap();
+    let bg = rgb(SOLARIZED_LIGHT.0, SOLARIZED_LIGHT.1, SOLARIZED_LIGHT.2);
+    // Black is the page itself; white is the darkest ink.
+    assert!((oklab(canvas.map_color(rgb(0, 0, 0))).0 - oklab(bg).0).abs() < 0.01);
+    assert!((oklab(canvas.map_color(rgb(255, 255, 255))).0 - LIGHT_CANVAS_INK_L).abs() < 0.01);
+    // A bright green head stays green, just dark.
+    let (l, a, b) = oklab(rgb(80, 255, 110));
+    let (ml, ma, mb) = oklab(canvas.map_color(rgb(80, 255, 110)));
+    assert!(ml < l - 0.4, "{ml} vs {l}");
+    assert!(ma < 0.0 && ma.signum() == a.signum() && mb.signum() == b.signum());
+    // 256-color inputs are decoded and mapped too.
+    assert!(matches!(
+        canvas.map_color(Color::AnsiValue(231)),
+        Color::Rgb { .. }
+    ));
+}
+
+#[test]
+fn named_colors_swap_light_and_dark() {
+    let mut canvas = LightCanvas::for_background((255, 255, 255)).unwrap();
+    assert_eq!(canvas.map_color(Color::White), Color::Black);
+    assert_eq!(canvas.map_color(Color::Black), Color::White);
+    assert_eq!(canvas.map_color(Color::Grey), Color::DarkGrey);
+    assert_eq!(canvas.map_color(Color::DarkGrey), Color::Grey);
+    assert_eq!(canvas.map_color(Color::Green), Color::Green);
+    assert_eq!(canvas.map_color(Color::Reset), Color::Reset);
+}
+
+#[test]
+fn sync_maps_default_background_frames_only() {
+    let mut canvas = LightCanvas::for_background(SOLARIZED_LIGHT).unwrap();
+    let head = rgb(240, 255, 240);
+
+    let mut frame = Frame::new(6, 3, None);
+    frame.set(2, 1, glyph(head));
+    let out = canvas.sync(&mut frame);
+    let got = *out.get(2, 1).unwrap();
+    assert_eq!(got.ch, 'x');
+    assert!(oklab(got.fg.unwrap()).0 < 0.4, "{got:?}");
+    // The simulation's frame keeps its dark-canvas colors.
+    assert_eq!(frame.get(2, 1).unwrap().fg, Some(head));
+    assert!(frame.dirty_indices().is_empty());
+
+    // A frame with its own background is drawn as it is.
+    let mut black = Frame::new(6, 3, Some(rgb(0, 0, 0)));
+    black.set(2, 1, glyph(head));
+    let out = canvas.sync(&mut black);
+    assert_eq!(out.get(2, 1).unwrap().fg, Some(head));
+    assert_eq!(out.blank.bg, Some(rgb(0, 0, 0)));
+}
+
+#[test]
+fn light_canvas_terminal_never_emits_palette_heads() {
+    let (w, h) = (20u16, 6u16);
+    let caps = TerminalCaps {
+        sync_output: false,
+        kitty_keyboard: false,
+        has_alternate_screen: true,
+        xtermjs_host: false,
+        vscode_integrated: false,
+        default_fps_cap: 240.0,
+        dynamic_default_fps: 60.0,
+        dynamic_fps_source: "test",
+        repeat_char: true,
+    };
+    let mem = MemorySink::new();
+    let mut term = Terminal::with_sink(Box::new(mem.clone()), (w, h), caps).unwrap();
+    term.set_light_canvas(LightCanvas::for_background(SOLARIZED_LIGHT));
+    let palette = build_palette(ColorScheme::Green, ColorMode::TrueColor, true);
+    term.set_color_cache(crate::color_cache::ColorCache::new(&palette));
+
+    let head = *palette.colors.last().unwrap();
+    let mut frame = Frame::new(w, h, palette.bg);
+    for x in 0..w {
+        frame.set(x, 2, glyph(head));
+    }
+    term.draw(&mut frame).unwrap();
+    let text = String::from_utf8(mem.take()).unwrap();
+    let (r, g, b) = color_to_rgb(head);
+    assert!(text.contains("38;2;"), "no truecolor glyphs drawn");
+    assert!(
+        !text.contains(&format!("38;2;{r};{g};{b}")),
+        "head drawn in its dark-canvas color"
+    );
+    let mapped = LightCanvas::for_background(SOLARIZED_LIGHT)
+        .unwrap()
+        .map_color(head);
+    let (r, g, b) = color_to_rgb(mapped);
+    assert!(text.contains(&format!("38;2;{r};{g};{b}")));
+    let (_, _, hits, _) = term.encoding_stats();
+    assert!(hits > 0, "mapped palette never hit the SGR cache");
+}
@@ -982,7 +982,8 @@ fn lock_inv17_engine_lock_report() {
 /// INV-19: assert that `ColorPipeline::detect` routes every `ColorMode`
 /// variant to the correct pipeline. Owner directive: "all color -> chroma
 /// dragon first -> fallback legacy rgb/srgb". The detection rule is:
-/// `ColorMode::TrueColor` → `ChromaDragon`, everything else → `LegacyRgb`.
+/// `ColorMode::{TrueColor, Color256, Color16}` → `ChromaDragon` (256/16
+/// output is quantized in OKLab at draw time), `Mono` → `LegacyRgb`.
 ///
 /// This invariant was added in  (Phase 9-D) by the chroma dragon
 /// audit. It locks the public contract of `ColorPipeline` so a future
@@ -1012,36 +1013,48 @@ fn lock_inv19_color_pipeline_disclosure_routes_correctly() {
         "is_chroma() must return true for the TrueColor pipeline"
     );
 
-    // Color256/Color16/Mono all fall back to legacy sRGB-linear — the
-    // OKLab palette would be quantized away by the terminal, so the
-    // raw-RGB math is used directly via chroma::legacy.
-    for mode in [ColorMode::Color256, ColorMode::Color16, ColorMode::Mono] {
+    // Color256/Color16 keep the chroma engine: the frame is computed in
+    // truecolor and `chroma::quantize` maps it to the terminal palette by
+    // nearest OKLab distance.
+    for mode in [ColorMode::Color256, ColorMode::Color16] {
         assert_eq!(
             ColorPipeline::detect(mode),
-            ColorPipeline::LegacyRgb,
-            "{:?} must route to LegacyRgb — chroma needs truecolor output",
-            mode
-        );
-        assert!(
-            !ColorPipeline::detect(mode).is_chroma(),
-            "is_chroma() must return false for {:?}",
-            mode
-        );
-        assert_eq!(
-            ColorPipeline::detect(mode).label(),
-            "legacy_rgb",
-            "label() must be the stable machine-readable string for {:?}",
-            mode
-        );
-        // Every LegacyRgb state must disclose why — the user is told the
-        // reason via -v / --doctor so they don't have to guess.
-        assert!(
-            ColorPipeline::detect(mode).disable_reason(mode).is_some(),
-            "disable_reason must be Some for {:?}",
+            ColorPipeline::ChromaDragon,
+            "{:?} must route to ChromaDragon — output is quantized in OKLab",
             mode
         );
+        assert_eq!(ColorPipeline::detect(mode).disable_reason(mode), None);
     }
 
+    // Mono falls back to legacy sRGB-linear — there is no palette to
+    // quantize to, so the raw-RGB math is used directly via
+    // chroma::legacy.
+    let mode = ColorMode::Mono;
+    assert_eq!(
+        ColorPipeline::detect(mode),
+        ColorPipeline::LegacyRgb,
+        "{:?} must route to LegacyRgb — mono has no palette",
+        mode
+    );
+    assert!(
+        !ColorPipeline::detect(mode).is_chroma(),
+        "is_chroma() must return false for {:?}",
+        mode
+    );
+    assert_eq!(
+        ColorPipeline::detect(mode).label(),
+        "legacy_rgb",
+        "label() must be the stable machine-readable string for {:?}",
+        mode
+    );
+    // Every LegacyRgb state must disclose why — the user is told the
+    // reason via -v / --doctor so they don't have to guess.
+    assert!(
+        ColorPipeline::detect(mode).disable_reason(mode).is_some(),
+        "disable_reason must be Some for {:?}",
+        mode
+    );
+
     // ChromaDragon must NOT emit a disable_reason (no fallback to explain).
     assert_eq!(
         ColorPipeline::ChromaDragon.disable_reason(ColorMode::TrueColor),
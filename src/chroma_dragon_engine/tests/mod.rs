@@ -7,7 +7,11 @@
 //! `chroma/lock_tests.rs`, loaded via `#[path]` from `chroma/mod.rs`
 //! (Pattern B). Moved to a dedicated `tests/` subdir (Pattern C).
 
+#[cfg(test)]
+mod canvas;
 #[cfg(test)]
 mod color_detection;
 #[cfg(test)]
 mod lock;
+#[cfg(test)]
+mod quantize;
@@ -0,0 +1,256 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! OKLab quantizer tests (`chroma::quantize`).
+//!
+//! Pins the nearest-entry choice against a brute-force OKLab search, the
+//! dither patterns, and that a `Terminal` with a quantizer installed never
+//! emits truecolor SGR.
+
+use crossterm::style::Color;
+
+use crate::cell::Cell;
+use crate::chroma_dragon_engine::gradient::srgb_to_oklab;
+use crate::chroma_dragon_engine::quantize::ColorQuantizer;
+use crate::config::ColorDither;
+use crate::frame::Frame;
+use crate::palette::{build_palette, color_to_rgb};
+use crate::runtime::{ColorMode, ColorScheme};
+use crate::termdetect::TerminalCaps;
+use crate::terminal::sink::MemorySink;
+use crate::terminal::Terminal;
+
+fn rgb(r: u8, g: u8, b: u8) -> Color {
+    Color::Rgb { r, g, b }
+}
+
+fn quantizer(mode: ColorMode, dither: ColorDither) -> ColorQuantizer {
+    ColorQuantizer::new(mode, dither).expect("quantized mode")
+}
+
+fn oklab_dist2(a: Color, b: Color) -> f32 {
+    let (ar, ag, ab) = color_to_rgb(a);
+    let (br, bg, bb) = color_to_rgb(b);
+    let p = srgb_to_oklab(ar, ag, ab);
+    let q = srgb_to_oklab(br, bg, bb);
+    (p.0 - q.0).powi(2) + (p.1 - q.1).powi(2) + (p.2 - q.2).powi(2)
+}
+
+fn lcg(state: &mut u64) -> u64 {
+    *state = state
+        .wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407);
+    *state >> 33
+}
+
+#[test]
+fn truecolor_and_mono_need_no_quantizer() {
+    assert!(ColorQuantizer::new(ColorMode::TrueColor, ColorDither::Ordered).is_none());
+    assert!(ColorQuantizer::new(ColorMode::Mono, ColorDither::Off).is_none());
+}
+
+#[test]
+fn cube_and_gray_entries_map_to_themselves() {
+    let mut q = quantizer(ColorMode::Color256, ColorDither::Off);
+    assert_eq!(q.nearest(rgb(0, 0, 0)), Color::AnsiValue(16));
+    assert_eq!(q.nearest(rgb(255, 255, 255)), Color::AnsiValue(231));
+    assert_eq!(q.nearest(rgb(0, 255, 0)), Color::AnsiValue(46));
+    assert_eq!(q.nearest(rgb(95, 135, 175)), Color::AnsiValue(67));
+    assert_eq!(q.nearest(rgb(128, 128, 128)), Color::AnsiValue(244));
+    // Named colors already fit every palette.
+    assert_eq!(q.nearest(Color::Green), Color::Green);
+    assert_eq!(q.nearest(Color::Reset), Color::Reset);
+}
+
+#[test]
+fn sixteen_color_targets_are_named() {
+    let mut q = quantizer(ColorMode::Color16, ColorDither::Off);
+    assert_eq!(q.nearest(rgb(0, 0, 0)), Color::Black);
+    assert_eq!(q.nearest(rgb(250, 250, 250)), Color::White);
+    assert_eq!(q.nearest(rgb(10, 240, 20)), Color::Green);
+    assert_eq!(q.nearest(rgb(0, 110, 0)), Color::DarkGreen);
+    assert_eq!(q.nearest(Color::AnsiValue(196)), Color::Red);
+}
+
+/// The table only narrows each 4-level bucket to three candidates, so a
+/// color at a three-way boundary could in principle miss; any miss must
+/// stay well under the ~0.02 OKLab just-noticeable difference.
+#[test]
+fn table_lookup_tracks_brute_force() {
+    for mode in [ColorMode::Color256, ColorMode::Color16] {
+        let mut q = quantizer(mode, ColorDither::Off);
+        let targets: Vec<Color> = match mode {
+            ColorMode::Color256 => (16..=255).map(Color::AnsiValue).collect(),
+            _ => vec![
+                Color::Black,
+                Color::DarkRed,
+                Color::DarkGreen,
+                Color::DarkYellow,
+                Color::DarkBlue,
+                Color::DarkMagenta,
+                Color::DarkCyan,
+                Color::Grey,
+                Color::DarkGrey,
+                Color::Red,
+                Color::Green,
+                Color::Yellow,
+                Color::Blue,
+                Color::Magenta,
+                Color::Cyan,
+                Color::White,
+            ],
+        };
+        let mut seed = 0x0c1a_u64;
+        let mut exact = 0;
+        for _ in 0..4000 {
+            let c = rgb(
+                lcg(&mut seed) as u8,
+                lcg(&mut seed) as u8,
+                lcg(&mut seed) as u8,
+            );
+            let best = targets
+                .iter()
+                .map(|&t| oklab_dist2(c, t))
+                .fold(f32::MAX, f32::min);
+            let got = oklab_dist2(c, q.nearest(c));
+            assert!(
+                got.sqrt() - best.sqrt() < 0.01,
+                "{c:?}: {got} vs best {best}"
+            );
+            exact += usize::from(got <= best);
+        }
+        assert!(exact > 3900, "{mode:?}: only {exact}/4000 exact");
+    }
+}
+
+#[test]
+fn ordered_dither_mixes_in_proportion() {
+    // Halfway between gray-ramp entries 243 (118) and 244 (128), so the
+    // 4×4 pattern should split roughly evenly.
+    let mid = rgb(123, 123, 123);
+    let mut off = quantizer(ColorMode::Color256, ColorDither::Off);
+    let nearest = off.nearest(mid);
+    let mut q = quantizer(ColorMode::Color256, ColorDither::Ordered);
+    let mut picks = std::collections::HashMap::new();
+    for y in 0..4 {
+        for x in 0..4 {
+            *picks.entry(q.dithered(mid, x, y)).or_insert(0) += 1;
+        }
+    }
+    assert_eq!(picks.len(), 2, "{picks:?}");
+    assert!(matches!(nearest, Color::AnsiValue(243 | 244)));
+    assert!((6..=10).contains(&picks[&nearest]), "{picks:?}");
+    // Fixed per cell: the same position always picks the same entry.
+    assert_eq!(q.dithered(mid, 5, 2), q.dithered(mid, 5, 2));
+    // An exact palette color never dithers.
+    for y in 0..4 {
+        for x in 0..4 {
+            assert_eq!(q.dithered(rgb(0, 255, 0), x, y), Color::AnsiValue(46));
+        }
+    }
+}
+
+#[test]
+fn temporal_dither_alternates_a_cell_over_frames() {
+    let mid = rgb(123, 123, 123);
+    let mut q = quantizer(ColorMode::Color256, ColorDither::Temporal);
+    let mut frame = Frame::new(4, 4, None);
+    let mut seen = std::collections::HashSet::new();
+    for n in 0..16u8 {
+        // Re-dirty the cell every frame, as a falling trail does.
+        let ch = if n.is_multiple_of(2) { 'a' } else { 'b' };
+        frame.set(
+            1,
+            1,
+            Cell {
+                ch,
+                fg: Some(mid),
+                bg: None,
+                bold: false,
+            },
+        );
+        let out = q.sync(&mut frame);
+        seen.insert(out.get(1, 1).unwrap().fg);
+    }
+    assert_eq!(seen.len(), 2, "{seen:?}");
+}
+
+#[test]
+fn sync_maps_backgrounds_without_dither_and_keeps_source() {
+    let mut q = quantizer(ColorMode::Color16, ColorDither::Ordered);
+    let mut frame = Frame::new(8, 4, Some(rgb(0, 0, 0)));
+    let cell = Cell {
+        ch: 'x',
+        fg: Some(rgb(10, 240, 20)),
+        bg: Some(rgb(100, 100, 100)),
+        bold: true,
+    };
+    frame.set(3, 2, cell);
+    let out = q.sync(&mut frame);
+    assert_eq!(out.blank.bg, Some(Color::Black));
+    let got = *out.get(3, 2).unwrap();
+    assert_eq!(got.ch, 'x');
+    assert!(got.bold);
+    assert_eq!(got.fg, Some(Color::Green));
+    assert_eq!(got.bg, Some(Color::DarkGrey));
+    // The simulation's frame keeps its truecolor values.
+    assert_eq!(*frame.get(3, 2).unwrap(), cell);
+    assert!(frame.dirty_indices().is_empty());
+}
+
+#[test]
+fn quantized_terminal_emits_no_truecolor() {
+    for mode in [ColorMode::Color256, ColorMode::Color16] {
+        let (w, h) = (40u16, 12u16);
+        let caps = TerminalCaps {
+            sync_output: false,
+            kitty_keyboard: false,
+            has_alternate_screen: true,
+            xtermjs_host: false,
+            vscode_integrated: false,
+            default_fps_cap: 240.0,
+            dynamic_default_fps: 60.0,
+            dynamic_fps_source: "test",
+            repeat_char: true,
+        };
+        let mem = MemorySink::new();
+        let mut term = Terminal::with_sink(Box::new(mem.clone()), (w, h), caps).unwrap();
+        term.set_color_quantizer(ColorQuantizer::new(mode, ColorDither::Temporal));
+        let palette = build_palette(ColorScheme::Spectrum20, ColorMode::TrueColor, false);
+        term.set_color_cache(crate::color_cache::ColorCache::new(&palette));
+
+        let mut frame = Frame::new(w, h, palette.bg);
+        let mut seed = 0xd17e_u64;
+        let mut out = Vec::new();
+        for _ in 0..30 {
+            for _ in 0..40 {
+                let x = (lcg(&mut seed) % u64::from(w)) as u16;
+                let y = (lcg(&mut seed) % u64::from(h)) as u16;
+                let fg = palette.colors[lcg(&mut seed) as usize % palette.colors.len()];
+                frame.set(
+                    x,
+                    y,
+                    Cell {
+                        ch: 'z',
+                        fg: Some(fg),
+                        bg: palette.bg,
+                        bold: false,
+                    },
+                );
+            }
+            term.draw(&mut frame).unwrap();
+            out.extend(mem.take());
+        }
+        let text = String::from_utf8(out).unwrap();
+        assert!(!text.contains("38;2;"), "{mode:?} emitted truecolor fg");
+        assert!(!text.contains("48;2;"), "{mode:?} emitted truecolor bg");
+        if mode == ColorMode::Color16 {
+            assert!(!text.contains("38;5;"), "16-color emitted 256-color fg");
+        }
+        let (_, _, hits, _) = term.encoding_stats();
+        assert!(
+            hits > 0,
+            "{mode:?}: quantized palette never hit the SGR cache"
+        );
+    }
+}
@@ -288,3 +288,35 @@ pub(crate) const BODY_TAIL_MAX_GAP_RATIO: f32 = 2.0;
 ///
 /// See `PALETTE_FLOOR_RATIO` for the full Phase 7 rationale.
 pub(crate) const GLOBAL_MAX_FLOOR: u16 = 180;
+
+/// Light canvas: OKLab lightness at or above which the terminal's default
+/// background counts as light and `canvas::LightCanvas` takes over.
+///
+/// `0.6` sits between the lightest common dark themes (Solarized Dark
+/// base03 ≈ 0.27, Gruvbox Dark ≈ 0.28) and the darkest light ones
+/// (Solarized Light base3 ≈ 0.97, Gruvbox Light ≈ 0.95, GitHub Light
+/// 1.0), so no mainstream theme is near the edge. Mid-gray backgrounds
+/// between the two keep the dark-canvas palettes.
+pub(crate) const LIGHT_CANVAS_MIN_BG_L: f32 = 0.6;
+
+/// Light canvas: OKLab lightness a full-brightness color (L = 1, the
+/// white-hot head) lands on. Every other color falls between this and
+/// the background, so heads come out darkest.
+///
+/// `0.25` is near-black ink with enough lightness left for hue. Lower
+/// values collapse saturated heads into black; higher ones (0.35+) leave
+/// the head–body step too small to read against a 0.97 background.
+pub(crate) const LIGHT_CANVAS_INK_L: f32 = 0.25;
+
+/// Light canvas: exponent on the source lightness before it is mapped
+/// between the background and `LIGHT_CANVAS_INK_L`. The light-canvas
+/// counterpart of the Phase 7 floor: below 1 it lifts dim colors away
+/// from the background, so trail stops, ghost glyphs (darkest stop ×
+/// 0.2) and phosphor afterglow keep visible contrast instead of fading
+/// into the page.
+///
+/// `0.7` keeps a ghost at source L ≈ 0.08 about 0.13 L away from a
+/// Solarized Light background (linear mapping would leave 0.06, below
+/// what reads at a glance), while the head→trail order is unchanged
+/// because the curve is monotonic.
+pub(crate) const LIGHT_CANVAS_GAMMA: f32 = 0.7;
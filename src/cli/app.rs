@@ -3,7 +3,7 @@
 
 //! Application configuration: CloudConfig struct and density calculation helpers.
 
-use crate::cloud::Cloud;
+use crate::cloud::{Clock, Cloud, RealClock};
 use crate::config::IntroType;
 use crate::constants::*;
 use crate::rain_style::RainStyle;
@@ -17,10 +17,17 @@ use crate::runtime::{BoldMode, ColorMode, ColorScheme, MonolithSize, ShadingMode
 #[derive(Clone)]
 pub struct CloudConfig {
     pub color_mode: ColorMode,
+    /// `--color-dither` / `color-dither`: dithering for the 256/16-color
+    /// quantizer. Ignored on truecolor and mono terminals. Startup-only.
+    pub color_dither: crate::config::ColorDither,
     pub shading_mode: ShadingMode,
     pub bold_mode: BoldMode,
     pub async_mode: bool,
     pub default_bg: bool,
+    /// The terminal's default background, queried at startup when
+    /// `default_bg` is set (`termdetect::query_background`). A light one
+    /// installs the `canvas::LightCanvas` mapping. Startup-only.
+    pub terminal_bg: Option<(u8, u8, u8)>,
     pub color_scheme: ColorScheme,
     /// Custom palette override (v16). When Some, the cloud uses this palette
     /// instead of the built-in palette from color_scheme. The color_scheme
@@ -72,6 +79,30 @@ pub struct CloudConfig {
     pub screen_size: Option<(u16, u16)>,
     /// Parsed --color-tune value. None means no tune (identity).
     pub color_tune: crate::color_tune::ColorTune,
+    /// `[rain-tuning]` config section (parallax, phosphor, bloom, fog,
+    /// vignettes). Defaults to the compiled-in constants.
+    pub(crate) rain_tuning: crate::constants::RainTuning,
+    /// `[events]` config section (per-event chance and cooldown, decrypt
+    /// words). Defaults to the compiled-in constants.
+    pub(crate) event_tuning: crate::constants::EventTuning,
+    /// `[power-dragon]` config section (idle tiers, self-healer, thermal
+    /// ramp, xterm.js budgets). Defaults to the compiled-in constants.
+    pub(crate) power_thresholds: crate::constants::PowerThresholds,
+    /// `[keys]` config section (runtime key bindings). Defaults to the
+    /// built-in keys.
+    pub(crate) keymap: crate::interactive::keymap::Keymap,
+    /// `crystal-dragon-calc` config key (theme selection method for
+    /// Crystal Dragon drift). Defaults to calc-v1.
+    pub(crate) crystal_dragon_calc:
+        crate::crystal_dragon_engine::crystal_dragon_control::CrystalDragonCalcMethod,
+    /// `crystal-dragon-sensor` / `crystal-dragon-sensor-mix` config keys
+    /// (Crystal Dragon point sources). Defaults to process CPU.
+    pub(crate) crystal_dragon_sensors:
+        crate::crystal_dragon_engine::crystal_dragon_control::CrystalDragonSensors,
+    /// `crystal-dragon-external` config key (file, FIFO or command for the
+    /// `external` sensor). `None` when unset.
+    pub(crate) crystal_dragon_external:
+        Option<crate::crystal_dragon_engine::external::ExternalSource>,
     /// Output benchmark report as JSON (--json flag).
     pub json: bool,
     /// --save-baseline PATH: save benchmark JSON to file
@@ -88,6 +119,17 @@ pub struct CloudConfig {
     /// SGR + BOLT bold escape) so the BOLT-backed production path is
     /// measurable. Requires --bench-io.
     pub bench_scene: Option<String>,
+    /// --record FILE: asciicast v2 recording of the interactive session.
+    /// Attached to the Terminal in `run_interactive`; ignored by the
+    /// benchmark paths (they never construct a Terminal).
+    pub(crate) record: Option<std::path::PathBuf>,
+    /// --control-socket PATH: Unix socket for runtime commands. Bound in
+    /// `run_interactive`; ignored by the benchmark and headless paths.
+    pub(crate) control_socket: Option<std::path::PathBuf>,
+    /// --seed / `seed =`: session RNG seed. None = default seeding
+    /// (constant initial seed + periodic entropy reseed). Startup-only:
+    /// live reload keeps the session seed.
+    pub(crate) seed: Option<u64>,
     /// --verbose flag: print diagnostic info to stderr.
     pub verbose: bool,
     pub density_auto: bool,
@@ -117,6 +159,12 @@ pub struct CloudConfig {
     /// Parsed from scene-custom.<name>.density-map config field (CSV f64).
     /// None = uniform distribution (default).
     pub(crate) monolith_density_map: Option<&'static [f64]>,
+    /// Optional 2D spawn/brightness mask for both rain styles. Parsed from
+    /// scene-custom.<name>.density-mask (text, ASCII art or PGM/PBM).
+    /// None = no mask (default).
+    pub(crate) density_mask: Option<std::sync::Arc<crate::scene_custom::DensityMask>>,
+    /// Weight outside the mask (scene-custom.<name>.density-mask-floor).
+    pub(crate) density_mask_floor: f32,
     /// Path to the config file being watched for live reload.
     /// None = no watcher (CLI-only run, no config file).
     pub(crate) config_path_for_watcher: Option<std::path::PathBuf>,
@@ -219,7 +267,14 @@ impl CloudConfig {
     }
 
     pub fn create_cloud(&self, density: f32) -> Cloud {
-        let mut cloud = Cloud::new(
+        self.create_cloud_with_clock(density, Box::new(RealClock))
+    }
+
+    /// [`create_cloud`](Self::create_cloud) on an explicit simulation
+    /// clock (`--render-frames` steps a `ManualClock` at exactly 1/fps).
+    pub(crate) fn create_cloud_with_clock(&self, density: f32, clock: Box<dyn Clock>) -> Cloud {
+        let mut cloud = Cloud::with_clock(
+            clock,
             self.color_mode,
             self.shading_mode,
             self.bold_mode,
@@ -228,6 +283,13 @@ impl CloudConfig {
             self.color_scheme,
             self.rain_style,
         );
+        // --seed must land before init_chars/reset draw from the RNG.
+        if let Some(seed) = self.seed {
+            cloud.set_seed(seed);
+        }
+        // Before reset(): the vignette LUT is baked from the tuning.
+        cloud.rain_tuning = self.rain_tuning;
+        cloud.set_event_tuning(self.event_tuning.clone());
 
         cloud.glitchy = self.glitch_enabled;
         cloud.set_glitch_pct(self.glitch_pct / 100.0);
@@ -269,6 +331,11 @@ impl CloudConfig {
         if let Some(map) = self.monolith_density_map {
             cloud.set_monolith_density_map(Some(map));
         }
+        // 2D density mask: weights spawns per column and dims cells outside
+        // the shape, so a logo or word emerges from the rain.
+        if let Some(mask) = &self.density_mask {
+            cloud.set_density_mask(Some(mask.clone()), self.density_mask_floor);
+        }
 
         // v17 mastery: hover/click visual effects are ALWAYS ON (--mouse flag
         // deleted). Mouse reporting is also always on (terminal-level, blocks
@@ -279,8 +346,14 @@ impl CloudConfig {
         // temperature group system for palette drift.
         cloud.crystal_dragon = self.crystal_dragon;
         // crystal_dragon_sensor and crystal_dragon_control are already
-        // initialized in Cloud::new() with default config. Future CLI
-        // flags can override crystal_dragon_control here.
+        // initialized in Cloud::new() with default config; the calc method
+        // (`crystal-dragon-calc`) and sensor sources
+        // (`crystal-dragon-sensor`) are configurable.
+        cloud.crystal_dragon_control.calc_method = self.crystal_dragon_calc;
+        cloud.crystal_dragon_control.sensors = self.crystal_dragon_sensors;
+        cloud
+            .crystal_dragon_sensor
+            .set_sources(self.crystal_dragon_sensors);
 
         // v30 strengthen (Bug #4): if a custom palette is active, drift's
         // set_color_scheme would overwrite the user's custom palette with a
@@ -308,10 +381,12 @@ impl CloudConfig {
     pub fn clone_config(&self) -> Self {
         Self {
             color_mode: self.color_mode,
+            color_dither: self.color_dither,
             shading_mode: self.shading_mode,
             bold_mode: self.bold_mode,
             async_mode: self.async_mode,
             default_bg: self.default_bg,
+            terminal_bg: self.terminal_bg,
             color_scheme: self.color_scheme,
             custom_palette: self.custom_palette.clone(),
             custom_palette_name: self.custom_palette_name.clone(),
@@ -341,12 +416,22 @@ impl CloudConfig {
             bench_duration: self.bench_duration,
             screen_size: self.screen_size,
             color_tune: self.color_tune,
+            rain_tuning: self.rain_tuning,
+            event_tuning: self.event_tuning.clone(),
+            power_thresholds: self.power_thresholds,
+            keymap: self.keymap.clone(),
+            crystal_dragon_calc: self.crystal_dragon_calc,
+            crystal_dragon_sensors: self.crystal_dragon_sensors,
+            crystal_dragon_external: self.crystal_dragon_external.clone(),
             json: false,
             save_baseline: None,
             compare_baseline: None,
             bench_io: false,
             bench_all: false,
             bench_scene: None,
+            record: None,
+            control_socket: None,
+            seed: self.seed,
             verbose: false,
             density_auto: self.density_auto,
             base_density: self.base_density,
@@ -362,6 +447,8 @@ impl CloudConfig {
             power_dragon: self.power_dragon,
             msg_mode: self.msg_mode,
             monolith_density_map: self.monolith_density_map,
+            density_mask: self.density_mask.clone(),
+            density_mask_floor: self.density_mask_floor,
             config_path_for_watcher: None, // watcher only for interactive, not benchmark
             scene_name: self.scene_name.clone(),
             scene_custom_name: self.scene_custom_name.clone(),
@@ -76,6 +76,15 @@ COMMON OPTIONS:
       --uniform flag (removed in v50-beta.3).
       cosmostrix --async-mode false
 
+  --control-socket <PATH>
+      Listen on a Unix domain socket for runtime commands, one per line:
+      scene, color, charset <name|next|prev>; speed, density <n|up|down>;
+      message [text]; pause, resume, toggle-pause, reseed, quit; state
+      (one-line JSON); help. Each reply is one line: ok, error: ..., or
+      the state JSON. Commands behave exactly like their keys. The socket
+      is mode 0600 and removed on exit. Unix only.
+      cosmostrix --control-socket /run/user/1000/cosmostrix.sock
+
   --crystal-dragon <true|false>
       Crystal Dragon ambient color drift (default: false). When true,
       enables point-based temperature group system that drifts the
@@ -88,6 +97,14 @@ COMMON OPTIONS:
       at user-configured density/speed regardless of CPU pressure.
       cosmostrix --power-dragon false
 
+  --probe-terminal <true|false>
+      Query the terminal at startup (DA1/DA2, XTVERSION, DECRQM 2026,
+      kitty keyboard, DECRQSS, OSC 11/4; up to 300 ms) and trust the
+      replies over TERM/COLORTERM/TERM_PROGRAM. Helps inside tmux,
+      mosh and containers. --doctor shows the replies (default: false).
+      Config: probe-terminal = true
+      cosmostrix --doctor --probe-terminal true
+
   --msg-mode <true|false>
       Message overlay master switch (default: true). When false,
       disables BOTH the default message AND any config message/
@@ -216,7 +233,10 @@ COMMON OPTIONS:
       `base-scene`, missing fields fall back to the global default scene
       (cinematic). When active, the verbose output shows `scene: <name>`
       and live reload applies edits to the block immediately
-      (color/charset/speed/density/density-map/glitch-level/base-scene).
+      (color/charset/speed/density/density-map/density-mask/glitch-level/
+      base-scene). `density-mask` (text:<TEXT>, ASCII art or a PGM/PBM
+      file) shapes where rain falls and how bright it is, so a logo or
+      word emerges from the rain.
 
       cosmostrix --scene-custom hacker-mode
       cosmostrix --scene-custom nightcore --fps 60
@@ -236,6 +256,10 @@ CONFIG:
         %APPDATA%/cosmostrix/ or %ProgramData%/cosmostrix/ (Windows)
       Everything else is rejected (current directory, /tmp/, ~/, etc.).
       Must have .toml extension.
+      Layers: include = ['a.toml', ...] merges other files first (the
+      including file wins); conf.d/*.toml next to the config, then
+      ~/.config/cosmostrix/conf.d/*.toml, are merged last in name order.
+      Includes follow the same whitelist and .toml rules.
 
   --dump-config [path]
       Print a complete, commented example config and exit.
@@ -341,6 +365,39 @@ DIAGNOSTICS:
       \"leanax\", \"production-drawmadadadaxa\") are rejected with an error
       at parse time — never silently fallback'd to the default lean path.
       cosmostrix --benchmark --bench-io --bench-scene production-draw
+  --record <FILE>
+      Record the interactive session to an asciicast v2 (.cast) file:
+      the exact ANSI bytes of every flushed frame with timestamps, plus
+      resize events. Replay with `asciinema play FILE` or the asciinema
+      web player. Lossless and far smaller than a screen-recorded GIF.
+      The file is created or truncated, so like --save-baseline it must
+      live under ~/.config/cosmostrix/ or /etc/cosmostrix/. A write
+      failure stops recording (reported on exit) but never interrupts
+      the rain session. Output delivered through the /dev/tty fallback
+      after stdout breaks is recorded too.
+      cosmostrix --scene storm --record ~/.config/cosmostrix/storm.cast
+  --seed <U64>
+      Seed every RNG (droplets, gusts, ghost events, intro particles)
+      so two runs with the same seed, screen size and settings produce
+      the same frame sequence. Use with --screen-size to reproduce a
+      reported visual glitch. Default: unseeded (varies per run).
+      Config: seed = 42 (startup only; live reload keeps the seed)
+  --render-frames <N> --out <DIR>
+      Render N frames offline (no terminal needed) into DIR, one file
+      per frame (frame-00001.ppm, ...). Time advances exactly 1/--fps
+      per frame, so 600 frames at --fps 60 cover 10 simulated seconds
+      regardless of how long encoding takes. Deterministic: the seed
+      defaults to 0 unless --seed is given. Grid from --screen-size
+      (default 80x24). Only the rain is rendered (no intro or HUD).
+      cosmostrix --scene storm --render-frames 600 --out frames/
+  --render-format <ppm|ansi|text|stream>
+      Frame file format for --render-frames. ppm (default): P6 image
+      using a built-in 5x7 pixel font at 2x scale. ansi: full-frame
+      truecolor ANSI text (.ans). text: glyphs only (.txt). stream: no
+      files; --out names one target (file, FIFO, Unix socket, or - for
+      stdout) that receives the renderer's own byte stream: alt screen,
+      then one diff per frame, written as fast as the target reads.
+      cosmostrix --render-frames 600 --render-format stream --out - > rain.ans
   --save-baseline <path>
       Save benchmark JSON to a file (whitelist-enforced path, same as
       --config). Use to lock in a regression baseline for later diffing.
@@ -385,7 +442,16 @@ ADVANCED (intentionally not in clap's auto-list, but documented here — honest
   --color-bg <black|default-background>
       Background rendering mode. 'default-background' (default) follows
       the terminal emulator background; 'black' forces solid #000000.
+      On a light terminal background (queried via OSC 11 at startup)
+      the rain is redrawn dark-on-light: heads darkest, trails fading
+      toward the page.
       Config: color-bg = \"black\"
+  --color-dither <off|ordered|temporal>
+      On 256- and 16-color terminals, colors are computed in truecolor and
+      mapped to the nearest palette entry in OKLab. 'ordered' dithers
+      between the two nearest entries with a 4x4 Bayer pattern;
+      'temporal' also rotates the pattern every frame. Default: off.
+      Config: color-dither = \"ordered\"
   -M, --shadingmode <0|1>
       Shading mode (0=random, 1=cinematic [default]).
       Config: shadingmode = 1
@@ -444,6 +510,21 @@ RUNTIME CONTROLS:
                 See docs/HUD.md for what each line means, why `fps:` ≠ `--fps`,
                 and diagnostic recipes for common symptoms.
 
+  Rebinding: every key above can be changed in a [keys] table in
+  config.toml. Value: one key spec or an array of them. Listing an action
+  replaces its default key; one key bound to two actions is an error.
+    [keys]
+    quit = ['q', 'ctrl+q']
+    hud = 'f2'
+  Actions:   quit, pause, reseed, cycle-color, cycle-color-back,
+             cycle-charset, cycle-charset-back, cycle-scene,
+             cycle-scene-back (unbound by default), speed-up, speed-down,
+             density-up, density-down, hud
+  Key specs: a character (C = shift+c) or space, enter, tab, esc, up, down,
+             left, right, home, end, pageup, pagedown, insert, delete,
+             backspace, plus, comma, f1-f24; prefixes ctrl+ alt+ super+ shift+
+             Validated by --testconf; applied on live reload.
+
 HELP:
   -h, --help      Print this full reference manual.
   -V, --version    Print complete version and build information.
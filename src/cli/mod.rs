@@ -119,7 +119,12 @@ pub fn detect_color_mode_auto() -> ColorMode {
 
     let colorterm = env::var("COLORTERM").unwrap_or_default();
     let term = env::var("TERM").unwrap_or_default();
-    detect_color_mode_from_terms(&colorterm, &term)
+    let mode = detect_color_mode_from_terms(&colorterm, &term);
+    // `--probe-terminal`: the DECRQSS reply beats COLORTERM/TERM.
+    match crate::termdetect::probe_report() {
+        Some(report) => report.color_mode(mode),
+        None => mode,
+    }
 }
 
 pub fn detect_color_mode(args: &Args) -> ColorMode {
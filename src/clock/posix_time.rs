@@ -35,6 +35,9 @@
 /// handle the (extremely rare) case where `localtime_r` returns NULL.
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct LocalTm {
+    pub year: i32,  // full year (e.g. 2026)
+    pub month: i32, // 1..=12
+    pub day: i32,   // 1..=31
     pub hour: i32,
     pub minute: i32,
     pub second: i32,
@@ -62,17 +65,8 @@ pub(crate) struct UtcTm {
 #[must_use]
 pub(crate) fn local_tm() -> Option<LocalTm> {
     use std::mem::MaybeUninit;
-    use std::sync::OnceLock;
 
-    // Process-wide tzset() — safe, idempotent, µs-cost. Runs exactly once
-    // on first wall-clock query so subsequent calls reuse cached tzdata.
-    // Declared as direct extern because libc 0.2.x does not export tzset
-    // in the top-level namespace on all targets.
-    extern "C" {
-        fn tzset();
-    }
-    static TZ_INIT: OnceLock<()> = OnceLock::new();
-    TZ_INIT.get_or_init(|| unsafe { tzset() });
+    ensure_tzset();
 
     // SAFETY: libc::time(NULL) — writes nothing when pointer is NULL,
     // returns time_t or -1 on error. No preconditions.
@@ -92,6 +86,9 @@ pub(crate) fn local_tm() -> Option<LocalTm> {
     let tm = unsafe { tm.assume_init() };
 
     Some(LocalTm {
+        year: tm.tm_year + 1900,
+        month: tm.tm_mon + 1,
+        day: tm.tm_mday,
         hour: tm.tm_hour,
         minute: tm.tm_min,
         second: tm.tm_sec,
@@ -99,6 +96,46 @@ pub(crate) fn local_tm() -> Option<LocalTm> {
     })
 }
 
+/// Process-wide tzset() — safe, idempotent, µs-cost. Runs exactly once
+/// on first wall-clock query so subsequent calls reuse cached tzdata.
+#[cfg(unix)]
+fn ensure_tzset() {
+    use std::sync::OnceLock;
+
+    // Declared as direct extern because libc 0.2.x does not export tzset
+    // in the top-level namespace on all targets.
+    extern "C" {
+        fn tzset();
+    }
+    static TZ_INIT: OnceLock<()> = OnceLock::new();
+    TZ_INIT.get_or_init(|| unsafe { tzset() });
+}
+
+/// Local UTC offset in seconds (east positive) in effect at Unix time
+/// `t`, DST included — `tm_gmtoff` from `libc::localtime_r`. `0` when the
+/// conversion fails.
+#[cfg(unix)]
+#[must_use]
+pub(crate) fn utc_offset_secs_at(t: i64) -> i64 {
+    use std::mem::MaybeUninit;
+
+    ensure_tzset();
+    // time_t is 64-bit on every target this runs on; a 32-bit time_t
+    // only truncates past 2038.
+    let t = t as libc::time_t;
+    let mut tm: MaybeUninit<libc::tm> = MaybeUninit::uninit();
+    // SAFETY: as in `local_tm` — `t` is a valid time_t and the buffer is
+    // only read after localtime_r reports success.
+    if unsafe { libc::localtime_r(&t, tm.as_mut_ptr()) }.is_null() {
+        return 0;
+    }
+    // SAFETY: localtime_r returned non-NULL → tm fully initialized.
+    let gmtoff = unsafe { tm.assume_init() }.tm_gmtoff;
+    // c_long: i64 here, i32 on 32-bit targets.
+    #[allow(clippy::useless_conversion)]
+    i64::from(gmtoff)
+}
+
 /// Call `libc::time(NULL)` → `libc::gmtime_r`, returning parsed UTC fields.
 ///
 /// Returns a zeroed `UtcTm` on any failure (clock unavailable, gmtime_r fails).
@@ -157,7 +194,11 @@ pub(crate) fn local_tm() -> Option<LocalTm> {
     let min = ((secs / 60) % 60) as i32;
     let sec = (secs % 60) as i32;
     let yday = ((secs / 86_400) % 366) as i32;
+    let date = utc_tm();
     Some(LocalTm {
+        year: date.year,
+        month: date.month,
+        day: date.day,
         hour,
         minute: min,
         second: sec,
@@ -165,6 +206,12 @@ pub(crate) fn local_tm() -> Option<LocalTm> {
     })
 }
 
+#[cfg(not(unix))]
+pub(crate) fn utc_offset_secs_at(_t: i64) -> i64 {
+    // Matches `local_tm`: local time is UTC here.
+    0
+}
+
 #[cfg(not(unix))]
 pub(crate) fn utc_tm() -> UtcTm {
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -253,6 +300,8 @@ mod tests {
             "yday out of range: {}",
             tm.yday
         );
+        assert!((1..=12).contains(&tm.month), "month: {}", tm.month);
+        assert!((1..=31).contains(&tm.day), "day: {}", tm.day);
     }
 
     #[test]
@@ -31,14 +31,15 @@
 //! functions. This comment was rewritten in the Phase 5 config-sync audit to
 //! match the actual wiring.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
 use clap::parser::ValueSource;
 use clap::ValueEnum;
 
 use crate::charset::charset_from_str;
 use crate::cli::parse_color_scheme;
-use crate::config::{Args, ColorBg, GlitchLevel, IntroType};
+use crate::config::{Args, ColorBg, ColorDither, GlitchLevel, IntroType};
+use crate::config_schema::{get_str, ConfigMap};
 use crate::constants::{DENSITY_CLAMP_MAX, SPEED_MAX, SPEED_MIN};
 use crate::runtime::MonolithSize;
 use crate::scene::{get_scene, validate_scene_name, DEFAULT_SCENE};
@@ -82,7 +83,8 @@ pub(crate) fn apply_config_and_runtime_defaults(
     // read. Previously this used load_config_file (which drops malformed/
     // unknown) and then re-read + re-parsed the file at line 200 to recover
     // them — a redundant ~200μs disk read on every startup.
-    let parsed_cfg = crate::configfile::load_config_file_full(args.config.as_deref());
+    let layers = crate::configfile::load_config_layers(args.config.as_deref());
+    let parsed_cfg = layers.parsed;
     let cfg = parsed_cfg.values;
     if args.verbose {
         // Show the ACTUALLY-RESOLVED config path (with system fallback),
@@ -102,6 +104,15 @@ pub(crate) fn apply_config_and_runtime_defaults(
             "config loaded from: {config_path_display} ({} keys)",
             cfg.len()
         ));
+        // Includes and conf.d drop-ins, in merge order.
+        if layers.files.len() > 1 {
+            let files: Vec<String> = layers
+                .files
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect();
+            crate::output::eprintln_verbose_raw(&format!("config layers: {}", files.join(", ")));
+        }
         // List the actual keys so the user can see exactly what is set.
         // This is critical for debugging config issues — without this list,
         // the user only sees "(N keys)" and has to manually re-read the
@@ -123,7 +134,26 @@ pub(crate) fn apply_config_and_runtime_defaults(
     // Test bypass: COSMOSTRIX_SKIP_STARTUP_VALIDATION=1 skips this check
     // so existing tests that verify apply/fallback logic with invalid values
     // still work. Production builds never set this env var.
-    if !cfg.is_empty() && std::env::var("COSMOSTRIX_SKIP_STARTUP_VALIDATION").is_err() {
+    // A file whose only keys failed the type check (or whose includes all
+    // failed) parses to an empty map; it still has to be rejected.
+    let has_content = !cfg.is_empty()
+        || !parsed_cfg.type_errors.is_empty()
+        || !parsed_cfg.include_errors.is_empty();
+    if has_content && std::env::var("COSMOSTRIX_SKIP_STARTUP_VALIDATION").is_err() {
+        // Layer 0: include targets / conf.d drop-ins that could not be read
+        if !parsed_cfg.include_errors.is_empty() {
+            let errs: Vec<&str> = parsed_cfg
+                .include_errors
+                .iter()
+                .take(3)
+                .map(String::as_str)
+                .collect();
+            return Err(format!(
+                "error: invalid config — include error(s): {}\n\n  Fix the error above, or run 'cosmostrix --testconf' for details.",
+                errs.join("; ")
+            ));
+        }
+
         // Layer 1: malformed lines (stray text without 'key = value')
         if !parsed_cfg.malformed_lines.is_empty() {
             let lines: Vec<&str> = parsed_cfg
@@ -133,11 +163,25 @@ pub(crate) fn apply_config_and_runtime_defaults(
                 .map(String::as_str)
                 .collect();
             return Err(format!(
-                "error: invalid config — malformed line(s): '{}' (expected 'key = value' syntax)\n\n  Fix the error above, or run 'cosmostrix --testconf' for details.",
+                "error: invalid config — malformed line(s): '{}' (not valid TOML)\n\n  Fix the error above, or run 'cosmostrix --testconf' for details.",
                 lines.join(", ")
             ));
         }
 
+        // Layer 1b: known keys with the wrong TOML type (`fps = true`)
+        if !parsed_cfg.type_errors.is_empty() {
+            let errs: Vec<String> = parsed_cfg
+                .type_errors
+                .iter()
+                .take(3)
+                .map(|(key, msg)| format!("{key}: {msg}"))
+                .collect();
+            return Err(format!(
+                "error: invalid config — wrong value type: '{}'\n\n  Fix the error above, or run 'cosmostrix --testconf' for details.",
+                errs.join(", ")
+            ));
+        }
+
         // Layer 2: unknown keys (typos)
         if !parsed_cfg.unknown_keys.is_empty() {
             let keys: Vec<&str> = parsed_cfg
@@ -177,7 +221,7 @@ pub(crate) fn apply_config_and_runtime_defaults(
     let intro_color_value: Option<String> = args
         .intro_color
         .clone()
-        .or_else(|| cfg.get("intro-color").cloned());
+        .or_else(|| get_str(&cfg, "intro-color").map(str::to_string));
     if let Some(v) = intro_color_value {
         let theme_ok = crate::theme::lookup_theme(&v).is_some();
         let custom_ok = cfg.contains_key(&format!("colors-custom.{v}.bg"));
@@ -315,7 +359,7 @@ fn apply_default_scene_values(
 fn apply_config_values(
     matches: &clap::ArgMatches,
     args: &mut Args,
-    cfg: &HashMap<String, String>,
+    cfg: &ConfigMap,
     config_touched: &mut HashSet<&'static str>,
 ) {
     if let Some(v) = config_value(matches, cfg, "scene", "scene") {
@@ -460,6 +504,12 @@ fn apply_config_values(
             config_touched.insert("color_bg");
         }
     }
+    if let Some(v) = config_value(matches, cfg, "color_dither", "color-dither") {
+        if let Some(dither) = parse_color_dither_config(&v) {
+            args.color_dither = dither;
+            config_touched.insert("color_dither");
+        }
+    }
     // Crystal Dragon Engine config.
     // v50-beta.3: CLI --crystal-dragon=true|false wins over config.
     // Previously config-only; now both paths set args.crystal_dragon: Option<bool>.
@@ -469,6 +519,13 @@ fn apply_config_values(
             config_touched.insert("crystal_dragon");
         }
     }
+    // --probe-terminal wins over `probe-terminal =` (config_value's is_explicit).
+    if let Some(v) = config_value(matches, cfg, "probe_terminal", "probe-terminal") {
+        if let Some(b) = parse_bool_config("probe-terminal", &v) {
+            args.probe_terminal = Some(b);
+            config_touched.insert("probe_terminal");
+        }
+    }
     // v50-beta.3: power-dragon CLI flag now exists (--power-dragon=true|false).
     // CLI wins over config (handled by config_value's is_explicit check).
     // Default when neither CLI nor config provides a value: true (main.rs).
@@ -498,6 +555,75 @@ fn apply_config_values(
         }
     }
 
+    // --seed wins over `seed =` (config_value's is_explicit check).
+    if let Some(v) = config_value(matches, cfg, "seed", "seed") {
+        match v.trim().parse::<u64>() {
+            Ok(seed) => {
+                args.seed = Some(seed);
+                config_touched.insert("seed");
+            }
+            Err(_) => crate::output::eprintln_error_labeled(&format!(
+                "invalid seed='{v}' (expected: integer in range 0..={})",
+                u64::MAX
+            )),
+        }
+    }
+
+    // [rain-tuning]: config-only. An invalid field keeps its constant
+    // (startup validation normally rejects the file before this point).
+    let (rain_tuning, rain_tuning_errors) = crate::constants::rain_tuning_from_config(cfg);
+    for (key, msg) in rain_tuning_errors {
+        crate::output::eprintln_error_labeled(&format!("invalid {key}: {msg}"));
+    }
+    args.rain_tuning = rain_tuning;
+
+    // [events]: same contract as [rain-tuning].
+    let (event_tuning, event_errors) = crate::constants::event_tuning_from_config(cfg);
+    for (key, msg) in event_errors {
+        crate::output::eprintln_error_labeled(&format!("invalid {key}: {msg}"));
+    }
+    args.event_tuning = event_tuning;
+
+    // [power-dragon] section: same contract as [rain-tuning]. Independent
+    // of the bare `power-dragon = true|false` toggle parsed above.
+    let (power_thresholds, power_errors) = crate::constants::power_thresholds_from_config(cfg);
+    for (key, msg) in power_errors {
+        crate::output::eprintln_error_labeled(&format!("invalid {key}: {msg}"));
+    }
+    args.power_thresholds = power_thresholds;
+
+    // [keys] section: same contract — bad entries keep their default key.
+    let (keymap, key_errors) = crate::interactive::keymap::keymap_from_config(cfg);
+    for (key, msg) in key_errors {
+        crate::output::eprintln_error_labeled(&format!("invalid {key}: {msg}"));
+    }
+    args.keymap = keymap;
+
+    // crystal-dragon-calc: config-only selector for the drift calc method.
+    if let Some(v) = get_str(cfg, "crystal-dragon-calc") {
+        match crate::crystal_dragon_engine::crystal_dragon_control::CrystalDragonCalcMethod::from_config(v) {
+            Some(method) => args.crystal_dragon_calc = method,
+            None => crate::output::eprintln_error_labeled(&format!(
+                "invalid crystal-dragon-calc='{v}' (expected: v1 or v2)"
+            )),
+        }
+    }
+
+    // crystal-dragon-sensor / -mix: config-only; a bad value keeps the
+    // default like the sections above.
+    let (sensors, sensor_errors) =
+        crate::crystal_dragon_engine::crystal_dragon_control::sensors_from_config(cfg);
+    for (key, msg) in sensor_errors {
+        crate::output::eprintln_error_labeled(&format!("invalid {key}: {msg}"));
+    }
+    args.crystal_dragon_sensors = sensors;
+    let (external, external_errors) =
+        crate::crystal_dragon_engine::external::external_from_config(cfg);
+    for (key, msg) in external_errors {
+        crate::output::eprintln_error_labeled(&format!("invalid {key}: {msg}"));
+    }
+    args.crystal_dragon_external = external;
+
     // v50-beta.3: msg-mode gate (runs AFTER all message/msg-mode parsing).
     // Rule: if msg-mode=false AND message came from config (not CLI -m/-mb),
     // clear it. CLI -m/-mb always wins (we don't touch message when it was
@@ -600,7 +726,7 @@ fn apply_glitch_level_values(
     // Phase 5 closure (P2-3): RECLASSIFIED as false positive. The deprecated
     // glitch flags (--glitchpct / --shortpct / --rippct / --maxdpc) were
     // removed in v17 — they are `#[arg(skip = ...)]` in config.rs and NOT in
-    // USER_CONFIG_KEYS, so users cannot set them via CLI or config.toml. The
+    // config_schema::ROOT_KEYS, so users cannot set them via CLI or config.toml. The
     // glitch_pct/shortpct/rippct fields are internal-only, set exclusively by
     // glitch_level presets below. No silent override is possible — the
     // original Phase 2 P2-3 finding described a v16-era scenario that no
@@ -664,14 +790,14 @@ fn apply_glitch_level_values(
 
 fn config_value(
     matches: &clap::ArgMatches,
-    cfg: &HashMap<String, String>,
+    cfg: &ConfigMap,
     arg_id: &str,
     config_key: &str,
 ) -> Option<String> {
     if is_explicit(matches, arg_id) {
         None
     } else {
-        cfg.get(config_key).cloned()
+        get_str(cfg, config_key).map(str::to_string)
     }
 }
 
@@ -768,3 +894,20 @@ fn parse_color_bg_config(value: &str) -> Option<ColorBg> {
         }
     }
 }
+
+pub(crate) fn parse_color_dither(value: &str) -> Result<ColorDither, String> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "off" | "none" => Ok(ColorDither::Off),
+        "ordered" => Ok(ColorDither::Ordered),
+        "temporal" => Ok(ColorDither::Temporal),
+        _ => Err(format!(
+            "invalid color-dither='{value}' (allowed: off, ordered, temporal)"
+        )),
+    }
+}
+
+fn parse_color_dither_config(value: &str) -> Option<ColorDither> {
+    parse_color_dither(value)
+        .map_err(|msg| crate::output::eprintln_error_labeled(&msg))
+        .ok()
+}
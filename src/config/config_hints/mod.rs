@@ -3,7 +3,7 @@
 
 //! "Did you mean …" hints for unknown config keys.
 //!
-//! The TOML parser in [`crate::configfile`] classifies any key not matching
+//! The key schema in [`crate::config_schema`] classifies any key not matching
 //! a known pattern as `unknown_keys`. Previously the only follow-up was a
 //! generic `(run 'cosmostrix --testconf' for known keys)` line, which
 //! doesn't help when the user has nested a key under the wrong section
@@ -30,7 +30,7 @@
 //! returns `Some`. Keys with no recognized pattern get the original
 //! generic "run --testconf" message unchanged.
 
-use crate::configfile::USER_CONFIG_KEYS;
+use crate::config_schema;
 
 /// Returns a targeted "did you mean" hint for known-bad key patterns, or
 /// `None` for keys with no recognized structural mistake.
@@ -52,7 +52,7 @@ pub(crate) fn suggest_for_unknown_key(key: &str) -> Option<String> {
 
     // Pattern 1: a top-level key accidentally nested under [color.tune].
     // Triggered by `color.tune.<suffix>` where `<suffix>` is a recognized
-    // top-level USER_CONFIG_KEYS entry (e.g. `color.tune.bold`).
+    // top-level schema key (e.g. `color.tune.bold`).
     if let Some(suffix) = key.strip_prefix("color.tune.") {
         if !suffix.is_empty() && is_top_level_user_key(suffix) {
             let mut hint = format!(
@@ -178,9 +178,18 @@ pub(crate) fn suggest_for_unknown_key(key: &str) -> Option<String> {
         ));
     }
 
+    // Pattern 7: malformed `ambient.[<days>.]<HH-MM>` key. The parse
+    // error already says which part is wrong (bad time, unknown day
+    // selector, reversed date range), which beats a generic typo hint.
+    if key.starts_with("ambient.") {
+        if let Err(msg) = crate::crystal_dragon_engine::ambient::parse_ambient_key(key) {
+            return Some(msg);
+        }
+    }
+
     // Pattern 4 (bug #13): top-level key typo. If the unknown key
     // is a simple word (no dots) that is edit-distance ≤ 2 from a known
-    // top-level USER_CONFIG_KEYS entry, suggest the closest match. This
+    // top-level schema key, suggest the closest match. This
     // catches common typos like `collor` → `color`, `speeed` → `speed`,
     // `densit` → `density`, `charaset` → `charset`, etc.
     //
@@ -232,7 +241,7 @@ pub(crate) fn suggest_for_unknown_key(key: &str) -> Option<String> {
     None
 }
 
-/// Find the closest match in `USER_CONFIG_KEYS` to `input` using edit distance.
+/// Find the closest root key in the config schema to `input` using edit distance.
 /// Returns `Some(suggestion)` if the best match has edit distance ≤ 2, or
 /// `None` if no key is close enough (avoiding false positives for keys that
 /// are genuinely unrelated).
@@ -240,7 +249,7 @@ pub(crate) fn suggest_for_unknown_key(key: &str) -> Option<String> {
 fn closest_top_level_key(input: &str) -> Option<&'static str> {
     let input_lower = input.to_ascii_lowercase();
     let mut best: Option<(&'static str, usize)> = None;
-    for &candidate in USER_CONFIG_KEYS.iter() {
+    for candidate in config_schema::root_keys() {
         let dist = edit_distance(&input_lower, candidate);
         // Only accept if distance ≤ 2 AND the candidate is at least 3 chars
         // (avoiding false matches for very short keys like `fps`).
@@ -281,11 +290,9 @@ fn edit_distance(a: &str, b: &str) -> usize {
 }
 
 /// Returns `true` if `field` is a recognized colors-custom field name.
-/// Mirrors `configfile::is_valid_colors_custom_field` (which is private).
-/// Kept in sync via tests in this module.
 #[inline]
 fn is_valid_colors_custom_field_str(field: &str) -> bool {
-    matches!(field, "bg" | "rain" | "stops")
+    config_schema::is_valid_colors_custom_field(field)
 }
 
 /// Build a targeted hint for an invalid `colors-custom.<name>.<field>` key.
@@ -359,7 +366,7 @@ pub(crate) fn format_hints_block(keys: &[String]) -> String {
 /// Used to distinguish `color.tune.bold` (real key, wrong location) from
 /// `color.tune.foobar` (genuine typo, no useful hint).
 fn is_top_level_user_key(candidate: &str) -> bool {
-    USER_CONFIG_KEYS.contains(&candidate)
+    config_schema::is_root_key(candidate)
 }
 
 #[cfg(test)]
@@ -119,9 +119,9 @@ fn adaptive_custom_at_top_level_returns_none() {
     // v30 (2026-08-05, atmosphere elimination): `adaptive-custom.10-00`
     // at file root is no longer a valid key — it would be caught by
     // is_known_key("adaptive-custom") returning false (the bare key is
-    // still in USER_CONFIG_KEYS for the validate_field_value migration
+    // still in config_schema::ROOT_KEYS for the validate_field_value migration
     // message, but `adaptive-custom.10-00` has the HH-MM suffix and is
-    // not in USER_CONFIG_KEYS). The hint function only handles the
+    // not in config_schema::ROOT_KEYS). The hint function only handles the
     // scene-custom nested pattern; the root-scope `adaptive-custom.10-00`
     // case is handled by the unknown_keys + validate_field_value path,
     // not by suggest_for_unknown_key. So this returns None here.
@@ -242,7 +242,10 @@ fn parse_scene_custom_section_with_nested_adaptive_custom_promotes_to_root() {
     );
     // `color = cosmos` was promoted to root scope.
     assert_eq!(
-        parsed.values.get("color").map(String::as_str),
+        parsed
+            .values
+            .get("color")
+            .and_then(crate::config_schema::ConfigValue::as_str),
         Some("cosmos")
     );
     // Hint still fires on the would-be-nested form (for testconf display).
@@ -495,7 +498,7 @@ fn snake_case_monolith_size_suggests_kebab_case() {
 
 #[test]
 fn random_underscore_key_without_kebab_match_gets_no_snake_hint() {
-    // `foo_bar` is not a snake_case form of any USER_CONFIG_KEYS entry,
+    // `foo_bar` is not a snake_case form of any config_schema::ROOT_KEYS entry,
     // so the snake_case hint should NOT fire (falls through to pattern 4
     // typo check, which also won't match because edit distance is too far).
     let hint = suggest_for_unknown_key("foo_bar");
@@ -0,0 +1,257 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Layered config: `include = [...]` and `conf.d/` drop-in directories.
+//!
+//! The effective config is merged from several files, a later file winning
+//! key by key:
+//!
+//! 1. The main file (`config.toml`, the system-wide fallback, or
+//!    `--config <path>`). Files it lists in `include = [...]` are merged
+//!    *before* its own keys, so the including file overrides what it pulls
+//!    in (a shared palette / scene pack, then personal tweaks).
+//! 2. `*.toml` in the `conf.d/` directory next to the main file, in lexical
+//!    order (`10-palette.toml` before `20-scenes.toml`).
+//! 3. `*.toml` in the user `conf.d/` (`~/.config/cosmostrix/conf.d/`), when
+//!    that is a different directory. With only
+//!    `/etc/cosmostrix/config.toml` installed, personal drop-ins still land
+//!    on top of it.
+//!
+//! Drop-ins may use `include` too. Relative include paths are resolved
+//! against the including file's directory; `~/` is expanded. Each file is
+//! read at most once, includes nest up to [`MAX_INCLUDE_DEPTH`] levels and
+//! a cycle is an error. Every path has to pass the same `safepath`
+//! whitelist as `--config` and end in `.toml`; anything that cannot be
+//! layered in is reported in `include_errors` and rejects the config like a
+//! malformed line.
+//!
+//! [`ConfigLayers::files`] lists every file that was read, so the live
+//! reload watcher can watch all of them (plus the `conf.d/` directories,
+//! for drop-ins created later).
+
+use std::path::{Path, PathBuf};
+
+use crate::configfile::{self, ParsedConfig};
+use crate::constants::CONF_D_DIR_NAME;
+
+/// Deepest `include` chain followed (main file = depth 0).
+pub(crate) const MAX_INCLUDE_DEPTH: usize = 8;
+
+/// Result of [`load`]: the merged config plus where it came from.
+#[derive(Debug, Default)]
+pub(crate) struct ConfigLayers {
+    /// All layers merged. `malformed_lines` and `legacy_notes` from files
+    /// other than the main one are prefixed with the file path.
+    pub parsed: ParsedConfig,
+    /// Every file that was read, in merge order (the main file first when
+    /// it exists, even though its includes are merged before it).
+    pub files: Vec<PathBuf>,
+    /// `conf.d/` directories consulted, whether or not they exist.
+    pub conf_d_dirs: Vec<PathBuf>,
+}
+
+/// Load `main` with its includes and drop-ins. A missing main file is not
+/// an error; the drop-ins are still merged.
+#[must_use]
+pub(crate) fn load(main: &Path) -> ConfigLayers {
+    load_with(main, &conf_d_dirs(main), crate::safepath::is_safe_path)
+}
+
+/// `conf.d/` next to `main`, then the user `conf.d/` if it differs.
+#[must_use]
+pub(crate) fn conf_d_dirs(main: &Path) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(parent) = main.parent() {
+        dirs.push(parent.join(CONF_D_DIR_NAME));
+    }
+    if let Some(user_dir) = configfile::default_config_file_path().parent() {
+        let user = user_dir.join(CONF_D_DIR_NAME);
+        if !dirs.contains(&user) {
+            dirs.push(user);
+        }
+    }
+    dirs
+}
+
+/// [`load`] with explicit drop-in directories and path policy (tests use a
+/// temp dir outside the whitelist).
+#[must_use]
+pub(crate) fn load_with(
+    main: &Path,
+    conf_d: &[PathBuf],
+    is_allowed: fn(&str) -> bool,
+) -> ConfigLayers {
+    let mut loader = Loader {
+        is_allowed,
+        layers: ConfigLayers {
+            conf_d_dirs: conf_d.to_vec(),
+            ..ConfigLayers::default()
+        },
+        chain: Vec::new(),
+    };
+    if let Ok(content) = std::fs::read_to_string(main) {
+        loader.layer(main, &content, None);
+    }
+    for dir in conf_d {
+        for path in drop_in_files(dir) {
+            loader.drop_in(&path);
+        }
+    }
+    loader.layers
+}
+
+/// `*.toml` files directly in `dir`, sorted by name. Hidden files (editor
+/// lock and swap files) are skipped.
+#[must_use]
+pub(crate) fn drop_in_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| is_drop_in_name(path) && path.is_file())
+        .collect();
+    files.sort();
+    files
+}
+
+/// `true` for a path a `conf.d/` scan would pick up, by name alone.
+#[must_use]
+pub(crate) fn is_drop_in_name(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+    !name.starts_with('.') && name.ends_with(".toml")
+}
+
+struct Loader {
+    is_allowed: fn(&str) -> bool,
+    layers: ConfigLayers,
+    /// Files currently being included, outermost first.
+    chain: Vec<PathBuf>,
+}
+
+impl Loader {
+    fn drop_in(&mut self, path: &Path) {
+        if self.layers.files.iter().any(|f| f == path) {
+            return;
+        }
+        if !(self.is_allowed)(&path.to_string_lossy()) {
+            self.error(format!(
+                "{}: outside the allowed config directories",
+                path.display()
+            ));
+            return;
+        }
+        match std::fs::read_to_string(path) {
+            Ok(content) => self.layer(path, &content, Some(path)),
+            Err(e) => self.error(format!("{}: cannot read: {e}", path.display())),
+        }
+    }
+
+    /// Merge one file: its includes first, then its own keys. `label` is
+    /// `None` for the main file.
+    fn layer(&mut self, path: &Path, content: &str, label: Option<&Path>) {
+        self.layers.files.push(path.to_path_buf());
+        let mut parsed = configfile::parse_config_text(content);
+        let includes = std::mem::take(&mut parsed.includes);
+        self.chain.push(path.to_path_buf());
+        for raw in &includes {
+            self.include(path, raw);
+        }
+        self.chain.pop();
+        merge(&mut self.layers.parsed, parsed, label);
+    }
+
+    fn include(&mut self, from: &Path, raw: &str) {
+        let written = crate::safepath::expand_user_path(raw);
+        let target = lexical_clean(&if written.is_absolute() {
+            written
+        } else {
+            from.parent().unwrap_or(Path::new("")).join(written)
+        });
+        let at = format!("{}: include '{raw}'", from.display());
+        if !(self.is_allowed)(&target.to_string_lossy()) {
+            self.error(format!("{at} is outside the allowed config directories"));
+            return;
+        }
+        if target.extension().and_then(|e| e.to_str()) != Some("toml") {
+            self.error(format!("{at} must be a .toml file"));
+            return;
+        }
+        if let Some(start) = self.chain.iter().position(|f| *f == target) {
+            let cycle: Vec<String> = self.chain[start..]
+                .iter()
+                .chain([&target])
+                .map(|f| display_name(f))
+                .collect();
+            self.error(format!("{at} is an include cycle ({})", cycle.join(" -> ")));
+            return;
+        }
+        if self.chain.len() > MAX_INCLUDE_DEPTH {
+            self.error(format!(
+                "{at} nests deeper than {MAX_INCLUDE_DEPTH} includes"
+            ));
+            return;
+        }
+        // Already merged through another path: once is enough.
+        if self.layers.files.contains(&target) {
+            return;
+        }
+        match std::fs::read_to_string(&target) {
+            Ok(content) => self.layer(&target, &content, Some(&target)),
+            Err(e) => self.error(format!("{at}: cannot read {}: {e}", target.display())),
+        }
+    }
+
+    fn error(&mut self, msg: String) {
+        self.layers.parsed.include_errors.push(msg);
+    }
+}
+
+/// Fold `from` into `into`; `from`'s values win.
+fn merge(into: &mut ParsedConfig, from: ParsedConfig, label: Option<&Path>) {
+    let tag = |entry: String| match label {
+        Some(path) => format!("{}: {entry}", path.display()),
+        None => entry,
+    };
+    into.values.extend(from.values);
+    into.unknown_keys.extend(from.unknown_keys);
+    into.malformed_lines
+        .extend(from.malformed_lines.into_iter().map(tag));
+    into.type_errors.extend(from.type_errors);
+    into.promoted_keys.extend(from.promoted_keys);
+    into.legacy_notes
+        .extend(from.legacy_notes.into_iter().map(tag));
+    into.include_errors.extend(from.include_errors);
+}
+
+/// Resolve `.` and `..` without touching the filesystem, so one file
+/// reached as `a/../b.toml` and `b.toml` is recognised as the same file.
+fn lexical_clean(path: &Path) -> PathBuf {
+    use std::path::Component;
+    let mut out = PathBuf::new();
+    for part in path.components() {
+        match part {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !out.pop() {
+                    out.push(part);
+                }
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+fn display_name(path: &Path) -> String {
+    path.file_name().map_or_else(
+        || path.display().to_string(),
+        |n| n.to_string_lossy().into_owned(),
+    )
+}
+
+#[cfg(test)]
+mod tests;
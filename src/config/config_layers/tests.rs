@@ -0,0 +1,197 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Include + `conf.d/` layering tests.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::*;
+
+static DIR_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Fresh empty directory per test (tests run in parallel).
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "cosmostrix-layers-{}-{name}-{}",
+        std::process::id(),
+        DIR_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write(path: &Path, content: &str) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+fn allow_all(_: &str) -> bool {
+    true
+}
+
+fn get<'a>(layers: &'a ConfigLayers, key: &str) -> Option<&'a str> {
+    layers
+        .parsed
+        .values
+        .get(key)
+        .and_then(crate::config_schema::ConfigValue::as_str)
+}
+
+#[test]
+fn includes_merge_before_the_including_file() {
+    let dir = scratch_dir("include-order");
+    write(
+        &dir.join("team.toml"),
+        "color = \"purple\"\nfps = 30\n\n[colors-custom.team]\nrain = [\"#112233\", \"#445566\"]\n",
+    );
+    write(
+        &dir.join("config.toml"),
+        "include = [\"team.toml\"]\nfps = 60\n",
+    );
+    let layers = load_with(&dir.join("config.toml"), &[], allow_all);
+    assert!(
+        layers.parsed.include_errors.is_empty(),
+        "{:?}",
+        layers.parsed.include_errors
+    );
+    assert_eq!(get(&layers, "fps"), Some("60"), "including file wins");
+    assert_eq!(get(&layers, "color"), Some("purple"));
+    assert!(layers.parsed.values.contains_key("colors-custom.team.rain"));
+    assert_eq!(
+        layers.files,
+        vec![dir.join("config.toml"), dir.join("team.toml")]
+    );
+    assert!(layers.parsed.unknown_keys.is_empty());
+}
+
+#[test]
+fn conf_d_merges_on_top_in_lexical_order() {
+    let dir = scratch_dir("conf-d");
+    let conf_d = dir.join("conf.d");
+    write(&dir.join("config.toml"), "fps = 60\nspeed = 10\n");
+    write(&conf_d.join("20-late.toml"), "fps = 25\n");
+    write(
+        &conf_d.join("10-early.toml"),
+        "fps = 40\nscene = \"calm\"\n",
+    );
+    write(&conf_d.join(".#10-early.toml"), "fps = 1\n");
+    write(&conf_d.join("notes.txt"), "fps = 2\n");
+    let layers = load_with(
+        &dir.join("config.toml"),
+        std::slice::from_ref(&conf_d),
+        allow_all,
+    );
+    assert_eq!(get(&layers, "fps"), Some("25"));
+    assert_eq!(get(&layers, "speed"), Some("10"));
+    assert_eq!(get(&layers, "scene"), Some("calm"));
+    assert_eq!(
+        layers.files,
+        vec![
+            dir.join("config.toml"),
+            conf_d.join("10-early.toml"),
+            conf_d.join("20-late.toml"),
+        ]
+    );
+}
+
+#[test]
+fn conf_d_applies_without_a_main_file() {
+    let dir = scratch_dir("no-main");
+    let conf_d = dir.join("conf.d");
+    write(&conf_d.join("override.toml"), "density = 0.5\n");
+    let layers = load_with(&dir.join("config.toml"), &[conf_d], allow_all);
+    assert_eq!(get(&layers, "density"), Some("0.5"));
+    assert!(layers.parsed.include_errors.is_empty());
+}
+
+#[test]
+fn include_cycles_and_missing_files_are_errors() {
+    let dir = scratch_dir("cycle");
+    write(
+        &dir.join("config.toml"),
+        "include = [\"a.toml\", \"gone.toml\"]\n",
+    );
+    write(
+        &dir.join("a.toml"),
+        "include = \"./sub/../config.toml\"\nfps = 50\n",
+    );
+    let layers = load_with(&dir.join("config.toml"), &[], allow_all);
+    let errors = &layers.parsed.include_errors;
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    assert!(
+        errors[0].contains("include cycle (config.toml -> a.toml -> config.toml)"),
+        "{errors:?}"
+    );
+    assert!(
+        errors[1].contains("include 'gone.toml': cannot read"),
+        "{errors:?}"
+    );
+    // The rest still merges, so --testconf can check it.
+    assert_eq!(get(&layers, "fps"), Some("50"));
+}
+
+#[test]
+fn include_paths_must_be_whitelisted_toml_files() {
+    fn only_inside_allowed(path: &str) -> bool {
+        path.contains("/allowed/")
+    }
+    let dir = scratch_dir("whitelist").join("allowed");
+    write(&dir.join("secret.txt"), "fps = 1\n");
+    write(
+        &dir.join("config.toml"),
+        "include = [\"../outside.toml\", \"secret.txt\"]\n",
+    );
+    let layers = load_with(&dir.join("config.toml"), &[], only_inside_allowed);
+    let errors = &layers.parsed.include_errors;
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    assert!(
+        errors[0].ends_with("include '../outside.toml' is outside the allowed config directories")
+    );
+    assert!(errors[1].ends_with("include 'secret.txt' must be a .toml file"));
+    assert!(layers.parsed.values.is_empty());
+}
+
+#[test]
+fn include_depth_is_bounded() {
+    let dir = scratch_dir("depth");
+    for n in 0..=MAX_INCLUDE_DEPTH + 1 {
+        write(
+            &dir.join(format!("{n}.toml")),
+            &format!("include = \"{}.toml\"\n", n + 1),
+        );
+    }
+    let layers = load_with(&dir.join("0.toml"), &[], allow_all);
+    let errors = &layers.parsed.include_errors;
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(errors[0].contains("nests deeper than"), "{errors:?}");
+    assert_eq!(layers.files.len(), MAX_INCLUDE_DEPTH + 1);
+}
+
+#[test]
+fn diagnostics_from_other_files_name_the_file() {
+    let dir = scratch_dir("labels");
+    let conf_d = dir.join("conf.d");
+    write(&dir.join("config.toml"), "not toml\n");
+    write(&conf_d.join("x.toml"), "also not toml\nscene = calm\n");
+    let layers = load_with(
+        &dir.join("config.toml"),
+        std::slice::from_ref(&conf_d),
+        allow_all,
+    );
+    let malformed = &layers.parsed.malformed_lines;
+    assert_eq!(malformed.len(), 2, "{malformed:?}");
+    assert!(malformed[0].starts_with("not toml  # ERROR: line 1"));
+    let drop_in = conf_d.join("x.toml").display().to_string();
+    assert!(malformed[1].starts_with(&format!("{drop_in}: also not toml")));
+    assert!(layers.parsed.legacy_notes[0].starts_with(&format!("{drop_in}: line 2")));
+}
+
+#[test]
+fn include_key_type_is_checked() {
+    let parsed = crate::configfile::parse_config_text("include = 3\n");
+    assert_eq!(parsed.type_errors.len(), 1);
+    assert_eq!(parsed.type_errors[0].0, "include");
+    assert!(parsed.unknown_keys.is_empty());
+}
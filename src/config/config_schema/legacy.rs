@@ -0,0 +1,262 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Legacy syntax accepted when the TOML parser rejects a file.
+//!
+//! Before `config.toml` went through a spec-compliant parser it was read by
+//! a line scanner that took a few liberties TOML does not. Files in the
+//! wild (and older `--dump-config` templates) rely on them. A file that
+//! parses as TOML is used as written; only when it does not does
+//! [`normalize`] rewrite it line by line into valid TOML:
+//!
+//! - **bare values** — `scene = calm` becomes `scene = "calm"`. Anything
+//!   that already parses as a TOML value (`60`, `true`, `0.75`) is left
+//!   alone. Each rewrite is recorded so `--testconf` can suggest quoting.
+//! - **scalar next to a table of the same name** — `color = "green"` with a
+//!   `[color.tune]` section becomes `color.name = "green"`, and
+//!   `power-dragon = true` with a `[power-dragon]` section becomes
+//!   `enabled = true` under that header (see
+//!   [`super::SCALAR_TABLE_ALIASES`]).
+//! - **unquoted `#` inside an array** (bug #7) — `rain = [#ff0000, ...]`
+//!   is reported as malformed with a quoting hint and dropped. TOML would
+//!   read the rest of the line as a comment and report an error on some
+//!   later line instead.
+//!
+//! Comment-only lines are blanked (TOML rejects control characters even in
+//! comments, the old scanner never looked at them). Lines inside
+//! multi-line strings and arrays are passed through untouched.
+
+use std::collections::{HashMap, HashSet};
+
+use super::SCALAR_TABLE_ALIASES;
+use crate::configfile::{strip_inline_comment, unquoted_hash_inside_array};
+
+/// Output of [`normalize`]: TOML text as lines, plus what was changed.
+#[derive(Debug, Default)]
+pub(crate) struct Normalized {
+    pub lines: Vec<String>,
+    /// 1-based line in the user's file for each entry of `lines`.
+    pub origin: Vec<usize>,
+    /// One entry per rewrite the user may want to fix by hand.
+    pub notes: Vec<String>,
+    /// `(line, entry)` for lines rejected before parsing, in
+    /// `malformed_lines` format.
+    pub malformed: Vec<(usize, String)>,
+    /// `full dotted key → key as written` for every `key = value` line
+    /// under a `[section]` header, lowercased. Auto-promotion only ever
+    /// re-homes a key to what the user typed on the line.
+    pub scoped_keys: HashMap<String, String>,
+}
+
+/// Root-scope `key = value` line whose key is also a table name.
+struct RootScalar {
+    index: usize,
+    key: String,
+    field: &'static str,
+    value: String,
+}
+
+/// Rewrite legacy syntax in `content` into TOML. See the module docs.
+#[must_use]
+pub(crate) fn normalize(content: &str) -> Normalized {
+    scan(content, true)
+}
+
+/// [`Normalized::scoped_keys`] for a file that is already valid TOML
+/// (nothing is rewritten).
+#[must_use]
+pub(crate) fn scoped_keys(content: &str) -> HashMap<String, String> {
+    scan(content, false).scoped_keys
+}
+
+fn scan(content: &str, rewrite: bool) -> Normalized {
+    let mut out = Normalized::default();
+    let mut open_brackets: i32 = 0;
+    let mut multiline_string: Option<&'static str> = None;
+    // Current `[section]`, normalized; empty at root.
+    let mut section = String::new();
+    // `[name]` header → output index, for inserting aliased scalars.
+    let mut headers: Vec<(String, usize)> = Vec::new();
+    // First segment of every header and root-level dotted key.
+    let mut table_roots: HashSet<String> = HashSet::new();
+    let mut root_scalars: Vec<RootScalar> = Vec::new();
+
+    for (n, line) in content.lines().enumerate() {
+        let index = out.lines.len();
+        out.lines.push(line.to_string());
+        out.origin.push(n + 1);
+
+        if let Some(delim) = multiline_string {
+            if line.contains(delim) {
+                multiline_string = None;
+            }
+            continue;
+        }
+        let code = strip_inline_comment(line).trim();
+        if code.is_empty() {
+            if rewrite {
+                out.lines[index].clear();
+            }
+            continue;
+        }
+        if open_brackets > 0 {
+            open_brackets += bracket_delta(code);
+            continue;
+        }
+
+        if code.starts_with('[') {
+            let name = code.trim_matches(|c| c == '[' || c == ']').trim();
+            table_roots.insert(first_segment(name).to_string());
+            headers.push((name.to_string(), index));
+            // `[]` is a parse error; TOML keeps the previous table open.
+            if !name.is_empty() {
+                section = dotted_lower(name);
+            }
+            continue;
+        }
+
+        let Some((key, value)) = split_entry(code) else {
+            continue;
+        };
+        let key = key.trim();
+        let value = value.trim();
+
+        if rewrite
+            && value.starts_with('[')
+            && !value.ends_with(']')
+            && unquoted_hash_inside_array(line).is_some()
+        {
+            out.malformed.push((
+                n + 1,
+                format!("{code}  # ERROR: unquoted '#' inside array — quote hex values (e.g. \"#ff0000\") or remove the '#'"),
+            ));
+            out.lines[index].clear();
+            continue;
+        }
+
+        let mut value = value.to_string();
+        if rewrite && !key.is_empty() && is_bare_value(&value) {
+            let quoted = format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""));
+            out.notes.push(format!(
+                "line {}: unquoted value for '{key}' read as the string {quoted} (TOML needs quotes)",
+                n + 1
+            ));
+            out.lines[index] = format!("{key} = {quoted}");
+            value = quoted;
+        }
+
+        for delim in ["\"\"\"", "'''"] {
+            if value.starts_with(delim) && value.matches(delim).count() == 1 {
+                multiline_string = Some(delim);
+            }
+        }
+        open_brackets += bracket_delta(&value);
+
+        if !section.is_empty() && !key.is_empty() {
+            let written = dotted_lower(key);
+            out.scoped_keys
+                .insert(format!("{section}.{written}"), written);
+        } else {
+            if key.contains('.') {
+                table_roots.insert(first_segment(key).to_string());
+            } else if let Some(&(_, field)) = SCALAR_TABLE_ALIASES
+                .iter()
+                .find(|(k, _)| rewrite && *k == key)
+            {
+                root_scalars.push(RootScalar {
+                    index,
+                    key: key.to_string(),
+                    field,
+                    value,
+                });
+            }
+        }
+    }
+
+    // Scalar + table collisions. Rewrites in place keep every index valid;
+    // insertions after a `[key]` header go last, bottom-up.
+    let mut inserts: Vec<(usize, String, usize)> = Vec::new();
+    for scalar in root_scalars {
+        if !table_roots.contains(&scalar.key) {
+            continue;
+        }
+        let RootScalar {
+            index,
+            key,
+            field,
+            value,
+        } = scalar;
+        match headers.iter().find(|(name, _)| *name == key) {
+            Some(&(_, header)) => {
+                out.lines[index].clear();
+                inserts.push((header + 1, format!("{field} = {value}"), out.origin[index]));
+            }
+            None => out.lines[index] = format!("{key}.{field} = {value}"),
+        }
+    }
+    inserts.sort_by_key(|insert| std::cmp::Reverse(insert.0));
+    for (at, line, origin) in inserts {
+        out.lines.insert(at, line);
+        out.origin.insert(at, origin);
+    }
+    out
+}
+
+/// `key = value` split at the first `=` outside a quoted key, so
+/// `"odd=key" = 1` keeps its key whole.
+fn split_entry(code: &str) -> Option<(&str, &str)> {
+    let (i, _) = unquoted(code).find(|&(_, ch)| ch == '=')?;
+    Some((&code[..i], &code[i + 1..]))
+}
+
+/// A value the old scanner accepted unquoted that TOML would reject.
+fn is_bare_value(value: &str) -> bool {
+    !value.is_empty()
+        && !value.starts_with(['"', '\'', '[', '{'])
+        && format!("v = {value}").parse::<toml::Table>().is_err()
+}
+
+/// `A . "b"` → `a.b`.
+fn dotted_lower(key: &str) -> String {
+    key.split('.')
+        .map(|part| {
+            part.trim()
+                .trim_matches(|c| c == '"' || c == '\'')
+                .to_ascii_lowercase()
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn first_segment(dotted: &str) -> &str {
+    dotted.split('.').next().unwrap_or(dotted).trim()
+}
+
+/// Net `[`/`{` minus `]`/`}` outside quotes.
+fn bracket_delta(code: &str) -> i32 {
+    unquoted(code)
+        .map(|(_, ch)| match ch {
+            '[' | '{' => 1,
+            ']' | '}' => -1,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// `(byte index, char)` of every character of `code` outside a basic or
+/// literal string (the quotes themselves excluded).
+fn unquoted(code: &str) -> impl Iterator<Item = (usize, char)> + '_ {
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    code.char_indices().filter(move |&(_, ch)| {
+        match quote {
+            Some('"') if escaped => escaped = false,
+            Some('"') if ch == '\\' => escaped = true,
+            Some(q) if ch == q => quote = None,
+            Some(_) => {}
+            None if matches!(ch, '"' | '\'') => quote = Some(ch),
+            None => return true,
+        }
+        false
+    })
+}
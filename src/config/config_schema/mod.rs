@@ -0,0 +1,674 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Typed key schema for `config.toml`.
+//!
+//! `configfile::parse_config_text` hands the file to the `toml` crate and
+//! then walks the resulting table through this module. Every key the
+//! program understands is registered here with the TOML value type it
+//! takes ([`ValueKind`]); the walk flattens nested tables into the dotted
+//! keys the rest of the program reads (`[color.tune] brightness = 1.2` →
+//! `color.tune.brightness`), type-checks each leaf into a [`ConfigValue`],
+//! and sorts the rest into `unknown_keys` / `promoted_keys`.
+//!
+//! `configfile`, `testconf` and `config_hints` all answer "is this a key?"
+//! from this one registry, so a new key is added in exactly one place.
+//!
+//! ## Types
+//!
+//! A leaf of the wrong TOML type (`fps = true`, `color = ["a"]`) lands in
+//! `type_errors` instead of `values`. A quoted string is accepted for every
+//! scalar kind and handed to the value validators as written — older
+//! templates quoted numbers (`fps = "60"`) and `async-mode = "on"` has
+//! always been valid. Range and enum checks stay in `testconf`.
+//!
+//! Arrays stay arrays: a list key arrives as [`ConfigValue::List`] (or
+//! [`ConfigValue::Numbers`]) and its readers take the items as they are.
+//! The comma-separated string form (`sensor = "cpu, clock"`) is split here,
+//! once, so no reader parses list text.
+//!
+//! ## Scalar + table pairs
+//!
+//! `color` is both a root key and the parent of `[color.tune]`, and
+//! `power-dragon` is both a toggle and the `[power-dragon]` thresholds
+//! section. TOML cannot hold both under one name, so the scalar may also be
+//! written inside the table ([`SCALAR_TABLE_ALIASES`]):
+//!
+//! ```toml
+//! [color]
+//! name = "green"          # same as root-level `color = "green"`
+//!
+//! [power-dragon]
+//! enabled = true          # same as root-level `power-dragon = true`
+//! ```
+//!
+//! The legacy pre-pass ([`legacy`]) rewrites the old side-by-side form into
+//! this one, so existing files keep loading.
+
+pub(crate) mod legacy;
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::configfile::ParsedConfig;
+
+/// TOML value type a config key takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ValueKind {
+    /// String (numbers and booleans are accepted and stringified).
+    Text,
+    /// Boolean, or `1`/`0`, or one of the lenient strings (`on`, `yes`, ...).
+    Bool,
+    /// Integer.
+    Integer,
+    /// Integer or float.
+    Number,
+    /// Array of scalars (a comma-separated string is also accepted).
+    List,
+    /// Array of numbers (a comma-separated string is also accepted).
+    Numbers,
+}
+
+impl ValueKind {
+    fn describe(self) -> &'static str {
+        match self {
+            ValueKind::Text => "a string",
+            ValueKind::Bool => "a boolean",
+            ValueKind::Integer => "an integer",
+            ValueKind::Number => "a number",
+            ValueKind::List => "an array",
+            ValueKind::Numbers => "an array of numbers",
+        }
+    }
+}
+
+/// One flattened config value, shaped by its key's [`ValueKind`].
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ConfigValue {
+    /// Any scalar kind, as written: strings unquoted, numbers and booleans
+    /// in their TOML form. The value validators parse it.
+    Scalar(String),
+    /// [`ValueKind::List`] items, stringified the same way.
+    List(Vec<String>),
+    /// [`ValueKind::Numbers`] items.
+    Numbers(Vec<f64>),
+}
+
+impl ConfigValue {
+    /// The text of a scalar; `None` for an array.
+    #[must_use]
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            ConfigValue::Scalar(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// The items of a [`ConfigValue::List`]; a scalar is a one-item list.
+    #[must_use]
+    pub(crate) fn items(&self) -> Option<&[String]> {
+        match self {
+            ConfigValue::Scalar(text) => Some(std::slice::from_ref(text)),
+            ConfigValue::List(items) => Some(items),
+            ConfigValue::Numbers(_) => None,
+        }
+    }
+
+    /// The items of a [`ConfigValue::Numbers`].
+    #[must_use]
+    pub(crate) fn numbers(&self) -> Option<&[f64]> {
+        match self {
+            ConfigValue::Numbers(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for ConfigValue {
+    fn from(text: &str) -> Self {
+        ConfigValue::Scalar(text.to_string())
+    }
+}
+
+impl From<String> for ConfigValue {
+    fn from(text: String) -> Self {
+        ConfigValue::Scalar(text)
+    }
+}
+
+/// TOML spelling, for messages: `green`, `["#ff0000", "#00ff00"]`,
+/// `[0.35, 1, 1.7]`.
+impl fmt::Display for ConfigValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigValue::Scalar(text) => f.write_str(text),
+            ConfigValue::List(items) => {
+                let items: Vec<String> = items.iter().map(|item| format!("{item:?}")).collect();
+                write!(f, "[{}]", items.join(", "))
+            }
+            ConfigValue::Numbers(items) => {
+                let items: Vec<String> = items.iter().map(f64::to_string).collect();
+                write!(f, "[{}]", items.join(", "))
+            }
+        }
+    }
+}
+
+/// The flattened config: dotted key → typed value.
+pub(crate) type ConfigMap = HashMap<String, ConfigValue>;
+
+/// The text of scalar key `key`, or `None` when it is absent (or, for a
+/// list key, not a scalar — lists are read through [`ConfigValue::items`]).
+#[must_use]
+pub(crate) fn get_str<'a>(cfg: &'a ConfigMap, key: &str) -> Option<&'a str> {
+    cfg.get(key).and_then(ConfigValue::as_str)
+}
+
+/// Root-scope keys and their types.
+pub(crate) const ROOT_KEYS: &[(&str, ValueKind)] = &[
+    ("scene", ValueKind::Text),
+    ("color", ValueKind::Text),
+    ("charset", ValueKind::Text),
+    ("fps", ValueKind::Integer),
+    ("speed", ValueKind::Integer),
+    ("density", ValueKind::Number),
+    ("monolith-size", ValueKind::Text),
+    ("glitch-level", ValueKind::Text),
+    ("bold", ValueKind::Integer),
+    ("shadingmode", ValueKind::Integer),
+    ("color-bg", ValueKind::Text),
+    // Dither for 256/16-color quantization: "off" (default), "ordered",
+    // "temporal". Startup-only.
+    ("color-dither", ValueKind::Text),
+    ("crystal-dragon", ValueKind::Bool),
+    // Crystal Dragon theme selection method: "v1" (weighted, default) or
+    // "v2" (state machine with memory). Config-only.
+    ("crystal-dragon-calc", ValueKind::Text),
+    // Crystal Dragon point sources: one or more of cpu, clock, loadavg,
+    // psi-cpu, psi-memory, psi-io, memory, battery; mixed by max or mean.
+    ("crystal-dragon-sensor", ValueKind::List),
+    ("crystal-dragon-sensor-mix", ValueKind::Text),
+    // Feed for the `external` sensor: a file/FIFO path or `command:<cmd>`.
+    ("crystal-dragon-external", ValueKind::Text),
+    // Observer position for solar `ambient.*` rules (`ambient.sunset+30m`):
+    // "<lat>, <lon>" or a two-number array.
+    ("ambient-location", ValueKind::Numbers),
+    // Crossfade length between ambient scenes ("90s", "2m"); absent = instant.
+    ("ambient-transition", ValueKind::Text),
+    // v50: Power Dragon toggle. When false, disables aggressive_throttle
+    // and idle FPS reduction — rain stays at user-configured density/speed
+    // regardless of CPU pressure. Default: true (protection enabled).
+    ("power-dragon", ValueKind::Bool),
+    // Active terminal probe at startup (default: false). Startup-only.
+    ("probe-terminal", ValueKind::Bool),
+    ("async-mode", ValueKind::Bool),
+    // (CLI-D-1 fix): `adaptive-custom` removed from this whitelist.
+    // The atmosphere engine was eliminated at commit 07b44b5 (2026-08-05),
+    // and `config_hints.rs` + `testconf.rs` both explicitly reject
+    // `adaptive-custom.*` keys with "have been removed" messages. The bare
+    // key falls into `unknown_keys` and triggers the startup rejection in
+    // `config_apply.rs`.
+    // v20: Cinematic intro selector. Values: "logo" | "cosmic" | "none".
+    // Default: "logo". CLI --intro flag wins over this config key.
+    ("intro", ValueKind::Text),
+    // v50: Intro color override. Allows the intro animation to use a
+    // different color theme than the rain. Values: any builtin theme
+    // name (e.g. "energy-zen", "neon-green") or custom palette name
+    // (e.g. "cyberpunk_2077"). Default: same as --color (rain color).
+    ("intro-color", ValueKind::Text),
+    // v50: Overlay message text. Two config keys mirror the CLI flags:
+    //   message         = "text"  → message WITHOUT border (matches -m)
+    //   message-border  = "text"  → message WITH border    (matches -mb)
+    // If both are present in config, `message-border` wins (border=true).
+    // CLI -m / -mb wins over either config key. When none of CLI/config
+    // provides a message, interactive mode defaults to a bordered overlay
+    // showing the project name (see main.rs CloudConfig construction).
+    // Benchmark mode never shows a message overlay.
+    ("message", ValueKind::Text),
+    ("message-border", ValueKind::Text),
+    // v50-beta.3: msg-mode master switch for the message overlay subsystem.
+    // When false, disables BOTH the default message AND any message/
+    // message-border config key (config-only message is suppressed).
+    // CLI -m / -mb always wins over msg-mode=false (CLI precedence).
+    // Default: true (message overlay active).
+    ("msg-mode", ValueKind::Bool),
+    // RNG seed for reproducible runs (u64). CLI --seed wins. Startup-only:
+    // live reload keeps the session seed (re-seeding mid-run would not
+    // reproduce anything).
+    ("seed", ValueKind::Integer),
+];
+
+/// `(root key, field)` pairs where `[<root key>] <field> = v` stands for
+/// the root-level scalar `<root key> = v`. See the module docs.
+pub(crate) const SCALAR_TABLE_ALIASES: &[(&str, &str)] =
+    &[("color", "name"), ("power-dragon", "enabled")];
+
+const SCENE_CUSTOM_CONFIG_KEY_HINT: &str = "scene-custom.<name>.<base-scene|color|charset|bold|colors-custom|charset-custom|shadingmode|glitch-level|fps|speed|density|density-map|density-mask|density-mask-floor|async-mode>";
+const COLORS_CUSTOM_CONFIG_KEY_HINT: &str = "colors-custom.<name>.<bg|rain|stops>";
+const CHARSET_CUSTOM_CONFIG_KEY_HINT: &str = "charset-custom.<name>.set";
+const COLOR_TUNE_CONFIG_KEY_HINT: &str = "color.tune.<brightness|saturation|head|body|tail>";
+/// Ambient phase scheduler: `ambient.[<days>.]<HH-MM> = <scene-name>`, or a
+/// solar event (`sunset+30m`) in place of `HH-MM`.
+///
+/// simplified — value is a single scene name (built-in OR custom).
+/// Config-only (no CLI flag). Time-of-day phase entries that switch the
+/// active scene at scheduled times. Switches instantly unless
+/// `ambient-transition` sets a crossfade window for speed, density and
+/// glitch (the palette wipes in over the same window). Dynamic idle/wake scheduler thread — zero CPU between phase boundaries.
+/// See `src/crystal_dragon_engine/ambient.rs` and `src/crystal_dragon_engine/ambient_scheduler.rs`.
+const AMBIENT_CONFIG_KEY_HINT: &str =
+    "ambient.[<mon..sun|weekdays|weekends|MM-DD|YYYY-MM-DD|<from>..<to>>.]<HH-MM|<dawn|sunrise|noon|sunset|dusk>[±<N>h<N>m]> = <scene-name>";
+
+/// Root key listing other config files to merge in; not a value key (see
+/// [`crate::config_layers`]).
+pub(crate) const INCLUDE_KEY: &str = "include";
+const INCLUDE_CONFIG_KEY_HINT: &str = "include = [\"<file>.toml\", ...]";
+
+/// Root key names, in registry order.
+pub(crate) fn root_keys() -> impl Iterator<Item = &'static str> {
+    ROOT_KEYS.iter().map(|(key, _)| *key)
+}
+
+/// `true` for a root-scope key (`fps`, `color`, ...).
+#[must_use]
+pub(crate) fn is_root_key(key: &str) -> bool {
+    ROOT_KEYS.iter().any(|(k, _)| *k == key)
+}
+
+/// `<prefix><a|b|c>` over a section's field table.
+fn section_hint<'a>(prefix: &str, fields: impl IntoIterator<Item = &'a str>) -> String {
+    let fields: Vec<&str> = fields.into_iter().collect();
+    format!("{prefix}<{}>", fields.join("|"))
+}
+
+/// Every root key plus one `<pattern>` line per section, for the
+/// `--testconf` "known keys" listing. Sections with a field table list
+/// it; the rest use a fixed hint.
+#[must_use]
+pub(crate) fn known_keys() -> Vec<String> {
+    let rain_tuning = section_hint(
+        crate::constants::RAIN_TUNING_PREFIX,
+        crate::constants::RAIN_TUNING_FIELDS.iter().map(|f| f.name),
+    );
+    let power_dragon = section_hint(
+        crate::constants::POWER_DRAGON_PREFIX,
+        crate::constants::power_dragon_field_names(),
+    );
+    // `events.<kind>.<field>` for every kind, plus the decrypt word list.
+    let events = {
+        use crate::constants::{EventKind, DECRYPT_WORDS_FIELD, EVENTS_PREFIX, EVENT_FIELDS};
+        format!(
+            "{}{}, {EVENTS_PREFIX}{}.{DECRYPT_WORDS_FIELD}",
+            section_hint(EVENTS_PREFIX, EventKind::ALL.map(EventKind::name)),
+            section_hint(".", EVENT_FIELDS.iter().map(|(f, _, _)| *f)),
+            EventKind::Decrypt.name(),
+        )
+    };
+    let keys = section_hint(
+        crate::interactive::keymap::KEYS_PREFIX,
+        crate::interactive::keymap::KeyAction::ALL.map(|a| a.name()),
+    );
+    root_keys()
+        .map(str::to_string)
+        .chain(
+            [
+                SCENE_CUSTOM_CONFIG_KEY_HINT,
+                COLORS_CUSTOM_CONFIG_KEY_HINT,
+                CHARSET_CUSTOM_CONFIG_KEY_HINT,
+                COLOR_TUNE_CONFIG_KEY_HINT,
+            ]
+            .map(str::to_string),
+        )
+        .chain([rain_tuning, events, power_dragon, keys])
+        .chain([AMBIENT_CONFIG_KEY_HINT, INCLUDE_CONFIG_KEY_HINT].map(str::to_string))
+        .collect()
+}
+
+/// The value type of `key` (flattened, lowercase), or `None` for an
+/// unknown key.
+#[must_use]
+pub(crate) fn kind_of(key: &str) -> Option<ValueKind> {
+    if let Some(&(_, kind)) = ROOT_KEYS.iter().find(|(k, _)| *k == key) {
+        return Some(kind);
+    }
+    if crate::scene_custom::is_scene_custom_config_key(key) {
+        // Scene fields share their root key's type; the block-reference
+        // fields (`base-scene`, `colors-custom`, ...) and `density-map`
+        // are plain strings.
+        let field = key.rsplit_once('.').map_or(key, |(_, field)| field);
+        return Some(root_kind(field).unwrap_or(ValueKind::Text));
+    }
+    if is_colors_custom_key(key) {
+        return Some(if key.ends_with(".bg") {
+            ValueKind::Text
+        } else {
+            ValueKind::List
+        });
+    }
+    if is_charset_custom_key(key)
+        || crate::crystal_dragon_engine::ambient::is_ambient_config_key(key)
+    {
+        return Some(ValueKind::Text);
+    }
+    if is_color_tune_key(key) {
+        return Some(ValueKind::Number);
+    }
+    // Section fields take their type from their own field tables.
+    if let Some(field) = crate::constants::rain_tuning_field(key) {
+        return Some(field.kind());
+    }
+    if let Some(kind) = crate::constants::event_value_kind(key) {
+        return Some(kind);
+    }
+    if let Some(field) = crate::constants::power_dragon_field(key) {
+        return Some(field.kind());
+    }
+    if crate::interactive::keymap::is_keys_key(key) {
+        // One key spec or an array of them.
+        return Some(ValueKind::List);
+    }
+    None
+}
+
+fn root_kind(key: &str) -> Option<ValueKind> {
+    ROOT_KEYS
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|&(_, kind)| kind)
+}
+
+/// v17: Check if key matches `color.tune.<field>` pattern.
+#[inline]
+fn is_color_tune_key(key: &str) -> bool {
+    matches!(
+        key,
+        "color.tune.brightness"
+            | "color.tune.saturation"
+            | "color.tune.head"
+            | "color.tune.body"
+            | "color.tune.tail"
+    )
+}
+
+/// Check if `key` matches `colors-custom.<name>.<field>` pattern.
+/// Recognized fields: `bg`, `rain` (canonical), `stops` (deprecated alias for `rain`).
+/// Invalid fields surface as `unknown_keys` so `config_hints` can attach a hint.
+/// Name must be non-empty, ASCII alphanumeric + `-`/`_` only.
+fn is_colors_custom_key(key: &str) -> bool {
+    let Some(rest) = key.strip_prefix("colors-custom.") else {
+        return false;
+    };
+    // Must have at least name.field (2+ segments after the prefix).
+    let Some((name, field)) = rest.split_once('.') else {
+        return false;
+    };
+    if name.is_empty() || !is_valid_custom_name(name) {
+        return false;
+    }
+    is_valid_colors_custom_field(field)
+}
+
+/// Check if a custom palette name is valid (non-empty, alphanumeric + -/_).
+#[inline]
+fn is_valid_custom_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Check if a colors-custom field name is recognized.
+///
+/// (bug #8): tightened from `bg | background | rain` to
+/// `bg | rain | stops`. `background` (undocumented alias) was removed —
+/// use `bg`. `stops` is a deprecated alias for `rain` (still accepted,
+/// `--testconf` emits a deprecation warning). Brings the key-checker in
+/// sync with `validate_colors_custom_value`, which already handled `.stops`.
+#[inline]
+pub(crate) fn is_valid_colors_custom_field(field: &str) -> bool {
+    matches!(field, "bg" | "rain" | "stops")
+}
+
+/// Check if `key` matches `charset-custom.<name>.set` pattern.
+/// v25: replaces legacy `--charset-file <PATH>` CLI flag. Only `set`
+/// field is recognized. Name must be non-empty, ASCII alphanumeric +
+/// `-`/`_` only (same rule as `colors-custom`).
+#[inline]
+fn is_charset_custom_key(key: &str) -> bool {
+    let Some(rest) = key.strip_prefix("charset-custom.") else {
+        return false;
+    };
+    let Some((name, field)) = rest.split_once('.') else {
+        return false;
+    };
+    if name.is_empty() || !is_valid_custom_name(name) {
+        return false;
+    }
+    field == "set"
+}
+
+/// Map `[color] name` / `[power-dragon] enabled` back to their root key.
+fn canonical_key(key: String) -> String {
+    for (root, field) in SCALAR_TABLE_ALIASES {
+        if key.strip_prefix(root).and_then(|r| r.strip_prefix('.')) == Some(field) {
+            return (*root).to_string();
+        }
+    }
+    key
+}
+
+/// Flatten a parsed TOML document into `parsed`.
+///
+/// Keys are lowercased. Known leaves are type-checked and stored as
+/// [`ConfigValue`]s.
+///
+/// An unknown key written under a `[section]` header whose as-written form
+/// is a known key (`intro = "cosmic"` below `[scene-custom.x]`, TOML
+/// scoping) is auto-promoted to that key; `scoped_keys` comes from
+/// [`legacy::normalize`]. An explicitly written key always wins over a
+/// promoted one.
+pub(crate) fn flatten_into(
+    table: &toml::Table,
+    scoped_keys: &HashMap<String, String>,
+    parsed: &mut ParsedConfig,
+) {
+    let mut promotions = Vec::new();
+    walk(table, "", scoped_keys, parsed, &mut promotions);
+    for (from, to, value) in promotions {
+        if let Some(value) = value {
+            parsed.values.entry(to.clone()).or_insert(value);
+        }
+        parsed.promoted_keys.push((from, to));
+    }
+}
+
+fn walk(
+    table: &toml::Table,
+    prefix: &str,
+    scoped_keys: &HashMap<String, String>,
+    parsed: &mut ParsedConfig,
+    promotions: &mut Vec<(String, String, Option<ConfigValue>)>,
+) {
+    for (raw_key, value) in table {
+        let lower = raw_key.to_ascii_lowercase();
+        let key = canonical_key(if prefix.is_empty() {
+            lower
+        } else {
+            format!("{prefix}.{lower}")
+        });
+        if key == INCLUDE_KEY {
+            collect_includes(value, parsed);
+            continue;
+        }
+        // Tables always recurse: their leaves are what gets classified.
+        if let toml::Value::Table(inner) = value {
+            walk(inner, &key, scoped_keys, parsed, promotions);
+            continue;
+        }
+        if let Some(kind) = kind_of(&key) {
+            match typed(kind, value) {
+                Ok(value) => {
+                    parsed.values.insert(key, value);
+                }
+                Err(msg) => parsed.type_errors.push((key, msg)),
+            }
+            continue;
+        }
+        let target = scoped_keys
+            .get(&key)
+            .map(|written| canonical_key(written.clone()))
+            .and_then(|target| kind_of(&target).map(|kind| (target, kind)));
+        let Some((target, kind)) = target else {
+            parsed.unknown_keys.push(key);
+            continue;
+        };
+        // A promoted key of the wrong type is still listed as promoted, so
+        // `--testconf` says where it went as well as what is wrong with it.
+        let value = match typed(kind, value) {
+            Ok(value) => Some(value),
+            Err(msg) => {
+                parsed.type_errors.push((key.clone(), msg));
+                None
+            }
+        };
+        promotions.push((key, target, value));
+    }
+}
+
+/// Root `include = "a.toml"` or `include = ["a.toml", "b.toml"]`.
+fn collect_includes(value: &toml::Value, parsed: &mut ParsedConfig) {
+    let expected = "expected a file name or an array of file names";
+    match value {
+        toml::Value::String(path) => parsed.includes.push(path.clone()),
+        toml::Value::Array(items) => {
+            for item in items {
+                match item {
+                    toml::Value::String(path) => parsed.includes.push(path.clone()),
+                    other => parsed.type_errors.push((
+                        INCLUDE_KEY.to_string(),
+                        format!("{expected}, got {} inside the array", type_name(other)),
+                    )),
+                }
+            }
+        }
+        other => parsed.type_errors.push((
+            INCLUDE_KEY.to_string(),
+            format!("{expected}, got {}", type_name(other)),
+        )),
+    }
+}
+
+/// Type one leaf as `kind`, or explain why its TOML type does not fit.
+fn typed(kind: ValueKind, value: &toml::Value) -> Result<ConfigValue, String> {
+    use toml::Value;
+    let fits = match value {
+        Value::String(_) => true,
+        Value::Integer(_) => !matches!(kind, ValueKind::List | ValueKind::Numbers),
+        Value::Float(_) => matches!(kind, ValueKind::Text | ValueKind::Number),
+        Value::Boolean(_) => matches!(kind, ValueKind::Text | ValueKind::Bool),
+        Value::Array(_) => matches!(kind, ValueKind::List | ValueKind::Numbers),
+        Value::Datetime(_) | Value::Table(_) => false,
+    };
+    if !fits {
+        return Err(mismatch(kind, value));
+    }
+    let items: Vec<Value> = match value {
+        Value::Array(items) => items.clone(),
+        // The comma-separated form of a list.
+        Value::String(text) if matches!(kind, ValueKind::List | ValueKind::Numbers) => text
+            .split(',')
+            .map(|item| Value::String(item.trim().to_string()))
+            .collect(),
+        other => return Ok(ConfigValue::Scalar(scalar(other))),
+    };
+    if kind == ValueKind::Numbers {
+        return items
+            .iter()
+            .map(|item| {
+                number(item).ok_or_else(|| {
+                    format!(
+                        "expected an array of numbers, got {} ({item}) inside the array",
+                        type_name(item)
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()
+            .map(ConfigValue::Numbers);
+    }
+    items
+        .iter()
+        .map(|item| match item {
+            Value::String(_) | Value::Integer(_) | Value::Float(_) | Value::Boolean(_) => {
+                Ok(scalar(item))
+            }
+            other => Err(format!(
+                "expected an array of strings or numbers, got {} inside the array",
+                type_name(other)
+            )),
+        })
+        .collect::<Result<_, _>>()
+        .map(ConfigValue::List)
+}
+
+/// A number, or a string that holds one (older templates quoted numbers).
+fn number(item: &toml::Value) -> Option<f64> {
+    match item {
+        toml::Value::Integer(n) => Some(*n as f64),
+        toml::Value::Float(n) => Some(*n),
+        toml::Value::String(text) => text.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+fn scalar(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn type_name(value: &toml::Value) -> &'static str {
+    match value {
+        toml::Value::String(_) => "a string",
+        toml::Value::Integer(_) => "an integer",
+        toml::Value::Float(_) => "a float",
+        toml::Value::Boolean(_) => "a boolean",
+        toml::Value::Datetime(_) => "a date/time",
+        toml::Value::Array(_) => "an array",
+        toml::Value::Table(_) => "a table",
+    }
+}
+
+fn mismatch(kind: ValueKind, value: &toml::Value) -> String {
+    let got = type_name(value);
+    match value {
+        toml::Value::Array(_) | toml::Value::Table(_) => {
+            format!("expected {}, got {got}", kind.describe())
+        }
+        toml::Value::Datetime(_) => format!(
+            "expected {}, got {got} ({value}) — quote it to use it as text",
+            kind.describe()
+        ),
+        _ => format!("expected {}, got {got} ({value})", kind.describe()),
+    }
+}
+
+/// `key = <text>` typed the way the walk types it: `[..]` text is read as
+/// a TOML array, a list key splits anything else on commas, and a scalar
+/// key (or text that does not type) stays a scalar. Lets tests build a
+/// [`ConfigMap`] from string pairs.
+#[cfg(test)]
+pub(crate) fn value_from_text(key: &str, text: &str) -> ConfigValue {
+    let value = text
+        .starts_with('[')
+        .then(|| format!("v = {text}").parse::<toml::Table>().ok())
+        .flatten()
+        .and_then(|mut table| table.remove("v"))
+        .unwrap_or_else(|| toml::Value::String(text.to_string()));
+    kind_of(key)
+        .and_then(|kind| typed(kind, &value).ok())
+        .unwrap_or_else(|| ConfigValue::from(text))
+}
+
+#[cfg(test)]
+mod tests;
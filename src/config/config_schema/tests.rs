@@ -0,0 +1,362 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Key schema + TOML parse pipeline tests.
+
+use super::*;
+use crate::configfile::parse_config_text;
+
+#[test]
+fn every_root_key_has_a_kind() {
+    for key in root_keys() {
+        assert!(kind_of(key).is_some(), "{key}");
+        assert!(is_root_key(key));
+    }
+    assert_eq!(kind_of("fps"), Some(ValueKind::Integer));
+    assert_eq!(kind_of("density"), Some(ValueKind::Number));
+    assert_eq!(kind_of("msg-mode"), Some(ValueKind::Bool));
+    assert_eq!(kind_of("scene-custom.foo.fps"), Some(ValueKind::Integer));
+    assert_eq!(
+        kind_of("scene-custom.foo.base-scene"),
+        Some(ValueKind::Text)
+    );
+    assert_eq!(kind_of("colors-custom.zen.rain"), Some(ValueKind::List));
+    assert_eq!(kind_of("rain-tuning.fog-rows"), Some(ValueKind::Integer));
+    assert_eq!(
+        kind_of("rain-tuning.parallax-speed"),
+        Some(ValueKind::Numbers)
+    );
+    assert_eq!(
+        kind_of("rain-tuning.phosphor-layer-decay"),
+        Some(ValueKind::Numbers)
+    );
+    assert_eq!(
+        kind_of("rain-tuning.fog-min-factor"),
+        Some(ValueKind::Number)
+    );
+    assert_eq!(kind_of("events.tear.chance"), Some(ValueKind::Number));
+    assert_eq!(kind_of("events.decrypt.words"), Some(ValueKind::List));
+    assert_eq!(kind_of("events.tear.words"), None);
+    assert_eq!(
+        kind_of("power-dragon.self-heal-downgrade"),
+        Some(ValueKind::Bool)
+    );
+    assert_eq!(
+        kind_of("power-dragon.xtermjs-window-frames"),
+        Some(ValueKind::Integer)
+    );
+    assert_eq!(
+        kind_of("power-dragon.self-heal-pressure-low"),
+        Some(ValueKind::Number)
+    );
+    assert_eq!(kind_of("power-dragon.turbo"), None);
+    assert_eq!(kind_of("ambient.06-00"), Some(ValueKind::Text));
+    assert_eq!(kind_of("colro"), None);
+}
+
+#[test]
+fn spec_toml_features_flatten_to_dotted_keys() {
+    let parsed = parse_config_text(
+        r##"
+scene = 'cinematic'
+message = "say \"hi\"\tnow"
+color.tune = { brightness = 1.2, tail = 0.5 }
+
+[scene-custom.hacker-mode]
+speed = 28
+density-map = '0.5,1.0'
+
+[colors-custom.zen]
+bg = "#0a0a0a"
+rain = [
+  "#1a0033", # deep
+  '#ffffff',
+]
+
+[rain-tuning]
+parallax-speed = [0.35, 1, 1.7]
+"##,
+    );
+    assert!(
+        parsed.malformed_lines.is_empty(),
+        "{:?}",
+        parsed.malformed_lines
+    );
+    assert!(parsed.unknown_keys.is_empty(), "{:?}", parsed.unknown_keys);
+    assert!(parsed.type_errors.is_empty(), "{:?}", parsed.type_errors);
+    let get = |k: &str| parsed.values.get(k).and_then(ConfigValue::as_str);
+    assert_eq!(get("scene"), Some("cinematic"));
+    assert_eq!(get("message"), Some("say \"hi\"\tnow"));
+    assert_eq!(get("color.tune.brightness"), Some("1.2"));
+    assert_eq!(get("color.tune.tail"), Some("0.5"));
+    assert_eq!(get("scene-custom.hacker-mode.speed"), Some("28"));
+    assert_eq!(get("scene-custom.hacker-mode.density-map"), Some("0.5,1.0"));
+    assert_eq!(
+        parsed.values["colors-custom.zen.rain"],
+        ConfigValue::List(vec!["#1a0033".to_string(), "#ffffff".to_string()])
+    );
+    assert_eq!(
+        parsed.values["rain-tuning.parallax-speed"],
+        ConfigValue::Numbers(vec![0.35, 1.0, 1.7])
+    );
+    assert!(parsed.legacy_notes.is_empty(), "{:?}", parsed.legacy_notes);
+}
+
+#[test]
+fn wrong_toml_type_is_a_type_error_not_a_value() {
+    let parsed = parse_config_text(
+        "fps = true\ncolor = [\"green\"]\ndensity = 0.5\nspeed = 7.5\nambient.06-00 = 06:00\n",
+    );
+    let keys: Vec<&str> = parsed.type_errors.iter().map(|(k, _)| k.as_str()).collect();
+    assert_eq!(keys, ["fps", "color", "speed", "ambient.06-00"]);
+    assert!(parsed.type_errors[0]
+        .1
+        .contains("expected an integer, got a boolean"));
+    assert!(parsed.type_errors[3].1.contains("quote it"));
+    assert_eq!(parsed.values.len(), 1);
+    assert_eq!(
+        parsed.values.get("density").and_then(ConfigValue::as_str),
+        Some("0.5")
+    );
+}
+
+#[test]
+fn quoted_scalars_and_lenient_bools_still_fit() {
+    let parsed = parse_config_text("fps = \"60\"\nasync-mode = \"on\"\nmsg-mode = 0\n");
+    assert!(parsed.type_errors.is_empty(), "{:?}", parsed.type_errors);
+    assert_eq!(
+        parsed.values.get("fps").and_then(ConfigValue::as_str),
+        Some("60")
+    );
+    assert_eq!(
+        parsed
+            .values
+            .get("async-mode")
+            .and_then(ConfigValue::as_str),
+        Some("on")
+    );
+    assert_eq!(
+        parsed.values.get("msg-mode").and_then(ConfigValue::as_str),
+        Some("0")
+    );
+}
+
+#[test]
+fn scalar_table_aliases_map_to_root_keys() {
+    let parsed = parse_config_text(
+        "[color]\nname = \"green\"\n\n[color.tune]\nhead = 1.5\n\n[power-dragon]\nenabled = false\nidle-fps-factor = 0.25\n",
+    );
+    assert!(
+        parsed.malformed_lines.is_empty(),
+        "{:?}",
+        parsed.malformed_lines
+    );
+    assert!(parsed.unknown_keys.is_empty(), "{:?}", parsed.unknown_keys);
+    assert_eq!(
+        parsed.values.get("color").and_then(ConfigValue::as_str),
+        Some("green")
+    );
+    assert_eq!(
+        parsed
+            .values
+            .get("power-dragon")
+            .and_then(ConfigValue::as_str),
+        Some("false")
+    );
+    assert_eq!(
+        parsed
+            .values
+            .get("power-dragon.idle-fps-factor")
+            .and_then(ConfigValue::as_str),
+        Some("0.25")
+    );
+}
+
+#[test]
+fn legacy_scalar_next_to_table_is_rewritten() {
+    // The old template had both forms side by side; TOML rejects that.
+    let parsed = parse_config_text(
+        "color = \"green\"\npower-dragon = true\n\n[color.tune]\nhead = 1.5\n\n[power-dragon]\nidle-fps-factor = 0.25\n",
+    );
+    assert!(
+        parsed.malformed_lines.is_empty(),
+        "{:?}",
+        parsed.malformed_lines
+    );
+    assert_eq!(
+        parsed.values.get("color").and_then(ConfigValue::as_str),
+        Some("green")
+    );
+    assert_eq!(
+        parsed
+            .values
+            .get("color.tune.head")
+            .and_then(ConfigValue::as_str),
+        Some("1.5")
+    );
+    assert_eq!(
+        parsed
+            .values
+            .get("power-dragon")
+            .and_then(ConfigValue::as_str),
+        Some("true")
+    );
+    assert_eq!(
+        parsed
+            .values
+            .get("power-dragon.idle-fps-factor")
+            .and_then(ConfigValue::as_str),
+        Some("0.25")
+    );
+}
+
+#[test]
+fn legacy_bare_values_are_quoted_and_noted() {
+    let parsed = parse_config_text("scene = calm\nfps = 60\nmessage = Hello \"world\"\n");
+    assert!(
+        parsed.malformed_lines.is_empty(),
+        "{:?}",
+        parsed.malformed_lines
+    );
+    assert_eq!(
+        parsed.values.get("scene").and_then(ConfigValue::as_str),
+        Some("calm")
+    );
+    assert_eq!(
+        parsed.values.get("fps").and_then(ConfigValue::as_str),
+        Some("60")
+    );
+    assert_eq!(
+        parsed.values.get("message").and_then(ConfigValue::as_str),
+        Some("Hello \"world\"")
+    );
+    assert_eq!(parsed.legacy_notes.len(), 2, "{:?}", parsed.legacy_notes);
+    assert!(parsed.legacy_notes[0].starts_with("line 1: unquoted value for 'scene'"));
+}
+
+#[test]
+fn valid_toml_is_not_rewritten() {
+    // The legacy scanner split at the first `=` and would have "quoted"
+    // `key" = 1` into a string.
+    let parsed =
+        parse_config_text("fps = 30\n\"odd=key\" = 1\n[scene-custom.x]\n\"intro\" = \"a = b\"\n");
+    assert!(
+        parsed.malformed_lines.is_empty(),
+        "{:?}",
+        parsed.malformed_lines
+    );
+    assert!(parsed.legacy_notes.is_empty(), "{:?}", parsed.legacy_notes);
+    assert_eq!(
+        parsed.values.get("fps").and_then(ConfigValue::as_str),
+        Some("30")
+    );
+    assert_eq!(parsed.unknown_keys, vec!["odd=key".to_string()]);
+    assert_eq!(
+        parsed.values.get("intro").and_then(ConfigValue::as_str),
+        Some("a = b"),
+        "quoted keys are still promoted"
+    );
+}
+
+#[test]
+fn syntax_errors_drop_only_their_line() {
+    let parsed = parse_config_text("fps = 60\nfps = 30\nthis is not toml\nspeed = 9\n");
+    assert_eq!(
+        parsed.malformed_lines.len(),
+        2,
+        "{:?}",
+        parsed.malformed_lines
+    );
+    assert!(
+        parsed.malformed_lines[0].starts_with("fps = 30  # ERROR: line 2: duplicate key"),
+        "{:?}",
+        parsed.malformed_lines
+    );
+    assert!(parsed.malformed_lines[1].starts_with("this is not toml  # ERROR: line 3:"));
+    assert_eq!(
+        parsed.values.get("fps").and_then(ConfigValue::as_str),
+        Some("60")
+    );
+    assert_eq!(
+        parsed.values.get("speed").and_then(ConfigValue::as_str),
+        Some("9")
+    );
+}
+
+#[test]
+fn unclosed_array_is_reported_without_panicking() {
+    let parsed = parse_config_text("[colors-custom.zen]\nrain = [\n  \"#ffffff\",\n");
+    assert!(!parsed.malformed_lines.is_empty());
+    assert!(!parsed.values.contains_key("colors-custom.zen.rain"));
+}
+
+#[test]
+fn unknown_tables_report_each_leaf() {
+    let parsed = parse_config_text("[profile.hacker]\nglyphs = \"abc\"\n[made-up]\nx = 1\n");
+    assert_eq!(
+        parsed.unknown_keys,
+        vec!["profile.hacker.glyphs".to_string(), "made-up.x".to_string()]
+    );
+}
+
+#[test]
+fn uncommented_dump_template_is_clean() {
+    // Every `# key = value` / `# [section]` line of the template, enabled.
+    let enabled: String = crate::configfile::dump_config_text()
+        .lines()
+        .filter_map(|line| line.strip_prefix("# "))
+        .filter(|rest| {
+            rest.starts_with('[')
+                || rest.split_once('=').is_some_and(|(key, _)| {
+                    let key = key.trim();
+                    !key.is_empty()
+                        && key
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_'))
+                })
+        })
+        .map(|line| format!("{line}\n"))
+        .collect();
+    let parsed = parse_config_text(&enabled);
+    assert!(
+        parsed.malformed_lines.is_empty(),
+        "{:?}",
+        parsed.malformed_lines
+    );
+    assert!(parsed.unknown_keys.is_empty(), "{:?}", parsed.unknown_keys);
+    assert!(parsed.type_errors.is_empty(), "{:?}", parsed.type_errors);
+    assert!(parsed.legacy_notes.is_empty(), "{:?}", parsed.legacy_notes);
+    for key in root_keys() {
+        assert!(parsed.values.contains_key(key), "{key}");
+    }
+    assert!(parsed.values.contains_key("power-dragon.thermal-one-c"));
+    assert!(parsed.values.contains_key("colors-custom.zen.rain"));
+}
+
+#[test]
+fn section_hints_list_every_field_of_their_table() {
+    let keys = known_keys();
+    let rain = keys
+        .iter()
+        .find(|k| k.starts_with("rain-tuning.<"))
+        .expect("rain-tuning hint");
+    let fields: Vec<&str> = crate::constants::RAIN_TUNING_FIELDS
+        .iter()
+        .map(|f| f.name)
+        .collect();
+    assert_eq!(*rain, format!("rain-tuning.<{}>", fields.join("|")));
+    let power: Vec<&str> = crate::constants::power_dragon_field_names().collect();
+    assert!(keys.contains(&format!("power-dragon.<{}>", power.join("|"))));
+    let actions = crate::interactive::keymap::KeyAction::ALL.map(|a| a.name());
+    assert!(keys.contains(&format!("keys.<{}>", actions.join("|"))));
+    let kinds = crate::constants::EventKind::ALL.map(|k| k.name());
+    let event_fields: Vec<&str> = crate::constants::EVENT_FIELDS
+        .iter()
+        .map(|(f, _, _)| *f)
+        .collect();
+    assert!(keys.contains(&format!(
+        "events.<{}>.<{}>, events.decrypt.words",
+        kinds.join("|"),
+        event_fields.join("|")
+    )));
+}
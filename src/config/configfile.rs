@@ -4,7 +4,9 @@
 //! Configuration file support for Cosmostrix.
 //!
 //! Reads an explicit `--config <PATH>` file or the default
-//! `~/.config/cosmostrix/config.toml` (or `$XDG_CONFIG_HOME/cosmostrix/config.toml`).
+//! `~/.config/cosmostrix/config.toml` (or `$XDG_CONFIG_HOME/cosmostrix/config.toml`),
+//! plus the files it includes and any `conf.d/` drop-ins
+//! ([`crate::config_layers`]).
 //!
 //! ## Philosophy
 //!
@@ -13,99 +15,49 @@
 //!
 //! ## Format
 //!
-//! ```text
-//! key = value          # one per line
-//! # comments           # blank lines ignored
+//! The file is TOML, parsed by the `toml` crate and checked against the key
+//! schema in [`crate::config_schema`]:
+//!
+//! ```toml
+//! scene = "cinematic"            # comments and blank lines are ignored
+//! fps = 60
+//!
+//! [color.tune]
+//! brightness = 1.2
 //! ```
 //!
+//! Older files that relied on the previous line scanner (unquoted values,
+//! `color = ...` next to `[color.tune]`) are rewritten by
+//! [`crate::config_schema::legacy`] before parsing. A syntax error drops
+//! only the offending line: it is reported in `malformed_lines` and the
+//! rest of the file is parsed again without it.
+//!
 //! Config file values serve as defaults; presets and explicit CLI args are
 //! applied later by `config_apply`.
 
-use std::collections::HashMap;
 use std::env;
 use std::path::{Path, PathBuf};
 
+use crate::config_layers::ConfigLayers;
+use crate::config_schema;
+use crate::config_schema::ConfigMap;
 use crate::constants::{CONFIG_DIR_NAME, CONFIG_FILE_NAME};
-use crate::scene_custom::is_scene_custom_config_key;
 use sha2::{Digest, Sha512};
 
-pub(crate) const USER_CONFIG_KEYS: &[&str] = &[
-    "scene",
-    "color",
-    "charset",
-    "fps",
-    "speed",
-    "density",
-    "monolith-size",
-    "glitch-level",
-    "bold",
-    "shadingmode",
-    "color-bg",
-    "crystal-dragon",
-    // v50: Power Dragon toggle. When false, disables aggressive_throttle
-    // and idle FPS reduction — rain stays at user-configured density/speed
-    // regardless of CPU pressure. Default: true (protection enabled).
-    "power-dragon",
-    "async-mode",
-    // (CLI-D-1 fix): `adaptive-custom` removed from this whitelist.
-    // The atmosphere engine was eliminated at commit 07b44b5 (2026-08-05),
-    // and `config_hints.rs` + `testconf.rs` both explicitly reject
-    // `adaptive-custom.*` keys with "have been removed" messages. But the
-    // bare key was still classified as "known" here, so a stale
-    // `adaptive-custom = "10-00, energy-zen, signal"` line was silently
-    // stored and never applied at runtime — zero warning at startup, only
-    // `--testconf` caught it. Now the bare key falls into `unknown_keys`
-    // and triggers the startup rejection at `config_apply.rs:149-163`.
-    // v20: Cinematic intro selector. Values: "logo" | "cosmic" | "none".
-    // Default: "logo". CLI --intro flag wins over this config key.
-    "intro",
-    // v50: Intro color override. Allows the intro animation to use a
-    // different color theme than the rain. Values: any builtin theme
-    // name (e.g. "energy-zen", "neon-green") or custom palette name
-    // (e.g. "cyberpunk_2077"). Default: same as --color (rain color).
-    // Use --list-colors to see available builtin themes.
-    "intro-color",
-    // v50: Overlay message text. Two config keys mirror the CLI flags:
-    //   message         = "text"  → message WITHOUT border (matches -m)
-    //   message-border  = "text"  → message WITH border    (matches -mb)
-    // If both are present in config, `message-border` wins (border=true).
-    // CLI -m / -mb wins over either config key. When none of CLI/config
-    // provides a message, interactive mode defaults to a bordered overlay
-    // showing the project name (see main.rs CloudConfig construction).
-    // Benchmark mode never shows a message overlay.
-    "message",
-    "message-border",
-    // v50-beta.3: msg-mode master switch for the message overlay subsystem.
-    // When false, disables BOTH the default message AND any message/
-    // message-border config key (config-only message is suppressed).
-    // CLI -m / -mb always wins over msg-mode=false (CLI precedence).
-    // Default: true (message overlay active).
-    "msg-mode",
-];
-
-const SCENE_CUSTOM_CONFIG_KEY_HINT: &str = "scene-custom.<name>.<base-scene|color|charset|bold|colors-custom|charset-custom|shadingmode|glitch-level|fps|speed|density|density-map|async-mode>";
-const COLORS_CUSTOM_CONFIG_KEY_HINT: &str = "colors-custom.<name>.<bg|rain|stops>";
-const CHARSET_CUSTOM_CONFIG_KEY_HINT: &str = "charset-custom.<name>.set";
-const COLOR_TUNE_CONFIG_KEY_HINT: &str = "color.tune.<brightness|saturation|head|body|tail>";
-/// Ambient phase scheduler: `ambient.<HH-MM> = <scene-name>`.
-///
-/// simplified — value is a single scene name (built-in OR custom).
-/// Config-only (no CLI flag). Time-of-day phase entries that switch the
-/// active scene at scheduled times. Instant switch (no blend window).
-/// Dynamic idle/wake scheduler thread — zero CPU between phase boundaries.
-/// See `src/crystal_dragon_engine/ambient.rs` and `src/crystal_dragon_engine/ambient_scheduler.rs`.
-const AMBIENT_CONFIG_KEY_HINT: &str = "ambient.<HH-MM> = <scene-name>";
-
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, PartialEq)]
 pub(crate) struct ParsedConfig {
-    pub values: HashMap<String, String>,
+    pub values: ConfigMap,
     pub unknown_keys: Vec<String>,
-    /// Non-empty, non-comment lines that do not match `key = value` syntax.
+    /// Lines the TOML parser rejected, as `<line>  # ERROR: <reason>`.
     ///
-    /// Tracked so `--testconf` can report them as errors and `load_config_file`
-    /// can warn on stderr. A line lands here when it has no `=` at all, or when
-    /// either side of `=` is empty after trimming.
+    /// Tracked so `--testconf` can report them as errors and startup / live
+    /// reload can refuse the file. Each rejected line is dropped and the
+    /// file re-parsed, so the remaining keys still land in `values`.
     pub malformed_lines: Vec<String>,
+    /// Known keys whose TOML type does not fit the schema, as
+    /// `(key, message)` (e.g. `fps = true` → "expected an integer, got a
+    /// boolean (true)"). The key is left out of `values`.
+    pub type_errors: Vec<(String, String)>,
     /// keys that were auto-promoted from a nested section to root scope.
     ///
     /// Each tuple is `(original_nested_key, promoted_root_key)`. Populated when
@@ -116,6 +68,16 @@ pub(crate) struct ParsedConfig {
     /// and `[scene-custom.<name>]` blocks coexist in the same file without
     /// forcing the user to learn TOML scope rules.
     pub promoted_keys: Vec<(String, String)>,
+    /// Legacy syntax that was rewritten before parsing (e.g. an unquoted
+    /// value). Informational; see [`crate::config_schema::legacy`].
+    pub legacy_notes: Vec<String>,
+    /// Root `include = [...]` entries as written, in order. Resolved and
+    /// merged by [`crate::config_layers`].
+    pub includes: Vec<String>,
+    /// Include and `conf.d/` files that could not be layered in (outside
+    /// the path whitelist, unreadable, include cycle). Rejected like
+    /// malformed lines.
+    pub include_errors: Vec<String>,
 }
 
 /// Load config file and return a HashMap of key → value pairs.
@@ -129,8 +91,11 @@ pub(crate) struct ParsedConfig {
 /// This means AUR users get a working default config out of the box —
 /// the package installs `/etc/cosmostrix/config.toml`, and cosmostrix
 /// reads it automatically if no user-level config exists.
+///
+/// Files named in `include = [...]` and `conf.d/*.toml` drop-ins are merged
+/// in; see [`load_config_layers`].
 #[must_use]
-pub(crate) fn load_config_file(path_override: Option<&Path>) -> HashMap<String, String> {
+pub(crate) fn load_config_file(path_override: Option<&Path>) -> ConfigMap {
     load_config_file_full(path_override).values
 }
 
@@ -147,201 +112,106 @@ pub(crate) fn load_config_file(path_override: Option<&Path>) -> HashMap<String,
 /// HashMap). Use this function only when you need the malformed/unknown vectors.
 #[must_use]
 pub(crate) fn load_config_file_full(path_override: Option<&Path>) -> ParsedConfig {
-    let path = path_override
-        .map(Path::to_path_buf)
-        .unwrap_or_else(default_config_file_path);
-    let content = match std::fs::read_to_string(&path) {
-        Ok(c) => c,
-        Err(_) => {
-            // Fallback: try system-wide config at /etc/cosmostrix/config.toml.
-            if path_override.is_none() {
-                let system_path = PathBuf::from("/etc/cosmostrix/config.toml");
-                if let Ok(sys_content) = std::fs::read_to_string(&system_path) {
-                    sys_content
-                } else {
-                    return ParsedConfig {
-                        values: HashMap::new(),
-                        unknown_keys: Vec::new(),
-                        malformed_lines: Vec::new(),
-                        promoted_keys: Vec::new(),
-                    };
-                }
+    load_config_layers(path_override).parsed
+}
+
+/// Load the config with its `include`s and `conf.d/` drop-ins (see
+/// [`crate::config_layers`]), keeping the list of files that were read.
+///
+/// The main file is `path_override`, else the default user path, else
+/// `/etc/cosmostrix/config.toml` when the user file does not exist.
+#[must_use]
+pub(crate) fn load_config_layers(path_override: Option<&Path>) -> ConfigLayers {
+    let main = match path_override {
+        Some(path) => path.to_path_buf(),
+        None => {
+            let user = default_config_file_path();
+            let system = PathBuf::from("/etc/cosmostrix/config.toml");
+            if !user.exists() && system.exists() {
+                system
             } else {
-                return ParsedConfig {
-                    values: HashMap::new(),
-                    unknown_keys: Vec::new(),
-                    malformed_lines: Vec::new(),
-                    promoted_keys: Vec::new(),
-                };
+                user
             }
         }
     };
-
-    parse_config_text(&content)
+    crate::config_layers::load(&main)
 }
 
+/// Syntax errors recovered from before giving up on the rest of the file.
+const MAX_SYNTAX_ERRORS: usize = 64;
+
+/// Parse config text into flattened, schema-checked key/value pairs.
+///
+/// Pipeline: `toml` → [`config_schema::flatten_into`]. A file the parser
+/// rejects goes through [`config_schema::legacy::normalize`] first and is
+/// parsed again; on a syntax error the reported line is dropped, recorded
+/// in `malformed_lines`, and the rest re-parsed.
 #[must_use]
 pub(crate) fn parse_config_text(content: &str) -> ParsedConfig {
-    let mut map = HashMap::new();
-    let mut unknown_keys = Vec::new();
-    let mut malformed_lines = Vec::new();
-    let mut promoted_keys: Vec<(String, String)> = Vec::new();
-
-    let mut current_section: String = String::new();
-
-    // Collect lines into a Vec so we can advance the index for multi-line
-    // array values (e.g. rain = [\n  "#1a0033",\n  ...\n]).
-    let lines: Vec<&str> = content.lines().collect();
-    let mut i = 0;
-    while i < lines.len() {
-        let line = lines[i];
-        let stripped = strip_inline_comment(line).trim();
-        if stripped.is_empty() {
-            i += 1;
-            continue;
-        }
-
-        if stripped.starts_with('[') && stripped.ends_with(']') && stripped.len() > 2 {
-            let section = &stripped[1..stripped.len() - 1];
-            let section = section.trim().to_ascii_lowercase();
-            if section.is_empty() {
-                malformed_lines.push(stripped.to_string());
-                i += 1;
-                continue;
-            }
-            current_section = section;
-            i += 1;
-            continue;
-        }
-
-        if let Some((key, value)) = stripped.split_once('=') {
-            let key = key.trim().to_ascii_lowercase();
-            let mut value = value.trim().to_string();
-            if key.is_empty() || value.is_empty() {
-                malformed_lines.push(stripped.to_string());
-                i += 1;
-                continue;
-            }
-
-            // Option 1 (internal independent QA): strip surrounding double
-            // quotes from string values. Standard TOML requires string
-            // values to be quoted ("value"), but cosmostrix's custom parser
-            // previously stored the raw value INCLUDING the quotes. This
-            // caused `intro = "logo"` to fail because the consumer saw
-            // `"logo"` (with quotes) instead of `logo`. Now we strip
-            // matching leading/trailing double quotes so both
-            // `intro = "logo"` and `intro = logo` produce the same stored
-            // value `logo`. This also fixes the template inconsistency
-            // where some values were quoted (intro, bg, set) and others
-            // were not (scene, color, charset).
-            //
-            // Only strip if the value starts AND ends with a double quote
-            // (both must be present — a lone leading quote is not stripped).
-            // Arrays (starting with '[') are NOT touched — their internal
-            // quotes are handled by the array consumer (hex colors, etc.).
-            if value.len() >= 2
-                && value.starts_with('"')
-                && value.ends_with('"')
-                && !value.starts_with('[')
-            {
-                value = value[1..value.len() - 1].to_string();
-            }
-
-            // (bug #7): Detect unquoted '#' inside an array value.
-            // strip_inline_comment strips at first unquoted '#'. If that
-            // happened while bracket depth > 0, the user wrote e.g.
-            // `rain = [#ff0000, #00ff00]` — '#' inside array was treated
-            // as comment, silently truncating value to '[' and triggering
-            // the multi-line consumer to eat subsequent lines. Reject
-            // explicitly. We check the ORIGINAL line (not `stripped`)
-            // because `stripped` is already truncated.
-            if value.starts_with('[')
-                && unquoted_hash_inside_array(line).is_some()
-                && !value.ends_with(']')
-            {
-                malformed_lines.push(format!(
-                    "{stripped}  # ERROR: unquoted '#' inside array — quote hex values (e.g. \"#ff0000\") or remove the '#'"
-                ));
-                i += 1;
-                continue;
-            }
-
-            // v25: Handle multi-line TOML arrays. If the value starts with
-            // '[' but doesn't end with ']', consume subsequent lines until
-            // we find the closing ']'. (bug #7) hardening: do NOT
-            // consume [section] headers — those were previously mistaken
-            // for the closing ']' of the array, corrupting subsequent
-            // block definitions.
-            if value.starts_with('[') && !value.ends_with(']') {
-                while i + 1 < lines.len() {
-                    let raw_next = lines[i + 1];
-                    let next_line = strip_inline_comment(raw_next).trim();
-                    if next_line.is_empty() {
-                        i += 1;
-                        continue;
-                    }
-                    // [section] header is never an array element. Stop.
-                    if next_line.starts_with('[') && next_line.ends_with(']') && next_line.len() > 2
-                    {
-                        break;
-                    }
-                    value.push(' ');
-                    value.push_str(next_line);
-                    i += 1;
-                    if next_line.ends_with(']') {
-                        break;
-                    }
-                }
-                // Still no closing ']' → genuinely malformed (user forgot
-                // to close, or '#' truncated). Reject explicitly.
-                if !value.ends_with(']') {
-                    malformed_lines.push(format!(
-                        "{stripped}  # ERROR: array never closed (missing ']') or '#' truncated the value"
-                    ));
-                    i += 1;
-                    continue;
-                }
-            }
-
-            let full_key = if !current_section.is_empty() {
-                format!("{current_section}.{key}")
-            } else {
-                key.clone()
-            };
-            if !is_known_key(&full_key) {
-                // Auto-promote forgiving parser. If the un-prefixed
-                // key is itself a known top-level key, the user accidentally
-                // nested it under a [section] header (very common when
-                // mixing [scene-custom.<name>] with top-level keys like
-                // `fps` or `speed`). Silently re-home it to root scope and
-                // record the promotion so --testconf can warn the user
-                // about the structural fix.
-                if !current_section.is_empty() && is_known_key(&key) {
-                    promoted_keys.push((full_key.clone(), key.clone()));
-                    // Don't overwrite an explicit root-scope value — first
-                    // writer wins (matches TOML semantics for duplicate keys).
-                    map.entry(key).or_insert(value);
-                } else {
-                    unknown_keys.push(full_key);
-                }
-                i += 1;
-                continue;
+    if let Ok(table) = content.parse::<toml::Table>() {
+        let mut parsed = ParsedConfig::default();
+        let scoped_keys = config_schema::legacy::scoped_keys(content);
+        config_schema::flatten_into(&table, &scoped_keys, &mut parsed);
+        return parsed;
+    }
+    let mut normalized = config_schema::legacy::normalize(content);
+    let mut malformed = std::mem::take(&mut normalized.malformed);
+
+    let mut table = toml::Table::new();
+    for _ in 0..=MAX_SYNTAX_ERRORS {
+        let text = normalized.lines.join("\n");
+        let err = match text.parse::<toml::Table>() {
+            Ok(t) => {
+                table = t;
+                break;
             }
-            map.insert(full_key, value);
-        } else {
-            // No `=` — malformed (unless we're inside a multi-line array,
-            // but those are consumed above).
-            malformed_lines.push(stripped.to_string());
-        }
-        i += 1;
+            Err(e) => e,
+        };
+        let Some(index) = err
+            .span()
+            .and_then(|span| error_line(&text, span.start, &normalized.lines))
+        else {
+            malformed.push((
+                usize::MAX,
+                format!("(end of file)  # ERROR: {}", err.message()),
+            ));
+            break;
+        };
+        let line = normalized.origin[index];
+        malformed.push((
+            line,
+            format!(
+                "{}  # ERROR: line {line}: {}",
+                strip_inline_comment(&normalized.lines[index]).trim(),
+                err.message()
+            ),
+        ));
+        normalized.lines[index].clear();
     }
+    // The parser reports syntax errors before duplicate keys; list them in
+    // file order.
+    malformed.sort_by_key(|(line, _)| *line);
+
+    let mut parsed = ParsedConfig {
+        malformed_lines: malformed.into_iter().map(|(_, entry)| entry).collect(),
+        legacy_notes: std::mem::take(&mut normalized.notes),
+        ..ParsedConfig::default()
+    };
+    config_schema::flatten_into(&table, &normalized.scoped_keys, &mut parsed);
+    parsed
+}
 
-    ParsedConfig {
-        values: map,
-        unknown_keys,
-        malformed_lines,
-        promoted_keys,
-    }
+/// Index of the line a parser error at byte `offset` belongs to. Errors
+/// reported past the end of a statement (an unclosed array at end of file)
+/// land on a blank line; walk back to the nearest line with content.
+fn error_line(text: &str, offset: usize, lines: &[String]) -> Option<usize> {
+    let at = text.as_bytes()[..offset.min(text.len())]
+        .iter()
+        .filter(|&&b| b == b'\n')
+        .count();
+    (0..=at.min(lines.len().checked_sub(1)?))
+        .rev()
+        .find(|&i| !lines[i].trim().is_empty())
 }
 
 /// Returns the path to the config file.
@@ -605,7 +475,10 @@ pub(crate) fn dump_config_text() -> &'static str {
 #
 # Override priority: CLI flags > config.toml > scene defaults.
 # Validate after editing: cosmostrix --testconf
+# Syntax: TOML — quote text ("cinematic"), leave numbers and true/false bare.
 # File location: ~/.config/cosmostrix/config.toml (see --help for platform paths)
+# Layers: include = ["team.toml"] merges other files first (this file wins);
+#   conf.d/*.toml next to this file is merged last, in file-name order.
 #
 # See --list-scenes, --list-colors, --list-charsets
 
@@ -616,6 +489,8 @@ pub(crate) fn dump_config_text() -> &'static str {
 # color = "energy-zen"              # See: cosmostrix --list-colors (cinematic default)
 # charset = "zen"                   # See: cosmostrix --list-charsets (cinematic default)
 # color-bg = "default-background"   # or "black"
+# color-dither = "off"              # off | ordered | temporal (256/16-color terminals)
+# probe-terminal = false            # query the terminal (DA1, XTVERSION, DECRQM, OSC 11) instead of trusting TERM
 # intro = "logo"                    # logo | cosmic | none (default: logo)
 # intro-color = "energy-zen"        # intro color override (default: same as rain color)
 
@@ -646,9 +521,15 @@ pub(crate) fn dump_config_text() -> &'static str {
 
 # glitch-level = "subtle"           # none | subtle | default | intense (cinematic default)
 # crystal-dragon = false            # Crystal Dragon ambient color drift (point-based temperature groups)
+# crystal-dragon-calc = "v1"        # v1 = weighted pick | v2 = no recent repeats, follows load trend
+# crystal-dragon-sensor = ["cpu"]   # cpu (this process) | clock | loadavg | psi-cpu | psi-memory | psi-io | memory | battery | external
+# crystal-dragon-sensor-mix = "max" # how several sensors combine: max (hottest wins) | mean
+# crystal-dragon-external = "/run/ci/point" # external sensor feed: file or FIFO holding 1-99 / cold / medium / hot,
+#                                           # or "command:<cmd>" (run every 15s, last stdout line)
 # power-dragon = true               # Power Dragon adaptive protection (true=throttle on pressure, false=keep user settings)
 # bold = 1                          # 0=off, 1=random, 2=all
 # shadingmode = 1                   # 0=random, 1=cinematic
+# seed = 42                         # fixed RNG seed for reproducible runs (default: unset = random; startup only)
 
 # Color Tuning
 # [color.tune]
@@ -658,6 +539,81 @@ pub(crate) fn dump_config_text() -> &'static str {
 # body = 1.0                        # 0.0-3.0
 # tail = 1.0                        # 0.0-3.0
 
+# Rain Tuning (defaults = compiled-in calibration; per-layer = [back, mid, front])
+# [rain-tuning]
+# parallax-speed = [0.35, 1.0, 1.7]         # each 0.05-4.0
+# parallax-brightness = [0.52, 0.80, 1.10]  # each 0.0-2.0
+# parallax-saturation = [0.50, 0.84, 1.12]  # each 0.0-2.0
+# parallax-density = [0.45, 0.62, 0.85]     # each 0.0-2.0
+# parallax-head-bloom = [0.48, 0.74, 1.30]  # each 0.0-3.0
+# phosphor-decay = 5.0              # 0.5-50.0 (lower = longer afterglow)
+# phosphor-layer-decay = [2.0, 1.2, 0.6]    # each 0.0-5.0
+# head-bloom-sigma = 1.2            # 0.1-5.0
+# head-bloom-intensity = 0.40       # 0.0-1.0
+# fog-rows = 3                      # 0-12
+# fog-min-factor = 1.0              # 0.0-1.0 (1.0 = fog off)
+# crt-vignette-height = 3           # 0-8 rows (0 = off)
+# crt-vignette-edge = 0.85          # 0.0-1.0 (edge-row brightness)
+# vignette-intensity = 0.20         # 0.0-1.0 (0.0 = off)
+# vignette-inner-radius = 0.7       # 0.0-0.95
+
+# Cinematic events (chance = per tick, 0 = never; cooldown = min gap between two of a kind)
+# [events.ghost]
+# chance = 0.003                    # 0.0-1.0
+# cooldown-secs = 0                 # 0-3600
+# [events.decrypt]
+# words = ["WAKE UP", "FOLLOW THE RAIN"]  # glyphs resolve into one of these (empty = off)
+# chance = 0.0008
+# cooldown-secs = 40
+# [events.scanline]
+# chance = 0.0005                   # default 0 (off)
+# cooldown-secs = 45
+# [events.cascade]
+# chance = 0.0015                   # default 0 (off)
+# cooldown-secs = 15
+# [events.tear]
+# chance = 0.0006                   # default 0 (off)
+# cooldown-secs = 30
+
+# Power Dragon thresholds (defaults = compiled-in; independent of `power-dragon = true|false`)
+# [power-dragon]
+# idle-threshold-secs = 30          # 5-3600 (no input → idle)
+# idle-fps-factor = 0.5             # 0.05-1.0 (FPS multiplier while idle)
+# idle-resync-secs = 20             # 1-3600 (first idle hour)
+# idle-resync-tier-2-secs = 60      # 1-3600 (1-4 idle hours)
+# idle-resync-tier-3-secs = 120     # 1-3600 (past 4 idle hours)
+# self-heal-downgrade = true        # false = never throttle on sustained pressure
+# self-heal-pressure-high = 0.6     # 0.05-1.0 (must be > pressure-low)
+# self-heal-pressure-low = 0.3      # 0.0-0.95
+# self-heal-downgrade-secs = 30     # 1-3600
+# self-heal-restore-secs = 60       # 1-3600
+# thermal-zero-c = 50               # 0-100 °C (thermal pressure 0.0)
+# thermal-one-c = 90                # 30-150 °C (thermal pressure 1.0, must be > zero)
+# xtermjs-byte-budget-mib = 40      # 1-1024 (xterm.js hosts only)
+# xtermjs-window-frames = 600       # 100-10000
+# xtermjs-ris-reset-mib = 50        # 1-4096 (must be < hard ceiling)
+# xtermjs-hard-ceiling-mib = 200    # 1-4096
+
+# Runtime key bindings (defaults shown). Value: one key spec or an array,
+# e.g. quit = ["q", "ctrl+q"]. Spec: a character or space, enter, tab, esc,
+# up, down, left, right, home, end, pageup, pagedown, insert, delete,
+# backspace, plus, comma, f1-f24; prefixes ctrl+, alt+, super+, shift+.
+# [keys]
+# quit = "q"
+# pause = "p"
+# reseed = "space"
+# cycle-color = "c"
+# cycle-color-back = "C"            # same as "shift+c"
+# cycle-charset = "s"
+# cycle-charset-back = "S"
+# cycle-scene = "x"
+# cycle-scene-back = "X"            # unbound by default
+# speed-up = "up"
+# speed-down = "down"
+# density-up = "]"
+# density-down = "["
+# hud = "i"
+
 # Custom Scenes
 # Define named scenes, load with: cosmostrix --scene-custom <name>
 # Paired fields: `color`/`charset` = built-in name; `colors-custom`/`charset-custom`
@@ -676,6 +632,8 @@ pub(crate) fn dump_config_text() -> &'static str {
 # shadingmode = 1                   # 0=random, 1=cinematic
 # glitch-level = "intense"
 # density-map = "0.5,1.0,1.5,1.0,0.5"  # per-zone density weights (each 0.0-1.0)
+# density-mask = "text:ACME"           # 2D mask: text:<TEXT>, '''ASCII art''' or ~/logo.pgm
+# density-mask-floor = 0.15            # rain weight outside the mask (0.0-1.0)
 # async-mode = true                    # variable column speeds
 
 # [scene-custom.cyberpunk_2077]
@@ -736,12 +694,28 @@ pub(crate) fn dump_config_text() -> &'static str {
 # Ambient Phase Scheduler
 # Time-of-day scene switches. Config-only (no CLI flag).
 # Format: ambient.<HH-MM> = <scene-name>  (24-hour, zero-padded)
+#         ambient.<days>.<HH-MM> = <scene-name>
+# <days>: mon..sun, weekdays, weekends, a range (mon..thu), a yearly date
+# "12-24", a date "2026-11-03" or a date range "12-24..01-01" (quote dates).
+# Each day follows the most specific matching <days> (date > range >
+# weekday > weekdays/weekends > every day); its other rules are ignored.
+# Solar rules replace <HH-MM> with dawn, sunrise, noon, sunset or dusk plus an
+# optional offset ("sunset+30m", "sunrise-1h"); times are computed daily from
+# ambient-location (decimal degrees, north/east positive), offline.
+# ambient-transition crossfades speed, density and glitch into each new phase
+# over the given time ("90s", "2m"; max 30m) while the new palette wipes down
+# the screen over the same time. Unset = instant.
 # Live reload: edits take effect on save.
 # Max 256 entries.
 
+# ambient-location = "52.52, 13.40"
+# ambient-transition = "90s"
 # ambient.06-00 = "signal"
 # ambient.12-00 = "monolith"
 # ambient.20-00 = "cinematic"
+# ambient.weekends.09-00 = "monolith"
+# ambient."2026-11-03".09-00 = "signal"
+# ambient."sunset+30m" = "cinematic"
 "##
 }
 
@@ -848,99 +822,6 @@ pub(crate) fn extract_template_fingerprint(content: &str) -> Option<String> {
     None
 }
 
-#[must_use]
-pub(crate) fn known_keys() -> Vec<&'static str> {
-    USER_CONFIG_KEYS
-        .iter()
-        .chain(std::iter::once(&SCENE_CUSTOM_CONFIG_KEY_HINT))
-        .chain(std::iter::once(&COLORS_CUSTOM_CONFIG_KEY_HINT))
-        .chain(std::iter::once(&CHARSET_CUSTOM_CONFIG_KEY_HINT))
-        .chain(std::iter::once(&COLOR_TUNE_CONFIG_KEY_HINT))
-        .chain(std::iter::once(&AMBIENT_CONFIG_KEY_HINT))
-        .copied()
-        .collect()
-}
-
-#[inline]
-fn is_known_key(key: &str) -> bool {
-    USER_CONFIG_KEYS.contains(&key)
-        || is_scene_custom_config_key(key)
-        || is_colors_custom_key(key)
-        || is_charset_custom_key(key)
-        || is_color_tune_key(key)
-        || crate::crystal_dragon_engine::ambient::is_ambient_config_key(key)
-}
-
-/// v17: Check if key matches `color.tune.<field>` pattern.
-#[inline]
-fn is_color_tune_key(key: &str) -> bool {
-    matches!(
-        key,
-        "color.tune.brightness"
-            | "color.tune.saturation"
-            | "color.tune.head"
-            | "color.tune.body"
-            | "color.tune.tail"
-    )
-}
-
-/// Check if `key` matches `colors-custom.<name>.<field>` pattern.
-/// Recognized fields: `bg`, `rain` (canonical), `stops` (deprecated alias for `rain`).
-/// Invalid fields surface as `unknown_keys` so `config_hints` can attach a hint.
-/// Name must be non-empty, ASCII alphanumeric + `-`/`_` only.
-fn is_colors_custom_key(key: &str) -> bool {
-    let Some(rest) = key.strip_prefix("colors-custom.") else {
-        return false;
-    };
-    // Must have at least name.field (2+ segments after the prefix).
-    let Some((name, field)) = rest.split_once('.') else {
-        return false;
-    };
-    if name.is_empty() || !is_valid_custom_name(name) {
-        return false;
-    }
-    is_valid_colors_custom_field(field)
-}
-
-/// Check if a custom palette name is valid (non-empty, alphanumeric + -/_).
-#[inline]
-fn is_valid_custom_name(name: &str) -> bool {
-    !name.is_empty()
-        && name
-            .chars()
-            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
-}
-
-/// Check if a colors-custom field name is recognized.
-///
-/// (bug #8): tightened from `bg | background | rain` to
-/// `bg | rain | stops`. `background` (undocumented alias) was removed —
-/// use `bg`. `stops` is a deprecated alias for `rain` (still accepted,
-/// `--testconf` emits a deprecation warning). Brings the key-checker in
-/// sync with `validate_colors_custom_value`, which already handled `.stops`.
-#[inline]
-fn is_valid_colors_custom_field(field: &str) -> bool {
-    matches!(field, "bg" | "rain" | "stops")
-}
-
-/// Check if `key` matches `charset-custom.<name>.set` pattern.
-/// v25: replaces legacy `--charset-file <PATH>` CLI flag. Only `set`
-/// field is recognized. Name must be non-empty, ASCII alphanumeric +
-/// `-`/`_` only (same rule as `colors-custom`).
-#[inline]
-fn is_charset_custom_key(key: &str) -> bool {
-    let Some(rest) = key.strip_prefix("charset-custom.") else {
-        return false;
-    };
-    let Some((name, field)) = rest.split_once('.') else {
-        return false;
-    };
-    if name.is_empty() || !is_valid_custom_name(name) {
-        return false;
-    }
-    field == "set"
-}
-
 /// Strip inline comments (`# ...`) from a config line, respecting quoted strings.
 ///
 /// A `#` inside a double-quoted or single-quoted string is NOT treated as a
@@ -954,7 +835,7 @@ fn is_charset_custom_key(key: &str) -> bool {
 ///
 /// Unquoted `#` still works as before for backward compatibility.
 #[inline]
-fn strip_inline_comment(line: &str) -> &str {
+pub(crate) fn strip_inline_comment(line: &str) -> &str {
     let mut in_dquote = false;
     let mut in_squote = false;
     for (i, ch) in line.char_indices() {
@@ -74,7 +74,7 @@ fn multiline_array_consumer_does_not_eat_section_header() {
         parsed
             .values
             .get("scene-custom.hacker-mode.color")
-            .map(String::as_str),
+            .and_then(crate::config_schema::ConfigValue::as_str),
         Some("green"),
         "scene-custom.hacker-mode.color must be preserved; got: {:?}",
         parsed.values
@@ -83,7 +83,7 @@ fn multiline_array_consumer_does_not_eat_section_header() {
         parsed
             .values
             .get("scene-custom.hacker-mode.speed")
-            .map(String::as_str),
+            .and_then(crate::config_schema::ConfigValue::as_str),
         Some("28"),
         "scene-custom.hacker-mode.speed must be preserved; got: {:?}",
         parsed.values
@@ -124,8 +124,8 @@ fn quoted_array_with_trailing_comment_still_works() {
         parsed
             .values
             .get("colors-custom.mytheme.rain")
-            .map(String::as_str),
-        Some("[\"#ff0000\", \"#00ff00\"]"),
+            .and_then(crate::config_schema::ConfigValue::items),
+        Some(&["#ff0000".to_string(), "#00ff00".to_string()][..]),
         "quoted array with trailing comment must be preserved; got: {:?}",
         parsed.values
     );
@@ -146,10 +146,10 @@ fn multiline_quoted_array_still_works() {
         parsed.values
     );
     let v = parsed.values.get("colors-custom.mytheme.rain").unwrap();
-    assert!(v.contains("#ff0000"), "must contain first stop; got: {v:?}");
-    assert!(
-        v.contains("#00ff00"),
-        "must contain second stop; got: {v:?}"
+    assert_eq!(
+        v.items(),
+        Some(&["#ff0000".to_string(), "#00ff00".to_string()][..]),
+        "must keep both stops; got: {v:?}"
     );
 }
 
@@ -51,12 +51,15 @@ intro = cosmic
         parsed
             .values
             .get("scene-custom.hacker-mode.color")
-            .map(String::as_str),
+            .and_then(crate::config_schema::ConfigValue::as_str),
         Some("green")
     );
     // The top-level key was promoted to root scope.
     assert_eq!(
-        parsed.values.get("intro").map(String::as_str),
+        parsed
+            .values
+            .get("intro")
+            .and_then(crate::config_schema::ConfigValue::as_str),
         Some("cosmic")
     );
     // Promotion was recorded for --testconf transparency.
@@ -115,7 +118,7 @@ charset-custom.zen.set = \"|\"
         parsed
             .values
             .get("charset-custom.zen.set")
-            .map(String::as_str),
+            .and_then(crate::config_schema::ConfigValue::as_str),
         Some("|")
     );
     assert_eq!(parsed.promoted_keys.len(), 1);
@@ -141,11 +144,17 @@ bold = 1
         parsed
             .values
             .get("color.tune.brightness")
-            .map(String::as_str),
+            .and_then(crate::config_schema::ConfigValue::as_str),
         Some("1.0")
     );
     // bold was promoted to root scope.
-    assert_eq!(parsed.values.get("bold").map(String::as_str), Some("1"));
+    assert_eq!(
+        parsed
+            .values
+            .get("bold")
+            .and_then(crate::config_schema::ConfigValue::as_str),
+        Some("1")
+    );
     assert_eq!(
         parsed.promoted_keys,
         vec![("color.tune.bold".to_string(), "bold".to_string())]
@@ -169,12 +178,15 @@ color = red
         parsed
             .values
             .get("scene-custom.hacker-mode.color")
-            .map(String::as_str),
+            .and_then(crate::config_schema::ConfigValue::as_str),
         Some("red")
     );
     // Root `color` is also stored (it's a known key at root scope).
     assert_eq!(
-        parsed.values.get("color").map(String::as_str),
+        parsed
+            .values
+            .get("color")
+            .and_then(crate::config_schema::ConfigValue::as_str),
         Some("green")
     );
     // No promotion needed — both keys are recognized in their own scope.
@@ -201,7 +213,7 @@ colro = green
 #[test]
 fn empty_section_header_is_malformed_and_promotion_still_fires() {
     // `[]` is rejected by the parser as malformed (empty section name) —
-    // it does NOT reset `current_section` to root scope. So a flat
+    // the line is dropped, so it does NOT reset scope to the root table. So a flat
     // top-level key written after `[]` still gets nested
     // under the previous [scene-custom.<name>] block and needs promotion.
     // This is exactly the  depth-test scenario.
@@ -213,7 +225,10 @@ intro = cosmic
 ";
     let parsed = parse_config_text(content);
     assert!(
-        parsed.malformed_lines.iter().any(|l| l == "[]"),
+        parsed
+            .malformed_lines
+            .iter()
+            .any(|l| l.starts_with("[]  # ERROR:")),
         "expected [] to be malformed, got: {:?}",
         parsed.malformed_lines
     );
@@ -22,10 +22,19 @@ fn default_path_falls_back_to_home_config() {
 fn parse_key_value_lines() {
     let parsed = parse_config_text("color = ocean\nfps = 60\n");
     assert_eq!(
-        parsed.values.get("color").map(String::as_str),
+        parsed
+            .values
+            .get("color")
+            .and_then(crate::config_schema::ConfigValue::as_str),
         Some("ocean")
     );
-    assert_eq!(parsed.values.get("fps").map(String::as_str), Some("60"));
+    assert_eq!(
+        parsed
+            .values
+            .get("fps")
+            .and_then(crate::config_schema::ConfigValue::as_str),
+        Some("60")
+    );
     assert!(parsed.unknown_keys.is_empty());
 }
 
@@ -34,10 +43,19 @@ fn parse_ignores_comments_blank_lines_and_inline_comments() {
     let parsed =
         parse_config_text("\n# comment\ncharset = minimal # trailing comment\n\nspeed = 5\n");
     assert_eq!(
-        parsed.values.get("charset").map(String::as_str),
+        parsed
+            .values
+            .get("charset")
+            .and_then(crate::config_schema::ConfigValue::as_str),
         Some("minimal")
     );
-    assert_eq!(parsed.values.get("speed").map(String::as_str), Some("5"));
+    assert_eq!(
+        parsed
+            .values
+            .get("speed")
+            .and_then(crate::config_schema::ConfigValue::as_str),
+        Some("5")
+    );
     assert_eq!(parsed.values.len(), 2);
 }
 
@@ -45,7 +63,10 @@ fn parse_ignores_comments_blank_lines_and_inline_comments() {
 fn parse_unknown_keys_are_reported_and_ignored() {
     let parsed = parse_config_text("color = ocean\ncolro = typo\n");
     assert_eq!(
-        parsed.values.get("color").map(String::as_str),
+        parsed
+            .values
+            .get("color")
+            .and_then(crate::config_schema::ConfigValue::as_str),
         Some("ocean")
     );
     assert_eq!(parsed.unknown_keys, vec!["colro"]);
@@ -99,12 +120,44 @@ fn profile_keys_are_flagged_as_unknown() {
     assert!(parsed.malformed_lines.is_empty());
 }
 
+/// Rejected lines without the `  # ERROR: line N: <reason>` suffix.
+fn malformed_sources(parsed: &ParsedConfig) -> Vec<&str> {
+    parsed
+        .malformed_lines
+        .iter()
+        .map(|l| {
+            l.split_once("  # ERROR: ")
+                .map_or(l.as_str(), |(src, _)| src)
+        })
+        .collect()
+}
+
+#[test]
+fn malformed_lines_carry_the_parser_reason() {
+    let parsed = parse_config_text(
+        "fps = 60
+fps = 30
+",
+    );
+    assert_eq!(
+        parsed.malformed_lines,
+        vec!["fps = 30  # ERROR: line 2: duplicate key"]
+    );
+    assert_eq!(
+        parsed
+            .values
+            .get("fps")
+            .and_then(crate::config_schema::ConfigValue::as_str),
+        Some("60")
+    );
+}
+
 #[test]
 fn malformed_lines_without_equals_are_collected() {
     // Lines with no '=' on a non-empty, non-comment line are malformed.
     let parsed = parse_config_text("color = ocean\necho here should error\n");
     assert_eq!(parsed.values.len(), 1);
-    assert_eq!(parsed.malformed_lines, vec!["echo here should error"]);
+    assert_eq!(malformed_sources(&parsed), ["echo here should error"]);
 }
 
 #[test]
@@ -112,7 +165,7 @@ fn malformed_lines_with_empty_value_are_collected() {
     // `key =` (no value) is malformed.
     let parsed = parse_config_text("color = ocean\nspeed =\n");
     assert_eq!(parsed.values.len(), 1);
-    assert_eq!(parsed.malformed_lines, vec!["speed ="]);
+    assert_eq!(malformed_sources(&parsed), ["speed ="]);
 }
 
 #[test]
@@ -120,7 +173,7 @@ fn malformed_lines_with_empty_key_are_collected() {
     // `= value` (no key) is malformed.
     let parsed = parse_config_text("color = ocean\n= 60\n");
     assert_eq!(parsed.values.len(), 1);
-    assert_eq!(parsed.malformed_lines, vec!["= 60"]);
+    assert_eq!(malformed_sources(&parsed), ["= 60"]);
 }
 
 #[test]
@@ -137,14 +190,14 @@ fn malformed_lines_inline_comment_stripped() {
     // A malformed line with an inline comment should be reported without
     // the comment portion.
     let parsed = parse_config_text("echo bad line # this is a comment\n");
-    assert_eq!(parsed.malformed_lines, vec!["echo bad line"]);
+    assert_eq!(malformed_sources(&parsed), ["echo bad line"]);
 }
 
 #[test]
 fn dump_config_contains_all_supported_keys() {
     let dump = dump_config_text();
-    for key in USER_CONFIG_KEYS.iter() {
-        assert!(dump.contains(*key), "dump config should mention {key}");
+    for key in crate::config_schema::root_keys() {
+        assert!(dump.contains(key), "dump config should mention {key}");
     }
     assert!(dump.contains("[scene-custom.hacker-mode]"));
     // v30+: ambient phase scheduler must be documented with at least one
@@ -242,9 +295,9 @@ fn dump_config_with_header_starts_with_header_lines() {
 fn dump_config_with_header_includes_all_keys() {
     // The header prepended must not break the existing key-coverage test.
     let dump = dump_config_with_header();
-    for key in USER_CONFIG_KEYS.iter() {
+    for key in crate::config_schema::root_keys() {
         assert!(
-            dump.contains(*key),
+            dump.contains(key),
             "header'd dump should still mention {key}"
         );
     }
@@ -272,9 +325,8 @@ fn parse_multiline_array_joins_correctly() {
     );
     let rain = parsed.values.get("colors-custom.zen.rain");
     assert!(rain.is_some(), "rain key should be parsed");
-    let rain = rain.unwrap();
-    assert!(rain.starts_with('['), "rain value should start with [");
-    assert!(rain.ends_with(']'), "rain value should end with ]");
+    let rain = rain.unwrap().items().expect("rain value should be a list");
+    assert_eq!(rain.len(), 7, "rain value should keep every stop");
 }
 
 // ── Termux fix: path resolution tests ──
@@ -17,6 +17,17 @@
 //!   Strict validation — any invalid value rejects the entire config.
 //! - Render thread: `try_recv()` each frame; rebuilds Cloud on update.
 //!
+//! ## Watched files
+//!
+//! Config is layered ([`crate::config_layers`]): `config.toml`, the files
+//! it includes and the `conf.d/*.toml` drop-ins. The native watcher
+//! watches the directory of every layer plus the `conf.d/` directories,
+//! and a change to any of them reparses the whole stack. The set is
+//! recomputed on every reload, so a newly added include or drop-in is
+//! picked up. The polling heartbeat checks the same files, and lists the
+//! `conf.d/` directories on every cycle so a new or deleted drop-in is
+//! seen without a native event.
+//!
 //! ## Strict validation
 //!
 //! Uses the same `validate_field_value` rules as `--testconf`. Invalid
@@ -39,7 +50,7 @@
 //! malformed config should not be silently ignored.
 
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::mpsc::{self, Receiver, SyncSender};
 use std::sync::{Arc, Mutex};
@@ -48,6 +59,7 @@ use std::time::Duration;
 use notify::{event::EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 
 // Polling heartbeat + snapshot dedup live in live_config_poll.rs.
+use crate::config_schema::{get_str, ConfigMap};
 use crate::live_config_poll::{
     env_poll_interval_ms, polling_heartbeat, snapshot_file_state, snapshot_file_state_cached,
     FileStateSnapshot,
@@ -69,16 +81,21 @@ pub(crate) use crate::live_config_state::{
 
 /// Live config event sent from watcher to render thread.
 /// Ok = valid config, rebuild Cloud. Err = invalid, exit cosmostrix.
-pub(crate) type LiveConfigEvent = Result<HashMap<String, String>, String>;
+pub(crate) type LiveConfigEvent = Result<ConfigMap, String>;
 
 /// Spawn a config file watcher on a background thread.
 ///
-/// Returns a `Receiver<HashMap<String, String>>` that the render thread polls
+/// Returns a `Receiver<ConfigMap>` that the render thread polls
 /// with `try_recv()` each frame. The watcher validates config strictly
 /// before sending — invalid configs are rejected with a stderr error.
 /// Returns `None` if the file doesn't exist or can't be watched.
 pub(crate) fn spawn_watcher(config_path: PathBuf) -> Option<Receiver<LiveConfigEvent>> {
-    if !config_path.exists() {
+    // A drop-in alone (no config.toml) is still a config worth watching.
+    if !config_path.exists()
+        && configfile::load_config_layers(Some(&config_path))
+            .files
+            .is_empty()
+    {
         lr_trace!(
             "config file does not exist — watcher NOT spawned: {}",
             config_path.display()
@@ -147,11 +164,16 @@ fn watcher_loop(path: PathBuf, tx: SyncSender<LiveConfigEvent>) {
     // Pillar 3: bounded notify channel (cap 64) — prevents unbounded growth.
     let (notify_tx, notify_rx) = std::sync::mpsc::sync_channel::<notify::Result<notify::Event>>(64);
 
+    // Every config layer, for event matching and extra watch directories.
+    let mut watch = WatchSet::new(&path, &configfile::load_config_layers(Some(&path)));
+    // The poller's copy, refreshed after every event.
+    let poll_watch = Arc::new(Mutex::new(watch.clone()));
+
     // Snapshot initial state to avoid startup reload.
-    let last_processed_state = Arc::new(Mutex::new(snapshot_file_state(&path)));
+    let last_processed_state = Arc::new(Mutex::new(watch.snapshots()));
 
     // Spawn polling heartbeat (recovery loop restarts on panic).
-    let poll_path = path.clone();
+    let poll_set = poll_watch.clone();
     let poll_tx = notify_tx.clone();
     let poll_counter = change_counter.clone();
     let poll_spawn_result = std::thread::Builder::new()
@@ -162,11 +184,17 @@ fn watcher_loop(path: PathBuf, tx: SyncSender<LiveConfigEvent>) {
                 poll_interval_ms
             );
             loop {
-                let path_inner = poll_path.clone();
+                let set_inner = poll_set.clone();
                 let tx_inner = poll_tx.clone();
                 let counter_inner = poll_counter.clone();
                 let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
-                    polling_heartbeat(path_inner, tx_inner, poll_interval_ms, counter_inner);
+                    let targets = move || {
+                        set_inner
+                            .lock()
+                            .map(|w| w.poll_paths())
+                            .unwrap_or_default()
+                    };
+                    polling_heartbeat(targets, tx_inner, poll_interval_ms, counter_inner);
                 }));
                 match result {
                     Ok(()) => {
@@ -233,6 +261,8 @@ fn watcher_loop(path: PathBuf, tx: SyncSender<LiveConfigEvent>) {
         })
         .unwrap_or_else(|| PathBuf::from("."));
 
+    // Directories with a native watch registered (main directory first).
+    let mut watched_dirs: Vec<PathBuf> = Vec::new();
     if let Some(ref mut w) = watcher {
         lr_trace!(
             "registering native watch on directory: {}",
@@ -248,12 +278,15 @@ fn watcher_loop(path: PathBuf, tx: SyncSender<LiveConfigEvent>) {
             watcher = None;
         } else {
             lr_trace!("native watch registered on: {}", watch_dir.display());
+            watched_dirs.push(watch_dir);
         }
     } else {
         lr_trace!("no native watcher — polling heartbeat is the sole change detector");
     }
+    if let Some(ref mut w) = watcher {
+        watch_layer_dirs(w, &watch, &mut watched_dirs);
+    }
 
-    let target_file = Arc::new(path.clone());
     let mut last_event = std::time::Instant::now();
 
     // native watcher liveness diagnostic.
@@ -262,7 +295,7 @@ fn watcher_loop(path: PathBuf, tx: SyncSender<LiveConfigEvent>) {
     let mut last_native_event: Option<std::time::Instant> = None;
     let mut native_silence_warned = false;
 
-    let _watcher = watcher;
+    let mut watcher = watcher;
     lr_trace!("event loop started — waiting for events on notify_rx");
     for event_result in notify_rx.iter() {
         // mpsc iter() returns None only when ALL senders drop. Err here =
@@ -309,7 +342,7 @@ fn watcher_loop(path: PathBuf, tx: SyncSender<LiveConfigEvent>) {
 
         if !handle_notify_event(
             event_result,
-            &target_file,
+            &mut watch,
             &path,
             &tx,
             &mut last_event,
@@ -319,35 +352,152 @@ fn watcher_loop(path: PathBuf, tx: SyncSender<LiveConfigEvent>) {
         ) {
             break;
         }
+        // A reload may have added an include or a conf.d directory.
+        if let Some(ref mut w) = watcher {
+            watch_layer_dirs(w, &watch, &mut watched_dirs);
+        }
+        if let Ok(mut shared) = poll_watch.lock() {
+            shared.clone_from(&watch);
+        }
     }
     lr_trace!("watcher_loop exited");
 }
 
+/// Files the watcher reacts to: every config layer, plus any `*.toml`
+/// appearing in a `conf.d/` directory.
+#[derive(Clone, Debug, Default)]
+struct WatchSet {
+    /// Main config path first (tracked even while it does not exist),
+    /// then the includes and drop-ins that were read.
+    files: Vec<PathBuf>,
+    conf_d_dirs: Vec<PathBuf>,
+}
+
+impl WatchSet {
+    fn new(main: &Path, layers: &crate::config_layers::ConfigLayers) -> Self {
+        let mut files = vec![main.to_path_buf()];
+        for file in &layers.files {
+            if !files.contains(file) {
+                files.push(file.clone());
+            }
+        }
+        Self {
+            files,
+            conf_d_dirs: layers.conf_d_dirs.clone(),
+        }
+    }
+
+    /// The tracked file among an event's paths, if any.
+    fn matching<'a>(&self, paths: &'a [PathBuf]) -> Option<&'a PathBuf> {
+        paths
+            .iter()
+            .find(|p| self.files.contains(p) || self.is_drop_in(p))
+    }
+
+    fn is_drop_in(&self, path: &Path) -> bool {
+        path.parent()
+            .is_some_and(|dir| self.conf_d_dirs.iter().any(|d| d == dir))
+            && crate::config_layers::is_drop_in_name(path)
+    }
+
+    /// Directories needing a native watch: each layer's parent, and each
+    /// `conf.d/` that exists.
+    fn dirs(&self) -> Vec<PathBuf> {
+        let mut dirs: Vec<PathBuf> = Vec::new();
+        let parents = self.files.iter().filter_map(|f| f.parent());
+        let conf_d = self.conf_d_dirs.iter().map(PathBuf::as_path);
+        for dir in parents.chain(conf_d.filter(|d| d.is_dir())) {
+            if !dir.as_os_str().is_empty() && !dirs.iter().any(|d| d == dir) {
+                dirs.push(dir.to_path_buf());
+            }
+        }
+        dirs
+    }
+
+    /// Files the polling heartbeat checks: the main config and includes,
+    /// plus the drop-ins that exist right now (listed fresh, so an added
+    /// or deleted one shows up without a reload).
+    fn poll_paths(&self) -> Vec<PathBuf> {
+        let mut paths: Vec<PathBuf> = self
+            .files
+            .iter()
+            .filter(|f| !self.is_drop_in(f))
+            .cloned()
+            .collect();
+        for dir in &self.conf_d_dirs {
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                continue;
+            };
+            let mut drop_ins: Vec<PathBuf> = entries
+                .filter_map(|e| e.ok().map(|e| e.path()))
+                .filter(|p| p.is_file() && crate::config_layers::is_drop_in_name(p))
+                .collect();
+            drop_ins.sort();
+            paths.extend(drop_ins);
+        }
+        paths
+    }
+
+    fn snapshots(&self) -> HashMap<PathBuf, FileStateSnapshot> {
+        self.files
+            .iter()
+            .map(|f| (f.clone(), snapshot_file_state(f)))
+            .collect()
+    }
+}
+
+/// Register a native watch on every layer directory not watched yet.
+/// Failures are logged; the config.toml watch (or the poller) still runs.
+fn watch_layer_dirs(
+    watcher: &mut RecommendedWatcher,
+    watch: &WatchSet,
+    watched_dirs: &mut Vec<PathBuf>,
+) {
+    for dir in watch.dirs() {
+        if watched_dirs.contains(&dir) {
+            continue;
+        }
+        match watcher.watch(&dir, RecursiveMode::NonRecursive) {
+            Ok(()) => lr_trace!("native watch registered on layer dir: {}", dir.display()),
+            Err(e) => {
+                lr_trace!("layer dir watch FAILED: {}: {e}", dir.display());
+                push_runtime_warning(&format!(
+                    "[live-reload] cannot watch {}: {e} — edits there are caught by the polling heartbeat",
+                    dir.display()
+                ));
+            }
+        }
+        // Recorded either way: retrying a failing directory on every
+        // event would only repeat the warning.
+        watched_dirs.push(dir);
+    }
+}
+
 /// Process a single notify event. Returns `false` if channel closed.
-/// Dedup: mtime + size + content hash; drops if all three equal
-/// `last_processed_state` (critical on Termux where mtime is unreliable).
+/// Dedup: mtime + size + content hash per layer file; drops if all three
+/// equal `last_processed_state` (critical on Termux where mtime is
+/// unreliable).
 #[allow(clippy::too_many_arguments)]
 fn handle_notify_event(
     event_result: notify::Result<notify::Event>,
-    target_file: &Arc<PathBuf>,
-    path: &PathBuf,
+    watch: &mut WatchSet,
+    path: &Path,
     tx: &SyncSender<LiveConfigEvent>,
     last_event: &mut std::time::Instant,
     debounce_ms: u64,
-    last_processed_state: &Arc<Mutex<FileStateSnapshot>>,
+    last_processed_state: &Arc<Mutex<HashMap<PathBuf, FileStateSnapshot>>>,
     change_counter: &Arc<AtomicU64>,
 ) -> bool {
     match event_result {
         Ok(event) => {
-            let touches_target = event.paths.iter().any(|p| p == &**target_file);
-            if !touches_target {
+            let Some(changed) = watch.matching(&event.paths).cloned() else {
                 lr_trace!(
                     "event ignored (does not touch target): kind={:?} paths={:?}",
                     event.kind,
                     event.paths
                 );
                 return true;
-            }
+            };
 
             let relevant = matches!(
                 event.kind,
@@ -374,8 +524,9 @@ fn handle_notify_event(
             // Small delay for atomic-save rename completion.
             std::thread::sleep(Duration::from_millis(50));
 
-            // SNAPSHOT DEDUP: mtime + size + content hash. Drop if
-            // equal to last_processed_state on all three signals.
+            // SNAPSHOT DEDUP: mtime + size + content hash of the changed
+            // layer file. Drop if equal to its last_processed_state entry
+            // on all three signals.
             //
             // masterclass: use `snapshot_file_state_cached` with the
             // previous snapshot as cache. On the common duplicate-event
@@ -388,35 +539,33 @@ fn handle_notify_event(
             // `last_processed_state` between our snapshot and our
             // compare+update. Holding the lock for the snapshot is safe
             // because the fast path is just `metadata()` (~5µs).
-            let current_state = {
-                // P1-#11: poison-safe lock. Poisoned mutex → skip, don't panic.
-                let guard = match last_processed_state.lock() {
-                    Ok(g) => g,
-                    Err(_) => return true,
-                };
-                snapshot_file_state_cached(path, Some(&*guard))
-            };
-            if current_state.size.is_none() {
-                // File doesn't exist (atomic save in progress) — skip.
-                lr_trace!("snapshot: file unreadable — skipping event");
-                return true;
-            }
             {
                 // P1-#11: poison-safe lock. Poisoned mutex → skip, don't panic.
                 let mut guard = match last_processed_state.lock() {
                     Ok(g) => g,
                     Err(_) => return true,
                 };
-                if *guard == current_state {
-                    // Duplicate event — both native + poll detected same change.
-                    lr_trace!("snapshot dedup: dropping duplicate for {:?}", current_state);
-                    return true;
+                let current_state = snapshot_file_state_cached(&changed, guard.get(&changed));
+                if current_state.size.is_none() {
+                    // A deleted drop-in is a real change: its keys go away.
+                    // Anything else missing is an atomic save in progress.
+                    if !watch.is_drop_in(&changed) || guard.remove(&changed).is_none() {
+                        lr_trace!("snapshot: file unreadable — skipping event");
+                        return true;
+                    }
+                    lr_trace!("drop-in removed: {}", changed.display());
+                } else {
+                    if guard.get(&changed) == Some(&current_state) {
+                        // Duplicate event — both native + poll detected same change.
+                        lr_trace!("snapshot dedup: dropping duplicate for {:?}", current_state);
+                        return true;
+                    }
+                    guard.insert(changed.clone(), current_state);
                 }
-                *guard = current_state;
             }
             lr_trace!(
-                "accepted event for {:?} (kind={:?})",
-                current_state,
+                "accepted event for {} (kind={:?})",
+                changed.display(),
                 event.kind
             );
 
@@ -424,19 +573,35 @@ fn handle_notify_event(
             // mode (200ms × 5 cycles) to catch rapid follow-up edits.
             change_counter.fetch_add(1, Ordering::AcqRel);
 
-            // Reparse via parse_config_text to catch malformed_lines AND unknown_keys.
-            let content = match std::fs::read_to_string(path) {
-                Ok(c) => c,
-                Err(_) => return true,
-            };
-            let parsed = configfile::parse_config_text(&content);
+            // Reparse the whole layer stack (config.toml + includes +
+            // conf.d) to catch malformed_lines AND unknown_keys anywhere.
+            let layers = configfile::load_config_layers(Some(path));
+            if layers.files.is_empty() {
+                return true; // nothing readable (atomic save in progress)
+            }
+            // Track layers added by this edit; snapshot them now so their
+            // first real change is not mistaken for a duplicate.
+            *watch = WatchSet::new(path, &layers);
+            if let Ok(mut guard) = last_processed_state.lock() {
+                for file in &watch.files {
+                    if !guard.contains_key(file) {
+                        guard.insert(file.clone(), snapshot_file_state(file));
+                    }
+                }
+            }
+            let parsed = layers.parsed;
             lr_trace!(
-                "parsed: {} values, {} malformed, {} unknown",
+                "parsed {} layer(s): {} values, {} malformed, {} unknown",
+                layers.files.len(),
                 parsed.values.len(),
                 parsed.malformed_lines.len(),
                 parsed.unknown_keys.len()
             );
-            if parsed.values.is_empty() && parsed.malformed_lines.is_empty() {
+            if parsed.values.is_empty()
+                && parsed.malformed_lines.is_empty()
+                && parsed.type_errors.is_empty()
+                && parsed.include_errors.is_empty()
+            {
                 return true; // empty parse — likely empty/whitespace-only file
             }
 
@@ -472,7 +637,21 @@ fn validate_and_send(
     parsed: &configfile::ParsedConfig,
     tx: &SyncSender<LiveConfigEvent>,
 ) -> Result<(), String> {
-    // Check malformed lines first — these are syntax errors.
+    // Includes / drop-ins that could not be read: the config is incomplete.
+    if !parsed.include_errors.is_empty() {
+        let errs: Vec<&str> = parsed
+            .include_errors
+            .iter()
+            .take(3)
+            .map(String::as_str)
+            .collect();
+        let msg = format!("include error(s): {}", errs.join("; "));
+        push_validation_rejection(&msg);
+        let _ = tx.try_send(Err(msg.clone()));
+        return Err(msg);
+    }
+
+    // Check malformed lines — these are syntax errors.
     if !parsed.malformed_lines.is_empty() {
         let lines: Vec<&str> = parsed
             .malformed_lines
@@ -480,16 +659,27 @@ fn validate_and_send(
             .take(3)
             .map(String::as_str)
             .collect();
-        let msg = format!(
-            "malformed line(s): '{}' (expected 'key = value' syntax)",
-            lines.join(", ")
-        );
+        let msg = format!("malformed line(s): '{}' (not valid TOML)", lines.join(", "));
         // (bug #14): surface to session rejection log.
         push_validation_rejection(&msg);
         let _ = tx.try_send(Err(msg.clone()));
         return Err(msg);
     }
 
+    // Known keys with the wrong TOML type — rejected like a bad value.
+    if !parsed.type_errors.is_empty() {
+        let errs: Vec<String> = parsed
+            .type_errors
+            .iter()
+            .take(3)
+            .map(|(key, msg)| format!("{key}: {msg}"))
+            .collect();
+        let msg = format!("wrong value type: '{}'", errs.join(", "));
+        push_validation_rejection(&msg);
+        let _ = tx.try_send(Err(msg.clone()));
+        return Err(msg);
+    }
+
     // Check unknown keys.
     if !parsed.unknown_keys.is_empty() {
         let keys: Vec<&str> = parsed
@@ -561,7 +751,7 @@ fn validate_and_send(
 #[must_use]
 pub(crate) fn rebuild_cloud_config(
     base: &crate::app::CloudConfig,
-    cfg: &HashMap<String, String>,
+    cfg: &ConfigMap,
 ) -> crate::app::CloudConfig {
     let mut new = base.clone();
     // Snapshot CLI-explicit tracker — preserved across reloads.
@@ -579,7 +769,7 @@ pub(crate) fn rebuild_cloud_config(
 
     // Color scheme — skip if CLI --color was explicit.
     if !cli.color {
-        if let Some(v) = cfg.get("color") {
+        if let Some(v) = get_str(cfg, "color") {
             if let Ok(scheme) = crate::cli::parse_color_scheme(v) {
                 lr_trace!("apply color='{}' -> {:?}", v, scheme);
                 new.color_scheme = scheme;
@@ -604,7 +794,7 @@ pub(crate) fn rebuild_cloud_config(
 
     // Charset — skip if CLI --charset
     if !cli.charset {
-        if let Some(v) = cfg.get("charset") {
+        if let Some(v) = get_str(cfg, "charset") {
             // v25: charset-custom.<name> takes precedence over built-in.
             if let Some(custom_chars) =
                 crate::charset_custom::load_custom_charset_if_matches(cfg, v)
@@ -614,11 +804,11 @@ pub(crate) fn rebuild_cloud_config(
                     v,
                     custom_chars.len()
                 );
-                new.charset_preset = v.clone();
+                new.charset_preset = v.to_string();
                 new.chars = custom_chars;
             } else if let Ok(charset) = crate::charset::charset_from_str(v, false) {
                 lr_trace!("apply charset='{}' (built-in)", v);
-                new.charset_preset = v.clone();
+                new.charset_preset = v.to_string();
                 new.chars = crate::charset::build_chars(charset, &new.user_ranges, new.def_ascii);
             } else {
                 lr_trace!(
@@ -638,7 +828,7 @@ pub(crate) fn rebuild_cloud_config(
     // Scene — skip if CLI --scene explicit. scene color/charset are
     // defaults; user config values win.
     if !cli.scene {
-        if let Some(v) = cfg.get("scene") {
+        if let Some(v) = get_str(cfg, "scene") {
             // v50 fix: update new.scene_name to match the config's scene
             // value. Without this, the live-reload path left scene_name at
             // base.scene_name (the previous value), so the HUD 'scn:' line
@@ -656,7 +846,7 @@ pub(crate) fn rebuild_cloud_config(
             // casing from config for display, matching the startup path in
             // main.rs (args.scene.as_deref().unwrap_or(DEFAULT_SCENE)).
             lr_trace!("apply scene='{}' (updating scene_name)", v);
-            new.scene_name = v.clone();
+            new.scene_name = v.to_string();
             if let Some(scene_info) = crate::scene::get_scene(v) {
                 new.rain_style = scene_info.config.rain_style;
                 if let Some(color) = scene_info.config.color {
@@ -717,7 +907,7 @@ pub(crate) fn rebuild_cloud_config(
 
     // Speed — skip if CLI --speed was explicit
     if !cli.speed {
-        if let Some(v) = cfg.get("speed") {
+        if let Some(v) = get_str(cfg, "speed") {
             if let Ok(n) = crate::validation::parse_canonical_speed("speed", v) {
                 lr_trace!("apply speed='{}' -> {}", v, n);
                 new.speed = n;
@@ -731,7 +921,7 @@ pub(crate) fn rebuild_cloud_config(
 
     // Density — skip if CLI --density was explicit
     if !cli.density {
-        if let Some(v) = cfg.get("density") {
+        if let Some(v) = get_str(cfg, "density") {
             if let Ok(n) = crate::validation::parse_canonical_f32_range("density", v, 0.01, 5.0) {
                 lr_trace!("apply density='{}' -> {}", v, n);
                 new.density = n;
@@ -746,7 +936,7 @@ pub(crate) fn rebuild_cloud_config(
 
     // FPS — skip if CLI --fps was explicit
     if !cli.fps {
-        if let Some(v) = cfg.get("fps") {
+        if let Some(v) = get_str(cfg, "fps") {
             if let Ok(n) = crate::validation::parse_canonical_f64_range("fps", v, 1.0, 240.0) {
                 lr_trace!("apply fps='{}' -> {}", v, n);
                 new.target_fps = n;
@@ -765,7 +955,7 @@ pub(crate) fn rebuild_cloud_config(
     // scene_custom.rs (bit-identical preset values, was inlined here).
     // max_dpc is NOT touched — never set by glitch_level presets at startup.
     if !cli.glitch_level {
-        if let Some(v) = cfg.get("glitch-level") {
+        if let Some(v) = get_str(cfg, "glitch-level") {
             lr_trace!("apply glitch-level='{}'", v);
             use clap::ValueEnum;
             match crate::config::GlitchLevel::from_str(v, true) {
@@ -787,7 +977,7 @@ pub(crate) fn rebuild_cloud_config(
     }
 
     // color-bg live reload (true = terminal default; false = solid black).
-    if let Some(v) = cfg.get("color-bg") {
+    if let Some(v) = get_str(cfg, "color-bg") {
         new.default_bg = match v.trim().to_ascii_lowercase().as_str() {
             "black" => false,
             "default-background" | "default_background" => true,
@@ -800,7 +990,7 @@ pub(crate) fn rebuild_cloud_config(
     // CLI --monolith-size wins over config on live-reload (was: config-only
     // path, no guard → CLI flag overridden by config edit on next reload).
     if !cli.monolith_size {
-        if let Some(v) = cfg.get("monolith-size") {
+        if let Some(v) = get_str(cfg, "monolith-size") {
             use clap::ValueEnum;
             if let Ok(size) = crate::runtime::MonolithSize::from_str(v, true) {
                 new.monolith_size = size;
@@ -811,7 +1001,7 @@ pub(crate) fn rebuild_cloud_config(
     // Crystal Dragon Engine — intent preservation: CLI --crystal-dragon
     // wins over config.toml on live reload.
     if !cli.crystal_dragon {
-        if let Some(v) = cfg.get("crystal-dragon") {
+        if let Some(v) = get_str(cfg, "crystal-dragon") {
             if let Some(b) = crate::config_apply::parse_bool_config("crystal-dragon", v) {
                 new.crystal_dragon = b;
             }
@@ -822,7 +1012,7 @@ pub(crate) fn rebuild_cloud_config(
     // over config (was: config-only path, no intent guard — CLI flag was
     // overridden by config edit on next reload). Now mirrors crystal-dragon.
     if !cli.power_dragon {
-        if let Some(v) = cfg.get("power-dragon") {
+        if let Some(v) = get_str(cfg, "power-dragon") {
             if let Some(b) = crate::config_apply::parse_bool_config("power-dragon", v) {
                 new.power_dragon = b;
             }
@@ -831,7 +1021,7 @@ pub(crate) fn rebuild_cloud_config(
 
     // (CLI-P-1): live-reload bold/shadingmode/async-mode (previously
     // silently ignored). Mirrors startup parsers with range validation.
-    if let Some(v) = cfg.get("bold").and_then(|s| s.trim().parse::<u8>().ok()) {
+    if let Some(v) = get_str(cfg, "bold").and_then(|s| s.trim().parse::<u8>().ok()) {
         // Range-gate to match startup parse_u8_config("bold", ..., 0, 2).
         // Upstream validate_config_strictly catches out-of-range before this
         // runs, but defense-in-depth prevents silent mis-parsing if that
@@ -847,10 +1037,7 @@ pub(crate) fn rebuild_cloud_config(
             new.bold_mode = base.bold_mode;
         }
     }
-    if let Some(v) = cfg
-        .get("shadingmode")
-        .and_then(|s| s.trim().parse::<u8>().ok())
-    {
+    if let Some(v) = get_str(cfg, "shadingmode").and_then(|s| s.trim().parse::<u8>().ok()) {
         new.shading_mode = match v {
             1 => crate::runtime::ShadingMode::DistanceFromHead,
             _ => crate::runtime::ShadingMode::Random,
@@ -863,7 +1050,7 @@ pub(crate) fn rebuild_cloud_config(
     // config (was: config-only path, no intent guard — CLI flag was
     // overridden by config edit on next reload). Now mirrors crystal-dragon.
     if !cli.async_mode {
-        if let Some(v) = cfg.get("async-mode") {
+        if let Some(v) = get_str(cfg, "async-mode") {
             if let Some(b) = crate::config_apply::parse_bool_config("async-mode", v) {
                 new.async_mode = b;
             }
@@ -872,6 +1059,11 @@ pub(crate) fn rebuild_cloud_config(
 
     // v20: scene-custom live reload — re-apply fields if active.
     if let Some(ref custom_name) = base.scene_custom_name {
+        // The density mask is cleared first so deleting the key (or
+        // breaking the file) on reload removes the mask instead of keeping
+        // the previous one.
+        new.density_mask = None;
+        new.density_mask_floor = crate::scene_custom::density_mask::DENSITY_MASK_DEFAULT_FLOOR;
         crate::scene_custom::apply_scene_custom_to_cloud_config(&mut new, cfg, custom_name);
     }
 
@@ -906,6 +1098,109 @@ pub(crate) fn rebuild_cloud_config(
         lr_trace!("color.tune: CLI --color-tune explicit — preserving base tune");
     }
 
+    // [rain-tuning] live reload — always re-parse, like color.tune: a key
+    // that was removed falls back to its compiled-in constant. Config-only,
+    // so there is no CLI guard. validate_and_send already rejected invalid
+    // values, so the error list is empty here.
+    let (new_rain_tuning, _) = crate::constants::rain_tuning_from_config(cfg);
+    if new_rain_tuning != new.rain_tuning {
+        lr_trace!(
+            "apply rain-tuning live reload: {:?} (was {:?})",
+            new_rain_tuning,
+            new.rain_tuning
+        );
+        new.rain_tuning = new_rain_tuning;
+    } else {
+        lr_trace!("rain-tuning: unchanged");
+    }
+
+    // [events] live reload — same always-re-parse contract; the rebuilt
+    // cloud hands the knobs to its event scheduler in create_cloud.
+    let (new_events, _) = crate::constants::event_tuning_from_config(cfg);
+    if new_events != new.event_tuning {
+        lr_trace!(
+            "apply events live reload: {:?} (was {:?})",
+            new_events,
+            new.event_tuning
+        );
+        new.event_tuning = new_events;
+    } else {
+        lr_trace!("events: unchanged");
+    }
+
+    // [power-dragon] live reload — same always-re-parse contract. The event
+    // loop pushes the result into PowerManager / self-healer / terminal
+    // after the rebuild.
+    let (new_power, _) = crate::constants::power_thresholds_from_config(cfg);
+    if new_power != new.power_thresholds {
+        lr_trace!(
+            "apply power-dragon live reload: {:?} (was {:?})",
+            new_power,
+            new.power_thresholds
+        );
+        new.power_thresholds = new_power;
+    } else {
+        lr_trace!("power-dragon: unchanged");
+    }
+
+    // [keys] live reload — same contract. The event loop reads the keymap
+    // from the live CloudConfig, so the new bindings apply to the next key.
+    // Strict validation only checks each key on its own, so two actions
+    // claiming one key get here; log the losing binding like startup does.
+    let (new_keymap, key_errors) = crate::interactive::keymap::keymap_from_config(cfg);
+    for (key, msg) in key_errors {
+        push_runtime_warning(&format!("live reload: invalid {key}: {msg}"));
+    }
+    if new_keymap != new.keymap {
+        lr_trace!("apply keys live reload: {:?}", new_keymap);
+        new.keymap = new_keymap;
+    } else {
+        lr_trace!("keys: unchanged");
+    }
+
+    // crystal-dragon-calc live reload: config-only, so the file is the
+    // only source — a removed key falls back to calc-v1. The rebuilt cloud
+    // picks the method up in create_cloud; calc-v2 memory is inherited.
+    let new_calc = get_str(cfg, "crystal-dragon-calc")
+        .and_then(|v| {
+            crate::crystal_dragon_engine::crystal_dragon_control::CrystalDragonCalcMethod::from_config(v)
+        })
+        .unwrap_or_default();
+    if new_calc != new.crystal_dragon_calc {
+        lr_trace!(
+            "apply crystal-dragon-calc live reload: {}",
+            new_calc.label()
+        );
+        new.crystal_dragon_calc = new_calc;
+    } else {
+        lr_trace!("crystal-dragon-calc: unchanged");
+    }
+
+    // crystal-dragon-sensor / -mix: same contract. The rebuilt cloud
+    // re-targets the inherited sensor in create_cloud.
+    let (new_sensors, _) =
+        crate::crystal_dragon_engine::crystal_dragon_control::sensors_from_config(cfg);
+    if new_sensors != new.crystal_dragon_sensors {
+        lr_trace!(
+            "apply crystal-dragon-sensor live reload: {}",
+            new_sensors.label()
+        );
+        new.crystal_dragon_sensors = new_sensors;
+    } else {
+        lr_trace!("crystal-dragon-sensor: unchanged");
+    }
+    // The event loop restarts the external reader when this changes.
+    let (new_external, _) = crate::crystal_dragon_engine::external::external_from_config(cfg);
+    if new_external != new.crystal_dragon_external {
+        lr_trace!(
+            "apply crystal-dragon-external live reload: {:?}",
+            new_external
+        );
+        new.crystal_dragon_external = new_external;
+    } else {
+        lr_trace!("crystal-dragon-external: unchanged");
+    }
+
     // v50.0.0-alpha.7: Live-reload for message / message-border / msg-mode.
     // Previously these 3 keys were NOT handled in rebuild_cloud_config —
     // editing config.toml mid-run had no effect until restart. This was
@@ -923,7 +1218,7 @@ pub(crate) fn rebuild_cloud_config(
     // message came from config (not CLI), clear it. CLI -m/-mb is unaffected.
     let msg_mode_on = if !cli.msg_mode {
         // CLI --msg-mode not explicit → read from config (default true).
-        cfg.get("msg-mode")
+        get_str(cfg, "msg-mode")
             .and_then(|v| crate::config_apply::parse_bool_config("msg-mode", v))
             .unwrap_or(true)
     } else {
@@ -934,10 +1229,9 @@ pub(crate) fn rebuild_cloud_config(
 
     if !cli.message {
         // CLI -m / -mb not explicit → read message from config.
-        let msg_from_config: Option<(String, bool)> = cfg
-            .get("message-border")
-            .map(|v| (v.clone(), true))
-            .or_else(|| cfg.get("message").map(|v| (v.clone(), false)));
+        let msg_from_config: Option<(String, bool)> = get_str(cfg, "message-border")
+            .map(|v| (v.to_string(), true))
+            .or_else(|| get_str(cfg, "message").map(|v| (v.to_string(), false)));
         if let Some((text, border)) = msg_from_config {
             // Apply msg-mode gate: if msg-mode=false, suppress config message.
             // CLI -m/-mb always wins (handled by cli.message guard above).
@@ -982,11 +1276,11 @@ pub(crate) fn rebuild_cloud_config(
     // cleared (mirrors startup behavior, but soft-fail on live-reload
     // to avoid crashing a running session).
     if !cli.intro_color {
-        if let Some(v) = cfg.get("intro-color") {
+        if let Some(v) = get_str(cfg, "intro-color") {
             let theme_ok = crate::theme::lookup_theme(v).is_some();
             let custom_ok = cfg.contains_key(&format!("colors-custom.{v}.bg"));
             if theme_ok || custom_ok {
-                new.intro_color = Some(v.clone());
+                new.intro_color = Some(v.to_string());
                 lr_trace!("apply intro-color='{}'", v);
             } else {
                 // Soft-fail on live-reload: log + clear. Don't crash the
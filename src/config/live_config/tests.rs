@@ -6,7 +6,7 @@ use super::*;
 #[test]
 fn validate_rejects_invalid_speed() {
     let mut cfg = HashMap::new();
-    cfg.insert("speed".to_string(), "100000".to_string());
+    cfg.insert("speed".to_string(), "100000".into());
     let result = crate::testconf::validate_config_strictly(&cfg);
     assert!(result.is_err());
     assert!(result.unwrap_err().contains("speed"));
@@ -15,7 +15,7 @@ fn validate_rejects_invalid_speed() {
 #[test]
 fn validate_rejects_invalid_density() {
     let mut cfg = HashMap::new();
-    cfg.insert("density".to_string(), "99.0".to_string());
+    cfg.insert("density".to_string(), "99.0".into());
     let result = crate::testconf::validate_config_strictly(&cfg);
     assert!(result.is_err());
 }
@@ -23,9 +23,9 @@ fn validate_rejects_invalid_density() {
 #[test]
 fn validate_accepts_valid_config() {
     let mut cfg = HashMap::new();
-    cfg.insert("speed".to_string(), "30".to_string());
-    cfg.insert("density".to_string(), "0.85".to_string());
-    cfg.insert("fps".to_string(), "60".to_string());
+    cfg.insert("speed".to_string(), "30".into());
+    cfg.insert("density".to_string(), "0.85".into());
+    cfg.insert("fps".to_string(), "60".into());
     let result = crate::testconf::validate_config_strictly(&cfg);
     assert!(result.is_ok());
 }
@@ -35,9 +35,9 @@ fn validate_skips_block_keys() {
     let mut cfg = HashMap::new();
     cfg.insert(
         "scene-custom.test.base-scene".to_string(),
-        "monolith".to_string(),
+        "monolith".into(),
     );
-    cfg.insert("speed".to_string(), "30".to_string());
+    cfg.insert("speed".to_string(), "30".into());
     let result = crate::testconf::validate_config_strictly(&cfg);
     assert!(result.is_ok());
 }
@@ -45,7 +45,7 @@ fn validate_skips_block_keys() {
 #[test]
 fn validate_rejects_invalid_charset() {
     let mut cfg = HashMap::new();
-    cfg.insert("charset".to_string(), "hackeres".to_string());
+    cfg.insert("charset".to_string(), "hackeres".into());
     let result = crate::testconf::validate_config_strictly(&cfg);
     assert!(result.is_err());
 }
@@ -78,10 +78,12 @@ fn minimal_cloud_config() -> crate::app::CloudConfig {
 
     crate::app::CloudConfig {
         color_mode: ColorMode::TrueColor,
+        color_dither: crate::config::ColorDither::Off,
         shading_mode: ShadingMode::Random,
         bold_mode: BoldMode::Random,
         async_mode: true,
         default_bg: true,
+        terminal_bg: None,
         color_scheme: ColorScheme::NeonPurple,
         custom_palette: None,
         custom_palette_name: None,
@@ -111,12 +113,22 @@ fn minimal_cloud_config() -> crate::app::CloudConfig {
         bench_duration: None,
         screen_size: None,
         color_tune: crate::color_tune::ColorTune::IDENTITY,
+        rain_tuning: crate::constants::RainTuning::DEFAULT,
+        event_tuning: crate::constants::EventTuning::default(),
+        power_thresholds: crate::constants::PowerThresholds::defaults(),
+        keymap: crate::interactive::keymap::Keymap::default(),
+        crystal_dragon_calc: Default::default(),
+        crystal_dragon_sensors: Default::default(),
+        crystal_dragon_external: None,
         json: false,
         save_baseline: None,
         compare_baseline: None,
         bench_io: false,
         bench_all: false,
         bench_scene: None,
+        record: None,
+        control_socket: None,
+        seed: None,
         verbose: false,
         density_auto: true,
         base_density: 0.75,
@@ -132,6 +144,8 @@ fn minimal_cloud_config() -> crate::app::CloudConfig {
         power_dragon: true,
         msg_mode: true,
         monolith_density_map: None,
+        density_mask: None,
+        density_mask_floor: crate::scene_custom::density_mask::DENSITY_MASK_DEFAULT_FLOOR,
         config_path_for_watcher: None,
         scene_name: "test-scene".to_string(),
         scene_custom_name: Some("test-scene".to_string()),
@@ -143,10 +157,7 @@ fn minimal_cloud_config() -> crate::app::CloudConfig {
 #[test]
 fn rebuild_applies_scene_custom_color_change() {
     let mut cfg = HashMap::new();
-    cfg.insert(
-        "scene-custom.test-scene.color".to_string(),
-        "green".to_string(),
-    );
+    cfg.insert("scene-custom.test-scene.color".to_string(), "green".into());
     let base = minimal_cloud_config();
     let new = rebuild_cloud_config(&base, &cfg);
     assert_eq!(new.color_scheme, crate::runtime::ColorScheme::Green);
@@ -157,8 +168,8 @@ fn rebuild_applies_scene_custom_color_change() {
 #[test]
 fn rebuild_user_color_wins_over_scene_default() {
     let mut cfg = HashMap::new();
-    cfg.insert("color".to_string(), "cosmos".to_string());
-    cfg.insert("scene".to_string(), "carbonic".to_string());
+    cfg.insert("color".to_string(), "cosmos".into());
+    cfg.insert("scene".to_string(), "carbonic".into());
     let new = rebuild_cloud_config(&minimal_cloud_config(), &cfg);
     assert_eq!(new.color_scheme, crate::runtime::ColorScheme::Cosmos);
 }
@@ -167,8 +178,8 @@ fn rebuild_user_color_wins_over_scene_default() {
 #[test]
 fn rebuild_user_charset_wins_over_scene_default() {
     let mut cfg = HashMap::new();
-    cfg.insert("charset".to_string(), "retro".to_string());
-    cfg.insert("scene".to_string(), "carbonic".to_string());
+    cfg.insert("charset".to_string(), "retro".into());
+    cfg.insert("scene".to_string(), "carbonic".into());
     let new = rebuild_cloud_config(&minimal_cloud_config(), &cfg);
     assert_eq!(new.charset_preset, "retro");
 }
@@ -179,13 +190,13 @@ fn rebuild_applies_color_bg_live_reload() {
     let base = minimal_cloud_config();
     assert!(base.default_bg);
     let mut cfg = HashMap::new();
-    cfg.insert("color-bg".to_string(), "black".to_string());
+    cfg.insert("color-bg".to_string(), "black".into());
     assert!(
         !rebuild_cloud_config(&base, &cfg).default_bg,
         "black → solid black"
     );
     let mut cfg2 = HashMap::new();
-    cfg2.insert("color-bg".to_string(), "default-background".to_string());
+    cfg2.insert("color-bg".to_string(), "default-background".into());
     assert!(
         rebuild_cloud_config(&base, &cfg2).default_bg,
         "default-background → terminal default"
@@ -197,7 +208,7 @@ fn rebuild_applies_color_bg_live_reload() {
 fn rebuild_color_bg_unrecognized_keeps_old() {
     let base = minimal_cloud_config();
     let mut cfg = HashMap::new();
-    cfg.insert("color-bg".to_string(), "purple".to_string());
+    cfg.insert("color-bg".to_string(), "purple".into());
     assert_eq!(
         rebuild_cloud_config(&base, &cfg).default_bg,
         base.default_bg
@@ -207,14 +218,8 @@ fn rebuild_color_bg_unrecognized_keeps_old() {
 #[test]
 fn rebuild_applies_scene_custom_speed_and_density_changes() {
     let mut cfg = HashMap::new();
-    cfg.insert(
-        "scene-custom.test-scene.speed".to_string(),
-        "24".to_string(),
-    );
-    cfg.insert(
-        "scene-custom.test-scene.density".to_string(),
-        "0.50".to_string(),
-    );
+    cfg.insert("scene-custom.test-scene.speed".to_string(), "24".into());
+    cfg.insert("scene-custom.test-scene.density".to_string(), "0.50".into());
     let base = minimal_cloud_config();
     let new = rebuild_cloud_config(&base, &cfg);
     assert_eq!(new.speed, 24.0);
@@ -227,7 +232,7 @@ fn rebuild_applies_scene_custom_density_map_change() {
     let mut cfg = HashMap::new();
     cfg.insert(
         "scene-custom.test-scene.density-map".to_string(),
-        "1.0,0.5,0.0,0.8".to_string(),
+        "1.0,0.5,0.0,0.8".into(),
     );
     let base = minimal_cloud_config();
     let new = rebuild_cloud_config(&base, &cfg);
@@ -245,10 +250,7 @@ fn rebuild_without_scene_custom_name_does_not_apply_custom_fields() {
     // scene-custom.* keys in config must NOT be applied — they belong
     // to a different scene and could clobber the active one.
     let mut cfg = HashMap::new();
-    cfg.insert(
-        "scene-custom.other-scene.color".to_string(),
-        "green".to_string(),
-    );
+    cfg.insert("scene-custom.other-scene.color".to_string(), "green".into());
     let mut base = minimal_cloud_config();
     base.scene_custom_name = None;
     let new = rebuild_cloud_config(&base, &cfg);
@@ -271,7 +273,7 @@ fn rebuild_without_scene_custom_name_does_not_apply_custom_fields() {
 #[test]
 fn rebuild_updates_scene_name_when_config_scene_changes() {
     let mut cfg = HashMap::new();
-    cfg.insert("scene".to_string(), "monolith".to_string());
+    cfg.insert("scene".to_string(), "monolith".into());
     // Base config has scene_name = "test-scene" (from minimal_cloud_config).
     let base = minimal_cloud_config();
     assert_eq!(
@@ -300,7 +302,7 @@ fn rebuild_updates_scene_name_when_config_scene_changes() {
 #[test]
 fn rebuild_preserves_scene_name_casing_from_config() {
     let mut cfg = HashMap::new();
-    cfg.insert("scene".to_string(), "Monolith".to_string());
+    cfg.insert("scene".to_string(), "Monolith".into());
     let base = minimal_cloud_config();
     let new = rebuild_cloud_config(&base, &cfg);
     assert_eq!(
@@ -314,7 +316,7 @@ fn rebuild_preserves_scene_name_casing_from_config() {
 #[test]
 fn rebuild_preserves_cli_explicit_scene_name_over_config() {
     let mut cfg = HashMap::new();
-    cfg.insert("scene".to_string(), "monolith".to_string());
+    cfg.insert("scene".to_string(), "monolith".into());
     let mut base = minimal_cloud_config();
     base.cli_explicit.scene = true;
     base.scene_name = "matrix".to_string();
@@ -333,7 +335,7 @@ fn rebuild_preserves_cli_explicit_scene_name_over_config() {
 #[test]
 fn rebuild_preserves_cli_explicit_color_over_config() {
     let mut cfg = HashMap::new();
-    cfg.insert("color".to_string(), "snow".to_string());
+    cfg.insert("color".to_string(), "snow".into());
     let mut base = minimal_cloud_config();
     // Simulate the user running `cosmostrix -c green`: the CLI flag
     // is recorded as explicit, and the color_scheme is set to Green.
@@ -352,7 +354,7 @@ fn rebuild_preserves_cli_explicit_color_over_config() {
 #[test]
 fn rebuild_applies_config_color_when_cli_not_explicit() {
     let mut cfg = HashMap::new();
-    cfg.insert("color".to_string(), "snow".to_string());
+    cfg.insert("color".to_string(), "snow".into());
     let base = minimal_cloud_config();
     // base.cli_explicit.color is false (default) — no CLI override.
     let new = rebuild_cloud_config(&base, &cfg);
@@ -364,7 +366,7 @@ fn rebuild_applies_config_color_when_cli_not_explicit() {
 #[test]
 fn rebuild_preserves_cli_explicit_speed_over_scene() {
     let mut cfg = HashMap::new();
-    cfg.insert("scene".to_string(), "matrix".to_string());
+    cfg.insert("scene".to_string(), "matrix".into());
     let mut base = minimal_cloud_config();
     base.cli_explicit.speed = true;
     base.cli_explicit.scene = false;
@@ -412,7 +414,7 @@ fn rebuild_applies_color_tune_live_reload_brightness() {
         "base config should start at identity brightness"
     );
     let mut cfg = HashMap::new();
-    cfg.insert("color.tune.brightness".to_string(), "0.5".to_string());
+    cfg.insert("color.tune.brightness".to_string(), "0.5".into());
     let new = rebuild_cloud_config(&base, &cfg);
     assert!(
         (new.color_tune.brightness - 0.5).abs() < 1e-6,
@@ -426,11 +428,11 @@ fn rebuild_applies_color_tune_live_reload_brightness() {
 fn rebuild_applies_color_tune_live_reload_all_fields() {
     let base = minimal_cloud_config();
     let mut cfg = HashMap::new();
-    cfg.insert("color.tune.brightness".to_string(), "1.5".to_string());
-    cfg.insert("color.tune.saturation".to_string(), "0.7".to_string());
-    cfg.insert("color.tune.head".to_string(), "2.0".to_string());
-    cfg.insert("color.tune.body".to_string(), "1.2".to_string());
-    cfg.insert("color.tune.tail".to_string(), "0.8".to_string());
+    cfg.insert("color.tune.brightness".to_string(), "1.5".into());
+    cfg.insert("color.tune.saturation".to_string(), "0.7".into());
+    cfg.insert("color.tune.head".to_string(), "2.0".into());
+    cfg.insert("color.tune.body".to_string(), "1.2".into());
+    cfg.insert("color.tune.tail".to_string(), "0.8".into());
     let new = rebuild_cloud_config(&base, &cfg);
     assert!((new.color_tune.brightness - 1.5).abs() < 1e-6);
     assert!((new.color_tune.saturation - 0.7).abs() < 1e-6);
@@ -500,7 +502,7 @@ fn rebuild_color_tune_live_reload_change() {
     base.color_tune.brightness = 0.0;
     base.cli_explicit.color_tune = false;
     let mut cfg = HashMap::new();
-    cfg.insert("color.tune.brightness".to_string(), "2.0".to_string());
+    cfg.insert("color.tune.brightness".to_string(), "2.0".into());
     let new = rebuild_cloud_config(&base, &cfg);
     assert_eq!(new.color_tune.brightness, 2.0);
 }
@@ -523,7 +525,7 @@ fn validate_and_send_pushes_oor_rejection_to_session_log() {
     let mut parsed = configfile::ParsedConfig::default();
     parsed
         .values
-        .insert("color.tune.tail".to_string(), "5.0".to_string());
+        .insert("color.tune.tail".to_string(), "5.0".into());
     let result = validate_and_send(&parsed, &tx);
     assert!(result.is_err(), "OOR color.tune.tail must be rejected");
 
@@ -606,7 +608,7 @@ fn validate_and_send_does_not_log_valid_config() {
     let mut parsed = configfile::ParsedConfig::default();
     parsed
         .values
-        .insert("color.tune.brightness".to_string(), "1.5".to_string());
+        .insert("color.tune.brightness".to_string(), "1.5".into());
     let result = validate_and_send(&parsed, &tx);
     assert!(result.is_ok(), "1.5 is in range [0.0, 3.0]");
 
@@ -624,7 +626,7 @@ fn live_reload_message_border_from_config() {
     // Config `message-border = "hello"` → new.message = "hello", border=true.
     let base = minimal_cloud_config();
     let mut cfg = HashMap::new();
-    cfg.insert("message-border".to_string(), "hello".to_string());
+    cfg.insert("message-border".to_string(), "hello".into());
     let new = rebuild_cloud_config(&base, &cfg);
     assert_eq!(new.message.as_deref(), Some("hello"));
     assert!(
@@ -638,7 +640,7 @@ fn live_reload_message_bare_from_config() {
     // Config `message = "hello"` (no border) → new.message = "hello", border=false.
     let base = minimal_cloud_config();
     let mut cfg = HashMap::new();
-    cfg.insert("message".to_string(), "hello".to_string());
+    cfg.insert("message".to_string(), "hello".into());
     let new = rebuild_cloud_config(&base, &cfg);
     assert_eq!(new.message.as_deref(), Some("hello"));
     assert!(!new.message_border, "message config must keep border=false");
@@ -649,8 +651,8 @@ fn live_reload_message_border_wins_over_message() {
     // Both keys present → message-border wins (border=true).
     let base = minimal_cloud_config();
     let mut cfg = HashMap::new();
-    cfg.insert("message".to_string(), "plain".to_string());
-    cfg.insert("message-border".to_string(), "boxed".to_string());
+    cfg.insert("message".to_string(), "plain".into());
+    cfg.insert("message-border".to_string(), "boxed".into());
     let new = rebuild_cloud_config(&base, &cfg);
     assert_eq!(new.message.as_deref(), Some("boxed"));
     assert!(new.message_border);
@@ -661,8 +663,8 @@ fn live_reload_msg_mode_false_suppresses_config_message() {
     // msg-mode=false + config message-border → message suppressed.
     let base = minimal_cloud_config();
     let mut cfg = HashMap::new();
-    cfg.insert("msg-mode".to_string(), "false".to_string());
-    cfg.insert("message-border".to_string(), "hello".to_string());
+    cfg.insert("msg-mode".to_string(), "false".into());
+    cfg.insert("message-border".to_string(), "hello".into());
     let new = rebuild_cloud_config(&base, &cfg);
     assert_eq!(
         new.message, None,
@@ -677,8 +679,8 @@ fn live_reload_msg_mode_true_keeps_config_message() {
     // msg-mode=true + config message → preserved.
     let base = minimal_cloud_config();
     let mut cfg = HashMap::new();
-    cfg.insert("msg-mode".to_string(), "true".to_string());
-    cfg.insert("message-border".to_string(), "hello".to_string());
+    cfg.insert("msg-mode".to_string(), "true".into());
+    cfg.insert("message-border".to_string(), "hello".into());
     let new = rebuild_cloud_config(&base, &cfg);
     assert_eq!(new.message.as_deref(), Some("hello"));
     assert!(new.message_border);
@@ -702,7 +704,7 @@ fn live_reload_cli_message_wins_over_config() {
     base.message_border = false;
     base.cli_explicit.message = true;
     let mut cfg = HashMap::new();
-    cfg.insert("message-border".to_string(), "from-config".to_string());
+    cfg.insert("message-border".to_string(), "from-config".into());
     let new = rebuild_cloud_config(&base, &cfg);
     assert_eq!(new.message.as_deref(), Some("from-cli"));
     assert!(!new.message_border, "CLI -m must keep border=false");
@@ -715,8 +717,8 @@ fn live_reload_cli_msg_mode_wins_over_config() {
     base.msg_mode = false;
     base.cli_explicit.msg_mode = true;
     let mut cfg = HashMap::new();
-    cfg.insert("msg-mode".to_string(), "true".to_string());
-    cfg.insert("message-border".to_string(), "hello".to_string());
+    cfg.insert("msg-mode".to_string(), "true".into());
+    cfg.insert("message-border".to_string(), "hello".into());
     let new = rebuild_cloud_config(&base, &cfg);
     // CLI msg-mode=false wins → message suppressed even though config has msg-mode=true + message.
     assert!(!new.msg_mode, "CLI msg-mode=false must win");
@@ -733,7 +735,7 @@ fn live_reload_power_dragon_respects_cli_explicit() {
     base.power_dragon = false;
     base.cli_explicit.power_dragon = true;
     let mut cfg = HashMap::new();
-    cfg.insert("power-dragon".to_string(), "true".to_string());
+    cfg.insert("power-dragon".to_string(), "true".into());
     let new = rebuild_cloud_config(&base, &cfg);
     assert!(
         !new.power_dragon,
@@ -741,6 +743,66 @@ fn live_reload_power_dragon_respects_cli_explicit() {
     );
 }
 
+#[test]
+fn live_reload_rebinds_keys_and_drops_removed_bindings() {
+    use crate::interactive::keymap::{keymap_from_config, KeyAction, Keymap};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    let f2 = KeyEvent::new(KeyCode::F(2), KeyModifiers::NONE);
+    let mut cfg = HashMap::new();
+    cfg.insert(
+        "keys.hud".to_string(),
+        crate::config_schema::value_from_text("keys.hud", "f2"),
+    );
+    let new = rebuild_cloud_config(&minimal_cloud_config(), &cfg);
+    assert_eq!(new.keymap.action_for(&f2), Some(KeyAction::Hud));
+
+    // Removing the section restores the built-in keys even when the base
+    // config still carries the old map.
+    let mut base = minimal_cloud_config();
+    base.keymap = keymap_from_config(&cfg).0;
+    let new = rebuild_cloud_config(&base, &HashMap::new());
+    assert_eq!(new.keymap, Keymap::default());
+
+    // A reload that binds one key twice keeps it on the earlier action.
+    cfg.insert(
+        "keys.quit".to_string(),
+        crate::config_schema::value_from_text("keys.quit", "f2"),
+    );
+    let new = rebuild_cloud_config(&base, &cfg);
+    assert_eq!(new.keymap.action_for(&f2), Some(KeyAction::Quit));
+}
+
+#[test]
+fn live_reload_switches_crystal_dragon_calc() {
+    use crate::crystal_dragon_engine::crystal_dragon_control::CrystalDragonCalcMethod;
+
+    let mut cfg = HashMap::new();
+    cfg.insert("crystal-dragon-calc".to_string(), "v2".into());
+    let new = rebuild_cloud_config(&minimal_cloud_config(), &cfg);
+    assert_eq!(new.crystal_dragon_calc, CrystalDragonCalcMethod::CalcV2);
+
+    // Removing the key falls back to calc-v1.
+    let new = rebuild_cloud_config(&new, &HashMap::new());
+    assert_eq!(new.crystal_dragon_calc, CrystalDragonCalcMethod::Calc);
+}
+
+#[test]
+fn live_reload_switches_crystal_dragon_sensors() {
+    use crate::crystal_dragon_engine::crystal_dragon_control::CrystalDragonSensors;
+
+    let mut cfg = HashMap::new();
+    cfg.insert(
+        "crystal-dragon-sensor".to_string(),
+        crate::config_schema::value_from_text("crystal-dragon-sensor", "[\"psi-memory\"]"),
+    );
+    let new = rebuild_cloud_config(&minimal_cloud_config(), &cfg);
+    assert_eq!(new.crystal_dragon_sensors.label(), "psi-memory");
+
+    let new = rebuild_cloud_config(&new, &HashMap::new());
+    assert_eq!(new.crystal_dragon_sensors, CrystalDragonSensors::default());
+}
+
 #[test]
 fn live_reload_async_mode_respects_cli_explicit() {
     // CLI --async-mode false explicit → config async-mode=true ignored.
@@ -748,7 +810,7 @@ fn live_reload_async_mode_respects_cli_explicit() {
     base.async_mode = false;
     base.cli_explicit.async_mode = true;
     let mut cfg = HashMap::new();
-    cfg.insert("async-mode".to_string(), "true".to_string());
+    cfg.insert("async-mode".to_string(), "true".into());
     let new = rebuild_cloud_config(&base, &cfg);
     assert!(
         !new.async_mode,
@@ -761,7 +823,7 @@ fn live_reload_intro_color_from_config() {
     // Config intro-color = "energy-zen" (valid) → new.intro_color set.
     let base = minimal_cloud_config();
     let mut cfg = HashMap::new();
-    cfg.insert("intro-color".to_string(), "energy-zen".to_string());
+    cfg.insert("intro-color".to_string(), "energy-zen".into());
     let new = rebuild_cloud_config(&base, &cfg);
     assert_eq!(new.intro_color.as_deref(), Some("energy-zen"));
 }
@@ -773,7 +835,7 @@ fn live_reload_intro_color_invalid_soft_fails() {
     // crashing a running session. User can fix config and save again.
     let base = minimal_cloud_config();
     let mut cfg = HashMap::new();
-    cfg.insert("intro-color".to_string(), "not-a-color".to_string());
+    cfg.insert("intro-color".to_string(), "not-a-color".into());
     let new = rebuild_cloud_config(&base, &cfg);
     assert_eq!(
         new.intro_color, None,
@@ -788,7 +850,7 @@ fn live_reload_intro_color_cli_explicit_wins() {
     base.intro_color = Some("green".to_string());
     base.cli_explicit.intro_color = true;
     let mut cfg = HashMap::new();
-    cfg.insert("intro-color".to_string(), "energy-zen".to_string());
+    cfg.insert("intro-color".to_string(), "energy-zen".into());
     let new = rebuild_cloud_config(&base, &cfg);
     assert_eq!(new.intro_color.as_deref(), Some("green"), "CLI must win");
 }
@@ -802,7 +864,7 @@ fn live_reload_monolith_size_respects_cli_explicit() {
     base.monolith_size = MonolithSize::Large;
     base.cli_explicit.monolith_size = true;
     let mut cfg = HashMap::new();
-    cfg.insert("monolith-size".to_string(), "small".to_string());
+    cfg.insert("monolith-size".to_string(), "small".into());
     let new = rebuild_cloud_config(&base, &cfg);
     assert_eq!(
         new.monolith_size,
@@ -817,7 +879,63 @@ fn live_reload_monolith_size_from_config_when_no_cli() {
     use crate::runtime::MonolithSize;
     let base = minimal_cloud_config();
     let mut cfg = HashMap::new();
-    cfg.insert("monolith-size".to_string(), "small".to_string());
+    cfg.insert("monolith-size".to_string(), "small".into());
     let new = rebuild_cloud_config(&base, &cfg);
     assert_eq!(new.monolith_size, MonolithSize::Small);
 }
+
+#[test]
+fn watch_set_tracks_every_layer_and_new_drop_ins() {
+    let main = PathBuf::from("/cfg/config.toml");
+    let layers = crate::config_layers::ConfigLayers {
+        files: vec![main.clone(), PathBuf::from("/shared/team.toml")],
+        conf_d_dirs: vec![PathBuf::from("/cfg/conf.d")],
+        ..Default::default()
+    };
+    let watch = WatchSet::new(&main, &layers);
+    let hit = |p: &str| watch.matching(&[PathBuf::from(p)]).is_some();
+    assert!(hit("/cfg/config.toml"));
+    assert!(hit("/shared/team.toml"));
+    assert!(
+        hit("/cfg/conf.d/99-new.toml"),
+        "drop-in created after startup"
+    );
+    assert!(!hit("/cfg/conf.d/.99-new.toml.swp"));
+    assert!(!hit("/cfg/other.toml"));
+    assert!(!hit("/shared/other.toml"));
+    // conf.d does not exist here, so only the layer parents are watched.
+    assert_eq!(
+        watch.dirs(),
+        vec![PathBuf::from("/cfg"), PathBuf::from("/shared")]
+    );
+}
+
+#[test]
+fn watch_set_polls_includes_and_the_drop_ins_on_disk() {
+    let root = std::env::temp_dir().join(format!("cx-poll-paths-{}", std::process::id()));
+    let conf_d = root.join("conf.d");
+    std::fs::create_dir_all(&conf_d).unwrap();
+    let main = root.join("config.toml");
+    let stale = conf_d.join("10-deleted.toml");
+    std::fs::write(conf_d.join("20-new.toml"), "speed = 5\n").unwrap();
+    std::fs::write(conf_d.join(".20-new.toml.swp"), "").unwrap();
+    let layers = crate::config_layers::ConfigLayers {
+        files: vec![
+            main.clone(),
+            PathBuf::from("/shared/team.toml"),
+            stale.clone(),
+        ],
+        conf_d_dirs: vec![conf_d.clone()],
+        ..Default::default()
+    };
+    let paths = WatchSet::new(&main, &layers).poll_paths();
+    let _ = std::fs::remove_dir_all(&root);
+    assert_eq!(
+        paths,
+        vec![
+            main,
+            PathBuf::from("/shared/team.toml"),
+            conf_d.join("20-new.toml"),
+        ]
+    );
+}
@@ -32,7 +32,8 @@
 //! at 750ms intervals. The previous FNV-1a 64-bit hash was replaced
 //! because owner required cryptographic strength for change detection.
 
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::mpsc::SyncSender;
 use std::sync::Arc;
@@ -91,8 +92,12 @@ pub(crate) fn env_poll_interval_ms() -> u64 {
         .unwrap_or(DEFAULT_POLL_INTERVAL_MS)
 }
 
-/// Polling heartbeat: checks file mtime/size/content every `base_interval_ms`
-/// and feeds synthetic notify events into `tx` when ANY of them changes.
+/// Polling heartbeat: checks mtime/size/content of every file `targets`
+/// returns every `base_interval_ms` and feeds a synthetic notify event
+/// into `tx` when ANY of them changes. `targets` is called each cycle so
+/// the set follows the config layers (includes, `conf.d/` drop-ins) as
+/// reloads add or remove them; see [`poll_targets`] for what counts as a
+/// change.
 /// This runs on a background thread alongside the native watcher,
 /// guaranteeing live reload works even when the native backend is silent
 /// (e.g., FreeBSD kqueue feature not active, Android Termux inotify
@@ -107,15 +112,16 @@ pub(crate) fn env_poll_interval_ms() -> u64 {
 /// window, the interval returns to `base_interval_ms`.
 ///
 /// The synthetic event uses `EventKind::Modify(ModifyKind::Any)` with
-/// the target file as the path, so the unified event loop in
+/// the changed files as its paths, so the unified event loop in
 /// `watcher_loop` (in `live_config.rs`) treats it identically to a
 /// native modify event.
 ///
 /// **Startup reload prevention**: all three signals are snapshotted at
-/// heartbeat start. The first poll (`base_interval_ms` later) compares
-/// against these initial values — if nothing changed, no event is sent.
+/// heartbeat start for every target. The first poll (`base_interval_ms`
+/// later) compares against these initial values — if nothing changed, no
+/// event is sent.
 pub(crate) fn polling_heartbeat(
-    path: std::path::PathBuf,
+    targets: impl Fn() -> Vec<PathBuf>,
     tx: SyncSender<notify::Result<notify::Event>>,
     base_interval_ms: u64,
     change_counter: Arc<AtomicU64>,
@@ -126,7 +132,13 @@ pub(crate) fn polling_heartbeat(
     // — `Some(t) != None`, so a transition from "mtime available" to
     // "mtime unavailable" registers as a change (the file may have been
     // replaced by an atomic save).
-    let mut last_state = snapshot_file_state(&path);
+    let mut last_state: HashMap<PathBuf, FileStateSnapshot> = targets()
+        .into_iter()
+        .map(|p| {
+            let snap = snapshot_file_state(&p);
+            (p, snap)
+        })
+        .collect();
 
     // Track the last-seen change_counter value to detect when the native
     // watcher has accepted an event (which increments the counter). On
@@ -195,40 +207,28 @@ pub(crate) fn polling_heartbeat(
             }
         }
 
-        let current_state = snapshot_file_state_cached(&path, Some(&last_state));
+        let (current_state, changed) = poll_targets(&targets(), &last_state);
 
         // periodic liveness trace every 5 cycles. This is the
         // KEY diagnostic for Termux — if the user sees these lines,
-        // the polling thread is alive and reading the file. If they
+        // the polling thread is alive and reading the files. If they
         // DON'T see them, the polling thread is dead/panicked.
         if cycle % 5 == 1 {
             lr_trace!(
-                "poll cycle #{} alive (interval={}ms) — current_state={:?}",
+                "poll cycle #{} alive (interval={}ms) — {} file(s)",
                 cycle,
                 interval_ms,
-                current_state
+                current_state.len()
             );
         }
+        last_state = current_state;
 
-        // If we can't even read the file's metadata, the file may have
-        // been deleted (atomic save in progress, editor temp-file swap).
-        // Skip this cycle — the next poll will catch the new file.
-        if current_state.size.is_none() {
-            lr_trace!("poll: metadata read failed — skipping cycle");
-            continue;
-        }
-
-        if current_state == last_state {
+        if changed.is_empty() {
             // No change detected by any signal. This is the common case.
             continue;
         }
 
-        lr_trace!(
-            "poll: change detected — old={:?} new={:?}",
-            last_state,
-            current_state
-        );
-        last_state = current_state;
+        lr_trace!("poll: change detected in {:?}", changed);
 
         // Enter/extend burst mode after detecting a change ourselves,
         // so we catch rapid follow-up edits (e.g., formatter re-save).
@@ -241,12 +241,12 @@ pub(crate) fn polling_heartbeat(
         };
 
         // Synthesize a notify::Event so the unified event loop handles
-        // it identically to a native event. The path must match what
-        // handle_notify_event's `touches_target` check expects (the
-        // target file's absolute path).
+        // it identically to a native event. The paths are layer files as
+        // the watch set lists them, so handle_notify_event matches them.
+        // One event is enough: it reparses the whole stack.
         let event = notify::Event {
             kind: EventKind::Modify(notify::event::ModifyKind::Any),
-            paths: vec![path.clone()],
+            paths: changed,
             attrs: Default::default(),
         };
         if tx.try_send(Ok(event)).is_err() {
@@ -263,6 +263,34 @@ pub(crate) fn polling_heartbeat(
     }
 }
 
+/// Snapshot every path in `targets` (reusing `last` as the hash cache)
+/// and list the ones that changed since `last`: a path that is new, or
+/// whose signals differ, or that dropped out of `targets` (a deleted
+/// drop-in). A target whose metadata cannot be read keeps its previous
+/// snapshot — an atomic save is in progress and the next poll catches
+/// the new file.
+pub(crate) fn poll_targets(
+    targets: &[PathBuf],
+    last: &HashMap<PathBuf, FileStateSnapshot>,
+) -> (HashMap<PathBuf, FileStateSnapshot>, Vec<PathBuf>) {
+    let mut current = HashMap::with_capacity(targets.len());
+    let mut changed = Vec::new();
+    for path in targets {
+        let prev = last.get(path);
+        let snap = snapshot_file_state_cached(path, prev);
+        if snap.size.is_none() {
+            current.insert(path.clone(), prev.copied().unwrap_or(snap));
+            continue;
+        }
+        if prev != Some(&snap) {
+            changed.push(path.clone());
+        }
+        current.insert(path.clone(), snap);
+    }
+    changed.extend(last.keys().filter(|p| !current.contains_key(*p)).cloned());
+    (current, changed)
+}
+
 /// Snapshot of a file's state for change detection. Three signals:
 /// mtime, size, content hash of the first 8KB. Equality of this struct
 /// means "no change detected by any signal" — the file is presumed
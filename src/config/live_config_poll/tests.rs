@@ -343,6 +343,40 @@ fn burst_cycle_constants_are_sane() {
     );
 }
 
+#[test]
+fn poll_targets_reports_new_changed_and_dropped_files() {
+    let dir = std::env::temp_dir().join(format!("cx-poll-targets-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let main = dir.join("config.toml");
+    let drop_in = dir.join("10-extra.toml");
+    std::fs::write(&main, "speed = 5\n").unwrap();
+    std::fs::write(&drop_in, "density = 1\n").unwrap();
+
+    let (base, changed) = poll_targets(std::slice::from_ref(&main), &HashMap::new());
+    assert_eq!(changed, vec![main.clone()], "first sighting is new");
+    let (_, changed) = poll_targets(std::slice::from_ref(&main), &base);
+    assert!(changed.is_empty());
+
+    // A drop-in appears, then the main file changes size.
+    let both = [main.clone(), drop_in.clone()];
+    let (state, changed) = poll_targets(&both, &base);
+    assert_eq!(changed, vec![drop_in.clone()]);
+    std::fs::write(&main, "speed = 50\n").unwrap();
+    let (state, changed) = poll_targets(&both, &state);
+    assert_eq!(changed, vec![main.clone()]);
+
+    // Unreadable main (atomic save mid-rename): keep the old snapshot.
+    std::fs::remove_file(&main).unwrap();
+    let (kept, changed) = poll_targets(&both, &state);
+    assert!(changed.is_empty());
+    assert_eq!(kept[&main], state[&main]);
+
+    // The drop-in is gone from the listing.
+    let (_, changed) = poll_targets(std::slice::from_ref(&main), &kept);
+    assert_eq!(changed, vec![drop_in]);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
 // ── (bug #18): polling-heartbeat end-to-end test ──────────────
 //
 // The polling heartbeat is the fallback path for live config reload on
@@ -428,7 +462,8 @@ fn polling_heartbeat_end_to_end() {
     let handle = std::thread::Builder::new()
         .name("test-polling-heartbeat".to_string())
         .spawn(move || {
-            polling_heartbeat(path_inner, tx, HEARTBEAT_E2E_POLL_MS, counter);
+            let targets = move || vec![path_inner.clone()];
+            polling_heartbeat(targets, tx, HEARTBEAT_E2E_POLL_MS, counter);
         })
         .expect("failed to spawn polling heartbeat thread");
 
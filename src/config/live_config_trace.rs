@@ -130,8 +130,8 @@ pub(crate) fn trace_rebuild_applied(
 /// Extracted from `event_loop.rs` to keep the file under the 1500-LOC cap.
 /// No-op when `COSMOSTRIX_LIVE_RELOAD_DEBUG` is unset.
 pub(crate) fn trace_config_diff(
-    old: Option<&std::collections::HashMap<String, String>>,
-    new: &std::collections::HashMap<String, String>,
+    old: Option<&crate::config_schema::ConfigMap>,
+    new: &crate::config_schema::ConfigMap,
 ) {
     if !live_reload_debug_enabled() {
         return;
@@ -24,6 +24,8 @@ pub mod config_apply;
 pub mod config_apply_tests;
 pub mod config_hints;
 pub mod config_io;
+pub mod config_layers;
+pub mod config_schema;
 pub mod configfile;
 #[cfg(test)]
 pub mod configfile_tests;
@@ -156,6 +158,33 @@ pub enum ColorBg {
     DefaultBackground,
 }
 
+/// Dithering between neighbouring palette entries when a 256- or 16-color
+/// terminal forces quantization (see `chroma_dragon_engine::quantize`).
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorDither {
+    /// Nearest palette entry only.
+    #[default]
+    #[value(name = "off")]
+    Off,
+    /// 4×4 Bayer pattern, fixed per cell.
+    #[value(name = "ordered")]
+    Ordered,
+    /// Bayer pattern rotated every frame, so each cell averages out over time.
+    #[value(name = "temporal")]
+    Temporal,
+}
+
+impl ColorDither {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Ordered => "ordered",
+            Self::Temporal => "temporal",
+        }
+    }
+}
+
 /// Glitch intensity presets. Provides a grouped interface over individual
 /// glitch tuning parameters (glitchpct, glitchms, shortpct, rippct).
 #[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
@@ -187,6 +216,25 @@ pub enum IntroType {
     None,
 }
 
+/// File format for `--render-frames` output (see `crate::headless`).
+///
+/// * `Ppm`  — binary P6 image rendered with the built-in 5×7 font.
+/// * `Ansi` — full-frame ANSI text (`cat` it to replay the frame).
+/// * `Text` — glyphs only, one line per row.
+/// * `Stream` — no files: every frame through the real renderer into the
+///   one `--out` target (file, FIFO, Unix socket, `-` for stdout).
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderFormat {
+    #[value(name = "ppm")]
+    Ppm,
+    #[value(name = "ansi")]
+    Ansi,
+    #[value(name = "text")]
+    Text,
+    #[value(name = "stream")]
+    Stream,
+}
+
 // U16Range
 
 #[derive(Clone, Copy, Debug)]
@@ -342,6 +390,15 @@ pub struct Args {
     )]
     pub async_mode: Option<bool>,
 
+    #[arg(
+        long = "control-socket",
+        value_name = "PATH",
+        help_heading = "ADVANCED",
+        display_order = 58,
+        help = "Listen on a Unix socket for runtime commands (scene, color, speed, message, state...)"
+    )]
+    pub control_socket: Option<PathBuf>,
+
     #[arg(
         short = 's',
         long = "screensaver",
@@ -568,6 +625,58 @@ pub struct Args {
     )]
     pub bench_scene: Option<String>,
 
+    #[arg(
+        long = "record",
+        value_name = "FILE",
+        help_heading = "DIAGNOSTICS",
+        display_order = 119,
+        help = "Record the interactive session to an asciicast v2 file \
+                (play with `asciinema play FILE`); must be under \
+                ~/.config/cosmostrix/ or /etc/cosmostrix/"
+    )]
+    pub record: Option<PathBuf>,
+
+    #[arg(
+        long = "seed",
+        value_name = "U64",
+        help_heading = "DIAGNOSTICS",
+        display_order = 120,
+        help = "Seed every RNG for a reproducible run (same seed + size + settings = same frames)"
+    )]
+    pub seed: Option<u64>,
+
+    #[arg(
+        long = "render-frames",
+        value_name = "N",
+        help_heading = "DIAGNOSTICS",
+        display_order = 121,
+        requires = "out",
+        value_parser = clap::value_parser!(u64).range(1..),
+        help = "Render N frames headless (no TTY) at --fps into --out DIR, then exit"
+    )]
+    pub render_frames: Option<u64>,
+
+    #[arg(
+        long = "out",
+        value_name = "DIR",
+        help_heading = "DIAGNOSTICS",
+        display_order = 122,
+        requires = "render_frames",
+        help = "Output directory for --render-frames (created if missing); for --render-format stream, a file, FIFO, Unix socket or - (stdout). Directories and files must be under ~/.config/cosmostrix/ or /etc/cosmostrix/"
+    )]
+    pub out: Option<PathBuf>,
+
+    #[arg(
+        long = "render-format",
+        value_name = "FORMAT",
+        value_enum,
+        default_value_t = RenderFormat::Ppm,
+        help_heading = "DIAGNOSTICS",
+        display_order = 123,
+        help = "Frame file format for --render-frames: ppm (image), ansi, text, or stream (one terminal byte stream)"
+    )]
+    pub render_format: RenderFormat,
+
     // v30 simplify: --info skip field REMOVED. Was a v17 ghost (CLI flag
     // deleted in v17, merged into --doctor). No consumer ever read this.
     #[arg(
@@ -678,6 +787,15 @@ pub struct Args {
     )]
     pub color_bg: ColorBg,
 
+    #[arg(
+        long = "color-dither",
+        default_value_t = ColorDither::Off,
+        value_enum,
+        hide = true,
+        help = "Dither 256/16-color output (off, ordered, temporal)"
+    )]
+    pub color_dither: ColorDither,
+
     #[arg(
         long = "duration",
         hide = true,
@@ -720,6 +838,18 @@ pub struct Args {
     )]
     pub crystal_dragon: Option<bool>,
 
+    /// Query the terminal at startup (DA1/DA2, XTVERSION, DECRQM 2026,
+    /// kitty keyboard, DECRQSS, OSC 11/4) and let the replies override the
+    /// `TERM`-based detection. Also configurable via `probe-terminal = true`.
+    #[arg(
+        long = "probe-terminal",
+        value_name = "BOOL",
+        num_args = 1,
+        value_parser = parse_true_false,
+        help = "Query the terminal for its capabilities at startup (true|false, default: false)"
+    )]
+    pub probe_terminal: Option<bool>,
+
     /// v50: Power Dragon toggle. CLI flag `--power-dragon <true|false>`.
     /// When false: disables aggressive_throttle + idle FPS reduction.
     /// Default: true (protection enabled). Also configurable via
@@ -801,6 +931,44 @@ pub struct Args {
     #[arg(skip = 3u8)]
     pub max_droplets_per_column: u8,
 
+    // Config-only `[rain-tuning]` section (no CLI flags). Filled by
+    // config_apply; defaults to the compiled-in calibration.
+    #[arg(skip)]
+    pub(crate) rain_tuning: crate::constants::RainTuning,
+
+    // Config-only `[events]` section (no CLI flags). Filled by
+    // config_apply; defaults to the compiled-in event knobs.
+    #[arg(skip)]
+    pub(crate) event_tuning: crate::constants::EventTuning,
+
+    // Config-only `[power-dragon]` section (no CLI flags). Filled by
+    // config_apply; defaults to the compiled-in thresholds.
+    #[arg(skip)]
+    pub(crate) power_thresholds: crate::constants::PowerThresholds,
+
+    // Config-only `[keys]` section (no CLI flags). Filled by config_apply;
+    // defaults to the built-in bindings.
+    #[arg(skip)]
+    pub(crate) keymap: crate::interactive::keymap::Keymap,
+
+    // Config-only `crystal-dragon-calc = "v1"|"v2"` (no CLI flag). Filled
+    // by config_apply; defaults to calc-v1.
+    #[arg(skip)]
+    pub(crate) crystal_dragon_calc:
+        crate::crystal_dragon_engine::crystal_dragon_control::CrystalDragonCalcMethod,
+
+    // Config-only `crystal-dragon-sensor` / `crystal-dragon-sensor-mix`
+    // (no CLI flags). Filled by config_apply; defaults to process CPU.
+    #[arg(skip)]
+    pub(crate) crystal_dragon_sensors:
+        crate::crystal_dragon_engine::crystal_dragon_control::CrystalDragonSensors,
+
+    // Config-only `crystal-dragon-external` (no CLI flag): file, FIFO or
+    // command feeding the `external` sensor. Filled by config_apply.
+    #[arg(skip)]
+    pub(crate) crystal_dragon_external:
+        Option<crate::crystal_dragon_engine::external::ExternalSource>,
+
     // v30 simplify: --noglitch CLI flag REMOVED. Was a strict duplicate of
     // `--glitch-level none` (the only behavior `--noglitch` had was to flip
     // `cloud.glitchy` to false, which is exactly what `--glitch-level none`
@@ -967,7 +1135,7 @@ pub(crate) fn print_list_scenes() {
 /// message suitable for `ux::die_config`.
 pub(crate) fn print_show_scene(
     name: &str,
-    cfg: &std::collections::HashMap<String, String>,
+    cfg: &crate::config_schema::ConfigMap,
 ) -> Result<(), String> {
     // 1. Built-in scene lookup.
     if let Some(info) = scene::get_scene(name) {
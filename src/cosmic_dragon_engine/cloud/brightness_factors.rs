@@ -7,9 +7,14 @@
 //! Each returns a multiplier in [0.0, 1.0] applied per-cell in droplet.rs::draw().
 
 use crate::constants::{
-    CRT_VIGNETTE_EDGE_FACTOR, CRT_VIGNETTE_HEIGHT, EDGE_FADE_BOTTOM_LIP, EDGE_FADE_BOTTOM_MIN,
-    EDGE_FADE_BOTTOM_ROWS, EDGE_FADE_ROWS, EDGE_FADE_TOP_MIN, RAIN_SHADOW_FLOOR, RAIN_SHADOW_PCT,
-    VIGNETTE_INNER_RADIUS, VIGNETTE_INTENSITY,
+    EDGE_FADE_BOTTOM_LIP, EDGE_FADE_BOTTOM_MIN, EDGE_FADE_BOTTOM_ROWS, EDGE_FADE_ROWS,
+    EDGE_FADE_TOP_MIN, RAIN_SHADOW_FLOOR, RAIN_SHADOW_PCT,
+};
+// Constant-default wrappers are test/audit-only; the render path feeds
+// `[rain-tuning]` values into the `_with` variants.
+#[cfg(test)]
+use crate::constants::{
+    CRT_VIGNETTE_EDGE_FACTOR, CRT_VIGNETTE_HEIGHT, VIGNETTE_INNER_RADIUS, VIGNETTE_INTENSITY,
 };
 
 /// to the viewport edges. Interior rows return 1.0 (no dimming).
@@ -85,9 +90,32 @@ pub(crate) fn viewport_edge_fade(line: u16, lines: u16) -> f32 {
 ///
 /// O(1) per cell: 2 subtractions, 2 multiplications, 1 sqrt, 1
 /// smoothstep, 1 multiply. Called once per cell in the draw loop.
+#[cfg(test)]
 #[inline]
 pub(crate) fn vignette_factor(col: u16, line: u16, cols: u16, lines: u16) -> f32 {
-    if cols == 0 || lines == 0 || VIGNETTE_INTENSITY <= 0.0 {
+    vignette_factor_with(
+        col,
+        line,
+        cols,
+        lines,
+        VIGNETTE_INTENSITY,
+        VIGNETTE_INNER_RADIUS,
+    )
+}
+
+/// [`vignette_factor`] with explicit `intensity` / `inner_radius`
+/// (`[rain-tuning]` vignette-intensity / vignette-inner-radius). Used to
+/// bake `Cloud::vignette_lut`.
+#[inline]
+pub(crate) fn vignette_factor_with(
+    col: u16,
+    line: u16,
+    cols: u16,
+    lines: u16,
+    intensity: f32,
+    inner_radius: f32,
+) -> f32 {
+    if cols == 0 || lines == 0 || intensity <= 0.0 {
         return 1.0;
     }
     // Normalize to [-1, 1] centered on screen midpoint.
@@ -108,13 +136,13 @@ pub(crate) fn vignette_factor(col: u16, line: u16, cols: u16, lines: u16) -> f32
     // normalize so the `corner of a square` maps to 1.0, which keeps the
     // inner-radius cutoff intuitive on standard terminals.
     let normalized = dist * std::f32::consts::FRAC_1_SQRT_2;
-    if normalized <= VIGNETTE_INNER_RADIUS {
+    if normalized <= inner_radius {
         return 1.0;
     }
-    // Smoothstep from VIGNETTE_INNER_RADIUS (factor=1.0) to 1.0 (factor=1-VIGNETTE_INTENSITY).
-    let t = ((normalized - VIGNETTE_INNER_RADIUS) / (1.0 - VIGNETTE_INNER_RADIUS)).clamp(0.0, 1.0);
+    // Smoothstep from inner_radius (factor=1.0) to 1.0 (factor=1-intensity).
+    let t = ((normalized - inner_radius) / (1.0 - inner_radius)).clamp(0.0, 1.0);
     let smooth = t * t * (3.0 - 2.0 * t);
-    1.0 - VIGNETTE_INTENSITY * smooth
+    1.0 - intensity * smooth
 }
 
 /// Rain shadow: quadratic fade-out across the bottom RAIN_SHADOW_PCT of
@@ -196,13 +224,21 @@ pub(crate) fn rain_shadow_factor(line: u16, lines: u16) -> f32 {
 /// smoothstep, 1 multiply. Used by `compounded_brightness` (audit/test
 /// path) and by `apply_crt_vignette` (per-row precompute, 2*H calls
 /// per frame — negligible).
+#[cfg(test)]
 #[inline]
 pub(crate) fn crt_vignette_factor(line: u16, lines: u16) -> f32 {
-    if CRT_VIGNETTE_HEIGHT == 0 || lines < 2 * CRT_VIGNETTE_HEIGHT {
+    crt_vignette_factor_with(line, lines, CRT_VIGNETTE_HEIGHT, CRT_VIGNETTE_EDGE_FACTOR)
+}
+
+/// [`crt_vignette_factor`] with an explicit band `height` and edge-row
+/// factor (`[rain-tuning]` crt-vignette-height / crt-vignette-edge).
+#[inline]
+pub(crate) fn crt_vignette_factor_with(line: u16, lines: u16, height: u16, edge: f32) -> f32 {
+    if height == 0 || lines < 2 * height {
         return 1.0;
     }
-    let top_end = CRT_VIGNETTE_HEIGHT;
-    let bottom_start = lines.saturating_sub(CRT_VIGNETTE_HEIGHT);
+    let top_end = height;
+    let bottom_start = lines.saturating_sub(height);
 
     // Distance from the nearest edge: 0 at the extreme edge row,
     // CRT_VIGNETTE_HEIGHT-1 at the interior edge of the band.
@@ -219,7 +255,7 @@ pub(crate) fn crt_vignette_factor(line: u16, lines: u16) -> f32 {
     // Smoothstep from 1.0 (at v=H-1, interior edge) down to
     // CRT_VIGNETTE_EDGE_FACTOR (at v=0, extreme edge). Same curve as
     // the inline precomputation in apply_crt_vignette.
-    let t = v as f32 / CRT_VIGNETTE_HEIGHT as f32;
+    let t = v as f32 / height as f32;
     let smooth = t * t * (3.0 - 2.0 * t);
-    CRT_VIGNETTE_EDGE_FACTOR + (1.0 - CRT_VIGNETTE_EDGE_FACTOR) * smooth
+    edge + (1.0 - edge) * smooth
 }
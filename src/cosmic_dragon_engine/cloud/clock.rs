@@ -0,0 +1,147 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Simulation clock for [`Cloud`](super::Cloud).
+//!
+//! Every simulation timestamp the cloud takes for itself (reset, spawn
+//! bookkeeping, ghost events, transitions, pause, ripples, the message
+//! reveal) comes from `Cloud::clock` instead of `Instant::now()`. The
+//! interactive loop keeps the default [`RealClock`]; offline renderers,
+//! golden-frame tests and frame-accurate exports install a
+//! [`ManualClock`] and step it by an exact timestep per frame, so the
+//! simulation runs faster or slower than wall time and two runs with the
+//! same `--seed` see the same timestamps.
+//!
+//! `rain_at(frame, now)` still accepts an explicit `now`; callers driving
+//! a manual clock pass `clock.now()` (or just call `rain()`, which reads
+//! the cloud's clock).
+//!
+//! Perf instrumentation (`enable_component_timing`, sim/render ms) stays
+//! on the wall clock — it measures the host, not the simulation.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Source of "now" for the cloud simulation.
+pub(crate) trait Clock: Send {
+    fn now(&self) -> Instant;
+}
+
+/// Wall clock (`Instant::now()`). The default for every cloud.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct RealClock;
+
+impl Clock for RealClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Manually stepped clock: `origin + offset`, where the offset only moves
+/// when [`advance`](Self::advance) / [`set_elapsed`](Self::set_elapsed)
+/// is called.
+///
+/// Clones share the same offset, so a renderer keeps one handle to step
+/// time and hands the other to [`Cloud::with_clock`](super::Cloud::with_clock).
+/// `Instant` has no public constructor, so the origin is a real instant
+/// captured once; two clocks built with [`starting_at`](Self::starting_at)
+/// on the same origin produce identical timestamps.
+#[derive(Clone, Debug)]
+pub(crate) struct ManualClock {
+    origin: Instant,
+    offset_ns: Arc<AtomicU64>,
+}
+
+impl ManualClock {
+    /// Clock frozen at the current wall instant.
+    #[must_use]
+    pub(crate) fn new() -> Self {
+        Self::starting_at(Instant::now())
+    }
+
+    /// Clock frozen at `origin`.
+    #[must_use]
+    pub(crate) fn starting_at(origin: Instant) -> Self {
+        Self {
+            origin,
+            offset_ns: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Step the clock forward by `dt`. Headless rendering sets absolute
+    /// times (`set_elapsed`) instead, so only tests step.
+    #[cfg(test)]
+    pub(crate) fn advance(&self, dt: Duration) {
+        let dt_ns = u64::try_from(dt.as_nanos()).unwrap_or(u64::MAX);
+        let _ = self
+            .offset_ns
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |ns| {
+                Some(ns.saturating_add(dt_ns))
+            });
+    }
+
+    /// Jump to exactly `elapsed` after the origin (may move backwards;
+    /// the cloud treats a backwards step like any other `saturating_*`
+    /// timestamp and degrades to zero-length intervals).
+    pub(crate) fn set_elapsed(&self, elapsed: Duration) {
+        let ns = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.offset_ns.store(ns, Ordering::Relaxed);
+    }
+
+    /// Time since the origin.
+    #[must_use]
+    pub(crate) fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.offset_ns.load(Ordering::Relaxed))
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        self.origin + self.elapsed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manual_clock_only_moves_when_stepped() {
+        let clock = ManualClock::new();
+        let t0 = clock.now();
+        std::thread::sleep(Duration::from_millis(2));
+        assert_eq!(clock.now(), t0);
+        clock.advance(Duration::from_millis(16));
+        assert_eq!(clock.now(), t0 + Duration::from_millis(16));
+    }
+
+    #[test]
+    fn manual_clock_clones_share_offset() {
+        let a = ManualClock::new();
+        let b = a.clone();
+        a.advance(Duration::from_secs(3));
+        assert_eq!(b.elapsed(), Duration::from_secs(3));
+        b.set_elapsed(Duration::from_millis(5));
+        assert_eq!(a.now(), b.now());
+        assert_eq!(a.elapsed(), Duration::from_millis(5));
+    }
+
+    #[test]
+    fn clocks_on_same_origin_agree() {
+        let origin = Instant::now();
+        let a = ManualClock::starting_at(origin);
+        let b = ManualClock::starting_at(origin);
+        a.advance(Duration::from_millis(33));
+        b.advance(Duration::from_millis(33));
+        assert_eq!(a.now(), b.now());
+    }
+}
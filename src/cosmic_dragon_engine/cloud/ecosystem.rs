@@ -62,20 +62,82 @@ pub(crate) struct ProfileParams {
     pub linger_mult: f32,
 }
 
+/// `a` → `b` at `t` — the one blend both parameter sets use.
+#[inline]
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
 #[inline]
 pub(crate) fn lerp_profile_params(a: ProfileParams, b: ProfileParams, t: f32) -> ProfileParams {
     ProfileParams {
-        speed_mult: a.speed_mult + (b.speed_mult - a.speed_mult) * t,
-        density_mult: a.density_mult + (b.density_mult - a.density_mult) * t,
-        turbulence_mult: a.turbulence_mult + (b.turbulence_mult - a.turbulence_mult) * t,
-        phosphor_decay_mult: a.phosphor_decay_mult
-            + (b.phosphor_decay_mult - a.phosphor_decay_mult) * t,
-        anomaly_freq_mult: a.anomaly_freq_mult + (b.anomaly_freq_mult - a.anomaly_freq_mult) * t,
-        luminance_offset: a.luminance_offset + (b.luminance_offset - a.luminance_offset) * t,
-        persistence_boost: a.persistence_boost + (b.persistence_boost - a.persistence_boost) * t,
-        entropy_rate: a.entropy_rate + (b.entropy_rate - a.entropy_rate) * t,
-        short_pct: a.short_pct + (b.short_pct - a.short_pct) * t,
-        linger_mult: a.linger_mult + (b.linger_mult - a.linger_mult) * t,
+        speed_mult: lerp(a.speed_mult, b.speed_mult, t),
+        density_mult: lerp(a.density_mult, b.density_mult, t),
+        turbulence_mult: lerp(a.turbulence_mult, b.turbulence_mult, t),
+        phosphor_decay_mult: lerp(a.phosphor_decay_mult, b.phosphor_decay_mult, t),
+        anomaly_freq_mult: lerp(a.anomaly_freq_mult, b.anomaly_freq_mult, t),
+        luminance_offset: lerp(a.luminance_offset, b.luminance_offset, t),
+        persistence_boost: lerp(a.persistence_boost, b.persistence_boost, t),
+        entropy_rate: lerp(a.entropy_rate, b.entropy_rate, t),
+        short_pct: lerp(a.short_pct, b.short_pct, t),
+        linger_mult: lerp(a.linger_mult, b.linger_mult, t),
+    }
+}
+
+/// Scene-managed rain parameters that an ambient crossfade blends from
+/// the old scene to the new one (see `Cloud::crossfade_ambient_entry`).
+/// Glitch timing is carried as `f32` so it lerps like the rest. The
+/// palette is not in here: it is never interpolated as a whole, it wipes
+/// down the screen row by row on the color transition wave.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct SceneBlendParams {
+    pub chars_per_sec: f32,
+    pub droplet_density: f32,
+    pub glitch_pct: f32,
+    pub glitch_low_ms: f32,
+    pub glitch_high_ms: f32,
+    pub short_pct: f32,
+    pub die_early_pct: f32,
+}
+
+#[inline]
+pub(crate) fn lerp_scene_blend_params(
+    a: SceneBlendParams,
+    b: SceneBlendParams,
+    t: f32,
+) -> SceneBlendParams {
+    SceneBlendParams {
+        chars_per_sec: lerp(a.chars_per_sec, b.chars_per_sec, t),
+        droplet_density: lerp(a.droplet_density, b.droplet_density, t),
+        glitch_pct: lerp(a.glitch_pct, b.glitch_pct, t),
+        glitch_low_ms: lerp(a.glitch_low_ms, b.glitch_low_ms, t),
+        glitch_high_ms: lerp(a.glitch_high_ms, b.glitch_high_ms, t),
+        short_pct: lerp(a.short_pct, b.short_pct, t),
+        die_early_pct: lerp(a.die_early_pct, b.die_early_pct, t),
+    }
+}
+
+/// An in-flight ambient crossfade: `from` → `to` over `duration`, eased
+/// with the same smoothstep as the profile interpolation.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct AmbientCrossfade {
+    pub from: SceneBlendParams,
+    pub to: SceneBlendParams,
+    /// Glitch state of the new scene, applied when the fade completes
+    /// (glitch stays on during the fade so its rate can ramp).
+    pub to_glitchy: bool,
+    /// Glitch rate the glitch map was last filled at.
+    pub map_glitch_pct: f32,
+    pub start: Instant,
+    pub duration: Duration,
+}
+
+impl AmbientCrossfade {
+    /// Eased progress in `0.0..=1.0` at `now`.
+    pub(crate) fn progress(&self, now: Instant) -> f32 {
+        let elapsed = now.saturating_duration_since(self.start).as_secs_f32();
+        let t = (elapsed / self.duration.as_secs_f32().max(f32::EPSILON)).min(1.0);
+        t * t * (3.0 - 2.0 * t)
     }
 }
 
@@ -0,0 +1,121 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Cascade — a burst of bright glyphs pouring down one to three adjacent
+//! columns much faster than the rain, with a fading trail.
+
+use std::time::{Duration, Instant};
+
+use crossterm::style::Color;
+use rand::Rng;
+
+use crate::cell::Cell;
+use crate::constants::EventKind;
+use crate::frame::Frame;
+
+use super::super::ghost_events::{CinematicEvent, EventCtx};
+use super::{scaled_rgb, scramble_glyph};
+
+/// Widest burst, in columns.
+const CASCADE_MAX_WIDTH: u16 = 3;
+/// Head glyph changes per second.
+const CASCADE_HEAD_FLICKER_HZ: f32 = 20.0;
+
+pub(crate) struct CascadeEvent {
+    col: u16,
+    width: u16,
+    trail: u16,
+    seed: u64,
+    spawn_time: Instant,
+    duration: Duration,
+}
+
+impl CascadeEvent {
+    /// `None` when the screen has no room for a burst.
+    pub(crate) fn new<R: Rng>(cols: u16, lines: u16, now: Instant, rng: &mut R) -> Option<Self> {
+        if cols == 0 || lines < 2 {
+            return None;
+        }
+        let width = rng.random_range(1..=CASCADE_MAX_WIDTH.min(cols));
+        Some(Self {
+            col: rng.random_range(0..=cols - width),
+            width,
+            trail: (lines / 3).max(3),
+            seed: rng.random(),
+            spawn_time: now,
+            duration: Duration::from_millis(600 + rng.random_range(0..500)),
+        })
+    }
+}
+
+impl CinematicEvent for CascadeEvent {
+    fn kind(&self) -> EventKind {
+        EventKind::Cascade
+    }
+
+    fn is_finished(&self, ctx: &EventCtx) -> bool {
+        ctx.now.saturating_duration_since(self.spawn_time) >= self.duration
+    }
+
+    fn render(&mut self, ctx: &EventCtx, frame: &mut Frame) {
+        let elapsed = ctx
+            .now
+            .saturating_duration_since(self.spawn_time)
+            .as_secs_f32();
+        let progress = (elapsed / self.duration.as_secs_f32()).clamp(0.0, 1.0);
+        let flicker = (elapsed * CASCADE_HEAD_FLICKER_HZ) as u32;
+        let span = f32::from(ctx.lines.saturating_add(self.trail));
+
+        for col in self.col..(self.col + self.width).min(ctx.cols) {
+            // Neighbouring columns start a row or two apart so the burst
+            // does not read as one solid bar.
+            let lag = u32::from(col - self.col) * 2 % 3;
+            let head = (progress * span) as i32 - lag as i32;
+            for k in 0..self.trail {
+                let row = head - i32::from(k);
+                if row < 0 {
+                    break;
+                }
+                if row >= i32::from(ctx.lines) {
+                    continue;
+                }
+                let line = row as u16;
+                let is_head = k == 0;
+                let ch = if is_head {
+                    scramble_glyph(self.seed, u32::from(col), flicker)
+                } else {
+                    scramble_glyph(self.seed, u32::from(col), u32::from(line) | 1 << 16)
+                };
+                let fade = 1.0 - f32::from(k) / f32::from(self.trail);
+                let (r, g, b) = if is_head {
+                    let (r, g, b) = ctx.highlight_color;
+                    (
+                        r.saturating_add((255 - r) / 2),
+                        g.saturating_add((255 - g) / 2),
+                        b.saturating_add((255 - b) / 2),
+                    )
+                } else {
+                    scaled_rgb(ctx, ctx.highlight_color, 0.9 * fade)
+                };
+                let Some(idx) = frame.index(col, line) else {
+                    continue;
+                };
+                let cell = frame.cell_at_index(idx);
+                frame.set_force(
+                    col,
+                    line,
+                    Cell {
+                        ch,
+                        fg: Some(Color::Rgb { r, g, b }),
+                        bold: is_head,
+                        ..cell
+                    },
+                );
+            }
+        }
+    }
+
+    fn is_pre_rain(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Decrypt — a row of scrambling glyphs that locks, letter by letter from
+//! the left, into a word from `events.decrypt.words`, holds, then fades.
+//!
+//! Renders post-rain so droplets never cut through the word. The fade-out
+//! ends at black, so the cells it leaves behind are invisible until the
+//! rain overwrites them.
+
+use std::time::{Duration, Instant};
+
+use crossterm::style::Color;
+use rand::Rng;
+
+use crate::cell::Cell;
+use crate::constants::EventKind;
+use crate::frame::Frame;
+
+use super::super::ghost_events::{CinematicEvent, EventCtx};
+use super::{scaled_rgb, scramble_glyph};
+
+/// Every letter scrambles at least this long before the first one locks.
+const DECRYPT_SCRAMBLE_SECS: f32 = 0.6;
+/// Delay between two letters locking.
+const DECRYPT_LOCK_STAGGER_SECS: f32 = 0.09;
+/// Scramble glyph changes per second.
+const DECRYPT_SCRAMBLE_HZ: f32 = 18.0;
+const DECRYPT_FADE_IN_SECS: f32 = 0.2;
+const DECRYPT_FADE_OUT_SECS: f32 = 0.8;
+/// Brightness of a scrambling letter relative to a locked one.
+const DECRYPT_SCRAMBLE_BRIGHTNESS: f32 = 0.55;
+
+pub(crate) struct DecryptEvent {
+    col: u16,
+    line: u16,
+    word: Vec<char>,
+    seed: u64,
+    spawn_time: Instant,
+    duration: Duration,
+}
+
+impl DecryptEvent {
+    /// Place `word` at a random spot that fits on screen, or `None` when
+    /// the screen is too small for it.
+    pub(crate) fn new<R: Rng>(
+        word: &str,
+        cols: u16,
+        lines: u16,
+        now: Instant,
+        rng: &mut R,
+    ) -> Option<Self> {
+        let word: Vec<char> = word.chars().collect();
+        let len = u16::try_from(word.len()).ok()?;
+        if len == 0 || len.saturating_add(2) > cols || lines < 3 {
+            return None;
+        }
+        let col = rng.random_range(1..=cols - 1 - len);
+        let line = rng.random_range(1..lines - 1);
+        let hold = 1.5 + rng.random_range(0.0..1.0);
+        let resolve = DECRYPT_SCRAMBLE_SECS + DECRYPT_LOCK_STAGGER_SECS * f32::from(len);
+        Some(Self {
+            col,
+            line,
+            word,
+            seed: rng.random(),
+            spawn_time: now,
+            duration: Duration::from_secs_f32(resolve + hold + DECRYPT_FADE_OUT_SECS),
+        })
+    }
+}
+
+impl CinematicEvent for DecryptEvent {
+    fn kind(&self) -> EventKind {
+        EventKind::Decrypt
+    }
+
+    fn is_finished(&self, ctx: &EventCtx) -> bool {
+        ctx.now.saturating_duration_since(self.spawn_time) >= self.duration
+    }
+
+    fn render(&mut self, ctx: &EventCtx, frame: &mut Frame) {
+        if self.line >= ctx.lines {
+            return;
+        }
+        let elapsed = ctx
+            .now
+            .saturating_duration_since(self.spawn_time)
+            .as_secs_f32();
+        let remaining = self.duration.as_secs_f32() - elapsed;
+        let opacity = (elapsed / DECRYPT_FADE_IN_SECS).min(1.0)
+            * (remaining / DECRYPT_FADE_OUT_SECS).clamp(0.0, 1.0);
+        let tick = (elapsed * DECRYPT_SCRAMBLE_HZ) as u32;
+
+        for (i, &letter) in self.word.iter().enumerate() {
+            let col = self.col + i as u16;
+            if col >= ctx.cols {
+                break;
+            }
+            let locked = elapsed >= DECRYPT_SCRAMBLE_SECS + DECRYPT_LOCK_STAGGER_SECS * i as f32;
+            let Some(idx) = frame.index(col, self.line) else {
+                continue;
+            };
+            let cell = frame.cell_at_index(idx);
+            if locked && letter == ' ' {
+                // Keep the gap readable: no rain glyph between words.
+                frame.set(col, self.line, Cell::blank_with_bg(cell.bg));
+                continue;
+            }
+            let (ch, brightness) = if locked {
+                (letter, 1.0)
+            } else {
+                (
+                    scramble_glyph(self.seed, i as u32, tick),
+                    DECRYPT_SCRAMBLE_BRIGHTNESS,
+                )
+            };
+            let (r, g, b) = scaled_rgb(ctx, ctx.highlight_color, brightness * opacity);
+            if r == 0 && g == 0 && b == 0 {
+                continue;
+            }
+            frame.set_force(
+                col,
+                self.line,
+                Cell {
+                    ch,
+                    fg: Some(Color::Rgb { r, g, b }),
+                    bold: locked,
+                    ..cell
+                },
+            );
+        }
+    }
+}
@@ -11,6 +11,7 @@ use crossterm::style::Color;
 use rand::Rng;
 
 use crate::cell::Cell;
+use crate::constants::EventKind;
 use crate::frame::Frame;
 
 use super::super::ghost_events::{CinematicEvent, EventCtx};
@@ -39,8 +40,9 @@ pub(crate) struct GhostEvent {
 }
 
 impl GhostEvent {
-    pub(crate) fn new(col: u16, line: u16, now: Instant) -> Self {
-        let mut rng = rand::rng();
+    /// `rng` is the scheduler's event RNG, so glyph and duration follow
+    /// the session seed like the spawn position does.
+    pub(crate) fn new<R: Rng>(col: u16, line: u16, now: Instant, rng: &mut R) -> Self {
         let idx = rng.random_range(0..GHOST_CHARS.len());
         let duration_var = 2000 + rng.random_range(0..2000);
         let ch = GHOST_CHARS[idx];
@@ -71,6 +73,10 @@ impl GhostEvent {
 }
 
 impl CinematicEvent for GhostEvent {
+    fn kind(&self) -> EventKind {
+        EventKind::Ghost
+    }
+
     fn is_finished(&self, ctx: &EventCtx) -> bool {
         // v30 Hinnant: use `ctx.now` (captured once at frame start) instead
         // of `self.spawn_time.elapsed()` (which issues an `Instant::now()`
@@ -81,7 +87,7 @@ impl CinematicEvent for GhostEvent {
         ctx.now.saturating_duration_since(self.spawn_time) >= self.duration
     }
 
-    fn render(&self, ctx: &EventCtx, frame: &mut Frame) {
+    fn render(&mut self, ctx: &EventCtx, frame: &mut Frame) {
         let elapsed = ctx
             .now
             .saturating_duration_since(self.spawn_time)
@@ -3,10 +3,62 @@
 
 //! Cinematic event implementations for the GhostEventScheduler.
 //!
-//! Each event type is a struct implementing the `GhostEvent` trait
+//! Each event type is a struct implementing the `CinematicEvent` trait
 //! defined in `super::ghost_events`. New event types are added here
-//! without modifying the renderer or event manager.
+//! without modifying the renderer or event manager; their spawn knobs
+//! live in the `[events]` config section (`constants::EventTuning`).
+//!
+//! Events that write into the rain (ghost, scanline, cascade) render
+//! pre-rain: droplets draw over them, and the phosphor pass captures the
+//! cells they wrote, so the afterglow fades them out like any other glyph
+//! once the event finishes. Decrypt renders on top and fades itself out.
+//! The tear renders on top too, but only displaces what is there; it puts
+//! the band back at the start of the next frame (`restore`).
 
+pub(crate) mod cascade;
+pub(crate) mod decrypt;
 pub(crate) mod ghost;
+pub(crate) mod scanline;
+pub(crate) mod tear;
 
+pub(crate) use cascade::CascadeEvent;
+pub(crate) use decrypt::DecryptEvent;
 pub(crate) use ghost::GhostEvent;
+pub(crate) use scanline::ScanlineEvent;
+pub(crate) use tear::TearEvent;
+
+use super::ghost_events::EventCtx;
+
+/// Glyphs shown while a decrypt letter or a cascade cell is scrambling.
+/// All width 1 (see the Bug #11 note on `ghost::GHOST_CHARS`).
+const SCRAMBLE_CHARS: &[char] = &[
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'ｱ', 'ｲ', 'ｳ', 'ｴ', 'ｵ', 'ｶ', 'ｷ', 'ｸ', 'ｹ',
+    'ｺ', 'ｻ', 'ｼ', 'ｽ', 'ｾ', 'ｿ', 'ﾀ', 'ﾁ', 'ﾂ', 'ﾃ', 'ﾄ', 'Z', 'X', '*', '+', '<', '>',
+];
+
+/// Stable pseudo-random glyph for `(seed, a, b)`. Events pick glyphs by
+/// hashing instead of holding an RNG, so `render(&self)` stays pure.
+fn scramble_glyph(seed: u64, a: u32, b: u32) -> char {
+    let mut h = seed ^ (u64::from(a) << 32 | u64::from(b));
+    h = (h ^ (h >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    h = (h ^ (h >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    h ^= h >> 31;
+    SCRAMBLE_CHARS[(h % SCRAMBLE_CHARS.len() as u64) as usize]
+}
+
+/// Scale an event color by `factor`, through the chroma engine when it is
+/// active and the legacy truncating multiply otherwise (same split as the
+/// ghost opacity fade).
+fn scaled_rgb(ctx: &EventCtx, (r, g, b): (u8, u8, u8), factor: f32) -> (u8, u8, u8) {
+    let factor = factor.clamp(0.0, 1.0);
+    if ctx.color_pipeline.is_chroma() {
+        let scaled = crate::chroma_dragon_engine::palette::apply_brightness_rgb(r, g, b, factor);
+        crate::palette::decode_color(scaled).unwrap_or((r, g, b))
+    } else {
+        (
+            (r as f32 * factor) as u8,
+            (g as f32 * factor) as u8,
+            (b as f32 * factor) as u8,
+        )
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Scanline — a bright line sweeping top to bottom, lifting every glyph
+//! it crosses toward the palette's brightest stop, with a short trail.
+
+use std::time::{Duration, Instant};
+
+use crossterm::style::Color;
+use rand::Rng;
+
+use crate::cell::Cell;
+use crate::constants::EventKind;
+use crate::frame::Frame;
+
+use super::super::ghost_events::{CinematicEvent, EventCtx};
+use super::super::phosphor::anomaly_halo_blend;
+
+/// Rows behind the sweep line that are still lifted (fading).
+const SCANLINE_TRAIL_ROWS: u16 = 3;
+/// Blend toward the highlight on the sweep line itself.
+const SCANLINE_INTENSITY: f32 = 0.85;
+
+pub(crate) struct ScanlineEvent {
+    spawn_time: Instant,
+    duration: Duration,
+}
+
+impl ScanlineEvent {
+    pub(crate) fn new<R: Rng>(now: Instant, rng: &mut R) -> Self {
+        Self {
+            spawn_time: now,
+            duration: Duration::from_millis(1200 + rng.random_range(0..800)),
+        }
+    }
+}
+
+impl CinematicEvent for ScanlineEvent {
+    fn kind(&self) -> EventKind {
+        EventKind::Scanline
+    }
+
+    fn is_finished(&self, ctx: &EventCtx) -> bool {
+        ctx.now.saturating_duration_since(self.spawn_time) >= self.duration
+    }
+
+    fn render(&mut self, ctx: &EventCtx, frame: &mut Frame) {
+        let progress = (ctx
+            .now
+            .saturating_duration_since(self.spawn_time)
+            .as_secs_f32()
+            / self.duration.as_secs_f32())
+        .clamp(0.0, 1.0);
+        // The line runs past the bottom by the trail length so the trail
+        // leaves the screen too.
+        let head = (progress * f32::from(ctx.lines + SCANLINE_TRAIL_ROWS)) as i32;
+        let (hr, hg, hb) = ctx.highlight_color;
+        let target = Some(Color::Rgb {
+            r: hr,
+            g: hg,
+            b: hb,
+        });
+        let is_chroma = ctx.color_pipeline.is_chroma();
+
+        for k in 0..=SCANLINE_TRAIL_ROWS {
+            let row = head - i32::from(k);
+            if row < 0 || row >= i32::from(ctx.lines) {
+                continue;
+            }
+            let line = row as u16;
+            let intensity =
+                SCANLINE_INTENSITY * (1.0 - f32::from(k) / f32::from(SCANLINE_TRAIL_ROWS + 1));
+            for col in 0..ctx.cols {
+                let Some(idx) = frame.index(col, line) else {
+                    break;
+                };
+                let cell = frame.cell_at_index(idx);
+                let Some(fg) = cell.fg else {
+                    continue;
+                };
+                if cell.ch == ' ' {
+                    continue;
+                }
+                frame.set(
+                    col,
+                    line,
+                    Cell {
+                        fg: Some(anomaly_halo_blend(fg, target, intensity, is_chroma)),
+                        ..cell
+                    },
+                );
+            }
+        }
+    }
+
+    fn is_pre_rain(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Glitch tear — a band of rows shifted sideways (wrapping) for a split
+//! second, then snapped back.
+
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+
+use crate::cell::Cell;
+use crate::constants::{EventKind, TEAR_MAX_OFFSET};
+use crate::frame::Frame;
+
+use super::super::ghost_events::{CinematicEvent, EventCtx};
+
+/// Tallest torn band, in rows.
+const TEAR_MAX_HEIGHT: u16 = 4;
+
+/// The band as it was before this frame's shift.
+struct Band {
+    /// `frame.current_gen()` when the band was saved; a frame cleared
+    /// since then has nothing to restore.
+    gen: u32,
+    cols: u16,
+    /// `cols` cells per torn row, top to bottom.
+    cells: Vec<Cell>,
+}
+
+pub(crate) struct TearEvent {
+    line: u16,
+    height: u16,
+    offset: i16,
+    spawn_time: Instant,
+    duration: Duration,
+    /// The rain keeps simulating in the frame's cells, so the shift is a
+    /// per-frame overlay: `render` saves the band and draws it shifted
+    /// after everything else, `restore` puts the saved cells back before
+    /// the next frame draws anything. The event only finishes once no
+    /// shifted band is left in the frame.
+    saved: Option<Band>,
+}
+
+impl TearEvent {
+    /// `None` when the screen is too narrow to tear.
+    pub(crate) fn new<R: Rng>(cols: u16, lines: u16, now: Instant, rng: &mut R) -> Option<Self> {
+        let max_offset = TEAR_MAX_OFFSET.min(cols / 2);
+        if max_offset < 2 || lines == 0 {
+            return None;
+        }
+        let height = rng.random_range(1..=TEAR_MAX_HEIGHT.min(lines));
+        let magnitude = rng.random_range(2..=max_offset) as i16;
+        let offset = if rng.random_range(0..2) == 0 {
+            magnitude
+        } else {
+            -magnitude
+        };
+        Some(Self {
+            line: rng.random_range(0..=lines - height),
+            height,
+            offset,
+            spawn_time: now,
+            duration: Duration::from_millis(150 + rng.random_range(0..300)),
+            saved: None,
+        })
+    }
+}
+
+impl CinematicEvent for TearEvent {
+    fn kind(&self) -> EventKind {
+        EventKind::Tear
+    }
+
+    fn is_finished(&self, ctx: &EventCtx) -> bool {
+        ctx.now.saturating_duration_since(self.spawn_time) >= self.duration && self.saved.is_none()
+    }
+
+    fn render(&mut self, ctx: &EventCtx, frame: &mut Frame) {
+        if ctx.now.saturating_duration_since(self.spawn_time) >= self.duration {
+            return;
+        }
+        let cols = ctx.cols.min(frame.width);
+        let end = self.line.saturating_add(self.height).min(ctx.lines);
+        let d = self.offset.unsigned_abs();
+        if d == 0 || d >= cols || end <= self.line || frame.index(cols - 1, end - 1).is_none() {
+            return;
+        }
+        let mut cells = Vec::with_capacity(usize::from(end - self.line) * usize::from(cols));
+        for line in self.line..end {
+            let row = usize::from(line) * usize::from(frame.width);
+            cells.extend((0..cols).map(|col| frame.cell_at_index(row + usize::from(col))));
+        }
+        // Column `col` shows the saved cell `offset` columns to its left
+        // (right for a negative offset), wrapping around the row.
+        let shift = (i32::from(cols) - i32::from(self.offset)).rem_euclid(i32::from(cols)) as usize;
+        for (row, line) in cells.chunks(usize::from(cols)).zip(self.line..end) {
+            for col in 0..cols {
+                frame.set_force(col, line, row[(usize::from(col) + shift) % row.len()]);
+            }
+        }
+        self.saved = Some(Band {
+            gen: frame.current_gen(),
+            cols,
+            cells,
+        });
+    }
+
+    fn restore(&mut self, frame: &mut Frame) {
+        let Some(band) = self.saved.take() else {
+            return;
+        };
+        if band.gen != frame.current_gen() {
+            return;
+        }
+        for (row, line) in band.cells.chunks(usize::from(band.cols)).zip(self.line..) {
+            for (col, cell) in row.iter().enumerate() {
+                frame.set_force(col as u16, line, *cell);
+            }
+        }
+    }
+}
@@ -1,11 +1,12 @@
 // Copyright (C) 2026 rezky_nightky
 // SPDX-License-Identifier: GPL-3.0-only
 
-//! Cinematic Event Engine — ghost-kanji event system.
+//! Cinematic Event Engine — ghost, decrypt, scanline, cascade and tear.
 //!
 //! Manages lifecycle of discrete cinematic visual events. Each event
-//! implements `GhostEvent`; new types are added without modifying
-//! the renderer.
+//! implements `CinematicEvent`; new types are added without modifying
+//! the renderer. Spawn chance and cooldown per kind come from the
+//! `[events]` config section ([`EventTuning`]).
 //!
 //! ## Lifecycle
 //!
@@ -18,10 +19,10 @@
 //! aspirational — no event implementation ever produced it, and the
 //! `seed_phosphor`/`clean_stale_phosphor` machinery that depended on it
 //! was unreachable. Both were removed in the v30 dragon-egg hunt.
-use super::events::GhostEvent;
+use super::events::{CascadeEvent, DecryptEvent, GhostEvent, ScanlineEvent, TearEvent};
 use crate::constants::*;
 use crate::frame::Frame;
-use rand::{distr::Distribution, rngs::StdRng, SeedableRng};
+use rand::{distr::Distribution, rngs::StdRng, Rng, SeedableRng};
 use smallvec::SmallVec;
 use std::time::Instant;
 
@@ -39,6 +40,9 @@ pub(crate) struct EventCtx {
     /// scene's color scheme (green palette → dark green ghosts, red
     /// palette → dark red ghosts, etc.).
     pub ghost_base_color: (u8, u8, u8),
+    /// The palette's brightest stop, for events that draw or lift glyphs
+    /// (decrypt, scanline, cascade). White when the palette has no RGB.
+    pub highlight_color: (u8, u8, u8),
     /// (chroma audit, A9): cached ColorPipeline so the ghost event
     /// render can route its opacity fade through chroma::palette (chroma
     /// path) or chroma::legacy (legacy fallback). Copy enum, predicted-
@@ -61,10 +65,13 @@ pub(crate) struct EventCtx {
 /// v30: renamed from `AtmosphericEvent` to `CinematicEvent` to avoid
 /// collision with the `GhostEvent` struct (which implements this trait)
 /// and to disambiguate from the deleted atmosphere engine subsystem.
-/// The scheduler (`GhostEventScheduler`) is currently scoped to ghost
-/// events only; the trait name is broader to allow future non-ghost
-/// cinematic events without rename churn.
+/// The scheduler kept its `GhostEventScheduler` name from when ghosts were
+/// the only implementation; it now drives every [`EventKind`].
 pub(crate) trait CinematicEvent: Send {
+    /// Which `[events]` knobs govern this event (per-kind active cap and
+    /// cooldown bookkeeping).
+    fn kind(&self) -> EventKind;
+
     /// Returns true when the event has finished and can be recycled.
     /// v30 Hinnant: takes `ctx` so implementations use `ctx.now` instead
     /// of `self.spawn_time.elapsed()` (which issues an `Instant::now()`
@@ -72,13 +79,18 @@ pub(crate) trait CinematicEvent: Send {
     fn is_finished(&self, ctx: &EventCtx) -> bool;
 
     /// Called each frame while alive. Writes visual output to Frame.
-    fn render(&self, ctx: &EventCtx, frame: &mut Frame);
+    fn render(&mut self, ctx: &EventCtx, frame: &mut Frame);
 
     /// Returns true if this event should render before rain (behind droplets).
     /// Ghost events render pre-rain so rain partially overwrites them.
     fn is_pre_rain(&self) -> bool {
         false
     }
+
+    /// Called at the start of every frame, before anything draws: put
+    /// back cells the last `render` moved instead of drew over, so the
+    /// rain never simulates on top of them. Only the tear needs it.
+    fn restore(&mut self, _frame: &mut Frame) {}
 }
 
 // ── Event Manager ─────────────────────────────────────────────────────────
@@ -90,6 +102,11 @@ pub(crate) struct GhostEventScheduler {
     rng: StdRng,
     /// Events are opt-in; disabled by default (tests, bench).
     events_enabled: bool,
+    /// Per-kind chance, cooldown and the decrypt word list.
+    tuning: EventTuning,
+    /// Last spawn per kind (indexed by `EventKind::index`), for cooldowns.
+    /// Survives `reset()` so a resize does not re-arm every event.
+    last_spawn: [Option<Instant>; EventKind::ALL.len()],
 }
 
 impl GhostEventScheduler {
@@ -109,9 +126,22 @@ impl GhostEventScheduler {
             events: SmallVec::new(),
             rng,
             events_enabled: false,
+            tuning: EventTuning::default(),
+            last_spawn: [None; EventKind::ALL.len()],
         }
     }
 
+    /// Replace the `[events]` knobs (startup and live reload).
+    pub(crate) fn set_tuning(&mut self, tuning: EventTuning) {
+        self.tuning = tuning;
+    }
+
+    /// Re-seed the event RNG from a user `--seed` (same XOR mask as the
+    /// default constant seed, so seed streams never alias the cloud RNG).
+    pub(crate) fn reseed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed ^ EVENT_RNG_XOR);
+    }
+
     /// Reset all state (terminal resize, scene change).
     ///
     /// Drops all active events — events are stateless between frames (no
@@ -132,16 +162,21 @@ impl GhostEventScheduler {
     }
 
     // ── Trigger Evaluation ────────────────────────────────────────────────
-    /// Evaluate triggers and spawn new ghost events as appropriate.
+    /// Evaluate triggers and spawn new events as appropriate, rolling each
+    /// kind in `EventKind::ALL` order.
     /// Called once per frame before simulation update.
     ///
     /// v30 dragon-egg hunt: dropped three legacy parameters (`now`,
     /// `anomaly_density`, `palette_color`) that were computed by the
     /// caller every frame just to be passed in here and then ignored.
     /// The remaining parameters are all read by the trigger logic.
+    ///
+    /// `now` is back (virtual clock): the spawned ghost's birth time must
+    /// come from the cloud's clock, not `Instant::now()`.
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn evaluate_triggers(
         &mut self,
+        now: Instant,
         perf_pressure: f32,
         cols: u16,
         lines: u16,
@@ -159,21 +194,31 @@ impl GhostEventScheduler {
             return;
         }
 
-        self.try_spawn_ghost(cols, lines);
+        for kind in EventKind::ALL {
+            self.try_spawn(kind, now, cols, lines);
+        }
+    }
+
+    /// Undo last frame's displacements (see [`CinematicEvent::restore`]).
+    /// Runs for finished events too: their last frame still needs it.
+    pub(crate) fn restore(&mut self, frame: &mut Frame) {
+        for event in &mut self.events {
+            event.restore(frame);
+        }
     }
 
     /// Render pre-rain events (ghosts, behind droplets).
-    pub(crate) fn render_pre_rain(&self, ctx: &EventCtx, frame: &mut Frame) {
+    pub(crate) fn render_pre_rain(&mut self, ctx: &EventCtx, frame: &mut Frame) {
         self.render_phase(ctx, frame, true);
     }
 
     /// Render post-rain events.
-    pub(crate) fn render(&self, ctx: &EventCtx, frame: &mut Frame) {
+    pub(crate) fn render(&mut self, ctx: &EventCtx, frame: &mut Frame) {
         self.render_phase(ctx, frame, false);
     }
 
-    fn render_phase(&self, ctx: &EventCtx, frame: &mut Frame, pre_rain: bool) {
-        for event in &self.events {
+    fn render_phase(&mut self, ctx: &EventCtx, frame: &mut Frame, pre_rain: bool) {
+        for event in &mut self.events {
             if !event.is_finished(ctx) && event.is_pre_rain() == pre_rain {
                 event.render(ctx, frame);
             }
@@ -203,28 +248,63 @@ impl GhostEventScheduler {
 
     // ── Private Helpers ────────────────────────────────────────────────────
 
-    /// Try to spawn a ghost kanji character.
-    fn try_spawn_ghost(&mut self, cols: u16, lines: u16) {
-        // Max 1 ghost active
-        if self.events.iter().filter(|e| e.is_pre_rain()).count() >= GHOST_MAX_ACTIVE {
+    /// Roll one event kind: skipped while its chance is 0, while one of
+    /// the same kind is still active (ghosts: `GHOST_MAX_ACTIVE`), or
+    /// during its cooldown.
+    fn try_spawn(&mut self, kind: EventKind, now: Instant, cols: u16, lines: u16) {
+        let knobs = self.tuning.knobs(kind);
+        if knobs.chance <= 0.0 {
             return;
         }
-        let uniform = rand::distr::Uniform::new(0.0f64, 1.0f64).expect("[0,1) valid");
-        if uniform.sample(&mut self.rng) >= GHOST_SPAWN_CHANCE_PER_TICK {
+        if kind == EventKind::Decrypt && self.tuning.decrypt_words.is_empty() {
             return;
         }
-        let col = if cols > 5 {
-            1 + (uniform.sample(&mut self.rng) * (cols - 5) as f64) as u16
+        let max_active = if kind == EventKind::Ghost {
+            GHOST_MAX_ACTIVE
         } else {
             1
         };
-        let line = if lines > 3 {
-            1 + (uniform.sample(&mut self.rng) * (lines - 3) as f64) as u16
-        } else {
-            1
+        if self.events.iter().filter(|e| e.kind() == kind).count() >= max_active {
+            return;
+        }
+        if let Some(last) = self.last_spawn[kind.index()] {
+            if now.saturating_duration_since(last) < knobs.cooldown {
+                return;
+            }
+        }
+        let uniform = rand::distr::Uniform::new(0.0f64, 1.0f64).expect("[0,1) valid");
+        if uniform.sample(&mut self.rng) >= knobs.chance {
+            return;
+        }
+        let event: Option<Box<dyn CinematicEvent>> = match kind {
+            EventKind::Ghost => {
+                let col = if cols > 5 {
+                    1 + (uniform.sample(&mut self.rng) * (cols - 5) as f64) as u16
+                } else {
+                    1
+                };
+                let line = if lines > 3 {
+                    1 + (uniform.sample(&mut self.rng) * (lines - 3) as f64) as u16
+                } else {
+                    1
+                };
+                Some(Box::new(GhostEvent::new(col, line, now, &mut self.rng)))
+            }
+            EventKind::Decrypt => {
+                let words = &self.tuning.decrypt_words;
+                let word = &words[self.rng.random_range(0..words.len())];
+                DecryptEvent::new(word, cols, lines, now, &mut self.rng)
+                    .map(|e| Box::new(e) as Box<dyn CinematicEvent>)
+            }
+            EventKind::Scanline => Some(Box::new(ScanlineEvent::new(now, &mut self.rng))),
+            EventKind::Cascade => CascadeEvent::new(cols, lines, now, &mut self.rng)
+                .map(|e| Box::new(e) as Box<dyn CinematicEvent>),
+            EventKind::Tear => TearEvent::new(cols, lines, now, &mut self.rng)
+                .map(|e| Box::new(e) as Box<dyn CinematicEvent>),
         };
-        let now = Instant::now();
-        let event: Box<dyn CinematicEvent> = Box::new(GhostEvent::new(col, line, now));
-        self.events.push(event);
+        if let Some(event) = event {
+            self.last_spawn[kind.index()] = Some(now);
+            self.events.push(event);
+        }
     }
 }
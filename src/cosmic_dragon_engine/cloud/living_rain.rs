@@ -76,8 +76,9 @@ impl GustState {
     /// Build a fresh state machine in the IDLE phase. The first IDLE
     /// window samples from `[GUST_IDLE_MIN_SECS, GUST_IDLE_MAX_SECS]` so
     /// the first gust doesn't always arrive at the same time after launch.
-    pub(crate) fn new(now: Instant) -> Self {
-        let idle_dur = sample_idle_duration(&mut rand::rng());
+    /// `rng` is the cloud RNG under `--seed`, thread entropy otherwise.
+    pub(crate) fn new<R: Rng>(now: Instant, rng: &mut R) -> Self {
+        let idle_dur = sample_idle_duration(rng);
         Self {
             phase: GustPhase::Idle,
             phase_start: now,
@@ -278,7 +279,7 @@ mod tests {
     #[test]
     fn gust_starts_idle_with_multiplier_one() {
         let now = Instant::now();
-        let mut g = GustState::new(now);
+        let mut g = GustState::new(now, &mut fixed_rng());
         let m = g.tick(now, &mut fixed_rng());
         assert!(
             (m - 1.0).abs() < 1e-6,
@@ -290,7 +291,7 @@ mod tests {
     fn gust_idle_stays_at_one_until_duration_elapses() {
         let now = Instant::now();
         let mut rng = fixed_rng();
-        let mut g = GustState::new(now);
+        let mut g = GustState::new(now, &mut fixed_rng());
         // Sample several frames inside IDLE — should never leave 1.0.
         for ms in [0, 100, 500, 1_000, 5_000, 10_000] {
             let t = now + Duration::from_millis(ms);
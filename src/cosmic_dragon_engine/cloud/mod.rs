@@ -5,13 +5,15 @@
 //! Key systems: **DrawCtx** (read-only renderer snapshot for per-frame
 //! callbacks), **DropletSpawner** (3 parallax layers, see `spawn.rs`),
 //! **GhostEventScheduler** (ghost-kanji events, see `ghost_events.rs`),
-//! **LivingRain** (wind-gust drift, see `living_rain.rs`).
+//! **LivingRain** (wind-gust drift, see `living_rain.rs`),
+//! **Clock** (real or manually stepped simulation time, see `clock.rs`).
 //! On color-scheme change, new droplets inherit the new palette while
 //! existing droplets keep their old colors until they age out —
 //! transition smoothed via Phase 8 hue-preserving chroma shader
 //! (see `chroma/shaders/transition.rs`).
 
 mod border;
+mod clock;
 // Newly relocated from src/ root (audit M12). Re-exported as `pub(crate)`
 // so the 11 existing `crate::cinematic::Foo` and
 // `crate::brightness_factors::Foo` call sites continue to resolve via the
@@ -38,10 +40,12 @@ mod state;
 #[cfg(test)]
 mod tests;
 
+pub(crate) use clock::{Clock, ManualClock, RealClock};
 pub(crate) use render::{CharLoc, DrawCtx};
 
 use border::is_border_char;
 
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use bitvec::prelude::BitVec;
@@ -60,9 +64,11 @@ use crate::frame::Frame;
 use crate::palette::{build_palette, Palette};
 use crate::rain_style::RainStyle;
 use crate::runtime::{BoldMode, ColorMode, ColorPipeline, ColorScheme, MonolithSize, ShadingMode};
+use crate::scene_custom::density_mask::{column_peaks, DensityMask, DENSITY_MASK_DEFAULT_FLOOR};
 
 use ecosystem::{
-    BehaviorProfile, ColorEcosystem, EntropyDrift, ProfileParams, RendererMemory, StorytellingState,
+    AmbientCrossfade, BehaviorProfile, ColorEcosystem, EntropyDrift, ProfileParams, RendererMemory,
+    StorytellingState,
 };
 use monolith::MonolithRain;
 use state::{AnomalyZone, ColumnStatus, MsgChr};
@@ -121,6 +127,16 @@ pub struct Cloud {
 
     pub(crate) droplet_density: f32,
     pub(crate) monolith_density_map: Option<&'static [f64]>,
+    /// 2D density mask from `scene-custom.<name>.density-mask`; baked into
+    /// `density_mask_lut` / `density_mask_cols` on every `reset()`.
+    pub(crate) density_mask: Option<Arc<DensityMask>>,
+    pub(crate) density_mask_floor: f32,
+    /// Baked mask weight per cell (flat: `line * cols + col`), applied as a
+    /// brightness factor. Empty when no mask is set.
+    pub(crate) density_mask_lut: Vec<f32>,
+    /// Peak mask weight per column, applied to spawn probability. Empty
+    /// when no mask is set.
+    pub(crate) density_mask_cols: Vec<f32>,
     pub(crate) droplets_per_sec: f32,
     pub(crate) chars_per_sec: f32,
 
@@ -170,6 +186,11 @@ pub struct Cloud {
 
     pub(crate) mt: StdRng,
 
+    /// Simulation time source. `RealClock` by default; offline renderers
+    /// and golden-frame tests install a stepped `ManualClock` via
+    /// `Cloud::with_clock` (see `clock.rs`).
+    pub(crate) clock: Box<dyn Clock>,
+
     pub(crate) rand_chance: Uniform<f32>,
     pub(crate) rand_line: Uniform<u16>,
     pub(crate) rand_cpidx: Uniform<u16>,
@@ -225,6 +246,10 @@ pub struct Cloud {
     pub(crate) active_palette_slot: u8,
 
     pub(crate) transition_start: Option<Instant>,
+    /// Length of the current palette transition wave. Normally
+    /// `COLOR_TRANSITION_DURATION_MS`; an ambient crossfade stretches it
+    /// to the crossfade length.
+    pub(crate) color_transition_ms: u32,
 
     pub(crate) column_palette_slot: Vec<u8>,
 
@@ -263,6 +288,8 @@ pub struct Cloud {
     pub(crate) profile_current: ProfileParams,
     pub(crate) profile_target: ProfileParams,
     pub(crate) profile_transition_start: Option<Instant>,
+    /// Active `ambient-transition` blend, if any.
+    pub(crate) ambient_crossfade: Option<AmbientCrossfade>,
 
     pub(crate) color_ecosystem: ColorEcosystem,
     pub(crate) entropy_drift: EntropyDrift,
@@ -273,7 +300,7 @@ pub struct Cloud {
 
     /// Crystal Dragon Engine: ambient intelligence for palette drift.
     /// Point-based temperature group system (Cold/Medium/Hot) +
-    /// calc-v1 probabilistic weighted theme selection.
+    /// calc-v1 / calc-v2 theme selection.
     pub(crate) crystal_dragon: bool,
     /// Crystal Dragon sensor state (CPU/CLOCK polling + point tracking).
     pub(crate) crystal_dragon_sensor: crate::crystal_dragon_engine::CrystalDragonSensor,
@@ -281,10 +308,17 @@ pub struct Cloud {
     pub(crate) crystal_dragon_control: crate::crystal_dragon_engine::CrystalDragonControl,
     /// Last Crystal Dragon poll timestamp. None until first poll.
     pub(crate) crystal_dragon_last_poll: Option<std::time::Instant>,
+    /// calc-v2 memory (recent themes, trend, hysteresis group). Unused
+    /// under calc-v1.
+    pub(crate) crystal_dragon_v2: crate::crystal_dragon_engine::point_system::CalcV2State,
     /// v30 Bug #4: true when --colors-custom active → suppress palette drift.
     pub(crate) custom_palette_active: bool,
     /// v30 Bug #5: color_tune stored on Cloud so set_color_scheme re-applies it.
     pub(crate) color_tune: crate::color_tune::ColorTune,
+    /// `[rain-tuning]` overrides for the per-frame look (parallax layers,
+    /// phosphor decay, head bloom, fog, vignettes). The radial vignette is
+    /// baked into `vignette_lut` on `reset()`.
+    pub(crate) rain_tuning: crate::constants::RainTuning,
     /// true when ambient asserted palette → suppress Crystal Dragon palette drift
     /// replacement (climate drift still runs). Cleared by `c`/`C`/`x`.
     /// See docs/audits/AMBIENT_SCHEDULER_AUDIT.md §1.3.
@@ -308,6 +342,10 @@ pub struct Cloud {
     pub(crate) verbose: bool,
     /// Total stuck cells cleared across all sweeps.
     pub(crate) stuck_cells_cleared_total: u64,
+    /// `--seed` / `seed =`: when Some, every cloud RNG (droplet `mt`,
+    /// ghost events, gusts, the 600 s reseed) and the intro particles
+    /// derive from this value. None = constant initial seed + entropy.
+    pub(crate) seed: Option<u64>,
     /// Total sweeps that found at least one stuck cell.
     pub(crate) stuck_sweeps_with_clears: u64,
 }
@@ -323,13 +361,38 @@ impl Cloud {
         color_scheme: ColorScheme,
         rain_style: RainStyle,
     ) -> Self {
-        let now = Instant::now();
+        Self::with_clock(
+            Box::new(RealClock),
+            color_mode,
+            shading_mode,
+            bold_mode,
+            async_mode,
+            default_background,
+            color_scheme,
+            rain_style,
+        )
+    }
+
+    /// Like [`Cloud::new`], but every simulation timestamp (including the
+    /// ones taken here) comes from `clock`.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn with_clock(
+        clock: Box<dyn Clock>,
+        color_mode: ColorMode,
+        shading_mode: ShadingMode,
+        bold_mode: BoldMode,
+        async_mode: bool,
+        default_background: bool,
+        color_scheme: ColorScheme,
+        rain_style: RainStyle,
+    ) -> Self {
+        let now = clock.now();
         let mt = StdRng::seed_from_u64(RNG_INITIAL_SEED);
 
         Self {
             lines: 25,
             cols: 80,
-            palette: build_palette(color_scheme, color_mode, default_background),
+            palette: build_palette(color_scheme, color_mode.palette_mode(), default_background),
             color_mode,
             color_pipeline: ColorPipeline::detect(color_mode),
             rain_style,
@@ -341,6 +404,10 @@ impl Cloud {
             pause: false,
             droplet_density: 1.0,
             monolith_density_map: None,
+            density_mask: None,
+            density_mask_floor: DENSITY_MASK_DEFAULT_FLOOR,
+            density_mask_lut: Vec::new(),
+            density_mask_cols: Vec::new(),
             droplets_per_sec: 5.0,
             chars_per_sec: 8.0,
             glitchy: true,
@@ -371,6 +438,7 @@ impl Cloud {
             droplet_free_list: Vec::new(),
             col_stat: Vec::new(),
             mt,
+            clock,
             rand_chance: Uniform::new(0.0, 1.0).expect("rand_chance: [0,1) always valid"),
             rand_line: Uniform::new_inclusive(0, 23).expect("rand_line: [0,23] always valid"),
             rand_cpidx: Uniform::new_inclusive(0, MAX_CHAR_POOL_IDX)
@@ -413,6 +481,7 @@ impl Cloud {
             palette_table: [None, None, None, None],
             active_palette_slot: 0,
             transition_start: None,
+            color_transition_ms: u32::from(COLOR_TRANSITION_DURATION_MS),
             column_palette_slot: Vec::new(),
             mouse_col: u16::MAX,
             mouse_line: u16::MAX,
@@ -459,6 +528,7 @@ impl Cloud {
             profile_current: BehaviorProfile::Monolith.params(),
             profile_target: BehaviorProfile::Monolith.params(),
             profile_transition_start: None,
+            ambient_crossfade: None,
             color_ecosystem: ColorEcosystem::new(now),
             entropy_drift: EntropyDrift::new(now),
             memory: RendererMemory::new(now),
@@ -471,15 +541,18 @@ impl Cloud {
             ),
             crystal_dragon_control: crate::crystal_dragon_engine::CrystalDragonControl::default(),
             crystal_dragon_last_poll: None,
+            crystal_dragon_v2: crate::crystal_dragon_engine::point_system::CalcV2State::default(),
             // v30 strengthen: overridden in app.rs create_cloud.
             custom_palette_active: false,
             color_tune: crate::color_tune::ColorTune::IDENTITY,
+            rain_tuning: crate::constants::RainTuning::DEFAULT,
             // ambient-harmony flags start false (set by ambient fire,
             // cleared by user override x/c/s).
             ambient_palette_locked: false,
             user_override_since_ambient: false,
             event_manager: GhostEventScheduler::new(now),
-            gust: living_rain::GustState::new(now),
+            gust: living_rain::GustState::new(now, &mut rand::rng()),
+            seed: None,
             last_sim_ms: 0.0,
             last_render_ms: 0.0,
             enable_component_timing: false,
@@ -493,18 +566,35 @@ impl Cloud {
     pub fn set_message(&mut self, msg: &str) {
         self.message_text = Some(msg.to_string());
         // v25: delay typewriter 6s so the intro finishes first.
-        self.message_start_time = Some(Instant::now() + Duration::from_secs(6));
+        self.message_start_time = Some(self.clock.now() + Duration::from_secs(6));
         self.reset_message();
         self.force_draw_everything = true;
     }
 
     pub fn restart_message_typewriter(&mut self) {
         if self.message_text.is_some() {
-            self.message_start_time = Some(Instant::now() + Duration::from_secs(6));
+            self.message_start_time = Some(self.clock.now() + Duration::from_secs(6));
             self.force_draw_everything = true;
         }
     }
 
+    /// Swap the overlay text mid-session (`None` removes the box). Unlike
+    /// [`Cloud::set_message`] the typewriter starts right away — the intro
+    /// is long over.
+    pub fn replace_message(&mut self, msg: Option<&str>) {
+        self.message_text = msg.map(str::to_string);
+        self.message_start_time = msg.map(|_| self.clock.now());
+        self.message.clear();
+        self.border_order.clear();
+        self.reset_message();
+        self.force_draw_everything = true;
+    }
+
+    #[must_use]
+    pub fn message_text(&self) -> Option<&str> {
+        self.message_text.as_deref()
+    }
+
     pub fn set_message_border(&mut self, on: bool) {
         self.message_border = on;
         if self.message_text.is_some() {
@@ -517,6 +607,12 @@ impl Cloud {
         self.event_manager.enable_events();
     }
 
+    /// Apply the `[events]` section (per-kind chance, cooldown, decrypt
+    /// words).
+    pub(crate) fn set_event_tuning(&mut self, tuning: crate::constants::EventTuning) {
+        self.event_manager.set_tuning(tuning);
+    }
+
     pub fn set_mouse_position(&mut self, col: u16, line: u16) {
         self.mouse_col = col;
         self.mouse_line = line;
@@ -525,9 +621,9 @@ impl Cloud {
     pub fn set_mouse_click(&mut self, col: u16, line: u16) {
         // v30 fix: bounded pool. Mirrors spawn_quantum_ripple: first inactive
         // slot, or evict OLDEST (smallest birth) if all active.
-        let now = Instant::now();
+        let now = self.clock.now();
         let mut slot = None;
-        let mut oldest = (0usize, Instant::now());
+        let mut oldest = (0usize, now);
         for (i, w) in self.flash_waves.iter_mut().enumerate() {
             if !w.active {
                 slot = Some(i);
@@ -606,6 +702,30 @@ impl Cloud {
         self.monolith_density_map = map;
     }
 
+    /// Set (or clear) the 2D density mask and its floor weight. Bakes the
+    /// lookup tables at once when the terminal size is already known;
+    /// otherwise the next `reset()` does.
+    pub(crate) fn set_density_mask(&mut self, mask: Option<Arc<DensityMask>>, floor: f32) {
+        self.density_mask = mask;
+        self.density_mask_floor = floor;
+        self.bake_density_mask();
+    }
+
+    /// Rebuild `density_mask_lut` / `density_mask_cols` for the current
+    /// `cols × lines`.
+    pub(crate) fn bake_density_mask(&mut self) {
+        self.density_mask_lut.clear();
+        self.density_mask_cols.clear();
+        let Some(mask) = &self.density_mask else {
+            return;
+        };
+        if self.cols == 0 || self.lines == 0 {
+            return;
+        }
+        self.density_mask_lut = mask.bake(self.cols, self.lines, self.density_mask_floor);
+        self.density_mask_cols = column_peaks(&self.density_mask_lut, self.cols);
+    }
+
     /// Phase D Bug #9: carry color_ecosystem + entropy_drift across live-reload
     /// (prevents brightness discontinuity when config is edited mid-session).
     /// v30: also carries `start_anchor` so time-varying phases stay continuous.
@@ -613,10 +733,19 @@ impl Cloud {
         self.color_ecosystem = other.color_ecosystem;
         self.entropy_drift = other.entropy_drift;
         self.start_anchor = other.start_anchor;
-        // Crystal Dragon sensor state survives live reload.
+        // Crystal Dragon sensor state survives live reload. The calc
+        // method and sensor sources come from the reloaded config, not
+        // the old cloud.
+        let own = self.crystal_dragon_control;
         self.crystal_dragon_sensor = other.crystal_dragon_sensor;
-        self.crystal_dragon_control = other.crystal_dragon_control;
+        self.crystal_dragon_sensor.set_sources(own.sensors);
+        self.crystal_dragon_control = crate::crystal_dragon_engine::CrystalDragonControl {
+            calc_method: own.calc_method,
+            sensors: own.sensors,
+            ..other.crystal_dragon_control
+        };
         self.crystal_dragon_last_poll = other.crystal_dragon_last_poll;
+        self.crystal_dragon_v2 = other.crystal_dragon_v2;
     }
     /// Active scene name. Test-only accessor — production reads the
     /// `scene_name` field directly or via `hud_colors()`.
@@ -669,7 +798,7 @@ impl Cloud {
         if self.pause {
             self.pause = false;
             if let Some(pt) = self.pause_time.take() {
-                let now = Instant::now();
+                let now = self.clock.now();
                 let elapsed = now.saturating_duration_since(pt);
                 self.last_spawn_time = now;
                 self.spawn_remainder = 0.0;
@@ -708,6 +837,9 @@ impl Cloud {
                 if let Some(ref mut pt) = self.profile_transition_start {
                     *pt += elapsed;
                 }
+                if let Some(ref mut cf) = self.ambient_crossfade {
+                    cf.start += elapsed;
+                }
                 if let Some(ref mut ct) = self.charset_transition_start {
                     *ct += elapsed;
                 }
@@ -743,7 +875,7 @@ impl Cloud {
         } else {
             // BRANCH 3: running → start deceleration. Clear stale
             // resume_start (audit §8.3 — rapid triple-tap state hygiene).
-            self.pause_start = Some(Instant::now());
+            self.pause_start = Some(self.clock.now());
             self.resume_start = None;
             true
         }
@@ -949,9 +1081,10 @@ impl Cloud {
 
         // v30 Hinnant: hoist start.elapsed() above the per-cell loop below
         // (was 1 syscall per revealed content cell, 50-200×/frame).
+        let now = self.clock.now();
         let message_elapsed_ms: Option<usize> = self
             .message_start_time
-            .map(|start| start.elapsed().as_millis() as usize);
+            .map(|start| now.saturating_duration_since(start).as_millis() as usize);
 
         let reveal_count = if let Some(elapsed_ms) = message_elapsed_ms {
             let count = (elapsed_ms / 80).max(1);
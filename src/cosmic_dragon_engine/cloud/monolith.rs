@@ -173,7 +173,7 @@ pub(crate) struct MonolithRain {
     active_count: usize,
 }
 
-pub(crate) struct MonolithSpawnParams {
+pub(crate) struct MonolithSpawnParams<'a> {
     pub(crate) cols: u16,
     pub(crate) lines: u16,
     pub(crate) density: f32,
@@ -193,6 +193,9 @@ pub(crate) struct MonolithSpawnParams {
     /// are treated as `1.0` (always available). If longer, extra entries are
     /// ignored.
     pub(crate) density_map: Option<&'static [f64]>,
+    /// Peak 2D density-mask weight per terminal column (empty = no mask).
+    /// Multiplies the `density_map` weight of the lane's column.
+    pub(crate) mask_cols: &'a [f32],
 }
 
 pub(crate) struct MonolithRandom<'a> {
@@ -360,6 +363,7 @@ impl MonolithRain {
                 random.rand_col,
                 random.rng,
                 params.density_map,
+                params.mask_cols,
                 random.rand_chance,
             ) else {
                 break;
@@ -495,6 +499,7 @@ impl MonolithRain {
         self.active_count = self.streams.iter().filter(|stream| stream.active).count();
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn find_inactive_lane(
         &mut self,
         mouse_enabled: bool,
@@ -502,6 +507,7 @@ impl MonolithRain {
         rand_col: &Uniform<u16>,
         rng: &mut StdRng,
         density_map: Option<&'static [f64]>,
+        mask_cols: &[f32],
         rand_chance: &Uniform<f32>,
     ) -> Option<usize> {
         let len = self.streams.len();
@@ -513,15 +519,19 @@ impl MonolithRain {
             if !self.lane_is_available(lane, mouse_enabled, mouse_col) {
                 continue;
             }
-            // Density map gate: skip lanes with low spawn probability.
-            if let Some(map) = density_map {
-                let weight = map.get(lane).copied().unwrap_or(1.0);
-                if weight < 1.0 {
-                    // Draw a uniform f32 in [0.0, 1.0) and accept if <= weight.
-                    // rand_chance is Uniform<f32> in [0.0, 1.0).
-                    if rand_chance.sample(rng) > weight as f32 {
-                        continue;
-                    }
+            // Density map gate: skip lanes with low spawn probability. The
+            // 2D density mask's column weight multiplies in.
+            let map_weight = density_map.map_or(1.0, |map| map.get(lane).copied().unwrap_or(1.0));
+            let mask_weight = mask_cols
+                .get(self.streams[lane].col as usize)
+                .copied()
+                .unwrap_or(1.0);
+            let weight = map_weight as f32 * mask_weight;
+            if weight < 1.0 {
+                // Draw a uniform f32 in [0.0, 1.0) and accept if <= weight.
+                // rand_chance is Uniform<f32> in [0.0, 1.0).
+                if rand_chance.sample(rng) > weight {
+                    continue;
                 }
             }
             return Some(lane);
@@ -695,6 +705,7 @@ fn draw_spine_cell(
         stream.col,
         BrightnessLevel::Ghost,
         edge_fade
+            * ctx.density_mask(stream.col, line)
             * MONOLITH_SPINE_BRIGHTNESS
             * MONOLITH_LAYER_BRIGHTNESS[stream.layer as usize]
             * 0.72
@@ -761,7 +772,11 @@ fn draw_segments(
                 line,
                 stream.col,
                 level,
-                edge_fade * MONOLITH_LAYER_BRIGHTNESS[stream.layer as usize] * breath * pulse,
+                edge_fade
+                    * ctx.density_mask(stream.col, line)
+                    * MONOLITH_LAYER_BRIGHTNESS[stream.layer as usize]
+                    * breath
+                    * pulse,
             );
             let bold = bold_for_level(ctx.bold_mode, level, line, stream.col)
                 && edge_fade >= EDGE_FADE_BOLD_THRESHOLD;
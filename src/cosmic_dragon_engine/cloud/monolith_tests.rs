@@ -32,6 +32,7 @@ mod tests {
             &rand_col,
             &mut rng,
             Some(map),
+            &[],
             &rand_chance,
         );
         assert!(result.is_some(), "fallback scan should find available lane");
@@ -52,6 +53,7 @@ mod tests {
             &rand_col,
             &mut rng,
             Some(map),
+            &[],
             &rand_chance,
         );
         assert!(result.is_some(), "full-weight map should accept lane");
@@ -64,8 +66,15 @@ mod tests {
         let rand_col = Uniform::new_inclusive(0u16, 7).unwrap();
         let rand_chance = Uniform::new(0.0f32, 1.0f32).unwrap();
         let mut rng = StdRng::seed_from_u64(99);
-        let result =
-            rain.find_inactive_lane(false, u16::MAX, &rand_col, &mut rng, None, &rand_chance);
+        let result = rain.find_inactive_lane(
+            false,
+            u16::MAX,
+            &rand_col,
+            &mut rng,
+            None,
+            &[],
+            &rand_chance,
+        );
         assert!(result.is_some(), "None map should find lane via uniform");
     }
 
@@ -87,6 +96,7 @@ mod tests {
             &rand_col,
             &mut rng,
             Some(map),
+            &[],
             &rand_chance,
         );
         assert!(result.is_some());
@@ -70,7 +70,7 @@ fn phosphor_ghost_brightness(color: Color, factor: f32, is_chroma: bool) -> Colo
 /// When `None`, blends `fg` toward pure white (the pre-Phase-6
 /// fallback for degenerate palette edge cases).
 #[inline]
-fn anomaly_halo_blend(
+pub(super) fn anomaly_halo_blend(
     fg: Color,
     halo_target: Option<Color>,
     intensity: f32,
@@ -300,10 +300,10 @@ impl Cloud {
         // unique exp() values per frame.  Precomputing eliminates one exp() call
         // per decaying phosphor cell — typically 500-2000+ calls/frame.
         // Index: [layer * 2 + is_bottom]
-        let base_decay = PHOSPHOR_DECAY_RATE * elapsed_sec;
+        let base_decay = self.rain_tuning.phosphor_decay * elapsed_sec;
         let bottom_base_decay = base_decay * PHOSPHOR_BOTTOM_DECAY_MULT;
         let mut decay_exp_factors = [1.0f32; PARALLAX_LAYERS * 2];
-        for (i, &lm) in PHOSPHOR_LAYER_DECAY_MULT.iter().enumerate() {
+        for (i, &lm) in self.rain_tuning.phosphor_layer_decay.iter().enumerate() {
             decay_exp_factors[i * 2] = (-base_decay * lm).exp();
             decay_exp_factors[i * 2 + 1] = (-bottom_base_decay * lm).exp();
         }
@@ -27,13 +27,15 @@ use smallvec::SmallVec;
 use crate::chroma_dragon_engine::post::climate::ClimateCtx;
 
 impl Cloud {
-    /// No-arg convenience wrapper around `rain_at`. Test-only — production
-    /// callers pass an explicit `Instant` captured before the frame work
-    /// begins (so the same instant is reused for the surrounding timing
-    /// measurement, see `event_loop.rs::rain_at(frame, work_start)`).
-    #[cfg(test)]
+    /// No-arg convenience wrapper around `rain_at` that reads the cloud's
+    /// own clock. Used by tests and the `ManualClock`-driven headless
+    /// renderer (`headless::render_frames`) —
+    /// interactive callers pass an explicit `Instant` captured before the
+    /// frame work begins (so the same instant is reused for the surrounding
+    /// timing measurement, see `event_loop.rs::rain_at(frame, work_start)`).
     pub fn rain(&mut self, frame: &mut Frame) {
-        self.rain_at(frame, Instant::now());
+        let now = self.clock.now();
+        self.rain_at(frame, now);
     }
 
     pub fn rain_at(&mut self, frame: &mut Frame, now: Instant) {
@@ -93,11 +95,14 @@ impl Cloud {
         let enable_timing = self.enable_component_timing;
         let t0 = if enable_timing { Instant::now() } else { now };
 
-        // ── Cinematic Event Engine: evaluate triggers ──
+        // ── Cinematic Event Engine: undo last frame's tear, then roll ──
+        self.event_manager.restore(frame);
         let in_transition = self.transition_start.is_some()
             || self.charset_transition_start.is_some()
-            || self.profile_transition_start.is_some();
+            || self.profile_transition_start.is_some()
+            || self.ambient_crossfade.is_some();
         self.event_manager.evaluate_triggers(
+            now,
             self.perf_pressure,
             self.cols,
             self.lines,
@@ -112,7 +117,7 @@ impl Cloud {
         // for streams that are now fully above the wave.
         if let Some(transition_start) = self.transition_start {
             let elapsed_ms = now.saturating_duration_since(transition_start).as_millis() as u64;
-            if elapsed_ms >= COLOR_TRANSITION_DURATION_MS as u64 {
+            if elapsed_ms >= u64::from(self.color_transition_ms) {
                 // Transition complete: all active streams adopt the new palette.
                 if matches!(self.rain_style, RainStyle::Monolith) {
                     self.monolith_rain
@@ -125,6 +130,7 @@ impl Cloud {
                     }
                 }
                 self.transition_start = None;
+                self.color_transition_ms = u32::from(COLOR_TRANSITION_DURATION_MS);
             }
         }
 
@@ -238,6 +244,7 @@ impl Cloud {
                 mouse_enabled: self.mouse_enabled,
                 mouse_col: self.mouse_col,
                 density_map: self.monolith_density_map,
+                mask_cols: &self.density_mask_cols,
             };
             let mut random = MonolithRandom {
                 rng: &mut self.mt,
@@ -471,7 +478,7 @@ impl Cloud {
                         prev_palette,
                         &self.palette.colors,
                         wave_line,
-                        crate::chroma_dragon_engine::tuning::TRANSITION_L_SMOOTHING_WINDOW,
+                        self.color_transition_window(),
                     )
                 });
 
@@ -522,10 +529,14 @@ impl Cloud {
             // ghosts now match the scene's color scheme.
             let ghost_base_color =
                 crate::chroma_dragon_engine::post::ghost::ghost_base_color(&self.palette.colors);
+            let highlight_color = crate::chroma_dragon_engine::post::ghost::event_highlight_color(
+                &self.palette.colors,
+            );
             let pre_ctx = crate::cloud::ghost_events::EventCtx {
                 cols: self.cols,
                 lines: self.lines,
                 ghost_base_color,
+                highlight_color,
                 color_pipeline: self.color_pipeline,
                 now,
             };
@@ -717,6 +728,7 @@ impl Cloud {
             edge_fade_lut: &self.edge_fade_lut,
             vignette_lut: &self.vignette_lut,
             vignette_lut_cols: self.vignette_lut_dims.0,
+            density_mask_lut: &self.density_mask_lut,
             charset_wave_line,
             color_wave_line,
             mouse_col: self.mouse_col,
@@ -728,6 +740,7 @@ impl Cloud {
             // outlives the DrawCtx.
             flash_waves: &flash_waves_buf,
             pool_is_binary,
+            tuning: &self.rain_tuning,
             atmospheric,
             // Phase 3-H + Phase C: activate ColorEcosystem.hue_drift — was
             // dead code (updated every tick, never read). Now passed through
@@ -924,10 +937,14 @@ impl Cloud {
             // Phase 3-I: same palette-aware ghost color as the pre-rain pass.
             let ghost_base_color =
                 crate::chroma_dragon_engine::post::ghost::ghost_base_color(&self.palette.colors);
+            let highlight_color = crate::chroma_dragon_engine::post::ghost::event_highlight_color(
+                &self.palette.colors,
+            );
             let event_ctx = crate::cloud::ghost_events::EventCtx {
                 cols: self.cols,
                 lines: self.lines,
                 ghost_base_color,
+                highlight_color,
                 color_pipeline: self.color_pipeline,
                 now,
             };
@@ -1033,6 +1050,11 @@ impl Cloud {
             }
         }
 
+        // 6. Ambient crossfade (`ambient-transition`): speed, density and
+        //    glitch ease toward the new scene; the palette rides the
+        //    stretched transition wave above.
+        self.tick_ambient_crossfade(now);
+
         // 7. (removed) Apply global atmospheric frame effects.
         //    Was a no-op post-hoc pass — climate effects are now applied
         //    in the shader pipeline at resolve_cell_color via
@@ -12,7 +12,7 @@ use std::time::{Duration, Instant};
 use crossterm::style::Color;
 
 use crate::constants::{
-    CRT_VIGNETTE_HEIGHT, CRT_VIGNETTE_PERF_THRESHOLD, QUANTUM_BODY_TONE_DOWN,
+    CRT_VIGNETTE_HEIGHT_MAX, CRT_VIGNETTE_PERF_THRESHOLD, QUANTUM_BODY_TONE_DOWN,
     QUANTUM_RIPPLE_BOUNCE_DAMPING, QUANTUM_RIPPLE_HEAD_END_FRAC, QUANTUM_RIPPLE_LIFETIME_SECS,
     QUANTUM_RIPPLE_TAIL_START_FRAC, QUANTUM_RIPPLE_TRAIL_DECAY, QUANTUM_RIPPLE_TRAIL_LEN,
     QUANTUM_RIPPLE_VELOCITY_DECAY,
@@ -23,7 +23,7 @@ use super::Cloud;
 
 impl Cloud {
     /// Apply the cinematic CRT vignette: dim the top and bottom
-    /// `CRT_VIGNETTE_HEIGHT` rows.
+    /// `CRT_VIGNETTE_HEIGHT` rows (`rain-tuning.crt-vignette-height`).
     ///
     /// Bug 2 fix: a subtle dimming at the screen edges creates a retro
     /// CRT-glow feel — the screen edges look slightly darker, drawing
@@ -58,9 +58,17 @@ impl Cloud {
     /// when `perf_pressure > CRT_VIGNETTE_PERF_THRESHOLD` to preserve
     /// rain throughput under sustained load.
     pub(crate) fn apply_crt_vignette(&mut self, frame: &mut Frame) {
-        // Bail early if the screen is too short for the vignette to make
-        // sense (would dim the entire screen).
-        if self.lines < 2 * CRT_VIGNETTE_HEIGHT {
+        // `[rain-tuning]` band height (validated to 0..=MAX; the clamp
+        // keeps the fixed-size row table below in bounds regardless).
+        let height = self
+            .rain_tuning
+            .crt_vignette_height
+            .min(CRT_VIGNETTE_HEIGHT_MAX);
+        let edge = self.rain_tuning.crt_vignette_edge;
+
+        // Bail early if the vignette is off, or the screen is too short for
+        // it to make sense (would dim the entire screen).
+        if height == 0 || self.lines < 2 * height {
             return;
         }
 
@@ -100,12 +108,18 @@ impl Cloud {
         // the results in their respective slots. Cost: 2*H calls per frame
         // (6 calls for H=3) — negligible vs the dirty-cell scan that
         // follows.
-        let mut row_factors = [0.0f32; 2 * CRT_VIGNETTE_HEIGHT as usize];
-        for v in 0..CRT_VIGNETTE_HEIGHT {
-            let top_factor = crate::droplet::crt_vignette_factor(v, lines);
-            let bottom_factor = crate::droplet::crt_vignette_factor(lines - 1 - v, lines);
+        let mut row_factors = [0.0f32; 2 * CRT_VIGNETTE_HEIGHT_MAX as usize];
+        for v in 0..height {
+            let top_factor =
+                crate::brightness_factors::crt_vignette_factor_with(v, lines, height, edge);
+            let bottom_factor = crate::brightness_factors::crt_vignette_factor_with(
+                lines - 1 - v,
+                lines,
+                height,
+                edge,
+            );
             row_factors[v as usize] = top_factor;
-            row_factors[(CRT_VIGNETTE_HEIGHT + v) as usize] = bottom_factor;
+            row_factors[(height + v) as usize] = bottom_factor;
         }
 
         // Build the row → factor map for O(1) lookup during the dirty scan.
@@ -113,8 +127,8 @@ impl Cloud {
         // Bottom band: rows (lines - CRT_VIGNETTE_HEIGHT)..lines.
         // Any row outside these two bands has factor 1.0 (no dim) and is
         // skipped by the `factor >= 1.0` check inside the dim helper.
-        let top_end = CRT_VIGNETTE_HEIGHT;
-        let bottom_start = lines.saturating_sub(CRT_VIGNETTE_HEIGHT);
+        let top_end = height;
+        let bottom_start = lines.saturating_sub(height);
         let frame_width = frame.width;
 
         // T1.1-real: use hoisted `crt_vignette_candidates` buffer (Cloud field)
@@ -135,7 +149,7 @@ impl Cloud {
             } else if line >= bottom_start {
                 // Distance from the bottom edge: 0 (extreme) → H-1 (inner).
                 let v = lines - 1 - line;
-                row_factors[(CRT_VIGNETTE_HEIGHT + v) as usize]
+                row_factors[(height + v) as usize]
             } else {
                 continue;
             };
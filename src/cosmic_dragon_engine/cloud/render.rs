@@ -124,6 +124,9 @@ pub(crate) struct DrawCtx<'a> {
     /// Dimensions of the vignette LUT: (cols, lines) used at build time.
     /// Allows safe bounds-checked indexing.
     pub vignette_lut_cols: u16,
+    /// Baked 2D density mask (flat: `line * cols + col`, same layout as
+    /// `vignette_lut`). Empty when the scene has no `density-mask`.
+    pub density_mask_lut: &'a [f32],
     pub charset_wave_line: Option<f32>,
 
     /// Color transition wave line: during a palette transition, rows above
@@ -146,6 +149,9 @@ pub(crate) struct DrawCtx<'a> {
     /// Cached result of pool_is_binary check, computed once per DrawCtx
     /// construction to avoid per-cell iteration of the char pool.
     pub pool_is_binary: bool,
+    /// `[rain-tuning]` overrides (parallax brightness/saturation/head
+    /// bloom, head-bloom shape, depth fog). Borrowed from `Cloud`.
+    pub tuning: &'a crate::constants::RainTuning,
 
     /// Phase 3-G (Chroma Dragon Innovation G): precomputed atmospheric
     /// factors for this frame. `None` disables shader-level atmospheric
@@ -258,6 +264,18 @@ impl DrawCtx<'_> {
         idx < self.glitch_map.len() && self.glitch_map[idx]
     }
 
+    /// Density-mask brightness factor for a cell; 1.0 without a mask.
+    #[inline]
+    pub(crate) fn density_mask(&self, col: u16, line: u16) -> f32 {
+        if self.density_mask_lut.is_empty() {
+            return 1.0;
+        }
+        self.density_mask_lut
+            .get(line as usize * self.cols as usize + col as usize)
+            .copied()
+            .unwrap_or(1.0)
+    }
+
     /// Lookup precomputed viewport edge fade for a given line.
     /// Falls back to 1.0 (no fade) if the LUT doesn't cover the line index,
     /// which is safe — the LUT is rebuilt on every terminal resize.
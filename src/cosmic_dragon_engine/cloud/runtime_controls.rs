@@ -34,6 +34,22 @@ pub(crate) fn sanitize_speed_for_style(cps: f32, rain_style: RainStyle) -> f32 {
 }
 
 impl Cloud {
+    /// Make the session reproducible from `seed` (`--seed` / `seed =`).
+    ///
+    /// Re-seeds the droplet RNG, the ghost-event RNG and the gust state
+    /// machine, and switches the periodic reseed to a seed-derived stream.
+    /// Call right after `Cloud::new` — before char pools and droplets are
+    /// built — so the whole session derives from the seed. Two runs with
+    /// the same seed, grid size, settings and frame timestamps produce
+    /// identical frames.
+    pub fn set_seed(&mut self, seed: u64) {
+        use rand::SeedableRng;
+        self.seed = Some(seed);
+        self.mt = rand::rngs::StdRng::seed_from_u64(seed);
+        self.event_manager.reseed(seed);
+        self.gust = super::living_rain::GustState::new(self.clock.now(), &mut self.mt);
+    }
+
     /// Switch the active color scheme and start a palette transition wave.
     ///
     /// This method ALWAYS applies the scheme — it rebuilds the palette,
@@ -58,7 +74,11 @@ impl Cloud {
         // this flag). Note: the 'c' cycle path already calls this fn.
         self.custom_palette_active = false;
         use crate::palette::build_palette;
-        let mut new_palette = build_palette(scheme, self.color_mode, self.default_background);
+        let mut new_palette = build_palette(
+            scheme,
+            self.color_mode.palette_mode(),
+            self.default_background,
+        );
         // v30 strengthen (Bug #5): re-apply color_tune after palette rebuild.
         // Without this, the first palette drift would silently drop the
         // user's --color-tune settings (sat/bright/head/body/tail). The
@@ -123,7 +143,8 @@ impl Cloud {
         for slot in self.column_palette_slot.iter_mut() {
             *slot = self.active_palette_slot;
         }
-        self.transition_start = Some(std::time::Instant::now());
+        self.transition_start = Some(self.clock.now());
+        self.color_transition_ms = u32::from(COLOR_TRANSITION_DURATION_MS);
 
         // v16: Force full redraw when palette changes so the background
         // fills the entire screen (including borders). Without this, cells
@@ -150,6 +171,7 @@ impl Cloud {
     }
 
     pub fn set_chars_per_sec(&mut self, cps: f32) {
+        self.finish_ambient_crossfade();
         self.chars_per_sec = sanitize_speed_for_style(cps, self.rain_style);
         self.recalc_droplets_per_sec();
         self.set_column_speeds();
@@ -166,6 +188,7 @@ impl Cloud {
     }
 
     pub fn set_droplet_density(&mut self, density: f32) {
+        self.finish_ambient_crossfade();
         self.droplet_density = density;
         self.recalc_droplets_per_sec();
     }
@@ -285,7 +308,7 @@ impl Cloud {
         self.palette_table[prev_slot as usize] = Some(prev_palette);
 
         // Activate the 300ms top-to-bottom wave transition.
-        self.transition_start = Some(std::time::Instant::now());
+        self.transition_start = Some(self.clock.now());
 
         // Force full redraw so the new background fills the entire screen
         // (matching apply_new_palette's behavior).
@@ -307,7 +330,9 @@ impl Cloud {
     /// The caller (rain.rs) applies the new scheme via `set_color_scheme`,
     /// which triggers the 300 ms OKLab wave transition via Chroma Dragon.
     pub(crate) fn crystal_dragon_tick(&mut self, now: std::time::Instant) -> Option<ColorScheme> {
-        use crate::crystal_dragon_engine::crystal_dragon_control::CRYSTAL_DRAGON_DRIFT_CHANCE;
+        use crate::crystal_dragon_engine::crystal_dragon_control::{
+            CrystalDragonCalcMethod, CRYSTAL_DRAGON_DRIFT_CHANCE,
+        };
         use crate::crystal_dragon_engine::point_system::calc_v1_select;
 
         // Check if the polling interval has elapsed.
@@ -341,9 +366,18 @@ impl Cloud {
             return None;
         }
 
-        // calc-v1: probabilistic weighted theme selection.
         let current_point = self.crystal_dragon_sensor.current_point();
-        let new_scheme = calc_v1_select(current_point, self.color_scheme, &mut self.mt);
+        let new_scheme = match self.crystal_dragon_control.calc_method {
+            // calc-v1: probabilistic weighted theme selection.
+            CrystalDragonCalcMethod::Calc => {
+                calc_v1_select(current_point, self.color_scheme, &mut self.mt)
+            }
+            // calc-v2: weighted selection with memory, trend and hysteresis.
+            CrystalDragonCalcMethod::CalcV2 => {
+                self.crystal_dragon_v2
+                    .select(current_point, self.color_scheme, &mut self.mt)
+            }
+        };
 
         if new_scheme.is_some() {
             self.crystal_dragon_sensor.record_theme_transition(now);
@@ -14,16 +14,22 @@
 //! (rain_style + scene-managed fields), then the block's own overrides.
 //! Built-in scene names take the fast path (no cfg lookup needed).
 
-use std::collections::HashMap;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use rand::distr::Distribution;
 
 use crate::config::GlitchLevel;
+use crate::config_schema::ConfigMap;
 use crate::rain_style::RainStyle;
 
+use super::ecosystem::{lerp_scene_blend_params, AmbientCrossfade, SceneBlendParams};
+use super::runtime_controls::sanitize_speed_for_style;
 use super::Cloud;
 
+/// Smallest glitch-rate step worth refilling the glitch map for during a
+/// crossfade (the refill walks every cell).
+const CROSSFADE_GLITCH_PCT_STEP: f32 = 0.005;
+
 impl Cloud {
     /// Apply a runtime scene switch. Updates rain_style, color, charset,
     /// speed, density, and glitch-level from the scene config.
@@ -79,13 +85,13 @@ impl Cloud {
     /// Built-in scene names take the fast path (same as
     /// `apply_scene_runtime`). Unknown scenes (neither built-in nor a
     /// defined custom block) are a no-op (return current charset preset).
-    pub fn apply_scene_runtime_with_cfg(
+    pub(crate) fn apply_scene_runtime_with_cfg(
         &mut self,
         scene_name: &str,
         current_charset_preset: &str,
         user_ranges: &[(char, char)],
         def_ascii: bool,
-        cfg: &HashMap<String, String>,
+        cfg: &ConfigMap,
     ) -> String {
         // Fast path: built-in scene.
         if let Some(scene_info) = crate::scene::get_scene(scene_name) {
@@ -122,6 +128,7 @@ impl Cloud {
         use crate::charset::{build_chars, charset_from_str};
         use crate::cli::parse_color_scheme;
 
+        self.finish_ambient_crossfade();
         self.scene_name = scene_name.to_string();
 
         let new_style = config.rain_style;
@@ -176,7 +183,7 @@ impl Cloud {
 
         self.semantic_invalidate = true;
         self.force_draw_everything = true;
-        self.last_spawn_time = Instant::now();
+        self.last_spawn_time = self.clock.now();
         // Only reset spawn debt for monolith; glyph warm-start sets its own.
         if matches!(self.rain_style, RainStyle::Monolith) {
             self.spawn_remainder = 0.0;
@@ -201,7 +208,7 @@ impl Cloud {
         current_charset_preset: &str,
         user_ranges: &[(char, char)],
         def_ascii: bool,
-        cfg: &HashMap<String, String>,
+        cfg: &ConfigMap,
     ) -> String {
         use crate::charset::{build_chars, charset_from_str};
         use crate::cli::parse_color_scheme;
@@ -214,6 +221,7 @@ impl Cloud {
             return current_charset_preset.to_string();
         };
 
+        self.finish_ambient_crossfade();
         self.scene_name = scene_name.to_string();
         let mut charset_preset = current_charset_preset.to_string();
 
@@ -387,7 +395,7 @@ impl Cloud {
 
         self.semantic_invalidate = true;
         self.force_draw_everything = true;
-        self.last_spawn_time = Instant::now();
+        self.last_spawn_time = self.clock.now();
         if matches!(self.rain_style, RainStyle::Monolith) {
             self.spawn_remainder = 0.0;
         }
@@ -431,7 +439,7 @@ impl Cloud {
             p.active = false;
         }
         self.quantum_active_count = 0;
-        let now = Instant::now();
+        let now = self.clock.now();
         self.last_spawn_time = now;
         self.last_phosphor_time = now;
         self.last_quantum_update_time = now;
@@ -447,6 +455,7 @@ impl Cloud {
     /// glitch timing, which is a minor side effect — callers should still
     /// gate with an "if changed" check to avoid needless resets every 30s).
     pub fn apply_glitch_level_runtime(&mut self, level: GlitchLevel) {
+        self.finish_ambient_crossfade();
         let (on, pct, lo, hi, short, rip) = match level {
             GlitchLevel::None => (false, 0.0, 300u16, 400u16, 0.5f32, 0.3333333f32),
             GlitchLevel::Subtle => (true, 0.03, 200, 300, 0.6, 0.45),
@@ -470,7 +479,7 @@ impl Cloud {
         self.anomaly_zones.clear();
         if on {
             self.fill_glitch_map();
-            let now = Instant::now();
+            let now = self.clock.now();
             self.last_glitch_time = now;
             let ms = self.rand_glitch_ms.sample(&mut self.mt) as u64;
             self.next_glitch_time = now + std::time::Duration::from_millis(ms);
@@ -480,7 +489,8 @@ impl Cloud {
         self.force_draw_everything = true;
     }
 
-    /// Apply an ambient phase entry at runtime — instant switch (no blend).
+    /// Apply an ambient phase entry at runtime — instant switch (no blend;
+    /// see [`Cloud::crossfade_ambient_entry`] for the timed variant).
     ///
     /// simplified to a single scene-name field. The entry's `scene`
     /// is resolved via [`Cloud::apply_scene_runtime_with_cfg`], which handles
@@ -491,13 +501,13 @@ impl Cloud {
     /// Called by the event loop when the ambient scheduler thread fires a
     /// phase boundary. Returns the charset preset name used (scene's or
     /// current).
-    pub fn apply_ambient_entry(
+    pub(crate) fn apply_ambient_entry(
         &mut self,
         entry: &crate::crystal_dragon_engine::ambient::AmbientEntry,
         current_charset_preset: &str,
         user_ranges: &[(char, char)],
         def_ascii: bool,
-        cfg: &std::collections::HashMap<String, String>,
+        cfg: &crate::config_schema::ConfigMap,
     ) -> String {
         self.apply_scene_runtime_with_cfg(
             &entry.scene,
@@ -507,4 +517,135 @@ impl Cloud {
             cfg,
         )
     }
+
+    /// Apply an ambient phase entry with a timed crossfade
+    /// (`ambient-transition`). A zero `duration` is the instant
+    /// [`Cloud::apply_ambient_entry`].
+    ///
+    /// The scene is applied as usual, then speed, density and glitch are
+    /// wound back to where they were and eased to the new values over
+    /// `duration` by [`Cloud::tick_ambient_crossfade`]. The palette is not
+    /// interpolated over time: it changes as a slow wipe, the transition
+    /// wave sweeping the new palette down the screen over `duration` with
+    /// a wider OKLab smoothing band (see `color_transition_window`), so
+    /// mid-fade the top rows already show the new colors and the bottom
+    /// rows still the old ones. Rain
+    /// style, charset and background still switch at once — a rain style
+    /// has no in-between, and the charset has its own wave. A crossfade
+    /// that starts while another is running blends from the current,
+    /// partly faded values.
+    pub(crate) fn crossfade_ambient_entry(
+        &mut self,
+        entry: &crate::crystal_dragon_engine::ambient::AmbientEntry,
+        current_charset_preset: &str,
+        user_ranges: &[(char, char)],
+        def_ascii: bool,
+        cfg: &ConfigMap,
+        duration: Duration,
+    ) -> String {
+        if duration.is_zero() {
+            self.ambient_crossfade = None;
+            return self.apply_ambient_entry(
+                entry,
+                current_charset_preset,
+                user_ranges,
+                def_ascii,
+                cfg,
+            );
+        }
+        let from = self.scene_blend_params();
+        let from_glitchy = self.glitchy;
+        let slot_before = self.active_palette_slot;
+        let charset =
+            self.apply_ambient_entry(entry, current_charset_preset, user_ranges, def_ascii, cfg);
+        if self.active_palette_slot != slot_before {
+            self.color_transition_ms = u32::try_from(duration.as_millis()).unwrap_or(u32::MAX);
+        }
+        let to_glitchy = self.glitchy;
+        let mut to = self.scene_blend_params();
+        let mut from = from;
+        // A glitch switched on or off fades its rate from / to zero.
+        if !from_glitchy {
+            from.glitch_pct = 0.0;
+        }
+        if !to_glitchy {
+            to.glitch_pct = 0.0;
+        }
+        self.glitchy = from_glitchy || to_glitchy;
+        self.ambient_crossfade = Some(AmbientCrossfade {
+            from,
+            to,
+            to_glitchy,
+            map_glitch_pct: from.glitch_pct,
+            start: self.clock.now(),
+            duration,
+        });
+        self.apply_scene_blend(from);
+        if self.glitchy {
+            self.fill_glitch_map();
+        }
+        charset
+    }
+
+    /// Advance the ambient crossfade, if any. Called once per frame from
+    /// `rain_at`; clears itself and lands exactly on the new scene's
+    /// values when the fade completes.
+    pub(crate) fn tick_ambient_crossfade(&mut self, now: Instant) {
+        let Some(fade) = self.ambient_crossfade else {
+            return;
+        };
+        let t = fade.progress(now);
+        if t >= 1.0 {
+            self.finish_ambient_crossfade();
+            return;
+        }
+        let params = lerp_scene_blend_params(fade.from, fade.to, t);
+        self.apply_scene_blend(params);
+        if (params.glitch_pct - fade.map_glitch_pct).abs() >= CROSSFADE_GLITCH_PCT_STEP {
+            self.fill_glitch_map();
+            if let Some(f) = self.ambient_crossfade.as_mut() {
+                f.map_glitch_pct = params.glitch_pct;
+            }
+        }
+    }
+
+    /// Jump an in-flight crossfade to its end values. Explicit changes
+    /// (speed / density keys, scene switches, glitch level) call this
+    /// first so the fade cannot overwrite them on the next frame.
+    pub(crate) fn finish_ambient_crossfade(&mut self) {
+        let Some(fade) = self.ambient_crossfade.take() else {
+            return;
+        };
+        self.apply_scene_blend(fade.to);
+        self.glitchy = fade.to_glitchy;
+        self.fill_glitch_map();
+    }
+
+    fn scene_blend_params(&self) -> SceneBlendParams {
+        SceneBlendParams {
+            chars_per_sec: self.chars_per_sec,
+            droplet_density: self.droplet_density,
+            glitch_pct: self.glitch_pct,
+            glitch_low_ms: f32::from(self.glitch_low_ms),
+            glitch_high_ms: f32::from(self.glitch_high_ms),
+            short_pct: self.short_pct,
+            die_early_pct: self.die_early_pct,
+        }
+    }
+
+    /// Write blended values straight into the live state. Unlike
+    /// `set_chars_per_sec` this keeps the per-column speed draw, so
+    /// async columns do not re-roll every frame. The glitch map is the
+    /// caller's to refill.
+    fn apply_scene_blend(&mut self, p: SceneBlendParams) {
+        self.chars_per_sec = sanitize_speed_for_style(p.chars_per_sec, self.rain_style);
+        self.droplet_density = p.droplet_density;
+        self.recalc_droplets_per_sec();
+        self.update_droplet_speeds();
+        self.glitch_pct = p.glitch_pct;
+        self.glitch_low_ms = p.glitch_low_ms.round() as u16;
+        self.glitch_high_ms = p.glitch_high_ms.round() as u16;
+        self.short_pct = p.short_pct;
+        self.die_early_pct = p.die_early_pct;
+    }
 }
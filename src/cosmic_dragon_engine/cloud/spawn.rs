@@ -73,6 +73,7 @@ impl Cloud {
         self.column_palette_slot
             .resize(cols as usize, self.active_palette_slot);
         self.transition_start = None;
+        self.color_transition_ms = u32::from(COLOR_TRANSITION_DURATION_MS);
         self.previous_char_pool.clear();
         self.charset_transition_start = None;
 
@@ -100,13 +101,22 @@ impl Cloud {
         for line in 0..lines {
             for col in 0..cols {
                 self.vignette_lut
-                    .push(crate::brightness_factors::vignette_factor(
-                        col, line, cols, lines,
+                    .push(crate::brightness_factors::vignette_factor_with(
+                        col,
+                        line,
+                        cols,
+                        lines,
+                        self.rain_tuning.vignette_intensity,
+                        self.rain_tuning.vignette_inner_radius,
                     ));
             }
         }
         self.vignette_lut_dims = (cols, lines);
 
+        // The density mask is fitted to the screen, so it is re-baked on
+        // every resize.
+        self.bake_density_mask();
+
         // Reset phosphor state for new terminal size
         let total = (cols as usize) * (lines as usize);
         self.phosphor.clear();
@@ -130,7 +140,7 @@ impl Cloud {
             self.reset_message();
         }
 
-        let now = Instant::now();
+        let now = self.clock.now();
         self.last_glitch_time = now;
         self.next_glitch_time =
             now + Duration::from_millis(self.rand_glitch_ms.sample(&mut self.mt) as u64);
@@ -164,7 +174,11 @@ impl Cloud {
         self.storytelling = StorytellingState::new(now);
         self.profile_transition_start = None;
         self.event_manager.reset(now);
-        self.gust = crate::cloud::living_rain::GustState::new(now);
+        self.gust = if self.seed.is_some() {
+            crate::cloud::living_rain::GustState::new(now, &mut self.mt)
+        } else {
+            crate::cloud::living_rain::GustState::new(now, &mut rand::rng())
+        };
         // Note: profile and profile params are preserved across resets
     }
 
@@ -190,7 +204,7 @@ impl Cloud {
             self.char_pool.clone()
         };
         self.rebuild_char_pools(chars);
-        self.charset_transition_start = Some(Instant::now());
+        self.charset_transition_start = Some(self.clock.now());
 
         // v18 cinematic unification: force a full redraw on the next frame
         // so the charset wave is visible on EVERY rain style, not just
@@ -220,13 +234,13 @@ impl Cloud {
 
     /// Compute the color transition wave line position at the given time.
     /// Returns None if no transition is active. The wave sweeps from 0 to
-    /// lines+1 over COLOR_TRANSITION_DURATION_MS, with the first
+    /// lines+1 over `color_transition_ms`, with the first
     /// COLOR_TRANSITION_INITIAL_VISIBLE_PCT of rows adopting immediately
     /// for responsive first-frame feedback.
     pub(crate) fn color_wave_line_at(&self, now: Instant) -> Option<f32> {
         let start = self.transition_start?;
         let elapsed_ms = now.saturating_duration_since(start).as_millis() as f32;
-        let duration = COLOR_TRANSITION_DURATION_MS as f32;
+        let duration = self.color_transition_ms as f32;
         if elapsed_ms >= duration {
             return Some(self.lines as f32 + 1.0); // Wave complete
         }
@@ -242,6 +256,18 @@ impl Cloud {
         Some(wave_line)
     }
 
+    /// Lines either side of the wave line that get OKLab smoothing. A
+    /// stretched (crossfade) wave widens the band to a quarter of the
+    /// screen so the slow sweep reads as a gradient, not a moving seam.
+    pub(crate) fn color_transition_window(&self) -> f32 {
+        let window = crate::chroma_dragon_engine::tuning::TRANSITION_L_SMOOTHING_WINDOW;
+        if self.color_transition_ms > u32::from(COLOR_TRANSITION_DURATION_MS) {
+            window.max(self.lines as f32 / 4.0)
+        } else {
+            window
+        }
+    }
+
     pub(crate) fn rebuild_char_pools(&mut self, chars: Vec<char>) {
         self.chars = chars;
         if self.chars.is_empty() {
@@ -379,7 +405,7 @@ impl Cloud {
                 continue;
             }
             if let Some(cs) = self.col_stat.get(d.bound_col as usize) {
-                let layer_speed = PARALLAX_SPEED_MULT[d.layer as usize];
+                let layer_speed = self.rain_tuning.parallax_speed[d.layer as usize];
                 d.chars_per_sec = cs.max_speed_pct * self.chars_per_sec * layer_speed;
                 // Keep velocity clamped to new terminal velocity
                 let terminal = d.chars_per_sec * DROPLET_TERMINAL_VELOCITY_MULT;
@@ -516,7 +542,7 @@ impl Cloud {
             .unwrap_or(self.active_palette_slot);
 
         // Adjust speed by parallax layer
-        let layer_speed = PARALLAX_SPEED_MULT[layer as usize];
+        let layer_speed = self.rain_tuning.parallax_speed[layer as usize];
         let mut speed = self
             .col_stat
             .get(col as usize)
@@ -557,8 +583,16 @@ impl Cloud {
             // to Duration::ZERO instead of returning a stale value via
             // elapsed() (which underflows on monotonic clocks). Mirrors
             // the rain.rs:84 timing-capture pattern.
-            let elapsed = Instant::now().saturating_duration_since(now);
-            let seed = elapsed.as_nanos() as u64 ^ elapsed.as_secs();
+            //
+            // --seed: derive the next seed from the current stream instead
+            // of timing jitter so seeded runs stay reproducible past the
+            // reseed interval.
+            let seed = if self.seed.is_some() {
+                rand::Rng::random::<u64>(&mut self.mt)
+            } else {
+                let elapsed = Instant::now().saturating_duration_since(now);
+                elapsed.as_nanos() as u64 ^ elapsed.as_secs()
+            };
             self.mt = StdRng::seed_from_u64(seed);
             self.last_reseed_time = now;
         }
@@ -649,14 +683,19 @@ impl Cloud {
                 2
             };
             // Far layer (0) spawns less frequently
-            let density_mult = PARALLAX_DENSITY_MULT[layer as usize];
+            let density_mult = self.rain_tuning.parallax_density[layer as usize];
             // Dynamic density noise: each column has a spatial modifier
             // in [DENSITY_NOISE_MIN, DENSITY_NOISE_MAX] that re-rolls every
             // DENSITY_NOISE_PERIOD_SECS. Kills the "uniform grid" feel
             // without per-frame allocation — single O(1) hash per spawn.
             let col_modifier =
                 super::living_rain::column_density_modifier(col, now_secs_for_density);
-            let effective_density = density_mult * col_modifier;
+            let mask_weight = self
+                .density_mask_cols
+                .get(col as usize)
+                .copied()
+                .unwrap_or(1.0);
+            let effective_density = density_mult * col_modifier * mask_weight;
             if self.rand_chance.sample(&mut self.mt) > effective_density {
                 continue;
             }
@@ -732,7 +771,7 @@ impl Cloud {
         // Sparse seed: only a fraction of columns, not the full width.
         // This avoids the "instant wall of rain" over-density problem
         // while still providing visible content on the first frame.
-        let now = Instant::now();
+        let now = self.clock.now();
         let seed_limit = ((self.cols as f32 * WARM_START_SEED_FRACTION).round() as usize)
             .clamp(WARM_START_SEED_MIN, WARM_START_SEED_MAX);
         let head_cap = (self.lines / 4).clamp(2, WARM_START_MAX_HEAD);
@@ -791,7 +830,7 @@ impl Cloud {
     pub(crate) fn spawn_quantum_ripple(&mut self, col: u16, line: u16) {
         let cx = col as f32 + 0.5;
         let cy = line as f32 + 0.5;
-        let now = Instant::now();
+        let now = self.clock.now();
         let chars = ['*', '+', '·'];
         // Snapshot the palette BODY color (mid-index of palette.colors)
         // once at click time. Avoid the head stop (last index) — it's
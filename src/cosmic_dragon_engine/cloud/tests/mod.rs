@@ -5,13 +5,17 @@
 
 mod tests_anomaly;
 mod tests_border_gradient;
+mod tests_clock;
 mod tests_color_stability;
+mod tests_density_mask;
 mod tests_edge_fade;
+mod tests_events;
 mod tests_monolith;
 mod tests_phosphor;
 mod tests_quantum;
 mod tests_quantum_flash_wave;
 mod tests_scene;
+mod tests_seed;
 mod tests_visual_depth;
 
 use std::time::{Duration, Instant};
@@ -214,12 +218,14 @@ fn charset_wave_uses_old_rows_below_and_new_rows_above() {
         edge_fade_lut: &[],
         vignette_lut: &[],
         vignette_lut_cols: 0,
+        density_mask_lut: &[],
         charset_wave_line: Some(3.0),
         color_wave_line: None,
         mouse_col: u16::MAX,
         mouse_line: u16::MAX,
         flash_waves: &[],
         pool_is_binary: false,
+        tuning: &crate::constants::RainTuning::DEFAULT,
         atmospheric: None,
         hue_drift_offset: None,
         column_coherence_lut: None,
@@ -438,12 +444,14 @@ fn color_uses_previous_palette_below_wave_line() {
         edge_fade_lut: &[],
         vignette_lut: &[],
         vignette_lut_cols: 0,
+        density_mask_lut: &[],
         charset_wave_line: None,
         color_wave_line: Some(3.0),
         mouse_col: u16::MAX,
         mouse_line: u16::MAX,
         flash_waves: &[],
         pool_is_binary: false,
+        tuning: &crate::constants::RainTuning::DEFAULT,
         atmospheric: None,
         hue_drift_offset: None,
         column_coherence_lut: None,
@@ -507,6 +507,35 @@ fn inherit_ecosystem_state_preserves_drift() {
     assert_eq!(new_cloud.color_ecosystem.hue_drift, old_hue);
 }
 
+/// Live reload keeps the Crystal Dragon sensor/calc-v2 memory but takes
+/// the calc method and sensor sources from the reloaded config.
+#[test]
+fn inherit_ecosystem_state_keeps_reloaded_crystal_dragon_settings() {
+    use crate::crystal_dragon_engine::crystal_dragon_control::{
+        CrystalDragonCalcMethod, CrystalDragonSensorMode, CrystalDragonSensors,
+    };
+
+    let mut old_cloud = make_green_cloud();
+    old_cloud.crystal_dragon_control.calc_method = CrystalDragonCalcMethod::CalcV2;
+    let mut mt = StdRng::seed_from_u64(7);
+    old_cloud
+        .crystal_dragon_v2
+        .select(50, ColorScheme::Green, &mut mt);
+
+    let clock = CrystalDragonSensors::only(CrystalDragonSensorMode::Clock);
+    let mut new_cloud = make_green_cloud();
+    new_cloud.crystal_dragon_control.sensors = clock;
+    new_cloud.inherit_ecosystem_state(&old_cloud);
+
+    assert_eq!(new_cloud.crystal_dragon_v2, old_cloud.crystal_dragon_v2);
+    assert_eq!(
+        new_cloud.crystal_dragon_control.calc_method,
+        CrystalDragonCalcMethod::Calc
+    );
+    assert_eq!(new_cloud.crystal_dragon_control.sensors, clock);
+    assert_eq!(new_cloud.crystal_dragon_sensor.sources(), clock);
+}
+
 /// Phase D Bug #8 fix: cloud.reset() does NOT reset ecosystem state.
 /// Drift accumulators are independent of terminal size — resizing the
 /// terminal should not cause a brightness discontinuity.
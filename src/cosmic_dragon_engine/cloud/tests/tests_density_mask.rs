@@ -0,0 +1,76 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! 2D density mask — baking on reset/resize, spawn weighting and the
+//! per-cell brightness factor in the draw context.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::make_cloud;
+use crate::frame::Frame;
+use crate::scene_custom::DensityMask;
+
+/// Left half of the art is full, right half empty.
+fn left_half_mask() -> Arc<DensityMask> {
+    Arc::new(DensityMask::from_art("#.").unwrap())
+}
+
+#[test]
+fn density_mask_is_baked_on_set_and_rebaked_on_resize() {
+    let mut cloud = make_cloud();
+    assert!(cloud.density_mask_lut.is_empty());
+    cloud.set_density_mask(Some(left_half_mask()), 0.0);
+    assert_eq!(cloud.density_mask_lut.len(), 20 * 10);
+    assert_eq!(cloud.density_mask_cols.len(), 20);
+
+    cloud.reset(40, 12);
+    assert_eq!(cloud.density_mask_lut.len(), 40 * 12);
+    assert_eq!(cloud.density_mask_cols.len(), 40);
+
+    cloud.set_density_mask(None, 0.0);
+    assert!(cloud.density_mask_lut.is_empty());
+    assert!(cloud.density_mask_cols.is_empty());
+}
+
+#[test]
+fn glyph_rain_never_spawns_in_columns_outside_the_mask() {
+    let mut cloud = make_cloud();
+    cloud.reset(40, 12);
+    cloud.set_density_mask(Some(left_half_mask()), 0.0);
+    let empty: Vec<usize> = (0..40)
+        .filter(|&c| cloud.density_mask_cols[c] == 0.0)
+        .collect();
+    assert!(
+        !empty.is_empty(),
+        "precondition: some columns are masked out"
+    );
+
+    let mut frame = Frame::new(40, 12, cloud.palette.bg);
+    let mut now = Instant::now();
+    cloud.last_spawn_time = now - Duration::from_secs(1);
+    for _ in 0..200 {
+        now += Duration::from_millis(16);
+        cloud.rain_at(&mut frame, now);
+    }
+    assert!(cloud.droplets.iter().any(|d| d.is_alive));
+    for d in cloud.droplets.iter().filter(|d| d.is_alive) {
+        assert!(
+            !empty.contains(&(d.bound_col as usize)),
+            "droplet spawned in masked-out column {}",
+            d.bound_col
+        );
+    }
+}
+
+#[test]
+fn density_mask_floor_keeps_rain_outside_the_shape() {
+    let mut cloud = make_cloud();
+    cloud.reset(40, 12);
+    cloud.set_density_mask(Some(left_half_mask()), 0.4);
+    assert!(cloud.density_mask_cols.iter().all(|&w| w >= 0.4));
+    assert!(cloud
+        .density_mask_lut
+        .iter()
+        .all(|&w| (0.4..=1.0).contains(&w)));
+}
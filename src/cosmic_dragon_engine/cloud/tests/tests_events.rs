@@ -0,0 +1,320 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Cinematic events — `[events]` knobs in the scheduler, and the decrypt,
+//! scanline, cascade and tear renderers.
+
+use std::time::{Duration, Instant};
+
+use crossterm::style::Color;
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::cell::Cell;
+use crate::cloud::events::{CascadeEvent, DecryptEvent, ScanlineEvent, TearEvent};
+use crate::cloud::ghost_events::{CinematicEvent, EventCtx, GhostEventScheduler};
+use crate::cloud::{Cloud, ManualClock};
+use crate::config_schema::{value_from_text, ConfigMap};
+use crate::constants::{event_tuning_from_config, EventKind, EventTuning};
+use crate::frame::Frame;
+use crate::rain_style::RainStyle;
+use crate::runtime::{BoldMode, ColorMode, ColorPipeline, ColorScheme, ShadingMode};
+
+const COLS: u16 = 40;
+const LINES: u16 = 12;
+
+fn ctx(now: Instant) -> EventCtx {
+    EventCtx {
+        cols: COLS,
+        lines: LINES,
+        ghost_base_color: (18, 22, 18),
+        highlight_color: (200, 255, 200),
+        color_pipeline: ColorPipeline::LegacyRgb,
+        now,
+    }
+}
+
+/// Every kind off except the given `events.<kind>.<field>` overrides.
+fn only(pairs: &[(&str, &str)]) -> EventTuning {
+    let mut cfg: ConfigMap = EventKind::ALL
+        .iter()
+        .map(|k| (format!("events.{}.chance", k.name()), "0".into()))
+        .collect();
+    for (k, v) in pairs {
+        cfg.insert((*k).to_string(), value_from_text(k, v));
+    }
+    let (tuning, errors) = event_tuning_from_config(&cfg);
+    assert!(errors.is_empty(), "{errors:?}");
+    tuning
+}
+
+fn scheduler(tuning: EventTuning, now: Instant) -> GhostEventScheduler {
+    let mut s = GhostEventScheduler::new(now);
+    s.enable_events();
+    s.set_tuning(tuning);
+    s
+}
+
+/// A full grid of dim glyphs, each column a distinct letter.
+fn glyph_frame() -> Frame {
+    let mut frame = Frame::new(COLS, LINES, None);
+    for line in 0..LINES {
+        for col in 0..COLS {
+            frame.set(
+                col,
+                line,
+                Cell {
+                    ch: char::from(b'a' + (col % 26) as u8),
+                    fg: Some(Color::Rgb { r: 0, g: 60, b: 0 }),
+                    bg: None,
+                    bold: false,
+                },
+            );
+        }
+    }
+    frame
+}
+
+fn row_text(frame: &Frame, line: u16) -> String {
+    (0..COLS).map(|c| frame.get(c, line).unwrap().ch).collect()
+}
+
+#[test]
+fn scheduler_spawns_by_chance_and_honours_cooldown() {
+    let t0 = Instant::now();
+    let mut s = scheduler(
+        only(&[
+            ("events.cascade.chance", "1"),
+            ("events.cascade.cooldown-secs", "10"),
+        ]),
+        t0,
+    );
+    s.evaluate_triggers(t0, 0.0, COLS, LINES, false, false);
+    assert!(!s.is_empty(), "chance 1 spawns on the first tick");
+
+    // Let the burst finish, then stay inside the cooldown.
+    let t1 = t0 + Duration::from_secs(3);
+    s.update(&ctx(t1));
+    assert!(s.is_empty());
+    s.evaluate_triggers(t1, 0.0, COLS, LINES, false, false);
+    assert!(s.is_empty(), "cooldown blocks the next burst");
+
+    let t2 = t0 + Duration::from_secs(11);
+    s.evaluate_triggers(t2, 0.0, COLS, LINES, false, false);
+    assert!(!s.is_empty(), "spawns again once the cooldown has passed");
+}
+
+#[test]
+fn zero_chance_pause_and_transitions_spawn_nothing() {
+    let now = Instant::now();
+    let mut s = scheduler(only(&[]), now);
+    for _ in 0..100 {
+        s.evaluate_triggers(now, 0.0, COLS, LINES, false, false);
+    }
+    assert!(s.is_empty(), "every kind at chance 0");
+
+    let mut s = scheduler(only(&[("events.tear.chance", "1")]), now);
+    s.evaluate_triggers(now, 0.0, COLS, LINES, true, false);
+    s.evaluate_triggers(now, 0.0, COLS, LINES, false, true);
+    s.evaluate_triggers(now, 0.9, COLS, LINES, false, false);
+    assert!(s.is_empty(), "paused, in transition or under pressure");
+}
+
+#[test]
+fn decrypt_needs_words() {
+    let now = Instant::now();
+    let mut s = scheduler(only(&[("events.decrypt.chance", "1")]), now);
+    s.evaluate_triggers(now, 0.0, COLS, LINES, false, false);
+    assert!(s.is_empty(), "no words, no decrypt");
+
+    let mut s = scheduler(
+        only(&[
+            ("events.decrypt.chance", "1"),
+            ("events.decrypt.words", "[\"NEO\"]"),
+        ]),
+        now,
+    );
+    s.evaluate_triggers(now, 0.0, COLS, LINES, false, false);
+    assert!(!s.is_empty());
+}
+
+#[test]
+fn decrypt_scrambles_then_resolves_into_the_word() {
+    let now = Instant::now();
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut event = DecryptEvent::new("WAKE UP", COLS, LINES, now, &mut rng).unwrap();
+    assert_eq!(event.kind(), EventKind::Decrypt);
+    assert!(DecryptEvent::new(&"X".repeat(39), COLS, LINES, now, &mut rng).is_none());
+
+    let mut frame = Frame::new(COLS, LINES, None);
+    event.render(&ctx(now + Duration::from_millis(300)), &mut frame);
+    let scrambled: String = (0..LINES).map(|l| row_text(&frame, l)).collect();
+    assert!(!scrambled.contains("WAKE"), "still scrambling");
+
+    event.render(&ctx(now + Duration::from_secs(2)), &mut frame);
+    let line = (0..LINES)
+        .find(|&l| row_text(&frame, l).contains("WAKE"))
+        .expect("word resolved on some row");
+    assert!(row_text(&frame, line).contains("WAKE UP"));
+    assert!(!event.is_pre_rain(), "drawn on top of the rain");
+    assert!(!event.is_finished(&ctx(now + Duration::from_secs(2))));
+    assert!(event.is_finished(&ctx(now + Duration::from_secs(10))));
+}
+
+#[test]
+fn scanline_lifts_glyphs_on_its_row_only() {
+    let now = Instant::now();
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut event = ScanlineEvent::new(now, &mut rng);
+    let mut frame = glyph_frame();
+    // Early in the sweep only the top rows are lit.
+    event.render(&ctx(now + Duration::from_millis(100)), &mut frame);
+    let green = |l: u16| match frame.get(0, l).unwrap().fg {
+        Some(Color::Rgb { g, .. }) => g,
+        other => panic!("unexpected fg {other:?}"),
+    };
+    assert!(green(0) > 60, "top row lifted");
+    assert_eq!(green(LINES - 1), 60, "bottom row untouched");
+    assert_eq!(frame.get(0, 0).unwrap().ch, 'a', "glyphs are kept");
+}
+
+#[test]
+fn cascade_pours_glyphs_down_its_columns() {
+    let now = Instant::now();
+    let mut rng = StdRng::seed_from_u64(3);
+    let mut event = CascadeEvent::new(COLS, LINES, now, &mut rng).unwrap();
+    let mut frame = Frame::new(COLS, LINES, None);
+    event.render(&ctx(now + Duration::from_millis(400)), &mut frame);
+    let drawn: Vec<u16> = (0..COLS)
+        .filter(|&c| (0..LINES).any(|l| frame.get(c, l).unwrap().ch != ' '))
+        .collect();
+    assert!(!drawn.is_empty() && drawn.len() <= 3, "{drawn:?}");
+    assert!(
+        drawn.windows(2).all(|w| w[1] == w[0] + 1),
+        "adjacent columns"
+    );
+}
+
+#[test]
+fn tear_shifts_the_band_and_snaps_it_back() {
+    let now = Instant::now();
+    let mut rng = StdRng::seed_from_u64(11);
+    let mut event = TearEvent::new(COLS, LINES, now, &mut rng).unwrap();
+    let mut frame = glyph_frame();
+    let before: Vec<String> = (0..LINES).map(|l| row_text(&frame, l)).collect();
+
+    event.render(&ctx(now), &mut frame);
+    let torn: Vec<u16> = (0..LINES)
+        .filter(|&l| row_text(&frame, l) != before[l as usize])
+        .collect();
+    assert!((1..=4).contains(&torn.len()), "{torn:?}");
+    // A wrapped rotation keeps every glyph of the row.
+    for &l in &torn {
+        let mut a: Vec<char> = row_text(&frame, l).chars().collect();
+        let mut b: Vec<char> = before[l as usize].chars().collect();
+        a.sort_unstable();
+        b.sort_unstable();
+        assert_eq!(a, b);
+    }
+    // Each frame restores the band before shifting it again, so it
+    // never slides further.
+    let mid: Vec<String> = (0..LINES).map(|l| row_text(&frame, l)).collect();
+    event.restore(&mut frame);
+    assert_eq!(
+        (0..LINES).map(|l| row_text(&frame, l)).collect::<Vec<_>>(),
+        before
+    );
+    event.render(&ctx(now + Duration::from_millis(50)), &mut frame);
+    assert_eq!(
+        (0..LINES).map(|l| row_text(&frame, l)).collect::<Vec<_>>(),
+        mid
+    );
+
+    let late = ctx(now + Duration::from_secs(1));
+    assert!(!event.is_finished(&late), "waits for the restore");
+    event.restore(&mut frame);
+    assert!(event.is_finished(&late));
+    assert_eq!(
+        (0..LINES).map(|l| row_text(&frame, l)).collect::<Vec<_>>(),
+        before
+    );
+}
+
+#[test]
+fn tear_leaves_the_rain_it_tore_untouched() {
+    const STEP: Duration = Duration::from_micros(16_667);
+    let origin = Instant::now();
+    let stepped = |clock: &ManualClock| {
+        let mut cloud = Cloud::with_clock(
+            Box::new(clock.clone()),
+            ColorMode::TrueColor,
+            ShadingMode::Random,
+            BoldMode::Off,
+            false,
+            true,
+            ColorScheme::Green,
+            RainStyle::Glyph,
+        );
+        cloud.set_seed(42);
+        cloud.init_chars(vec!['0', '1', '2', '3', '4', '5', '6', '7', '8', '9']);
+        cloud.reset(COLS, LINES);
+        cloud
+    };
+    let (ca, cb) = (
+        ManualClock::starting_at(origin),
+        ManualClock::starting_at(origin),
+    );
+    let mut torn = stepped(&ca);
+    let mut plain = stepped(&cb);
+    let (mut fa, mut fb) = (
+        Frame::new(COLS, LINES, torn.palette.bg),
+        Frame::new(COLS, LINES, plain.palette.bg),
+    );
+    let row = |frame: &Frame, line: u16| -> Vec<Cell> {
+        let start = frame.index(0, line).unwrap();
+        (start..start + COLS as usize)
+            .map(|i| frame.cell_at_index(i))
+            .collect()
+    };
+    // Warm up so the band has rain in it, then tear while it keeps falling.
+    for _ in 0..60 {
+        ca.advance(STEP);
+        cb.advance(STEP);
+        torn.rain(&mut fa);
+        plain.rain(&mut fb);
+    }
+    torn.set_event_tuning(only(&[("events.tear.chance", "1")]));
+    torn.enable_events();
+    let mut torn_frames = 0;
+    // 40 frames outlast the longest tear (450 ms); the cooldown keeps a
+    // second one from spawning.
+    for _ in 0..40 {
+        ca.advance(STEP);
+        cb.advance(STEP);
+        torn.rain(&mut fa);
+        plain.rain(&mut fb);
+        let mut differs = false;
+        for line in 0..LINES {
+            let (a, b) = (row(&fa, line), row(&fb, line));
+            if a != b {
+                differs = true;
+                assert!(
+                    (1..COLS as usize).any(|k| {
+                        let mut r = b.clone();
+                        r.rotate_right(k);
+                        r == a
+                    }),
+                    "row {line} is not a rotation of the untorn rain"
+                );
+            }
+        }
+        torn_frames += usize::from(differs);
+    }
+    assert!(torn_frames > 0, "the tear never showed");
+    ca.advance(STEP);
+    cb.advance(STEP);
+    torn.rain(&mut fa);
+    plain.rain(&mut fb);
+    for line in 0..LINES {
+        assert_eq!(row(&fa, line), row(&fb, line), "row {line} after the tear");
+    }
+}
@@ -162,12 +162,14 @@ fn monolith_color_for_level_ghost_is_faintest() {
         edge_fade_lut: &[],
         vignette_lut: &[],
         vignette_lut_cols: 0,
+        density_mask_lut: &[],
         charset_wave_line: None,
         color_wave_line: None,
         mouse_col: u16::MAX,
         mouse_line: u16::MAX,
         flash_waves: &[],
         pool_is_binary: false,
+        tuning: &crate::constants::RainTuning::DEFAULT,
         atmospheric: None,
         hue_drift_offset: None,
         column_coherence_lut: None,
@@ -230,12 +232,14 @@ fn monolith_background_muddy_residue_guard() {
         edge_fade_lut: &[],
         vignette_lut: &[],
         vignette_lut_cols: 0,
+        density_mask_lut: &[],
         charset_wave_line: None,
         color_wave_line: None,
         mouse_col: u16::MAX,
         mouse_line: u16::MAX,
         flash_waves: &[],
         pool_is_binary: false,
+        tuning: &crate::constants::RainTuning::DEFAULT,
         atmospheric: None,
         hue_drift_offset: None,
         column_coherence_lut: None,
@@ -229,7 +229,7 @@ fn repeated_uppercase_forward_cycle_never_blank() {
 //    the block's own overrides.
 
 use crate::cloud::Cloud;
-use crate::crystal_dragon_engine::ambient::AmbientEntry;
+use crate::crystal_dragon_engine::ambient::{AmbientDays, AmbientEntry};
 use crate::runtime::ColorScheme;
 use std::collections::HashMap;
 
@@ -256,6 +256,8 @@ fn apply_ambient_entry_builtin_scene_applies_scene_defaults() {
         hour: 13,
         minute: 0,
         scene: "signal".to_string(),
+        days: AmbientDays::Daily,
+        solar: None,
     };
     let cfg = HashMap::new();
     let charset_preset = cloud.apply_ambient_entry(&entry, "zen", &[], false, &cfg);
@@ -302,20 +304,16 @@ fn apply_ambient_entry_custom_scene_applies_base_scene_then_overrides() {
         hour: 15,
         minute: 0,
         scene: "afternoon".to_string(),
+        days: AmbientDays::Daily,
+        solar: None,
     };
     let mut cfg = HashMap::new();
     cfg.insert(
         "scene-custom.afternoon.base-scene".to_string(),
-        "signal".to_string(),
-    );
-    cfg.insert(
-        "scene-custom.afternoon.color".to_string(),
-        "cosmos".to_string(),
-    );
-    cfg.insert(
-        "scene-custom.afternoon.speed".to_string(),
-        "12.0".to_string(),
+        "signal".into(),
     );
+    cfg.insert("scene-custom.afternoon.color".to_string(), "cosmos".into());
+    cfg.insert("scene-custom.afternoon.speed".to_string(), "12.0".into());
     let charset_preset = cloud.apply_ambient_entry(&entry, "zen", &[], false, &cfg);
 
     assert_eq!(
@@ -349,11 +347,13 @@ fn apply_ambient_entry_custom_scene_without_base_scene_uses_glyph_rain() {
         hour: 18,
         minute: 0,
         scene: "minimal".to_string(),
+        days: AmbientDays::Daily,
+        solar: None,
     };
     let mut cfg = HashMap::new();
     cfg.insert(
         "scene-custom.minimal.color".to_string(),
-        "neon-green".to_string(),
+        "neon-green".into(),
     );
     // No base-scene, no speed/density — those retain current values.
     let _ = cloud.apply_ambient_entry(&entry, "zen", &[], false, &cfg);
@@ -386,6 +386,8 @@ fn apply_ambient_entry_unknown_scene_is_noop() {
         hour: 20,
         minute: 0,
         scene: "nonexistent-scene".to_string(),
+        days: AmbientDays::Daily,
+        solar: None,
     };
     let cfg = HashMap::new();
     let charset_preset = cloud.apply_ambient_entry(&entry, "zen", &[], false, &cfg);
@@ -397,6 +399,80 @@ fn apply_ambient_entry_unknown_scene_is_noop() {
     assert!((cloud.droplet_density - 0.75).abs() < 0.01);
 }
 
+// ── crossfade_ambient_entry (ambient-transition) ──
+
+fn signal_entry() -> AmbientEntry {
+    AmbientEntry {
+        hour: 13,
+        minute: 0,
+        scene: "signal".to_string(),
+        days: AmbientDays::Daily,
+        solar: None,
+    }
+}
+
+#[test]
+fn crossfade_ambient_entry_starts_at_old_values_and_lands_on_new_scene() {
+    // cinematic (speed 9.0, density 0.75, energy-zen) → signal (14.0, 0.55,
+    // aurora) over 90s: the palette slot switches at once but its wave is
+    // stretched; speed/density start where they were and ease over.
+    let mut cloud = make_cinematic_like_cloud();
+    let cfg = HashMap::new();
+    let duration = Duration::from_secs(90);
+    let charset_preset =
+        cloud.crossfade_ambient_entry(&signal_entry(), "zen", &[], false, &cfg, duration);
+
+    assert_eq!(charset_preset, "retro");
+    assert_eq!(cloud.color_scheme(), ColorScheme::Aurora);
+    assert_eq!(cloud.color_transition_ms, 90_000);
+    assert!((cloud.chars_per_sec - 9.0).abs() < 0.01);
+    assert!((cloud.droplet_density - 0.75).abs() < 0.01);
+    let fade = cloud.ambient_crossfade.expect("crossfade must be running");
+
+    cloud.tick_ambient_crossfade(fade.start + duration / 2);
+    assert!(
+        cloud.chars_per_sec > 9.0 && cloud.chars_per_sec < 14.0,
+        "speed must be between the two scenes mid-fade, got {}",
+        cloud.chars_per_sec
+    );
+    assert!(cloud.droplet_density > 0.55 && cloud.droplet_density < 0.75);
+
+    cloud.tick_ambient_crossfade(fade.start + duration);
+    assert!(cloud.ambient_crossfade.is_none());
+    assert!((cloud.chars_per_sec - 14.0).abs() < 0.01);
+    assert!((cloud.droplet_density - 0.55).abs() < 0.01);
+}
+
+#[test]
+fn crossfade_ambient_entry_zero_duration_is_instant() {
+    let mut cloud = make_cinematic_like_cloud();
+    let cfg = HashMap::new();
+    let _ = cloud.crossfade_ambient_entry(&signal_entry(), "zen", &[], false, &cfg, Duration::ZERO);
+    assert!(cloud.ambient_crossfade.is_none());
+    assert!((cloud.chars_per_sec - 14.0).abs() < 0.01);
+    assert!((cloud.droplet_density - 0.55).abs() < 0.01);
+}
+
+#[test]
+fn user_speed_change_finishes_running_crossfade() {
+    // An explicit speed key must not be overwritten by the fade on the
+    // next frame: the fade lands first, then the key applies on top.
+    let mut cloud = make_cinematic_like_cloud();
+    let cfg = HashMap::new();
+    let _ = cloud.crossfade_ambient_entry(
+        &signal_entry(),
+        "zen",
+        &[],
+        false,
+        &cfg,
+        Duration::from_secs(60),
+    );
+    cloud.set_chars_per_sec(20.0);
+    assert!(cloud.ambient_crossfade.is_none());
+    assert!((cloud.droplet_density - 0.55).abs() < 0.01);
+    assert!((cloud.chars_per_sec - 20.0).abs() < 0.01);
+}
+
 // ── apply_startup_ambient regression ( hotfix) ──
 //
 // Bug: `apply_startup_ambient` originally passed `&HashMap::new()` (empty
@@ -422,7 +498,11 @@ fn apply_startup_ambient_with_empty_cfg_is_noop_for_custom_scene() {
             hour: 0,
             minute: 0,
             scene: "afternoon".to_string(),
+            days: AmbientDays::Daily,
+            solar: None,
         }],
+        location: None,
+        transition: Duration::ZERO,
     };
     let empty_cfg = HashMap::new();
     let (charset_preset, entry) =
@@ -448,17 +528,18 @@ fn apply_startup_ambient_with_real_cfg_applies_custom_scene() {
             hour: 0,
             minute: 0,
             scene: "afternoon".to_string(),
+            days: AmbientDays::Daily,
+            solar: None,
         }],
+        location: None,
+        transition: Duration::ZERO,
     };
     let mut cfg = HashMap::new();
     cfg.insert(
         "scene-custom.afternoon.base-scene".to_string(),
-        "signal".to_string(),
-    );
-    cfg.insert(
-        "scene-custom.afternoon.color".to_string(),
-        "cosmos".to_string(),
+        "signal".into(),
     );
+    cfg.insert("scene-custom.afternoon.color".to_string(), "cosmos".into());
     let (charset_preset, entry) =
         apply_startup_ambient(&mut cloud, &schedule, "zen", &[], false, &cfg);
 
@@ -173,12 +173,14 @@ fn hero_spine_trail_empty_space_have_distinct_brightness() {
         edge_fade_lut: &[],
         vignette_lut: &[],
         vignette_lut_cols: 0,
+        density_mask_lut: &[],
         charset_wave_line: None,
         color_wave_line: None,
         mouse_col: u16::MAX,
         mouse_line: u16::MAX,
         flash_waves: &[],
         pool_is_binary: false,
+        tuning: &crate::constants::RainTuning::DEFAULT,
         atmospheric: None,
         hue_drift_offset: None,
         column_coherence_lut: None,
@@ -514,12 +516,14 @@ fn depth_lab_brightness_level_four_tier_hierarchy() {
         edge_fade_lut: &[],
         vignette_lut: &[],
         vignette_lut_cols: 0,
+        density_mask_lut: &[],
         charset_wave_line: None,
         color_wave_line: None,
         mouse_col: u16::MAX,
         mouse_line: u16::MAX,
         flash_waves: &[],
         pool_is_binary: false,
+        tuning: &crate::constants::RainTuning::DEFAULT,
         atmospheric: None,
         hue_drift_offset: None,
         column_coherence_lut: None,
@@ -665,12 +669,14 @@ fn depth_lab_no_muddy_residue_on_dark_backgrounds() {
             edge_fade_lut: &[],
             vignette_lut: &[],
             vignette_lut_cols: 0,
+            density_mask_lut: &[],
             charset_wave_line: None,
             color_wave_line: None,
             mouse_col: u16::MAX,
             mouse_line: u16::MAX,
             flash_waves: &[],
             pool_is_binary: false,
+            tuning: &crate::constants::RainTuning::DEFAULT,
             atmospheric: None,
             hue_drift_offset: None,
             column_coherence_lut: None,
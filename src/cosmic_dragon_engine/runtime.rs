@@ -9,6 +9,26 @@ pub enum ColorMode {
     TrueColor,
 }
 
+impl ColorMode {
+    /// Mode palettes and shaders are computed in. 256- and 16-color
+    /// terminals run the full truecolor pipeline and `Terminal::draw`
+    /// quantizes the result (see `chroma_dragon_engine::quantize`); only
+    /// `Mono` stays as it is.
+    #[must_use]
+    pub const fn palette_mode(self) -> Self {
+        match self {
+            Self::Mono => Self::Mono,
+            Self::Color16 | Self::Color256 | Self::TrueColor => Self::TrueColor,
+        }
+    }
+
+    /// `true` for the modes `Terminal::draw` quantizes to.
+    #[must_use]
+    pub const fn is_quantized(self) -> bool {
+        matches!(self, Self::Color256 | Self::Color16)
+    }
+}
+
 /// Which color pipeline is active for the current run.
 ///
 /// The Chroma Dragon engine (`src/chroma_dragon_engine/`) is the primary coloring
@@ -22,8 +42,9 @@ pub enum ColorMode {
 ///
 /// Detection rule (owner directive: "all color -> chroma dragon first
 /// -> fallback legacy rgb/srgb"):
-/// - `ColorMode::TrueColor` -> `ChromaDragon`
-/// - `ColorMode::{Color256, Color16, Mono}` -> `LegacyRgb`
+/// - `ColorMode::{TrueColor, Color256, Color16}` -> `ChromaDragon`
+///   (256/16-color output is quantized in OKLab at draw time)
+/// - `ColorMode::Mono` -> `LegacyRgb`
 ///
 /// The active pipeline is disclosed in `cosmostrix -v`,
 /// `cosmostrix --doctor`, and the benchmark CONFIG block so the user
@@ -32,12 +53,12 @@ pub enum ColorMode {
 pub enum ColorPipeline {
     /// Chroma Dragon engine: OKLab gradient, perceptual blend,
     /// climate post-FX, head halo, L-smoothing, subpixel jitter.
-    /// Active when `ColorMode == TrueColor`.
+    /// Active for every `ColorMode` except `Mono`.
     ChromaDragon,
 
     /// Legacy sRGB-linear pipeline: raw per-channel RGB math, no
-    /// OKLab, no climate post-FX. Active when `ColorMode` is
-    /// `Color256`, `Color16`, or `Mono`. This is NOT a separate code
+    /// OKLab, no climate post-FX. Active when `ColorMode` is `Mono`.
+    /// This is NOT a separate code
     /// path -- it is the SAME call sites with the chroma helpers
     /// swapped for their raw-RGB equivalents in `chroma::legacy`.
     LegacyRgb,
@@ -46,16 +67,17 @@ pub enum ColorPipeline {
 impl ColorPipeline {
     /// Resolve the active pipeline from the terminal color mode.
     ///
-    /// The chroma engine requires truecolor output to express the
-    /// OKLab-constructed palette stops. On `Color256`/`Color16`/`Mono`
-    /// the palette would be quantized away, so the legacy sRGB-linear
+    /// The chroma engine computes in truecolor. On `Color256`/`Color16`
+    /// the result is mapped to the nearest palette entry by OKLab
+    /// distance when drawn, so gradients and chroma features survive.
+    /// `Mono` has a single colour to map to, so the legacy sRGB-linear
     /// math is used directly -- it produces the same per-channel
     /// brightness/blend result without the wasted OKLab round-trip.
     #[must_use]
     pub const fn detect(color_mode: ColorMode) -> Self {
         match color_mode {
-            ColorMode::TrueColor => Self::ChromaDragon,
-            ColorMode::Color256 | ColorMode::Color16 | ColorMode::Mono => Self::LegacyRgb,
+            ColorMode::TrueColor | ColorMode::Color256 | ColorMode::Color16 => Self::ChromaDragon,
+            ColorMode::Mono => Self::LegacyRgb,
         }
     }
 
@@ -78,7 +100,7 @@ impl ColorPipeline {
                 "oklab gradient, perceptual blend, climate post-fx, head halo, l-smoothing"
             }
             Self::LegacyRgb => {
-                "sRGB-linear fallback (color mode lacks truecolor; no OKLab, no climate post-fx)"
+                "sRGB-linear fallback (mono color mode; no OKLab, no climate post-fx)"
             }
         }
     }
@@ -98,18 +120,18 @@ impl ColorPipeline {
     pub const fn disable_reason(self, color_mode: ColorMode) -> Option<&'static str> {
         match (self, color_mode) {
             (Self::ChromaDragon, _) => None,
-            (Self::LegacyRgb, ColorMode::Color256) => {
-                Some("color_mode=Color256 -- chroma needs truecolor; legacy sRGB-linear in effect")
-            }
-            (Self::LegacyRgb, ColorMode::Color16) => {
-                Some("color_mode=Color16 -- chroma needs truecolor; legacy sRGB-linear in effect")
-            }
             (Self::LegacyRgb, ColorMode::Mono) => {
-                Some("color_mode=Mono -- chroma needs truecolor; legacy sRGB-linear in effect")
+                Some("color_mode=Mono -- no palette to quantize to; legacy sRGB-linear in effect")
             }
-            // Defensive: LegacyRgb with TrueColor should not occur via
+            // Defensive: LegacyRgb with a color mode should not occur via
             // `detect()`, but the function is public so a caller could
             // force it. Disclose the state honestly.
+            (Self::LegacyRgb, ColorMode::Color256) => {
+                Some("color_mode=Color256 but pipeline forced to legacy_rgb")
+            }
+            (Self::LegacyRgb, ColorMode::Color16) => {
+                Some("color_mode=Color16 but pipeline forced to legacy_rgb")
+            }
             (Self::LegacyRgb, ColorMode::TrueColor) => {
                 Some("color_mode=TrueColor but pipeline forced to legacy_rgb")
             }
@@ -216,21 +238,43 @@ mod tests {
     }
 
     #[test]
-    fn detect_non_truecolor_routes_to_legacy_rgb() {
+    fn detect_quantized_modes_route_to_chroma_dragon() {
+        // 256/16-color output is computed in truecolor and quantized in
+        // OKLab at draw time, so the chroma pipeline stays active.
         assert_eq!(
             ColorPipeline::detect(ColorMode::Color256),
-            ColorPipeline::LegacyRgb
+            ColorPipeline::ChromaDragon
         );
         assert_eq!(
             ColorPipeline::detect(ColorMode::Color16),
-            ColorPipeline::LegacyRgb
+            ColorPipeline::ChromaDragon
         );
+    }
+
+    #[test]
+    fn detect_mono_routes_to_legacy_rgb() {
         assert_eq!(
             ColorPipeline::detect(ColorMode::Mono),
             ColorPipeline::LegacyRgb
         );
     }
 
+    #[test]
+    fn palette_mode_is_truecolor_except_mono() {
+        assert_eq!(ColorMode::Mono.palette_mode(), ColorMode::Mono);
+        for mode in [
+            ColorMode::Color16,
+            ColorMode::Color256,
+            ColorMode::TrueColor,
+        ] {
+            assert_eq!(mode.palette_mode(), ColorMode::TrueColor);
+        }
+        assert!(ColorMode::Color256.is_quantized());
+        assert!(ColorMode::Color16.is_quantized());
+        assert!(!ColorMode::TrueColor.is_quantized());
+        assert!(!ColorMode::Mono.is_quantized());
+    }
+
     #[test]
     fn label_is_stable_machine_readable() {
         // These strings appear in -v / --doctor / --benchmark output.
@@ -0,0 +1,259 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Asciicast v2 recorder for `--record <file.cast>`.
+//!
+//! Captures the exact bytes `Terminal::flush_ansi` hands to the output
+//! sink (SYNC wrappers and RIS resets included) as `"o"` events, plus
+//! `"r"` events for applied grid resizes. The result replays with
+//! `asciinema play` or the asciinema web player — a lossless, tiny
+//! alternative to screen-recording a GIF for demos and bug repros.
+//!
+//! ## Format
+//!
+//! ```text
+//! {"version": 2, "width": 120, "height": 40, "timestamp": 1760000000, ...}
+//! [0.000000, "o", "\u001b[?25l"]
+//! [0.016712, "o", "\u001b[?2026h\u001b[1;1H..."]
+//! [3.402118, "r", "100x30"]
+//! ```
+//!
+//! One JSON document per line. Event times are seconds since the
+//! recorder was created (microsecond precision).
+//!
+//! ## Failure policy
+//!
+//! A failing record file must never take down the rain session: the
+//! first write error is latched, further events are dropped, and the
+//! error is reported on stderr after the terminal is restored (see
+//! `event_loop_finalize.rs`).
+
+use std::fs::File;
+use std::io::{BufWriter, Result, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// Record file buffer. Frames are small diffs; 64 KiB batches a few
+/// dozen of them per `write()` without holding much in memory on crash.
+const RECORD_BUF_CAPACITY: usize = 64 * 1024;
+
+/// Outcome of a recording, reported after the terminal is restored.
+pub(crate) struct RecordingSummary {
+    pub(crate) path: PathBuf,
+    pub(crate) events: u64,
+    pub(crate) error: Option<std::io::Error>,
+}
+
+pub(crate) struct AsciicastRecorder {
+    out: Box<dyn Write + Send>,
+    path: PathBuf,
+    start: Instant,
+    events: u64,
+    /// Reusable line buffer (avoids a per-frame String allocation).
+    line: String,
+    error: Option<std::io::Error>,
+}
+
+impl AsciicastRecorder {
+    /// Create (or truncate) `path` and write the v2 header for a
+    /// `cols`×`lines` grid.
+    pub(crate) fn create(path: &Path, cols: u16, lines: u16) -> Result<Self> {
+        let file = File::create(path)?;
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        Self::with_writer(
+            Box::new(BufWriter::with_capacity(RECORD_BUF_CAPACITY, file)),
+            path.to_path_buf(),
+            cols,
+            lines,
+            timestamp,
+        )
+    }
+
+    /// Recorder over an arbitrary writer (tests, pipes).
+    pub(crate) fn with_writer(
+        mut out: Box<dyn Write + Send>,
+        path: PathBuf,
+        cols: u16,
+        lines: u16,
+        timestamp: u64,
+    ) -> Result<Self> {
+        let mut header = format!(
+            "{{\"version\": 2, \"width\": {cols}, \"height\": {lines}, \"timestamp\": {timestamp}, \"title\": "
+        );
+        push_json_str(
+            &mut header,
+            &format!("cosmostrix v{}", env!("CARGO_PKG_VERSION")),
+        );
+        header.push_str(", \"env\": {\"TERM\": ");
+        push_json_str(&mut header, &std::env::var("TERM").unwrap_or_default());
+        header.push_str(", \"SHELL\": ");
+        push_json_str(&mut header, &std::env::var("SHELL").unwrap_or_default());
+        header.push_str("}}\n");
+        out.write_all(header.as_bytes())?;
+        let mut rec = Self {
+            out,
+            path,
+            start: Instant::now(),
+            events: 0,
+            line: String::new(),
+            error: None,
+        };
+        // The player starts with a visible cursor; the live session hid
+        // it during Terminal init (before the recorder existed).
+        rec.record_output(b"\x1b[?25l");
+        Ok(rec)
+    }
+
+    /// Append an `"o"` (output) event. Frame bytes are whole UTF-8
+    /// writes, so lossy decoding never actually replaces anything.
+    pub(crate) fn record_output(&mut self, bytes: &[u8]) {
+        if bytes.is_empty() {
+            return;
+        }
+        let data = String::from_utf8_lossy(bytes);
+        self.push_event("o", &data);
+    }
+
+    /// Append an `"r"` (resize) event for a `cols`×`lines` grid.
+    pub(crate) fn record_resize(&mut self, cols: u16, lines: u16) {
+        self.push_event("r", &format!("{cols}x{lines}"));
+    }
+
+    fn push_event(&mut self, code: &str, data: &str) {
+        if self.error.is_some() {
+            return;
+        }
+        let t = self.start.elapsed().as_secs_f64();
+        self.line.clear();
+        use std::fmt::Write as _;
+        let _ = write!(self.line, "[{t:.6}, \"{code}\", ");
+        push_json_str(&mut self.line, data);
+        self.line.push_str("]\n");
+        match self.out.write_all(self.line.as_bytes()) {
+            Ok(()) => self.events += 1,
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    /// Flush and return the summary. Consumes the recorder.
+    pub(crate) fn finish(mut self) -> RecordingSummary {
+        if self.error.is_none() {
+            if let Err(e) = self.out.flush() {
+                self.error = Some(e);
+            }
+        }
+        RecordingSummary {
+            path: self.path,
+            events: self.events,
+            error: self.error,
+        }
+    }
+}
+
+/// Append `s` as a JSON string literal (quotes included). Control bytes
+/// — ESC above all — use `\u00XX` escapes as asciinema itself writes them.
+fn push_json_str(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 || c == '\u{7f}' => {
+                use std::fmt::Write as _;
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::terminal::sink::MemorySink;
+
+    fn recorder(mem: &MemorySink) -> AsciicastRecorder {
+        AsciicastRecorder::with_writer(Box::new(mem.clone()), PathBuf::from("t.cast"), 80, 24, 42)
+            .unwrap()
+    }
+
+    fn lines(mem: &MemorySink) -> Vec<String> {
+        String::from_utf8(mem.contents())
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn header_is_v2_with_grid_size() {
+        let mem = MemorySink::new();
+        let _rec = recorder(&mem);
+        let header = &lines(&mem)[0];
+        assert!(
+            header.starts_with("{\"version\": 2, \"width\": 80, \"height\": 24, \"timestamp\": 42")
+        );
+        assert!(header.ends_with("}}"));
+    }
+
+    #[test]
+    fn output_event_escapes_control_bytes() {
+        let mem = MemorySink::new();
+        let mut rec = recorder(&mem);
+        rec.record_output(b"\x1b[1;1H\"a\\b\"\r\n");
+        let ev = lines(&mem).pop().unwrap();
+        assert!(ev.starts_with('['));
+        assert!(
+            ev.ends_with(", \"o\", \"\\u001b[1;1H\\\"a\\\\b\\\"\\r\\n\"]"),
+            "{ev}"
+        );
+    }
+
+    #[test]
+    fn resize_event_uses_colsxlines() {
+        let mem = MemorySink::new();
+        let mut rec = recorder(&mem);
+        rec.record_resize(100, 30);
+        let ev = lines(&mem).pop().unwrap();
+        assert!(ev.ends_with(", \"r\", \"100x30\"]"), "{ev}");
+    }
+
+    #[test]
+    fn finish_counts_events_including_cursor_prologue() {
+        let mem = MemorySink::new();
+        let mut rec = recorder(&mem);
+        rec.record_output(b"x");
+        rec.record_output(b"");
+        let summary = rec.finish();
+        assert_eq!(summary.events, 2);
+        assert!(summary.error.is_none());
+    }
+
+    #[test]
+    fn write_error_is_latched_not_propagated() {
+        struct Broken;
+        impl Write for Broken {
+            fn write(&mut self, _: &[u8]) -> Result<usize> {
+                Err(std::io::Error::from(std::io::ErrorKind::BrokenPipe))
+            }
+            fn flush(&mut self) -> Result<()> {
+                Ok(())
+            }
+        }
+        let mem = MemorySink::new();
+        let mut rec = recorder(&mem);
+        rec.out = Box::new(Broken);
+        rec.record_output(b"x");
+        rec.record_resize(1, 1);
+        let summary = rec.finish();
+        assert_eq!(summary.events, 1);
+        assert!(summary.error.is_some());
+    }
+}
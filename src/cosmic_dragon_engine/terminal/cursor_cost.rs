@@ -0,0 +1,261 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Byte-cost-optimal cursor and glyph encoding for [`Terminal::draw`].
+//!
+//! Over SSH and on xterm.js hosts (see `tier2.rs` byte budgets) the bytes
+//! written per frame are the bottleneck, not CPU. The render paths used to
+//! emit an absolute `MoveTo` for every run and every glyph verbatim; these
+//! helpers count the exact bytes of each equivalent encoding and emit the
+//! cheapest one:
+//!
+//! - **Gaps:** CUP (`CSI r;c H`), CUF (`CSI n C`), CUB (`CSI n D`) or
+//!   CR + LF×n (+ CUF). Moves to another row only ever use CUP or CR/LF,
+//!   both of which reset the column — the row-boundary resync from bug #12
+//!   still holds, it just no longer costs a full CUP.
+//! - **Repeated glyphs:** one copy plus REP (`CSI n b`), on terminals with
+//!   [`TerminalCaps::repeat_char`](crate::termdetect::TerminalCaps).
+//! - **Blank runs:** ECH (`CSI n X`), which does not move the cursor. A
+//!   trailing blank segment may leave the cursor parked at its start;
+//!   [`push_run`] reports how far the cursor really advanced so the
+//!   caller's position stays exact. ECH is only used for runs on the
+//!   default background: terminals without back-color erase (BCE) fill
+//!   erased cells with the default background, not the current SGR one,
+//!   and there is no capability probe to tell the two apart.
+//!
+//! Ties go to the plainer form (absolute over relative, literal glyphs over
+//! REP/ECH). Everything lands in `ansi_buf`, so the savings show up
+//! directly in `Terminal::encoding_stats()` bytes/frame.
+//!
+//! [`Terminal::draw`]: super::Terminal::draw
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::sgr_format::push_u16;
+
+/// Decimal digit count of `n`.
+#[inline]
+fn digits(n: u16) -> usize {
+    match n {
+        0..=9 => 1,
+        10..=99 => 2,
+        100..=999 => 3,
+        1000..=9999 => 4,
+        _ => 5,
+    }
+}
+
+/// Bytes of `CSI n <final>`; a count of 1 is the default and is omitted.
+#[inline]
+fn csi_n_cost(n: u16) -> usize {
+    if n == 1 {
+        3
+    } else {
+        3 + digits(n)
+    }
+}
+
+#[inline]
+fn push_csi_n(buf: &mut Vec<u8>, n: u16, final_byte: u8) {
+    buf.extend_from_slice(b"\x1b[");
+    if n != 1 {
+        push_u16(buf, n);
+    }
+    buf.push(final_byte);
+}
+
+/// One way of getting the cursor from a known (or unknown) cell to a
+/// target cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum CursorMove {
+    /// Already there.
+    Stay,
+    /// Absolute CUP to `(x, y)`.
+    Cup(u16, u16),
+    /// Cursor forward `n` columns.
+    Forward(u16),
+    /// Cursor back `n` columns.
+    Back(u16),
+    /// CR, `down` line feeds, then forward to column `x`.
+    CrLf { down: u16, x: u16 },
+}
+
+impl CursorMove {
+    /// Exact encoded length in bytes.
+    #[must_use]
+    pub(super) fn cost(self) -> usize {
+        match self {
+            CursorMove::Stay => 0,
+            CursorMove::Cup(0, 0) => 3,
+            CursorMove::Cup(0, y) => 3 + digits(y + 1),
+            CursorMove::Cup(x, y) => 4 + digits(y + 1) + digits(x + 1),
+            CursorMove::Forward(n) | CursorMove::Back(n) => csi_n_cost(n),
+            CursorMove::CrLf { down, x } => {
+                1 + down as usize + if x == 0 { 0 } else { csi_n_cost(x) }
+            }
+        }
+    }
+
+    fn push(self, buf: &mut Vec<u8>) {
+        match self {
+            CursorMove::Stay => {}
+            CursorMove::Cup(x, y) => {
+                buf.extend_from_slice(b"\x1b[");
+                if x != 0 || y != 0 {
+                    push_u16(buf, y + 1);
+                }
+                if x != 0 {
+                    buf.push(b';');
+                    push_u16(buf, x + 1);
+                }
+                buf.push(b'H');
+            }
+            CursorMove::Forward(n) => push_csi_n(buf, n, b'C'),
+            CursorMove::Back(n) => push_csi_n(buf, n, b'D'),
+            CursorMove::CrLf { down, x } => {
+                buf.push(b'\r');
+                buf.extend(std::iter::repeat_n(b'\n', down as usize));
+                if x != 0 {
+                    push_csi_n(buf, x, b'C');
+                }
+            }
+        }
+    }
+}
+
+/// Cheapest move from `from` (`None` = position unknown, e.g. after a
+/// write into the last column left a pending wrap) to `to`.
+#[must_use]
+pub(super) fn plan_move(from: Option<(u16, u16)>, to: (u16, u16)) -> CursorMove {
+    let cup = CursorMove::Cup(to.0, to.1);
+    let Some((fx, fy)) = from else {
+        return cup;
+    };
+    if (fx, fy) == to {
+        return CursorMove::Stay;
+    }
+    let relative = if fy == to.1 {
+        if to.0 > fx {
+            CursorMove::Forward(to.0 - fx)
+        } else {
+            let back = CursorMove::Back(fx - to.0);
+            let cr = CursorMove::CrLf { down: 0, x: to.0 };
+            if cr.cost() < back.cost() {
+                cr
+            } else {
+                back
+            }
+        }
+    } else if to.1 > fy {
+        CursorMove::CrLf {
+            down: to.1 - fy,
+            x: to.0,
+        }
+    } else {
+        return cup;
+    };
+    if relative.cost() < cup.cost() {
+        relative
+    } else {
+        cup
+    }
+}
+
+/// Emit the cheapest cursor move from `from` to `to`.
+#[inline]
+pub(super) fn push_move(buf: &mut Vec<u8>, from: Option<(u16, u16)>, to: (u16, u16)) {
+    plan_move(from, to).push(buf);
+}
+
+/// How one segment of identical glyphs is encoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Segment {
+    /// The glyph `k` times.
+    Literal,
+    /// One glyph, then REP for the other `k - 1`.
+    Repeat,
+    /// ECH over `k` blanks, plus a CUF past them unless parked.
+    Erase,
+}
+
+/// Cheapest encoding of `k` copies of `c` and its byte cost. `erase`
+/// allows ECH (the run is on the default background); `parked` means the
+/// cursor may stay put after an erase (last segment of a run whose caller
+/// tracks the real position).
+#[must_use]
+pub(super) fn plan_segment(
+    c: char,
+    k: u16,
+    rep: bool,
+    erase: bool,
+    parked: bool,
+) -> (Segment, usize) {
+    let len = c.len_utf8();
+    let mut best = (Segment::Literal, k as usize * len);
+    if rep && k > 1 && UnicodeWidthChar::width(c) == Some(1) {
+        let cost = len + csi_n_cost(k - 1);
+        if cost < best.1 {
+            best = (Segment::Repeat, cost);
+        }
+    }
+    if erase && c == ' ' {
+        let cost = csi_n_cost(k) + if parked { 0 } else { csi_n_cost(k) };
+        if cost < best.1 {
+            best = (Segment::Erase, cost);
+        }
+    }
+    best
+}
+
+/// Columns the cursor advances for a segment of `k` cells.
+#[inline]
+#[must_use]
+pub(super) fn segment_advance(segment: Segment, k: u16, parked: bool) -> u16 {
+    if segment == Segment::Erase && parked {
+        0
+    } else {
+        k
+    }
+}
+
+/// Emit a same-style run of single-cell glyphs at the cursor, collapsing
+/// repeats into REP (when `rep` is set) and blanks into ECH (when `erase`
+/// is set, i.e. the run's background is the default).
+///
+/// With `park` set, a trailing blank segment is erased without moving
+/// the cursor past it; otherwise the cursor always ends after the run.
+/// Returns the number of columns the cursor advanced.
+pub(super) fn push_run(buf: &mut Vec<u8>, run: &str, rep: bool, erase: bool, park: bool) -> u16 {
+    let mut advanced: u16 = 0;
+    let mut chars = run.chars().peekable();
+    while let Some(c) = chars.next() {
+        let mut k: u16 = 1;
+        while chars.next_if_eq(&c).is_some() {
+            k = k.saturating_add(1);
+        }
+        let parked = park && chars.peek().is_none();
+        let mut tmp = [0u8; 4];
+        let bytes = c.encode_utf8(&mut tmp).as_bytes();
+
+        let (segment, _) = plan_segment(c, k, rep, erase, parked);
+        match segment {
+            Segment::Literal => {
+                for _ in 0..k {
+                    buf.extend_from_slice(bytes);
+                }
+            }
+            Segment::Repeat => {
+                buf.extend_from_slice(bytes);
+                push_csi_n(buf, k - 1, b'b');
+            }
+            Segment::Erase => {
+                push_csi_n(buf, k, b'X');
+                if !parked {
+                    push_csi_n(buf, k, b'C');
+                }
+            }
+        }
+        advanced = advanced.saturating_add(segment_advance(segment, k, parked));
+    }
+    advanced
+}
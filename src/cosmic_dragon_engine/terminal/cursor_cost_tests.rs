@@ -0,0 +1,333 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Cursor/glyph cost-encoder tests.
+//!
+//! Declared as `#[cfg(test)] mod cursor_cost_tests;` in `terminal/mod.rs`.
+//! The unit tests pin the byte choices in `cursor_cost.rs`; the replay
+//! tests feed `Terminal::draw` output through a minimal VT model (CUP,
+//! CUF, CUB, CR, LF, REP, ECH, pending wrap) and check the resulting
+//! screen matches the frame, so a wrong cost choice cannot silently
+//! corrupt the picture.
+
+use super::cursor_cost::{plan_move, push_move, push_run, CursorMove};
+use super::sink::MemorySink;
+use super::*;
+use crate::frame::Frame;
+
+fn caps(repeat_char: bool) -> TerminalCaps {
+    TerminalCaps {
+        sync_output: false,
+        kitty_keyboard: false,
+        has_alternate_screen: true,
+        xtermjs_host: false,
+        vscode_integrated: false,
+        default_fps_cap: 240.0,
+        dynamic_default_fps: 60.0,
+        dynamic_fps_source: "test",
+        repeat_char,
+    }
+}
+
+fn glyph(ch: char) -> Cell {
+    Cell {
+        ch,
+        fg: Some(Color::Rgb { r: 0, g: 255, b: 0 }),
+        bg: None,
+        bold: false,
+    }
+}
+
+fn run(s: &str, rep: bool, park: bool) -> (Vec<u8>, u16) {
+    let mut buf = Vec::new();
+    let adv = push_run(&mut buf, s, rep, true, park);
+    (buf, adv)
+}
+
+/// Just enough of a VT to replay what `draw` emits.
+struct Screen {
+    w: usize,
+    h: usize,
+    cells: Vec<char>,
+    x: usize,
+    y: usize,
+    pending_wrap: bool,
+    last: char,
+}
+
+impl Screen {
+    fn new(w: u16, h: u16) -> Self {
+        let (w, h) = (w as usize, h as usize);
+        Self {
+            w,
+            h,
+            cells: vec!['\0'; w * h],
+            x: 0,
+            y: 0,
+            pending_wrap: false,
+            last: ' ',
+        }
+    }
+
+    fn print(&mut self, c: char) {
+        if self.pending_wrap {
+            self.x = 0;
+            self.y = (self.y + 1).min(self.h - 1);
+            self.pending_wrap = false;
+        }
+        self.cells[self.y * self.w + self.x] = c;
+        self.last = c;
+        if self.x + 1 == self.w {
+            self.pending_wrap = true;
+        } else {
+            self.x += 1;
+        }
+    }
+
+    fn feed(&mut self, bytes: &[u8]) {
+        let text = std::str::from_utf8(bytes).expect("draw output is UTF-8");
+        let mut it = text.chars().peekable();
+        while let Some(c) = it.next() {
+            match c {
+                '\x1b' => {
+                    assert_eq!(it.next(), Some('['), "only CSI sequences expected");
+                    let mut params = String::new();
+                    let fin = loop {
+                        let c = it.next().expect("unterminated CSI");
+                        if ('\x40'..='\x7e').contains(&c) {
+                            break c;
+                        }
+                        params.push(c);
+                    };
+                    if params.starts_with('?') {
+                        continue;
+                    }
+                    let nums: Vec<usize> =
+                        params.split(';').map(|p| p.parse().unwrap_or(0)).collect();
+                    let n = nums.first().copied().filter(|&n| n > 0).unwrap_or(1);
+                    match fin {
+                        'H' => {
+                            self.y = n - 1;
+                            self.x = nums.get(1).copied().filter(|&n| n > 0).unwrap_or(1) - 1;
+                            self.pending_wrap = false;
+                        }
+                        'C' => {
+                            self.x = (self.x + n).min(self.w - 1);
+                            self.pending_wrap = false;
+                        }
+                        'D' => {
+                            self.x = self.x.saturating_sub(n);
+                            self.pending_wrap = false;
+                        }
+                        'b' => {
+                            for _ in 0..n {
+                                self.print(self.last);
+                            }
+                        }
+                        'X' => {
+                            let end = (self.x + n).min(self.w);
+                            let row = self.y * self.w;
+                            self.cells[row + self.x..row + end].fill(' ');
+                            self.pending_wrap = false;
+                        }
+                        'm' => {}
+                        other => panic!("unexpected CSI final {other:?}"),
+                    }
+                }
+                '\r' => {
+                    self.x = 0;
+                    self.pending_wrap = false;
+                }
+                '\n' => {
+                    assert!(self.y + 1 < self.h, "LF on the last row would scroll");
+                    self.y += 1;
+                    self.pending_wrap = false;
+                }
+                c => self.print(c),
+            }
+        }
+    }
+
+    fn matches(&self, frame: &Frame) -> bool {
+        (0..self.cells.len()).all(|i| self.cells[i] == frame.cell_at_index(i).ch)
+    }
+}
+
+/// Tiny deterministic LCG so the replay test needs no RNG dependency.
+fn lcg(state: &mut u64) -> u64 {
+    *state = state
+        .wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407);
+    *state >> 33
+}
+
+#[test]
+fn unknown_position_uses_absolute_cup() {
+    assert_eq!(plan_move(None, (4, 2)), CursorMove::Cup(4, 2));
+    let mut buf = Vec::new();
+    push_move(&mut buf, None, (0, 0));
+    assert_eq!(buf, b"\x1b[H");
+    buf.clear();
+    push_move(&mut buf, None, (0, 9));
+    assert_eq!(buf, b"\x1b[10H");
+    buf.clear();
+    push_move(&mut buf, None, (7, 4));
+    assert_eq!(buf, b"\x1b[5;8H");
+}
+
+#[test]
+fn relative_moves_win_when_shorter() {
+    assert_eq!(plan_move(Some((3, 3)), (3, 3)), CursorMove::Stay);
+    assert_eq!(plan_move(Some((3, 3)), (4, 3)), CursorMove::Forward(1));
+    assert_eq!(plan_move(Some((3, 3)), (40, 3)), CursorMove::Forward(37));
+    assert_eq!(
+        plan_move(Some((30, 3)), (0, 4)),
+        CursorMove::CrLf { down: 1, x: 0 }
+    );
+    assert_eq!(
+        plan_move(Some((30, 3)), (0, 3)),
+        CursorMove::CrLf { down: 0, x: 0 }
+    );
+    assert_eq!(plan_move(Some((30, 3)), (28, 3)), CursorMove::Back(2));
+    // Moving up has no relative form; far down is cheaper as CUP.
+    assert_eq!(plan_move(Some((5, 9)), (5, 2)), CursorMove::Cup(5, 2));
+    assert_eq!(plan_move(Some((5, 0)), (5, 30)), CursorMove::Cup(5, 30));
+}
+
+#[test]
+fn move_cost_matches_encoded_length() {
+    let points = [0u16, 1, 8, 9, 10, 98, 99, 100, 998, 999];
+    for &fx in &points {
+        for &fy in &points {
+            for &tx in &points {
+                for &ty in &points {
+                    for from in [None, Some((fx, fy))] {
+                        let mv = plan_move(from, (tx, ty));
+                        let mut buf = Vec::new();
+                        push_move(&mut buf, from, (tx, ty));
+                        assert_eq!(buf.len(), mv.cost(), "{from:?} -> {:?}", (tx, ty));
+                        assert!(mv.cost() <= CursorMove::Cup(tx, ty).cost());
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn repeats_collapse_into_rep_only_when_supported_and_shorter() {
+    assert_eq!(run("AAAAAAAAAA", true, false), (b"A\x1b[9b".to_vec(), 10));
+    assert_eq!(
+        run("AAAAAAAAAA", false, false),
+        (b"AAAAAAAAAA".to_vec(), 10)
+    );
+    // 5 × 'A' costs 5 bytes literally and 5 as `A ESC[4b`: tie → literal.
+    assert_eq!(run("AAAAA", true, false), (b"AAAAA".to_vec(), 5));
+    // Multi-byte glyphs pay off sooner.
+    let (buf, adv) = run("ｱｱｱ", true, false);
+    assert_eq!(buf, "ｱ\x1b[2b".as_bytes());
+    assert_eq!(adv, 3);
+}
+
+#[test]
+fn blank_runs_use_ech_and_report_the_parked_cursor() {
+    // Trailing blanks with `park`: erase only, cursor stays before them.
+    assert_eq!(
+        run("AB          ", false, true),
+        (b"AB\x1b[10X".to_vec(), 2)
+    );
+    // Without `park` the cursor must end after the run, and ECH + CUF
+    // (12 bytes) loses to ten literal blanks.
+    assert_eq!(
+        run("AB          ", false, false),
+        (b"AB          ".to_vec(), 12)
+    );
+    // Interior blanks always move past themselves.
+    let (buf, adv) = run("A                    B", false, true);
+    assert_eq!(buf, b"A\x1b[20X\x1b[20CB");
+    assert_eq!(adv, 22);
+    // Short blank gaps stay literal.
+    assert_eq!(run("A  B", true, true), (b"A  B".to_vec(), 4));
+}
+
+#[test]
+fn blank_runs_on_a_colored_background_stay_literal() {
+    // Without BCE, ECH would fill with the default background.
+    let mut buf = Vec::new();
+    let adv = push_run(&mut buf, "AB          ", false, false, true);
+    assert_eq!((buf, adv), (b"AB          ".to_vec(), 12));
+    let mut buf = Vec::new();
+    let adv = push_run(&mut buf, "A                    B", true, false, true);
+    assert_eq!(buf, b"A \x1b[19bB");
+    assert_eq!(adv, 22);
+}
+
+#[test]
+fn draw_never_erases_over_a_background_color() {
+    let (w, h) = (40u16, 6u16);
+    let mem = MemorySink::new();
+    let mut term = Terminal::with_sink(Box::new(mem.clone()), (w, h), caps(false)).unwrap();
+    mem.take();
+    let bg = Some(Color::Rgb { r: 0, g: 0, b: 32 });
+    let mut frame = Frame::new(w, h, bg);
+    frame.set(1, 1, Cell { bg, ..glyph('A') });
+    term.draw(&mut frame).unwrap();
+    let out = String::from_utf8(mem.take()).unwrap();
+    assert!(!out.contains('X'), "ECH with a background color: {out:?}");
+}
+
+#[test]
+fn diff_draw_replays_to_the_frame() {
+    for rep in [true, false] {
+        let (w, h) = (60u16, 16u16);
+        let mem = MemorySink::new();
+        let mut term = Terminal::with_sink(Box::new(mem.clone()), (w, h), caps(rep)).unwrap();
+        mem.take();
+        let mut screen = Screen::new(w, h);
+        let mut frame = Frame::new(w, h, None);
+        let mut seed = 0x5eed_u64;
+        let glyphs = ['A', 'B', 'ｱ', '0', ' '];
+        for step in 0..120 {
+            // Mostly sparse diff frames with streaks and blank runs;
+            // every 40th is dense enough to take the full-redraw path.
+            let writes = if step % 40 == 0 { 600 } else { 25 };
+            for _ in 0..writes {
+                let x = (lcg(&mut seed) % w as u64) as u16;
+                let y = (lcg(&mut seed) % h as u64) as u16;
+                let ch = glyphs[(lcg(&mut seed) % glyphs.len() as u64) as usize];
+                let len = (lcg(&mut seed) % 14) as u16 + 1;
+                for dx in 0..len.min(w - x) {
+                    frame.set(x + dx, y, glyph(ch));
+                }
+            }
+            term.draw(&mut frame).unwrap();
+            screen.feed(&mem.take());
+            assert!(
+                screen.matches(&frame),
+                "screen diverged at step {step} (rep={rep})"
+            );
+        }
+    }
+}
+
+#[test]
+fn sparse_full_redraw_is_cheaper_than_one_byte_per_cell() {
+    let (w, h) = (80u16, 24u16);
+    let mem = MemorySink::new();
+    let mut term = Terminal::with_sink(Box::new(mem.clone()), (w, h), caps(true)).unwrap();
+    mem.take();
+    let mut frame = Frame::new(w, h, None);
+    for y in 0..h {
+        frame.set(y * 3, y, glyph('Z'));
+    }
+    term.draw(&mut frame).unwrap();
+    let (bytes, flushes, _, _) = term.encoding_stats();
+    assert_eq!(flushes, 1);
+    assert!(
+        bytes < (w as u64 * h as u64) / 2,
+        "mostly blank full redraw took {bytes} bytes"
+    );
+    let mut screen = Screen::new(w, h);
+    screen.feed(&mem.take());
+    assert!(screen.matches(&frame));
+}
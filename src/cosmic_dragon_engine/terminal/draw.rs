@@ -10,13 +10,18 @@
 //!
 //! ## Two paths
 //!
-//! 1. **Full redraw** (≥12.5% cells dirty, or dim/semantic change): row-RLE
-//!    pass over all cells, accumulates into `ansi_buf`, one `write_all`.
-//! 2. **Differential** (<12.5% cells dirty): flat sorted dirty-index
-//!    iteration, contiguous-run detection, per-run MoveTo + SGR batching.
+//! 1. **Full redraw** (cheaper by the cost model, or dim/semantic change):
+//!    row-RLE pass over all cells, accumulates into `ansi_buf`, one `write_all`.
+//! 2. **Differential** (cheaper by the cost model): flat sorted dirty-index
+//!    iteration, contiguous-run detection, per-run cursor move + SGR batching.
 //!
-//! The crossover threshold is `DIRTY_THRESHOLD_RATIO` (currently 8 = 12.5%).
-//! See `cosmic_dragon::egg::threshold_sweep` for the benchmark that tuned it.
+//! Both paths hand cursor moves and glyph runs to `cursor_cost.rs`, which
+//! picks the cheapest byte encoding (CUP / CUF / CR+LF, REP, ECH).
+//!
+//! The crossover is decided per frame by `redraw_cost.rs`, which walks both
+//! paths' byte cost for the actual dirty set. It replaced the fixed
+//! `DIRTY_THRESHOLD_RATIO` (12.5%) after `cosmic_dragon::egg::threshold_sweep`
+//! showed the crossover moves with terminal size and style density.
 
 use std::io::{Result, Write};
 
@@ -25,12 +30,11 @@ use crossterm::{
     QueueableCommand,
 };
 
+use super::cursor_cost::{push_move, push_run};
+use super::redraw_cost::prefers_full_redraw;
+use super::{LastFrame, Terminal};
 use crate::bolt::{BOLD_ESCAPES, BOLD_ESCAPE_LENS};
-use crate::constants::DIRTY_THRESHOLD_RATIO;
 use crate::frame::Frame;
-use crate::sgr_format::push_u16;
-
-use super::{LastFrame, Terminal};
 
 impl Terminal {
     /// Render the frame to stdout via the diff-based ANSI pipeline.
@@ -39,10 +43,33 @@ impl Terminal {
     /// perf-critical hot path — every allocation here was audited during the
     /// Cosmic Dragon egg experiments (see `docs/archive/cosmic_dragon/FINDINGS.md`).
     pub(crate) fn draw(&mut self, frame: &mut Frame) -> Result<()> {
+        // Light default background, then 256/16-color terminals: draw the
+        // mapped mirrors of the frame (see `chroma_dragon_engine::canvas`
+        // and `chroma_dragon_engine::quantize`).
+        if self.light_canvas.is_none() && self.color_quantizer.is_none() {
+            return self.draw_frame(frame);
+        }
+        let mut canvas = self.light_canvas.take();
+        let mut quantizer = self.color_quantizer.take();
+        let mut target: &mut Frame = frame;
+        if let Some(canvas) = canvas.as_mut() {
+            target = canvas.sync(target);
+        }
+        if let Some(quantizer) = quantizer.as_mut() {
+            target = quantizer.sync(target);
+        }
+        let result = self.draw_frame(target);
+        self.light_canvas = canvas;
+        self.color_quantizer = quantizer;
+        result
+    }
+
+    fn draw_frame(&mut self, frame: &mut Frame) -> Result<()> {
         let mut cur_fg: Option<Color> = None;
         let mut cur_bg: Option<Color> = None;
         let mut cur_bold: bool = false;
         let mut cur_pos: Option<(u16, u16)> = None;
+        let rep = self.term_caps.repeat_char;
 
         // Separate dimension-change detection from semantic-change detection.
         // Clear(All) is ONLY issued when the terminal dimensions changed, because
@@ -71,16 +98,12 @@ impl Terminal {
             // (do_full_redraw will be true when needs_clear is true, so
             // every cell gets written regardless.)
             if let Some(bg) = frame.blank.bg {
-                self.stdout.queue(SetBackgroundColor(bg))?;
+                self.sink.queue(SetBackgroundColor(bg))?;
             }
         }
 
         let can_reuse_last = !needs_full_redraw && self.last.is_some();
-        let total_cells = frame.width as usize * frame.height as usize;
         let dirty_count = frame.dirty_indices().len();
-        let dirty_is_large =
-            total_cells > 0 && dirty_count >= (total_cells / DIRTY_THRESHOLD_RATIO);
-        let do_full_redraw = !can_reuse_last || frame.is_dirty_all() || dirty_is_large;
 
         // ── Idle-frame fast path (v30 Cosmic Dragon) ──
         //
@@ -100,6 +123,56 @@ impl Terminal {
             return Ok(());
         }
 
+        let do_full_redraw = !can_reuse_last || frame.is_dirty_all() || {
+            // PERF: flat dirty-index buffer replaces the previous Vec<Vec<usize>>
+            // nested structure. Collect all dirty indices into a single Vec,
+            // sort once (row-major index sort groups by row AND orders within
+            // row in one pass), then iterate contiguous runs. This eliminates
+            // per-row Vec allocations on resize and improves cache locality.
+            //
+            // (perf audit): the previous `dirty_flat.extend(dirty.iter()
+            // .copied().filter(|&idx| idx < height * width))` had an O(N) bounds
+            // filter that ran every frame. The filter is redundant — every entry
+            // in `frame.dirty_indices()` was pushed by `Frame::set()` /
+            // `set_force()`, both of which call `self.index(x, y)` first and
+            // only push `Some(i)` results. So every dirty index is already
+            // guaranteed in-bounds.
+            //
+            // Replaced the filter with a `debug_assert!` that verifies the
+            // invariant in debug builds (zero cost in release). If a future
+            // caller bypasses `index()` and pushes an OOB index, the debug
+            // build will catch it immediately instead of silently masking it.
+            let dirty_flat = &mut self.dirty_flat;
+            dirty_flat.clear();
+            dirty_flat.extend(frame.dirty_indices().iter().copied());
+            dirty_flat.sort_unstable();
+            // (perf polish): the previous O(N) `dirty_flat.iter().all()`
+            // checked every index every frame in debug builds (~4800
+            // comparisons on a 200×40 terminal). Since `dirty_flat` is now
+            // sorted ascending (we just called `sort_unstable()`), only the
+            // LAST (largest) index needs to be checked — if it's in bounds,
+            // all smaller indices are too. This drops the debug-build cost
+            // from O(N) to O(1) per frame, with zero release-build impact
+            // (debug_assert! is elided in release).
+            //
+            // SAFETY: `Frame::set()` / `set_force()` both call
+            // `self.index(x, y)` first and only push `Some(i)` results,
+            // so every dirty index is guaranteed in-bounds. This assert
+            // catches the unlikely case where a future caller bypasses
+            // `index()` and pushes an OOB index.
+            debug_assert!(
+                dirty_flat
+                    .last()
+                    .is_none_or(|&idx| idx < frame.height as usize * frame.width as usize),
+                "dirty_indices must be in-bounds — Frame::set guarantees this"
+            );
+
+            // Cost model: take whichever path is fewer bytes for this
+            // frame's dirty set (see `redraw_cost.rs`).
+            let last = self.last.as_ref().expect("can_reuse_last");
+            prefers_full_redraw(frame, dirty_flat, Some(&last.cells), rep)
+        };
+
         if do_full_redraw {
             let needs_new_last = self
                 .last
@@ -136,16 +209,11 @@ impl Terminal {
             if row_buf.capacity() < need_cap {
                 row_buf.reserve(need_cap - row_buf.capacity());
             }
-            // MoveTo(0,0) directly into ansi_buf
-            ansi_buf.extend_from_slice(b"\x1b[1;1H");
             for y in 0..frame.height {
-                if y > 0 {
-                    // MoveTo(0, y) directly into ansi_buf
-                    ansi_buf.push(0x1b);
-                    ansi_buf.push(b'[');
-                    push_u16(ansi_buf, y + 1);
-                    ansi_buf.extend_from_slice(b";1H");
-                }
+                // Row start: CUP, or CR+LF when the previous row parked
+                // the cursor on a trailing ECH instead of a pending wrap.
+                push_move(ansi_buf, cur_pos, (0, y));
+                let mut run_x: u16 = 0;
                 row_buf.clear();
                 let width_usize = frame.width as usize;
                 for x in 0..frame.width {
@@ -156,8 +224,9 @@ impl Terminal {
                     let style_changed =
                         cell.fg != cur_fg || cell.bg != cur_bg || cell.bold != cur_bold;
                     if style_changed && !row_buf.is_empty() {
-                        ansi_buf.extend_from_slice(row_buf.as_bytes());
+                        push_run(ansi_buf, row_buf, rep, cur_bg.is_none(), false);
                         row_buf.clear();
+                        run_x = x;
                     }
 
                     // Combined fg+bg SGR — cached when possible
@@ -182,16 +251,17 @@ impl Terminal {
                     row_buf.push(cell.ch);
                     last.cells[idx] = cell;
                 }
-                // Flush remaining cells in the row buffer
-                if !row_buf.is_empty() {
-                    ansi_buf.extend_from_slice(row_buf.as_bytes());
-                }
+                // Flush remaining cells in the row buffer. A trailing blank
+                // segment may park the cursor (ECH); otherwise it ends in
+                // the last column with a pending wrap, i.e. unknown.
+                let end = run_x + push_run(ansi_buf, row_buf, rep, cur_bg.is_none(), true);
+                cur_pos = (end < frame.width).then_some((end, y));
             }
 
             // Reset attributes + flush all buffered ANSI bytes in one write_all.
             ansi_buf.extend_from_slice(b"\x1b[0m");
             self.flush_ansi()?;
-            self.stdout.flush()?;
+            self.sink.flush()?;
 
             frame.clear_dirty();
             return Ok(());
@@ -199,69 +269,25 @@ impl Terminal {
 
         let last = self.last.as_mut().expect("checked above");
 
-        let dirty = frame.dirty_indices();
+        // `dirty_flat` was filled and sorted by the cost-model check above.
+        let dirty_flat = &self.dirty_flat;
         let width_usize = frame.width as usize;
-        let height_usize = frame.height as usize;
         let run_buf = &mut self.run_buf;
         let ansi_buf = &mut self.ansi_buf;
         let cache_ref = self.color_cache.as_ref();
         ansi_buf.clear();
 
-        // PERF: flat dirty-index buffer replaces the previous Vec<Vec<usize>>
-        // nested structure. Collect all dirty indices into a single Vec,
-        // sort once (row-major index sort groups by row AND orders within
-        // row in one pass), then iterate contiguous runs. This eliminates
-        // per-row Vec allocations on resize and improves cache locality.
-        //
-        // (perf audit): the previous `dirty_flat.extend(dirty.iter()
-        // .copied().filter(|&idx| idx < height * width))` had an O(N) bounds
-        // filter that ran every frame. The filter is redundant — every entry
-        // in `frame.dirty_indices()` was pushed by `Frame::set()` /
-        // `set_force()`, both of which call `self.index(x, y)` first and
-        // only push `Some(i)` results. So every dirty index is already
-        // guaranteed in-bounds.
-        //
-        // Replaced the filter with a `debug_assert!` that verifies the
-        // invariant in debug builds (zero cost in release). If a future
-        // caller bypasses `index()` and pushes an OOB index, the debug
-        // build will catch it immediately instead of silently masking it.
-        let dirty_flat = &mut self.dirty_flat;
-        dirty_flat.clear();
-        dirty_flat.extend(dirty.iter().copied());
-        dirty_flat.sort_unstable();
-        // (perf polish): the previous O(N) `dirty_flat.iter().all()`
-        // checked every index every frame in debug builds (~4800
-        // comparisons on a 200×40 terminal). Since `dirty_flat` is now
-        // sorted ascending (we just called `sort_unstable()`), only the
-        // LAST (largest) index needs to be checked — if it's in bounds,
-        // all smaller indices are too. This drops the debug-build cost
-        // from O(N) to O(1) per frame, with zero release-build impact
-        // (debug_assert! is elided in release).
-        //
-        // SAFETY: `Frame::set()` / `set_force()` both call
-        // `self.index(x, y)` first and only push `Some(i)` results,
-        // so every dirty index is guaranteed in-bounds. This assert
-        // catches the unlikely case where a future caller bypasses
-        // `index()` and pushes an OOB index.
-        debug_assert!(
-            dirty_flat
-                .last()
-                .is_none_or(|&idx| idx < height_usize * width_usize),
-            "dirty_indices must be in-bounds — Frame::set guarantees this"
-        );
-
         // Iterate the flat sorted array, detecting row boundaries and
         // contiguous horizontal runs for RLE batching.
-        // (bug #12): track the current row to force a MoveTo at
-        // each row boundary. This prevents cursor desync where the terminal
+        // (bug #12): every row boundary must reset the cursor column.
+        // This prevents cursor desync where the terminal
         // autowraps or drifts at row boundaries (especially the bottom rows
         // where phosphor decay writes many ghost cells). Without this, a
         // single-cell "row shift right" glitch can appear transiently at
         // the bottom of the screen and self-correct only when the periodic
-        // full-redraw kicks in (~5 minutes). Forcing MoveTo at each row
-        // start costs ~6 bytes/row (negligible) and eliminates the desync.
+        // full-redraw kicks in (~5 minutes). Every row start resets the
+        // column (CUP or CR+LF), which eliminates the desync.
         let mut i = 0usize;
-        let mut last_row: u16 = u16::MAX;
         while i < dirty_flat.len() {
             let idx0 = dirty_flat[i];
             // Borrow instead of copy: compare with last frame without allocating.
@@ -286,19 +312,8 @@ impl Terminal {
             let bg0 = cell0.bg;
             let bold0 = cell0.bold;
 
-            // (bug #12): force cursor resync at each row boundary.
-            // When we cross from one row to the next, invalidate cur_pos so
-            // a MoveTo is always emitted for the first dirty cell in the
-            // new row. This corrects any terminal-side autowrap or cursor
-            // drift that accumulated during the previous row's run.
-            if y0 != last_row {
-                cur_pos = None;
-                last_row = y0;
-            }
-
             run_buf.clear();
             run_buf.push(cell0.ch);
-            let mut run_len: u16 = 1;
             let mut last_idx_in_run = idx0;
             let mut j = i + 1;
 
@@ -326,20 +341,17 @@ impl Terminal {
                 run_buf.push(cell1_ref.ch);
                 let cell1 = *cell1_ref;
                 last.cells[idx1] = cell1;
-                run_len = run_len.saturating_add(1);
                 last_idx_in_run = idx1;
                 j += 1;
             }
 
-            if cur_pos != Some((x0, y0)) {
-                // MoveTo(x0, y0) directly into ansi_buf
-                ansi_buf.push(0x1b);
-                ansi_buf.push(b'[');
-                push_u16(ansi_buf, y0 + 1);
-                ansi_buf.push(b';');
-                push_u16(ansi_buf, x0 + 1);
-                ansi_buf.push(b'H');
-            }
+            // (bug #12): force cursor resync at each row boundary.
+            // A move to a new row is always CUP or CR+LF (see
+            // `cursor_cost::plan_move`), both of which reset the column,
+            // so drift from the previous row's run cannot carry over. A
+            // run that reached the last column leaves `cur_pos` unknown
+            // (pending wrap), which forces CUP.
+            push_move(ansi_buf, cur_pos, (x0, y0));
 
             // Combined fg+bg SGR — cached when possible
             let style_changed = fg0 != cur_fg || bg0 != cur_bg;
@@ -357,9 +369,9 @@ impl Terminal {
                 cur_bold = bold0;
             }
 
-            // Print run directly into ANSI buffer (UTF-8 bytes).
-            ansi_buf.extend_from_slice(run_buf.as_bytes());
-            let next_x = x0.saturating_add(run_len);
+            // Print run directly into ANSI buffer (UTF-8 bytes, REP/ECH
+            // where cheaper). A trailing ECH parks the cursor early.
+            let next_x = x0.saturating_add(push_run(ansi_buf, run_buf, rep, bg0.is_none(), true));
             cur_pos = if next_x < frame.width {
                 Some((next_x, y0))
             } else {
@@ -372,7 +384,7 @@ impl Terminal {
         // Reset attributes + flush all buffered ANSI bytes in one write_all.
         ansi_buf.extend_from_slice(b"\x1b[0m");
         self.flush_ansi()?;
-        self.stdout.flush()?;
+        self.sink.flush()?;
         frame.clear_dirty();
         Ok(())
     }
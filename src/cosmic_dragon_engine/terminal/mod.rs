@@ -6,6 +6,14 @@
 //! Provides raw mode, alternate screen management, optional mouse capture, and the
 //! core diff-based ANSI rendering pipeline.
 //!
+//! ## Output Sinks
+//!
+//! Frames are written to a boxed [`OutputSink`] rather than a hard-wired
+//! stdout. [`Terminal::with_signal_exit`] uses the stdout sink (raw mode,
+//! input protocols, `/dev/tty` recovery); [`Terminal::with_sink`] drives
+//! the same pipeline into a file, FIFO, Unix socket or in-memory buffer
+//! with a fixed grid size. See `sink.rs`.
+//!
 //! ## Output Strategy
 //!
 //! The terminal uses a 64 KiB buffered writer to batch an entire frame's
@@ -17,7 +25,9 @@
 //!
 //! For differential (non-full) redraws, dirty cells are grouped by row,
 //! sorted, and scanned for contiguous runs of matching style. This produces
-//! minimal cursor movement and style-change overhead.
+//! minimal cursor movement and style-change overhead. Cursor moves and
+//! glyph runs are byte-cost encoded (relative moves, REP, ECH) by
+//! `cursor_cost.rs`.
 //!
 //! ## Terminal Safety
 //!
@@ -28,7 +38,7 @@
 
 #[cfg(unix)]
 use std::io::{stdin, IsTerminal};
-use std::io::{stdout, BufWriter, Result, Stdout, Write};
+use std::io::{stdout, BufWriter, Result, Write};
 #[cfg(unix)]
 use std::process::Command;
 use std::sync::atomic::AtomicBool;
@@ -66,6 +76,8 @@ use crossterm::{
 };
 
 use crate::cell::Cell;
+use crate::chroma_dragon_engine::canvas::LightCanvas;
+use crate::chroma_dragon_engine::quantize::ColorQuantizer;
 use crate::color_cache::ColorCache;
 use crate::constants::{
     MAX_TERMINAL_COLS, MAX_TERMINAL_LINES, MIN_TERMINAL_COLS, MIN_TERMINAL_LINES,
@@ -74,15 +86,25 @@ use crate::constants::{
 };
 use crate::sgr_format::write_sgr_colors_buf;
 use crate::termdetect::TerminalCaps;
-use crate::tier2::{should_backpressure, should_ris_reset, ByteWindow};
+use crate::tier2::{should_backpressure_with, should_ris_reset_with, ByteWindow};
 
 // ── dragon-fight split: sub-modules ──────────────────────────────────────
 // Extracted from this file to keep mod.rs under the 1500-LOC cap and isolate
 // concerns. See each module's docs for its responsibility.
+pub(crate) mod asciicast;
+mod cursor_cost;
+#[cfg(test)]
+mod cursor_cost_tests;
 mod draw;
 mod last_frame;
 #[cfg(test)]
 mod p5_tests;
+pub(crate) mod redraw_cost;
+#[cfg(test)]
+mod redraw_cost_tests;
+pub(crate) mod sink;
+#[cfg(test)]
+mod sink_tests;
 
 // Newly relocated from src/ root (audit M4). Re-exported as `pub(crate)`
 // so the 7 existing `crate::terminal_tty::Foo` / `crate::sgr_format::Foo` /
@@ -92,7 +114,9 @@ pub(crate) mod sgr_format;
 pub(crate) mod terminal_tty;
 pub(crate) mod tier2;
 
+use asciicast::{AsciicastRecorder, RecordingSummary};
 use last_frame::LastFrame;
+pub(crate) use sink::{OutputSink, SinkKind};
 
 /// Buffer size for stdout BufWriter (256 KiB). Large enough to batch an
 /// entire frame's ANSI commands into a single `write()` syscall during
@@ -102,7 +126,14 @@ use last_frame::LastFrame;
 const STDOUT_BUF_CAPACITY: usize = 256 * 1024;
 
 pub(crate) struct Terminal {
-    stdout: BufWriter<Stdout>,
+    sink: BufWriter<Box<dyn OutputSink>>,
+    /// Kind of the boxed sink. Stdout-only behaviors (raw mode,
+    /// `/dev/tty` recovery, fd health probe, mouse capture) are gated
+    /// on `SinkKind::Stdout`.
+    sink_kind: SinkKind,
+    /// Grid size reported by `size()` for non-TTY sinks. `None` for the
+    /// stdout sink, which queries the real terminal.
+    fixed_size: Option<(u16, u16)>,
     last: Option<LastFrame>,
     run_buf: String,
     /// Reusable buffer for full-redraw row batching (avoids per-frame allocation).
@@ -150,6 +181,12 @@ pub(crate) struct Terminal {
     term_caps: TerminalCaps,
     /// Color byte cache for palette colors (built after palette is known).
     color_cache: Option<ColorCache>,
+    /// OKLab quantizer for 256/16-color terminals; `draw` renders its
+    /// quantized mirror of the frame instead of the frame itself.
+    color_quantizer: Option<ColorQuantizer>,
+    /// Lightness inversion for light default backgrounds; applied before
+    /// the quantizer.
+    light_canvas: Option<LightCanvas>,
     /// Cumulative ANSI bytes flushed to stdout across all frames.
     /// Incremented in `flush_ansi()` by `ansi_buf.len()` before clearing.
     /// Used by `--perf-stats` to report average bytes/frame and total bandwidth.
@@ -190,6 +227,10 @@ pub(crate) struct Terminal {
     /// preemptive backpressure when the recent byte rate exceeds the
     /// budget. Only consulted when `term_caps.xtermjs_host` is true.
     byte_window: ByteWindow,
+    /// Tier 2: budgets for the two checks above. Compiled-in defaults
+    /// until the event loop installs the `[power-dragon]` values via
+    /// [`set_power_thresholds`](Self::set_power_thresholds).
+    power_thresholds: crate::constants::PowerThresholds,
     /// Tier 2: # of flushes suppressed by byte-budget backpressure.
     /// Reported in `--perf-stats` exit summary. Reset only by restart.
     backpressure_skips: u64,
@@ -202,6 +243,10 @@ pub(crate) struct Terminal {
     /// otherwise suppression masks itself (no write → stale latency →
     /// no perf_pressure accumulation → self-healer never fires).
     last_flush_suppressed: bool,
+    /// `--record`: asciicast v2 recorder fed with every byte buffer
+    /// `write_with_recovery` delivers successfully. `None` when not
+    /// recording (the common case — one branch per flush).
+    recorder: Option<AsciicastRecorder>,
 }
 
 impl Terminal {
@@ -209,9 +254,7 @@ impl Terminal {
     /// call-site compatibility but is not stored — the event loop keeps
     /// its own Arc<AtomicBool> and polls it directly.
     pub(crate) fn with_signal_exit(_signal_exit: Arc<AtomicBool>) -> Result<Self> {
-        let raw = stdout();
         crossterm_terminal::enable_raw_mode()?;
-        let out = BufWriter::with_capacity(STDOUT_BUF_CAPACITY, raw);
         let term_caps = crate::termdetect::detect();
         // v50 scrollback fix: do NOT emit SYNC_START (\x1b[?2026h) here.
         //
@@ -239,48 +282,9 @@ impl Terminal {
         // LeaveAlternateScreen, so sync mode is closed on the alt screen
         // (where it was opened by the last frame) before switching back
         // to the (untouched) main screen.
-        let mut term = Self {
-            stdout: out,
-            last: None,
-            run_buf: {
-                let mut s = String::new();
-                s.reserve(RENDER_RUN_BUF_INIT_CAP);
-                s
-            },
-            row_buf: String::with_capacity(RENDER_ROW_BUF_INIT_CAP),
-            dirty_flat: Vec::new(),
-            ansi_buf: Vec::with_capacity(STDOUT_BUF_CAPACITY),
-            mouse_capture_enabled: false,
-            focus_change_enabled: false,
-            bracketed_paste_enabled: false,
-            kitty_keyboard_enabled: false,
-            raw_mode_enabled: true,
-            alternate_screen_enabled: false,
-            cursor_hidden: false,
-            line_wrap_disabled: false,
-            cleaned_up: false,
-            shutdown_complete: Arc::new(AtomicBool::new(false)),
-            term_caps,
-            color_cache: None,
-            total_ansi_bytes: 0,
-            flush_count: 0,
-            combined_flush_buf: Vec::with_capacity(RENDER_COMBINED_FLUSH_INIT_CAP),
-            #[cfg(unix)]
-            tty_fallback: None,
-            #[cfg(unix)]
-            tty_recoveries: 0,
-            last_write_ns: 0,
-            bytes_since_ris: 0,
-            byte_window: ByteWindow::with_capacity(
-                crate::constants::XTERMJS_BYTE_BUDGET_WINDOW_FRAMES as usize,
-            ),
-            backpressure_skips: 0,
-            ris_resets: 0,
-            last_flush_suppressed: false,
-        };
-
+        let mut term = Self::from_parts(Box::new(sink::StdoutSink::new()), term_caps, true, None);
         let init_res: Result<()> = (|| {
-            let out = &mut term.stdout;
+            let out = &mut term.sink;
             // Only enter alternate screen if the terminal supports it.
             // The Linux virtual console (TERM=linux) DOES support the
             // alternate screen buffer (\x1b[?1049h) via vt.c since kernel
@@ -352,7 +356,135 @@ impl Terminal {
         Ok(term)
     }
 
+    /// Create a Terminal that renders into an arbitrary [`OutputSink`]
+    /// with a fixed `(cols, lines)` grid.
+    ///
+    /// Raw mode and the input-side protocols (bracketed paste, kitty
+    /// keyboard, mouse capture) are never touched — they describe the
+    /// controlling terminal, not the byte sink. The output-side setup
+    /// (alt screen when `term_caps` allows it, cursor hide, line wrap
+    /// off, SGR reset) and the per-frame SYNC wrapping are identical to
+    /// the stdout path, so the sink sees the production byte stream.
+    pub(crate) fn with_sink(
+        sink: Box<dyn OutputSink>,
+        size: (u16, u16),
+        term_caps: TerminalCaps,
+    ) -> Result<Self> {
+        let size = (
+            size.0.clamp(MIN_TERMINAL_COLS, MAX_TERMINAL_COLS),
+            size.1.clamp(MIN_TERMINAL_LINES, MAX_TERMINAL_LINES),
+        );
+        let mut term = Self::from_parts(sink, term_caps, false, Some(size));
+        let init_res: Result<()> = (|| {
+            let out = &mut term.sink;
+            if term.term_caps.has_alternate_screen {
+                out.execute(crossterm_terminal::EnterAlternateScreen)?;
+                term.alternate_screen_enabled = true;
+            }
+            out.execute(cursor::Hide)?;
+            term.cursor_hidden = true;
+            out.execute(crossterm_terminal::DisableLineWrap)?;
+            term.line_wrap_disabled = true;
+            out.execute(SetAttribute(Attribute::Reset))?;
+            out.execute(ResetColor)?;
+            out.flush()?;
+            Ok(())
+        })();
+        if let Err(e) = init_res {
+            term.cleanup_terminal();
+            return Err(e);
+        }
+        Ok(term)
+    }
+
+    fn from_parts(
+        sink: Box<dyn OutputSink>,
+        term_caps: TerminalCaps,
+        raw_mode_enabled: bool,
+        fixed_size: Option<(u16, u16)>,
+    ) -> Self {
+        let sink_kind = sink.kind();
+        Self {
+            sink: BufWriter::with_capacity(STDOUT_BUF_CAPACITY, sink),
+            sink_kind,
+            fixed_size,
+            last: None,
+            run_buf: {
+                let mut s = String::new();
+                s.reserve(RENDER_RUN_BUF_INIT_CAP);
+                s
+            },
+            row_buf: String::with_capacity(RENDER_ROW_BUF_INIT_CAP),
+            dirty_flat: Vec::new(),
+            ansi_buf: Vec::with_capacity(STDOUT_BUF_CAPACITY),
+            mouse_capture_enabled: false,
+            focus_change_enabled: false,
+            bracketed_paste_enabled: false,
+            kitty_keyboard_enabled: false,
+            raw_mode_enabled,
+            alternate_screen_enabled: false,
+            cursor_hidden: false,
+            line_wrap_disabled: false,
+            cleaned_up: false,
+            shutdown_complete: Arc::new(AtomicBool::new(false)),
+            term_caps,
+            color_cache: None,
+            color_quantizer: None,
+            light_canvas: None,
+            total_ansi_bytes: 0,
+            flush_count: 0,
+            combined_flush_buf: Vec::with_capacity(RENDER_COMBINED_FLUSH_INIT_CAP),
+            #[cfg(unix)]
+            tty_fallback: None,
+            #[cfg(unix)]
+            tty_recoveries: 0,
+            last_write_ns: 0,
+            bytes_since_ris: 0,
+            byte_window: ByteWindow::with_capacity(
+                crate::constants::XTERMJS_BYTE_BUDGET_WINDOW_FRAMES as usize,
+            ),
+            power_thresholds: crate::constants::PowerThresholds::defaults(),
+            backpressure_skips: 0,
+            ris_resets: 0,
+            last_flush_suppressed: false,
+            recorder: None,
+        }
+    }
+
+    /// Start recording flushed frames (`--record`). Replaces any
+    /// previous recorder.
+    pub(crate) fn set_recorder(&mut self, recorder: AsciicastRecorder) {
+        self.recorder = Some(recorder);
+    }
+
+    /// Detach the recorder, e.g. to carry it across a SIGCONT
+    /// terminal re-init.
+    pub(crate) fn take_recorder(&mut self) -> Option<AsciicastRecorder> {
+        self.recorder.take()
+    }
+
+    /// Record an applied grid resize. No-op when not recording.
+    pub(crate) fn record_resize(&mut self, cols: u16, lines: u16) {
+        if let Some(rec) = self.recorder.as_mut() {
+            rec.record_resize(cols, lines);
+        }
+    }
+
+    /// Flush the recorder and return its summary (`None` when not
+    /// recording). Call before dropping the terminal.
+    pub(crate) fn finish_recording(&mut self) -> Option<RecordingSummary> {
+        self.recorder.take().map(AsciicastRecorder::finish)
+    }
+
+    /// Kind of the output sink this terminal writes to.
+    pub(crate) fn sink_kind(&self) -> SinkKind {
+        self.sink_kind
+    }
+
     pub(crate) fn size(&self) -> Result<(u16, u16)> {
+        if let Some(size) = self.fixed_size {
+            return Ok(size);
+        }
         let (w, h) = crossterm_terminal::size()?;
         // Clamp to prevent OOM from misreported terminal sizes
         let w = w.min(MAX_TERMINAL_COLS);
@@ -408,7 +540,7 @@ impl Terminal {
         // and resets xterm.js's buffer.
         if self.term_caps.xtermjs_host {
             let cumulative = self.bytes_since_ris + frame_bytes;
-            if should_ris_reset(cumulative, self.bytes_since_ris) {
+            if should_ris_reset_with(cumulative, self.bytes_since_ris, &self.power_thresholds) {
                 self.emit_ris_reset()?;
                 // bytes_since_ris was reset inside emit_ris_reset; the
                 // upcoming flush's frame_bytes will be added below.
@@ -423,7 +555,7 @@ impl Terminal {
         // window so the budget recovers as old frames age out.
         if self.term_caps.xtermjs_host {
             let window_sum = self.byte_window.sum();
-            if should_backpressure(window_sum, self.bytes_since_ris) {
+            if should_backpressure_with(window_sum, self.bytes_since_ris, &self.power_thresholds) {
                 self.backpressure_skips += 1;
                 self.byte_window.push(0);
                 // signal backpressure so the event loop injects
@@ -538,12 +670,21 @@ impl Terminal {
         // Time the write so the event loop can detect slow downstream
         // terminals. Instant::now() is ~20ns — negligible vs the write.
         let start = std::time::Instant::now();
-        let result = self.stdout.write_all(buf);
+        let result = self.sink.write_all(buf);
         self.last_write_ns = start.elapsed().as_nanos() as u64;
         match result {
-            Ok(()) => Ok(()),
-            Err(e) => self.recover_to_tty(buf, e),
+            Ok(()) => {}
+            // /dev/tty recovery only makes sense when the broken fd was
+            // the controlling terminal; other sinks propagate the error.
+            Err(e) if self.sink_kind == SinkKind::Stdout => self.recover_to_tty(buf, e)?,
+            Err(e) => return Err(e),
+        }
+        // Record whatever reached the screen, including the buffer the
+        // /dev/tty fallback delivered.
+        if let Some(rec) = self.recorder.as_mut() {
+            rec.record_output(buf);
         }
+        Ok(())
     }
 
     /// P3 helper: attempt to recover a failed stdout write by routing the
@@ -636,8 +777,10 @@ impl Terminal {
     pub(crate) fn probe_stdout_health(&mut self) -> bool {
         #[cfg(unix)]
         {
-            use std::io::IsTerminal;
-            if !self.stdout.get_ref().is_terminal() {
+            if self.sink_kind != SinkKind::Stdout {
+                return true;
+            }
+            if !self.sink.get_ref().is_terminal() {
                 // stdout is no longer a tty — synthesize a BrokenPipe
                 // error and reuse the P3 recovery path. The empty
                 // buffer means no data is written to /dev/tty (just
@@ -656,35 +799,84 @@ impl Terminal {
 
     /// Enable mouse capture so mouse events are reported.
     pub(crate) fn enable_mouse_capture(&mut self) -> Result<()> {
-        self.stdout.execute(event::EnableMouseCapture)?;
+        // Mouse reports come from the controlling terminal; a non-TTY
+        // sink has no input side to enable them on.
+        if self.sink_kind != SinkKind::Stdout {
+            return Ok(());
+        }
+        self.sink.execute(event::EnableMouseCapture)?;
         self.mouse_capture_enabled = true;
-        self.stdout.execute(event::EnableFocusChange)?;
+        self.sink.execute(event::EnableFocusChange)?;
         self.focus_change_enabled = true;
-        self.stdout.flush()?;
+        self.sink.flush()?;
         Ok(())
     }
 
     /// Disable mouse capture.
     pub(crate) fn disable_mouse_capture(&mut self) -> Result<()> {
         if self.mouse_capture_enabled {
-            self.stdout.execute(event::DisableMouseCapture)?;
+            self.sink.execute(event::DisableMouseCapture)?;
             self.mouse_capture_enabled = false;
             // Keep the global signal-handler flag in sync so that signal
             // handlers don't issue a redundant DisableMouseCapture later.
             crate::interactive::clear_mouse_capture_flag();
         }
         if self.focus_change_enabled {
-            self.stdout.execute(event::DisableFocusChange)?;
+            self.sink.execute(event::DisableFocusChange)?;
             self.focus_change_enabled = false;
         }
-        self.stdout.flush()?;
+        self.sink.flush()?;
         Ok(())
     }
 
+    /// Install the `[power-dragon]` xterm.js budgets. A changed window
+    /// length rebuilds the (empty) byte window; an unchanged one keeps the
+    /// history so a live reload does not reopen the budget.
+    pub(crate) fn set_power_thresholds(&mut self, thresholds: crate::constants::PowerThresholds) {
+        if thresholds.xtermjs_window_frames != self.power_thresholds.xtermjs_window_frames {
+            self.byte_window = ByteWindow::with_capacity(thresholds.xtermjs_window_frames as usize);
+        }
+        self.power_thresholds = thresholds;
+    }
+
+    /// The installed `[power-dragon]` xterm.js budgets.
+    #[cfg(test)]
+    pub(crate) fn power_thresholds(&self) -> crate::constants::PowerThresholds {
+        self.power_thresholds
+    }
+
     /// Set the color byte cache for this terminal session.
     /// Must be called after the palette is built and before the first draw.
+    ///
+    /// With a light canvas or a quantizer installed the cache is rebuilt
+    /// from the mapped palette, so its entries match the colors `draw`
+    /// actually emits.
     pub(crate) fn set_color_cache(&mut self, cache: ColorCache) {
-        self.color_cache = Some(cache);
+        if self.light_canvas.is_none() && self.color_quantizer.is_none() {
+            self.color_cache = Some(cache);
+            return;
+        }
+        let mut palette = cache.palette();
+        if let Some(canvas) = self.light_canvas.as_mut() {
+            palette = canvas.map_palette(&palette);
+        }
+        if let Some(q) = self.color_quantizer.as_mut() {
+            palette = q.quantize_palette(&palette);
+        }
+        self.color_cache = Some(ColorCache::new(&palette));
+    }
+
+    /// Quantize every drawn frame to a 256/16-color palette (`None` draws
+    /// frames as they are). Install before the first `set_color_cache`.
+    pub(crate) fn set_color_quantizer(&mut self, quantizer: Option<ColorQuantizer>) {
+        self.color_quantizer = quantizer;
+    }
+
+    /// Map frames without a background of their own onto a light terminal
+    /// background (`None` draws them as they are). Install before the
+    /// first `set_color_cache`.
+    pub(crate) fn set_light_canvas(&mut self, canvas: Option<LightCanvas>) {
+        self.light_canvas = canvas;
     }
 
     /// Return encoding statistics as `(total_ansi_bytes, flush_count, sgr_hits, sgr_misses)`.
@@ -778,7 +970,7 @@ impl Terminal {
 
         let _ = self.disable_mouse_capture();
         if self.bracketed_paste_enabled {
-            let _ = self.stdout.execute(event::DisableBracketedPaste);
+            let _ = self.sink.execute(event::DisableBracketedPaste);
             self.bracketed_paste_enabled = false;
         }
         // Kitty keyboard protocol pop: must happen before
@@ -797,17 +989,17 @@ impl Terminal {
         // to avoid emitting `CSI <1u` to terminals that never got a
         // push (which could otherwise misinterpret the bytes).
         if self.kitty_keyboard_enabled {
-            let _ = self.stdout.execute(event::PopKeyboardEnhancementFlags);
+            let _ = self.sink.execute(event::PopKeyboardEnhancementFlags);
             self.kitty_keyboard_enabled = false;
         }
-        let _ = self.stdout.execute(SetAttribute(Attribute::Reset));
-        let _ = self.stdout.execute(ResetColor);
+        let _ = self.sink.execute(SetAttribute(Attribute::Reset));
+        let _ = self.sink.execute(ResetColor);
         if self.cursor_hidden {
-            let _ = self.stdout.execute(cursor::Show);
+            let _ = self.sink.execute(cursor::Show);
             self.cursor_hidden = false;
         }
         if self.line_wrap_disabled {
-            let _ = self.stdout.execute(crossterm_terminal::EnableLineWrap);
+            let _ = self.sink.execute(crossterm_terminal::EnableLineWrap);
             self.line_wrap_disabled = false;
         }
 
@@ -846,7 +1038,7 @@ impl Terminal {
                 // open on the alt screen. Close it here BEFORE leaving the
                 // alt screen, so the main screen is never touched by a
                 // sync-end sequence.
-                let _ = self.stdout.write_all(crate::termdetect::SYNC_END);
+                let _ = self.sink.write_all(crate::termdetect::SYNC_END);
             }
             // v50 TTY scrollback fix: ALWAYS flush before LeaveAlternateScreen,
             // not just when sync_output is true. On TTY terminals (and
@@ -860,10 +1052,8 @@ impl Terminal {
             // Flushing here ensures ALL pending content is sent to the alt
             // screen BEFORE the screen switch, so the main screen is
             // untouched when LeaveAlternateScreen reveals it.
-            let _ = self.stdout.flush();
-            let _ = self
-                .stdout
-                .execute(crossterm_terminal::LeaveAlternateScreen);
+            let _ = self.sink.flush();
+            let _ = self.sink.execute(crossterm_terminal::LeaveAlternateScreen);
             self.alternate_screen_enabled = false;
 
             // v50 stabilization: defensive post-LeaveAlternateScreen
@@ -883,9 +1073,9 @@ impl Terminal {
             // a brief window where the terminal is in raw mode but showing
             // the main screen (user input might not be echoed during this
             // window).
-            let (_w, h) = crossterm_terminal::size().unwrap_or((80, 24));
-            let _ = self.stdout.execute(cursor::MoveTo(0, h.saturating_sub(1)));
-            let _ = self.stdout.flush();
+            let h = self.cleanup_rows();
+            let _ = self.sink.execute(cursor::MoveTo(0, h.saturating_sub(1)));
+            let _ = self.sink.flush();
         } else if !self.term_caps.has_alternate_screen {
             // No alternate screen was entered (terminal doesn't support it).
             // We ran on the main screen directly. Scrollback-safe exit:
@@ -895,14 +1085,25 @@ impl Terminal {
             // so the shell prompt appears on a clean line. The rain
             // rendering scrolls into scrollback naturally, which is the
             // expected behavior — previous command output is preserved.
-            let (_w, h) = crossterm_terminal::size().unwrap_or((80, 24));
-            let _ = self.stdout.execute(cursor::MoveTo(0, h.saturating_sub(1)));
+            let h = self.cleanup_rows();
+            let _ = self.sink.execute(cursor::MoveTo(0, h.saturating_sub(1)));
         }
         if self.raw_mode_enabled {
             let _ = crossterm_terminal::disable_raw_mode();
             self.raw_mode_enabled = false;
         }
-        let _ = self.stdout.flush();
+        let _ = self.sink.flush();
+    }
+}
+
+impl Terminal {
+    /// Row count used for the final cursor placement in cleanup: the
+    /// fixed grid for non-TTY sinks, the live terminal size otherwise.
+    fn cleanup_rows(&self) -> u16 {
+        match self.fixed_size {
+            Some((_, h)) => h,
+            None => crossterm_terminal::size().unwrap_or((80, 24)).1,
+        }
     }
 }
 
@@ -102,8 +102,8 @@ fn p5_is_terminal_returns_false_for_non_tty_files() {
         .expect("/dev/null should be openable on Unix");
 
     // std::fs::File implements IsTerminal since Rust 1.70.
-    // probe_stdout_health calls self.stdout.get_ref().is_terminal()
-    // where stdout is BufWriter<Stdout> and get_ref() returns &Stdout.
+    // probe_stdout_health calls self.sink.get_ref().is_terminal()
+    // where get_ref() returns the boxed StdoutSink wrapping Stdout.
     // Stdout's is_terminal() uses the same trait, so testing it on
     // File validates the same codepath.
     assert!(
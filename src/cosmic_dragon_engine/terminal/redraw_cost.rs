@@ -0,0 +1,255 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Per-frame byte-cost model for the full-vs-diff redraw decision.
+//!
+//! `DIRTY_THRESHOLD_RATIO` picked the path from the dirty fraction alone
+//! (≥12.5% dirty → full redraw). The `threshold_sweep` egg showed the real
+//! crossover moves with terminal size and style density: a sparse frame
+//! full of blanks is cheap to redraw (ECH, CR+LF), while a dense
+//! multi-colour frame pays an SGR per style run either way. Since
+//! `cursor_cost.rs` made both paths' output data-dependent, a fixed ratio
+//! can no longer track it.
+//!
+//! Instead both paths are walked without writing anything, using the same
+//! cost functions the encoder uses (`plan_move`, `plan_segment`,
+//! `sgr_colors_len`, the bold escape table), so the estimate matches the
+//! emitted byte count exactly except for SGR cache entries (same bytes as
+//! `write_sgr_colors_buf`) and the one-off resize/clear preamble.
+//!
+//! Cost: the diff walk is O(dirty). The full walk is O(cells) but
+//! [`prefers_full_redraw`] stops it as soon as it passes the diff cost,
+//! so on typical sparse frames it reads only the first few rows.
+
+use crossterm::style::Color;
+
+use crate::bolt::BOLD_ESCAPE_LENS;
+use crate::cell::Cell;
+use crate::constants::DIRTY_THRESHOLD_RATIO;
+use crate::frame::Frame;
+use crate::sgr_format::sgr_colors_len;
+
+use super::cursor_cost::{plan_move, plan_segment, segment_advance};
+
+/// `\x1b[0m` appended to every drawn frame.
+const RESET_LEN: usize = 4;
+
+/// Render path `Terminal::draw` takes for a frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum RedrawPath {
+    /// Every cell, row by row.
+    Full,
+    /// Only cells that changed since the last frame.
+    Diff,
+}
+
+/// Estimated bytes for both render paths of one frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct RedrawEstimate {
+    pub full_bytes: usize,
+    pub diff_bytes: usize,
+}
+
+impl RedrawEstimate {
+    /// Cheaper path; ties go to the full redraw, which resyncs every cell.
+    #[must_use]
+    pub(crate) fn path(self) -> RedrawPath {
+        if self.diff_bytes < self.full_bytes {
+            RedrawPath::Diff
+        } else {
+            RedrawPath::Full
+        }
+    }
+
+    /// Bytes of the chosen path.
+    #[must_use]
+    pub(crate) fn chosen_bytes(self) -> usize {
+        self.full_bytes.min(self.diff_bytes)
+    }
+
+    /// Bytes saved against the path the fixed `DIRTY_THRESHOLD_RATIO`
+    /// rule would have taken for `dirty_count` of `total_cells`.
+    #[must_use]
+    pub(crate) fn saved_vs_threshold(self, dirty_count: usize, total_cells: usize) -> usize {
+        let threshold_bytes = match threshold_path(dirty_count, total_cells) {
+            RedrawPath::Full => self.full_bytes,
+            RedrawPath::Diff => self.diff_bytes,
+        };
+        threshold_bytes - self.chosen_bytes()
+    }
+}
+
+/// The pre-cost-model rule: full redraw at ≥ 1/`DIRTY_THRESHOLD_RATIO`
+/// dirty cells.
+#[must_use]
+pub(crate) fn threshold_path(dirty_count: usize, total_cells: usize) -> RedrawPath {
+    if total_cells > 0 && dirty_count >= total_cells / DIRTY_THRESHOLD_RATIO {
+        RedrawPath::Full
+    } else {
+        RedrawPath::Diff
+    }
+}
+
+/// Exact estimate of both paths. `sorted_dirty` must be ascending;
+/// `last` is what the terminal already shows (`None` treats every dirty
+/// cell as changed, as the benchmark does).
+#[must_use]
+pub(crate) fn estimate_redraw(
+    frame: &Frame,
+    sorted_dirty: &[usize],
+    last: Option<&[Cell]>,
+    rep: bool,
+) -> RedrawEstimate {
+    RedrawEstimate {
+        full_bytes: full_cost(frame, rep, usize::MAX),
+        diff_bytes: diff_cost(frame, sorted_dirty, last, rep),
+    }
+}
+
+/// `true` when the full redraw is no more bytes than the diff. Same
+/// answer as `estimate_redraw(..).path() == Full`, but the full walk
+/// stops once it passes the diff cost.
+#[must_use]
+pub(crate) fn prefers_full_redraw(
+    frame: &Frame,
+    sorted_dirty: &[usize],
+    last: Option<&[Cell]>,
+    rep: bool,
+) -> bool {
+    let diff = diff_cost(frame, sorted_dirty, last, rep);
+    full_cost(frame, rep, diff) <= diff
+}
+
+/// SGR state as the render loop tracks it; both paths start a frame
+/// with nothing emitted.
+#[derive(Default)]
+struct Pen {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    bold: bool,
+}
+
+impl Pen {
+    #[inline]
+    fn differs(&self, cell: &Cell) -> bool {
+        cell.fg != self.fg || cell.bg != self.bg || cell.bold != self.bold
+    }
+
+    /// Bytes to switch to `cell`'s style (colour SGR and/or bold toggle).
+    #[inline]
+    fn switch_to(&mut self, cell: &Cell) -> usize {
+        let mut n = 0;
+        if cell.fg != self.fg || cell.bg != self.bg {
+            n += sgr_colors_len(cell.fg, cell.bg);
+            self.fg = cell.fg;
+            self.bg = cell.bg;
+        }
+        if cell.bold != self.bold {
+            n += BOLD_ESCAPE_LENS[cell.bold as usize];
+            self.bold = cell.bold;
+        }
+        n
+    }
+}
+
+/// A streak of identical glyphs within one style run.
+struct Streak {
+    ch: char,
+    len: u16,
+    x: u16,
+}
+
+impl Streak {
+    fn new(ch: char, x: u16) -> Self {
+        Self { ch, len: 1, x }
+    }
+
+    /// Bytes of a streak followed by more of the run, drawn with `pen`.
+    fn cost(&self, rep: bool, pen: &Pen) -> usize {
+        plan_segment(self.ch, self.len, rep, pen.bg.is_none(), false).1
+    }
+
+    /// Bytes of the run's last streak and the column the cursor ends on.
+    fn finish(&self, rep: bool, pen: &Pen) -> (usize, u16) {
+        let (segment, bytes) = plan_segment(self.ch, self.len, rep, pen.bg.is_none(), true);
+        (bytes, self.x + segment_advance(segment, self.len, true))
+    }
+}
+
+/// Bytes of the full-redraw path, or the first partial sum over `limit`.
+fn full_cost(frame: &Frame, rep: bool, limit: usize) -> usize {
+    let width = frame.width;
+    let mut pen = Pen::default();
+    let mut pos = None;
+    let mut cost = RESET_LEN;
+    for y in 0..frame.height {
+        cost += plan_move(pos, (0, y)).cost();
+        let row = y as usize * width as usize;
+        let first = frame.cell_at_index_ref(row);
+        cost += pen.switch_to(first);
+        let mut streak = Streak::new(first.ch, 0);
+        for x in 1..width {
+            let cell = frame.cell_at_index_ref(row + x as usize);
+            let restyle = pen.differs(cell);
+            if restyle || cell.ch != streak.ch {
+                cost += streak.cost(rep, &pen);
+                streak = Streak::new(cell.ch, x);
+                cost += pen.switch_to(cell);
+            } else {
+                streak.len += 1;
+            }
+        }
+        let (bytes, end) = streak.finish(rep, &pen);
+        cost += bytes;
+        pos = (end < width).then_some((end, y));
+        if cost > limit {
+            return cost;
+        }
+    }
+    cost
+}
+
+/// Bytes of the differential path over `sorted_dirty`.
+fn diff_cost(frame: &Frame, sorted_dirty: &[usize], last: Option<&[Cell]>, rep: bool) -> usize {
+    let width = frame.width as usize;
+    let changed = |i: usize| last.is_none_or(|l| l[i] != *frame.cell_at_index_ref(i));
+    let mut pen = Pen::default();
+    let mut pos = None;
+    let mut cost = RESET_LEN;
+    let mut i = 0;
+    while i < sorted_dirty.len() {
+        let idx0 = sorted_dirty[i];
+        if !changed(idx0) {
+            i += 1;
+            continue;
+        }
+        let cell0 = frame.cell_at_index_ref(idx0);
+        let (x0, y0) = ((idx0 % width) as u16, (idx0 / width) as u16);
+        cost += plan_move(pos, (x0, y0)).cost();
+        cost += pen.switch_to(cell0);
+        let mut streak = Streak::new(cell0.ch, x0);
+        let mut j = i + 1;
+        while j < sorted_dirty.len() {
+            let idx1 = sorted_dirty[j];
+            if idx1 != sorted_dirty[j - 1] + 1 || idx1 / width != idx0 / width || !changed(idx1) {
+                break;
+            }
+            let cell1 = frame.cell_at_index_ref(idx1);
+            if pen.differs(cell1) {
+                break;
+            }
+            if cell1.ch == streak.ch {
+                streak.len += 1;
+            } else {
+                cost += streak.cost(rep, &pen);
+                streak = Streak::new(cell1.ch, (idx1 % width) as u16);
+            }
+            j += 1;
+        }
+        let (bytes, end) = streak.finish(rep, &pen);
+        cost += bytes;
+        pos = (end < frame.width).then_some((end, y0));
+        i = j;
+    }
+    cost
+}
@@ -0,0 +1,182 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Redraw cost-model tests.
+//!
+//! Declared as `#[cfg(test)] mod redraw_cost_tests;` in `terminal/mod.rs`.
+//! The model is only useful if its byte counts match what `draw` really
+//! writes, so the main test drives `Terminal::draw` into a `MemorySink`
+//! and compares the chosen estimate with the bytes that came out.
+
+use super::redraw_cost::{estimate_redraw, threshold_path, RedrawEstimate, RedrawPath};
+use super::sgr_format::{sgr_colors_len, write_sgr_colors_buf};
+use super::sink::MemorySink;
+use super::*;
+use crate::frame::Frame;
+
+fn caps() -> TerminalCaps {
+    TerminalCaps {
+        sync_output: false,
+        kitty_keyboard: false,
+        has_alternate_screen: true,
+        xtermjs_host: false,
+        vscode_integrated: false,
+        default_fps_cap: 240.0,
+        dynamic_default_fps: 60.0,
+        dynamic_fps_source: "test",
+        repeat_char: true,
+    }
+}
+
+fn cell(ch: char, g: u8, bold: bool) -> Cell {
+    Cell {
+        ch,
+        fg: Some(Color::Rgb { r: 0, g, b: 0 }),
+        bg: None,
+        bold,
+    }
+}
+
+fn lcg(state: &mut u64) -> u64 {
+    *state = state
+        .wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407);
+    *state >> 33
+}
+
+fn sorted_dirty(frame: &Frame) -> Vec<usize> {
+    let mut dirty = frame.dirty_indices().to_vec();
+    dirty.sort_unstable();
+    dirty
+}
+
+#[test]
+fn sgr_len_matches_formatter() {
+    let colors = [
+        None,
+        Some(Color::Reset),
+        Some(Color::Rgb { r: 0, g: 0, b: 0 }),
+        Some(Color::Rgb {
+            r: 9,
+            g: 10,
+            b: 255,
+        }),
+        Some(Color::AnsiValue(7)),
+        Some(Color::AnsiValue(200)),
+        Some(Color::Green),
+        Some(Color::Black),
+        Some(Color::DarkCyan),
+        Some(Color::White),
+    ];
+    for fg in colors {
+        for bg in colors {
+            let mut buf = Vec::new();
+            write_sgr_colors_buf(&mut buf, fg, bg);
+            assert_eq!(sgr_colors_len(fg, bg), buf.len(), "{fg:?} / {bg:?}");
+        }
+    }
+}
+
+#[test]
+fn threshold_rule_and_savings() {
+    assert_eq!(threshold_path(99, 800), RedrawPath::Diff);
+    assert_eq!(threshold_path(100, 800), RedrawPath::Full);
+    let est = RedrawEstimate {
+        full_bytes: 900,
+        diff_bytes: 400,
+    };
+    assert_eq!(est.path(), RedrawPath::Diff);
+    assert_eq!(est.chosen_bytes(), 400);
+    // The old rule would have taken the 900-byte full redraw.
+    assert_eq!(est.saved_vs_threshold(100, 800), 500);
+    assert_eq!(est.saved_vs_threshold(10, 800), 0);
+    let tie = RedrawEstimate {
+        full_bytes: 400,
+        diff_bytes: 400,
+    };
+    assert_eq!(tie.path(), RedrawPath::Full);
+}
+
+/// Every frame, the chosen estimate equals the bytes `draw` emits.
+#[test]
+fn estimate_matches_emitted_bytes() {
+    let (w, h) = (48u16, 14u16);
+    let mem = MemorySink::new();
+    let mut term = Terminal::with_sink(Box::new(mem.clone()), (w, h), caps()).unwrap();
+    let mut frame = Frame::new(w, h, None);
+    frame.set(0, 0, cell('A', 255, false));
+    term.draw(&mut frame).unwrap();
+    mem.take();
+
+    let mut seed = 0xc057_u64;
+    let mut seen = [false; 2];
+    for step in 0..200u32 {
+        // Every few steps repaint the whole frame in a fresh shade, so
+        // every cell changes and the full redraw wins; otherwise scatter
+        // a few streaks for the diff path.
+        if step.is_multiple_of(5) {
+            let shade = 1 + step as u8 % 30;
+            for y in 0..h {
+                for x in 0..w {
+                    let ch = ['ｱ', 'B', ' '][(lcg(&mut seed) % 3) as usize];
+                    frame.set(x, y, cell(ch, shade, y.is_multiple_of(4)));
+                }
+            }
+        }
+        for _ in 0..6 {
+            let x = (lcg(&mut seed) % w as u64) as u16;
+            let y = (lcg(&mut seed) % h as u64) as u16;
+            let ch = ['ｱ', 'B', ' ', '7'][(lcg(&mut seed) % 4) as usize];
+            let shade = [40, 120, 255][(lcg(&mut seed) % 3) as usize];
+            let bold = lcg(&mut seed).is_multiple_of(5);
+            let len = (lcg(&mut seed) % 10) as u16 + 1;
+            for dx in 0..len.min(w - x) {
+                frame.set(x + dx, y, cell(ch, shade, bold));
+            }
+        }
+        if frame.dirty_indices().is_empty() {
+            continue;
+        }
+        let dirty = sorted_dirty(&frame);
+        let last = term.last.as_ref().unwrap().cells.clone();
+        let est = estimate_redraw(&frame, &dirty, Some(&last), true);
+        seen[(est.path() == RedrawPath::Diff) as usize] = true;
+
+        term.draw(&mut frame).unwrap();
+        let out = mem.take();
+        assert_eq!(out.len(), est.chosen_bytes(), "step {step}: {est:?}");
+    }
+    assert!(
+        seen[0] && seen[1],
+        "both paths should be chosen at least once"
+    );
+}
+
+/// A frame where the old 12.5% rule and the cost model disagree: many
+/// dirty cells, but in long identical streaks that REP makes cheap to
+/// patch, next to a busy background a full redraw would have to resend.
+#[test]
+fn cost_model_beats_threshold_on_streaky_frames() {
+    let (w, h) = (40u16, 10u16);
+    let mut frame = Frame::new(w, h, None);
+    for y in 0..h {
+        for x in 0..w {
+            frame.set(
+                x,
+                y,
+                cell(char::from(b'a' + (x % 26) as u8), (x * 6) as u8, false),
+            );
+        }
+    }
+    frame.clear_dirty();
+    for x in 0..w {
+        frame.set(x, 4, cell('Z', 255, true));
+        frame.set(x, 5, cell('Z', 255, true));
+    }
+    let dirty = sorted_dirty(&frame);
+    let total = w as usize * h as usize;
+    assert_eq!(threshold_path(dirty.len(), total), RedrawPath::Full);
+    let est = estimate_redraw(&frame, &dirty, None, true);
+    assert_eq!(est.path(), RedrawPath::Diff, "{est:?}");
+    assert!(est.saved_vs_threshold(dirty.len(), total) > 0);
+}
@@ -32,6 +32,33 @@ pub(crate) fn push_u16(buf: &mut Vec<u8>, n: u16) {
     crate::bolt::push_u16(buf, n);
 }
 
+/// SGR foreground parameter for the 16 named ANSI colours (30–37 and
+/// 90–97); the background parameter is this plus 10. `None` for `Reset`,
+/// `Rgb` and `AnsiValue`. The 16-colour quantizer outputs these.
+#[inline]
+#[must_use]
+pub(crate) const fn named_fg_code(color: Color) -> Option<u8> {
+    Some(match color {
+        Color::Black => 30,
+        Color::DarkRed => 31,
+        Color::DarkGreen => 32,
+        Color::DarkYellow => 33,
+        Color::DarkBlue => 34,
+        Color::DarkMagenta => 35,
+        Color::DarkCyan => 36,
+        Color::Grey => 37,
+        Color::DarkGrey => 90,
+        Color::Red => 91,
+        Color::Green => 92,
+        Color::Yellow => 93,
+        Color::Blue => 94,
+        Color::Magenta => 95,
+        Color::Cyan => 96,
+        Color::White => 97,
+        _ => return None,
+    })
+}
+
 /// Write combined fg+bg SGR escape sequence directly into buf.
 /// Produces `\x1b[38;2;r;g;b;48;2;r;g;bm` (or subset for Reset/None).
 /// Bypasses crossterm trait dispatch + fmt machinery + heap String alloc.
@@ -58,7 +85,12 @@ pub(crate) fn write_sgr_colors_buf(buf: &mut Vec<u8>, fg: Option<Color>, bg: Opt
             buf.extend_from_slice(b"39");
             first = false;
         }
-        _ => {} // named colors: skip (rare in production TrueColor mode)
+        Some(c) => {
+            if let Some(code) = named_fg_code(c) {
+                push_u8(buf, code);
+                first = false;
+            }
+        }
     }
     match bg {
         Some(Color::Rgb { r, g, b }) => {
@@ -85,7 +117,38 @@ pub(crate) fn write_sgr_colors_buf(buf: &mut Vec<u8>, fg: Option<Color>, bg: Opt
             }
             buf.extend_from_slice(b"49");
         }
-        _ => {} // named colors: skip
+        Some(c) => {
+            if let Some(code) = named_fg_code(c) {
+                if !first {
+                    buf.push(b';');
+                }
+                push_u8(buf, code + 10);
+            }
+        }
     }
     buf.extend_from_slice(b"m");
 }
+
+/// Exact byte length of what [`write_sgr_colors_buf`] emits for `(fg, bg)`,
+/// without formatting anything. Used by the redraw cost model.
+#[must_use]
+pub(crate) fn sgr_colors_len(fg: Option<Color>, bg: Option<Color>) -> usize {
+    fn u8_len(n: u8) -> usize {
+        match n {
+            0..=9 => 1,
+            10..=99 => 2,
+            _ => 3,
+        }
+    }
+    fn part(c: Option<Color>, bg: bool) -> Option<usize> {
+        match c {
+            Some(Color::Rgb { r, g, b }) => Some(7 + u8_len(r) + u8_len(g) + u8_len(b)),
+            Some(Color::AnsiValue(v)) => Some(5 + u8_len(v)),
+            Some(Color::Reset) | None => Some(2),
+            Some(c) => named_fg_code(c).map(|code| u8_len(code + 10 * u8::from(bg))),
+        }
+    }
+    let fg = part(fg, false);
+    let bg = part(bg, true).map(|n| n + usize::from(fg.is_some()));
+    3 + fg.unwrap_or(0) + bg.unwrap_or(0)
+}
@@ -0,0 +1,267 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Pluggable output sinks for [`Terminal`](super::Terminal).
+//!
+//! The diff/RLE/SGR pipeline in `draw.rs` only needs a byte sink. Before
+//! this module the sink was hard-wired to `BufWriter<Stdout>`, so the only
+//! way to drive the real renderer without a TTY was the fake `--bench-io`
+//! writer (which mirrors `Terminal::draw` but is a separate code path).
+//!
+//! ## Sinks
+//!
+//! | Sink | Target | Terminal modes |
+//! |------|--------|----------------|
+//! | [`StdoutSink`] | process stdout (default) | raw mode + alt screen + input protocols |
+//! | [`FileSink`] | regular file, FIFO, pipe, char device | alt screen + cursor only |
+//! | [`UnixSocketSink`] | connected `AF_UNIX` stream (Unix only) | alt screen + cursor only |
+//! | [`MemorySink`] | shared in-memory buffer (test harnesses) | alt screen + cursor only |
+//!
+//! Only the stdout sink touches the controlling terminal (raw mode, mouse
+//! capture, bracketed paste, kitty keyboard, `/dev/tty` recovery). Every
+//! other sink receives exactly the bytes a real terminal would receive for
+//! the frames themselves — alt-screen enter/leave, cursor hide/show, and
+//! the per-frame SYNC-wrapped diff — so a remote display or a golden test
+//! sees the production byte stream.
+//!
+//! The interactive loop uses [`StdoutSink`]; `--render-format stream`
+//! (`headless::stream_frames`) opens the others through [`open_sink`];
+//! [`MemorySink`] exists for tests.
+
+use std::fs::File;
+use std::io::{IsTerminal, Result, Stdout, Write};
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+#[cfg(test)]
+use std::sync::{Arc, Mutex};
+
+/// Which concrete sink a [`Terminal`](super::Terminal) is writing to.
+///
+/// Used to gate the stdout-only behaviors (raw mode, `/dev/tty` fallback,
+/// fd health probe) and to label the sink in verbose / perf output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SinkKind {
+    Stdout,
+    File,
+    UnixSocket,
+    #[cfg(test)]
+    Memory,
+}
+
+impl SinkKind {
+    /// Short lowercase label for diagnostics.
+    #[must_use]
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            SinkKind::Stdout => "stdout",
+            SinkKind::File => "file",
+            SinkKind::UnixSocket => "unix-socket",
+            #[cfg(test)]
+            SinkKind::Memory => "memory",
+        }
+    }
+}
+
+/// Byte sink the terminal renderer writes ANSI frames into.
+///
+/// `Send` so a sink can be handed to a recorder or watchdog thread.
+pub(crate) trait OutputSink: Write + Send {
+    /// Concrete sink kind (see [`SinkKind`]).
+    fn kind(&self) -> SinkKind;
+
+    /// True when the sink is an interactive terminal. Only meaningful for
+    /// [`StdoutSink`]; every other sink reports `false`.
+    fn is_terminal(&self) -> bool {
+        false
+    }
+}
+
+/// Default sink: the process stdout.
+pub(crate) struct StdoutSink(Stdout);
+
+impl StdoutSink {
+    #[must_use]
+    pub(crate) fn new() -> Self {
+        Self(std::io::stdout())
+    }
+}
+
+impl Write for StdoutSink {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.0.write(buf)
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.0.write_all(buf)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.0.flush()
+    }
+}
+
+impl OutputSink for StdoutSink {
+    fn kind(&self) -> SinkKind {
+        SinkKind::Stdout
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.0.is_terminal()
+    }
+}
+
+/// File-descriptor sink: regular file, FIFO (named pipe), anonymous pipe
+/// or character device. Opened write-only; regular files are truncated.
+pub(crate) struct FileSink(File);
+
+impl FileSink {
+    /// Open `path` for writing. Regular files are created / truncated;
+    /// existing FIFOs and devices are opened as-is (opening a FIFO blocks
+    /// until a reader attaches, which is the expected pipe semantics).
+    pub(crate) fn open(path: &Path) -> Result<Self> {
+        let is_special = std::fs::metadata(path).is_ok_and(|m| !m.is_file());
+        let file = if is_special {
+            std::fs::OpenOptions::new().write(true).open(path)?
+        } else {
+            File::create(path)?
+        };
+        Ok(Self(file))
+    }
+}
+
+impl Write for FileSink {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.0.flush()
+    }
+}
+
+impl OutputSink for FileSink {
+    fn kind(&self) -> SinkKind {
+        SinkKind::File
+    }
+}
+
+/// Connected Unix domain stream socket (e.g. a display daemon that feeds
+/// the bytes into its own PTY). Unix only.
+#[cfg(unix)]
+pub(crate) struct UnixSocketSink(UnixStream);
+
+#[cfg(unix)]
+impl UnixSocketSink {
+    /// Connect to the listening socket at `path`.
+    pub(crate) fn connect(path: &Path) -> Result<Self> {
+        Ok(Self(UnixStream::connect(path)?))
+    }
+}
+
+#[cfg(unix)]
+impl Write for UnixSocketSink {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.0.flush()
+    }
+}
+
+#[cfg(unix)]
+impl OutputSink for UnixSocketSink {
+    fn kind(&self) -> SinkKind {
+        SinkKind::UnixSocket
+    }
+}
+
+/// In-memory sink. Clones share the same buffer, so a test keeps one
+/// clone and hands the other to [`Terminal::with_sink`](super::Terminal::with_sink).
+#[cfg(test)]
+#[derive(Clone, Default)]
+pub(crate) struct MemorySink {
+    buf: Arc<Mutex<Vec<u8>>>,
+}
+
+#[cfg(test)]
+impl MemorySink {
+    #[must_use]
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Copy of everything written so far.
+    #[must_use]
+    pub(crate) fn contents(&self) -> Vec<u8> {
+        self.buf.lock().map(|b| b.clone()).unwrap_or_default()
+    }
+
+    /// Drain and return everything written so far.
+    pub(crate) fn take(&self) -> Vec<u8> {
+        self.buf
+            .lock()
+            .map(|mut b| std::mem::take(&mut *b))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+impl Write for MemorySink {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        match self.buf.lock() {
+            Ok(mut b) => {
+                b.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+            Err(_) => Err(std::io::Error::other("memory sink poisoned")),
+        }
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+impl OutputSink for MemorySink {
+    fn kind(&self) -> SinkKind {
+        SinkKind::Memory
+    }
+}
+
+/// Open a sink from a user-supplied target string.
+///
+/// - `-` → stdout
+/// - an existing Unix domain socket path → [`UnixSocketSink`] (Unix only)
+/// - anything else → [`FileSink`] (file / FIFO / device), after
+///   `check_file` accepts the path — opening it truncates a regular file,
+///   so the caller decides which paths may be written.
+///
+/// Open errors carry the target in their message.
+pub(crate) fn open_sink(
+    target: &str,
+    check_file: impl FnOnce(&Path) -> Result<()>,
+) -> Result<Box<dyn OutputSink>> {
+    if target == "-" {
+        return Ok(Box::new(StdoutSink::new()));
+    }
+    let path = Path::new(target);
+    let with_target = |e: std::io::Error| std::io::Error::new(e.kind(), format!("{target}: {e}"));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::FileTypeExt;
+        if std::fs::metadata(path).is_ok_and(|m| m.file_type().is_socket()) {
+            return Ok(Box::new(
+                UnixSocketSink::connect(path).map_err(with_target)?,
+            ));
+        }
+    }
+    check_file(path)?;
+    Ok(Box::new(FileSink::open(path).map_err(with_target)?))
+}
@@ -0,0 +1,181 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Output-sink tests — drive the real `Terminal::draw` pipeline into a
+//! `MemorySink` / `FileSink` instead of stdout.
+//!
+//! Declared as `#[cfg(test)] mod sink_tests;` in `terminal/mod.rs`.
+//! Before the sink abstraction a full `Terminal` could not be built in
+//! unit tests (see `p5_tests.rs`); `Terminal::with_sink` never touches
+//! raw mode, so these run headless.
+
+use super::sink::{FileSink, MemorySink};
+use super::*;
+use crate::frame::Frame;
+
+fn caps(sync_output: bool, has_alternate_screen: bool) -> TerminalCaps {
+    TerminalCaps {
+        sync_output,
+        kitty_keyboard: false,
+        has_alternate_screen,
+        xtermjs_host: false,
+        vscode_integrated: false,
+        default_fps_cap: 240.0,
+        dynamic_default_fps: 60.0,
+        dynamic_fps_source: "test",
+        repeat_char: true,
+    }
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|w| w == needle)
+}
+
+fn glyph(ch: char) -> Cell {
+    Cell {
+        ch,
+        fg: Some(Color::Rgb { r: 0, g: 255, b: 0 }),
+        bg: None,
+        bold: false,
+    }
+}
+
+#[test]
+fn with_sink_reports_fixed_size_and_kind() {
+    let mem = MemorySink::new();
+    let term = Terminal::with_sink(Box::new(mem), (40, 12), caps(false, true)).unwrap();
+    assert_eq!(term.size().unwrap(), (40, 12));
+    assert_eq!(term.sink_kind(), SinkKind::Memory);
+    assert_eq!(term.sink_kind().label(), "memory");
+}
+
+#[test]
+fn with_sink_clamps_degenerate_size() {
+    let term =
+        Terminal::with_sink(Box::new(MemorySink::new()), (0, 0), caps(false, false)).unwrap();
+    assert_eq!(
+        term.size().unwrap(),
+        (MIN_TERMINAL_COLS, MIN_TERMINAL_LINES)
+    );
+}
+
+/// Init enters the alt screen and hides the cursor but never emits the
+/// input-side protocols (bracketed paste, kitty keyboard).
+#[test]
+fn with_sink_init_emits_output_modes_only() {
+    let mem = MemorySink::new();
+    let _term = Terminal::with_sink(Box::new(mem.clone()), (20, 5), caps(false, true)).unwrap();
+    let init = mem.contents();
+    assert!(contains(&init, b"\x1b[?1049h"), "alt screen enter missing");
+    assert!(contains(&init, b"\x1b[?25l"), "cursor hide missing");
+    assert!(
+        !contains(&init, b"\x1b[?2004h"),
+        "bracketed paste is input-side"
+    );
+    assert!(
+        !contains(&init, b"\x1b[>1u"),
+        "kitty keyboard is input-side"
+    );
+}
+
+#[test]
+fn draw_writes_glyphs_into_memory_sink() {
+    let mem = MemorySink::new();
+    let mut term = Terminal::with_sink(Box::new(mem.clone()), (20, 5), caps(false, true)).unwrap();
+    mem.take();
+    let mut frame = Frame::new(20, 5, None);
+    frame.set(3, 2, glyph('Z'));
+    term.draw(&mut frame).unwrap();
+    let out = mem.take();
+    assert!(out.contains(&b'Z'), "drawn glyph must reach the sink");
+
+    // Second draw with one changed cell takes the diff path and
+    // emits an absolute cursor move to the dirty cell.
+    frame.set(7, 4, glyph('Q'));
+    term.draw(&mut frame).unwrap();
+    let diff = mem.take();
+    assert!(
+        contains(&diff, b"\x1b[5;8H"),
+        "diff path must position at row 5 col 8"
+    );
+    assert!(diff.contains(&b'Q'));
+    assert!(
+        !diff.contains(&b'Z'),
+        "unchanged cell must not be re-emitted"
+    );
+}
+
+#[test]
+fn draw_wraps_frames_in_sync_markers_when_supported() {
+    let mem = MemorySink::new();
+    let mut term = Terminal::with_sink(Box::new(mem.clone()), (10, 3), caps(true, true)).unwrap();
+    mem.take();
+    let mut frame = Frame::new(10, 3, None);
+    frame.set(0, 0, glyph('A'));
+    term.draw(&mut frame).unwrap();
+    let out = mem.take();
+    assert!(out.starts_with(crate::termdetect::SYNC_START));
+    assert!(out.ends_with(crate::termdetect::SYNC_END));
+}
+
+#[test]
+fn drop_restores_cursor_and_leaves_alt_screen() {
+    let mem = MemorySink::new();
+    {
+        let _term = Terminal::with_sink(Box::new(mem.clone()), (10, 3), caps(false, true)).unwrap();
+        mem.take();
+    }
+    let out = mem.contents();
+    assert!(contains(&out, b"\x1b[?25h"), "cursor show missing");
+    assert!(contains(&out, b"\x1b[?1049l"), "alt screen leave missing");
+}
+
+/// Non-stdout sinks never take the `/dev/tty` fallback path, and the fd
+/// health probe is a no-op for them.
+#[test]
+fn non_stdout_sink_skips_tty_health_probe() {
+    let mut term =
+        Terminal::with_sink(Box::new(MemorySink::new()), (10, 3), caps(false, false)).unwrap();
+    assert!(term.probe_stdout_health());
+    // Mouse capture is input-side: enabling it on a memory sink is a no-op.
+    term.enable_mouse_capture().unwrap();
+    assert!(!term.mouse_capture_enabled);
+}
+
+#[test]
+fn file_sink_receives_frame_bytes() {
+    let path = std::env::temp_dir().join(format!("cx-sink-test-{}.ans", std::process::id()));
+    {
+        let sink = FileSink::open(&path).unwrap();
+        let mut term = Terminal::with_sink(Box::new(sink), (8, 2), caps(false, false)).unwrap();
+        let mut frame = Frame::new(8, 2, None);
+        frame.set(1, 1, glyph('X'));
+        term.draw(&mut frame).unwrap();
+    }
+    let bytes = std::fs::read(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert!(bytes.contains(&b'X'));
+}
+
+#[test]
+fn open_sink_dash_is_stdout() {
+    // stdout is never a file target, so the check is not consulted.
+    let sink = sink::open_sink("-", |_| panic!("stdout checked as a file")).unwrap();
+    assert_eq!(sink.kind(), SinkKind::Stdout);
+}
+
+#[test]
+fn open_sink_refuses_a_file_the_check_rejects() {
+    let path = std::env::temp_dir().join(format!("cx-sink-reject-{}.ans", std::process::id()));
+    let denied = |_: &std::path::Path| {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "denied",
+        ))
+    };
+    let err = sink::open_sink(&path.to_string_lossy(), denied)
+        .err()
+        .unwrap();
+    assert_eq!(err.kind(), std::io::ErrorKind::PermissionDenied);
+    assert!(!path.exists(), "rejected target was created");
+}
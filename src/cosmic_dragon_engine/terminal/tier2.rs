@@ -33,6 +33,8 @@
 //! cost is ~600 additions every frame, roughly 2.4 us -- negligible vs
 //! the flush itself (~50 us typical).
 
+use crate::constants::PowerThresholds;
+#[cfg(test)]
 use crate::constants::{
     XTERMJS_BYTE_BUDGET_PER_WINDOW, XTERMJS_HARD_CEILING_BYTES, XTERMJS_RIS_RESET_BYTES,
 };
@@ -110,10 +112,21 @@ impl ByteWindow {
 ///
 /// Extracted as a free function so it can be unit-tested without
 /// constructing a full `Terminal` (which requires a real TTY).
+#[cfg(test)]
 pub(crate) fn should_backpressure(window_sum: u64, bytes_since_ris: u64) -> bool {
     window_sum >= XTERMJS_BYTE_BUDGET_PER_WINDOW || bytes_since_ris >= XTERMJS_HARD_CEILING_BYTES
 }
 
+/// [`should_backpressure`] against the `[power-dragon]` budgets in
+/// `limits` instead of the compiled-in constants.
+pub(crate) fn should_backpressure_with(
+    window_sum: u64,
+    bytes_since_ris: u64,
+    limits: &PowerThresholds,
+) -> bool {
+    window_sum >= limits.xtermjs_byte_budget || bytes_since_ris >= limits.xtermjs_hard_ceiling_bytes
+}
+
 /// Returns true if the given cumulative-bytes-since-RIS + upcoming-
 /// frame-bytes pair should trigger a RIS reset (ESC c emission).
 ///
@@ -124,11 +137,23 @@ pub(crate) fn should_backpressure(window_sum: u64, bytes_since_ris: u64) -> bool
 ///    frame) already exceed the hard ceiling (200 MB). This catches
 ///    the case where a single frame is so large it skipped past the
 ///    RIS threshold entirely.
+#[cfg(test)]
 pub(crate) fn should_ris_reset(cumulative_with_frame: u64, bytes_since_ris: u64) -> bool {
     cumulative_with_frame >= XTERMJS_RIS_RESET_BYTES
         || bytes_since_ris >= XTERMJS_HARD_CEILING_BYTES
 }
 
+/// [`should_ris_reset`] against the `[power-dragon]` thresholds in
+/// `limits` instead of the compiled-in constants.
+pub(crate) fn should_ris_reset_with(
+    cumulative_with_frame: u64,
+    bytes_since_ris: u64,
+    limits: &PowerThresholds,
+) -> bool {
+    cumulative_with_frame >= limits.xtermjs_ris_reset_bytes
+        || bytes_since_ris >= limits.xtermjs_hard_ceiling_bytes
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -244,6 +269,36 @@ mod tests {
         w.reset(); // must not panic
     }
 
+    /// `[power-dragon]` budgets replace the constants in the `_with`
+    /// variants; the defaults agree with the constant-backed checks.
+    #[test]
+    fn with_variants_follow_power_dragon_budgets() {
+        let defaults = PowerThresholds::defaults();
+        for (sum, since) in [
+            (0, 0),
+            (XTERMJS_BYTE_BUDGET_PER_WINDOW, 0),
+            (0, XTERMJS_HARD_CEILING_BYTES),
+        ] {
+            assert_eq!(
+                should_backpressure(sum, since),
+                should_backpressure_with(sum, since, &defaults)
+            );
+            assert_eq!(
+                should_ris_reset(sum, since),
+                should_ris_reset_with(sum, since, &defaults)
+            );
+        }
+        let tight = PowerThresholds {
+            xtermjs_byte_budget: 8 * 1024 * 1024,
+            xtermjs_ris_reset_bytes: 16 * 1024 * 1024,
+            ..defaults
+        };
+        assert!(should_backpressure_with(8 * 1024 * 1024, 0, &tight));
+        assert!(!should_backpressure(8 * 1024 * 1024, 0));
+        assert!(should_ris_reset_with(16 * 1024 * 1024, 0, &tight));
+        assert!(!should_ris_reset(16 * 1024 * 1024, 0));
+    }
+
     /// ByteWindow push of 0 bytes is a valid signal (means backpressure
     /// was applied this frame). The 0 is recorded, not skipped -- it
     /// ages out the oldest entry just like any other push.
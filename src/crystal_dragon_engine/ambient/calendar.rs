@@ -0,0 +1,311 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Calendar day selectors for `ambient.<days>.<HH-MM>` rules.
+//!
+//! A selector restricts a rule to some days. Selectors are matched against
+//! the local date, and every day runs the rules of exactly one selector:
+//! the most specific one that matches (see [`AmbientDays::precedence`]).
+//!
+//! | Selector | Days | Precedence |
+//! |----------|------|------------|
+//! | `2026-11-03` | that date | 1 (most specific) |
+//! | `12-24` | that date, every year | 2 |
+//! | `12-24..01-02`, `2026-11-02..2026-11-06` | the range, inclusive | 3 (shorter first) |
+//! | `sat`, `monday` | that weekday | 4 |
+//! | `weekdays`, `weekends`, `mon..thu` | those weekdays | 5 (fewer first) |
+//! | (none) — `ambient.<HH-MM>` | every day | 6 |
+//!
+//! Yearly ranges may wrap the year end (`12-24..01-02`); dated ranges may
+//! not. Weekday ranges wrap the week (`fri..mon`).
+
+use std::fmt;
+
+/// Weekday names, Monday first (bit `i` of a weekday mask).
+const WEEKDAY_NAMES: [&str; 7] = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+const WEEKDAY_FULL_NAMES: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+const WEEKDAYS_MASK: u8 = 0b001_1111;
+const WEEKENDS_MASK: u8 = 0b110_0000;
+
+/// A local calendar date (proleptic Gregorian).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CivilDate {
+    pub year: i32,
+    /// 1..=12
+    pub month: u8,
+    /// 1..=31
+    pub day: u8,
+}
+
+impl CivilDate {
+    /// `None` unless `year-month-day` is a real date.
+    #[must_use]
+    pub(crate) fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        ((1..=12).contains(&month) && day >= 1 && day <= days_in_month(Some(year), month))
+            .then_some(Self { year, month, day })
+    }
+
+    /// Days since 1970-01-01 (Howard Hinnant's `days_from_civil`).
+    #[must_use]
+    pub(crate) fn to_days(self) -> i64 {
+        let y = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let m = i64::from(self.month);
+        let doy = (153 * (m + if m > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// Inverse of [`Self::to_days`].
+    #[must_use]
+    pub(crate) fn from_days(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+
+    /// The date `n` days later (earlier for negative `n`).
+    #[must_use]
+    pub(crate) fn add_days(self, n: i64) -> Self {
+        Self::from_days(self.to_days() + n)
+    }
+
+    /// 0 = Monday … 6 = Sunday.
+    #[must_use]
+    pub(crate) fn weekday(self) -> u8 {
+        // 1970-01-01 was a Thursday.
+        (self.to_days() + 3).rem_euclid(7) as u8
+    }
+}
+
+impl fmt::Display for CivilDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Days in `month`; `year: None` (yearly selectors) allows February 29.
+fn days_in_month(year: Option<i32>, month: u8) -> u8 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 => match year {
+            Some(y) if !(y % 4 == 0 && (y % 100 != 0 || y % 400 == 0)) => 28,
+            _ => 29,
+        },
+        _ => 31,
+    }
+}
+
+/// Which days an ambient rule applies to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AmbientDays {
+    /// No selector: `ambient.<HH-MM>`.
+    #[default]
+    Daily,
+    /// Weekday mask, bit 0 = Monday.
+    Weekdays(u8),
+    /// `MM-DD` or `MM-DD..MM-DD`, every year (`from == to` for one day).
+    Yearly { from: (u8, u8), to: (u8, u8) },
+    /// `YYYY-MM-DD` or a range of them (`from == to` for one day).
+    Dated { from: CivilDate, to: CivilDate },
+}
+
+impl AmbientDays {
+    /// Parse the `<days>` segment of `ambient.<days>.<HH-MM>`.
+    pub(crate) fn parse(s: &str) -> Result<Self, String> {
+        let s = s.trim().to_ascii_lowercase();
+        match s.as_str() {
+            "weekdays" | "weekday" => return Ok(Self::Weekdays(WEEKDAYS_MASK)),
+            "weekends" | "weekend" => return Ok(Self::Weekdays(WEEKENDS_MASK)),
+            _ => {}
+        }
+        if let Some((a, b)) = s.split_once("..") {
+            if let (Some(a), Some(b)) = (weekday_index(a), weekday_index(b)) {
+                let mut mask = 0u8;
+                let mut d = a;
+                loop {
+                    mask |= 1 << d;
+                    if d == b {
+                        break;
+                    }
+                    d = (d + 1) % 7;
+                }
+                return Ok(Self::Weekdays(mask));
+            }
+            return match (parse_date(a)?, parse_date(b)?) {
+                (DateSpec::Yearly(from), DateSpec::Yearly(to)) => Ok(Self::Yearly { from, to }),
+                (DateSpec::Dated(from), DateSpec::Dated(to)) if from <= to => {
+                    Ok(Self::Dated { from, to })
+                }
+                (DateSpec::Dated(_), DateSpec::Dated(_)) => {
+                    Err(format!("date range '{s}' ends before it starts"))
+                }
+                _ => Err(format!(
+                    "date range '{s}' mixes MM-DD and YYYY-MM-DD (use one form for both ends)"
+                )),
+            };
+        }
+        if let Some(d) = weekday_index(&s) {
+            return Ok(Self::Weekdays(1 << d));
+        }
+        match parse_date(&s)? {
+            DateSpec::Yearly(md) => Ok(Self::Yearly { from: md, to: md }),
+            DateSpec::Dated(date) => Ok(Self::Dated {
+                from: date,
+                to: date,
+            }),
+        }
+    }
+
+    /// `true` when a rule with this selector applies on `date`.
+    #[must_use]
+    pub(crate) fn matches(self, date: CivilDate) -> bool {
+        match self {
+            Self::Daily => true,
+            Self::Weekdays(mask) => mask & (1 << date.weekday()) != 0,
+            Self::Yearly { from, to } => {
+                let md = (date.month, date.day);
+                if from <= to {
+                    from <= md && md <= to
+                } else {
+                    md >= from || md <= to
+                }
+            }
+            Self::Dated { from, to } => from <= date && date <= to,
+        }
+    }
+
+    /// Sort key: lower is more specific. When several selectors match a
+    /// day, the smallest key wins and only its rules run that day. Ties
+    /// (two equally long overlapping ranges) fall back to the derived
+    /// `Ord`, so the choice is stable.
+    #[must_use]
+    pub(crate) fn precedence(self) -> (u8, u32, Self) {
+        let (tier, span) = match self {
+            Self::Dated { from, to } if from == to => (1, 1),
+            Self::Yearly { from, to } if from == to => (2, 1),
+            Self::Dated { from, to } => (3, (to.to_days() - from.to_days() + 1) as u32),
+            Self::Yearly { from, to } => {
+                // Day numbers in a leap year, so 02-29 counts.
+                let ord = |(m, d): (u8, u8)| {
+                    CivilDate {
+                        year: 2000,
+                        month: m,
+                        day: d,
+                    }
+                    .to_days()
+                };
+                (3, ((ord(to) - ord(from)).rem_euclid(366) + 1) as u32)
+            }
+            Self::Weekdays(mask) if mask.count_ones() == 1 => (4, 1),
+            Self::Weekdays(mask) => (5, mask.count_ones()),
+            Self::Daily => (6, 7),
+        };
+        (tier, span, self)
+    }
+}
+
+impl fmt::Display for AmbientDays {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Daily => f.write_str("daily"),
+            Self::Weekdays(WEEKDAYS_MASK) => f.write_str("weekdays"),
+            Self::Weekdays(WEEKENDS_MASK) => f.write_str("weekends"),
+            Self::Weekdays(mask) => {
+                // Parsed masks are one contiguous (possibly wrapping) run:
+                // start at the day whose predecessor is not set.
+                let has = |d: u8| mask & (1 << d) != 0;
+                let first = (0..7).find(|&d| has(d) && !has((d + 6) % 7)).unwrap_or(0);
+                let last = (first + mask.count_ones() as u8 + 6) % 7;
+                if first == last {
+                    f.write_str(WEEKDAY_NAMES[first as usize])
+                } else {
+                    write!(
+                        f,
+                        "{}..{}",
+                        WEEKDAY_NAMES[first as usize], WEEKDAY_NAMES[last as usize]
+                    )
+                }
+            }
+            Self::Yearly { from, to } => {
+                write!(f, "{:02}-{:02}", from.0, from.1)?;
+                if from != to {
+                    write!(f, "..{:02}-{:02}", to.0, to.1)?;
+                }
+                Ok(())
+            }
+            Self::Dated { from, to } => {
+                write!(f, "{from}")?;
+                if from != to {
+                    write!(f, "..{to}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+enum DateSpec {
+    Yearly((u8, u8)),
+    Dated(CivilDate),
+}
+
+fn weekday_index(s: &str) -> Option<u8> {
+    WEEKDAY_NAMES
+        .iter()
+        .position(|n| *n == s)
+        .or_else(|| WEEKDAY_FULL_NAMES.iter().position(|n| *n == s))
+        .map(|i| i as u8)
+}
+
+/// `MM-DD` or `YYYY-MM-DD`, zero-padded.
+fn parse_date(s: &str) -> Result<DateSpec, String> {
+    let bad = || {
+        format!(
+            "unknown day selector '{s}' (expected mon..sun, weekdays, weekends, MM-DD, \
+             YYYY-MM-DD, or a range like 12-24..12-26)"
+        )
+    };
+    let parts: Vec<&str> = s.split('-').collect();
+    let widths: Vec<usize> = parts.iter().map(|p| p.len()).collect();
+    if !parts.iter().all(|p| p.bytes().all(|b| b.is_ascii_digit())) {
+        return Err(bad());
+    }
+    match widths.as_slice() {
+        [2, 2] => {
+            let (month, day) = (
+                parts[0].parse().map_err(|_| bad())?,
+                parts[1].parse().map_err(|_| bad())?,
+            );
+            if !(1..=12).contains(&month) || day < 1 || day > days_in_month(None, month) {
+                return Err(format!("'{s}' is not a valid MM-DD date"));
+            }
+            Ok(DateSpec::Yearly((month, day)))
+        }
+        [4, 2, 2] => {
+            let year: i32 = parts[0].parse().map_err(|_| bad())?;
+            let month: u8 = parts[1].parse().map_err(|_| bad())?;
+            let day: u8 = parts[2].parse().map_err(|_| bad())?;
+            CivilDate::new(year, month, day)
+                .map(DateSpec::Dated)
+                .ok_or_else(|| format!("'{s}' is not a valid YYYY-MM-DD date"))
+        }
+        _ => Err(bad()),
+    }
+}
@@ -46,6 +46,36 @@
 //! the source of truth — no field can be "lost" between the scene switch
 //! and the override layer.
 //!
+//! ## Calendar rules
+//!
+//! A day selector between the namespace and the time restricts an entry to
+//! some days:
+//!
+//! ```toml
+//! ambient.06-00 = "signal"               # every day
+//! ambient.weekends.09-00 = "monolith"    # Saturday and Sunday
+//! ambient.fri.17-00 = "cinematic"        # Fridays
+//! ambient."12-24".18-00 = "aurora"       # every 24 December
+//! ambient."2026-11-03".09-00 = "release" # one release day
+//! ambient."12-24..01-01".00-00 = "aurora"
+//! ```
+//!
+//! (TOML needs the quotes around selectors that start with a digit and
+//! contain dots; `[ambient.sat]` tables work too.) Selector syntax and
+//! precedence are documented in [`calendar`]. Each day runs the rules of
+//! **one** selector — the most specific one that matches the date — so a
+//! weekend rule replaces the everyday timeline on weekends instead of
+//! interleaving with it. Before a day's first rule fires, the last phase
+//! of the previous scheduled day stays active (the same wrap-around as a
+//! plain daily schedule).
+//!
+//! ## Solar events
+//!
+//! The time may name a solar event instead: `ambient."sunset+30m"`,
+//! `ambient.weekends.dawn`. Event times are computed per date from
+//! `ambient-location` (see [`solar`]) and resolved wherever the schedule
+//! is queried, so the scheduler's next boundary follows the seasons.
+//!
 //! ## Dynamic idle/wake scheduler
 //!
 //! The scheduler thread ([`crate::ambient_scheduler`]) does NOT poll on a
@@ -69,15 +99,29 @@
 //!
 //! ## Instant switch
 //!
-//! There is no blend window. When the scheduler fires a phase entry, the
-//! scene is applied immediately via [`Cloud::apply_ambient_entry`] (which
-//! delegates to [`Cloud::apply_scene_runtime_with_cfg`]). The only visual
-//! smoothing comes from the existing `transition_chars` and
+//! By default there is no blend window. When the scheduler fires a phase
+//! entry, the scene is applied immediately via [`Cloud::apply_ambient_entry`]
+//! (which delegates to [`Cloud::apply_scene_runtime_with_cfg`]). The only
+//! visual smoothing comes from the existing `transition_chars` and
 //! `transition_rain_style` machinery (glyph warm-start, rain-style pool
 //! reset) — those are required for correctness (preventing ghosting), not
 //! for cinematic blending.
+//!
+//! `ambient-transition = "90s"` opts into a timed crossfade for
+//! scheduler-fired phases: speed, density, glitch and palette ease from
+//! the old scene to the new one over that window
+//! ([`Cloud::crossfade_ambient_entry`]). Cold start, config reloads and
+//! auto-snapback stay instant.
+
+use std::time::Duration;
 
-use std::collections::HashMap;
+use crate::config_schema::{get_str, ConfigMap};
+
+pub(crate) mod calendar;
+pub(crate) mod solar;
+
+pub(crate) use calendar::{AmbientDays, CivilDate};
+pub(crate) use solar::{GeoLocation, SolarTime};
 
 /// Config namespace prefix for ambient phase entries.
 pub(crate) const AMBIENT_NAMESPACE: &str = "ambient";
@@ -89,7 +133,21 @@ pub(crate) const AMBIENT_NAMESPACE: &str = "ambient";
 /// mistake. The cap also bounds the sort cost (O(n log n)) at parse time.
 pub(crate) const AMBIENT_MAX_ENTRIES: usize = 256;
 
-/// One entry in the ambient schedule. Parsed from `ambient.HH-MM = <scene>`.
+/// How many days [`AmbientSchedule`] looks back (for the carried-over
+/// phase) or ahead (for the next boundary) to find a day with rules. A
+/// year covers every yearly and weekly selector; only a `02-29`-only
+/// schedule can come up empty.
+pub(crate) const AMBIENT_CALENDAR_SCAN_DAYS: i64 = 366;
+
+/// Config key for the opt-in crossfade between ambient scenes.
+pub(crate) const TRANSITION_CONFIG_KEY: &str = "ambient-transition";
+
+/// Longest accepted `ambient-transition` (30 minutes). Longer fades
+/// would overlap most schedules' next boundary.
+pub(crate) const AMBIENT_TRANSITION_MAX_SECS: u64 = 30 * 60;
+
+/// One entry in the ambient schedule. Parsed from
+/// `ambient.[<days>.]HH-MM = <scene>`.
 ///
 /// simplified from a 7-field struct (color/scene/speed/density/fps/
 /// charset/glitch_level) to just `scene`. All parameters now live inside the
@@ -107,6 +165,13 @@ pub struct AmbientEntry {
     /// scene name defined via `[scene-custom.<name>]`. Validation happens
     /// in [`validate_ambient_entries`].
     pub scene: String,
+    /// Days this entry applies to ([`AmbientDays::Daily`] when the key
+    /// has no selector).
+    pub days: AmbientDays,
+    /// Solar event the entry is keyed to (`ambient.sunset+30m`). When set,
+    /// `hour` / `minute` are unused (0) and the firing time is resolved
+    /// per date by [`AmbientSchedule::entry_minute`].
+    pub solar: Option<SolarTime>,
 }
 
 impl AmbientEntry {
@@ -119,18 +184,40 @@ impl AmbientEntry {
     pub fn minutes_of_day(&self) -> u32 {
         self.hour * 60 + self.minute
     }
+
+    /// The key suffix this entry came from: `HH-MM`, `<event>[±offset]`,
+    /// or either prefixed with `<days>.`.
+    #[must_use]
+    pub fn label(&self) -> String {
+        let time = match self.solar {
+            Some(solar) => solar.to_string(),
+            None => format!("{:02}-{:02}", self.hour, self.minute),
+        };
+        match self.days {
+            AmbientDays::Daily => time,
+            days => format!("{days}.{time}"),
+        }
+    }
 }
 
 /// The full ambient schedule — a sorted list of [`AmbientEntry`] values.
 ///
-/// Entries are sorted ascending by `minutes_of_day()` at construction time
-/// (see [`collect_ambient_schedule`]). The scheduler binary-searches this
-/// list to find the current and next phases.
+/// Entries are sorted ascending by `minutes_of_day()` (then by selector) at
+/// construction time (see [`collect_ambient_schedule`]). Lookups take the
+/// local date as well as the time: [`Self::day_entries`] picks the entries
+/// in effect on a date, resolves solar entries to that date's times, and
+/// the phase helpers walk day by day from there.
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct AmbientSchedule {
     /// Entries sorted ascending by `minutes_of_day()`. Empty = schedule
     /// disabled (scheduler thread sleeps idle).
     pub entries: Vec<AmbientEntry>,
+    /// `ambient-location`, needed to resolve solar entries. Without it
+    /// [`collect_ambient_schedule`] drops them.
+    pub location: Option<GeoLocation>,
+    /// `ambient-transition`: crossfade length for scheduler-fired phase
+    /// changes. Zero (the default) switches instantly.
+    pub transition: Duration,
 }
 
 impl AmbientSchedule {
@@ -142,62 +229,116 @@ impl AmbientSchedule {
         self.entries.is_empty()
     }
 
-    /// Find the **current** phase — the latest entry whose `minutes_of_day()`
-    /// is `<= now_min`. Returns `None` if no entry has fired yet today
-    /// (i.e. `now_min` is earlier than the first entry's boundary, which
-    /// only happens at startup before the first phase of the day).
+    /// Local minute of day (0..=1439) at which `entry` fires on `date`.
+    /// `None` for a solar entry when there is no location or the event
+    /// does not happen that day (polar day / night).
+    #[must_use]
+    pub fn entry_minute(&self, entry: &AmbientEntry, date: CivilDate) -> Option<u32> {
+        match entry.solar {
+            None => Some(entry.minutes_of_day()),
+            Some(time) => solar::local_minute(
+                date,
+                self.location?,
+                time,
+                crate::posix_time::utc_offset_secs_at,
+            ),
+        }
+    }
+
+    /// Entries in effect on `date` with the minute each fires at, in time
+    /// order: those of the most specific selector that matches it (see
+    /// [`AmbientDays::precedence`]). Empty when no selector matches.
+    #[must_use]
+    pub fn day_entries(&self, date: CivilDate) -> Vec<(u32, &AmbientEntry)> {
+        let selected = self
+            .entries
+            .iter()
+            .map(|e| e.days)
+            .filter(|days| days.matches(date))
+            .min_by_key(|days| days.precedence());
+        let mut day: Vec<(u32, &AmbientEntry)> = self
+            .entries
+            .iter()
+            .filter(|e| Some(e.days) == selected)
+            .filter_map(|e| Some((self.entry_minute(e, date)?, e)))
+            .collect();
+        // Stable: entries resolving to the same minute keep schedule order.
+        day.sort_by_key(|(minute, _)| *minute);
+        day
+    }
+
+    /// Find the **current** phase on `date` — the latest of the day's
+    /// entries whose `minutes_of_day()` is `<= now_min`.
     ///
-    /// Wrap-around: if `now_min` is 0:30 and the earliest entry is 6:00,
-    /// the "current" phase is the **last** entry of the previous day (it has
-    /// been active since its boundary fired yesterday). This means a **single
-    /// entry** schedule is active ALL DAY (it wraps from yesterday before its
-    /// boundary and becomes today's phase at/after its boundary). This is
-    /// correct by design — ambient is a 24-hour repeating schedule, not a
+    /// Wrap-around: before the day's first entry (or on a day with no
+    /// entries at all), the current phase is the **last** entry of the
+    /// most recent earlier day that has entries — it has been active since
+    /// its boundary fired. For a plain daily schedule that is simply the
+    /// last entry, so a **single entry** schedule is active ALL DAY. This
+    /// is correct by design — ambient is a repeating schedule, not a
     /// one-shot timer. To have a scene activate only after a specific time,
     /// use at least two entries (e.g. `03-16 = cinematic` then
     /// `03-17 = hacker-mode`).
     ///
+    /// Returns `None` only for an empty schedule (or one whose rules never
+    /// matched in the last [`AMBIENT_CALENDAR_SCAN_DAYS`]).
+    ///
     /// This matches the archived `adaptive-custom` semantics.
     #[must_use]
-    pub fn current_phase(&self, now_min: u32) -> Option<&AmbientEntry> {
-        // Find latest entry with minutes_of_day() <= now_min.
-        let idx = self
-            .entries
-            .partition_point(|e| e.minutes_of_day() <= now_min);
-        if idx == 0 {
-            // No entry has fired yet today — wrap to last entry (yesterday's
-            // last phase is still active).
-            self.entries.last()
-        } else {
-            self.entries.get(idx - 1)
+    pub fn current_phase(&self, date: CivilDate, now_min: u32) -> Option<&AmbientEntry> {
+        if self.is_empty() {
+            return None;
         }
+        if let Some((_, e)) = self
+            .day_entries(date)
+            .into_iter()
+            .rfind(|(minute, _)| *minute <= now_min)
+        {
+            return Some(e);
+        }
+        // No entry has fired yet today — the last phase of the previous
+        // scheduled day is still active.
+        (1..=AMBIENT_CALENDAR_SCAN_DAYS).find_map(|back| {
+            self.day_entries(date.add_days(-back))
+                .last()
+                .map(|(_, e)| *e)
+        })
     }
 
-    /// Find the **next** phase — the earliest entry whose `minutes_of_day()`
-    /// is `> now_min`. Returns `None` if the schedule is empty.
+    /// Find the **next** phase — the earliest entry firing after `now_min`
+    /// on `date` — with how many days ahead it fires (0 = today) and its
+    /// minute of day on that day. Returns `None` if the schedule is empty.
     ///
-    /// Wrap-around: if `now_min` is 23:30 and all entries are <= 23:00, the
-    /// "next" phase is the first entry of tomorrow (the earliest entry in
-    /// the list). The caller computes sleep duration as
-    /// `(24*60 - now_min + next.minutes_of_day()) * 60`.
+    /// Wrap-around: when the day has no later entry, the next phase is the
+    /// first entry of the next day that has entries (tomorrow's first entry
+    /// for a plain daily schedule).
     #[must_use]
-    pub fn next_phase(&self, now_min: u32) -> Option<&AmbientEntry> {
-        let idx = self
-            .entries
-            .partition_point(|e| e.minutes_of_day() <= now_min);
-        if let Some(e) = self.entries.get(idx) {
-            Some(e)
-        } else {
-            // Wrap to tomorrow's first entry.
-            self.entries.first()
+    pub fn next_phase(&self, date: CivilDate, now_min: u32) -> Option<(i64, u32, &AmbientEntry)> {
+        if self.is_empty() {
+            return None;
         }
+        if let Some((minute, e)) = self
+            .day_entries(date)
+            .into_iter()
+            .find(|(minute, _)| *minute > now_min)
+        {
+            return Some((0, minute, e));
+        }
+        (1..=AMBIENT_CALENDAR_SCAN_DAYS).find_map(|ahead| {
+            self.day_entries(date.add_days(ahead))
+                .first()
+                .map(|&(minute, e)| (ahead, minute, e))
+        })
     }
 
     /// Seconds to sleep until the next phase boundary fires.
     ///
-    /// Returns `None` if the schedule is empty. Handles midnight wrap-around:
-    /// if the next phase is "tomorrow's first entry", the sleep duration
-    /// correctly accounts for crossing midnight.
+    /// Returns `None` if the schedule is empty. Handles midnight wrap-around
+    /// and days without entries: if the next phase is on a later day, the
+    /// sleep duration correctly accounts for every midnight crossed. Solar
+    /// entries are resolved for the day they fire on, so the sunset rule
+    /// tracks the season (the hourly cap re-resolves them at least once an
+    /// hour anyway).
     ///
     /// Capped at 3600 seconds (1 hour) to bound live-reload latency — if
     /// the user edits the config to add a new entry, the scheduler will
@@ -205,37 +346,100 @@ impl AmbientSchedule {
     /// In practice the live-reload path also wakes the thread immediately
     /// via condvar, so this cap is a defense-in-depth.
     #[must_use]
-    pub fn seconds_to_next_phase(&self, now_min: u32, now_sec: u32) -> Option<u64> {
-        let next = self.next_phase(now_min)?;
-        let next_min = next.minutes_of_day();
-        let now_total_sec = (now_min * 60 + now_sec.min(59)) as u64;
-        let next_total_sec = if next_min > now_min {
-            (next_min * 60) as u64
-        } else {
-            // Wrap: next is tomorrow.
-            ((24 * 60 + next_min) * 60) as u64
-        };
+    pub fn seconds_to_next_phase(
+        &self,
+        date: CivilDate,
+        now_min: u32,
+        now_sec: u32,
+    ) -> Option<u64> {
+        let (days_ahead, next_min, _) = self.next_phase(date, now_min)?;
+        let now_total_sec = u64::from(now_min * 60 + now_sec.min(59));
+        let next_total_sec = days_ahead as u64 * 24 * 60 * 60 + u64::from(next_min * 60);
         let diff = next_total_sec.saturating_sub(now_total_sec);
         Some(diff.min(3600))
     }
 }
 
-/// Returns `true` if `key` is a recognized `ambient.<HH-MM>` config key.
+/// Returns `true` if `key` is a recognized
+/// `ambient.[<days>.]<HH-MM|event[±offset]>` config key.
 ///
 /// Mirrors [`crate::scene_custom::is_scene_custom_config_key`] but for the
-/// `ambient` namespace. The suffix must match `HH-MM` (24-hour, zero-padded,
-/// dash-separated). Invalid suffixes (e.g. `ambient.midnight`,
-/// `ambient.24-00`, `ambient.12-60`) return `false` and surface as
-/// `unknown_keys` so `--testconf` can attach a hint.
+/// `ambient` namespace. The last segment must match `HH-MM` (24-hour,
+/// zero-padded, dash-separated) or name a solar event (`sunset+30m`), and
+/// the optional middle segment must be a valid day selector. Invalid keys (e.g. `ambient.midnight`,
+/// `ambient.24-00`, `ambient.someday.12-00`) return `false` and surface as
+/// `unknown_keys` so `--testconf` can attach a hint (the
+/// [`parse_ambient_key`] error).
 #[must_use]
 pub(crate) fn is_ambient_config_key(key: &str) -> bool {
-    let Some((prefix, rest)) = key.split_once('.') else {
-        return false;
+    parse_ambient_key(key).is_ok()
+}
+
+/// What an `ambient.*` key names: day selector, hour, minute, and the
+/// solar event for `ambient.sunset+30m`-style keys.
+pub(crate) type AmbientSlot = (AmbientDays, u32, u32, Option<SolarTime>);
+
+/// Split an `ambient.[<days>.]<HH-MM|event[±offset]>` key into its day
+/// selector, hour, minute and solar event (hour and minute are 0 for a
+/// solar key).
+///
+/// # Errors
+///
+/// Returns `Err` describing the problem when `key` is not in the
+/// `ambient` namespace, the time is neither `HH-MM` nor a solar event, or
+/// the day selector is not recognized.
+pub(crate) fn parse_ambient_key(key: &str) -> Result<AmbientSlot, String> {
+    let rest = key
+        .split_once('.')
+        .filter(|(prefix, _)| *prefix == AMBIENT_NAMESPACE)
+        .map(|(_, rest)| rest)
+        .ok_or_else(|| format!("'{key}' is not an ambient.* key"))?;
+    // Selectors may contain dots themselves (`12-24..12-26`), the time
+    // never does: split at the last dot.
+    let (days, time) = match rest.rsplit_once('.') {
+        Some((days, time)) => (
+            AmbientDays::parse(days).map_err(|e| format!("ambient: {e} in '{key}'"))?,
+            time,
+        ),
+        None => (AmbientDays::Daily, rest),
     };
-    if prefix != AMBIENT_NAMESPACE {
-        return false;
+    if !is_valid_hh_mm(time) {
+        if time.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            let solar = SolarTime::parse(time).map_err(|e| format!("ambient: {e} in '{key}'"))?;
+            return Ok((days, 0, 0, Some(solar)));
+        }
+        return Err(format!(
+            "ambient: invalid time key '{key}' (expected HH-MM or a solar event, e.g. \
+             'ambient.12-00', 'ambient.sat.09-00' or 'ambient.sunset+30m')"
+        ));
     }
-    is_valid_hh_mm(rest)
+    let hour: u32 = time[0..2].parse().unwrap_or(0);
+    let minute: u32 = time[3..5].parse().unwrap_or(0);
+    Ok((days, hour, minute, None))
+}
+
+/// Parse `ambient-transition`: a duration such as `90s`, `2m` or `1m30s`
+/// (bare numbers are seconds), up to [`AMBIENT_TRANSITION_MAX_SECS`].
+/// `0`, `off` and `none` mean instant.
+///
+/// # Errors
+///
+/// Returns `Err` when the value is not a duration or is too long.
+pub(crate) fn parse_ambient_transition(v: &str) -> Result<Duration, String> {
+    let v = v.trim();
+    if matches!(v.to_ascii_lowercase().as_str(), "0" | "off" | "none") {
+        return Ok(Duration::ZERO);
+    }
+    let secs = crate::cli::cli_parse::parse_duration(TRANSITION_CONFIG_KEY, v).map_err(|_| {
+        format!("expected a duration like 90s, 2m or 1m30s (0 = instant), got '{v}'")
+    })?;
+    if secs > AMBIENT_TRANSITION_MAX_SECS {
+        return Err(format!(
+            "'{v}' is longer than {} minutes",
+            AMBIENT_TRANSITION_MAX_SECS / 60
+        ));
+    }
+    Ok(Duration::from_secs(secs))
 }
 
 /// Validate `HH-MM` format: 5 chars, dash at index 2, HH in 00..=23,
@@ -314,32 +518,51 @@ pub(crate) fn parse_ambient_value(value: &str) -> Result<AmbientEntry, String> {
         hour: 0,
         minute: 0,
         scene,
+        days: AmbientDays::Daily,
+        solar: None,
     })
 }
 
 /// Collect all `ambient.*` entries from a flat config map and return a
 /// sorted [`AmbientSchedule`].
 ///
-/// Entries are sorted ascending by `minutes_of_day()`. Duplicate `HH-MM`
-/// keys follow standard `HashMap::insert` semantics (last writer wins),
+/// Entries are sorted ascending by `minutes_of_day()`. Duplicate keys
+/// follow standard `HashMap::insert` semantics (last writer wins),
 /// matching how `configfile.rs` already deduplicates — by the time we get
-/// the map, duplicates are already collapsed.
+/// the map, duplicates are already collapsed. Distinct keys naming the
+/// same slot (`ambient.weekend.09-00` and `ambient.weekends.09-00`) keep
+/// the alphabetically first key; `--testconf` rejects them.
+///
+/// Solar entries (`ambient.sunset+30m`) need `ambient-location`; without a
+/// valid one they are dropped, like any other entry `--testconf` rejects.
 ///
 /// Returns an empty schedule (not an error) if no `ambient.*` keys are
 /// present — the ambient feature is then effectively disabled.
 #[must_use]
-pub(crate) fn collect_ambient_schedule(cfg: &HashMap<String, String>) -> AmbientSchedule {
+pub(crate) fn collect_ambient_schedule(cfg: &ConfigMap) -> AmbientSchedule {
+    let location = cfg
+        .get(solar::LOCATION_CONFIG_KEY)
+        .and_then(|v| GeoLocation::from_config(v).ok());
+    let mut keys: Vec<&String> = cfg.keys().filter(|k| k.starts_with("ambient.")).collect();
+    keys.sort();
     let mut entries: Vec<AmbientEntry> = Vec::new();
-    for (key, value) in cfg {
-        let Some(rest) = key.strip_prefix("ambient.") else {
+    for key in keys {
+        let Ok((days, hour, minute, solar)) = parse_ambient_key(key) else {
+            // Should not happen — is_known_key filters these. Defensive.
             continue;
         };
-        if !is_valid_hh_mm(rest) {
-            // Should not happen — is_known_key filters these. Defensive.
+        if solar.is_some() && location.is_none() {
             continue;
         }
-        let hour: u32 = rest[0..2].parse().unwrap_or(0);
-        let minute: u32 = rest[3..5].parse().unwrap_or(0);
+        if entries
+            .iter()
+            .any(|e| e.days == days && e.hour == hour && e.minute == minute && e.solar == solar)
+        {
+            continue;
+        }
+        let Some(value) = cfg[key].as_str() else {
+            continue;
+        };
         let mut entry = match parse_ambient_value(value) {
             Ok(e) => e,
             Err(_) => {
@@ -351,13 +574,22 @@ pub(crate) fn collect_ambient_schedule(cfg: &HashMap<String, String>) -> Ambient
         };
         entry.hour = hour;
         entry.minute = minute;
+        entry.days = days;
+        entry.solar = solar;
         entries.push(entry);
     }
-    entries.sort_by_key(AmbientEntry::minutes_of_day);
+    entries.sort_by_key(|e| (e.minutes_of_day(), e.solar, e.days));
     // Defensive cap (DoS hardening — config is user-controlled, but a 10k
     // entry file would still waste sort time).
     entries.truncate(AMBIENT_MAX_ENTRIES);
-    AmbientSchedule { entries }
+    let transition = get_str(cfg, TRANSITION_CONFIG_KEY)
+        .and_then(|v| parse_ambient_transition(v).ok())
+        .unwrap_or_default();
+    AmbientSchedule {
+        entries,
+        location,
+        transition,
+    }
 }
 
 /// Strict validation of all `ambient.*` entries in the config map.
@@ -367,24 +599,48 @@ pub(crate) fn collect_ambient_schedule(cfg: &HashMap<String, String>) -> Ambient
 /// exit code 2 (matches the rest of the strict validation contract).
 ///
 /// validation rules:
+/// - Key must be `ambient.[<days>.]<HH-MM|event[±offset]>` with a valid
+///   day selector.
+/// - Solar keys need a valid `ambient-location`.
+/// - Two keys must not name the same slot (`weekend` / `weekends`).
 /// - Value must parse as a single scene name (no commas, no `=`).
 /// - The scene name must be a recognized built-in scene OR a
 ///   `[scene-custom.<name>]` block that exists in the config.
-pub(crate) fn validate_ambient_entries(cfg: &HashMap<String, String>) -> Result<(), String> {
+pub(crate) fn validate_ambient_entries(cfg: &ConfigMap) -> Result<(), String> {
     // Sort keys for deterministic error ordering (BTreeMap iteration).
     let mut keys: Vec<&String> = cfg.keys().filter(|k| k.starts_with("ambient.")).collect();
     keys.sort();
 
     let custom_scenes = crate::scene_custom::collect_custom_scenes(cfg);
+    let location = cfg.get(solar::LOCATION_CONFIG_KEY);
+    let mut slots: Vec<(AmbientSlot, &String)> = Vec::new();
 
     for key in keys {
         let value = &cfg[key];
-        let rest = key.strip_prefix("ambient.").unwrap_or("");
-        if !is_valid_hh_mm(rest) {
+        let slot = parse_ambient_key(key)?;
+        if slot.3.is_some() {
+            match location {
+                None => {
+                    return Err(format!(
+                        "{key}: solar rules need {} = \"<latitude>, <longitude>\"",
+                        solar::LOCATION_CONFIG_KEY
+                    ))
+                }
+                Some(v) => {
+                    GeoLocation::from_config(v)
+                        .map_err(|e| format!("{}: {e}", solar::LOCATION_CONFIG_KEY))?;
+                }
+            }
+        }
+        if let Some((_, first)) = slots.iter().find(|(s, _)| *s == slot) {
             return Err(format!(
-                "ambient: invalid time key '{key}' (expected HH-MM, e.g. 'ambient.12-00')"
+                "{key}: same days and time as '{first}' — keep one of them"
             ));
         }
+        slots.push((slot, key));
+        let Some(value) = value.as_str() else {
+            return Err(format!("{key}: expected a scene name, got {value}"));
+        };
         let entry = parse_ambient_value(value).map_err(|e| format!("{key}: {e}"))?;
 
         // Validate scene name — must be a built-in scene OR a defined
@@ -441,6 +697,20 @@ pub(crate) fn current_yday() -> i32 {
     crate::posix_time::local_tm().map(|tm| tm.yday).unwrap_or(0)
 }
 
+/// Returns today's local date, for matching calendar rules.
+///
+/// Falls back to 1970-01-01 (a Thursday) if the wall clock is unavailable.
+#[must_use]
+pub(crate) fn current_local_date() -> CivilDate {
+    crate::posix_time::local_tm()
+        .and_then(|tm| CivilDate::new(tm.year, tm.month as u8, tm.day as u8))
+        .unwrap_or(CivilDate {
+            year: 1970,
+            month: 1,
+            day: 1,
+        })
+}
+
 /// masterclass: compute the current ambient phase and apply it to the
 /// cloud at startup (synchronous, before the event loop). Returns the new
 /// charset preset + the applied entry (or None if no schedule is active).
@@ -471,10 +741,13 @@ pub(crate) fn apply_startup_ambient(
     charset_preset: &str,
     user_ranges: &[(char, char)],
     def_ascii: bool,
-    cfg: &std::collections::HashMap<String, String>,
+    cfg: &crate::config_schema::ConfigMap,
 ) -> (String, Option<AmbientEntry>) {
     let now_min = current_minute_of_day();
-    let Some(entry) = schedule.current_phase(now_min).cloned() else {
+    let Some(entry) = schedule
+        .current_phase(current_local_date(), now_min)
+        .cloned()
+    else {
         crate::lr_trace!(
             "ambient: startup — no active phase at minute {} of day, default scene retained",
             now_min
@@ -497,9 +770,8 @@ pub(crate) fn apply_startup_ambient(
     }
     let color_before = cloud.color_scheme();
     crate::lr_trace!(
-        "ambient: startup sync apply — phase {:02}:{:02} (scene={}, color_before={:?})",
-        entry.hour,
-        entry.minute,
+        "ambient: startup sync apply — phase {} (scene={}, color_before={:?})",
+        entry.label(),
         entry.scene,
         color_before
     );
@@ -0,0 +1,260 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Solar-event times for `ambient.<event>[±offset]` rules.
+//!
+//! Computed offline from `ambient-location = "<lat>, <lon>"` with the
+//! standard sunrise equation (mean anomaly → equation of center →
+//! ecliptic longitude → declination → hour angle), accurate to about a
+//! minute away from the poles. No network, no tz database beyond the
+//! libc one already used for the wall clock.
+//!
+//! | Event | Sun altitude |
+//! |-------|--------------|
+//! | `dawn` | −6° rising (civil twilight begins) |
+//! | `sunrise` | −0.833° rising (upper limb + refraction) |
+//! | `noon` | solar transit |
+//! | `sunset` | −0.833° setting |
+//! | `dusk` | −6° setting (civil twilight ends) |
+//!
+//! Times are resolved per local date, so they track the seasons (and DST)
+//! day by day. An offset (`sunset+30m`, `sunrise-1h15m`, up to ±12h) is
+//! added afterwards and the result is clamped to that day (00:00–23:59).
+//! Near the poles an event may not happen at all (midnight sun, polar
+//! night); its rule is skipped on those days.
+
+use std::fmt;
+
+use super::calendar::CivilDate;
+use crate::config_schema::ConfigValue;
+
+/// Config key holding the observer position for solar rules.
+pub(crate) const LOCATION_CONFIG_KEY: &str = "ambient-location";
+
+/// Largest `±offset` accepted after an event name, in minutes.
+pub(crate) const SOLAR_MAX_OFFSET_MIN: i32 = 12 * 60;
+
+/// Days from 1970-01-01 to 2000-01-01 (the J2000 epoch's date).
+const J2000_UNIX_DAYS: i64 = 10_957;
+/// Julian date of J2000 (2000-01-01 12:00).
+const J2000_JD: f64 = 2_451_545.0;
+/// Julian date of the Unix epoch.
+const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+/// Earth's axial tilt, degrees.
+const OBLIQUITY_DEG: f64 = 23.4397;
+
+/// Observer position, decimal degrees (north / east positive).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeoLocation {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+impl GeoLocation {
+    /// Read `"<lat>, <lon>"` or the `[<lat>, <lon>]` array form (both
+    /// arrive as numbers from `config_schema`).
+    pub(crate) fn from_config(value: &ConfigValue) -> Result<Self, String> {
+        let Some(&[lat, lon]) = value.numbers() else {
+            return Err(format!(
+                "expected \"<latitude>, <longitude>\" in decimal degrees, got {value}"
+            ));
+        };
+        let check = |x: f64, what: &str, limit: f64| -> Result<f64, String> {
+            if x.is_finite() && x.abs() <= limit {
+                Ok(x)
+            } else {
+                Err(format!(
+                    "{what} '{x}' must be a number from -{limit} to {limit}"
+                ))
+            }
+        };
+        Ok(Self {
+            lat: check(lat, "latitude", 90.0)?,
+            lon: check(lon, "longitude", 180.0)?,
+        })
+    }
+}
+
+impl fmt::Display for GeoLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.4}, {:.4}", self.lat, self.lon)
+    }
+}
+
+/// A point in the solar day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SolarEvent {
+    Dawn,
+    Sunrise,
+    Noon,
+    Sunset,
+    Dusk,
+}
+
+impl SolarEvent {
+    pub(crate) const ALL: [Self; 5] = [
+        Self::Dawn,
+        Self::Sunrise,
+        Self::Noon,
+        Self::Sunset,
+        Self::Dusk,
+    ];
+
+    #[must_use]
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Dawn => "dawn",
+            Self::Sunrise => "sunrise",
+            Self::Noon => "noon",
+            Self::Sunset => "sunset",
+            Self::Dusk => "dusk",
+        }
+    }
+
+    /// Sun altitude defining the event and whether it is the rising
+    /// (morning) crossing; `None` for noon.
+    fn crossing(self) -> Option<(f64, bool)> {
+        match self {
+            Self::Dawn => Some((-6.0, true)),
+            Self::Sunrise => Some((-0.833, true)),
+            Self::Noon => None,
+            Self::Sunset => Some((-0.833, false)),
+            Self::Dusk => Some((-6.0, false)),
+        }
+    }
+}
+
+/// An event plus a signed offset: the time half of `ambient.sunset+30m`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SolarTime {
+    pub event: SolarEvent,
+    pub offset_min: i32,
+}
+
+impl SolarTime {
+    /// Parse `<event>`, `<event>+<offset>` or `<event>-<offset>`, where
+    /// the offset is `<N>h`, `<N>m` or `<N>h<N>m`.
+    pub(crate) fn parse(s: &str) -> Result<Self, String> {
+        let Some(event) = SolarEvent::ALL
+            .into_iter()
+            .find(|e| s.starts_with(e.name()))
+        else {
+            let names: Vec<&str> = SolarEvent::ALL.iter().map(|e| e.name()).collect();
+            return Err(format!(
+                "'{s}' is neither HH-MM nor a solar event ({})",
+                names.join(", ")
+            ));
+        };
+        let rest = &s[event.name().len()..];
+        if rest.is_empty() {
+            return Ok(Self {
+                event,
+                offset_min: 0,
+            });
+        }
+        let bad = || {
+            format!(
+                "bad offset in '{s}' (expected e.g. {}+30m or {}-1h15m)",
+                event.name(),
+                event.name()
+            )
+        };
+        let (sign, body) = match rest.as_bytes()[0] {
+            b'+' => (1, &rest[1..]),
+            b'-' => (-1, &rest[1..]),
+            _ => return Err(bad()),
+        };
+        let (hours, mins) = match body.split_once('h') {
+            Some((h, "")) => (h, "0"),
+            Some((h, m)) => (h, m.strip_suffix('m').ok_or_else(bad)?),
+            None => ("0", body.strip_suffix('m').ok_or_else(bad)?),
+        };
+        let num = |t: &str| -> Result<i32, String> {
+            if t.is_empty() || !t.bytes().all(|b| b.is_ascii_digit()) || t.len() > 4 {
+                return Err(bad());
+            }
+            t.parse().map_err(|_| bad())
+        };
+        let total = num(hours)? * 60 + num(mins)?;
+        if total > SOLAR_MAX_OFFSET_MIN {
+            return Err(format!(
+                "offset in '{s}' is over {}h",
+                SOLAR_MAX_OFFSET_MIN / 60
+            ));
+        }
+        Ok(Self {
+            event,
+            offset_min: sign * total,
+        })
+    }
+}
+
+impl fmt::Display for SolarTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.event.name())?;
+        if self.offset_min != 0 {
+            let sign = if self.offset_min < 0 { '-' } else { '+' };
+            let (h, m) = (self.offset_min.abs() / 60, self.offset_min.abs() % 60);
+            match (h, m) {
+                (0, m) => write!(f, "{sign}{m}m")?,
+                (h, 0) => write!(f, "{sign}{h}h")?,
+                (h, m) => write!(f, "{sign}{h}h{m}m")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Unix time (seconds, UTC) of `event` on the solar day of `date` at
+/// `loc`, or `None` when the sun never crosses the event's altitude.
+#[must_use]
+pub(crate) fn event_unix_secs(date: CivilDate, loc: GeoLocation, event: SolarEvent) -> Option<f64> {
+    let (sin, cos) = (|d: f64| d.to_radians().sin(), |d: f64| d.to_radians().cos());
+    // Mean solar time at the observer's meridian for this date.
+    let n = (date.to_days() - J2000_UNIX_DAYS) as f64;
+    let j_star = n - loc.lon / 360.0;
+    let mean_anomaly = (357.5291 + 0.985_600_28 * j_star).rem_euclid(360.0);
+    let center = 1.9148 * sin(mean_anomaly)
+        + 0.0200 * sin(2.0 * mean_anomaly)
+        + 0.0003 * sin(3.0 * mean_anomaly);
+    let ecliptic_lon = (mean_anomaly + center + 180.0 + 102.9372).rem_euclid(360.0);
+    let transit = J2000_JD + j_star + 0.0053 * sin(mean_anomaly) - 0.0069 * sin(2.0 * ecliptic_lon);
+
+    let jd = match event.crossing() {
+        None => transit,
+        Some((altitude, rising)) => {
+            let sin_decl = sin(ecliptic_lon) * sin(OBLIQUITY_DEG);
+            let cos_decl = (1.0 - sin_decl * sin_decl).sqrt();
+            let cos_hour_angle =
+                (sin(altitude) - sin(loc.lat) * sin_decl) / (cos(loc.lat) * cos_decl);
+            if !(-1.0..=1.0).contains(&cos_hour_angle) {
+                return None;
+            }
+            let half_day = cos_hour_angle.acos().to_degrees() / 360.0;
+            if rising {
+                transit - half_day
+            } else {
+                transit + half_day
+            }
+        }
+    };
+    Some((jd - UNIX_EPOCH_JD) * 86_400.0)
+}
+
+/// Local minute of day (0..=1439) at which `time` fires on `date`, using
+/// `utc_offset_secs(t)` for the local offset at Unix time `t` (see
+/// [`crate::posix_time::utc_offset_secs_at`]). Offsets that push the time
+/// past either midnight are clamped to the day.
+#[must_use]
+pub(crate) fn local_minute(
+    date: CivilDate,
+    loc: GeoLocation,
+    time: SolarTime,
+    utc_offset_secs: impl Fn(i64) -> i64,
+) -> Option<u32> {
+    let t =
+        event_unix_secs(date, loc, time.event)?.round() as i64 + i64::from(time.offset_min) * 60;
+    let local = t + utc_offset_secs(t);
+    let minutes_from_midnight = (local - date.to_days() * 86_400).div_euclid(60);
+    Some(minutes_from_midnight.clamp(0, 24 * 60 - 1) as u32)
+}
@@ -125,12 +125,21 @@ fn migration_message_includes_user_repro_example() {
 
 // ── AmbientSchedule helpers (current_phase / next_phase / seconds_to_next_phase) ──
 
+/// A Wednesday, for schedule lookups that ignore the calendar.
+const WED: CivilDate = CivilDate {
+    year: 2026,
+    month: 10,
+    day: 14,
+};
+
 /// Helper: build a minimal entry for schedule tests.
 fn entry(h: u32, m: u32, scene: &str) -> AmbientEntry {
     AmbientEntry {
         hour: h,
         minute: m,
         scene: scene.to_string(),
+        days: AmbientDays::Daily,
+        solar: None,
     }
 }
 
@@ -138,13 +147,15 @@ fn entry(h: u32, m: u32, scene: &str) -> AmbientEntry {
 fn current_phase_finds_latest_before_now() {
     let s = AmbientSchedule {
         entries: vec![entry(0, 0, "cinematic"), entry(12, 0, "signal")],
+        location: None,
+        transition: Duration::ZERO,
     };
     // 12:30 → current is 12:00
-    assert_eq!(s.current_phase(12 * 60 + 30).unwrap().hour, 12);
+    assert_eq!(s.current_phase(WED, 12 * 60 + 30).unwrap().hour, 12);
     // 11:59 → current is 00:00 (12:00 not yet fired)
-    assert_eq!(s.current_phase(11 * 60 + 59).unwrap().hour, 0);
+    assert_eq!(s.current_phase(WED, 11 * 60 + 59).unwrap().hour, 0);
     // 13:00 → current is 12:00 (last entry <= 13:00)
-    assert_eq!(s.current_phase(13 * 60).unwrap().hour, 12);
+    assert_eq!(s.current_phase(WED, 13 * 60).unwrap().hour, 12);
 }
 
 #[test]
@@ -153,70 +164,78 @@ fn current_phase_wraps_to_last_entry_before_first() {
     // wrap to last entry (18:00 from yesterday).
     let s = AmbientSchedule {
         entries: vec![entry(6, 0, "matrix"), entry(18, 0, "monolith")],
+        location: None,
+        transition: Duration::ZERO,
     };
-    let cur = s.current_phase(3 * 60).unwrap();
+    let cur = s.current_phase(WED, 3 * 60).unwrap();
     assert_eq!(cur.hour, 18);
 }
 
 #[test]
 fn current_phase_empty_schedule_returns_none() {
     let s = AmbientSchedule::default();
-    assert!(s.current_phase(0).is_none());
+    assert!(s.current_phase(WED, 0).is_none());
 }
 
 #[test]
 fn next_phase_finds_earliest_after_now() {
     let s = AmbientSchedule {
         entries: vec![entry(0, 0, "cinematic"), entry(12, 0, "signal")],
+        location: None,
+        transition: Duration::ZERO,
     };
     // 11:00 → next is 12:00
-    assert_eq!(s.next_phase(11 * 60).unwrap().hour, 12);
+    assert_eq!(s.next_phase(WED, 11 * 60).unwrap().2.hour, 12);
     // 12:30 → next is 00:00 (tomorrow)
-    assert_eq!(s.next_phase(12 * 60 + 30).unwrap().hour, 0);
+    assert_eq!(s.next_phase(WED, 12 * 60 + 30).unwrap().2.hour, 0);
 }
 
 #[test]
 fn next_phase_empty_schedule_returns_none() {
     let s = AmbientSchedule::default();
-    assert!(s.next_phase(0).is_none());
+    assert!(s.next_phase(WED, 0).is_none());
 }
 
 #[test]
 fn seconds_to_next_phase_normal_case() {
     let s = AmbientSchedule {
         entries: vec![entry(12, 0, "signal")],
+        location: None,
+        transition: Duration::ZERO,
     };
     // now = 11:00:00 (660 min, 0 sec). next = 12:00:00 (720 min). diff = 60*60 = 3600 sec.
-    assert_eq!(s.seconds_to_next_phase(660, 0), Some(3600));
+    assert_eq!(s.seconds_to_next_phase(WED, 660, 0), Some(3600));
     // now = 11:59:30 (719 min, 30 sec). next = 12:00:00. diff = 30 sec.
-    assert_eq!(s.seconds_to_next_phase(719, 30), Some(30));
+    assert_eq!(s.seconds_to_next_phase(WED, 719, 30), Some(30));
 }
 
 #[test]
 fn seconds_to_next_phase_wraps_midnight() {
     let s = AmbientSchedule {
         entries: vec![entry(6, 0, "matrix")],
+        location: None,
+        transition: Duration::ZERO,
     };
     // now = 23:00:00 (1380 min). next = 06:00:00 tomorrow (360 min).
     // diff = (24*60 - 1380 + 360) * 60 = 420 * 60 = 25200 sec.
     // Capped at 3600.
-    assert_eq!(s.seconds_to_next_phase(1380, 0), Some(3600));
+    assert_eq!(s.seconds_to_next_phase(WED, 1380, 0), Some(3600));
 }
 
 #[test]
 fn seconds_to_next_phase_empty_returns_none() {
     let s = AmbientSchedule::default();
-    assert!(s.seconds_to_next_phase(0, 0).is_none());
+    assert!(s.seconds_to_next_phase(WED, 0, 0).is_none());
 }
 
 // ── collect_ambient_schedule ──
 
 #[test]
 fn collect_sorts_entries_by_time() {
-    let mut cfg = HashMap::new();
-    cfg.insert("ambient.18-00".into(), "monolith".into());
-    cfg.insert("ambient.06-00".into(), "matrix".into());
-    cfg.insert("ambient.12-00".into(), "signal".into());
+    let mut cfg = ConfigMap::new();
+    cfg.insert("ambient.18-00".to_string(), "monolith".into());
+    cfg.insert("ambient.06-00".to_string(), "matrix".into());
+    cfg.insert("ambient.12-00".to_string(), "signal".into());
     let s = collect_ambient_schedule(&cfg);
     assert_eq!(s.entries.len(), 3);
     assert_eq!(s.entries[0].hour, 6);
@@ -234,9 +253,12 @@ fn collect_skips_legacy_format_entries() {
     // dropped from the runtime schedule (strict --testconf still errors).
     // This matches the live-reload contract: a half-edited config must
     // not crash the runtime.
-    let mut cfg = HashMap::new();
-    cfg.insert("ambient.12-00".into(), "signal".into());
-    cfg.insert("ambient.18-00".into(), "neon, monolith, speed=15".into());
+    let mut cfg = ConfigMap::new();
+    cfg.insert("ambient.12-00".to_string(), "signal".into());
+    cfg.insert(
+        "ambient.18-00".to_string(),
+        "neon, monolith, speed=15".into(),
+    );
     let s = collect_ambient_schedule(&cfg);
     assert_eq!(s.entries.len(), 1);
     assert_eq!(s.entries[0].hour, 12);
@@ -245,9 +267,9 @@ fn collect_skips_legacy_format_entries() {
 
 #[test]
 fn collect_returns_empty_when_no_ambient_keys() {
-    let mut cfg = HashMap::new();
-    cfg.insert("color".into(), "neon-green".into());
-    cfg.insert("scene".into(), "monolith".into());
+    let mut cfg = ConfigMap::new();
+    cfg.insert("color".to_string(), "neon-green".into());
+    cfg.insert("scene".to_string(), "monolith".into());
     let s = collect_ambient_schedule(&cfg);
     assert!(s.is_empty());
 }
@@ -257,8 +279,8 @@ fn collect_preserves_custom_scene_names() {
     // custom scene names are stored verbatim — validation that
     // they reference a defined [scene-custom.<name>] block happens in
     // validate_ambient_entries, not collect_ambient_schedule.
-    let mut cfg = HashMap::new();
-    cfg.insert("ambient.13-00".into(), "afternoon".into());
+    let mut cfg = ConfigMap::new();
+    cfg.insert("ambient.13-00".to_string(), "afternoon".into());
     let s = collect_ambient_schedule(&cfg);
     assert_eq!(s.entries.len(), 1);
     assert_eq!(s.entries[0].scene, "afternoon");
@@ -268,25 +290,28 @@ fn collect_preserves_custom_scene_names() {
 
 #[test]
 fn validate_accepts_builtin_scene_names() {
-    let mut cfg = HashMap::new();
-    cfg.insert("ambient.00-00".into(), "cinematic".into());
-    cfg.insert("ambient.12-00".into(), "signal".into());
-    cfg.insert("ambient.18-00".into(), "monolith".into());
+    let mut cfg = ConfigMap::new();
+    cfg.insert("ambient.00-00".to_string(), "cinematic".into());
+    cfg.insert("ambient.12-00".to_string(), "signal".into());
+    cfg.insert("ambient.18-00".to_string(), "monolith".into());
     assert!(validate_ambient_entries(&cfg).is_ok());
 }
 
 #[test]
 fn validate_accepts_custom_scene_names() {
-    let mut cfg = HashMap::new();
-    cfg.insert("scene-custom.afternoon.color".into(), "neon-green".into());
-    cfg.insert("ambient.15-00".into(), "afternoon".into());
+    let mut cfg = ConfigMap::new();
+    cfg.insert(
+        "scene-custom.afternoon.color".to_string(),
+        "neon-green".into(),
+    );
+    cfg.insert("ambient.15-00".to_string(), "afternoon".into());
     assert!(validate_ambient_entries(&cfg).is_ok());
 }
 
 #[test]
 fn validate_rejects_unknown_scene_name() {
-    let mut cfg = HashMap::new();
-    cfg.insert("ambient.00-00".into(), "nonexistent-scene".into());
+    let mut cfg = ConfigMap::new();
+    cfg.insert("ambient.00-00".to_string(), "nonexistent-scene".into());
     let err = validate_ambient_entries(&cfg).unwrap_err();
     assert!(
         err.contains("unknown scene 'nonexistent-scene'"),
@@ -304,9 +329,9 @@ fn validate_rejects_legacy_format_with_migration_hint() {
     // full migration message. This is the primary user-facing error
     // path — when a user runs `--testconf` on an old config, they see
     // this and learn how to migrate.
-    let mut cfg = HashMap::new();
+    let mut cfg = ConfigMap::new();
     cfg.insert(
-        "ambient.15-00".into(),
+        "ambient.15-00".to_string(),
         "neon-purple, signal, speed=50, density=0.65".into(),
     );
     let err = validate_ambient_entries(&cfg).unwrap_err();
@@ -317,7 +342,7 @@ fn validate_rejects_legacy_format_with_migration_hint() {
 
 #[test]
 fn validate_accepts_empty_schedule() {
-    let cfg = HashMap::new();
+    let cfg = ConfigMap::new();
     assert!(validate_ambient_entries(&cfg).is_ok());
 }
 
@@ -325,9 +350,12 @@ fn validate_accepts_empty_schedule() {
 fn validate_case_insensitive_custom_scene_lookup() {
     // Custom scene names are stored lowercase by collect_custom_scenes;
     // validate_ambient_entries should match case-insensitively.
-    let mut cfg = HashMap::new();
-    cfg.insert("scene-custom.afternoon.color".into(), "neon-green".into());
-    cfg.insert("ambient.15-00".into(), "AFTERNOON".into());
+    let mut cfg = ConfigMap::new();
+    cfg.insert(
+        "scene-custom.afternoon.color".to_string(),
+        "neon-green".into(),
+    );
+    cfg.insert("ambient.15-00".to_string(), "AFTERNOON".into());
     assert!(validate_ambient_entries(&cfg).is_ok());
 }
 
@@ -344,3 +372,370 @@ fn current_second_of_minute_bounded() {
     let s = current_second_of_minute();
     assert!(s < 60, "second of minute out of range: {s}");
 }
+
+// ── calendar rules ──
+
+fn date(year: i32, month: u8, day: u8) -> CivilDate {
+    CivilDate::new(year, month, day).unwrap()
+}
+
+fn days(s: &str) -> AmbientDays {
+    AmbientDays::parse(s).unwrap()
+}
+
+fn cal_entry(selector: &str, h: u32, scene: &str) -> AmbientEntry {
+    AmbientEntry {
+        days: days(selector),
+        ..entry(h, 0, scene)
+    }
+}
+
+#[test]
+fn civil_dates_step_across_months_and_leap_days() {
+    assert_eq!(WED.weekday(), 2);
+    assert_eq!(date(1970, 1, 1).weekday(), 3);
+    assert_eq!(date(2028, 2, 28).add_days(1), date(2028, 2, 29));
+    assert_eq!(date(2027, 2, 28).add_days(1), date(2027, 3, 1));
+    assert_eq!(date(2027, 1, 1).add_days(-1), date(2026, 12, 31));
+    assert_eq!(WED.add_days(-400).add_days(400), WED);
+    assert!(CivilDate::new(2027, 2, 29).is_none());
+    assert!(CivilDate::new(2026, 13, 1).is_none());
+}
+
+#[test]
+fn day_selectors_parse_and_print() {
+    for (input, canonical) in [
+        ("sat", "sat"),
+        ("Saturday", "sat"),
+        ("weekend", "weekends"),
+        ("weekdays", "weekdays"),
+        ("sat..sun", "weekends"),
+        ("mon..thu", "mon..thu"),
+        ("fri..mon", "fri..mon"),
+        ("12-24", "12-24"),
+        ("02-29", "02-29"),
+        ("12-24..01-01", "12-24..01-01"),
+        ("2026-11-03", "2026-11-03"),
+        ("2026-11-02..2026-11-06", "2026-11-02..2026-11-06"),
+    ] {
+        assert_eq!(days(input).to_string(), canonical, "{input}");
+    }
+    for bad in [
+        "someday",
+        "13-01",
+        "02-30",
+        "2027-02-29",
+        "12-24..2026-12-26",
+        "2026-12-26..2026-12-24",
+        "1-05",
+        "",
+    ] {
+        assert!(AmbientDays::parse(bad).is_err(), "{bad:?}");
+    }
+}
+
+#[test]
+fn day_selectors_match_dates() {
+    let sat = date(2026, 10, 17);
+    assert!(days("weekends").matches(sat));
+    assert!(!days("weekdays").matches(sat));
+    assert!(days("fri..mon").matches(sat));
+    assert!(!days("mon..thu").matches(sat));
+    assert!(days("12-24..01-01").matches(date(2026, 12, 31)));
+    assert!(days("12-24..01-01").matches(date(2027, 1, 1)));
+    assert!(!days("12-24..01-01").matches(date(2027, 1, 2)));
+    assert!(days("2026-11-02..2026-11-06").matches(date(2026, 11, 4)));
+    assert!(!days("2026-11-02..2026-11-06").matches(date(2027, 11, 4)));
+    assert!(days("10-17").matches(sat));
+}
+
+#[test]
+fn ambient_keys_with_day_selectors() {
+    assert_eq!(
+        parse_ambient_key("ambient.sat.09-00"),
+        Ok((days("sat"), 9, 0, None))
+    );
+    assert_eq!(
+        parse_ambient_key("ambient.12-24..12-26.18-30"),
+        Ok((days("12-24..12-26"), 18, 30, None))
+    );
+    assert_eq!(
+        parse_ambient_key("ambient.06-00"),
+        Ok((AmbientDays::Daily, 6, 0, None))
+    );
+    assert!(is_ambient_config_key("ambient.2026-11-03.09-00"));
+    assert!(!is_ambient_config_key("ambient.someday.09-00"));
+    assert!(!is_ambient_config_key("ambient.sat.25-00"));
+    let err = parse_ambient_key("ambient.someday.09-00").unwrap_err();
+    assert!(err.contains("unknown day selector 'someday'"), "{err}");
+}
+
+#[test]
+fn most_specific_selector_owns_the_day() {
+    let s = AmbientSchedule {
+        entries: vec![
+            entry(6, 0, "signal"),
+            entry(22, 0, "monolith"),
+            cal_entry("weekends", 9, "cinematic"),
+            cal_entry("sun", 11, "matrix"),
+            cal_entry("10-17", 12, "aurora"),
+            cal_entry("2026-10-17", 13, "neon"),
+            cal_entry("12-20..12-31", 8, "long"),
+            cal_entry("12-24..12-26", 8, "short"),
+        ],
+        location: None,
+        transition: Duration::ZERO,
+    };
+    let scenes = |d: CivilDate| -> Vec<&str> {
+        s.day_entries(d)
+            .iter()
+            .map(|(_, e)| e.scene.as_str())
+            .collect()
+    };
+    assert_eq!(scenes(WED), ["signal", "monolith"]);
+    assert_eq!(scenes(date(2026, 10, 24)), ["cinematic"], "a Saturday");
+    assert_eq!(scenes(date(2026, 10, 25)), ["matrix"], "sun beats weekends");
+    assert_eq!(
+        scenes(date(2027, 10, 17)),
+        ["aurora"],
+        "yearly beats weekday"
+    );
+    assert_eq!(scenes(date(2026, 10, 17)), ["neon"], "dated beats yearly");
+    assert_eq!(scenes(date(2026, 12, 25)), ["short"], "shorter range wins");
+    assert_eq!(scenes(date(2026, 12, 21)), ["long"]);
+}
+
+#[test]
+fn calendar_phases_carry_over_until_the_first_rule_of_the_day() {
+    let s = AmbientSchedule {
+        entries: vec![
+            entry(6, 0, "signal"),
+            entry(22, 0, "monolith"),
+            cal_entry("weekends", 9, "cinematic"),
+        ],
+        location: None,
+        transition: Duration::ZERO,
+    };
+    let (fri, sat, mon) = (date(2026, 10, 16), date(2026, 10, 17), date(2026, 10, 19));
+    // Saturday before 09:00: Friday's 22:00 phase is still running.
+    assert_eq!(s.current_phase(sat, 3 * 60).unwrap().scene, "monolith");
+    assert_eq!(s.current_phase(sat, 10 * 60).unwrap().scene, "cinematic");
+    // Monday before 06:00: Sunday's weekend phase is still running.
+    assert_eq!(s.current_phase(mon, 5 * 60).unwrap().scene, "cinematic");
+    assert_eq!(s.current_phase(mon, 7 * 60).unwrap().scene, "signal");
+    // After Friday 22:00 the next boundary is Saturday 09:00.
+    let (ahead, _, next) = s.next_phase(fri, 23 * 60).unwrap();
+    assert_eq!((ahead, next.scene.as_str()), (1, "cinematic"));
+    assert_eq!(s.seconds_to_next_phase(sat, 8 * 60 + 30, 0), Some(1800));
+}
+
+#[test]
+fn date_only_schedule_waits_for_its_date() {
+    let s = AmbientSchedule {
+        entries: vec![cal_entry("2026-11-03", 9, "signal")],
+        location: None,
+        transition: Duration::ZERO,
+    };
+    assert!(s.current_phase(WED, 12 * 60).is_none(), "never fired yet");
+    let (ahead, _, _) = s.next_phase(WED, 12 * 60).unwrap();
+    assert_eq!(ahead, 20);
+    assert_eq!(s.seconds_to_next_phase(WED, 12 * 60, 0), Some(3600));
+    assert_eq!(
+        s.current_phase(date(2026, 11, 10), 0).unwrap().scene,
+        "signal",
+        "stays active after its date"
+    );
+}
+
+#[test]
+fn collect_and_validate_calendar_keys() {
+    let mut cfg = ConfigMap::new();
+    cfg.insert("ambient.06-00".to_string(), "signal".into());
+    cfg.insert("ambient.weekends.09-00".to_string(), "monolith".into());
+    cfg.insert("ambient.2026-11-03.09-00".to_string(), "cinematic".into());
+    let s = collect_ambient_schedule(&cfg);
+    assert_eq!(s.entries.len(), 3);
+    assert_eq!(s.entries[0].label(), "06-00");
+    assert_eq!(s.entries[1].label(), "weekends.09-00");
+    assert_eq!(s.entries[2].label(), "2026-11-03.09-00");
+    assert!(validate_ambient_entries(&cfg).is_ok());
+
+    // Two spellings of the same slot: collect keeps one, validate rejects.
+    cfg.insert("ambient.sat..sun.09-00".to_string(), "cinematic".into());
+    assert_eq!(collect_ambient_schedule(&cfg).entries.len(), 3);
+    let err = validate_ambient_entries(&cfg).unwrap_err();
+    assert!(err.contains("same days and time"), "{err}");
+}
+
+// ── solar events ──
+
+const LONDON: GeoLocation = GeoLocation {
+    lat: 51.5074,
+    lon: -0.1278,
+};
+
+fn solar(s: &str) -> SolarTime {
+    SolarTime::parse(s).unwrap()
+}
+
+/// Minute of day in UTC, so expectations do not depend on the host zone.
+fn utc_minute(d: CivilDate, loc: GeoLocation, time: &str) -> Option<u32> {
+    solar::local_minute(d, loc, solar(time), |_| 0)
+}
+
+#[test]
+fn solar_times_match_published_tables() {
+    // 2026-06-21, London: sunrise 03:43 UTC, noon 12:02, sunset 20:21.
+    let midsummer = date(2026, 6, 21);
+    let near = |got: Option<u32>, want: u32| {
+        let got = got.expect("event happens");
+        assert!(got.abs_diff(want) <= 3, "got {got}, want {want}");
+    };
+    near(utc_minute(midsummer, LONDON, "sunrise"), 3 * 60 + 43);
+    near(utc_minute(midsummer, LONDON, "noon"), 12 * 60 + 2);
+    near(utc_minute(midsummer, LONDON, "sunset"), 20 * 60 + 21);
+    // Civil twilight brackets sunrise/sunset.
+    assert!(utc_minute(midsummer, LONDON, "dawn") < utc_minute(midsummer, LONDON, "sunrise"));
+    assert!(utc_minute(midsummer, LONDON, "dusk") > utc_minute(midsummer, LONDON, "sunset"));
+    // Offsets shift the event; past midnight they clamp to the day.
+    assert_eq!(
+        utc_minute(midsummer, LONDON, "sunset+30m"),
+        utc_minute(midsummer, LONDON, "sunset").map(|m| m + 30)
+    );
+    assert_eq!(utc_minute(midsummer, LONDON, "sunset+12h"), Some(1439));
+    assert_eq!(utc_minute(midsummer, LONDON, "sunrise-12h"), Some(0));
+}
+
+#[test]
+fn polar_days_have_no_sunset() {
+    let tromso = GeoLocation {
+        lat: 69.65,
+        lon: 18.96,
+    };
+    assert_eq!(utc_minute(date(2026, 6, 21), tromso, "sunset"), None);
+    assert_eq!(utc_minute(date(2026, 12, 21), tromso, "sunrise"), None);
+    assert!(utc_minute(date(2026, 6, 21), tromso, "noon").is_some());
+    assert!(utc_minute(date(2026, 3, 20), tromso, "sunrise").is_some());
+}
+
+#[test]
+fn solar_keys_parse_and_display() {
+    for s in [
+        "sunset",
+        "sunset+30m",
+        "sunrise-1h15m",
+        "dusk+2h",
+        "noon-45m",
+    ] {
+        assert_eq!(solar(s).to_string(), s);
+    }
+    assert_eq!(solar("dawn+90m").to_string(), "dawn+1h30m");
+    assert_eq!(solar("sunset+0m").to_string(), "sunset");
+    assert!(SolarTime::parse("sunset+30").is_err());
+    assert!(SolarTime::parse("sunset*30m").is_err());
+    assert!(SolarTime::parse("sunset+13h").is_err());
+    assert!(SolarTime::parse("moonrise").is_err());
+    assert_eq!(
+        parse_ambient_key("ambient.weekends.sunset+30m"),
+        Ok((days("weekends"), 0, 0, Some(solar("sunset+30m"))))
+    );
+    let err = parse_ambient_key("ambient.moonrise").unwrap_err();
+    assert!(err.contains("neither HH-MM nor a solar event"), "{err}");
+    assert!(!is_ambient_config_key("ambient.sunset+30"));
+}
+
+fn location(text: &str) -> Result<GeoLocation, String> {
+    GeoLocation::from_config(&crate::config_schema::value_from_text(
+        solar::LOCATION_CONFIG_KEY,
+        text,
+    ))
+}
+
+#[test]
+fn locations_parse_in_both_forms() {
+    let loc = location("52.52, 13.40").unwrap();
+    assert_eq!((loc.lat, loc.lon), (52.52, 13.4));
+    assert_eq!(location("[-33.87, 151.21]").unwrap().lat, -33.87);
+    assert!(location("52.52").is_err());
+    assert!(location("91, 0").is_err());
+    assert!(location("0, 181").is_err());
+    assert!(location("north, east").is_err());
+}
+
+#[test]
+fn schedule_resolves_solar_entries_per_day() {
+    let mut cfg = ConfigMap::new();
+    cfg.insert("ambient.06-00".to_string(), "signal".into());
+    cfg.insert("ambient.sunset+30m".to_string(), "cinematic".into());
+    // Without a location the solar entry is dropped and rejected.
+    assert_eq!(collect_ambient_schedule(&cfg).entries.len(), 1);
+    let err = validate_ambient_entries(&cfg).unwrap_err();
+    assert!(err.contains("ambient-location"), "{err}");
+
+    cfg.insert(
+        "ambient-location".to_string(),
+        crate::config_schema::value_from_text("ambient-location", "51.5074, -0.1278"),
+    );
+    assert!(validate_ambient_entries(&cfg).is_ok());
+    let s = collect_ambient_schedule(&cfg);
+    assert_eq!(s.location, Some(LONDON));
+    assert_eq!(s.entries.len(), 2);
+    let solar_entry = s.entries.iter().find(|e| e.solar.is_some()).unwrap();
+    assert_eq!(solar_entry.label(), "sunset+30m");
+
+    let sunset_min = |d: CivilDate| {
+        solar::local_minute(
+            d,
+            LONDON,
+            solar("sunset"),
+            crate::posix_time::utc_offset_secs_at,
+        )
+        .unwrap()
+    };
+    let day = s.day_entries(WED);
+    assert_eq!(day.len(), 2);
+    assert_eq!(day[1], (sunset_min(WED) + 30, solar_entry));
+    // Sunset moves with the seasons.
+    let june = date(2026, 6, 21);
+    assert_eq!(
+        s.entry_minute(solar_entry, june),
+        Some(sunset_min(june) + 30)
+    );
+    assert_ne!(sunset_min(june), sunset_min(WED));
+    let (ahead, minute, next) = s.next_phase(WED, 12 * 60).unwrap();
+    assert_eq!(
+        (ahead, minute, next.scene.as_str()),
+        (0, sunset_min(WED) + 30, "cinematic")
+    );
+}
+
+// ── ambient-transition ──
+
+#[test]
+fn parses_transition_durations() {
+    let secs = |v: &str| parse_ambient_transition(v).map(|d| d.as_secs());
+    assert_eq!(secs("90s"), Ok(90));
+    assert_eq!(secs("2m"), Ok(120));
+    assert_eq!(secs("1m30s"), Ok(90));
+    assert_eq!(secs("45"), Ok(45));
+    assert_eq!(secs("0"), Ok(0));
+    assert_eq!(secs("off"), Ok(0));
+    assert_eq!(secs("30m"), Ok(1800));
+    assert!(secs("31m").unwrap_err().contains("longer than 30 minutes"));
+    assert!(secs("slow").unwrap_err().contains("expected a duration"));
+}
+
+#[test]
+fn collect_reads_transition_and_defaults_to_instant() {
+    let mut cfg = ConfigMap::new();
+    cfg.insert("ambient.09-00".to_string(), "matrix".into());
+    assert!(collect_ambient_schedule(&cfg).transition.is_zero());
+    cfg.insert(TRANSITION_CONFIG_KEY.to_string(), "2m".into());
+    assert_eq!(
+        collect_ambient_schedule(&cfg).transition,
+        Duration::from_secs(120)
+    );
+    // Invalid values fall back to instant (testconf reports them).
+    cfg.insert(TRANSITION_CONFIG_KEY.to_string(), "forever".into());
+    assert!(collect_ambient_schedule(&cfg).transition.is_zero());
+}
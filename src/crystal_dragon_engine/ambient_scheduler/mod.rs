@@ -17,7 +17,8 @@
 //! This module implements that contract. The scheduler thread:
 //!
 //! 1. Computes `time_to_next_phase` (seconds until the next entry's `HH:MM`
-//!    boundary, with midnight wrap-around).
+//!    boundary, with midnight wrap-around; with calendar rules the next
+//!    boundary may be on a later day).
 //! 2. Sleeps for that duration (capped at 1 hour for reload responsiveness).
 //! 3. On wake, fires the new phase via the mpsc channel.
 //! 4. Returns to step 1.
@@ -63,6 +64,10 @@
 //! - **DST fall-back**: entries in the repeated hour (01:00–01:59) fire
 //!   twice. Acceptable — `apply_ambient_entry` is idempotent.
 //! - **Midnight wrap**: handled in `AmbientSchedule::seconds_to_next_phase`.
+//! - **Calendar rules**: a new day can bring a different timeline
+//!   (weekend, release day). Its first boundary is just another "next
+//!   phase" — possibly days away, so the 1-hour cap re-checks the date
+//!   along the way.
 
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Condvar, Mutex};
@@ -70,7 +75,8 @@ use std::thread;
 use std::time::Duration;
 
 use super::ambient::{
-    current_minute_of_day, current_second_of_minute, AmbientEntry, AmbientSchedule,
+    current_local_date, current_minute_of_day, current_second_of_minute, AmbientEntry,
+    AmbientSchedule,
 };
 
 /// Handle returned by [`spawn_ambient_scheduler`].
@@ -219,6 +225,7 @@ fn scheduler_loop(
     let mut last_fired_yday: i32 = -1;
 
     loop {
+        let today = current_local_date();
         let now_min = current_minute_of_day();
         let now_sec = current_second_of_minute();
 
@@ -228,15 +235,26 @@ fn scheduler_loop(
         //
         // also snapshot the generation counter so we can detect a
         // missed condvar notify later (see the wait block below).
-        let (current_entry, sleep_secs, seen_gen) = {
+        //
+        // `fired_today`: the current phase is one of today's entries and
+        // its boundary has passed (as opposed to a phase carried over from
+        // an earlier day's timeline).
+        let (current_entry, fired_today, sleep_secs, seen_gen) = {
             let Ok(s) = schedule.lock() else {
                 // Mutex poisoned — scheduler can't recover. Exit silently.
                 return;
             };
-            let current = s.current_phase(now_min).cloned();
-            let sleep = s.seconds_to_next_phase(now_min, now_sec).unwrap_or(60);
+            let current = s.current_phase(today, now_min).cloned();
+            let fired_today = current.as_ref().is_some_and(|c| {
+                s.day_entries(today)
+                    .iter()
+                    .any(|&(minute, e)| minute <= now_min && e == c)
+            });
+            let sleep = s
+                .seconds_to_next_phase(today, now_min, now_sec)
+                .unwrap_or(60);
             let gen = generation.load(Ordering::SeqCst);
-            (current, sleep, gen)
+            (current, fired_today, sleep, gen)
         };
 
         // Fire current phase if its identity changed since last fire.
@@ -250,9 +268,8 @@ fn scheduler_loop(
         if let Some(entry) = &current_entry {
             if last_applied.as_ref() != Some(entry) {
                 crate::lr_trace!(
-                    "ambient-scheduler: firing phase {:02}:{:02} (scene={})",
-                    entry.hour,
-                    entry.minute,
+                    "ambient-scheduler: firing phase {} (scene={})",
+                    entry.label(),
                     entry.scene
                 );
                 if tx.try_send(entry.clone()).is_err() {
@@ -280,7 +297,7 @@ fn scheduler_loop(
 
         // day-boundary refire. If we're in a new day (yday changed since
         // the last fire) AND the current phase's boundary has been crossed
-        // today (entry.minutes_of_day() <= now_min), refire even if
+        // today (`fired_today`), refire even if
         // `entry == last_applied`. This handles single-entry schedules where
         // the same entry is "current" across multiple days — without this, a
         // user who presses 'x' after 22:10 would never see aurora re-asserted
@@ -299,7 +316,7 @@ fn scheduler_loop(
         let today_yday = super::ambient::current_yday();
         if today_yday != last_fired_yday {
             if let Some(entry) = &current_entry {
-                if entry.minutes_of_day() <= now_min && last_applied.as_ref() == Some(entry) {
+                if fired_today && last_applied.as_ref() == Some(entry) {
                     // Same entry, new day, past today's boundary — refire.
                     // The `last_applied == Some(entry)` guard ensures we only
                     // take this branch when the existing != check above did
@@ -308,9 +325,8 @@ fn scheduler_loop(
                     // None or different), the != check above already fired it
                     // and we just mark today as "seen".
                     crate::lr_trace!(
-                        "ambient-scheduler: day-boundary refire {:02}:{:02} (scene={}, yday={})",
-                        entry.hour,
-                        entry.minute,
+                        "ambient-scheduler: day-boundary refire {} (scene={}, yday={})",
+                        entry.label(),
                         entry.scene,
                         today_yday
                     );
@@ -6,7 +6,7 @@
 //!
 //! Uses `use super::*;` to access ambient_scheduler.rs's private items unchanged.
 
-use crate::crystal_dragon_engine::ambient::AmbientEntry;
+use crate::crystal_dragon_engine::ambient::{AmbientDays, AmbientEntry};
 use std::collections::HashMap;
 
 /// Helper: build a minimal entry.
@@ -15,6 +15,8 @@ fn entry(h: u32, m: u32) -> AmbientEntry {
         hour: h,
         minute: m,
         scene: "cinematic".to_string(),
+        days: AmbientDays::Daily,
+        solar: None,
     }
 }
 
@@ -23,11 +25,15 @@ use super::*;
 fn handle_reload_swaps_schedule_atomically() {
     let s1 = AmbientSchedule {
         entries: vec![entry(6, 0)],
+        location: None,
+        transition: std::time::Duration::ZERO,
     };
     let handle = spawn_ambient_scheduler(s1);
     // Reload with a different schedule.
     let s2 = AmbientSchedule {
         entries: vec![entry(0, 0), entry(12, 0), entry(18, 0)],
+        location: None,
+        transition: std::time::Duration::ZERO,
     };
     handle.reload(s2);
     // Verify the swap took (lock and check).
@@ -39,6 +45,8 @@ fn handle_reload_swaps_schedule_atomically() {
 fn handle_reload_with_empty_schedule() {
     let s1 = AmbientSchedule {
         entries: vec![entry(12, 0)],
+        location: None,
+        transition: std::time::Duration::ZERO,
     };
     let handle = spawn_ambient_scheduler(s1);
     handle.reload(AmbientSchedule::default());
@@ -62,6 +70,8 @@ fn spawn_fires_current_phase_on_startup() {
     // today. The thread should fire the entry once at startup.
     let s = AmbientSchedule {
         entries: vec![entry(0, 0)],
+        location: None,
+        transition: std::time::Duration::ZERO,
     };
     let handle = spawn_ambient_scheduler(s);
     // Give the thread time to fire.
@@ -78,6 +88,8 @@ fn reload_fires_new_current_phase_if_different() {
     // Start with one entry at 00:00. Thread fires it.
     let s1 = AmbientSchedule {
         entries: vec![entry(0, 0)],
+        location: None,
+        transition: std::time::Duration::ZERO,
     };
     let handle = spawn_ambient_scheduler(s1);
 
@@ -91,6 +103,8 @@ fn reload_fires_new_current_phase_if_different() {
     // the past (so it's "current" at any time of day).
     let s2 = AmbientSchedule {
         entries: vec![entry(0, 1)],
+        location: None,
+        transition: std::time::Duration::ZERO,
     };
     handle.reload(s2);
 
@@ -157,11 +171,15 @@ fn ambient_entry_eq_compares_all_fields() {
         hour: 20,
         minute: 20,
         scene: "afternoon".to_string(),
+        days: AmbientDays::Daily,
+        solar: None,
     };
     let d = AmbientEntry {
         hour: 20,
         minute: 20,
         scene: "evening".to_string(),
+        days: AmbientDays::Daily,
+        solar: None,
     };
     assert_ne!(
         c, d,
@@ -172,11 +190,15 @@ fn ambient_entry_eq_compares_all_fields() {
         hour: 20,
         minute: 20,
         scene: "afternoon".to_string(),
+        days: AmbientDays::Daily,
+        solar: None,
     };
     let f = AmbientEntry {
         hour: 21,
         minute: 0,
         scene: "afternoon".to_string(),
+        days: AmbientDays::Daily,
+        solar: None,
     };
     assert_ne!(
         e, f,
@@ -195,7 +217,11 @@ fn reload_with_renamed_scene_triggers_refire() {
             hour: 20,
             minute: 20,
             scene: "evening".to_string(),
+            days: AmbientDays::Daily,
+            solar: None,
         }],
+        location: None,
+        transition: std::time::Duration::ZERO,
     };
     let handle = spawn_ambient_scheduler(s1);
 
@@ -211,7 +237,11 @@ fn reload_with_renamed_scene_triggers_refire() {
             hour: 20,
             minute: 20,
             scene: "afternoon".to_string(),
+            days: AmbientDays::Daily,
+            solar: None,
         }],
+        location: None,
+        transition: std::time::Duration::ZERO,
     };
     handle.reload(s2);
 
@@ -266,6 +296,8 @@ fn current_yday_returns_sane_value() {
 fn single_entry_no_spurious_refire_within_same_day() {
     let s = AmbientSchedule {
         entries: vec![entry(0, 0)],
+        location: None,
+        transition: std::time::Duration::ZERO,
     };
     let handle = spawn_ambient_scheduler(s);
 
@@ -330,6 +362,8 @@ fn reload_after_empty_refires_same_entry() {
     // wall-clock time when the test runs.
     let s_initial = AmbientSchedule {
         entries: vec![entry(0, 0)],
+        location: None,
+        transition: std::time::Duration::ZERO,
     };
     let handle = spawn_ambient_scheduler(s_initial);
 
@@ -363,6 +397,8 @@ fn reload_after_empty_refires_same_entry() {
     // cleared to None when the schedule went empty, so this fires.
     handle.reload(AmbientSchedule {
         entries: vec![entry(0, 0)],
+        location: None,
+        transition: std::time::Duration::ZERO,
     });
 
     // The scheduler MUST fire the entry within a reasonable window.
@@ -4,8 +4,11 @@
 //! Crystal Dragon Engine configuration.
 //!
 //! Holds the tuning knobs that control how the Crystal Dragon engine
-//! samples the system and selects color themes. These are owner-editable
-//! constants — no runtime config file exposure yet (silent-elegant mode).
+//! samples the system and selects color themes. Timing knobs are
+//! owner-editable constants; the sensor sources (`crystal-dragon-sensor`)
+//! and calc method (`crystal-dragon-calc`) come from config.toml.
+
+use crate::config_schema::{ConfigMap, ConfigValue};
 
 // ── Polling interval ─────────────────────────────────────────────────────
 
@@ -44,38 +47,305 @@ pub(crate) const CRYSTAL_DRAGON_CPU_EMA_ALPHA: f32 = 0.25;
 
 // ── Sensor mode ──────────────────────────────────────────────────────────
 
-/// Sensor input mode for the Crystal Dragon engine.
+/// Sensor input mode (source) for the Crystal Dragon engine.
 ///
-/// CPU mode is primary (reads process CPU% via sysinfo/procfs).
+/// CPU mode is the default (reads process CPU% via sysinfo/procfs).
 /// CLOCK mode is the fallback (derives a point from UTC time-of-day)
-/// when CPU sampling is unsupported on the current platform.
+/// when no configured source can be read on the current platform. The
+/// host-wide sources read `crate::hoststat` and make the palette follow
+/// the machine rather than cosmostrix itself.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) enum CrystalDragonSensorMode {
     /// Read process CPU% and map to point 1–99.
     Cpu,
     /// Derive point from UTC hour + minute (no CPU dependency).
     Clock,
+    /// 1-minute load average per online CPU (`/proc/loadavg`).
+    LoadAvg,
+    /// CPU stall pressure (`/proc/pressure/cpu`).
+    PsiCpu,
+    /// Memory stall pressure (`/proc/pressure/memory`).
+    PsiMemory,
+    /// I/O stall pressure (`/proc/pressure/io`).
+    PsiIo,
+    /// Memory in use (`/proc/meminfo`, `MemTotal - MemAvailable`).
+    Memory,
+    /// Battery drain (`/sys/class/power_supply`, `100 - capacity`).
+    Battery,
+    /// Point written by local tooling (`crystal-dragon-external`: file,
+    /// FIFO or command). See `crystal_dragon_engine::external`.
+    External,
+}
+
+impl CrystalDragonSensorMode {
+    /// Every mode, in config/documentation order.
+    pub(crate) const ALL: [Self; 9] = [
+        Self::Cpu,
+        Self::Clock,
+        Self::LoadAvg,
+        Self::PsiCpu,
+        Self::PsiMemory,
+        Self::PsiIo,
+        Self::Memory,
+        Self::Battery,
+        Self::External,
+    ];
+
+    /// Config name (`crystal-dragon-sensor` value).
+    #[must_use]
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Cpu => "cpu",
+            Self::Clock => "clock",
+            Self::LoadAvg => "loadavg",
+            Self::PsiCpu => "psi-cpu",
+            Self::PsiMemory => "psi-memory",
+            Self::PsiIo => "psi-io",
+            Self::Memory => "memory",
+            Self::Battery => "battery",
+            Self::External => "external",
+        }
+    }
+
+    /// Case-insensitive inverse of [`Self::name`].
+    #[must_use]
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase();
+        Self::ALL.into_iter().find(|m| m.name() == name)
+    }
+
+    fn bit(self) -> u16 {
+        1 << (self as u16)
+    }
+}
+
+/// How the points of several sensor sources combine into one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum CrystalDragonSensorMix {
+    /// Hottest source wins: any one stressed resource warms the rain.
+    #[default]
+    Max,
+    /// Average of the readable sources.
+    Mean,
+}
+
+impl CrystalDragonSensorMix {
+    /// Parse a `crystal-dragon-sensor-mix` value (case-insensitive).
+    #[must_use]
+    pub(crate) fn from_config(v: &str) -> Option<Self> {
+        match v.trim().to_ascii_lowercase().as_str() {
+            "max" => Some(Self::Max),
+            "mean" => Some(Self::Mean),
+            _ => None,
+        }
+    }
+
+    /// Config spelling.
+    #[must_use]
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Self::Max => "max",
+            Self::Mean => "mean",
+        }
+    }
+
+    /// Combine per-source points (1–99). `None` when nothing was read.
+    #[must_use]
+    pub(crate) fn combine(self, points: &[u8]) -> Option<u8> {
+        match self {
+            Self::Max => points.iter().copied().max(),
+            Self::Mean if points.is_empty() => None,
+            Self::Mean => {
+                let sum: u32 = points.iter().map(|&p| u32::from(p)).sum();
+                let n = points.len() as u32;
+                Some(((sum + n / 2) / n) as u8)
+            }
+        }
+    }
+}
+
+/// Set of sensor sources plus how to mix them. Copy-sized so it can live
+/// in `CrystalDragonControl` and the sensor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct CrystalDragonSensors {
+    mask: u16,
+    pub(crate) mix: CrystalDragonSensorMix,
+}
+
+impl Default for CrystalDragonSensors {
+    fn default() -> Self {
+        Self::only(CrystalDragonSensorMode::Cpu)
+    }
+}
+
+impl CrystalDragonSensors {
+    /// A single source with the default mix.
+    #[must_use]
+    pub(crate) fn only(mode: CrystalDragonSensorMode) -> Self {
+        Self {
+            mask: mode.bit(),
+            mix: CrystalDragonSensorMix::default(),
+        }
+    }
+
+    #[must_use]
+    pub(crate) fn contains(self, mode: CrystalDragonSensorMode) -> bool {
+        self.mask & mode.bit() != 0
+    }
+
+    /// Selected sources, in [`CrystalDragonSensorMode::ALL`] order.
+    pub(crate) fn iter(self) -> impl Iterator<Item = CrystalDragonSensorMode> {
+        CrystalDragonSensorMode::ALL
+            .into_iter()
+            .filter(move |m| self.contains(*m))
+    }
+
+    /// `loadavg+psi-memory (max)` — for `--doctor` and trace output.
+    #[must_use]
+    pub(crate) fn label(self) -> String {
+        let names: Vec<&str> = self.iter().map(CrystalDragonSensorMode::name).collect();
+        if names.len() > 1 {
+            format!("{} ({})", names.join("+"), self.mix.label())
+        } else {
+            names.join("+")
+        }
+    }
+}
+
+/// Config key listing the sensor sources.
+pub(crate) const SENSOR_CONFIG_KEY: &str = "crystal-dragon-sensor";
+
+/// Config key choosing how several sources combine.
+pub(crate) const SENSOR_MIX_CONFIG_KEY: &str = "crystal-dragon-sensor-mix";
+
+/// Parse a `crystal-dragon-sensor` value: one name or a list of names
+/// (`config_schema` has already split the comma-separated form).
+fn parse_sensor_list(value: &ConfigValue) -> Result<u16, String> {
+    let names = value
+        .items()
+        .ok_or_else(|| format!("expected a sensor name or an array of them, got {value}"))?;
+    let mut mask = 0;
+    for name in names.iter().map(|e| e.trim()) {
+        if name.is_empty() {
+            continue;
+        }
+        match CrystalDragonSensorMode::from_name(name) {
+            Some(mode) => mask |= mode.bit(),
+            None => {
+                let known: Vec<&str> = CrystalDragonSensorMode::ALL
+                    .iter()
+                    .map(|m| m.name())
+                    .collect();
+                return Err(format!(
+                    "unknown sensor '{name}' (expected: {})",
+                    known.join(", ")
+                ));
+            }
+        }
+    }
+    if mask == 0 {
+        return Err("expected at least one sensor".to_string());
+    }
+    Ok(mask)
+}
+
+/// Validate `crystal-dragon-sensor` on its own (`--testconf` / strict
+/// validation).
+#[must_use]
+pub(crate) fn validate_sensor_list(value: &ConfigValue) -> Option<String> {
+    parse_sensor_list(value).err()
+}
+
+/// Validate `crystal-dragon-sensor-mix`.
+#[must_use]
+pub(crate) fn validate_sensor_mix(v: &str) -> Option<String> {
+    CrystalDragonSensorMix::from_config(v)
+        .is_none()
+        .then(|| format!("expected max/mean, got '{v}'"))
+}
+
+/// [`validate_sensor_list`] plus the cross-key rule: the `external`
+/// source needs `crystal-dragon-external` to say where to read from.
+#[must_use]
+pub(crate) fn validate_sensor_with_cfg(value: &ConfigValue, cfg: &ConfigMap) -> Option<String> {
+    let mask = match parse_sensor_list(value) {
+        Ok(mask) => mask,
+        Err(err) => return Some(err),
+    };
+    let external_key = crate::crystal_dragon_engine::external::EXTERNAL_CONFIG_KEY;
+    let wants_external = mask & CrystalDragonSensorMode::External.bit() != 0;
+    (wants_external && !cfg.contains_key(external_key)).then(|| {
+        format!("the external sensor needs {external_key} = \"<path>\" or \"command:<cmd>\"")
+    })
+}
+
+/// Read `crystal-dragon-sensor` / `crystal-dragon-sensor-mix`. A bad value
+/// keeps its default and is reported as `(key, message)`.
+pub(crate) fn sensors_from_config(
+    cfg: &ConfigMap,
+) -> (CrystalDragonSensors, Vec<(String, String)>) {
+    let mut sensors = CrystalDragonSensors::default();
+    let mut errors = Vec::new();
+    if let Some(v) = cfg.get(SENSOR_CONFIG_KEY) {
+        match parse_sensor_list(v) {
+            Ok(mask) => sensors.mask = mask,
+            Err(e) => errors.push((SENSOR_CONFIG_KEY.to_string(), e)),
+        }
+    }
+    if let Some(v) = cfg.get(SENSOR_MIX_CONFIG_KEY).and_then(ConfigValue::as_str) {
+        match CrystalDragonSensorMix::from_config(v) {
+            Some(mix) => sensors.mix = mix,
+            None => errors.push((
+                SENSOR_MIX_CONFIG_KEY.to_string(),
+                format!("expected max/mean, got '{v}'"),
+            )),
+        }
+    }
+    (sensors, errors)
 }
 
 // ── Calc method ──────────────────────────────────────────────────────────
 
 /// Calculation method for theme selection within a temperature group.
 ///
-/// The owner chose calc-v1 (probabilistic weighted selection) for the
-/// initial Crystal Dragon release. calc-v2 (pattern state machine with
-/// memory) is reserved for future implementation.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-#[allow(dead_code)]
+/// calc-v1 (probabilistic weighted selection) is the default. calc-v2
+/// (pattern state machine with memory) is opt-in via
+/// `crystal-dragon-calc = "v2"` in config.toml.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub(crate) enum CrystalDragonCalcMethod {
     /// Probabilistic weighted selection: themes closer to the current
     /// point receive higher weight, but any theme in the group can be
     /// selected. This produces organic, unpredictable transitions.
+    #[default]
     Calc,
-    /// Pattern state machine with memory (NOT YET IMPLEMENTED).
-    /// Reserved for calc-v2 future release.
+    /// Pattern state machine with memory: no repeats among recent
+    /// themes, target follows the point's trend, and group changes
+    /// need a margin past the boundary. See `point_system::calc_v2`.
     CalcV2,
 }
 
+impl CrystalDragonCalcMethod {
+    /// Parse a `crystal-dragon-calc` value (case-insensitive):
+    /// `v1` / `calc-v1` / `calc` or `v2` / `calc-v2`.
+    #[must_use]
+    pub(crate) fn from_config(v: &str) -> Option<Self> {
+        match v.trim().to_ascii_lowercase().as_str() {
+            "v1" | "calc-v1" | "calc" => Some(Self::Calc),
+            "v2" | "calc-v2" => Some(Self::CalcV2),
+            _ => None,
+        }
+    }
+
+    /// Config spelling (`v1` / `v2`).
+    #[must_use]
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Self::Calc => "v1",
+            Self::CalcV2 => "v2",
+        }
+    }
+}
+
 // ── Config struct ────────────────────────────────────────────────────────
 
 /// Configuration for the Crystal Dragon engine.
@@ -94,9 +364,11 @@ pub(crate) struct CrystalDragonControl {
     pub drift_chance: f32,
     /// EMA alpha for CPU% smoothing.
     pub cpu_ema_alpha: f32,
-    /// Active sensor mode (CPU or CLOCK).
-    pub sensor_mode: CrystalDragonSensorMode,
-    /// Active calc method (Calc or CalcV2).
+    /// Sensor sources and mix. Set from `crystal-dragon-sensor` in
+    /// `create_cloud`.
+    pub sensors: CrystalDragonSensors,
+    /// Active calc method (Calc or CalcV2). Set from `crystal-dragon-calc`
+    /// in `create_cloud`.
     pub calc_method: CrystalDragonCalcMethod,
 }
 
@@ -107,7 +379,7 @@ impl Default for CrystalDragonControl {
             min_dwell_secs: CRYSTAL_DRAGON_MIN_DWELL_SECS,
             drift_chance: CRYSTAL_DRAGON_DRIFT_CHANCE,
             cpu_ema_alpha: CRYSTAL_DRAGON_CPU_EMA_ALPHA,
-            sensor_mode: CrystalDragonSensorMode::Cpu,
+            sensors: CrystalDragonSensors::default(),
             calc_method: CrystalDragonCalcMethod::Calc,
         }
     }
@@ -14,7 +14,10 @@ fn default_uses_owner_chosen_values() {
     assert_eq!(cfg.min_dwell_secs, 60.0);
     assert!((cfg.drift_chance - 0.12).abs() < f32::EPSILON);
     assert!((cfg.cpu_ema_alpha - 0.25).abs() < f32::EPSILON);
-    assert_eq!(cfg.sensor_mode, CrystalDragonSensorMode::Cpu);
+    assert_eq!(
+        cfg.sensors,
+        CrystalDragonSensors::only(CrystalDragonSensorMode::Cpu)
+    );
     assert_eq!(cfg.calc_method, CrystalDragonCalcMethod::Calc);
 }
 
@@ -30,3 +33,93 @@ fn calc_method_labels_are_stable() {
     assert_eq!(format!("{:?}", CrystalDragonCalcMethod::Calc), "Calc");
     assert_eq!(format!("{:?}", CrystalDragonCalcMethod::CalcV2), "CalcV2");
 }
+
+#[test]
+fn calc_method_parses_config_spellings() {
+    for v in ["v1", "calc-v1", "Calc", " V1 "] {
+        assert_eq!(
+            CrystalDragonCalcMethod::from_config(v),
+            Some(CrystalDragonCalcMethod::Calc),
+            "{v:?}"
+        );
+    }
+    for v in ["v2", "CALC-V2"] {
+        assert_eq!(
+            CrystalDragonCalcMethod::from_config(v),
+            Some(CrystalDragonCalcMethod::CalcV2),
+            "{v:?}"
+        );
+    }
+    assert_eq!(CrystalDragonCalcMethod::from_config("v3"), None);
+    assert_eq!(CrystalDragonCalcMethod::CalcV2.label(), "v2");
+    assert_eq!(CrystalDragonCalcMethod::default().label(), "v1");
+}
+
+fn sensor_cfg(pairs: &[(&str, &str)]) -> ConfigMap {
+    pairs
+        .iter()
+        .map(|(k, v)| {
+            (
+                (*k).to_string(),
+                crate::config_schema::value_from_text(k, v),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn sensor_sources_parse_from_every_list_form() {
+    for v in [
+        "[\"loadavg\", \"PSI-Memory\"]",
+        "loadavg, psi-memory",
+        "psi-memory,loadavg,",
+    ] {
+        let (sensors, errors) = sensors_from_config(&sensor_cfg(&[(SENSOR_CONFIG_KEY, v)]));
+        assert!(errors.is_empty(), "{v}: {errors:?}");
+        assert_eq!(sensors.label(), "loadavg+psi-memory (max)", "{v}");
+        assert!(!sensors.contains(CrystalDragonSensorMode::Cpu));
+    }
+    let (sensors, _) = sensors_from_config(&sensor_cfg(&[
+        (SENSOR_CONFIG_KEY, "battery"),
+        (SENSOR_MIX_CONFIG_KEY, "Mean"),
+    ]));
+    assert_eq!(sensors.label(), "battery");
+    assert_eq!(sensors.mix, CrystalDragonSensorMix::Mean);
+    for mode in CrystalDragonSensorMode::ALL {
+        assert_eq!(CrystalDragonSensorMode::from_name(mode.name()), Some(mode));
+    }
+}
+
+#[test]
+fn bad_sensor_values_keep_the_default() {
+    let (sensors, errors) = sensors_from_config(&sensor_cfg(&[
+        (SENSOR_CONFIG_KEY, "[\"loadavg\", \"gpu\"]"),
+        (SENSOR_MIX_CONFIG_KEY, "median"),
+    ]));
+    assert_eq!(sensors, CrystalDragonSensors::default());
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].1.contains("unknown sensor 'gpu'"), "{errors:?}");
+    assert!(errors[1].1.contains("expected max/mean"), "{errors:?}");
+    assert!(validate_sensor_list(&crate::config_schema::value_from_text(
+        SENSOR_CONFIG_KEY,
+        "[]"
+    ))
+    .is_some());
+    assert!(validate_sensor_list(&crate::config_schema::value_from_text(
+        SENSOR_CONFIG_KEY,
+        "cpu, clock"
+    ))
+    .is_none());
+}
+
+#[test]
+fn sensor_mix_combines_points() {
+    assert_eq!(CrystalDragonSensorMix::Max.combine(&[10, 80, 40]), Some(80));
+    assert_eq!(
+        CrystalDragonSensorMix::Mean.combine(&[10, 80, 40]),
+        Some(43)
+    );
+    assert_eq!(CrystalDragonSensorMix::Mean.combine(&[1, 2]), Some(2));
+    assert_eq!(CrystalDragonSensorMix::Max.combine(&[]), None);
+    assert_eq!(CrystalDragonSensorMix::Mean.combine(&[]), None);
+}
@@ -0,0 +1,378 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! External-signal source for the Crystal Dragon sensor.
+//!
+//! Lets local tooling drive the palette: a CI job or pager hook writes a
+//! point (`1`–`99`) or a group name (`cold` / `medium` / `hot`) and the
+//! rain follows. `crystal-dragon-external` names where it comes from:
+//!
+//! | Value | Read |
+//! |-------|------|
+//! | `/run/ci/point` | regular file, re-read every [`FILE_REFRESH`] |
+//! | same path, but a FIFO (`mkfifo`) | every line written, as it arrives |
+//! | `command:ci-status --point` | run via the shell every [`COMMAND_INTERVAL`] |
+//!
+//! The last non-empty line wins; anything that is not a point or a group
+//! name is ignored. A missing file, a failed or timed-out command, or a
+//! bad value clears the reading, and the sensor skips the source until
+//! the next good one. FIFO readings persist until the next line.
+//!
+//! ## Threading
+//!
+//! Reads happen on one background thread (FIFOs and commands can block),
+//! which publishes the latest point through an atomic. The event loop
+//! copies it into the sensor each frame, so the render thread never
+//! touches the file system for this. Nothing here opens a network
+//! connection.
+
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::config_schema::{get_str, ConfigMap};
+use crate::crystal_dragon_engine::palette_groups::TemperatureGroup;
+use crate::crystal_dragon_engine::sensor::group_point_range;
+
+/// Config key naming the external signal.
+pub(crate) const EXTERNAL_CONFIG_KEY: &str = "crystal-dragon-external";
+
+/// `command:` prefix selecting the command form.
+const COMMAND_PREFIX: &str = "command:";
+
+/// How often a regular file is re-read.
+pub(crate) const FILE_REFRESH: Duration = Duration::from_secs(5);
+
+/// How often the command runs (start to start).
+pub(crate) const COMMAND_INTERVAL: Duration = Duration::from_secs(15);
+
+/// A command still running after this is killed; its reading is dropped.
+pub(crate) const COMMAND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Worker sleep between checks (stop flag, FIFO data, command exit).
+const TICK: Duration = Duration::from_millis(250);
+
+/// Bytes of a file or command output considered (the tail).
+const READ_LIMIT: u64 = 4096;
+
+/// Where the external signal comes from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum ExternalSource {
+    /// Regular file or FIFO (told apart when opened). `~` is expanded.
+    Path(PathBuf),
+    /// Shell command line.
+    Command(String),
+}
+
+impl ExternalSource {
+    /// Parse a `crystal-dragon-external` value.
+    pub(crate) fn from_config(v: &str) -> Result<Self, String> {
+        let v = v.trim();
+        if let Some(cmd) = v.strip_prefix(COMMAND_PREFIX) {
+            let cmd = cmd.trim();
+            if cmd.is_empty() {
+                return Err("command: needs a command line".to_string());
+            }
+            return Ok(Self::Command(cmd.to_string()));
+        }
+        if v.is_empty() {
+            return Err("expected a file path or command:<command line>".to_string());
+        }
+        let path = crate::safepath::expand_user_path(v);
+        if !path.is_absolute() {
+            return Err(format!("'{v}' must be an absolute path (or start with ~/)"));
+        }
+        Ok(Self::Path(path))
+    }
+}
+
+/// Parse one signal: the last non-empty line, as a point `1`–`99`
+/// (decimals are rounded) or a group name. `None` for anything else.
+#[must_use]
+pub(crate) fn parse_signal(text: &str) -> Option<u8> {
+    let line = text.lines().map(str::trim).rfind(|l| !l.is_empty())?;
+    let group = match line.to_ascii_lowercase().as_str() {
+        "cold" => Some(TemperatureGroup::Cold),
+        "medium" => Some(TemperatureGroup::Medium),
+        "hot" => Some(TemperatureGroup::Hot),
+        _ => None,
+    };
+    if let Some(group) = group {
+        let (lo, hi) = group_point_range(group);
+        return Some(lo + (hi - lo) / 2);
+    }
+    let n: f32 = line.parse().ok()?;
+    (1.0..=99.0).contains(&n).then(|| n.round() as u8)
+}
+
+/// Validate a `crystal-dragon-external` value.
+#[must_use]
+pub(crate) fn validate_external_value(v: &str) -> Option<String> {
+    ExternalSource::from_config(v).err()
+}
+
+/// Read `crystal-dragon-external`. A bad value is reported and ignored.
+pub(crate) fn external_from_config(
+    cfg: &ConfigMap,
+) -> (Option<ExternalSource>, Vec<(String, String)>) {
+    match get_str(cfg, EXTERNAL_CONFIG_KEY).map(ExternalSource::from_config) {
+        None => (None, Vec::new()),
+        Some(Ok(source)) => (Some(source), Vec::new()),
+        Some(Err(e)) => (None, vec![(EXTERNAL_CONFIG_KEY.to_string(), e)]),
+    }
+}
+
+// ── Worker handle ────────────────────────────────────────────────────────
+
+/// Owns the reader thread for the configured source (if any).
+///
+/// Held by the event loop: [`Self::latest`] each frame,
+/// [`Self::reload`] after a live config reload. Dropping it stops the
+/// thread at its next tick.
+pub(crate) struct ExternalSignalHandle {
+    source: Option<ExternalSource>,
+    /// Latest point, `0` = no reading.
+    latest: Arc<AtomicU8>,
+    stop: Arc<AtomicBool>,
+}
+
+impl ExternalSignalHandle {
+    /// Start reading `source`; `None` starts nothing.
+    #[must_use]
+    pub(crate) fn spawn(source: Option<ExternalSource>) -> Self {
+        let latest = Arc::new(AtomicU8::new(0));
+        let stop = Arc::new(AtomicBool::new(false));
+        if let Some(source) = source.clone() {
+            let (latest, stop) = (Arc::clone(&latest), Arc::clone(&stop));
+            let spawned = thread::Builder::new()
+                .name("cosmostrix-external-signal".to_string())
+                .spawn(move || run_worker(&source, &latest, &stop));
+            if let Err(e) = spawned {
+                crate::output::eprintln_error_labeled(&format!(
+                    "{EXTERNAL_CONFIG_KEY}: could not start reader thread: {e}"
+                ));
+            }
+        }
+        Self {
+            source,
+            latest,
+            stop,
+        }
+    }
+
+    /// Latest point, or `None` when there is no good reading.
+    #[must_use]
+    pub(crate) fn latest(&self) -> Option<u8> {
+        match self.latest.load(Ordering::Relaxed) {
+            0 => None,
+            p => Some(p),
+        }
+    }
+
+    /// Switch to `source` (live reload). No-op when unchanged; otherwise
+    /// the old thread is stopped and its reading dropped.
+    pub(crate) fn reload(&mut self, source: Option<ExternalSource>) {
+        if source != self.source {
+            *self = Self::spawn(source);
+        }
+    }
+}
+
+impl Drop for ExternalSignalHandle {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+fn publish(latest: &AtomicU8, point: Option<u8>) {
+    latest.store(point.unwrap_or(0), Ordering::Relaxed);
+}
+
+/// Sleep `total` in [`TICK`] steps; `false` once `stop` is set.
+fn sleep_unless_stopped(total: Duration, stop: &AtomicBool) -> bool {
+    let deadline = Instant::now() + total;
+    while Instant::now() < deadline {
+        if stop.load(Ordering::Relaxed) {
+            return false;
+        }
+        thread::sleep(TICK.min(deadline.saturating_duration_since(Instant::now())));
+    }
+    !stop.load(Ordering::Relaxed)
+}
+
+fn run_worker(source: &ExternalSource, latest: &AtomicU8, stop: &AtomicBool) {
+    match source {
+        ExternalSource::Path(path) => loop {
+            if is_fifo(path) {
+                read_fifo(path, latest, stop);
+            } else {
+                publish(
+                    latest,
+                    read_file_tail(path).as_deref().and_then(parse_signal),
+                );
+            }
+            if !sleep_unless_stopped(FILE_REFRESH, stop) {
+                return;
+            }
+        },
+        ExternalSource::Command(cmd) => loop {
+            let started = Instant::now();
+            publish(
+                latest,
+                run_command(cmd, stop).as_deref().and_then(parse_signal),
+            );
+            let rest = COMMAND_INTERVAL.saturating_sub(started.elapsed());
+            if !sleep_unless_stopped(rest, stop) {
+                return;
+            }
+        },
+    }
+}
+
+/// Last [`READ_LIMIT`] bytes of a regular file.
+fn read_file_tail(path: &Path) -> Option<String> {
+    let mut file = std::fs::File::open(path).ok()?;
+    let len = file.metadata().ok()?.len();
+    file.seek(SeekFrom::Start(len.saturating_sub(READ_LIMIT)))
+        .ok()?;
+    let mut buf = Vec::new();
+    file.take(READ_LIMIT).read_to_end(&mut buf).ok()?;
+    Some(String::from_utf8_lossy(&buf).into_owned())
+}
+
+#[cfg(unix)]
+fn is_fifo(path: &Path) -> bool {
+    use std::os::unix::fs::FileTypeExt;
+    std::fs::metadata(path).is_ok_and(|m| m.file_type().is_fifo())
+}
+
+#[cfg(not(unix))]
+fn is_fifo(_path: &Path) -> bool {
+    false
+}
+
+/// Read lines from a FIFO until stopped (or the FIFO disappears).
+///
+/// Opened non-blocking and kept open, so writers never block on
+/// `open()` and the stop flag is seen within a [`TICK`]. Each complete
+/// line that parses replaces the reading; it is kept between writers.
+#[cfg(unix)]
+fn read_fifo(path: &Path, latest: &AtomicU8, stop: &AtomicBool) {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let Ok(mut fifo) = std::fs::OpenOptions::new()
+        .read(true)
+        .custom_flags(libc::O_NONBLOCK)
+        .open(path)
+    else {
+        return;
+    };
+    let mut pending: Vec<u8> = Vec::new();
+    let mut buf = [0u8; 512];
+    while !stop.load(Ordering::Relaxed) {
+        match fifo.read(&mut buf) {
+            Ok(0) => {
+                // No writer connected: a final unterminated line counts.
+                if !pending.is_empty() {
+                    if let Some(p) = parse_signal(&String::from_utf8_lossy(&pending)) {
+                        publish(latest, Some(p));
+                    }
+                    pending.clear();
+                }
+                if !is_fifo(path) {
+                    return;
+                }
+                thread::sleep(TICK);
+            }
+            Ok(n) => {
+                pending.extend_from_slice(&buf[..n]);
+                if let Some(end) = pending.iter().rposition(|&b| b == b'\n') {
+                    let lines = String::from_utf8_lossy(&pending[..end]).into_owned();
+                    if let Some(p) = parse_signal(&lines) {
+                        publish(latest, Some(p));
+                    }
+                    pending.drain(..=end);
+                }
+                if pending.len() as u64 > READ_LIMIT {
+                    pending.clear();
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => thread::sleep(TICK),
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(_) => return,
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn read_fifo(_path: &Path, _latest: &AtomicU8, _stop: &AtomicBool) {}
+
+/// Run `cmd` through the platform shell; its stdout on success within
+/// [`COMMAND_TIMEOUT`], else `None`.
+fn run_command(cmd: &str, stop: &AtomicBool) -> Option<String> {
+    use std::process::{Command, Stdio};
+
+    #[cfg(unix)]
+    let mut command = {
+        let mut c = Command::new("sh");
+        c.arg("-c").arg(cmd);
+        c
+    };
+    #[cfg(not(unix))]
+    let mut command = {
+        let mut c = Command::new("cmd");
+        c.arg("/C").arg(cmd);
+        c
+    };
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    // Drain stdout on a helper thread so a chatty command can't fill the
+    // pipe and stall until the timeout.
+    let mut stdout = child.stdout.take()?;
+    let reader = thread::spawn(move || {
+        let mut out = Vec::new();
+        let mut buf = [0u8; 512];
+        while let Ok(n) = stdout.read(&mut buf) {
+            if n == 0 {
+                break;
+            }
+            out.extend_from_slice(&buf[..n]);
+            let excess = out.len().saturating_sub(READ_LIMIT as usize);
+            out.drain(..excess);
+        }
+        out
+    });
+    let deadline = Instant::now() + COMMAND_TIMEOUT;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) if Instant::now() < deadline && !stop.load(Ordering::Relaxed) => {
+                thread::sleep(TICK);
+            }
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                break None;
+            }
+        }
+    };
+    // A killed command's grandchildren may still hold the pipe; don't
+    // wait on the reader in that case.
+    let status = status?;
+    let out = reader.join().ok()?;
+    status
+        .success()
+        .then(|| String::from_utf8_lossy(&out).into_owned())
+}
+
+// ── Tests ────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,139 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! External-signal parsing and reader tests.
+
+use super::*;
+
+fn temp_dir(tag: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("cosmostrix-ext-{tag}-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Poll `handle` until it reports `want` (or give up after 5 s).
+fn wait_for(handle: &ExternalSignalHandle, want: Option<u8>) -> Option<u8> {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while handle.latest() != want && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(20));
+    }
+    handle.latest()
+}
+
+#[test]
+fn signals_are_points_or_group_names() {
+    assert_eq!(parse_signal("42\n"), Some(42));
+    assert_eq!(parse_signal(" 87.6 "), Some(88));
+    assert_eq!(parse_signal("HOT"), Some(83));
+    assert_eq!(parse_signal("cold\n"), Some(17));
+    assert_eq!(parse_signal("medium"), Some(50));
+    assert_eq!(parse_signal("10\n90\n\n"), Some(90), "last line wins");
+    for bad in ["", "0", "100", "-5", "warm", "NaN", "12 pts"] {
+        assert_eq!(parse_signal(bad), None, "{bad:?}");
+    }
+}
+
+#[test]
+fn sources_parse_from_config() {
+    assert_eq!(
+        ExternalSource::from_config("command: ci-status --point"),
+        Ok(ExternalSource::Command("ci-status --point".to_string()))
+    );
+    assert_eq!(
+        ExternalSource::from_config("/run/ci/point"),
+        Ok(ExternalSource::Path(PathBuf::from("/run/ci/point")))
+    );
+    assert!(validate_external_value("command:").is_some());
+    assert!(validate_external_value("relative/point")
+        .unwrap()
+        .contains("absolute path"));
+    let cfg = [(EXTERNAL_CONFIG_KEY.to_string(), "".into())].into();
+    let (source, errors) = external_from_config(&cfg);
+    assert_eq!(source, None);
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn file_source_follows_the_file() {
+    let dir = temp_dir("file");
+    let path = dir.join("point");
+    std::fs::write(&path, "hot\n").unwrap();
+    let handle = ExternalSignalHandle::spawn(Some(ExternalSource::Path(path.clone())));
+    assert_eq!(wait_for(&handle, Some(83)), Some(83));
+    drop(handle);
+
+    std::fs::write(&path, "not a point").unwrap();
+    let handle = ExternalSignalHandle::spawn(Some(ExternalSource::Path(path)));
+    thread::sleep(Duration::from_millis(100));
+    assert_eq!(handle.latest(), None);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[cfg(unix)]
+#[test]
+fn fifo_lines_arrive_without_blocking_writers() {
+    use std::io::Write;
+
+    let dir = temp_dir("fifo");
+    let path = dir.join("point.fifo");
+    let c_path = std::ffi::CString::new(path.to_string_lossy().as_bytes()).unwrap();
+    // SAFETY: valid NUL-terminated path; mkfifo has no other preconditions.
+    assert_eq!(unsafe { libc::mkfifo(c_path.as_ptr(), 0o600) }, 0);
+
+    let handle = ExternalSignalHandle::spawn(Some(ExternalSource::Path(path.clone())));
+    // The reader keeps the FIFO open, so a writer's open() returns once
+    // the thread is up; retry until then.
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let mut writer = loop {
+        use std::os::unix::fs::OpenOptionsExt;
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .custom_flags(libc::O_NONBLOCK)
+            .open(&path)
+        {
+            Ok(w) => break w,
+            Err(_) if Instant::now() < deadline => thread::sleep(Duration::from_millis(20)),
+            Err(e) => panic!("no reader on the FIFO: {e}"),
+        }
+    };
+    writer.write_all(b"20\n7").unwrap();
+    assert_eq!(wait_for(&handle, Some(20)), Some(20));
+    drop(writer);
+    // Closing the writer completes the unterminated line.
+    assert_eq!(wait_for(&handle, Some(7)), Some(7));
+    drop(handle);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[cfg(unix)]
+#[test]
+fn command_output_and_failures() {
+    let handle = ExternalSignalHandle::spawn(Some(ExternalSource::Command(
+        "echo starting; echo 91".to_string(),
+    )));
+    assert_eq!(wait_for(&handle, Some(91)), Some(91));
+
+    let stop = AtomicBool::new(false);
+    assert_eq!(run_command("echo 50; exit 3", &stop), None, "non-zero exit");
+    assert_eq!(
+        run_command("printf 'medium'", &stop).as_deref(),
+        Some("medium")
+    );
+}
+
+#[test]
+fn reload_switches_and_stops_sources() {
+    let dir = temp_dir("reload");
+    let (a, b) = (dir.join("a"), dir.join("b"));
+    std::fs::write(&a, "10").unwrap();
+    std::fs::write(&b, "60").unwrap();
+    let mut handle = ExternalSignalHandle::spawn(Some(ExternalSource::Path(a.clone())));
+    assert_eq!(wait_for(&handle, Some(10)), Some(10));
+    handle.reload(Some(ExternalSource::Path(a)));
+    assert_eq!(handle.latest(), Some(10), "unchanged source keeps reading");
+    handle.reload(Some(ExternalSource::Path(b)));
+    assert_eq!(wait_for(&handle, Some(60)), Some(60));
+    handle.reload(None);
+    assert_eq!(handle.latest(), None);
+    let _ = std::fs::remove_dir_all(&dir);
+}
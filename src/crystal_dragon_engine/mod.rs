@@ -11,7 +11,8 @@
 //! Maps system state (CPU usage or wall-clock time) to a **point** (1–99),
 //! classifies the point into a **temperature group** (Cold / Medium / Hot),
 //! and selects a color theme from that group via probabilistic weighted
-//! selection (calc-v1). The selected theme is handed to the Chroma Dragon
+//! selection (calc-v1, default) or the calc-v2 pattern state machine
+//! (`crystal-dragon-calc = "v2"`). The selected theme is handed to the Chroma Dragon
 //! engine for a smooth 300 ms OKLab wave transition.
 //!
 //! ### 2. Ambient scheduler (time-of-day → scene)
@@ -26,9 +27,10 @@
 //! | File | Role |
 //! |------|------|
 //! | `crystal_dragon_control` | Config struct + defaults (polling interval, sensor mode, calc method) |
-//! | `sensor` | CPU sampling (sysinfo/procfs) + CLOCK fallback (UTC time-based) |
+//! | `sensor` | CPU / host-load sampling + CLOCK fallback (UTC time-based) |
+//! | `external` | Background reader for the external signal (file, FIFO, command) |
 //! | `palette_groups` | 44 themes partitioned into Cold(14) / Medium(14) / Hot(14) + Reserved(2) |
-//! | `point_system` | calc-v1: probabilistic weighted theme selection within a group |
+//! | `point_system` | calc-v1 weighted selection + calc-v2 state machine (memory, trend, hysteresis) |
 //! | `ambient` | Time-of-day schedule types, parsing, validation, startup apply |
 //! | `ambient_scheduler` | Background thread that fires schedule entries |
 //! | `ambient_diag` | Diagnostics counters (exit summary) |
@@ -36,7 +38,7 @@
 //! ## Owner decisions
 //!
 //! - **Option A (Silent-Elegant)**: no HUD indicator, no verbose logging of drift events
-//! - **calc-v1**: probabilistic weighted selection (not pattern-based calc-v2)
+//! - **calc-v1 by default**: probabilistic weighted selection; calc-v2 is opt-in
 //! - **Polling 60 s**: sensor sampling every 60 seconds
 //! - **CPU primary**, CLOCK fallback when CPU sampling is unsupported
 //!
@@ -64,6 +66,7 @@ pub(crate) mod ambient;
 pub(crate) mod ambient_diag;
 pub(crate) mod ambient_scheduler;
 pub(crate) mod crystal_dragon_control;
+pub(crate) mod external;
 pub(crate) mod palette_groups;
 pub(crate) mod point_system;
 pub(crate) mod sensor;
@@ -109,18 +109,23 @@ pub(crate) fn group_themes(group: TemperatureGroup) -> &'static [ColorScheme] {
 /// At distance 0 → weight 1.0 (maximum).
 /// At distance 33 → weight ~0.23 (still selectable).
 pub(crate) fn theme_weight(current_point: u8, theme_index: usize, group_size: usize) -> f32 {
-    // Map theme_index (0..group_size-1) to a natural point within the
-    // group's range. This distributes themes evenly across the group.
     let group = super::sensor::point_to_group(current_point);
+    let distance = (current_point as f32 - natural_point(group, theme_index, group_size)).abs();
+    1.0 / (1.0 + distance * 0.1)
+}
+
+/// The "natural point" of the theme at `theme_index` in `group`: the
+/// group's point range spread evenly over its themes (first theme at the
+/// low edge, last at the high edge).
+#[must_use]
+pub(crate) fn natural_point(group: TemperatureGroup, theme_index: usize, group_size: usize) -> f32 {
     let (lo, hi) = super::sensor::group_point_range(group);
     let range = (hi - lo) as f32;
-    let natural_point = if group_size <= 1 {
+    if group_size <= 1 {
         (lo + hi) as f32 / 2.0
     } else {
         lo as f32 + (theme_index as f32 / (group_size - 1) as f32) * range
-    };
-    let distance = (current_point as f32 - natural_point).abs();
-    1.0 / (1.0 + distance * 0.1)
+    }
 }
 
 // ── Tests ────────────────────────────────────────────────────────────────
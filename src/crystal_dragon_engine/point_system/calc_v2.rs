@@ -0,0 +1,190 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! calc-v2: pattern state machine with memory.
+//!
+//! calc-v1 draws every pick independently, so a steady point keeps landing
+//! on the same few themes near it. calc-v2 carries state between picks:
+//!
+//! 1. **Group hysteresis.** The active group only changes once the point
+//!    is [`CALC_V2_HYSTERESIS`] points past the group's edge, so a point
+//!    hovering at 33/34 stays in one group.
+//! 2. **Trend.** The point at the previous pick is remembered. A rise of
+//!    more than [`CALC_V2_TREND_DEADBAND`] points shifts the target
+//!    [`CALC_V2_TREND_LEAD`] points up the group (warmer end), a fall
+//!    shifts it down; otherwise the target is the point itself.
+//! 3. **Memory.** The last [`CALC_V2_MEMORY`] themes shown (including the
+//!    current one, so user picks count) are not picked again. When the
+//!    whole group is in memory, the theme shown longest ago wins.
+//!
+//! Among the remaining themes the pick is weighted by distance to the
+//! target with the calc-v1 weight curve, so it is still organic, just
+//! never a repeat.
+
+use rand::distr::{Distribution, Uniform};
+
+use crate::crystal_dragon_engine::palette_groups::{group_themes, natural_point, TemperatureGroup};
+use crate::crystal_dragon_engine::sensor::{group_point_range, point_to_group};
+use crate::runtime::ColorScheme;
+
+/// Themes remembered (and not repeated), current theme included.
+pub(crate) const CALC_V2_MEMORY: usize = 6;
+
+/// Points past a group edge before the active group follows the point.
+pub(crate) const CALC_V2_HYSTERESIS: u8 = 4;
+
+/// Point change between picks that still counts as steady.
+pub(crate) const CALC_V2_TREND_DEADBAND: u8 = 2;
+
+/// How far along the trend the target point leads the current point.
+pub(crate) const CALC_V2_TREND_LEAD: f32 = 8.0;
+
+/// Direction of the point since the previous pick.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Trend {
+    Steady,
+    Rising,
+    Falling,
+}
+
+/// calc-v2 state. Carried on `Cloud` next to the sensor and kept across
+/// live reloads.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct CalcV2State {
+    /// Group the last pick came from (`None` before the first pick).
+    pub(super) group: Option<TemperatureGroup>,
+    pub(super) last_point: Option<u8>,
+    /// Trend measured at the last pick.
+    pub(super) trend: Trend,
+    /// Most recent first.
+    pub(super) recent: [Option<ColorScheme>; CALC_V2_MEMORY],
+}
+
+impl Default for CalcV2State {
+    fn default() -> Self {
+        Self {
+            group: None,
+            last_point: None,
+            trend: Trend::Steady,
+            recent: [None; CALC_V2_MEMORY],
+        }
+    }
+}
+
+impl CalcV2State {
+    /// Pick the next theme for `current_point`, or `None` when the group
+    /// has nothing but the current theme.
+    pub(crate) fn select(
+        &mut self,
+        current_point: u8,
+        current_scheme: ColorScheme,
+        mt: &mut rand::rngs::StdRng,
+    ) -> Option<ColorScheme> {
+        self.observe(current_point);
+        self.remember(current_scheme);
+        let group = self.group.unwrap_or_else(|| point_to_group(current_point));
+        let themes = group_themes(group);
+
+        let (lo, hi) = group_point_range(group);
+        let lead = match self.trend {
+            Trend::Steady => 0.0,
+            Trend::Rising => CALC_V2_TREND_LEAD,
+            Trend::Falling => -CALC_V2_TREND_LEAD,
+        };
+        let target = (current_point as f32 + lead).clamp(lo as f32, hi as f32);
+
+        let fresh: Vec<(usize, ColorScheme)> = themes
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, theme)| !self.recent.contains(&Some(*theme)))
+            .collect();
+        let picked = if fresh.is_empty() {
+            self.oldest_remembered(themes, current_scheme)?
+        } else {
+            weighted_pick(&fresh, group, themes.len(), target, mt)
+        };
+        self.remember(picked);
+        Some(picked)
+    }
+
+    /// Update the trend and the hysteresis group from a new point.
+    fn observe(&mut self, point: u8) {
+        if let Some(last) = self.last_point {
+            self.trend = if point > last.saturating_add(CALC_V2_TREND_DEADBAND) {
+                Trend::Rising
+            } else if point.saturating_add(CALC_V2_TREND_DEADBAND) < last {
+                Trend::Falling
+            } else {
+                Trend::Steady
+            };
+        }
+        self.last_point = Some(point);
+
+        let raw = point_to_group(point);
+        self.group = Some(match self.group {
+            Some(group) if group != raw => {
+                let (lo, hi) = group_point_range(group);
+                let past_edge = point > hi.saturating_add(CALC_V2_HYSTERESIS)
+                    || point.saturating_add(CALC_V2_HYSTERESIS) < lo;
+                if past_edge {
+                    raw
+                } else {
+                    group
+                }
+            }
+            _ => raw,
+        });
+    }
+
+    /// Move `scheme` to the front of the memory.
+    fn remember(&mut self, scheme: ColorScheme) {
+        let end = self
+            .recent
+            .iter()
+            .position(|s| *s == Some(scheme))
+            .unwrap_or(CALC_V2_MEMORY - 1);
+        self.recent.copy_within(0..end, 1);
+        self.recent[0] = Some(scheme);
+    }
+
+    /// Every theme of the group is in memory: take the one shown longest
+    /// ago that is not the current theme.
+    fn oldest_remembered(
+        &self,
+        themes: &[ColorScheme],
+        current_scheme: ColorScheme,
+    ) -> Option<ColorScheme> {
+        self.recent
+            .iter()
+            .rev()
+            .flatten()
+            .copied()
+            .find(|s| *s != current_scheme && themes.contains(s))
+    }
+}
+
+/// Weighted draw over `candidates` (index in the group, theme), favouring
+/// natural points close to `target`.
+fn weighted_pick(
+    candidates: &[(usize, ColorScheme)],
+    group: TemperatureGroup,
+    group_size: usize,
+    target: f32,
+    mt: &mut rand::rngs::StdRng,
+) -> ColorScheme {
+    let weights: Vec<f32> = candidates
+        .iter()
+        .map(|&(i, _)| 1.0 / (1.0 + (natural_point(group, i, group_size) - target).abs() * 0.1))
+        .collect();
+    let total: f32 = weights.iter().sum();
+    let u_dist = Uniform::new(0.0f32, 1.0f32).expect("uniform f32 always valid");
+    let mut u = u_dist.sample(mt) * total;
+    for (&(_, theme), w) in candidates.iter().zip(&weights) {
+        if u < *w {
+            return theme;
+        }
+        u -= w;
+    }
+    candidates[candidates.len() - 1].1
+}
@@ -1,7 +1,8 @@
 // Copyright (C) 2026 rezky_nightky
 // SPDX-License-Identifier: GPL-3.0-only
 
-//! Crystal Dragon point system: calc-v1 probabilistic weighted selection.
+//! Crystal Dragon point system: calc-v1 probabilistic weighted selection
+//! and the calc-v2 pattern state machine (see [`calc_v2`]).
 //!
 //! Given the current point (1–99) from the sensor, this module selects
 //! a color theme from the appropriate temperature group using a
@@ -21,6 +22,10 @@
 //! the group can be selected, but themes closer to the current system
 //! intensity are favored.
 
+mod calc_v2;
+
+pub(crate) use calc_v2::CalcV2State;
+
 use rand::distr::{Distribution, Uniform};
 
 use crate::crystal_dragon_engine::palette_groups::{group_themes, theme_weight};
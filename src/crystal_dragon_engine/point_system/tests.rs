@@ -102,3 +102,101 @@ fn cdf_select_produces_valid_indices() {
         assert!(themes.contains(&selected));
     }
 }
+
+// ── calc-v2 ─────────────────────────────────────────────────────────────
+
+#[test]
+fn calc_v2_never_repeats_within_the_memory_window() {
+    let mut mt = seeded_rng();
+    let mut state = CalcV2State::default();
+    let mut shown = vec![ColorScheme::Blue];
+    for _ in 0..200 {
+        let current = *shown.last().unwrap();
+        let next = state.select(17, current, &mut mt).expect("14 cold themes");
+        assert!(group_themes(TemperatureGroup::Cold).contains(&next));
+        let window = shown.len().saturating_sub(calc_v2::CALC_V2_MEMORY - 1);
+        assert!(
+            !shown[window..].contains(&next),
+            "{next:?} repeated within {:?}",
+            &shown[window..]
+        );
+        shown.push(next);
+    }
+}
+
+#[test]
+fn calc_v2_user_picks_enter_memory() {
+    let mut mt = seeded_rng();
+    let mut state = CalcV2State::default();
+    let user_pick = group_themes(TemperatureGroup::Cold)[3];
+    let next = state.select(17, user_pick, &mut mt).unwrap();
+    assert_ne!(next, user_pick);
+    assert!(state.recent.contains(&Some(user_pick)));
+    assert_eq!(state.recent[0], Some(next));
+}
+
+#[test]
+fn calc_v2_group_changes_only_past_the_hysteresis_margin() {
+    let mut mt = seeded_rng();
+    let mut state = CalcV2State::default();
+    let mut current = ColorScheme::Blue;
+    // Cold ends at 33; hovering just across the edge keeps Cold.
+    for point in [30, 34, 36, 37, 35] {
+        current = state.select(point, current, &mut mt).unwrap();
+        assert_eq!(state.group, Some(TemperatureGroup::Cold), "point {point}");
+        assert!(group_themes(TemperatureGroup::Cold).contains(&current));
+    }
+    current = state.select(38, current, &mut mt).unwrap();
+    assert_eq!(state.group, Some(TemperatureGroup::Medium));
+    assert!(group_themes(TemperatureGroup::Medium).contains(&current));
+    // Back down by less than the margin stays Medium.
+    state.select(30, current, &mut mt).unwrap();
+    assert_eq!(state.group, Some(TemperatureGroup::Medium));
+    state.select(29, current, &mut mt).unwrap();
+    assert_eq!(state.group, Some(TemperatureGroup::Cold));
+}
+
+#[test]
+fn calc_v2_trend_shifts_picks_toward_the_direction_of_travel() {
+    use crate::crystal_dragon_engine::palette_groups::natural_point;
+
+    // Mean natural point of picks at point 50, reached by a rise or a fall.
+    let mean_pick = |from: u8| {
+        let mut mt = seeded_rng();
+        let themes = group_themes(TemperatureGroup::Medium);
+        let mut sum = 0.0;
+        for _ in 0..400 {
+            let mut state = CalcV2State::default();
+            state.select(from, ColorScheme::Green, &mut mt);
+            let pick = state.select(50, ColorScheme::Green, &mut mt).unwrap();
+            assert_eq!(
+                state.trend,
+                if from < 50 {
+                    calc_v2::Trend::Rising
+                } else {
+                    calc_v2::Trend::Falling
+                }
+            );
+            let i = themes.iter().position(|t| *t == pick).unwrap();
+            sum += natural_point(TemperatureGroup::Medium, i, themes.len());
+        }
+        sum / 400.0
+    };
+    let rising = mean_pick(40);
+    let falling = mean_pick(60);
+    assert!(
+        rising > falling + 3.0,
+        "rising mean {rising} should sit above falling mean {falling}"
+    );
+}
+
+#[test]
+fn calc_v2_small_changes_are_steady() {
+    let mut mt = seeded_rng();
+    let mut state = CalcV2State::default();
+    state.select(50, ColorScheme::Green, &mut mt);
+    state.select(52, ColorScheme::Green, &mut mt);
+    assert_eq!(state.trend, calc_v2::Trend::Steady);
+    state.select(48, ColorScheme::Green, &mut mt);
+    assert_eq!(state.trend, calc_v2::Trend::Falling);
+}
@@ -1,11 +1,16 @@
 // Copyright (C) 2026 rezky_nightky
 // SPDX-License-Identifier: GPL-3.0-only
 
-//! Crystal Dragon sensor: CPU polling + CLOCK fallback.
+//! Crystal Dragon sensor: CPU / host-load polling + CLOCK fallback.
 //!
 //! Reads the system state and produces a **point** (1–99) that the
 //! point system maps to a temperature group and color theme.
 //!
+//! The sources come from `crystal-dragon-sensor` (default: `cpu`). Each
+//! readable source yields its own point; several are combined by
+//! `crystal-dragon-sensor-mix` (`max` by default, or `mean`). When no
+//! source can be read this poll, the CLOCK point is used instead.
+//!
 //! ## CPU mode (primary)
 //!
 //! Samples process CPU% via `cpustat::current_cpu_ns()`, smooths with
@@ -18,6 +23,23 @@
 //! Low CPU → low point → Cold group (Snow, Moon, Aurora, …).
 //! High CPU → high point → Hot group (Sun, Fire, Red, …).
 //!
+//! ## Host sources
+//!
+//! `loadavg`, `psi-cpu`, `psi-memory`, `psi-io`, `memory` and `battery`
+//! read the whole machine via `crate::hoststat`. Each reader already
+//! returns 0–100%, mapped with the same linear formula as CPU. They are
+//! not EMA-smoothed: load and PSI are kernel moving averages already, and
+//! memory / battery move slowly.
+//!
+//! ## External source
+//!
+//! `external` takes the point published by the
+//! `crystal_dragon_engine::external` reader (file, FIFO or command),
+//! handed over by the event loop via
+//! [`CrystalDragonSensor::set_external_reading`]. It goes through the
+//! same EMA as CPU, so a jump from cold to hot crosses into Hot after a
+//! few polls rather than on the first.
+//!
 //! ## CLOCK mode (fallback)
 //!
 //! When CPU sampling is unsupported (Windows, some sandboxes), derives
@@ -38,9 +60,11 @@ use std::time::Instant;
 
 use crate::cpustat;
 use crate::crystal_dragon_engine::crystal_dragon_control::{
-    CrystalDragonControl, CrystalDragonSensorMode, CRYSTAL_DRAGON_CPU_EMA_ALPHA,
+    CrystalDragonControl, CrystalDragonSensorMode, CrystalDragonSensors,
+    CRYSTAL_DRAGON_CPU_EMA_ALPHA,
 };
 use crate::crystal_dragon_engine::palette_groups::TemperatureGroup;
+use crate::hoststat::{self, PsiResource};
 
 // ── Point range ──────────────────────────────────────────────────────────
 
@@ -65,7 +89,7 @@ const MEDIUM_MAX: u8 = 66;
 /// Crystal Dragon sensor state. Persists across poll ticks.
 ///
 /// Carried as a field on `Cloud`. Owns the EMA-smoothed CPU%,
-/// the last sample timestamp, and the active sensor mode.
+/// the last sample timestamp, and the configured sources.
 #[derive(Clone, Copy)]
 #[allow(dead_code)]
 pub(crate) struct CrystalDragonSensor {
@@ -81,11 +105,16 @@ pub(crate) struct CrystalDragonSensor {
     theme_entered_at: Instant,
     /// Whether CPU sampling is supported on this platform.
     ///
-    /// Probed once at construction. When `false`, sensor falls back
-    /// to CLOCK mode regardless of config.
+    /// Probed once at construction. When `false`, the `cpu` source never
+    /// reads and the sensor falls back to CLOCK unless another source
+    /// is configured.
     cpu_supported: bool,
-    /// Effective sensor mode (may differ from config if CPU unsupported).
-    effective_mode: CrystalDragonSensorMode,
+    /// Configured sources and mix.
+    sources: CrystalDragonSensors,
+    /// Latest external point (set by the event loop), `None` = no reading.
+    external_reading: Option<u8>,
+    /// EMA-smoothed external point. Reset when the reading goes away.
+    external_ema: Option<f32>,
 }
 
 #[allow(dead_code)]
@@ -93,16 +122,10 @@ impl CrystalDragonSensor {
     /// Construct a new sensor. Probes CPU sampling support once.
     ///
     /// `now` should be `Instant::now()` from the caller's context.
-    /// `control` provides the configured sensor mode; if CPU is
-    /// unsupported, the effective mode falls back to CLOCK.
+    /// `control` provides the configured sensor sources.
     pub(crate) fn new(now: Instant, control: CrystalDragonControl) -> Self {
         let initial_cpu_ns = cpustat::current_cpu_ns();
         let cpu_supported = initial_cpu_ns.is_some();
-        let effective_mode = if cpu_supported {
-            control.sensor_mode
-        } else {
-            CrystalDragonSensorMode::Clock
-        };
         // Start at point 17 (lower-middle of Cold group) for a calm
         // cold-start. This avoids an immediate theme change on the
         // first poll tick.
@@ -113,22 +136,49 @@ impl CrystalDragonSensor {
             current_point: 17,
             theme_entered_at: now,
             cpu_supported,
-            effective_mode,
+            sources: control.sensors,
+            external_reading: None,
+            external_ema: None,
         }
     }
 
     /// Poll the sensor and compute a new point.
     ///
     /// Called every `polling_secs` seconds by the Crystal Dragon tick.
-    /// Samples CPU (if in CPU mode) or reads UTC time (if in CLOCK mode),
-    /// then maps to a 1–99 point.
+    /// Reads every configured source, maps each to a 1–99 point and
+    /// mixes them; falls back to UTC time when nothing could be read.
     ///
     /// `now` is the caller's `Instant`.
     pub(crate) fn poll(&mut self, now: Instant) {
-        self.current_point = match self.effective_mode {
-            CrystalDragonSensorMode::Cpu => self.poll_cpu(now),
-            CrystalDragonSensorMode::Clock => self.poll_clock(),
-        };
+        let mut points = [0u8; CrystalDragonSensorMode::ALL.len()];
+        let mut n = 0;
+        for mode in self.sources.iter() {
+            if let Some(point) = self.read_source(mode, now) {
+                points[n] = point;
+                n += 1;
+            }
+        }
+        self.current_point = self
+            .sources
+            .mix
+            .combine(&points[..n])
+            .unwrap_or_else(|| self.poll_clock());
+    }
+
+    /// Configured sources and mix.
+    pub(crate) fn sources(self) -> CrystalDragonSensors {
+        self.sources
+    }
+
+    /// Hand over the latest external-signal point (`None` = no reading).
+    pub(crate) fn set_external_reading(&mut self, point: Option<u8>) {
+        self.external_reading = point;
+    }
+
+    /// Switch sources (live reload). Sampling state is kept, so a CPU
+    /// source that stays selected keeps its EMA.
+    pub(crate) fn set_sources(&mut self, sources: CrystalDragonSensors) {
+        self.sources = sources;
     }
 
     /// Current point (1–99). Read by the point system to select a theme.
@@ -172,21 +222,18 @@ impl CrystalDragonSensor {
         self.theme_entered_at += elapsed;
     }
 
-    // ── Private: CPU sampling ────────────────────────────────────────
-
-    /// Sample CPU%, update EMA, map to 1–99 point.
-    fn poll_cpu(&mut self, now: Instant) -> u8 {
-        let cpu = self.sample_cpu_percent(now);
-        match cpu {
-            Some(pct) => {
-                // Linear map: 0% → 1, 100% → 99
-                let raw = (pct * 0.99).clamp(0.0, 98.01);
-                (raw.round() as u8).clamp(POINT_MIN, POINT_MAX)
-            }
-            None => self.poll_clock(), // Fallback if sample failed mid-run
+    /// Point for one source, or `None` when it can't be read right now.
+    fn read_source(&mut self, mode: CrystalDragonSensorMode, now: Instant) -> Option<u8> {
+        match mode {
+            CrystalDragonSensorMode::Cpu => self.sample_cpu_percent(now).map(percent_to_point),
+            CrystalDragonSensorMode::Clock => Some(self.poll_clock()),
+            CrystalDragonSensorMode::External => self.smooth_external(),
+            host => host_percent(host).map(percent_to_point),
         }
     }
 
+    // ── Private: CPU sampling ────────────────────────────────────────
+
     /// Sample process CPU% and update the EMA. Returns the smoothed
     /// CPU% (or `None` if the sample failed).
     fn sample_cpu_percent(&mut self, now: Instant) -> Option<f32> {
@@ -211,6 +258,23 @@ impl CrystalDragonSensor {
         self.cpu_ema
     }
 
+    /// EMA over the external point (same alpha as CPU).
+    fn smooth_external(&mut self) -> Option<u8> {
+        let Some(point) = self.external_reading else {
+            self.external_ema = None;
+            return None;
+        };
+        let point = f32::from(point);
+        let smoothed = match self.external_ema {
+            None => point,
+            Some(prev) => {
+                prev * (1.0 - CRYSTAL_DRAGON_CPU_EMA_ALPHA) + point * CRYSTAL_DRAGON_CPU_EMA_ALPHA
+            }
+        };
+        self.external_ema = Some(smoothed);
+        Some((smoothed.round() as u8).clamp(POINT_MIN, POINT_MAX))
+    }
+
     // ── Private: CLOCK fallback ──────────────────────────────────────
 
     /// Derive point from UTC time-of-day.
@@ -237,6 +301,30 @@ impl CrystalDragonSensor {
 
 // ── Pure functions ───────────────────────────────────────────────────────
 
+/// Linear map of a 0–100% reading to a point: 0% → 1, 100% → 99.
+fn percent_to_point(pct: f32) -> u8 {
+    let raw = (pct * 0.99).clamp(0.0, 98.01);
+    (raw.round() as u8).clamp(POINT_MIN, POINT_MAX)
+}
+
+/// Current host-wide reading (0–100%) for a host source. `None` for
+/// `cpu` / `clock` / `external` (read by the sensor itself) and for
+/// sources the platform doesn't expose.
+#[must_use]
+pub(crate) fn host_percent(mode: CrystalDragonSensorMode) -> Option<f32> {
+    match mode {
+        CrystalDragonSensorMode::Cpu
+        | CrystalDragonSensorMode::Clock
+        | CrystalDragonSensorMode::External => None,
+        CrystalDragonSensorMode::LoadAvg => hoststat::loadavg_percent(),
+        CrystalDragonSensorMode::PsiCpu => hoststat::psi_percent(PsiResource::Cpu),
+        CrystalDragonSensorMode::PsiMemory => hoststat::psi_percent(PsiResource::Memory),
+        CrystalDragonSensorMode::PsiIo => hoststat::psi_percent(PsiResource::Io),
+        CrystalDragonSensorMode::Memory => hoststat::memory_used_percent(),
+        CrystalDragonSensorMode::Battery => hoststat::battery_drain_percent(),
+    }
+}
+
 /// Map a point (1–99) to a temperature group.
 ///
 /// ```text
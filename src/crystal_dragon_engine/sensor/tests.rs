@@ -89,3 +89,89 @@ fn record_theme_transition_updates_timestamp() {
     sensor.record_theme_transition(later);
     assert_eq!(sensor.theme_entered_at(), later);
 }
+
+fn sensor_with(sources: &str) -> CrystalDragonSensor {
+    use crate::crystal_dragon_engine::crystal_dragon_control::{
+        sensors_from_config, SENSOR_CONFIG_KEY,
+    };
+    let cfg = [(
+        SENSOR_CONFIG_KEY.to_string(),
+        crate::config_schema::value_from_text(SENSOR_CONFIG_KEY, sources),
+    )]
+    .into();
+    let (sensors, errors) = sensors_from_config(&cfg);
+    assert!(errors.is_empty(), "{errors:?}");
+    let control = CrystalDragonControl {
+        sensors,
+        ..CrystalDragonControl::default()
+    };
+    CrystalDragonSensor::new(Instant::now(), control)
+}
+
+#[test]
+fn percent_to_point_maps_linearly() {
+    assert_eq!(percent_to_point(0.0), 1);
+    assert_eq!(percent_to_point(50.0), 50);
+    assert_eq!(percent_to_point(100.0), 98);
+    assert_eq!(percent_to_point(400.0), 98);
+}
+
+#[test]
+fn clock_source_polls_the_clock_point() {
+    let mut sensor = sensor_with("clock");
+    sensor.poll(Instant::now());
+    let clock = sensor.poll_clock();
+    // The minute may roll over between the two reads.
+    assert!(sensor.current_point().abs_diff(clock) <= 1);
+}
+
+#[test]
+fn host_sources_fall_back_to_clock_when_unreadable() {
+    let mut sensor = sensor_with("loadavg, memory, battery");
+    sensor.poll(Instant::now());
+    let readable: Vec<u8> = [
+        CrystalDragonSensorMode::LoadAvg,
+        CrystalDragonSensorMode::Memory,
+        CrystalDragonSensorMode::Battery,
+    ]
+    .into_iter()
+    .filter_map(host_percent)
+    .map(percent_to_point)
+    .collect();
+    if readable.is_empty() {
+        assert!(sensor.current_point().abs_diff(sensor.poll_clock()) <= 1);
+    } else {
+        // Max mix: never below the coolest readable source.
+        assert!(sensor.current_point() >= *readable.iter().min().unwrap());
+    }
+    assert!((1..=99).contains(&sensor.current_point()));
+}
+
+#[test]
+fn set_sources_retargets_the_sensor() {
+    let mut sensor = sensor_with("cpu");
+    let clock_only = sensor_with("clock").sources();
+    sensor.set_sources(clock_only);
+    assert_eq!(sensor.sources(), clock_only);
+}
+
+#[test]
+fn external_source_is_ema_smoothed() {
+    let mut sensor = sensor_with("external");
+    let now = Instant::now();
+    sensor.set_external_reading(Some(83));
+    sensor.poll(now);
+    assert_eq!(sensor.current_point(), 83, "first reading is taken as-is");
+    sensor.set_external_reading(Some(17));
+    sensor.poll(now);
+    assert_eq!(sensor.current_point(), 67, "83 * 0.75 + 17 * 0.25");
+    assert_eq!(sensor.current_group(), TemperatureGroup::Hot);
+
+    // No reading: clock fallback, and the EMA starts over afterwards.
+    sensor.set_external_reading(None);
+    sensor.poll(now);
+    assert!(sensor.current_point().abs_diff(sensor.poll_clock()) <= 1);
+    sensor.set_external_reading(Some(17));
+    sensor.poll(now);
+    assert_eq!(sensor.current_point(), 17);
+}
@@ -92,6 +92,13 @@ pub(crate) fn print_doctor_report(args: &Args) {
         if let Some(reason) = pipeline.disable_reason(effective) {
             s.field("chroma_disable_reason", reason);
         }
+        if effective.is_quantized() {
+            s.field(
+                "color_quantize",
+                "oklab nearest-entry (chroma output mapped at draw time)",
+            );
+            s.field("color_dither", args.color_dither.as_str());
+        }
         s.field("identity", ri.identity);
         s.field("gpu_usage", "not_applicable");
         s.field(
@@ -245,6 +252,27 @@ pub(crate) fn print_doctor_report(args: &Args) {
                 s.field("cpu_ema_percent", "n/a (no sample yet)");
             }
         }
+        // Host-wide sources (`crystal-dragon-sensor`): current reading or
+        // n/a, so it's clear which ones this machine can feed.
+        use crate::crystal_dragon_engine::crystal_dragon_control::CrystalDragonSensorMode;
+        let host: Vec<String> = CrystalDragonSensorMode::ALL
+            .into_iter()
+            .filter(|m| {
+                !matches!(
+                    m,
+                    CrystalDragonSensorMode::Cpu
+                        | CrystalDragonSensorMode::Clock
+                        | CrystalDragonSensorMode::External
+                )
+            })
+            .map(
+                |mode| match crate::crystal_dragon_engine::sensor::host_percent(mode) {
+                    Some(pct) => format!("{}={pct:.0}%", mode.name()),
+                    None => format!("{}=n/a", mode.name()),
+                },
+            )
+            .collect();
+        s.field("host_sensors", &host.join(" "));
         s.field("polling_secs", &control.polling_secs.to_string());
         s.field("drift_chance", &control.drift_chance.to_string());
         s.field("source", "diagnostic probe (not runtime state)");
@@ -302,12 +330,53 @@ pub(crate) fn print_doctor_report(args: &Args) {
         }
     }
 
+    // TERMINAL PROBE section: what the terminal answered to
+    // `--probe-terminal`, and the capabilities in effect afterwards
+    // (probe answers override the TERM/TERM_PROGRAM heuristics).
+    {
+        let s = r.section("TERMINAL PROBE");
+        match crate::termdetect::probe_outcome() {
+            None => {
+                s.field("status", "off (enable with --probe-terminal true)");
+            }
+            Some(Err(reason)) => {
+                s.field("status", &format!("not run: {reason}"));
+            }
+            Some(Ok(report)) if !report.answered() => {
+                s.field(
+                    "status",
+                    &format!("no reply within {} ms (heuristics kept)", report.elapsed_ms),
+                );
+            }
+            Some(Ok(report)) => {
+                s.field("status", &format!("answered in {} ms", report.elapsed_ms));
+                for (key, value) in report.doctor_fields() {
+                    s.field(key, &value);
+                }
+            }
+        }
+        let caps = crate::termdetect::detect();
+        s.field("sync_output", if caps.sync_output { "yes" } else { "no" });
+        s.field(
+            "kitty_keyboard_enabled",
+            if caps.kitty_keyboard { "yes" } else { "no" },
+        );
+        s.field("fps_source", caps.dynamic_fps_source);
+    }
+
     // COMPATIBILITY section
     {
         let s = r.section("COMPATIBILITY");
         s.field("terminal_class", terminal_family);
         s.field("color_capability", color_capability(effective));
         s.field("background", background_guidance(args.color_bg));
+        if args.color_bg == ColorBg::DefaultBackground {
+            let canvas = crate::termdetect::terminal_background().map_or_else(
+                || "dark assumed (terminal did not report its background)".to_string(),
+                |bg| bg.describe(),
+            );
+            s.field("canvas", &canvas);
+        }
         s.field("normal_exit", "non-destructive mode/style restore");
         s.field(
             "reset_terminal",
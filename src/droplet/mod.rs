@@ -41,13 +41,11 @@ use crossterm::style::Color;
 use crate::cloud::{CharLoc, DrawCtx};
 use crate::constants::{
     ADVANCE_REMAINDER_CAP, DROPLET_GRAVITY, DROPLET_TERMINAL_VELOCITY_MULT,
-    EDGE_FADE_BOLD_THRESHOLD, FOG_MIN_FACTOR, FOG_ROWS, FRACTIONAL_BLOOM_AMP,
-    FRACTIONAL_HEAD_BRIGHTNESS_AMP, HEAD_BLOOM_CELLS, HEAD_BLOOM_INTENSITY, HEAD_BLOOM_SIGMA,
-    HEAD_LINGER_BRIGHTNESS_MS, HEAD_SELFBLOOM_BASE, HEAD_SHIMMER_PERIOD_SECS,
+    EDGE_FADE_BOLD_THRESHOLD, FRACTIONAL_BLOOM_AMP, FRACTIONAL_HEAD_BRIGHTNESS_AMP,
+    HEAD_BLOOM_CELLS, HEAD_LINGER_BRIGHTNESS_MS, HEAD_SELFBLOOM_BASE, HEAD_SHIMMER_PERIOD_SECS,
     MOUSE_FLASH_INTENSITY, MOUSE_FLASH_RING_WIDTH, MOUSE_FLASH_SECONDARY_FRAC,
     MOUSE_GLOW_INTENSITY, MOUSE_GLOW_RADIUS_COLS, MOUSE_GLOW_RADIUS_LINES,
-    PARALLAX_BRIGHTNESS_MULT, PARALLAX_CONTRAST_REDUCTION, PARALLAX_GLYPH_DIM,
-    PARALLAX_HEAD_BLOOM_MULT, PARALLAX_HEAD_SELFBLOOM_MULT, PARALLAX_SATURATION_MULT,
+    PARALLAX_CONTRAST_REDUCTION, PARALLAX_GLYPH_DIM, PARALLAX_HEAD_SELFBLOOM_MULT,
     RAIN_SHADOW_LAYER_MULT, STARTUP_EASE_TAU, STARTUP_VELOCITY_FRACTION,
     TRANSITION_ENERGY_DURATION_SECS, TRANSITION_ENERGY_SATURATION_BOOST,
     TRANSITION_HEAD_GLOW_BOOST, TURBULENCE_AMPLITUDE, TURBULENCE_FREQ, VIGNETTE_LAYER_MULT,
@@ -58,12 +56,15 @@ use crate::constants::{
 use crate::constants::PARALLAX_LAYERS;
 // Re-export brightness factors so existing `crate::droplet::viewport_edge_fade`
 // etc. references continue to resolve after extraction to brightness_factors.rs.
-// viewport_edge_fade + crt_vignette_factor are used by production code
-// (cloud/spawn.rs, droplet draw path). rain_shadow_factor + vignette_factor
-// are used by tests via `crate::droplet::*` — gate those with #[cfg(test)].
-pub(crate) use crate::brightness_factors::{crt_vignette_factor, viewport_edge_fade};
+// viewport_edge_fade is used by production code (cloud/spawn.rs). The
+// constant-default crt_vignette_factor / vignette_factor (production uses
+// the `_with` variants fed by `[rain-tuning]`) and rain_shadow_factor are
+// used by tests via `crate::droplet::*` — gate those with #[cfg(test)].
+pub(crate) use crate::brightness_factors::viewport_edge_fade;
 #[cfg(test)]
-pub(crate) use crate::brightness_factors::{rain_shadow_factor, vignette_factor};
+pub(crate) use crate::brightness_factors::{
+    crt_vignette_factor, rain_shadow_factor, vignette_factor,
+};
 use crate::frame::Frame;
 use crate::palette;
 
@@ -559,17 +560,18 @@ impl Droplet {
                     let dist_from_head = self.head_put_line.saturating_sub(line);
                     if dist_from_head > 0 && dist_from_head < HEAD_BLOOM_CELLS {
                         let d = dist_from_head as f32;
-                        let gaussian = (-d * d / (2.0 * HEAD_BLOOM_SIGMA * HEAD_BLOOM_SIGMA)).exp();
+                        let sigma = ctx.tuning.head_bloom_sigma;
+                        let gaussian = (-d * d / (2.0 * sigma * sigma)).exp();
                         let bloom = if is_new_generation {
-                            HEAD_BLOOM_INTENSITY + TRANSITION_HEAD_GLOW_BOOST
+                            ctx.tuning.head_bloom_intensity + TRANSITION_HEAD_GLOW_BOOST
                         } else {
-                            HEAD_BLOOM_INTENSITY
+                            ctx.tuning.head_bloom_intensity
                         };
                         // Depth-of-field: scale head bloom by layer so back-layer
                         // heads don't out-bloom front-layer bodies. Without this,
                         // a short back-layer droplet (head + 1 body cell) shows as
                         // a bright bloom spot against the dark background.
-                        let layer_bloom = PARALLAX_HEAD_BLOOM_MULT[self.layer as usize];
+                        let layer_bloom = ctx.tuning.parallax_head_bloom[self.layer as usize];
                         let frac_bloom = 1.0 + frac_progress * FRACTIONAL_BLOOM_AMP;
                         // (chroma audit, A15): route head-bloom white-blend
                         // through chroma::palette::blend_toward_white_rgb when
@@ -599,7 +601,7 @@ impl Droplet {
                 // was a complete no-op. Changed to `!= 1.0` so both dimming
                 // (< 1.0) and boosting (> 1.0) apply. The integer pipeline
                 // already handles > 1.0 correctly (fi > 256 scales r upward).
-                let layer_brightness = PARALLAX_BRIGHTNESS_MULT[self.layer as usize];
+                let layer_brightness = ctx.tuning.parallax_brightness[self.layer as usize];
                 let glyph_dim = PARALLAX_GLYPH_DIM[self.layer as usize];
                 let combined_layer = layer_brightness * glyph_dim;
                 if combined_layer != 1.0 {
@@ -626,7 +628,7 @@ impl Droplet {
                 // .0 fix: gate is `!= 1.0` (not `< 1.0`) so front-layer
                 // oversaturation (sat > 1.0) also applies. Luminance uses
                 // Rec. 601 (0.299R + 0.587G + 0.114B) integer math.
-                let saturation_mult = PARALLAX_SATURATION_MULT[self.layer as usize];
+                let saturation_mult = ctx.tuning.parallax_saturation[self.layer as usize];
                 if saturation_mult != 1.0 {
                     let lum = (r as u32 * 77 + g as u32 * 150 + b as u32 * 29 + 128) >> 8;
                     let lum = lum.min(255) as u8;
@@ -684,13 +686,14 @@ impl Droplet {
                 }
 
                 // Depth fog: dim top and bottom rows
-                let fog_factor = if line < FOG_ROWS {
-                    FOG_MIN_FACTOR + (1.0 - FOG_MIN_FACTOR) * (line as f32 / FOG_ROWS as f32)
+                let fog_rows = ctx.tuning.fog_rows;
+                let fog_min = ctx.tuning.fog_min_factor;
+                let fog_factor = if line < fog_rows {
+                    fog_min + (1.0 - fog_min) * (line as f32 / fog_rows as f32)
                 } else {
                     let bottom_dist = ctx.lines.saturating_sub(line).saturating_sub(1);
-                    if bottom_dist < FOG_ROWS {
-                        FOG_MIN_FACTOR
-                            + (1.0 - FOG_MIN_FACTOR) * (bottom_dist as f32 / FOG_ROWS as f32)
+                    if bottom_dist < fog_rows {
+                        fog_min + (1.0 - fog_min) * (bottom_dist as f32 / fog_rows as f32)
                     } else {
                         1.0
                     }
@@ -702,9 +705,9 @@ impl Droplet {
                     // paths: \`((c * fi + 128) >> 8).clamp(0, 255)\` where
                     // fi = (fog_factor * 256) as i32.
                     //
-                    // Factor safety: fog_factor is gated to < 1.0 here, but
-                    // FOG_MIN_FACTOR=1.0 (disabled), so this block is dead
-                    // code — fog_factor is always 1.0 and the gate never enters.
+                    // Factor safety: fog_factor is gated to < 1.0 here. With
+                    // the default FOG_MIN_FACTOR=1.0 (disabled) the gate never
+                    // enters; `rain-tuning.fog-min-factor` (0.0-1.0) enables it.
                     let (nr, ng, nb) = if ctx.color_pipeline.is_chroma() {
                         // (Color-#5): tuple-returning variant avoids Color wrap + decode_color round-trip.
                         crate::chroma_dragon_engine::palette::apply_brightness_rgb_unclamped(
@@ -979,11 +982,13 @@ impl Droplet {
                             + (self.bound_col as usize),
                     )
                     .copied()
-                    .unwrap_or(crate::brightness_factors::vignette_factor(
+                    .unwrap_or(crate::brightness_factors::vignette_factor_with(
                         self.bound_col,
                         line,
                         ctx.cols,
                         ctx.lines,
+                        ctx.tuning.vignette_intensity,
+                        ctx.tuning.vignette_inner_radius,
                     ));
                 let vignette =
                     1.0 - (1.0 - vignette_raw) * VIGNETTE_LAYER_MULT[self.layer as usize];
@@ -1003,6 +1008,25 @@ impl Droplet {
                     g = ng;
                     b = nb;
                 }
+
+                // 2D density mask (`scene-custom.<name>.density-mask`):
+                // cells outside the shape dim toward the mask floor so the
+                // image or text reads through the rain. Unlike the vignette
+                // this applies to every layer — the front layer would
+                // otherwise paint over the shape at full brightness.
+                let mask = ctx.density_mask(self.bound_col, line);
+                if mask < 1.0 {
+                    let (nr, ng, nb) = if ctx.color_pipeline.is_chroma() {
+                        crate::chroma_dragon_engine::palette::apply_brightness_rgb_unclamped(
+                            r, g, b, mask,
+                        )
+                    } else {
+                        crate::chroma_dragon_engine::legacy::scale_rgb(r, g, b, mask)
+                    };
+                    r = nr;
+                    g = ng;
+                    b = nb;
+                }
                 Some(Color::Rgb { r, g, b })
             });
             // Suppress bold at viewport edges to prevent harsh bright spots
@@ -0,0 +1,127 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Frame encoders for `--render-frames`: ANSI text, plain text, PPM.
+//!
+//! All three are full-frame (no diffing against the previous frame) so
+//! every output file stands alone: `cat frame-00042.ans` repaints the
+//! whole grid, and a PPM converts straight to PNG with any image tool.
+
+use super::font::{glyph_rows, GLYPH_H, GLYPH_W};
+use crate::cell::Cell;
+use crate::frame::Frame;
+use crate::palette::color_to_rgb;
+use crate::terminal::sgr_format::write_sgr_colors_buf;
+
+/// Pixels per font pixel in PPM output. 2 keeps the 5×7 font legible
+/// for README screenshots without a resampling step.
+pub(crate) const PPM_SCALE: usize = 2;
+/// Cell size in font pixels: glyph + 1 px column gap + 2 px line gap.
+const CELL_W: usize = GLYPH_W + 1;
+const CELL_H: usize = GLYPH_H + 2;
+/// Canvas colour for cells without a background (default-background
+/// mode): the terminal's own background, assumed black.
+const DEFAULT_BG: (u8, u8, u8) = (0, 0, 0);
+/// Ink for cells without a foreground colour.
+const DEFAULT_FG: (u8, u8, u8) = (192, 192, 192);
+
+/// Full-frame ANSI: one SGR per style run (same formatter as
+/// `Terminal::draw`), `\x1b[0m` + newline per row.
+pub(crate) fn to_ansi(frame: &Frame) -> Vec<u8> {
+    let mut out = Vec::with_capacity(frame.cells.len() * 4);
+    let mut utf8 = [0u8; 4];
+    for row in rows(frame) {
+        let mut last = None;
+        for i in row {
+            let cell = frame.cell_at_index_ref(i);
+            let style = (cell.fg, cell.bg, cell.bold);
+            if last != Some(style) {
+                out.extend_from_slice(if cell.bold { b"\x1b[0;1m" } else { b"\x1b[0m" });
+                if cell.fg.is_some() || cell.bg.is_some() {
+                    write_sgr_colors_buf(&mut out, cell.fg, cell.bg);
+                }
+                last = Some(style);
+            }
+            out.extend_from_slice(cell.ch.encode_utf8(&mut utf8).as_bytes());
+        }
+        out.extend_from_slice(b"\x1b[0m\n");
+    }
+    out
+}
+
+/// Plain glyph grid, one line per row (colours dropped).
+pub(crate) fn to_text(frame: &Frame) -> Vec<u8> {
+    let mut out = String::with_capacity(frame.cells.len() + usize::from(frame.height));
+    for row in rows(frame) {
+        out.extend(row.map(|i| frame.cell_at_index_ref(i).ch));
+        out.push('\n');
+    }
+    out.into_bytes()
+}
+
+/// Cell indices of each row. Cells are read through
+/// `Frame::cell_at_index_ref`: after a clear, `frame.cells` still holds
+/// the previous generation's glyphs.
+fn rows(frame: &Frame) -> impl Iterator<Item = std::ops::Range<usize>> + '_ {
+    let width = usize::from(frame.width);
+    (0..usize::from(frame.height)).map(move |line| line * width..(line + 1) * width)
+}
+
+/// Binary PPM (P6) raster of the frame in the built-in 5×7 font. Bold
+/// cells are drawn double-struck (glyph OR-ed with itself shifted 1 px).
+pub(crate) fn to_ppm(frame: &Frame) -> Vec<u8> {
+    let cols = usize::from(frame.width);
+    let lines = usize::from(frame.height);
+    let px_w = cols * CELL_W * PPM_SCALE;
+    let px_h = lines * CELL_H * PPM_SCALE;
+    let header = format!("P6\n{px_w} {px_h}\n255\n");
+    let mut out = Vec::with_capacity(header.len() + px_w * px_h * 3);
+    out.extend_from_slice(header.as_bytes());
+
+    // Rasterize one font-pixel row of a text line at a time, then emit it
+    // PPM_SCALE times (vertical scale) with each pixel repeated
+    // PPM_SCALE times (horizontal scale).
+    let mut scanline: Vec<(u8, u8, u8)> = Vec::with_capacity(cols * CELL_W);
+    for line in 0..lines {
+        let row: Vec<&Cell> = (line * cols..(line + 1) * cols)
+            .map(|i| frame.cell_at_index_ref(i))
+            .collect();
+        let glyphs: Vec<[u8; GLYPH_H]> = row
+            .iter()
+            .map(|c| {
+                let mut g = glyph_rows(c.ch);
+                if c.bold {
+                    for r in &mut g {
+                        *r |= *r >> 1;
+                    }
+                }
+                g
+            })
+            .collect();
+        for fy in 0..CELL_H {
+            scanline.clear();
+            for (cell, glyph) in row.iter().zip(&glyphs) {
+                let bg = cell.bg.map_or(DEFAULT_BG, color_to_rgb);
+                let fg = cell.fg.map_or(DEFAULT_FG, color_to_rgb);
+                // Glyph occupies font rows 1..=GLYPH_H of the cell.
+                let bits = fy
+                    .checked_sub(1)
+                    .and_then(|gy| glyph.get(gy))
+                    .copied()
+                    .unwrap_or(0);
+                for fx in 0..CELL_W {
+                    let ink = fx < GLYPH_W && bits & (1 << (GLYPH_W - 1 - fx)) != 0;
+                    scanline.push(if ink { fg } else { bg });
+                }
+            }
+            for _ in 0..PPM_SCALE {
+                for &(r, g, b) in &scanline {
+                    for _ in 0..PPM_SCALE {
+                        out.extend_from_slice(&[r, g, b]);
+                    }
+                }
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,187 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Built-in 5×7 bitmap font for the PPM rasterizer.
+//!
+//! Printable ASCII is hand-drawn below (one glyph per line, rows joined
+//! by `|`, `#` = ink). Everything else the rain can emit (half-width
+//! katakana, zen / cyberpunk symbols, box drawing) has no hand-drawn
+//! shape; block and line elements get a geometric approximation and the
+//! rest a stable pseudo-random pattern derived from the codepoint — the
+//! image reads as "glyph rain" and two runs produce the same pixels.
+
+/// Glyph cell width in font pixels.
+pub(crate) const GLYPH_W: usize = 5;
+/// Glyph cell height in font pixels.
+pub(crate) const GLYPH_H: usize = 7;
+
+/// Row bitmaps for `ch`, bit 4 = leftmost column.
+pub(crate) fn glyph_rows(ch: char) -> [u8; GLYPH_H] {
+    if let Some(art) = ascii_art(ch) {
+        return parse_art(art);
+    }
+    if let Some(rows) = geometric(ch) {
+        return rows;
+    }
+    hashed(ch)
+}
+
+fn parse_art(art: &str) -> [u8; GLYPH_H] {
+    let mut rows = [0u8; GLYPH_H];
+    for (row, line) in rows.iter_mut().zip(art.split('|')) {
+        for (i, px) in line.bytes().take(GLYPH_W).enumerate() {
+            if px == b'#' {
+                *row |= 1 << (GLYPH_W - 1 - i);
+            }
+        }
+    }
+    rows
+}
+
+/// Block, shade and line elements common in the monolith / retro charsets.
+fn geometric(ch: char) -> Option<[u8; GLYPH_H]> {
+    const FULL: u8 = 0b11111;
+    Some(match ch {
+        '█' => [FULL; GLYPH_H],
+        '▓' => [
+            0b11011, 0b10111, 0b11101, 0b11011, 0b10111, 0b11101, 0b11011,
+        ],
+        '▒' => [
+            0b10101, 0b01010, 0b10101, 0b01010, 0b10101, 0b01010, 0b10101,
+        ],
+        '░' => [
+            0b10001, 0b00100, 0b10001, 0b00100, 0b10001, 0b00100, 0b10001,
+        ],
+        '▀' => [FULL, FULL, FULL, 0, 0, 0, 0],
+        '▄' => [0, 0, 0, 0, FULL, FULL, FULL],
+        '▌' => [0b11100; GLYPH_H],
+        '▐' => [0b00111; GLYPH_H],
+        '─' | '━' | '═' => [0, 0, 0, FULL, 0, 0, 0],
+        '│' | '┃' | '║' => [0b00100; GLYPH_H],
+        '┼' | '╋' | '╬' => [0b00100, 0b00100, 0b00100, FULL, 0b00100, 0b00100, 0b00100],
+        '·' | '•' | '∙' => [0, 0, 0, 0b00100, 0, 0, 0],
+        _ => return None,
+    })
+}
+
+/// Stable ~40% fill pattern for glyphs without a drawn shape. SplitMix64
+/// over the codepoint; the outer column pair is thinned so neighbouring
+/// glyphs don't fuse into a solid wall.
+fn hashed(ch: char) -> [u8; GLYPH_H] {
+    let mut z = u64::from(ch as u32).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    let mut rows = [0u8; GLYPH_H];
+    for (i, row) in rows.iter_mut().enumerate() {
+        let a = z.rotate_right(i as u32 * 5) as u8 & 0b11111;
+        let b = z.rotate_right(i as u32 * 5 + 33) as u8 & 0b11111;
+        *row = a & (b | 0b01110);
+    }
+    if rows.iter().all(|&r| r == 0) {
+        rows[GLYPH_H / 2] = 0b01110;
+    }
+    rows
+}
+
+#[rustfmt::skip]
+fn ascii_art(ch: char) -> Option<&'static str> {
+    Some(match ch {
+        ' ' => ".....|.....|.....|.....|.....|.....|.....",
+        '!' => "..#..|..#..|..#..|..#..|..#..|.....|..#..",
+        '"' => ".#.#.|.#.#.|.....|.....|.....|.....|.....",
+        '#' => ".#.#.|.#.#.|#####|.#.#.|#####|.#.#.|.#.#.",
+        '$' => "..#..|.####|#.#..|.###.|..#.#|####.|..#..",
+        '%' => "##...|##..#|...#.|..#..|.#...|#..##|...##",
+        '&' => ".##..|#..#.|#.#..|.#...|#.#.#|#..#.|.##.#",
+        '\'' => "..#..|..#..|.....|.....|.....|.....|.....",
+        '(' => "...#.|..#..|.#...|.#...|.#...|..#..|...#.",
+        ')' => ".#...|..#..|...#.|...#.|...#.|..#..|.#...",
+        '*' => ".....|..#..|#.#.#|.###.|#.#.#|..#..|.....",
+        '+' => ".....|..#..|..#..|#####|..#..|..#..|.....",
+        ',' => ".....|.....|.....|.....|..##.|..#..|.#...",
+        '-' => ".....|.....|.....|#####|.....|.....|.....",
+        '.' => ".....|.....|.....|.....|.....|.##..|.##..",
+        '/' => ".....|....#|...#.|..#..|.#...|#....|.....",
+        '0' => ".###.|#...#|#..##|#.#.#|##..#|#...#|.###.",
+        '1' => "..#..|.##..|..#..|..#..|..#..|..#..|.###.",
+        '2' => ".###.|#...#|....#|...#.|..#..|.#...|#####",
+        '3' => "#####|...#.|..#..|...#.|....#|#...#|.###.",
+        '4' => "...#.|..##.|.#.#.|#..#.|#####|...#.|...#.",
+        '5' => "#####|#....|####.|....#|....#|#...#|.###.",
+        '6' => "..##.|.#...|#....|####.|#...#|#...#|.###.",
+        '7' => "#####|....#|...#.|..#..|.#...|.#...|.#...",
+        '8' => ".###.|#...#|#...#|.###.|#...#|#...#|.###.",
+        '9' => ".###.|#...#|#...#|.####|....#|...#.|.##..",
+        ':' => ".....|.##..|.##..|.....|.##..|.##..|.....",
+        ';' => ".....|.##..|.##..|.....|.##..|..#..|.#...",
+        '<' => "...#.|..#..|.#...|#....|.#...|..#..|...#.",
+        '=' => ".....|.....|#####|.....|#####|.....|.....",
+        '>' => ".#...|..#..|...#.|....#|...#.|..#..|.#...",
+        '?' => ".###.|#...#|....#|...#.|..#..|.....|..#..",
+        '@' => ".###.|#...#|....#|.##.#|#.#.#|#.#.#|.###.",
+        'A' => ".###.|#...#|#...#|#####|#...#|#...#|#...#",
+        'B' => "####.|#...#|#...#|####.|#...#|#...#|####.",
+        'C' => ".###.|#...#|#....|#....|#....|#...#|.###.",
+        'D' => "###..|#..#.|#...#|#...#|#...#|#..#.|###..",
+        'E' => "#####|#....|#....|####.|#....|#....|#####",
+        'F' => "#####|#....|#....|####.|#....|#....|#....",
+        'G' => ".###.|#...#|#....|#.###|#...#|#...#|.####",
+        'H' => "#...#|#...#|#...#|#####|#...#|#...#|#...#",
+        'I' => ".###.|..#..|..#..|..#..|..#..|..#..|.###.",
+        'J' => "..###|...#.|...#.|...#.|...#.|#..#.|.##..",
+        'K' => "#...#|#..#.|#.#..|##...|#.#..|#..#.|#...#",
+        'L' => "#....|#....|#....|#....|#....|#....|#####",
+        'M' => "#...#|##.##|#.#.#|#.#.#|#...#|#...#|#...#",
+        'N' => "#...#|#...#|##..#|#.#.#|#..##|#...#|#...#",
+        'O' => ".###.|#...#|#...#|#...#|#...#|#...#|.###.",
+        'P' => "####.|#...#|#...#|####.|#....|#....|#....",
+        'Q' => ".###.|#...#|#...#|#...#|#.#.#|#..#.|.##.#",
+        'R' => "####.|#...#|#...#|####.|#.#..|#..#.|#...#",
+        'S' => ".####|#....|#....|.###.|....#|....#|####.",
+        'T' => "#####|..#..|..#..|..#..|..#..|..#..|..#..",
+        'U' => "#...#|#...#|#...#|#...#|#...#|#...#|.###.",
+        'V' => "#...#|#...#|#...#|#...#|#...#|.#.#.|..#..",
+        'W' => "#...#|#...#|#...#|#.#.#|#.#.#|#.#.#|.#.#.",
+        'X' => "#...#|#...#|.#.#.|..#..|.#.#.|#...#|#...#",
+        'Y' => "#...#|#...#|#...#|.#.#.|..#..|..#..|..#..",
+        'Z' => "#####|....#|...#.|..#..|.#...|#....|#####",
+        '[' => ".###.|.#...|.#...|.#...|.#...|.#...|.###.",
+        '\\' => ".....|#....|.#...|..#..|...#.|....#|.....",
+        ']' => ".###.|...#.|...#.|...#.|...#.|...#.|.###.",
+        '^' => "..#..|.#.#.|#...#|.....|.....|.....|.....",
+        '_' => ".....|.....|.....|.....|.....|.....|#####",
+        '`' => ".#...|..#..|.....|.....|.....|.....|.....",
+        'a' => ".....|.....|.###.|....#|.####|#...#|.####",
+        'b' => "#....|#....|#.##.|##..#|#...#|#...#|####.",
+        'c' => ".....|.....|.###.|#....|#....|#...#|.###.",
+        'd' => "....#|....#|.##.#|#..##|#...#|#...#|.####",
+        'e' => ".....|.....|.###.|#...#|#####|#....|.###.",
+        'f' => "..##.|.#..#|.#...|###..|.#...|.#...|.#...",
+        'g' => ".....|.####|#...#|#...#|.####|....#|.###.",
+        'h' => "#....|#....|#.##.|##..#|#...#|#...#|#...#",
+        'i' => "..#..|.....|.##..|..#..|..#..|..#..|.###.",
+        'j' => "...#.|.....|..##.|...#.|...#.|#..#.|.##..",
+        'k' => "#....|#....|#..#.|#.#..|##...|#.#..|#..#.",
+        'l' => ".##..|..#..|..#..|..#..|..#..|..#..|.###.",
+        'm' => ".....|.....|##.#.|#.#.#|#.#.#|#...#|#...#",
+        'n' => ".....|.....|#.##.|##..#|#...#|#...#|#...#",
+        'o' => ".....|.....|.###.|#...#|#...#|#...#|.###.",
+        'p' => ".....|.....|####.|#...#|####.|#....|#....",
+        'q' => ".....|.....|.##.#|#..##|.####|....#|....#",
+        'r' => ".....|.....|#.##.|##..#|#....|#....|#....",
+        's' => ".....|.....|.###.|#....|.###.|....#|####.",
+        't' => ".#...|.#...|###..|.#...|.#...|.#..#|..##.",
+        'u' => ".....|.....|#...#|#...#|#...#|#..##|.##.#",
+        'v' => ".....|.....|#...#|#...#|#...#|.#.#.|..#..",
+        'w' => ".....|.....|#...#|#...#|#.#.#|#.#.#|.#.#.",
+        'x' => ".....|.....|#...#|.#.#.|..#..|.#.#.|#...#",
+        'y' => ".....|.....|#...#|#...#|.####|....#|.###.",
+        'z' => ".....|.....|#####|...#.|..#..|.#...|#####",
+        '{' => "...#.|..#..|..#..|.#...|..#..|..#..|...#.",
+        '|' => "..#..|..#..|..#..|..#..|..#..|..#..|..#..",
+        '}' => ".#...|..#..|..#..|...#.|..#..|..#..|.#...",
+        '~' => ".....|.....|.#...|#.#.#|...#.|.....|.....",
+        _ => return None,
+    })
+}
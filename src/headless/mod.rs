@@ -0,0 +1,243 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Headless offline renderer: `--render-frames N --out DIR`.
+//!
+//! Renders the configured scene without a TTY, one file per frame, at an
+//! exact simulation timestep of `1 / --fps` (a stepped `ManualClock`, not
+//! wall time — 600 frames at 60 FPS cover 10 simulated seconds however
+//! long the encoding takes). Like the benchmark it builds on, it drives
+//! `Cloud::rain_at` into a `Frame::new_bench` buffer; unlike the
+//! benchmark, the result is deterministic: without `--seed` the run uses
+//! seed 0, so CI can regenerate the screenshots in `assets/` and diff
+//! them.
+//!
+//! ## Output
+//!
+//! `DIR/frame-00001.<ext>` … one file per frame (zero-padded to at least
+//! five digits). `DIR` (or a stream file target) must sit under the same
+//! whitelist as `--record` — `~/.config/cosmostrix/` or `/etc/cosmostrix/`
+//! — because existing frames are overwritten. Formats (`--render-format`):
+//!
+//! | Format | Ext | Content |
+//! |--------|-----|---------|
+//! | `ppm`  | `.ppm` | P6 image, built-in 5×7 font, 2× scale (see `encode.rs`) |
+//! | `ansi` | `.ans` | full-frame ANSI text; `cat` it in a truecolor terminal |
+//! | `text` | `.txt` | glyph grid only |
+//!
+//! `stream` writes no files: `--out` names a single target (file, FIFO,
+//! Unix socket, `-` for stdout) and every frame goes through the real
+//! renderer (`Terminal::with_sink`), so the target receives exactly the
+//! bytes a terminal would — alt screen, then one SYNC-wrapped diff per
+//! frame. Frames are written as fast as the target reads them.
+//!
+//! ## Scope
+//!
+//! Only the rain simulation is rendered. The intro, HUD, live reload,
+//! ambient schedule and Power Dragon live in the interactive event loop
+//! and are skipped. Grid size comes from `--screen-size` (default 80x24).
+
+mod encode;
+mod font;
+#[cfg(test)]
+mod tests;
+
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::app::{effective_density, CloudConfig};
+use crate::cloud::{Cloud, ManualClock};
+use crate::config::RenderFormat;
+use crate::frame::Frame;
+use crate::interactive::term_setup::configure_terminal;
+use crate::terminal::sink::open_sink;
+use crate::terminal::{SinkKind, Terminal};
+
+/// Grid used when `--screen-size` is not given.
+pub(crate) const DEFAULT_RENDER_SIZE: (u16, u16) = (80, 24);
+
+/// Seed used when `--seed` is not given: offline renders are always
+/// reproducible.
+pub(crate) const DEFAULT_RENDER_SEED: u64 = 0;
+
+/// One `--render-frames` invocation.
+#[derive(Clone, Debug)]
+pub(crate) struct RenderJob {
+    pub frames: u64,
+    pub out: PathBuf,
+    pub format: RenderFormat,
+}
+
+impl RenderFormat {
+    /// File extension for this format (`stream` output is ANSI too).
+    #[must_use]
+    pub(crate) fn extension(self) -> &'static str {
+        match self {
+            RenderFormat::Ppm => "ppm",
+            RenderFormat::Ansi | RenderFormat::Stream => "ans",
+            RenderFormat::Text => "txt",
+        }
+    }
+
+    /// Encode one frame as a standalone file. `stream` frames are diffs
+    /// written by [`stream_frames`]; on their own they encode as `ansi`.
+    #[must_use]
+    pub(crate) fn encode(self, frame: &Frame) -> Vec<u8> {
+        match self {
+            RenderFormat::Ppm => encode::to_ppm(frame),
+            RenderFormat::Ansi | RenderFormat::Stream => encode::to_ansi(frame),
+            RenderFormat::Text => encode::to_text(frame),
+        }
+    }
+}
+
+/// The seeded cloud, its clock and frame for `cfg` — shared by the file
+/// and stream outputs.
+struct Scene {
+    cfg: CloudConfig,
+    cloud: Cloud,
+    clock: ManualClock,
+    frame: Frame,
+    step: Duration,
+}
+
+impl Scene {
+    fn new(cfg: &CloudConfig) -> Self {
+        let (cols, lines) = cfg.screen_size.unwrap_or(DEFAULT_RENDER_SIZE);
+        let mut cfg = cfg.clone();
+        cfg.seed.get_or_insert(DEFAULT_RENDER_SEED);
+        let clock = ManualClock::new();
+        let density = effective_density(cfg.base_density, cols, cfg.density_auto);
+        let mut cloud = cfg.create_cloud_with_clock(density, Box::new(clock.clone()));
+        cloud.reset(cols, lines);
+        cloud.enable_events();
+        let frame = Frame::new_bench(cols, lines, cloud.palette.bg);
+        Self {
+            step: frame_step(cfg.target_fps),
+            cfg,
+            cloud,
+            clock,
+            frame,
+        }
+    }
+
+    /// Simulate frame `n` (1-based).
+    fn advance_to(&mut self, n: u64) {
+        // Absolute time per frame (not accumulated steps) so rounding
+        // never drifts over long renders.
+        self.clock.set_elapsed(
+            self.step
+                .saturating_mul(u32::try_from(n).unwrap_or(u32::MAX)),
+        );
+        self.cloud.rain(&mut self.frame);
+    }
+}
+
+/// Render `job.frames` frames of `cfg` into `job.out`. Returns the number
+/// of frames written. `job.out` must pass `allowed` before it is created
+/// (the production caller passes [`crate::is_safe_path`]).
+pub(crate) fn render_frames(
+    cfg: &CloudConfig,
+    job: &RenderJob,
+    allowed: fn(&str) -> bool,
+) -> io::Result<u64> {
+    if job.format == RenderFormat::Stream {
+        return stream_frames(cfg, job, allowed).map(|(frames, _)| frames);
+    }
+    check_out(&job.out, allowed)?;
+    std::fs::create_dir_all(&job.out).map_err(|e| with_path(e, &job.out))?;
+    let mut scene = Scene::new(cfg);
+    let width = job.frames.to_string().len().max(5);
+    let ext = job.format.extension();
+    for n in 1..=job.frames {
+        scene.advance_to(n);
+        let path = job.out.join(format!("frame-{n:0width$}.{ext}"));
+        write_file(&path, &job.format.encode(&scene.frame))?;
+        scene.frame.clear_dirty();
+    }
+    Ok(job.frames)
+}
+
+/// `--render-format stream`: draw every frame through a [`Terminal`]
+/// writing to the `job.out` sink. Returns the frame count and the kind of
+/// sink `job.out` opened as. A file target must pass `allowed` (the
+/// production caller passes [`crate::is_safe_path`]); stdout and sockets
+/// are never truncated and skip the check.
+fn stream_frames(
+    cfg: &CloudConfig,
+    job: &RenderJob,
+    allowed: fn(&str) -> bool,
+) -> io::Result<(u64, SinkKind)> {
+    let sink = open_sink(&job.out.to_string_lossy(), |path| check_out(path, allowed))?;
+    let mut scene = Scene::new(cfg);
+    let size = (scene.frame.width, scene.frame.height);
+    let mut term = Terminal::with_sink(sink, size, crate::termdetect::detect())?;
+    configure_terminal(&mut term, &scene.cfg, &scene.cloud.palette);
+    for n in 1..=job.frames {
+        scene.advance_to(n);
+        // `draw` clears the frame's dirty set.
+        term.draw(&mut scene.frame)?;
+    }
+    Ok((job.frames, term.sink_kind()))
+}
+
+/// Entry point from `main`: render, then report (on stderr for a stream,
+/// whose target may be stdout).
+pub(crate) fn run_render(cfg: &CloudConfig, job: &RenderJob) -> io::Result<()> {
+    let (cols, lines) = cfg.screen_size.unwrap_or(DEFAULT_RENDER_SIZE);
+    let seed = cfg.seed.unwrap_or(DEFAULT_RENDER_SEED);
+    if job.format == RenderFormat::Stream {
+        let (written, kind) = stream_frames(cfg, job, crate::is_safe_path)?;
+        eprintln!(
+            "streamed {written} frames ({cols}x{lines} @ {} fps, seed {seed}) to {} ({})",
+            cfg.target_fps,
+            job.out.display(),
+            kind.label()
+        );
+        return Ok(());
+    }
+    let written = render_frames(cfg, job, crate::is_safe_path)?;
+    println!(
+        "rendered {written} {} frames ({cols}x{lines} @ {} fps, seed {seed}) to {}",
+        job.format.extension(),
+        cfg.target_fps,
+        job.out.display()
+    );
+    Ok(())
+}
+
+/// Simulation time per frame. Non-finite / non-positive FPS falls back
+/// to 60.
+fn frame_step(fps: f64) -> Duration {
+    let fps = if fps.is_finite() && fps > 0.0 {
+        fps
+    } else {
+        60.0
+    };
+    Duration::from_secs_f64(1.0 / fps)
+}
+
+/// Same whitelist as `--record`: `--out` creates and truncates its target.
+fn check_out(path: &Path, allowed: fn(&str) -> bool) -> io::Result<()> {
+    if allowed(&path.to_string_lossy()) {
+        return Ok(());
+    }
+    Err(io::Error::new(
+        io::ErrorKind::PermissionDenied,
+        format!(
+            "--out '{}' is outside allowed directories\n  \
+             Allowed: ~/.config/cosmostrix/, /etc/cosmostrix/",
+            path.display()
+        ),
+    ))
+}
+
+fn write_file(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    let mut file = std::fs::File::create(path).map_err(|e| with_path(e, path))?;
+    file.write_all(bytes).map_err(|e| with_path(e, path))
+}
+
+fn with_path(e: io::Error, path: &Path) -> io::Error {
+    io::Error::new(e.kind(), format!("{}: {e}", path.display()))
+}
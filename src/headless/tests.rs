@@ -0,0 +1,257 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Headless renderer tests: encoders, font and an end-to-end render into
+//! a temp directory.
+
+use super::encode::{to_ansi, to_ppm, to_text, PPM_SCALE};
+use super::font::{glyph_rows, GLYPH_H};
+use super::*;
+use crate::cell::Cell;
+use crossterm::style::Color;
+
+/// Minimal CloudConfig (mirrors `live_config/tests.rs`).
+fn minimal_cloud_config() -> crate::app::CloudConfig {
+    use crate::rain_style::RainStyle;
+    use crate::runtime::{BoldMode, ColorMode, ColorScheme, MonolithSize, ShadingMode};
+
+    crate::app::CloudConfig {
+        color_mode: ColorMode::TrueColor,
+        color_dither: crate::config::ColorDither::Off,
+        shading_mode: ShadingMode::Random,
+        bold_mode: BoldMode::Random,
+        async_mode: true,
+        default_bg: true,
+        terminal_bg: None,
+        color_scheme: ColorScheme::NeonPurple,
+        custom_palette: None,
+        custom_palette_name: None,
+        rain_style: RainStyle::Glyph,
+        glitch_enabled: true,
+        glitch_pct: 10.0,
+        glitch_low: 300,
+        glitch_high: 400,
+        linger_low: 400,
+        linger_high: 600,
+        short_pct: 50.0,
+        die_early_pct: 33.0,
+        max_dpc: 5,
+        density: 0.75,
+        speed: 9.0,
+        monolith_size: MonolithSize::Normal,
+        chars: vec!['0', '1'],
+        message: None,
+        message_border: false,
+        target_fps: 60.0,
+        xtermjs_host: false,
+        default_fps_cap: 240.0,
+        duration: None,
+        duration_s: None,
+        bench_frames: None,
+        benchmark: false,
+        bench_duration: None,
+        screen_size: None,
+        color_tune: crate::color_tune::ColorTune::IDENTITY,
+        rain_tuning: crate::constants::RainTuning::DEFAULT,
+        event_tuning: crate::constants::EventTuning::default(),
+        power_thresholds: crate::constants::PowerThresholds::defaults(),
+        keymap: crate::interactive::keymap::Keymap::default(),
+        crystal_dragon_calc: Default::default(),
+        crystal_dragon_sensors: Default::default(),
+        crystal_dragon_external: None,
+        json: false,
+        save_baseline: None,
+        compare_baseline: None,
+        bench_io: false,
+        bench_all: false,
+        bench_scene: None,
+        record: None,
+        control_socket: None,
+        seed: None,
+        verbose: false,
+        density_auto: true,
+        base_density: 0.75,
+        perf_stats: false,
+        screensaver: false,
+        intro: crate::config::IntroType::None,
+        intro_color: None,
+        mouse: false,
+        charset_preset: "binary".to_string(),
+        user_ranges: vec![],
+        def_ascii: false,
+        crystal_dragon: false,
+        power_dragon: true,
+        msg_mode: true,
+        monolith_density_map: None,
+        density_mask: None,
+        density_mask_floor: crate::scene_custom::density_mask::DENSITY_MASK_DEFAULT_FLOOR,
+        config_path_for_watcher: None,
+        scene_name: "test-scene".to_string(),
+        scene_custom_name: Some("test-scene".to_string()),
+        cli_explicit: crate::app::CliExplicit::default(),
+        ambient_schedule: crate::crystal_dragon_engine::ambient::AmbientSchedule::default(),
+    }
+}
+
+fn temp_out(tag: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("cx-headless-{tag}-{}", std::process::id()))
+}
+
+fn two_cell_frame() -> Frame {
+    let mut frame = Frame::new(2, 1, None);
+    frame.set(
+        0,
+        0,
+        Cell {
+            ch: 'A',
+            fg: Some(Color::Rgb { r: 0, g: 255, b: 0 }),
+            bg: None,
+            bold: true,
+        },
+    );
+    frame
+}
+
+#[test]
+fn every_printable_ascii_glyph_is_drawn() {
+    for ch in '!'..='~' {
+        assert!(
+            glyph_rows(ch).iter().any(|&r| r != 0),
+            "{ch:?} has no pixels"
+        );
+    }
+    assert_eq!(glyph_rows(' '), [0; GLYPH_H]);
+}
+
+#[test]
+fn fallback_glyphs_are_stable_and_visible() {
+    for ch in ['ｱ', 'ﾝ', '☯', '∞'] {
+        let g = glyph_rows(ch);
+        assert_eq!(g, glyph_rows(ch));
+        assert!(g.iter().any(|&r| r != 0), "{ch:?} has no pixels");
+    }
+    assert_ne!(glyph_rows('ｱ'), glyph_rows('ｲ'));
+}
+
+#[test]
+fn text_encoding_is_the_glyph_grid() {
+    let frame = two_cell_frame();
+    // Frame::new clamps to the minimum grid; the glyph lands top-left.
+    let text = String::from_utf8(to_text(&frame)).unwrap();
+    let first = text.lines().next().unwrap();
+    assert!(first.starts_with('A'));
+    assert_eq!(text.lines().count(), usize::from(frame.height));
+}
+
+#[test]
+fn ansi_encoding_emits_truecolor_bold_sgr() {
+    let frame = two_cell_frame();
+    let ansi = String::from_utf8(to_ansi(&frame)).unwrap();
+    assert!(
+        ansi.starts_with("\x1b[0;1m\x1b[38;2;0;255;0;49mA\x1b[0m "),
+        "{ansi:?}"
+    );
+    assert!(ansi.ends_with("\x1b[0m\n"));
+}
+
+#[test]
+fn encoders_drop_cells_of_a_cleared_generation() {
+    let mut frame = two_cell_frame();
+    frame.clear_with_bg(None);
+    let clean = Frame::new(frame.width, frame.height, None);
+    assert_eq!(to_text(&frame), to_text(&clean));
+    assert_eq!(to_ansi(&frame), to_ansi(&clean));
+    assert_eq!(to_ppm(&frame), to_ppm(&clean));
+}
+
+#[test]
+fn ppm_has_p6_header_and_exact_size() {
+    let frame = two_cell_frame();
+    let ppm = to_ppm(&frame);
+    let w = usize::from(frame.width) * 6 * PPM_SCALE;
+    let h = usize::from(frame.height) * 9 * PPM_SCALE;
+    let header = format!("P6\n{w} {h}\n255\n");
+    assert!(ppm.starts_with(header.as_bytes()));
+    assert_eq!(ppm.len(), header.len() + w * h * 3);
+    // Some green ink from the 'A' glyph made it into the raster.
+    assert!(ppm[header.len()..].chunks(3).any(|px| px == [0, 255, 0]));
+}
+
+#[test]
+fn render_frames_writes_one_file_per_frame_deterministically() {
+    let mut cfg = minimal_cloud_config();
+    cfg.screen_size = Some((24, 8));
+    let render = |tag: &str| {
+        let out = temp_out(tag);
+        let job = RenderJob {
+            frames: 12,
+            out: out.clone(),
+            format: RenderFormat::Ansi,
+        };
+        assert_eq!(render_frames(&cfg, &job, |_| true).unwrap(), 12);
+        let last = std::fs::read(out.join("frame-00012.ans")).unwrap();
+        assert!(out.join("frame-00001.ans").is_file());
+        assert!(!out.join("frame-00013.ans").exists());
+        let _ = std::fs::remove_dir_all(&out);
+        last
+    };
+    assert_eq!(render("a"), render("b"));
+}
+
+#[test]
+fn render_refuses_a_directory_outside_the_whitelist() {
+    // Relative, so no test-only whitelist prefix can admit it.
+    let out = PathBuf::from(format!("cx-render-denied-{}", std::process::id()));
+    let job = RenderJob {
+        frames: 1,
+        out: out.clone(),
+        format: RenderFormat::Text,
+    };
+    let err = render_frames(&minimal_cloud_config(), &job, crate::is_safe_path).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+    assert!(err.to_string().starts_with("--out '"));
+    assert!(!out.exists(), "rejected directory was created");
+}
+
+#[test]
+fn stream_draws_every_frame_into_one_sink() {
+    let mut cfg = minimal_cloud_config();
+    cfg.screen_size = Some((24, 8));
+    let out = temp_out("stream");
+    let job = RenderJob {
+        frames: 12,
+        out: out.clone(),
+        format: RenderFormat::Stream,
+    };
+    let (written, kind) = stream_frames(&cfg, &job, |_| true).unwrap();
+    assert_eq!(written, 12);
+    assert_eq!(kind, SinkKind::File);
+    let bytes = std::fs::read(&out).unwrap();
+    let _ = std::fs::remove_file(&out);
+    let text = String::from_utf8_lossy(&bytes);
+    assert!(text.contains("\x1b[?1049h"), "no alt screen");
+    assert!(text.contains("\x1b[?2026h"), "no synchronized frames");
+    assert!(text.contains('0') || text.contains('1'), "no rain glyphs");
+}
+
+#[test]
+fn stream_refuses_a_file_outside_the_whitelist() {
+    // Relative, so no test-only whitelist prefix can admit it.
+    let out = PathBuf::from(format!("cx-stream-denied-{}.ans", std::process::id()));
+    let job = RenderJob {
+        frames: 1,
+        out: out.clone(),
+        format: RenderFormat::Stream,
+    };
+    let err = stream_frames(&minimal_cloud_config(), &job, crate::is_safe_path).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+    assert!(err.to_string().contains("outside allowed directories"));
+    assert!(!out.exists(), "rejected target was created");
+}
+
+#[test]
+fn frame_step_follows_fps_with_sane_fallback() {
+    assert_eq!(frame_step(50.0), Duration::from_millis(20));
+    assert_eq!(frame_step(0.0), frame_step(60.0));
+    assert_eq!(frame_step(f64::NAN), frame_step(60.0));
+}
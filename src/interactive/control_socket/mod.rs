@@ -0,0 +1,456 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! `--control-socket <path>`: drive a running session from scripts.
+//!
+//! The interactive loop listens on a Unix domain socket and accepts one
+//! command per line, answering each with exactly one line. Every command
+//! that changes the session parses into the same [`RuntimeAction`] a
+//! keystroke does, so `color next` and pressing `c` are indistinguishable
+//! (ambient override flags, palette transition, pause guard included).
+//!
+//! ## Protocol
+//!
+//! ```text
+//! scene <name> | next | prev          built-in scene (x)
+//! color <name> | next | prev          color scheme (c / C)
+//! charset <name> | next | prev        charset preset (s / S)
+//! speed <chars/sec> | up | down       rain speed (Up / Down)
+//! density <n> | up | down             droplet density ([ / ])
+//! message <text>                      replace the overlay (\n = newline)
+//! message                             remove the overlay
+//! pause | resume | toggle-pause       (p)
+//! reseed                              (Space)
+//! quit                                (q)
+//! state                               one-line JSON snapshot
+//! help                                list the commands
+//! ```
+//!
+//! Replies are `ok`, `error: <reason>`, or the JSON object for `state`.
+//! While paused only `pause`, `resume`, `toggle-pause`, `quit`, `state` and
+//! `help` are accepted — the same rule as the keyboard.
+//!
+//! ## Threads
+//!
+//! An accept thread hands each connection to its own thread, which parses
+//! lines and forwards valid commands to the render thread over a channel.
+//! At most [`MAX_CONNECTIONS`] clients are served at once; the next one
+//! gets a single `error:` line and is closed. The render thread drains the
+//! channel once per frame ([`serve_pending`]) and answers on a per-request
+//! reply channel, so a reply arrives within one frame interval. The socket
+//! file is created `0600` (anyone who can connect can quit the session) —
+//! the mode is set by the umask around `bind`, so there is no window where
+//! it is wider — and removed on exit. A stale socket left by a crashed run
+//! is replaced; a live one is an error.
+
+use std::path::{Path, PathBuf};
+#[cfg(unix)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{Receiver, Sender};
+#[cfg(unix)]
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::charset::charset_from_str;
+use crate::constants::MESSAGE_MAX_LEN;
+use crate::runtime::ColorScheme;
+
+use super::input::{apply_action, KeybindingCtx, RuntimeAction};
+
+/// Longest accepted command line, newline included. A `message` at
+/// [`MESSAGE_MAX_LEN`] characters of 4-byte UTF-8 still fits.
+pub(super) const MAX_LINE_BYTES: usize = 1024;
+
+/// Clients served at once. Scripts hold one connection, so a handful is
+/// plenty; the cap keeps a connect loop from piling up threads.
+#[cfg_attr(not(unix), allow(dead_code))]
+pub(super) const MAX_CONNECTIONS: usize = 4;
+
+/// How long a connection waits for the render thread to answer.
+#[cfg_attr(not(unix), allow(dead_code))]
+const REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+
+const HELP_REPLY: &str = "commands: scene <name|next|prev>, color <name|next|prev>, \
+charset <name|next|prev>, speed <n|up|down>, density <n|up|down>, message [text], \
+pause, resume, toggle-pause, reseed, quit, state, help";
+
+const PAUSED_REPLY: &str =
+    "error: paused — only pause, resume, toggle-pause, quit, state and help are accepted";
+
+/// A parsed command line.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum ControlCommand {
+    Action(RuntimeAction),
+    State,
+    /// Answered by the connection thread, never forwarded.
+    Help,
+}
+
+/// A command waiting for the render thread, with its reply channel.
+pub(super) struct ControlRequest {
+    pub command: ControlCommand,
+    reply: Sender<String>,
+}
+
+impl ControlRequest {
+    fn answer(self, line: String) {
+        // The client may have hung up already; nothing to do then.
+        let _ = self.reply.send(line);
+    }
+}
+
+/// Parse one command line. `Ok(None)` for a blank line.
+pub(super) fn parse_command(line: &str) -> Result<Option<ControlCommand>, String> {
+    let line = line.trim_end_matches(['\r', '\n']);
+    let trimmed = line.trim_start();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+    let (verb, rest) = trimmed
+        .split_once(char::is_whitespace)
+        .unwrap_or((trimmed, ""));
+    let arg = rest.trim();
+    let action = match verb.to_ascii_lowercase().as_str() {
+        "state" => return no_arg(verb, arg, ControlCommand::State).map(Some),
+        "help" => return no_arg(verb, arg, ControlCommand::Help).map(Some),
+        "quit" => no_arg(verb, arg, RuntimeAction::Quit)?,
+        "reseed" => no_arg(verb, arg, RuntimeAction::Reseed)?,
+        "pause" => no_arg(verb, arg, RuntimeAction::Pause)?,
+        "resume" => no_arg(verb, arg, RuntimeAction::Resume)?,
+        "toggle-pause" => no_arg(verb, arg, RuntimeAction::TogglePause)?,
+        "scene" => match step(arg) {
+            Some(dir) => RuntimeAction::CycleScene(dir),
+            None => RuntimeAction::SetScene(parse_scene(arg)?),
+        },
+        "color" => match step(arg) {
+            Some(dir) => RuntimeAction::CycleColor(dir),
+            None => RuntimeAction::SetColor(parse_color(arg)?),
+        },
+        "charset" => match step(arg) {
+            Some(dir) => RuntimeAction::CycleCharset(dir),
+            None => RuntimeAction::SetCharset(parse_charset(arg)?),
+        },
+        "speed" => match adjust(arg) {
+            Some(dir) => RuntimeAction::StepSpeed(dir),
+            None => RuntimeAction::SetSpeed(parse_number("speed", arg)?),
+        },
+        "density" => match adjust(arg) {
+            Some(dir) => RuntimeAction::StepDensity(dir),
+            None => RuntimeAction::SetDensity(parse_number("density", arg)?),
+        },
+        // Keep the text as sent: leading spaces after the first separator
+        // are part of the message, trailing ones are not significant.
+        "message" => RuntimeAction::SetMessage(parse_message(rest.trim_end())?),
+        _ => return Err(format!("unknown command '{verb}' (try 'help')")),
+    };
+    Ok(Some(ControlCommand::Action(action)))
+}
+
+fn no_arg<T>(verb: &str, arg: &str, value: T) -> Result<T, String> {
+    if arg.is_empty() {
+        Ok(value)
+    } else {
+        Err(format!("'{verb}' takes no argument"))
+    }
+}
+
+fn step(arg: &str) -> Option<i32> {
+    match arg.to_ascii_lowercase().as_str() {
+        "next" => Some(1),
+        "prev" => Some(-1),
+        _ => None,
+    }
+}
+
+fn adjust(arg: &str) -> Option<i32> {
+    match arg.to_ascii_lowercase().as_str() {
+        "up" => Some(1),
+        "down" => Some(-1),
+        _ => None,
+    }
+}
+
+fn parse_scene(arg: &str) -> Result<String, String> {
+    if arg.is_empty() {
+        return Err("'scene' needs a name, 'next' or 'prev'".to_string());
+    }
+    crate::scene::validate_scene_name(arg)
+        .map_err(|_| format!("unknown scene '{arg}' (see --list-scenes)"))
+}
+
+fn parse_color(arg: &str) -> Result<ColorScheme, String> {
+    if arg.is_empty() {
+        return Err("'color' needs a name, 'next' or 'prev'".to_string());
+    }
+    crate::theme::lookup_theme(arg).ok_or_else(|| match crate::theme::suggest_closest_theme(arg) {
+        Some(name) => format!("unknown color '{arg}' (did you mean '{name}'?)"),
+        None => format!("unknown color '{arg}' (see --list-colors)"),
+    })
+}
+
+fn parse_charset(arg: &str) -> Result<String, String> {
+    if arg.is_empty() {
+        return Err("'charset' needs a name, 'next' or 'prev'".to_string());
+    }
+    let name = crate::cli::normalize_charset_preset_name(arg);
+    match charset_from_str(&name, false) {
+        Ok(_) => Ok(name),
+        Err(_) => Err(format!("unknown charset '{arg}' (see --list-charsets)")),
+    }
+}
+
+fn parse_number(verb: &str, arg: &str) -> Result<f32, String> {
+    match arg.parse::<f32>() {
+        Ok(v) if v.is_finite() && v > 0.0 => Ok(v),
+        _ => Err(format!(
+            "'{verb}' needs a positive number, 'up' or 'down' (got '{arg}')"
+        )),
+    }
+}
+
+fn parse_message(raw: &str) -> Result<Option<String>, String> {
+    if raw.is_empty() {
+        return Ok(None);
+    }
+    let text = raw.replace("\\n", "\n");
+    if text.chars().count() > MESSAGE_MAX_LEN {
+        return Err(format!(
+            "message exceeds {MESSAGE_MAX_LEN} characters (got {})",
+            text.chars().count()
+        ));
+    }
+    let (clean, _, _) = crate::message::sanitize_message_text_quiet(&text);
+    Ok(Some(clean))
+}
+
+/// `state` reply: the live scene, color, charset, speed, density, pause
+/// state, overlay text and grid size.
+pub(super) fn state_json(ctx: &KeybindingCtx) -> String {
+    use crate::bench_json::push_json_string;
+
+    let cloud = &*ctx.cloud;
+    let color = crate::theme::canonical_name_for_scheme(cloud.color_scheme())
+        .map_or_else(|| format!("{:?}", cloud.color_scheme()), str::to_string);
+    let mut out = String::from("{\"scene\":");
+    push_json_string(&mut out, ctx.scene_name);
+    out.push_str(",\"color\":");
+    push_json_string(&mut out, &color);
+    out.push_str(",\"custom_palette\":");
+    out.push_str(if cloud.custom_palette_active {
+        "true"
+    } else {
+        "false"
+    });
+    out.push_str(",\"charset\":");
+    push_json_string(&mut out, ctx.charset_preset);
+    out.push_str(&format!(
+        ",\"speed\":{},\"density\":{},\"paused\":{}",
+        cloud.chars_per_sec,
+        cloud.droplet_density,
+        cloud.is_paused_or_decelerating()
+    ));
+    out.push_str(",\"message\":");
+    match cloud.message_text() {
+        Some(text) => push_json_string(&mut out, text),
+        None => out.push_str("null"),
+    }
+    out.push_str(&format!(
+        ",\"cols\":{},\"lines\":{}}}",
+        ctx.frame.width, ctx.frame.height
+    ));
+    out
+}
+
+/// Answer one forwarded command on the render thread. Returns `true` when
+/// the session changed (the caller treats it like user input).
+pub(super) fn answer(ctx: &mut KeybindingCtx, request: ControlRequest) -> bool {
+    let (reply, changed) = match &request.command {
+        ControlCommand::State => (state_json(ctx), false),
+        ControlCommand::Help => (HELP_REPLY.to_string(), false),
+        ControlCommand::Action(action)
+            if ctx.cloud.is_paused_or_decelerating() && !action.allowed_while_paused() =>
+        {
+            (PAUSED_REPLY.to_string(), false)
+        }
+        ControlCommand::Action(action) => {
+            apply_action(ctx, action.clone());
+            ("ok".to_string(), true)
+        }
+    };
+    request.answer(reply);
+    changed
+}
+
+/// Drain every command queued since the last frame. Returns `true` if any
+/// of them changed the session.
+pub(super) fn serve_pending(socket: &ControlSocket, ctx: &mut KeybindingCtx) -> bool {
+    let mut changed = false;
+    while let Ok(request) = socket.rx.try_recv() {
+        changed |= answer(ctx, request);
+    }
+    changed
+}
+
+/// A listening control socket. Dropping it removes the socket file.
+pub(super) struct ControlSocket {
+    path: PathBuf,
+    rx: Receiver<ControlRequest>,
+}
+
+impl ControlSocket {
+    /// Listen on `path` and start the accept thread.
+    #[cfg(unix)]
+    pub(super) fn bind(path: &Path) -> std::io::Result<Self> {
+        use std::io::{Error, ErrorKind};
+        use std::os::unix::fs::FileTypeExt;
+        use std::os::unix::net::{UnixListener, UnixStream};
+
+        if let Ok(meta) = std::fs::symlink_metadata(path) {
+            if !meta.file_type().is_socket() {
+                return Err(Error::new(
+                    ErrorKind::AlreadyExists,
+                    "path exists and is not a socket",
+                ));
+            }
+            if UnixStream::connect(path).is_ok() {
+                return Err(Error::new(
+                    ErrorKind::AddrInUse,
+                    "another process is already listening there",
+                ));
+            }
+            // Left behind by a run that did not exit cleanly.
+            std::fs::remove_file(path)?;
+        }
+        // SAFETY: umask only swaps the process file-creation mask; it
+        // cannot fail. 0o177 makes bind() create the socket 0600.
+        let old_mask = unsafe { libc::umask(0o177) };
+        let listener = UnixListener::bind(path);
+        // SAFETY: as above; puts the caller's mask back.
+        unsafe { libc::umask(old_mask) };
+        let listener = listener?;
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::Builder::new()
+            .name("cosmostrix-control".to_string())
+            .spawn(move || accept_loop(&listener, &tx))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            rx,
+        })
+    }
+
+    #[cfg(not(unix))]
+    pub(super) fn bind(path: &Path) -> std::io::Result<Self> {
+        let _ = path;
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "Unix domain sockets are not available on this platform",
+        ))
+    }
+}
+
+impl Drop for ControlSocket {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(unix)]
+fn accept_loop(listener: &std::os::unix::net::UnixListener, tx: &Sender<ControlRequest>) {
+    use std::io::Write;
+
+    let active = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        let Ok(mut stream) = stream else {
+            continue;
+        };
+        // Only this thread adds, so the check and the add cannot race.
+        if active.load(Ordering::Acquire) >= MAX_CONNECTIONS {
+            let _ = writeln!(
+                stream,
+                "error: too many connections (limit {MAX_CONNECTIONS})"
+            );
+            continue;
+        }
+        let slot = ConnectionSlot::take(&active);
+        let tx = tx.clone();
+        // A failed spawn drops the closure, and the slot with it.
+        let _ = std::thread::Builder::new()
+            .name("cosmostrix-control-conn".to_string())
+            .spawn(move || {
+                let _slot = slot;
+                let _ = serve_connection(stream, &tx);
+            });
+    }
+}
+
+/// One of the [`MAX_CONNECTIONS`] places; given back on drop.
+#[cfg(unix)]
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+#[cfg(unix)]
+impl ConnectionSlot {
+    fn take(active: &Arc<AtomicUsize>) -> Self {
+        active.fetch_add(1, Ordering::AcqRel);
+        Self(Arc::clone(active))
+    }
+}
+
+#[cfg(unix)]
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Read commands from one client until it disconnects.
+#[cfg(unix)]
+fn serve_connection(
+    stream: std::os::unix::net::UnixStream,
+    tx: &Sender<ControlRequest>,
+) -> std::io::Result<()> {
+    use std::io::{BufRead, BufReader, Read, Write};
+
+    let mut writer = stream.try_clone()?;
+    let mut reader = BufReader::new(stream);
+    let mut buf = Vec::new();
+    loop {
+        buf.clear();
+        let n = reader
+            .by_ref()
+            .take(MAX_LINE_BYTES as u64)
+            .read_until(b'\n', &mut buf)?;
+        if n == 0 {
+            return Ok(());
+        }
+        if n == MAX_LINE_BYTES && buf.last() != Some(&b'\n') {
+            writeln!(writer, "error: line longer than {MAX_LINE_BYTES} bytes")?;
+            return Ok(());
+        }
+        let reply = match std::str::from_utf8(&buf) {
+            Err(_) => "error: not UTF-8".to_string(),
+            Ok(line) => match parse_command(line) {
+                Ok(None) => continue,
+                Ok(Some(ControlCommand::Help)) => HELP_REPLY.to_string(),
+                Ok(Some(command)) => forward(tx, command),
+                Err(e) => format!("error: {e}"),
+            },
+        };
+        writeln!(writer, "{reply}")?;
+    }
+}
+
+/// Hand `command` to the render thread and wait for its answer.
+#[cfg(unix)]
+fn forward(tx: &Sender<ControlRequest>, command: ControlCommand) -> String {
+    let (reply, answer) = std::sync::mpsc::channel();
+    if tx.send(ControlRequest { command, reply }).is_err() {
+        return "error: session is shutting down".to_string();
+    }
+    answer
+        .recv_timeout(REPLY_TIMEOUT)
+        .unwrap_or_else(|_| "error: no answer from the renderer".to_string())
+}
+
+#[cfg(test)]
+mod tests;
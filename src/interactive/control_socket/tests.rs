@@ -0,0 +1,299 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Control socket protocol + dispatch tests.
+
+use super::*;
+use crate::frame::Frame;
+use crate::interactive::tests::cases::{make_test_cloud, make_test_config};
+use crate::platform::default_term_reinit;
+
+fn action(line: &str) -> RuntimeAction {
+    match parse_command(line) {
+        Ok(Some(ControlCommand::Action(action))) => action,
+        other => panic!("{line:?} parsed to {other:?}"),
+    }
+}
+
+fn error(line: &str) -> String {
+    parse_command(line).expect_err(line)
+}
+
+/// Session state a `KeybindingCtx` borrows from.
+struct Session {
+    cloud: crate::cloud::Cloud,
+    frame: Frame,
+    charset: String,
+    scene: String,
+    generation: u64,
+    cfg: crate::CloudConfig,
+}
+
+impl Session {
+    fn new() -> Self {
+        Self {
+            cloud: make_test_cloud(),
+            frame: Frame::new(20, 10, None),
+            charset: "binary".to_string(),
+            scene: "monolith".to_string(),
+            generation: 0,
+            cfg: make_test_config(),
+        }
+    }
+
+    /// Forward `line` like a connection thread and answer it like the
+    /// render thread; returns the reply line.
+    fn send(&mut self, line: &str) -> String {
+        let command = match parse_command(line) {
+            Ok(Some(command)) => command,
+            Ok(None) => return String::new(),
+            Err(e) => return format!("error: {e}"),
+        };
+        let (reply, rx) = std::sync::mpsc::channel();
+        let term_reinit = default_term_reinit();
+        answer(
+            &mut KeybindingCtx {
+                cloud: &mut self.cloud,
+                frame: &mut self.frame,
+                charset_preset: &mut self.charset,
+                scene_name: &mut self.scene,
+                scene_generation: &mut self.generation,
+                user_ranges: &[],
+                def_ascii: true,
+                cfg: &self.cfg,
+                term_reinit: &term_reinit,
+            },
+            ControlRequest { command, reply },
+        );
+        rx.recv().expect("reply")
+    }
+}
+
+#[test]
+fn commands_parse_into_keyboard_actions() {
+    assert_eq!(action("color next"), RuntimeAction::CycleColor(1));
+    assert_eq!(action("COLOR prev"), RuntimeAction::CycleColor(-1));
+    assert_eq!(action("charset next"), RuntimeAction::CycleCharset(1));
+    assert_eq!(action("scene next"), RuntimeAction::CycleScene(1));
+    assert_eq!(action("speed up"), RuntimeAction::StepSpeed(1));
+    assert_eq!(action("density down\r\n"), RuntimeAction::StepDensity(-1));
+    assert_eq!(action("toggle-pause"), RuntimeAction::TogglePause);
+    assert_eq!(action("reseed"), RuntimeAction::Reseed);
+    assert_eq!(action("  quit  "), RuntimeAction::Quit);
+    assert_eq!(parse_command("state\n"), Ok(Some(ControlCommand::State)));
+    assert_eq!(parse_command(" \n"), Ok(None));
+}
+
+#[test]
+fn named_values_are_validated_and_normalized() {
+    assert_eq!(
+        action("scene Cinematic"),
+        RuntimeAction::SetScene("cinematic".to_string())
+    );
+    assert_eq!(
+        action("color green"),
+        RuntimeAction::SetColor(crate::runtime::ColorScheme::Green)
+    );
+    assert_eq!(
+        action("charset 01"),
+        RuntimeAction::SetCharset("binary".to_string())
+    );
+    assert_eq!(action("speed 12.5"), RuntimeAction::SetSpeed(12.5));
+    assert_eq!(action("density 0.4"), RuntimeAction::SetDensity(0.4));
+    assert!(error("scene nope").contains("unknown scene 'nope'"));
+    assert!(error("color gren").contains("did you mean"));
+    assert!(error("charset nope").contains("unknown charset"));
+    assert!(error("speed fast").contains("positive number"));
+    assert!(error("density -1").contains("positive number"));
+    assert!(error("density NaN").contains("positive number"));
+    assert!(error("scene").contains("needs a name"));
+    assert!(error("pause now").contains("takes no argument"));
+    assert!(error("jump").contains("unknown command 'jump'"));
+}
+
+#[test]
+fn message_keeps_inner_spacing_and_expands_newlines() {
+    assert_eq!(
+        action("message  Wall 3:\\nbuild green  "),
+        RuntimeAction::SetMessage(Some(" Wall 3:\nbuild green".to_string()))
+    );
+    assert_eq!(action("message"), RuntimeAction::SetMessage(None));
+    assert_eq!(
+        action("message a\u{7}b\u{4e16}"),
+        RuntimeAction::SetMessage(Some("ab?".to_string()))
+    );
+    let long = format!("message {}", "x".repeat(MESSAGE_MAX_LEN + 1));
+    assert!(error(&long).contains("exceeds"));
+}
+
+#[test]
+fn commands_apply_like_the_matching_keys() {
+    let mut s = Session::new();
+    assert_eq!(s.send("scene matrix"), "ok");
+    assert_eq!(s.scene, "matrix");
+    assert_eq!(s.generation, 1);
+    assert!(s.cloud.user_override_since_ambient);
+
+    s.cloud.ambient_palette_locked = true;
+    assert_eq!(s.send("color ocean"), "ok");
+    assert_eq!(
+        Some(s.cloud.color_scheme()),
+        crate::theme::lookup_theme("ocean")
+    );
+    assert!(!s.cloud.ambient_palette_locked);
+
+    assert_eq!(s.send("charset hex"), "ok");
+    assert_eq!(s.charset, "hex");
+
+    assert_eq!(s.send("speed 9999"), "ok");
+    let max = s.cloud.chars_per_sec;
+    assert!(max < 9999.0, "speed is clamped");
+    assert_eq!(s.send("density 40"), "ok");
+    assert_eq!(s.cloud.droplet_density, 5.0);
+
+    assert_eq!(s.send("message hello"), "ok");
+    assert_eq!(s.cloud.message_text(), Some("hello"));
+    assert_eq!(s.send("message"), "ok");
+    assert_eq!(s.cloud.message_text(), None);
+
+    assert_eq!(s.send("quit"), "ok");
+    assert!(!s.cloud.raining);
+}
+
+#[test]
+fn pause_guard_matches_the_keyboard() {
+    let mut s = Session::new();
+    assert_eq!(s.send("resume"), "ok");
+    assert!(!s.cloud.is_paused_or_decelerating(), "resume is idempotent");
+    assert_eq!(s.send("pause"), "ok");
+    assert!(s.cloud.is_paused_or_decelerating());
+    assert_eq!(s.send("pause"), "ok");
+    assert!(s.cloud.is_paused_or_decelerating(), "pause is idempotent");
+
+    let before = s.cloud.color_scheme();
+    assert_eq!(s.send("color next"), PAUSED_REPLY);
+    assert_eq!(s.cloud.color_scheme(), before);
+    assert!(s.send("state").contains("\"paused\":true"));
+
+    assert_eq!(s.send("resume"), "ok");
+    assert!(!s.cloud.is_paused_or_decelerating());
+    assert_eq!(s.send("color next"), "ok");
+    assert_ne!(s.cloud.color_scheme(), before);
+}
+
+#[test]
+fn state_is_one_line_of_json() {
+    let mut s = Session::new();
+    s.send("message say \"hi\"\\nthere");
+    let state = s.send("state");
+    assert!(!state.contains('\n'));
+    assert!(state.starts_with("{\"scene\":\"monolith\",\"color\":\"green\","));
+    assert!(state.contains("\"charset\":\"binary\""));
+    assert!(state.contains("\"message\":\"say \\\"hi\\\"\\nthere\""));
+    assert!(state.ends_with("\"cols\":20,\"lines\":10}"));
+}
+
+#[cfg(unix)]
+#[test]
+fn socket_round_trip_and_cleanup() {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixStream;
+
+    let dir = std::env::temp_dir().join(format!("cosmostrix-ctl-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("ctl.sock");
+    // A stale socket from an earlier crash is replaced.
+    drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+    let socket = ControlSocket::bind(&path).unwrap();
+    assert!(ControlSocket::bind(&path).is_err(), "live socket is kept");
+    let mode =
+        std::os::unix::fs::PermissionsExt::mode(&std::fs::metadata(&path).unwrap().permissions());
+    assert_eq!(mode & 0o777, 0o600);
+
+    let client_path = path.clone();
+    let client = std::thread::spawn(move || {
+        let mut stream = UnixStream::connect(&client_path).unwrap();
+        stream
+            .write_all(b"help\nbogus\ndensity 0.5\nstate\n")
+            .unwrap();
+        let mut lines = BufReader::new(stream).lines();
+        (0..4)
+            .map(|_| lines.next().unwrap().unwrap())
+            .collect::<Vec<_>>()
+    });
+
+    let mut s = Session::new();
+    let term_reinit = default_term_reinit();
+    while !client.is_finished() {
+        serve_pending(
+            &socket,
+            &mut KeybindingCtx {
+                cloud: &mut s.cloud,
+                frame: &mut s.frame,
+                charset_preset: &mut s.charset,
+                scene_name: &mut s.scene,
+                scene_generation: &mut s.generation,
+                user_ranges: &[],
+                def_ascii: true,
+                cfg: &s.cfg,
+                term_reinit: &term_reinit,
+            },
+        );
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    let replies = client.join().unwrap();
+    assert!(replies[0].starts_with("commands: "));
+    assert!(replies[1].starts_with("error: unknown command 'bogus'"));
+    assert_eq!(replies[2], "ok");
+    assert!(replies[3].contains("\"density\":0.5"), "{}", replies[3]);
+
+    drop(socket);
+    assert!(!path.exists());
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[cfg(unix)]
+#[test]
+fn bind_refuses_to_replace_a_regular_file() {
+    let dir = std::env::temp_dir().join(format!("cosmostrix-ctl-file-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("not-a-socket");
+    std::fs::write(&path, "keep me").unwrap();
+    let err = ControlSocket::bind(&path).err().expect("bind must fail");
+    assert!(err.to_string().contains("not a socket"));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "keep me");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[cfg(unix)]
+#[test]
+fn connections_past_the_limit_are_turned_away() {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixStream;
+
+    let dir = std::env::temp_dir().join(format!("cosmostrix-ctl-cap-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("ctl.sock");
+    let socket = ControlSocket::bind(&path).unwrap();
+    // `help` is answered on the connection thread, so a reply proves the
+    // connection holds a slot.
+    let held: Vec<_> = (0..MAX_CONNECTIONS)
+        .map(|_| {
+            let mut stream = UnixStream::connect(&path).unwrap();
+            stream.write_all(b"help\n").unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            assert!(line.starts_with("commands: "), "{line}");
+            reader
+        })
+        .collect();
+    let mut line = String::new();
+    BufReader::new(UnixStream::connect(&path).unwrap())
+        .read_line(&mut line)
+        .unwrap();
+    assert!(line.starts_with("error: too many connections"), "{line}");
+    drop(held);
+    drop(socket);
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -10,21 +10,26 @@
 use std::sync::atomic::Ordering;
 use std::time::{Duration, Instant};
 
-use crossterm::event::{Event, KeyCode, KeyEventKind, MouseEventKind};
+use crossterm::event::{Event, KeyEventKind, MouseEventKind};
 
 use crate::color_cache::ColorCache;
 use crate::constants::*;
 use crate::frame::Frame;
+use crate::terminal::asciicast::AsciicastRecorder;
 use crate::terminal::{is_terminal_gone, Terminal};
 
 use super::super::{effective_density, CloudConfig};
 use super::activity::{register_activity, spin_wait, FrameTimeTracker};
 use super::adaptive::{
-    adaptive_resync_interval, EnduranceHealth, PerformanceSelfHealer, ReclaimState, SelfHealAction,
+    adaptive_resync_interval_with, EnduranceHealth, PerformanceSelfHealer, ReclaimState,
+    SelfHealAction,
 };
+use super::control_socket::{self, ControlSocket};
 use super::event_loop_finalize::{finalize_session, SessionStats};
 use super::hud::{FrameMode, HudState};
-use super::input::{handle_keybinding, is_unmodified, KeybindingCtx, PasteBurstGuard};
+use super::input::{handle_keybinding, KeybindingCtx, PasteBurstGuard};
+use super::keymap::KeyAction;
+use super::term_setup::{configure_terminal, reinit_terminal};
 use super::watchdog::{FRAME_COUNTER, GRACEFUL_SHUTDOWN, MOUSE_CAPTURE_ACTIVE};
 use crate::central_control_dragon_power::sample_thermal_pressure;
 
@@ -51,6 +56,37 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
     } else {
         term.size()?
     };
+    // --record: attach before the intro so it is captured too.
+    if let Some(path) = cfg.record.as_deref() {
+        // Same whitelist as --save-baseline: --record truncates its target.
+        if !crate::is_safe_path(&path.to_string_lossy()) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::PermissionDenied,
+                format!(
+                    "--record '{}' is outside allowed directories\n  \
+                     Allowed: ~/.config/cosmostrix/, /etc/cosmostrix/",
+                    path.display()
+                ),
+            ));
+        }
+        let rec = AsciicastRecorder::create(path, w, h).map_err(|e| {
+            std::io::Error::new(
+                e.kind(),
+                format!("cannot record to '{}': {e}", path.display()),
+            )
+        })?;
+        term.set_recorder(rec);
+    }
+    // --control-socket: bind before the intro so a bad path fails fast.
+    let control_socket = match cfg.control_socket.as_deref() {
+        Some(path) => Some(ControlSocket::bind(path).map_err(|e| {
+            std::io::Error::new(
+                e.kind(),
+                format!("cannot listen on control socket '{}': {e}", path.display()),
+            )
+        })?),
+        None => None,
+    };
 
     let density = effective_density(cfg.base_density, w, cfg.density_auto);
 
@@ -69,8 +105,8 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
     // branch correctly restores the user's state after each rebuild.
     cloud.user_override_since_ambient = true;
 
-    // Build color byte cache so the draw hot path emits pre-formatted SGR.
-    term.set_color_cache(ColorCache::new(&cloud.palette));
+    // [power-dragon] budgets, light canvas, quantizer and color cache.
+    configure_terminal(&mut term, cfg, &cloud.palette);
 
     let mut frame = Frame::new(w, h, cloud.palette.bg);
 
@@ -177,6 +213,7 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
     let mut next_frame = Instant::now();
     // (Phase 3): PowerManager owns perf_pressure, is_idle, effective FPS.
     let mut power_manager = PowerManager::new(cfg.target_fps, Instant::now());
+    power_manager.set_thresholds(cfg.power_thresholds); // [power-dragon]
 
     let mut perf_frames: u64 = 0;
     let mut perf_drawn_frames: u64 = 0;
@@ -211,6 +248,7 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
 
     let mut last_user_input_at = Instant::now(); // auto-snapback driver
     let mut self_healer = PerformanceSelfHealer::new(); // P1+P2
+    self_healer.set_thresholds(cfg.power_thresholds);
 
     let mut charset_preset = cfg.charset_preset.clone();
     let mut scene_name = cfg.scene_name.clone();
@@ -237,13 +275,18 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
     // instead of the live-reloaded values (e.g. crystal_dragon=true).
     let mut current_cfg = cfg.clone();
     // Pending rebuild: set when watcher sends new config, applied at top of next frame.
-    let mut pending_config: Option<std::collections::HashMap<String, String>> = None;
+    let mut pending_config: Option<crate::config_schema::ConfigMap> = None;
 
     // Ambient scheduler: idle/wake thread sends AmbientEntry via mpsc.
     let ambient_handle = crate::crystal_dragon_engine::ambient_scheduler::spawn_ambient_scheduler(
         base_cfg.ambient_schedule.clone(),
     );
     let mut last_ambient_schedule = base_cfg.ambient_schedule.clone();
+    // External-signal reader for the `external` Crystal Dragon sensor
+    // (file / FIFO / command). Idle when the source isn't in use.
+    let mut external_signal = crate::crystal_dragon_engine::external::ExternalSignalHandle::spawn(
+        active_external_source(&base_cfg),
+    );
     // last-applied ambient entry — re-applied after live-reload rebuilds.
     let mut last_applied_ambient_entry: Option<
         crate::crystal_dragon_engine::ambient::AmbientEntry,
@@ -266,7 +309,7 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
         .as_deref()
         .map(|p| crate::configfile::load_config_file(Some(p)))
         .unwrap_or_default();
-    let mut last_applied_cfg_map: Option<std::collections::HashMap<String, String>> =
+    let mut last_applied_cfg_map: Option<crate::config_schema::ConfigMap> =
         Some(initial_cfg_map.clone());
 
     // +hotfix: synchronous ambient apply at startup with REAL cfg map.
@@ -281,8 +324,9 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
     // startup ambient info for post-exit verbose (main.rs prints after drop).
     let ambient_info = match &startup_entry {
         Some(e) => format!(
-            "ambient: startup phase {:02}:{:02} (scene={}) applied at cold start",
-            e.hour, e.minute, e.scene
+            "ambient: startup phase {} (scene={}) applied at cold start",
+            e.label(),
+            e.scene
         ),
         None => "ambient: no active phase at startup, default scene retained".to_string(),
     };
@@ -385,8 +429,12 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
             }
             // Fresh Cloud from rebuild — reset self-healer.
             self_healer.reset();
-            // Rebuild color cache + frame + fill bg + charset.
-            term.set_color_cache(ColorCache::new(&cloud.palette));
+            // [power-dragon] thresholds are live: idle/FPS, self-healer,
+            // thermal ramp and xterm.js budgets all pick them up here.
+            power_manager.set_thresholds(new_cfg.power_thresholds);
+            self_healer.set_thresholds(new_cfg.power_thresholds);
+            // Terminal state (incl. color cache) + frame + fill bg + charset.
+            configure_terminal(&mut term, &new_cfg, &cloud.palette);
             frame = Frame::new(w, h, cloud.palette.bg);
             super::fill_terminal_bg(cloud.palette.bg);
             charset_preset = new_cfg.charset_preset.clone();
@@ -397,6 +445,8 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
             hud_state.set_target_fps(safe_fps);
             // AB-07: count every config rebuild for diagnostics.
             super::ambient_diag_config_rebuild();
+            // External signal: restart the reader if its source changed.
+            external_signal.reload(active_external_source(&new_cfg));
             // Ambient: push new schedule to scheduler if it changed.
             if new_cfg.ambient_schedule != last_ambient_schedule {
                 super::ambient_diag_schedule_reload();
@@ -531,6 +581,9 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
                 cloud.ambient_palette_locked = false;
             }
         }
+        cloud
+            .crystal_dragon_sensor
+            .set_external_reading(external_signal.latest());
         // AB-03+AB-04: poll ambient phase events. Empty schedule → drain.
         // Non-empty → discard events no longer in schedule (membership check).
         let mut last_ambient_entry: Option<crate::crystal_dragon_engine::ambient::AmbientEntry> =
@@ -554,8 +607,10 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
         {
             last_ground_truth_check = Instant::now();
             if let Some(ref path) = config_path_for_ground_truth {
-                if let Ok(c) = std::fs::read_to_string(path) {
-                    let pv = &crate::configfile::parse_config_text(&c).values;
+                // Same layer stack as startup (includes + conf.d). Skipped
+                // while config.toml is missing mid atomic-save.
+                if path.exists() {
+                    let pv = &crate::configfile::load_config_file(Some(path));
                     if crate::crystal_dragon_engine::ambient::collect_ambient_schedule(pv)
                         .entries
                         .is_empty()
@@ -583,12 +638,13 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
                 // Duplicate — already applied.
             } else {
                 let cfg_map = last_applied_cfg_map.clone().unwrap_or_default();
-                charset_preset = cloud.apply_ambient_entry(
+                charset_preset = cloud.crossfade_ambient_entry(
                     &entry,
                     &charset_preset,
                     &user_ranges,
                     def_ascii,
                     &cfg_map,
+                    last_ambient_schedule.transition,
                 );
                 last_applied_ambient_entry = Some(entry.clone());
                 scene_name = entry.scene.clone();
@@ -615,8 +671,8 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
             if !ambient_snapback_killed && _ab06_sked_len > 0 && _ab06_last_applied {
                 let mut empty = false;
                 if let Some(ref path) = config_path_for_ground_truth {
-                    if let Ok(c) = std::fs::read_to_string(path) {
-                        let pv = &crate::configfile::parse_config_text(&c).values;
+                    if path.exists() {
+                        let pv = &crate::configfile::load_config_file(Some(path));
                         if crate::crystal_dragon_engine::ambient::collect_ambient_schedule(pv)
                             .entries
                             .is_empty()
@@ -675,7 +731,8 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
             .idle_started()
             .map(|t| loop_now.saturating_duration_since(t).as_secs_f64())
             .unwrap_or(0.0);
-        let effective_resync_interval = adaptive_resync_interval(idle_secs);
+        let effective_resync_interval =
+            adaptive_resync_interval_with(idle_secs, &power_manager.thresholds());
         if is_idle
             && loop_now
                 .saturating_duration_since(last_resync_time)
@@ -701,10 +758,44 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
             cloud.raining = false;
             break;
         }
+        // --control-socket: apply commands queued since the last frame.
+        // They count as user input (idle wake, auto-snapback timer).
+        if let Some(ref socket) = control_socket {
+            let changed = control_socket::serve_pending(
+                socket,
+                &mut KeybindingCtx {
+                    cloud: &mut cloud,
+                    frame: &mut frame,
+                    charset_preset: &mut charset_preset,
+                    scene_name: &mut scene_name,
+                    scene_generation: &mut scene_generation,
+                    user_ranges: &user_ranges,
+                    def_ascii,
+                    // Live-reloaded, same as the keyboard path below.
+                    cfg: &current_cfg,
+                    term_reinit: &term_reinit,
+                },
+            );
+            if changed {
+                let activity_time = Instant::now();
+                if register_activity(
+                    &mut power_manager,
+                    &mut last_resync_time,
+                    activity_time,
+                    is_idle,
+                    false,
+                ) {
+                    cloud.force_draw_everything();
+                }
+                last_user_input_at = activity_time;
+                next_frame = activity_time;
+            }
+        }
         let mut pending_resize: Option<(u16, u16)> = None;
         if crate::platform::swap_term_reinit(&term_reinit) {
-            drop(term);
-            term = Terminal::with_signal_exit(signal_exit.clone())?;
+            term = reinit_terminal(term, &current_cfg, &cloud.palette, || {
+                Terminal::with_signal_exit(signal_exit.clone())
+            })?;
             // v17: always re-enable mouse reporting after SIGCONT (see
             // startup comment for rationale — block copy in all modes).
             if term.enable_mouse_capture().is_ok() {
@@ -787,13 +878,13 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
                             next_frame = activity_time;
                             continue;
                         }
-                        // HUD toggle ('i'): check BEFORE screensaver exit to prevent
-                        // self-exit on Android/Termux. v30: lowercase-only. Toggling
-                        // OFF calls force_draw_everything() to clear stale HUD residue.
-                        // Modifier guard: only bare 'i' (NONE). Rejects Shift+'i'
-                        // (which produces 'I', no binding) and all other modifiers
-                        // (Ctrl/Super/Alt/Hyper/Meta+'i'). See is_unmodified().
-                        if is_unmodified(k.modifiers) && matches!(k.code, KeyCode::Char('i')) {
+                        // HUD toggle ('i' unless `[keys] hud` rebinds it): check
+                        // BEFORE screensaver exit to prevent self-exit on
+                        // Android/Termux. Toggling OFF calls force_draw_everything()
+                        // to clear stale HUD residue. The keymap matches modifiers
+                        // exactly, so Shift+'i' ('I') and Ctrl/Super/Alt+'i' are
+                        // rejected by default. Reads the live-reloaded keymap.
+                        if current_cfg.keymap.action_for(&k) == Some(KeyAction::Hud) {
                             let now_visible = hud_state.toggle();
                             if !now_visible {
                                 cloud.force_draw_everything();
@@ -826,8 +917,8 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
                         // refresh auto-snapback idle timer on every key press.
                         last_user_input_at = activity_time;
                         // Process the keybinding. This lets interactive
-                        // keys (q, c/C, s/S, p, x/X, [, ], Space, Up/Down,
-                        // i/I, h/H) work even in --screensaver mode.
+                        // keys (q, c/C, s/S, p, x, [, ], Space, Up/Down by
+                        // default; see `[keys]`) work even in --screensaver mode.
                         let redraw_needed = handle_keybinding(
                             &mut KeybindingCtx {
                                 cloud: &mut cloud,
@@ -837,14 +928,15 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
                                 scene_generation: &mut scene_generation,
                                 user_ranges: &user_ranges,
                                 def_ascii,
-                                cfg,
+                                // Live-reloaded: carries the current `[keys]` map.
+                                cfg: &current_cfg,
                                 term_reinit: &term_reinit,
                             },
                             &k,
                         );
                         if cfg.screensaver {
                             // Screensaver: recognized keys process+continue; others ignored.
-                            // Mouse click doesn't exit (v17). Only 'q' quits.
+                            // Mouse click doesn't exit (v17). Only the quit key ('q') quits.
                             if !cloud.raining {
                                 break;
                             }
@@ -995,6 +1087,7 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
             break;
         }
         if let Some((nw, nh)) = pending_resize {
+            term.record_resize(nw, nh);
             cloud.reset(nw, nh);
             frame = Frame::new(nw, nh, cloud.palette.bg);
             if cfg.density_auto {
@@ -1233,7 +1326,7 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
         // sampler returns None and the previous thermal_pressure value
         // is preserved (NOT reset to 0.0).
         if perf_rss_samples.is_multiple_of(THERMAL_SAMPLER_INTERVAL_FRAMES) {
-            if let Some(p) = sample_thermal_pressure() {
+            if let Some(p) = sample_thermal_pressure(&power_manager.thresholds()) {
                 power_manager.set_thermal_pressure(p);
             }
         }
@@ -1375,3 +1468,18 @@ pub(crate) fn run_interactive(cfg: &CloudConfig) -> std::io::Result<()> {
         &current_cfg,
     )
 }
+
+/// The external-signal source to read, if Crystal Dragon is on and the
+/// `external` sensor is selected.
+fn active_external_source(
+    cfg: &CloudConfig,
+) -> Option<crate::crystal_dragon_engine::external::ExternalSource> {
+    use crate::crystal_dragon_engine::crystal_dragon_control::CrystalDragonSensorMode;
+    let wanted = cfg.crystal_dragon
+        && cfg
+            .crystal_dragon_sensors
+            .contains(CrystalDragonSensorMode::External);
+    wanted
+        .then(|| cfg.crystal_dragon_external.clone())
+        .flatten()
+}
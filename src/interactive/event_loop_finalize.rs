@@ -67,7 +67,7 @@ pub(crate) struct SessionStats<'a> {
 /// 7. Print the deferred final FPS line if `--perf-stats`.
 pub(crate) fn finalize_session(
     stats: &SessionStats,
-    term: Terminal,
+    mut term: Terminal,
     cloud: &Cloud,
     scene_name: &str,
     charset_preset: &str,
@@ -91,12 +91,29 @@ pub(crate) fn finalize_session(
 
     let (enc_bytes, enc_flushes, sgr_hits, sgr_misses) = term.encoding_stats();
     let (tier2_skips, tier2_resets, tier2_bytes_since) = term.tier2_stats();
+    let recording = term.finish_recording();
 
     // AB-10: drop the terminal BEFORE any stderr write so the alt screen
     // is restored and the final FPS line lands on the main screen, not
     // polluting the rain matrix on exit.
     drop(term);
 
+    // --record outcome: always reported (the user asked for a file).
+    if let Some(rec) = recording {
+        match rec.error {
+            None => eprintln!(
+                "[cosmostrix] recorded {} events to {}",
+                rec.events,
+                rec.path.display()
+            ),
+            Some(e) => eprintln!(
+                "[cosmostrix] recording to {} stopped after {} events: {e}",
+                rec.path.display(),
+                rec.events
+            ),
+        }
+    }
+
     // Print the perf report AFTER drop(term) — stdout is captured by the
     // alt-screen buffer and lost when Terminal::drop() restores the main
     // screen. Using eprint() (stderr) so the report survives the restore.
@@ -16,6 +16,7 @@ use crate::cloud::Cloud;
 use crate::constants::*;
 use crate::frame::Frame;
 use crate::rain_style::RainStyle;
+use crate::runtime::ColorScheme;
 
 use crate::scene;
 
@@ -72,30 +73,15 @@ pub(super) fn is_plain_printable_key(key: &crossterm::event::KeyEvent) -> bool {
         && (key.modifiers.is_empty() || key.modifiers == KeyModifiers::SHIFT)
 }
 
-/// Returns true if the key event has NO modifier bits set.
-///
-/// Use for non-cycle shortcuts (q, c, s, x, i, p, [, ], space,
-/// arrows) that must only respond to bare keypresses — Shift+key
-/// (e.g. Shift+q → 'Q') and any other modifier combination are rejected.
-///
-/// CapsLock is a keyboard state, NOT a KeyModifiers bit — it changes
-/// which Char the terminal reports ('q' → 'Q' when CapsLock is on,
-/// with modifiers=NONE). It is inherently allowed by this check because
-/// the CapsLock-produced uppercase char simply doesn't match the lowercase
-/// KeyCode::Char('q') arm (it matches 'Q', which has no binding).
-pub(super) fn is_unmodified(modifiers: crossterm::event::KeyModifiers) -> bool {
-    modifiers.is_empty()
-}
-
 /// Returns true if the key event's modifiers are in the "safe" allowlist:
 /// only bare keys (KeyModifiers::NONE) or SHIFT (for capital S/C reverse-
 /// cycle bindings). Rejects ALL other modifier bits: CONTROL, ALT, SUPER,
 /// HYPER, META.
 ///
-/// Use ONLY for cycle shortcuts (uppercase S, C) where Shift is a
-/// deliberate input (Shift+s → S = reverse charset cycle, Shift+c → C =
-/// reverse color cycle). Non-cycle shortcuts must use `is_unmodified()`
-/// instead.
+/// Used by the intro skip check. Runtime shortcuts match their exact
+/// modifier set through the keymap instead (see `keymap::KeySpec`), which
+/// gives the same result for the default bindings: Shift is only accepted
+/// on the uppercase S / C reverse-cycle keys.
 ///
 /// CapsLock is a keyboard state, NOT a KeyModifiers bit — it changes
 /// which Char the terminal reports ('c' → 'C' when CapsLock is on,
@@ -126,208 +112,272 @@ pub(super) struct KeybindingCtx<'a> {
     pub term_reinit: &'a TermReinit,
 }
 
-pub(super) fn handle_keybinding(ctx: &mut KeybindingCtx, k: &crossterm::event::KeyEvent) -> bool {
-    let cloud = &mut *ctx.cloud;
-    let frame = &mut *ctx.frame;
-    let charset_preset = &mut *ctx.charset_preset;
-    let scene_name = &mut *ctx.scene_name;
-    let scene_generation = &mut *ctx.scene_generation;
-    let user_ranges = ctx.user_ranges;
-    let def_ascii = ctx.def_ascii;
-    let _cfg = ctx.cfg;
-    let _term_reinit = ctx.term_reinit;
-
-    use crossterm::event::{KeyCode, KeyModifiers};
+/// One runtime change. Keystrokes map onto these through the `[keys]`
+/// keymap ([`super::keymap::KeyAction::runtime_action`]) and
+/// `--control-socket` commands parse into them, so both paths go through
+/// [`apply_action`] and behave identically.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum RuntimeAction {
+    /// `q`.
+    Quit,
+    /// Space: reseed the rain and replay the message typewriter.
+    Reseed,
+    /// `c` (+1) / `C` (-1).
+    CycleColor(i32),
+    /// `s` (+1) / `S` (-1).
+    CycleCharset(i32),
+    /// `x` (+1).
+    CycleScene(i32),
+    /// `p`.
+    TogglePause,
+    Pause,
+    Resume,
+    /// Up (+1) / Down (-1).
+    StepSpeed(i32),
+    /// `]` (+1) / `[` (-1).
+    StepDensity(i32),
+    SetColor(ColorScheme),
+    /// Charset preset name, already validated.
+    SetCharset(String),
+    /// Built-in scene name, already validated.
+    SetScene(String),
+    /// Chars per second; clamped like Up/Down.
+    SetSpeed(f32),
+    /// Clamped like `[` / `]`.
+    SetDensity(f32),
+    /// Sanitized overlay text; `None` removes the box.
+    SetMessage(Option<String>),
+}
 
-    // Pause guard: when paused OR decelerating toward pause, ONLY
-    // 'p' (resume/cancel-decel) and 'q' (quit) are processed. All
-    // other keys are silently ignored to prevent queued state changes
-    // from accumulating during the pause/deceleration window and
-    // causing "stuck particles" or visual glitches on resume.
-    //
-    // Must check `is_paused_or_decelerating()` (not just `pause`)
-    // because the deceleration phase (pause_start.is_some()) is also
-    // a pause-related state where user interactions should be
-    // suppressed (owner-reported bug: rapid p-taps left effects
-    // hanging).
-    if cloud.is_paused_or_decelerating() {
-        match (k.code, k.modifiers) {
-            (KeyCode::Char('p'), KeyModifiers::NONE) => {
-                return cloud.toggle_pause();
-            }
-            (KeyCode::Char('q'), KeyModifiers::NONE) => {
-                // Allow quit during pause
-            }
-            _ => {
-                // Silently ignore all other keys during pause
-                return false;
-            }
-        }
+impl RuntimeAction {
+    /// Actions still accepted while paused or decelerating (see the pause
+    /// guard in [`handle_keybinding`]).
+    #[must_use]
+    pub(crate) fn allowed_while_paused(&self) -> bool {
+        matches!(
+            self,
+            Self::Quit | Self::TogglePause | Self::Pause | Self::Resume
+        )
     }
+}
 
-    // Modifier fast-reject: block any key event that carries modifier bits
-    // beyond NONE and SHIFT. The per-arm match below then applies the final
-    // policy:
+pub(super) fn handle_keybinding(ctx: &mut KeybindingCtx, k: &crossterm::event::KeyEvent) -> bool {
+    // Modifier policy lives in the keymap: a binding matches only its exact
+    // modifier set (see `keymap::KeySpec::from_event`). With the default
+    // map that means:
     //  • Non-cycle shortcuts (lowercase q/c/s/x/p/[/]/space, arrows):
     //    match only KeyModifiers::NONE — Shift+key is rejected (owner
     //    requirement: only bare lowercase key, not uppercase variant).
-    //  • Cycle shortcuts (uppercase C/S):
-    //    match any modifier that passes this guard (NONE or SHIFT) — both
-    //    are valid: NONE = CapsLock produced the uppercase char, SHIFT =
-    //    Shift produced it.
+    //  • Cycle shortcuts (uppercase C/S): match NONE or SHIFT — NONE =
+    //    CapsLock produced the uppercase char, SHIFT = Shift produced it.
     //
     // Owner-reported bug (v50 alpha.3): Super+C still cycled colors on
     // modern terminals (kitty, wezterm, foot) that report the kitty
     // keyboard protocol's enhanced modifier bits. crossterm 0.29 exposes
     // SUPER (0b1000), HYPER (0b10000), and META (0b100000) as separate
     // KeyModifiers bits — the previous denylist only blocked CONTROL |
-    // ALT, leaving SUPER/HYPER/META unguarded.
+    // ALT, leaving SUPER/HYPER/META unguarded. Exact matching keeps every
+    // bit a binding does not name (including HYPER/META, which no
+    // binding can name) a reject.
     //
     // Owner follow-up (v50 alpha.4): non-cycle shortcuts like 'q' must
     // only respond to the bare lowercase key, NOT the Shift-produced
-    // uppercase variant. Previously Shift+q (CapsLock on) could trigger
-    // quit because the match arm used `_` for modifiers.
+    // uppercase variant.
     //
-    // The allowlist approach is future-proof: if crossterm adds new
-    // modifier bits (e.g. FUNCTION), they are rejected by default — no
-    // silent passthrough.
+    // Quit policy: only the `quit` binding exits ('q' by default). Esc,
+    // Ctrl+C (SIGINT is deprecated), Ctrl+Z (in-app suspend removed v30:
+    // terminal-driven SIGTSTP still works via signal_handlers.rs),
+    // Tab/BackTab, and any other unbound key are silently ignored unless
+    // `[keys]` binds them. The regression suite in tests.rs::tab_*
+    // documents the historical Tab shading-mode bug.
     //
-    // CapsLock is a keyboard state, NOT a KeyModifiers bit — it changes
-    // which Char the terminal reports ('c' → 'C' when CapsLock is on,
-    // with modifiers=NONE). It is inherently allowed by the allowlist
-    // because no modifier bit is set.
-    if !is_unmodified_or_shift(k.modifiers) {
+    // The HUD toggle is bound here too but handled by the event loop
+    // before this call; it has no session action.
+    let Some(action) = ctx
+        .cfg
+        .keymap
+        .action_for(k)
+        .and_then(|bound| bound.runtime_action())
+    else {
         return false;
-    }
+    };
 
-    // Quit policy: only 'q' exits. Esc, Ctrl+C (SIGINT is deprecated),
-    // Ctrl+Z (in-app suspend removed v30: terminal-driven SIGTSTP still
-    // works via signal_handlers.rs), Tab/BackTab, and any other
-    // unrecognized key are silently ignored (fall through to the
-    // `_ => {}` arm at the end of this match). This prevents accidental
-    // exits from terminal menu Esc, Ctrl+C muscle memory, or stray
-    // function keys. The user must press 'q' deliberately to quit.
-    // SIGINT (Ctrl+C) is no longer in the graceful-shutdown
-    // signal list — see signal_handlers.rs.
+    // Pause guard: when paused OR decelerating toward pause, ONLY
+    // pause (resume/cancel-decel) and quit are processed. All
+    // other keys are silently ignored to prevent queued state changes
+    // from accumulating during the pause/deceleration window and
+    // causing "stuck particles" or visual glitches on resume.
     //
-    // Historical note: Tab previously had an explicit arm here that
-    // toggled shading mode, which triggered a phosphor ghost-flood bug.
-    // The arm was removed v30; Tab now falls through to `_ => {}`. The
-    // regression suite in tests.rs::tab_* documents the historical bug
-    // and verifies Tab remains a no-op.
-    match (k.code, k.modifiers) {
-        // Non-cycle shortcuts: KeyModifiers::NONE only.
-        // Owner requirement: only bare lowercase key, not Shift-produced
-        // uppercase. E.g. 'q' quits, 'Q' does nothing.
-        (KeyCode::Char('q'), KeyModifiers::NONE) => cloud.raining = false,
-        (KeyCode::Char(' '), KeyModifiers::NONE) => {
+    // Must check `is_paused_or_decelerating()` (not just `pause`)
+    // because the deceleration phase (pause_start.is_some()) is also
+    // a pause-related state where user interactions should be
+    // suppressed (owner-reported bug: rapid p-taps left effects
+    // hanging).
+    if ctx.cloud.is_paused_or_decelerating() && !action.allowed_while_paused() {
+        return false;
+    }
+
+    apply_action(ctx, action)
+}
+
+/// Apply `action` to the session. Returns `true` when the caller should
+/// render immediately (pause state changed). The pause guard is the
+/// caller's job.
+pub(super) fn apply_action(ctx: &mut KeybindingCtx, action: RuntimeAction) -> bool {
+    let cloud = &mut *ctx.cloud;
+    let frame = &mut *ctx.frame;
+    let charset_preset = &mut *ctx.charset_preset;
+    let scene_name = &mut *ctx.scene_name;
+    let scene_generation = &mut *ctx.scene_generation;
+    let user_ranges = ctx.user_ranges;
+    let def_ascii = ctx.def_ascii;
+    let _cfg = ctx.cfg;
+    let _term_reinit = ctx.term_reinit;
+
+    match action {
+        RuntimeAction::Quit => cloud.raining = false,
+        RuntimeAction::Reseed => {
             cloud.reset(frame.width, frame.height);
             cloud.force_draw_everything();
             // Restart message typewriter so Space gives a full cinematic
             // replay — rain reseed + message types out from scratch.
             cloud.restart_message_typewriter();
         }
-        // Color cycle: 'c' forward, 'C' (shift+c) reverse.
-        // v30 simplify had removed uppercase 'C'/'S' for consistency; owner
-        // restored them as reverse-cycle bindings (shift+c/s is simple and
-        // matches the c/C, s/S convention). See audit task flags-audit-4.
-        //
-        // ambient harmony: 'c'/'C' clears `ambient_palette_locked` (user
-        // is taking ownership of color) and sets `user_override_since_ambient`
-        // (so the next ambient fire isn't deduped). See
-        // docs/audits/AMBIENT_SCHEDULER_AUDIT.md §2.3.
-        (KeyCode::Char('c'), KeyModifiers::NONE) => {
-            let next = cycle_color_scheme(cloud.color_scheme(), 1);
-            cloud.set_color_scheme(next);
-            cloud.user_override_since_ambient = true;
-            cloud.ambient_palette_locked = false;
+        // ambient harmony: a color change clears `ambient_palette_locked`
+        // (user is taking ownership of color) and sets
+        // `user_override_since_ambient` (so the next ambient fire isn't
+        // deduped). See docs/audits/AMBIENT_SCHEDULER_AUDIT.md §2.3.
+        RuntimeAction::CycleColor(dir) => {
+            let next = cycle_color_scheme(cloud.color_scheme(), dir);
+            set_user_color(cloud, next);
         }
-        // Cycle shortcut: uppercase 'C' accepts NONE (CapsLock) or SHIFT.
-        // The global guard already limits modifiers to NONE | SHIFT, so
-        // `_` here is safe and concise.
-        (KeyCode::Char('C'), _) => {
-            let prev = cycle_color_scheme(cloud.color_scheme(), -1);
-            cloud.set_color_scheme(prev);
-            cloud.user_override_since_ambient = true;
-            cloud.ambient_palette_locked = false;
+        RuntimeAction::SetColor(scheme) => set_user_color(cloud, scheme),
+        RuntimeAction::CycleCharset(dir) => {
+            let next = cycle_charset_preset(charset_preset, dir);
+            set_user_charset(cloud, charset_preset, next, user_ranges, def_ascii);
         }
-        (KeyCode::Char('s'), KeyModifiers::NONE) => {
-            let next = cycle_charset_preset(charset_preset, 1);
-            *charset_preset = next.to_string();
-            if let Ok(cs) = charset_from_str(charset_preset, def_ascii) {
-                let chars = build_chars(cs, user_ranges, def_ascii);
-                cloud.transition_chars(chars);
-            }
-            // charset change is a user override — flag it so the next
-            // ambient fire (which resets charset via apply_ambient_entry)
-            // isn't deduped.
-            cloud.user_override_since_ambient = true;
+        RuntimeAction::SetCharset(name) => {
+            set_user_charset(cloud, charset_preset, &name, user_ranges, def_ascii);
         }
-        // Cycle shortcut: uppercase 'S' accepts NONE (CapsLock) or SHIFT.
-        (KeyCode::Char('S'), _) => {
-            let prev = cycle_charset_preset(charset_preset, -1);
-            *charset_preset = prev.to_string();
-            if let Ok(cs) = charset_from_str(charset_preset, def_ascii) {
-                let chars = build_chars(cs, user_ranges, def_ascii);
-                cloud.transition_chars(chars);
+        RuntimeAction::TogglePause => return cloud.toggle_pause(),
+        RuntimeAction::Pause => {
+            if !cloud.is_paused_or_decelerating() {
+                return cloud.toggle_pause();
             }
-            cloud.user_override_since_ambient = true;
         }
-
-        (KeyCode::Char('p'), KeyModifiers::NONE) => {
-            return cloud.toggle_pause();
+        RuntimeAction::Resume => {
+            if cloud.is_paused_or_decelerating() {
+                return cloud.toggle_pause();
+            }
         }
-        (KeyCode::Char('x'), KeyModifiers::NONE) => {
-            let next = scene::cycle_scene(scene_name, 1);
-            *scene_name = next.to_string();
-            *scene_generation = scene_generation.wrapping_add(1);
-            *charset_preset =
-                cloud.apply_scene_runtime(next, charset_preset, user_ranges, def_ascii);
-            // scene change is a user override — flag both. The palette
-            // lock is cleared because the new scene may bring its own color
-            // (and Crystal Dragon should be free to drift from there until the
-            // next ambient fire re-locks).
-            cloud.user_override_since_ambient = true;
-            cloud.ambient_palette_locked = false;
+        RuntimeAction::CycleScene(dir) => {
+            let next = scene::cycle_scene(scene_name, dir);
+            set_user_scene(
+                cloud,
+                scene_name,
+                scene_generation,
+                charset_preset,
+                next,
+                user_ranges,
+                def_ascii,
+            );
         }
-        (KeyCode::Up, KeyModifiers::NONE) => {
-            let mut cps = cloud.chars_per_sec;
-            if cps <= 0.5 {
-                cps *= 2.0;
-            } else {
-                cps += 1.0;
-            }
-            cloud.set_chars_per_sec(runtime_speed_clamp(cps, cloud.rain_style()));
+        RuntimeAction::SetScene(name) => {
+            set_user_scene(
+                cloud,
+                scene_name,
+                scene_generation,
+                charset_preset,
+                &name,
+                user_ranges,
+                def_ascii,
+            );
         }
-        (KeyCode::Down, KeyModifiers::NONE) => {
+        RuntimeAction::StepSpeed(dir) => {
             let mut cps = cloud.chars_per_sec;
-            if cps <= 1.0 {
+            if dir > 0 {
+                if cps <= 0.5 {
+                    cps *= 2.0;
+                } else {
+                    cps += 1.0;
+                }
+            } else if cps <= 1.0 {
                 cps /= 2.0;
             } else {
                 cps -= 1.0;
             }
             cloud.set_chars_per_sec(runtime_speed_clamp(cps, cloud.rain_style()));
         }
-        // Density: '[' decreases, ']' increases. Simplified from the
-        // legacy alias set (-/_ for down, +/=Shift for up) — those were
-        // carried over from an older keymap and never documented in the
-        // --help reference, so they only caused confusion. '[' and ']'
-        // are the canonical density keys and the only ones documented.
-        (KeyCode::Char('['), KeyModifiers::NONE) => {
-            let d = (cloud.droplet_density - DENSITY_STEP).max(0.01);
-            cloud.set_droplet_density(d);
+        RuntimeAction::SetSpeed(cps) => {
+            cloud.set_chars_per_sec(runtime_speed_clamp(cps, cloud.rain_style()));
         }
-        (KeyCode::Char(']'), KeyModifiers::NONE) => {
-            let d = (cloud.droplet_density + DENSITY_STEP).min(5.0);
+        RuntimeAction::StepDensity(dir) => {
+            let d = if dir < 0 {
+                (cloud.droplet_density - DENSITY_STEP).max(0.01)
+            } else {
+                (cloud.droplet_density + DENSITY_STEP).min(5.0)
+            };
             cloud.set_droplet_density(d);
         }
-
-        _ => {}
+        RuntimeAction::SetDensity(d) => cloud.set_droplet_density(runtime_density_clamp(d)),
+        RuntimeAction::SetMessage(text) => cloud.replace_message(text.as_deref()),
     }
 
     false
 }
 
+fn set_user_color(cloud: &mut Cloud, scheme: ColorScheme) {
+    cloud.set_color_scheme(scheme);
+    cloud.user_override_since_ambient = true;
+    cloud.ambient_palette_locked = false;
+}
+
+fn set_user_charset(
+    cloud: &mut Cloud,
+    charset_preset: &mut String,
+    name: &str,
+    user_ranges: &[(char, char)],
+    def_ascii: bool,
+) {
+    *charset_preset = name.to_string();
+    if let Ok(cs) = charset_from_str(charset_preset, def_ascii) {
+        let chars = build_chars(cs, user_ranges, def_ascii);
+        cloud.transition_chars(chars);
+    }
+    // charset change is a user override — flag it so the next ambient
+    // fire (which resets charset via apply_ambient_entry) isn't deduped.
+    cloud.user_override_since_ambient = true;
+}
+
+fn set_user_scene(
+    cloud: &mut Cloud,
+    scene_name: &mut String,
+    scene_generation: &mut u64,
+    charset_preset: &mut String,
+    next: &str,
+    user_ranges: &[(char, char)],
+    def_ascii: bool,
+) {
+    *scene_name = next.to_string();
+    *scene_generation = scene_generation.wrapping_add(1);
+    *charset_preset = cloud.apply_scene_runtime(next, charset_preset, user_ranges, def_ascii);
+    // scene change is a user override — flag both. The palette lock is
+    // cleared because the new scene may bring its own color (and Crystal
+    // Dragon should be free to drift from there until the next ambient
+    // fire re-locks).
+    cloud.user_override_since_ambient = true;
+    cloud.ambient_palette_locked = false;
+}
+
+/// Density bounds shared by `[` / `]` and `density <n>`.
+pub(super) fn runtime_density_clamp(d: f32) -> f32 {
+    if d.is_finite() {
+        d.clamp(0.01, 5.0)
+    } else {
+        0.01
+    }
+}
+
 pub(super) fn runtime_speed_clamp(cps: f32, rain_style: RainStyle) -> f32 {
     let max = if matches!(rain_style, RainStyle::Monolith) {
         MONOLITH_EFFECTIVE_SPEED_MAX
@@ -382,7 +432,7 @@ pub(super) fn try_auto_snapback(
     scene_generation: &mut u64,
     last_applied_ambient_entry: &mut Option<crate::crystal_dragon_engine::ambient::AmbientEntry>,
     schedule: &crate::crystal_dragon_engine::ambient::AmbientSchedule,
-    last_cfg_map: &Option<std::collections::HashMap<String, String>>,
+    last_cfg_map: &Option<crate::config_schema::ConfigMap>,
     user_ranges: &[(char, char)],
     def_ascii: bool,
     last_user_input_at: Instant,
@@ -415,15 +465,15 @@ pub(super) fn try_auto_snapback(
         return false;
     }
     let now_min = crate::crystal_dragon_engine::ambient::current_minute_of_day();
-    let Some(entry) = schedule.current_phase(now_min).cloned() else {
+    let today = crate::crystal_dragon_engine::ambient::current_local_date();
+    let Some(entry) = schedule.current_phase(today, now_min).cloned() else {
         return false;
     };
     let cfg_map = last_cfg_map.clone().unwrap_or_default();
     crate::lr_trace!(
-        "ambient: auto-snapback after {:.1}s idle — applying phase {:02}:{:02} (scene={})",
+        "ambient: auto-snapback after {:.1}s idle — applying phase {} (scene={})",
         idle_secs,
-        entry.hour,
-        entry.minute,
+        entry.label(),
         entry.scene
     );
     *charset_preset =
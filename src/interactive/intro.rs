@@ -340,7 +340,13 @@ pub(super) fn lerp_rgb(a: (u8, u8, u8), b: (u8, u8, u8), t: f32) -> (u8, u8, u8)
 
 /// Seed an [`XorShift`] RNG from wall-clock nanos. Each intro run gets a
 /// different particle pattern, which keeps repeat viewings fresh.
-pub(super) fn seed_rng() -> XorShift {
+///
+/// With `--seed` (`cloud.seed` is `Some`) the particle pattern is folded
+/// from the session seed instead, so seeded runs replay the intro too.
+pub(super) fn seed_rng(seed: Option<u64>) -> XorShift {
+    if let Some(s) = seed {
+        return XorShift::new((s ^ (s >> 32)) as u32);
+    }
     let seed = Instant::now()
         .elapsed()
         .as_nanos()
@@ -90,7 +90,7 @@ pub(super) fn run_cosmic_intro(
     h: u16,
     logo_color: (u8, u8, u8),
 ) -> std::io::Result<()> {
-    let mut rng = seed_rng();
+    let mut rng = seed_rng(cloud.seed);
 
     // Center of the screen — the singularity point.
     let center_x = w as f32 * 0.5;
@@ -362,7 +362,7 @@ pub(super) fn run_logo_intro(
             .unwrap_or(std::cmp::Ordering::Equal)
     });
 
-    let mut rng = seed_rng();
+    let mut rng = seed_rng(cloud.seed);
     let palette_bg = cloud.palette.bg;
     let palette_rgb = palette_target_rgb(cloud);
     let rain_charset = rain_chars(cloud);
@@ -0,0 +1,450 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Runtime key bindings — the `[keys]` config section.
+//!
+//! Every runtime shortcut is a named [`KeyAction`]. The built-in map is the
+//! classic one (`q`, `p`, `c`/`C`, `[`/`]`, `i`, ...); a `[keys]` table
+//! rebinds any subset of it:
+//!
+//! ```toml
+//! [keys]
+//! quit = ["q", "ctrl+q"]   # a key spec, or an array of them
+//! hud = "f2"               # see KNOWN_ISSUES.md (`i` on Windows / Termux)
+//! density-up = "alt+up"
+//! cycle-scene-back = "X"   # unbound by default
+//! ```
+//!
+//! A key spec is one character or a key name (`space`, `up`, `f5`, ...),
+//! optionally prefixed with `ctrl+`, `alt+`, `super+` and `shift+`.
+//! `shift+c` and `C` are the same key. Binding matches are exact: a
+//! binding without modifiers ignores the key when Ctrl/Alt/Super (or any
+//! modifier bit this module does not know) is held, which keeps the
+//! Super+C rejection of the original hardcoded table.
+//!
+//! Listing an action replaces its default key. A key bound explicitly is
+//! taken away from the action that had it by default, so `pause = "space"`
+//! simply leaves `reseed` unbound. Two explicit bindings of one key are an
+//! error (the later action in [`KeyAction::ALL`] order loses the key), and
+//! `quit` can never end up without a key.
+//!
+//! Live reload rebuilds the map from the defaults plus the new `[keys]`
+//! table, so deleting a line gives that action its default key back.
+//! Conflicts are resolved the same way as at startup, against the new
+//! table only: the later action loses the key and the rejected binding
+//! lands in the runtime warning log.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::input::RuntimeAction;
+use crate::config_schema::{ConfigMap, ConfigValue};
+
+/// Config key prefix for the `[keys]` section.
+pub(crate) const KEYS_PREFIX: &str = "keys.";
+
+/// A bindable runtime shortcut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeyAction {
+    Quit,
+    Pause,
+    Reseed,
+    CycleColor,
+    CycleColorBack,
+    CycleCharset,
+    CycleCharsetBack,
+    CycleScene,
+    CycleSceneBack,
+    SpeedUp,
+    SpeedDown,
+    DensityUp,
+    DensityDown,
+    Hud,
+}
+
+impl KeyAction {
+    /// Every action, in the order conflicts are resolved.
+    pub(crate) const ALL: [KeyAction; 14] = [
+        Self::Quit,
+        Self::Pause,
+        Self::Reseed,
+        Self::CycleColor,
+        Self::CycleColorBack,
+        Self::CycleCharset,
+        Self::CycleCharsetBack,
+        Self::CycleScene,
+        Self::CycleSceneBack,
+        Self::SpeedUp,
+        Self::SpeedDown,
+        Self::DensityUp,
+        Self::DensityDown,
+        Self::Hud,
+    ];
+
+    /// Name used as the `[keys]` field.
+    #[must_use]
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Quit => "quit",
+            Self::Pause => "pause",
+            Self::Reseed => "reseed",
+            Self::CycleColor => "cycle-color",
+            Self::CycleColorBack => "cycle-color-back",
+            Self::CycleCharset => "cycle-charset",
+            Self::CycleCharsetBack => "cycle-charset-back",
+            Self::CycleScene => "cycle-scene",
+            Self::CycleSceneBack => "cycle-scene-back",
+            Self::SpeedUp => "speed-up",
+            Self::SpeedDown => "speed-down",
+            Self::DensityUp => "density-up",
+            Self::DensityDown => "density-down",
+            Self::Hud => "hud",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.name() == name)
+    }
+
+    fn config_key(self) -> String {
+        format!("{KEYS_PREFIX}{}", self.name())
+    }
+
+    /// The built-in binding (`None`: unbound unless configured).
+    fn default_key(self) -> Option<KeySpec> {
+        let code = match self {
+            Self::Quit => KeyCode::Char('q'),
+            Self::Pause => KeyCode::Char('p'),
+            Self::Reseed => KeyCode::Char(' '),
+            Self::CycleColor => KeyCode::Char('c'),
+            Self::CycleColorBack => KeyCode::Char('C'),
+            Self::CycleCharset => KeyCode::Char('s'),
+            Self::CycleCharsetBack => KeyCode::Char('S'),
+            Self::CycleScene => KeyCode::Char('x'),
+            Self::CycleSceneBack => return None,
+            Self::SpeedUp => KeyCode::Up,
+            Self::SpeedDown => KeyCode::Down,
+            Self::DensityUp => KeyCode::Char(']'),
+            Self::DensityDown => KeyCode::Char('['),
+            Self::Hud => KeyCode::Char('i'),
+        };
+        Some(KeySpec {
+            code,
+            modifiers: KeyModifiers::NONE,
+        })
+    }
+
+    /// The session change this key performs. `None` for `hud`, which the
+    /// event loop handles itself (the overlay is not session state).
+    #[must_use]
+    pub(super) fn runtime_action(self) -> Option<RuntimeAction> {
+        Some(match self {
+            Self::Quit => RuntimeAction::Quit,
+            Self::Pause => RuntimeAction::TogglePause,
+            Self::Reseed => RuntimeAction::Reseed,
+            Self::CycleColor => RuntimeAction::CycleColor(1),
+            Self::CycleColorBack => RuntimeAction::CycleColor(-1),
+            Self::CycleCharset => RuntimeAction::CycleCharset(1),
+            Self::CycleCharsetBack => RuntimeAction::CycleCharset(-1),
+            Self::CycleScene => RuntimeAction::CycleScene(1),
+            Self::CycleSceneBack => RuntimeAction::CycleScene(-1),
+            Self::SpeedUp => RuntimeAction::StepSpeed(1),
+            Self::SpeedDown => RuntimeAction::StepSpeed(-1),
+            Self::DensityUp => RuntimeAction::StepDensity(1),
+            Self::DensityDown => RuntimeAction::StepDensity(-1),
+            Self::Hud => return None,
+        })
+    }
+}
+
+/// One key plus modifiers, normalized so a config spec and a terminal
+/// event for the same keystroke compare equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct KeySpec {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeySpec {
+    /// Normalize a key event. Terminals report Shift+c as `'C'`, with or
+    /// without the SHIFT bit (CapsLock sets no bit at all), so SHIFT is
+    /// dropped for uppercase characters and for BackTab. Shift on a
+    /// lowercase character or a named key stays, so Shift+q and Shift+Up
+    /// do not trigger `q` and `up`.
+    #[must_use]
+    pub(crate) fn from_event(key: &KeyEvent) -> Self {
+        let mut modifiers = key.modifiers;
+        match key.code {
+            KeyCode::Char(c) if c.is_uppercase() => modifiers.remove(KeyModifiers::SHIFT),
+            KeyCode::BackTab => modifiers.remove(KeyModifiers::SHIFT),
+            _ => {}
+        }
+        Self {
+            code: key.code,
+            modifiers,
+        }
+    }
+}
+
+impl fmt::Display for KeySpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (bit, name) in MODIFIERS {
+            if self.modifiers.contains(*bit) {
+                write!(f, "{name}+")?;
+            }
+        }
+        if let Some((name, _)) = NAMED_KEYS.iter().find(|(_, k)| *k == self.code) {
+            return f.write_str(name);
+        }
+        match self.code {
+            KeyCode::Char(c) => write!(f, "{c}"),
+            KeyCode::F(n) => write!(f, "f{n}"),
+            KeyCode::BackTab => f.write_str("shift+tab"),
+            code => write!(f, "{code:?}"),
+        }
+    }
+}
+
+/// Modifier prefixes, in display order.
+const MODIFIERS: &[(KeyModifiers, &str)] = &[
+    (KeyModifiers::CONTROL, "ctrl"),
+    (KeyModifiers::ALT, "alt"),
+    (KeyModifiers::SUPER, "super"),
+    (KeyModifiers::SHIFT, "shift"),
+];
+
+/// Key names a spec may use instead of a character. `plus` and `comma`
+/// exist because `+` separates modifiers and `,` separates list entries.
+const NAMED_KEYS: &[(&str, KeyCode)] = &[
+    ("space", KeyCode::Char(' ')),
+    ("plus", KeyCode::Char('+')),
+    ("comma", KeyCode::Char(',')),
+    ("enter", KeyCode::Enter),
+    ("tab", KeyCode::Tab),
+    ("backspace", KeyCode::Backspace),
+    ("esc", KeyCode::Esc),
+    ("up", KeyCode::Up),
+    ("down", KeyCode::Down),
+    ("left", KeyCode::Left),
+    ("right", KeyCode::Right),
+    ("home", KeyCode::Home),
+    ("end", KeyCode::End),
+    ("pageup", KeyCode::PageUp),
+    ("pagedown", KeyCode::PageDown),
+    ("insert", KeyCode::Insert),
+    ("delete", KeyCode::Delete),
+];
+
+/// Parse one key spec (`q`, `C`, `shift+c`, `ctrl+alt+x`, `space`, `f5`).
+pub(crate) fn parse_key_spec(spec: &str) -> Result<KeySpec, String> {
+    let spec = spec.trim();
+    if spec.is_empty() {
+        return Err("empty key spec".to_string());
+    }
+    // `+` on its own, or as the key after modifiers (`ctrl++`).
+    let (prefix, key) = if spec == "+" {
+        ("", "+")
+    } else if let Some(prefix) = spec.strip_suffix("++") {
+        (prefix, "+")
+    } else {
+        spec.rsplit_once('+').unwrap_or(("", spec))
+    };
+
+    let mut modifiers = KeyModifiers::NONE;
+    for part in prefix.split('+').filter(|_| !prefix.is_empty()) {
+        let lower = part.trim().to_ascii_lowercase();
+        let Some((bit, _)) = MODIFIERS.iter().find(|(_, name)| *name == lower) else {
+            return Err(format!(
+                "unknown modifier '{part}' in '{spec}' (expected ctrl, alt, super or shift)"
+            ));
+        };
+        modifiers.insert(*bit);
+    }
+
+    let mut chars = key.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) if !c.is_control() => KeyCode::Char(c),
+        _ => {
+            let lower = key.to_ascii_lowercase();
+            if let Some(&(_, code)) = NAMED_KEYS.iter().find(|(name, _)| *name == lower) {
+                code
+            } else if let Some(n) = lower
+                .strip_prefix('f')
+                .and_then(|n| n.parse::<u8>().ok())
+                .filter(|n| (1..=24).contains(n))
+            {
+                KeyCode::F(n)
+            } else {
+                return Err(format!(
+                    "unknown key '{key}' in '{spec}' (expected one character, f1-f24 or one of: {})",
+                    NAMED_KEYS
+                        .iter()
+                        .map(|(name, _)| *name)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+        }
+    };
+
+    // Same normalization as `from_event`: Shift+letter is the uppercase
+    // letter, Shift+Tab is BackTab.
+    let code = match code {
+        KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) && c.is_alphabetic() => {
+            modifiers.remove(KeyModifiers::SHIFT);
+            KeyCode::Char(c.to_uppercase().next().unwrap_or(c))
+        }
+        KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => {
+            modifiers.remove(KeyModifiers::SHIFT);
+            KeyCode::BackTab
+        }
+        other => other,
+    };
+    Ok(KeySpec { code, modifiers })
+}
+
+/// Parse a `[keys]` value: one spec or a list of them (`config_schema`
+/// has already split the comma-separated form).
+fn parse_key_list(value: &ConfigValue) -> Result<Vec<KeySpec>, String> {
+    let items = value
+        .items()
+        .ok_or_else(|| format!("expected a key or an array of keys, got {value}"))?;
+    let mut keys: Vec<KeySpec> = Vec::new();
+    for item in items {
+        let spec = parse_key_spec(item)?;
+        if !keys.contains(&spec) {
+            keys.push(spec);
+        }
+    }
+    Ok(keys)
+}
+
+/// Parse the value of `keys.<action>` on its own.
+fn parse_binding(action: KeyAction, value: &ConfigValue) -> Result<Vec<KeySpec>, String> {
+    let keys = parse_key_list(value)?;
+    if keys.is_empty() && action == KeyAction::Quit {
+        return Err("quit needs at least one key".to_string());
+    }
+    Ok(keys)
+}
+
+/// The effective key → action table.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Keymap {
+    bindings: Vec<(KeySpec, KeyAction)>,
+}
+
+impl Default for Keymap {
+    /// The built-in bindings.
+    fn default() -> Self {
+        keymap_from_config(&HashMap::new()).0
+    }
+}
+
+impl Keymap {
+    /// The action bound to `key`, if any.
+    #[must_use]
+    pub(crate) fn action_for(&self, key: &KeyEvent) -> Option<KeyAction> {
+        let spec = KeySpec::from_event(key);
+        self.bindings
+            .iter()
+            .find(|(bound, _)| *bound == spec)
+            .map(|&(_, action)| action)
+    }
+}
+
+/// `true` for `keys.<action>` with a known action.
+#[must_use]
+pub(crate) fn is_keys_key(key: &str) -> bool {
+    key.strip_prefix(KEYS_PREFIX)
+        .is_some_and(|name| KeyAction::from_name(name).is_some())
+}
+
+/// Validate one `keys.<action>` value in isolation (used by the
+/// `--testconf` field table).
+#[must_use]
+pub(crate) fn validate_keys_value(key: &str, value: &ConfigValue) -> Option<String> {
+    let name = key.strip_prefix(KEYS_PREFIX).unwrap_or(key);
+    let Some(action) = KeyAction::from_name(name) else {
+        return Some(format!("unknown [keys] action '{name}'"));
+    };
+    parse_binding(action, value).err()
+}
+
+/// Validate one `keys.<action>` value plus the conflicts it takes part in,
+/// against the rest of the section in `cfg`.
+#[must_use]
+pub(crate) fn validate_keys_with_cfg(
+    key: &str,
+    value: &ConfigValue,
+    cfg: &ConfigMap,
+) -> Option<String> {
+    if let Some(msg) = validate_keys_value(key, value) {
+        return Some(msg);
+    }
+    keymap_from_config(cfg)
+        .1
+        .into_iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, msg)| msg)
+}
+
+/// Build the keymap from a parsed config map. Absent actions keep their
+/// default key; an invalid value keeps the default and a conflicting key
+/// is dropped from the later action. Problems come back as
+/// `(key, message)`.
+pub(crate) fn keymap_from_config(cfg: &ConfigMap) -> (Keymap, Vec<(String, String)>) {
+    let mut errors = Vec::new();
+    let mut explicit: Vec<(KeyAction, Vec<KeySpec>)> = Vec::new();
+    for action in KeyAction::ALL {
+        let key = action.config_key();
+        let Some(raw) = cfg.get(&key) else {
+            continue;
+        };
+        match parse_binding(action, raw) {
+            Ok(keys) => explicit.push((action, keys)),
+            Err(msg) => errors.push((key, format!("{msg}; keeping the default"))),
+        }
+    }
+    let is_explicit = |action: KeyAction| explicit.iter().any(|(a, _)| *a == action);
+
+    let mut bindings: Vec<(KeySpec, KeyAction)> = Vec::new();
+    for (action, keys) in &explicit {
+        for spec in keys {
+            let owner = bindings
+                .iter()
+                .find(|(bound, _)| bound == spec)
+                .map(|&(_, owner)| owner);
+            if let Some(owner) = owner {
+                errors.push((
+                    action.config_key(),
+                    format!("'{spec}' is already bound to {}", owner.name()),
+                ));
+            } else if !is_explicit(KeyAction::Quit) && KeyAction::Quit.default_key() == Some(*spec)
+            {
+                errors.push((
+                    action.config_key(),
+                    format!("'{spec}' is the only quit key; rebind quit first"),
+                ));
+            } else {
+                bindings.push((*spec, *action));
+            }
+        }
+    }
+    for action in KeyAction::ALL {
+        if is_explicit(action) {
+            continue;
+        }
+        if let Some(spec) = action.default_key() {
+            if !bindings.iter().any(|(bound, _)| *bound == spec) {
+                bindings.push((spec, action));
+            }
+        }
+    }
+    (Keymap { bindings }, errors)
+}
+
+#[cfg(test)]
+mod tests;
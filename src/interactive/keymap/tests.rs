@@ -0,0 +1,210 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! `[keys]` parsing, matching and conflict tests.
+
+use super::*;
+use crate::config_schema::value_from_text;
+
+fn cfg(pairs: &[(&str, &str)]) -> ConfigMap {
+    pairs
+        .iter()
+        .map(|(k, v)| ((*k).to_string(), value_from_text(k, v)))
+        .collect()
+}
+
+fn event(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+    KeyEvent::new(code, modifiers)
+}
+
+fn spec(s: &str) -> KeySpec {
+    parse_key_spec(s).unwrap_or_else(|e| panic!("{s}: {e}"))
+}
+
+#[test]
+fn default_map_matches_the_classic_keys() {
+    let map = Keymap::default();
+    let plain = |c| map.action_for(&event(KeyCode::Char(c), KeyModifiers::NONE));
+    assert_eq!(plain('q'), Some(KeyAction::Quit));
+    assert_eq!(plain(' '), Some(KeyAction::Reseed));
+    assert_eq!(plain('C'), Some(KeyAction::CycleColorBack), "CapsLock C");
+    assert_eq!(plain('['), Some(KeyAction::DensityDown));
+    assert_eq!(plain('i'), Some(KeyAction::Hud));
+    assert_eq!(plain('X'), None, "cycle-scene-back is unbound");
+    assert_eq!(
+        map.action_for(&event(KeyCode::Char('S'), KeyModifiers::SHIFT)),
+        Some(KeyAction::CycleCharsetBack)
+    );
+    assert_eq!(
+        map.action_for(&event(KeyCode::Up, KeyModifiers::NONE)),
+        Some(KeyAction::SpeedUp)
+    );
+    for (code, modifiers) in [
+        (KeyCode::Char('q'), KeyModifiers::SHIFT),
+        (KeyCode::Char('i'), KeyModifiers::CONTROL),
+        (
+            KeyCode::Char('C'),
+            KeyModifiers::SUPER | KeyModifiers::SHIFT,
+        ),
+        (KeyCode::Char('c'), KeyModifiers::HYPER),
+        (KeyCode::Up, KeyModifiers::SHIFT),
+        (KeyCode::Tab, KeyModifiers::NONE),
+        (KeyCode::Esc, KeyModifiers::NONE),
+    ] {
+        assert_eq!(
+            map.action_for(&event(code, modifiers)),
+            None,
+            "{code:?} + {modifiers:?}"
+        );
+    }
+}
+
+#[test]
+fn key_specs_parse_and_normalize() {
+    assert_eq!(spec("shift+c"), spec("C"));
+    assert_eq!(spec("Ctrl+Alt+x").to_string(), "ctrl+alt+x");
+    assert_eq!(spec("space").to_string(), "space");
+    assert_eq!(spec("F5").to_string(), "f5");
+    assert_eq!(spec("shift+tab").to_string(), "shift+tab");
+    assert_eq!(spec("+"), spec("plus"));
+    assert_eq!(spec("ctrl++").to_string(), "ctrl+plus");
+    assert_eq!(spec("[").to_string(), "[");
+    assert_eq!(spec("shift+up").to_string(), "shift+up");
+    for bad in ["", "hyper+q", "ctrl+", "f25", "pgup", "ctrl+qq"] {
+        assert!(parse_key_spec(bad).is_err(), "{bad:?} must be rejected");
+    }
+    assert!(parse_key_spec("meta+q")
+        .unwrap_err()
+        .contains("unknown modifier 'meta'"));
+}
+
+#[test]
+fn events_match_their_specs() {
+    let (map, errors) = keymap_from_config(&cfg(&[
+        ("keys.quit", "[\"q\", \"ctrl+c\"]"),
+        ("keys.hud", "f2"),
+        ("keys.cycle-scene-back", "shift+x"),
+        ("keys.density-up", "alt+up, +"),
+    ]));
+    assert!(errors.is_empty(), "{errors:?}");
+    let hit = |code, modifiers| map.action_for(&event(code, modifiers));
+    assert_eq!(
+        hit(KeyCode::Char('c'), KeyModifiers::CONTROL),
+        Some(KeyAction::Quit)
+    );
+    assert_eq!(
+        hit(KeyCode::Char('q'), KeyModifiers::NONE),
+        Some(KeyAction::Quit)
+    );
+    assert_eq!(hit(KeyCode::F(2), KeyModifiers::NONE), Some(KeyAction::Hud));
+    assert_eq!(
+        hit(KeyCode::Char('i'), KeyModifiers::NONE),
+        None,
+        "replaced"
+    );
+    assert_eq!(
+        hit(KeyCode::Char('X'), KeyModifiers::SHIFT),
+        Some(KeyAction::CycleSceneBack)
+    );
+    assert_eq!(
+        hit(KeyCode::Up, KeyModifiers::ALT),
+        Some(KeyAction::DensityUp)
+    );
+    assert_eq!(
+        hit(KeyCode::Char('+'), KeyModifiers::NONE),
+        Some(KeyAction::DensityUp)
+    );
+    assert_eq!(hit(KeyCode::Char(']'), KeyModifiers::NONE), None);
+    assert_eq!(
+        hit(KeyCode::Up, KeyModifiers::NONE),
+        Some(KeyAction::SpeedUp)
+    );
+}
+
+#[test]
+fn explicit_keys_take_defaults_and_conflicts_are_reported() {
+    // `space` moves from reseed to pause; reseed is left unbound.
+    let (map, errors) = keymap_from_config(&cfg(&[("keys.pause", "space")]));
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(
+        map.action_for(&event(KeyCode::Char(' '), KeyModifiers::NONE)),
+        Some(KeyAction::Pause)
+    );
+
+    let pairs = [("keys.pause", "k"), ("keys.hud", "k")];
+    let (map, errors) = keymap_from_config(&cfg(&pairs));
+    assert_eq!(
+        errors,
+        vec![(
+            "keys.hud".to_string(),
+            "'k' is already bound to pause".to_string()
+        )]
+    );
+    assert_eq!(
+        map.action_for(&event(KeyCode::Char('k'), KeyModifiers::NONE)),
+        Some(KeyAction::Pause)
+    );
+    assert!(validate_keys_with_cfg("keys.hud", &"k".into(), &cfg(&pairs)).is_some());
+    assert!(validate_keys_with_cfg("keys.pause", &"k".into(), &cfg(&pairs)).is_none());
+}
+
+#[test]
+fn quit_always_keeps_a_key() {
+    let (map, errors) = keymap_from_config(&cfg(&[("keys.hud", "q")]));
+    assert!(errors[0].1.contains("only quit key"), "{errors:?}");
+    assert_eq!(
+        map.action_for(&event(KeyCode::Char('q'), KeyModifiers::NONE)),
+        Some(KeyAction::Quit)
+    );
+
+    let (map, errors) = keymap_from_config(&cfg(&[("keys.quit", "[]")]));
+    assert!(errors[0].1.contains("at least one key"), "{errors:?}");
+    assert_eq!(map, Keymap::default());
+
+    // Moving quit first frees `q`.
+    let (map, errors) = keymap_from_config(&cfg(&[("keys.quit", "ctrl+q"), ("keys.hud", "q")]));
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(
+        map.action_for(&event(KeyCode::Char('q'), KeyModifiers::NONE)),
+        Some(KeyAction::Hud)
+    );
+}
+
+#[test]
+fn invalid_values_keep_the_default() {
+    let (map, errors) = keymap_from_config(&cfg(&[("keys.pause", "ctrl+nope")]));
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].1.ends_with("keeping the default"));
+    assert_eq!(
+        map.action_for(&event(KeyCode::Char('p'), KeyModifiers::NONE)),
+        Some(KeyAction::Pause)
+    );
+    assert!(
+        validate_keys_value("keys.pause", &value_from_text("keys.pause", "ctrl+nope")).is_some()
+    );
+    assert!(validate_keys_value(
+        "keys.pause",
+        &value_from_text("keys.pause", "[\"p\", \"comma\"]")
+    )
+    .is_none());
+}
+
+#[test]
+fn every_action_is_a_key_and_documented() {
+    let help = include_str!("../../cli/help_detail.rs");
+    let template = crate::configfile::dump_config_text();
+    for action in KeyAction::ALL {
+        assert!(is_keys_key(&action.config_key()));
+        assert!(
+            help.contains(action.name()),
+            "--help lists {}",
+            action.name()
+        );
+        assert!(
+            template.contains(&format!("# {} = ", action.name())),
+            "dump template lists {}",
+            action.name()
+        );
+    }
+    assert!(!is_keys_key("keys.jump"));
+}
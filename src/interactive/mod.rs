@@ -40,6 +40,7 @@
 mod activity;
 mod adaptive;
 mod bg_fill;
+mod control_socket;
 mod event_loop;
 mod event_loop_finalize;
 mod hud;
@@ -47,7 +48,9 @@ mod input;
 mod intro;
 pub(crate) mod intro_cosmic;
 mod intro_logo;
+pub(crate) mod keymap;
 mod signal_handlers;
+pub(crate) mod term_setup;
 mod watchdog;
 
 #[cfg(test)]
@@ -0,0 +1,51 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Per-terminal setup shared by startup, live reload and SIGCONT re-init
+//! (and the headless `--render-format stream` terminal).
+//!
+//! A `Terminal` carries state that comes from the config rather than from
+//! the tty: the `[power-dragon]` xterm.js budgets, the light-background
+//! canvas, the 256/16-color quantizer and the color cache built through
+//! both. Every `Terminal` the loop draws with must get them — including
+//! the fresh one built after Ctrl-Z/`fg`, which otherwise starts from the
+//! defaults: raw truecolor SGR on a 256-color terminal, dark-canvas rain
+//! on a light one.
+
+use std::io::Result;
+
+use crate::chroma_dragon_engine::canvas::LightCanvas;
+use crate::chroma_dragon_engine::quantize::ColorQuantizer;
+use crate::color_cache::ColorCache;
+use crate::palette::Palette;
+use crate::terminal::Terminal;
+use crate::CloudConfig;
+
+/// Install the config-derived state on `term`, with the color cache
+/// built for `palette`. The canvas and quantizer go in before the cache
+/// so the cache is built from the colors `draw` really emits.
+pub(crate) fn configure_terminal(term: &mut Terminal, cfg: &CloudConfig, palette: &Palette) {
+    term.set_power_thresholds(cfg.power_thresholds);
+    term.set_light_canvas(cfg.terminal_bg.and_then(LightCanvas::for_background));
+    term.set_color_quantizer(ColorQuantizer::new(cfg.color_mode, cfg.color_dither));
+    term.set_color_cache(ColorCache::new(palette));
+}
+
+/// Replace `term` after SIGCONT: drop it first (restoring the tty), open
+/// a new one with `open`, and carry over the recorder and the
+/// config-derived state.
+pub(super) fn reinit_terminal(
+    mut term: Terminal,
+    cfg: &CloudConfig,
+    palette: &Palette,
+    open: impl FnOnce() -> Result<Terminal>,
+) -> Result<Terminal> {
+    let recorder = term.take_recorder();
+    drop(term);
+    let mut term = open()?;
+    if let Some(rec) = recorder {
+        term.set_recorder(rec);
+    }
+    configure_terminal(&mut term, cfg, palette);
+    Ok(term)
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
 #[cfg(test)]
-mod cases {
+pub(super) mod cases {
     use std::time::{Duration, Instant};
 
     use crate::platform::{default_term_reinit, TermReinit};
@@ -193,7 +193,7 @@ mod cases {
     // These tests verify that Tab and BackTab are safely ignored and do not
     // cause ghost background artifacts, state mutations, or visual flicker.
 
-    fn make_test_cloud() -> Cloud {
+    pub(in crate::interactive) fn make_test_cloud() -> Cloud {
         let mut cloud = Cloud::new(
             crate::runtime::ColorMode::Mono,
             crate::runtime::ShadingMode::Random,
@@ -213,13 +213,15 @@ mod cases {
         cloud
     }
 
-    fn make_test_config() -> CloudConfig {
+    pub(in crate::interactive) fn make_test_config() -> CloudConfig {
         CloudConfig {
             color_mode: crate::runtime::ColorMode::Mono,
+            color_dither: crate::config::ColorDither::Off,
             shading_mode: crate::runtime::ShadingMode::Random,
             bold_mode: crate::runtime::BoldMode::Off,
             async_mode: false,
             default_bg: true,
+            terminal_bg: None,
             color_scheme: crate::runtime::ColorScheme::Green,
             custom_palette: None,
             custom_palette_name: None,
@@ -252,8 +254,18 @@ mod cases {
             bench_io: false,
             bench_all: false,
             bench_scene: None,
+            record: None,
+            control_socket: None,
+            seed: None,
             screen_size: None,
             color_tune: crate::color_tune::ColorTune::IDENTITY,
+            rain_tuning: crate::constants::RainTuning::DEFAULT,
+            event_tuning: crate::constants::EventTuning::default(),
+            power_thresholds: crate::constants::PowerThresholds::defaults(),
+            keymap: crate::interactive::keymap::Keymap::default(),
+            crystal_dragon_calc: Default::default(),
+            crystal_dragon_sensors: Default::default(),
+            crystal_dragon_external: None,
             json: false,
             verbose: false,
             density_auto: false,
@@ -270,6 +282,8 @@ mod cases {
             power_dragon: true,
             msg_mode: true,
             monolith_density_map: None,
+            density_mask: None,
+            density_mask_floor: crate::scene_custom::density_mask::DENSITY_MASK_DEFAULT_FLOOR,
             config_path_for_watcher: None,
             scene_name: "monolith".to_string(),
             scene_custom_name: None,
@@ -801,6 +815,127 @@ mod cases {
             "'c' key must clear ambient_palette_locked"
         );
     }
+
+    fn sink_terminal(
+        sink: &crate::terminal::sink::MemorySink,
+    ) -> std::io::Result<crate::terminal::Terminal> {
+        let caps = crate::termdetect::TerminalCaps {
+            sync_output: false,
+            kitty_keyboard: false,
+            has_alternate_screen: true,
+            xtermjs_host: false,
+            vscode_integrated: false,
+            default_fps_cap: 240.0,
+            dynamic_default_fps: 60.0,
+            dynamic_fps_source: "test",
+            repeat_char: true,
+        };
+        crate::terminal::Terminal::with_sink(Box::new(sink.clone()), (20, 10), caps)
+    }
+
+    /// Ctrl-Z/`fg` builds a fresh `Terminal`; the `[power-dragon]`
+    /// budgets must come along instead of reverting to the defaults.
+    #[test]
+    fn sigcont_reinit_keeps_power_thresholds() {
+        use crate::interactive::term_setup::{configure_terminal, reinit_terminal};
+
+        let mut cfg = make_test_config();
+        cfg.power_thresholds.xtermjs_window_frames = 77;
+        cfg.power_thresholds.xtermjs_byte_budget = 1 << 20;
+        let palette = make_test_cloud().palette;
+        let mem = crate::terminal::sink::MemorySink::new();
+        let mut term = sink_terminal(&mem).unwrap();
+        configure_terminal(&mut term, &cfg, &palette);
+        assert_eq!(term.power_thresholds(), cfg.power_thresholds);
+
+        let term = reinit_terminal(term, &cfg, &palette, || sink_terminal(&mem)).unwrap();
+        assert_eq!(term.power_thresholds(), cfg.power_thresholds);
+        assert_ne!(
+            term.power_thresholds(),
+            crate::constants::PowerThresholds::defaults()
+        );
+    }
+
+    /// After Ctrl-Z/`fg` a 256-color terminal still gets quantized
+    /// output, and the rebuilt color cache is hit.
+    #[test]
+    fn sigcont_reinit_keeps_color_quantizer() {
+        use crate::interactive::term_setup::{configure_terminal, reinit_terminal};
+
+        let mut cfg = make_test_config();
+        cfg.color_mode = crate::runtime::ColorMode::Color256;
+        let palette = crate::palette::build_palette(
+            crate::runtime::ColorScheme::Green,
+            crate::runtime::ColorMode::TrueColor,
+            false,
+        );
+        let mem = crate::terminal::sink::MemorySink::new();
+        let mut term = sink_terminal(&mem).unwrap();
+        configure_terminal(&mut term, &cfg, &palette);
+        let mut term = reinit_terminal(term, &cfg, &palette, || sink_terminal(&mem)).unwrap();
+        mem.take();
+
+        let mut frame = Frame::new(20, 10, palette.bg);
+        for (x, &fg) in palette.colors.iter().enumerate().take(20) {
+            frame.set(
+                x as u16,
+                3,
+                crate::cell::Cell {
+                    ch: 'z',
+                    fg: Some(fg),
+                    bg: palette.bg,
+                    bold: false,
+                },
+            );
+        }
+        term.draw(&mut frame).unwrap();
+        let text = String::from_utf8(mem.take()).unwrap();
+        assert!(text.contains("38;5;"), "nothing quantized was drawn");
+        assert!(!text.contains("38;2;"), "truecolor SGR after re-init");
+        let (_, _, hits, _) = term.encoding_stats();
+        assert!(hits > 0, "color cache not rebuilt after re-init");
+    }
+
+    /// After Ctrl-Z/`fg` a light terminal still gets the light-canvas
+    /// mapping: a white-hot head is not drawn white.
+    #[test]
+    fn sigcont_reinit_keeps_light_canvas() {
+        use crate::interactive::term_setup::{configure_terminal, reinit_terminal};
+
+        let mut cfg = make_test_config();
+        cfg.color_mode = crate::runtime::ColorMode::TrueColor;
+        cfg.terminal_bg = Some((253, 246, 227));
+        let palette = crate::palette::build_palette(
+            crate::runtime::ColorScheme::Green,
+            crate::runtime::ColorMode::TrueColor,
+            true,
+        );
+        let mem = crate::terminal::sink::MemorySink::new();
+        let mut term = sink_terminal(&mem).unwrap();
+        configure_terminal(&mut term, &cfg, &palette);
+        let mut term = reinit_terminal(term, &cfg, &palette, || sink_terminal(&mem)).unwrap();
+        mem.take();
+
+        let mut frame = Frame::new(20, 10, None);
+        let head = crate::cell::Cell {
+            ch: 'z',
+            fg: Some(crossterm::style::Color::Rgb {
+                r: 255,
+                g: 255,
+                b: 255,
+            }),
+            bg: None,
+            bold: false,
+        };
+        frame.set(4, 4, head);
+        term.draw(&mut frame).unwrap();
+        let text = String::from_utf8(mem.take()).unwrap();
+        assert!(text.contains("38;2;"), "head not drawn");
+        assert!(
+            !text.contains("38;2;255;255;255"),
+            "light canvas lost after re-init"
+        );
+    }
 }
 
 // v50 LTS regression tests (first-reload scene reset crash). Extracted to
@@ -25,8 +25,7 @@ mod cases_v35 {
     use crate::frame::Frame;
 
     use crate::interactive::input::{
-        handle_keybinding, is_unmodified, is_unmodified_or_shift, should_auto_snapback,
-        KeybindingCtx,
+        handle_keybinding, is_unmodified_or_shift, should_auto_snapback, KeybindingCtx,
     };
     use crate::platform::{default_term_reinit, TermReinit};
     use crate::CloudConfig;
@@ -61,10 +60,12 @@ mod cases_v35 {
     fn make_test_config() -> CloudConfig {
         CloudConfig {
             color_mode: crate::runtime::ColorMode::Mono,
+            color_dither: crate::config::ColorDither::Off,
             shading_mode: crate::runtime::ShadingMode::Random,
             bold_mode: crate::runtime::BoldMode::Off,
             async_mode: false,
             default_bg: true,
+            terminal_bg: None,
             color_scheme: crate::runtime::ColorScheme::Green,
             custom_palette: None,
             custom_palette_name: None,
@@ -97,8 +98,18 @@ mod cases_v35 {
             bench_io: false,
             bench_all: false,
             bench_scene: None,
+            record: None,
+            control_socket: None,
+            seed: None,
             screen_size: None,
             color_tune: crate::color_tune::ColorTune::IDENTITY,
+            rain_tuning: crate::constants::RainTuning::DEFAULT,
+            event_tuning: crate::constants::EventTuning::default(),
+            power_thresholds: crate::constants::PowerThresholds::defaults(),
+            keymap: crate::interactive::keymap::Keymap::default(),
+            crystal_dragon_calc: Default::default(),
+            crystal_dragon_sensors: Default::default(),
+            crystal_dragon_external: None,
             json: false,
             verbose: false,
             density_auto: false,
@@ -115,6 +126,8 @@ mod cases_v35 {
             power_dragon: true,
             msg_mode: true,
             monolith_density_map: None,
+            density_mask: None,
+            density_mask_floor: crate::scene_custom::density_mask::DENSITY_MASK_DEFAULT_FLOOR,
             config_path_for_watcher: None,
             scene_name: "monolith".to_string(),
             scene_custom_name: None,
@@ -833,19 +846,38 @@ mod cases_v35 {
     }
 
     #[test]
-    fn is_unmodified_accepts_only_none() {
-        assert!(is_unmodified(KeyModifiers::NONE));
-        for m in [
-            KeyModifiers::SHIFT,
-            KeyModifiers::CONTROL,
-            KeyModifiers::ALT,
-            KeyModifiers::SUPER,
-            KeyModifiers::HYPER,
-            KeyModifiers::META,
-            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
-        ] {
-            assert!(!is_unmodified(m));
-        }
+    fn rebound_keys_follow_the_config_keymap() {
+        // `[keys] cycle-color = "ctrl+k"` moves the forward color cycle off
+        // bare 'c'; the modifier guard is the binding's own modifier set.
+        let mut cloud = make_test_cloud();
+        let mut frame = Frame::new(cloud.cols, cloud.lines, cloud.palette.bg);
+        let mut charset_preset = String::from("binary");
+        let mut cfg = make_test_config();
+        let keys =
+            std::collections::HashMap::from([("keys.cycle-color".to_string(), "ctrl+k".into())]);
+        cfg.keymap = crate::interactive::keymap::keymap_from_config(&keys).0;
+        let tri = default_term_reinit();
+        let before = color_scheme_of(&cloud);
+
+        call_handle_keybinding(
+            &mut cloud,
+            &mut frame,
+            &key('c'),
+            &mut charset_preset,
+            &cfg,
+            &tri,
+        );
+        assert_eq!(color_scheme_of(&cloud), before, "'c' is no longer bound");
+
+        call_handle_keybinding(
+            &mut cloud,
+            &mut frame,
+            &key_with_mod('k', KeyModifiers::CONTROL),
+            &mut charset_preset,
+            &cfg,
+            &tri,
+        );
+        assert_ne!(color_scheme_of(&cloud), before, "Ctrl+K cycles colors");
     }
 
     #[test]
@@ -101,6 +101,9 @@ mod docs_tests;
 // Group: Droplet subsystem
 mod droplet;
 
+// Group: Headless offline renderer (--render-frames)
+mod headless;
+
 // Group: Interactive subsystem (event loop, HUD, intro, etc.)
 mod interactive;
 
@@ -122,7 +125,7 @@ pub(crate) use scene::{charset, charset_custom};
 // Group: Scene custom subsystem
 mod scene_custom;
 
-// Group: Sysstat subsystem (cpustat, memstat, usagestat, envstat)
+// Group: Sysstat subsystem (cpustat, memstat, usagestat, envstat, hoststat)
 mod sysstat;
 pub(crate) use sysstat::*;
 
@@ -702,7 +705,18 @@ fn main() -> std::io::Result<()> {
     }
     canonicalize_runtime_args(&mut args);
 
+    // --probe-terminal: ask the terminal before anything reads TerminalCaps
+    // or the color mode, so its answers replace the TERM-based guesses
+    // (and show up in --doctor).
+    let probe_terminal = args.probe_terminal == Some(true);
+
     if args.doctor {
+        if probe_terminal {
+            crate::termdetect::run_probe();
+        }
+        if matches!(args.color_bg, ColorBg::DefaultBackground) {
+            crate::termdetect::query_background();
+        }
         doctor::print_doctor_report(&args);
         return Ok(());
     }
@@ -729,6 +743,10 @@ fn main() -> std::io::Result<()> {
 
     // v17: --info/-i REMOVED. Merged into --doctor. Use --doctor for all diagnostics.
 
+    if probe_terminal {
+        crate::termdetect::run_probe();
+    }
+
     // --- Validate all arguments using Result-based validators ---
     let def_ascii = default_to_ascii();
     let color_mode = detect_color_mode(&args);
@@ -960,6 +978,15 @@ fn main() -> std::io::Result<()> {
     ));
 
     let default_bg = matches!(args.color_bg, ColorBg::DefaultBackground);
+    // default-background: ask the terminal what that background is, so a
+    // light one gets the light-canvas mapping. Interactive runs only —
+    // benchmark and headless output never reach a terminal.
+    let terminal_bg = (default_bg
+        && !bench_mode
+        && args.render_frames.is_none()
+        && std::io::stdout().is_terminal())
+    .then(crate::termdetect::query_background)
+    .flatten();
 
     // v50-beta.3: --async-mode CLI flag replaces --uniform.
     // Default: true (async variable pacing on). --async-mode false = uniform.
@@ -1069,8 +1096,10 @@ fn main() -> std::io::Result<()> {
             rain_style,
             color_scheme,
             color_mode,
+            color_dither: args.color_dither,
             color_tune,
             color_bg: args.color_bg,
+            terminal_bg,
             custom_palette_bg: custom_palette.as_ref().and_then(|p| p.bg),
             charset_preset: &charset_preset,
             chars: &chars,
@@ -1129,22 +1158,31 @@ fn main() -> std::io::Result<()> {
     }
     // v14 Peak Monolith: resolve per-column density map from the active
     // scene-custom block (if any). The map sculpts monolith pillar formation.
-    let monolith_density_map = args.scene_custom.as_deref().and_then(|name| {
+    // The block's 2D density mask (and its floor) is resolved alongside.
+    let active_custom_scene = args.scene_custom.as_deref().and_then(|name| {
         let cfg = configfile::load_config_file(args.config.as_deref());
-        let scenes = scene_custom::collect_custom_scenes(&cfg);
-        scenes
-            .get(name)
-            .and_then(|s| s.density_map.as_deref())
-            .and_then(scene_custom::parse_density_map)
+        scene_custom::collect_custom_scenes(&cfg).remove(name)
     });
+    let monolith_density_map = active_custom_scene
+        .as_ref()
+        .and_then(|s| s.density_map.as_deref())
+        .and_then(scene_custom::parse_density_map);
+    let (density_mask, density_mask_floor) =
+        match (args.scene_custom.as_deref(), &active_custom_scene) {
+            (Some(name), Some(scene)) => scene_custom::load_density_mask(name, scene),
+            _ => (None, scene_custom::density_mask::DENSITY_MASK_DEFAULT_FLOOR),
+        };
+    let density_mask = density_mask.map(std::sync::Arc::new);
 
     // CliExplicit is Copy — field copy after CloudConfig move (avoids E0382).
     let cloud_cfg = CloudConfig {
         color_mode,
+        color_dither: args.color_dither,
         shading_mode,
         bold_mode,
         async_mode: effective_async,
         default_bg,
+        terminal_bg: terminal_bg.map(|bg| bg.rgb),
         color_scheme,
         custom_palette,
         custom_palette_name,
@@ -1217,12 +1255,22 @@ fn main() -> std::io::Result<()> {
         bench_duration: crate::bench_helpers::resolve_bench_duration_args(&args.bench_duration),
         screen_size,
         color_tune,
+        rain_tuning: args.rain_tuning,
+        event_tuning: args.event_tuning.clone(),
+        power_thresholds: args.power_thresholds,
+        keymap: args.keymap.clone(),
+        crystal_dragon_calc: args.crystal_dragon_calc,
+        crystal_dragon_sensors: args.crystal_dragon_sensors,
+        crystal_dragon_external: args.crystal_dragon_external.clone(),
         json: args.json,
         save_baseline: args.save_baseline.clone(),
         compare_baseline: args.compare_baseline.clone(),
         bench_io: args.bench_io,
         bench_all: args.bench_all,
         bench_scene: args.bench_scene.clone(),
+        record: args.record.clone(),
+        control_socket: args.control_socket.clone(),
+        seed: args.seed,
         verbose: args.verbose,
         density_auto,
         base_density,
@@ -1238,6 +1286,8 @@ fn main() -> std::io::Result<()> {
         power_dragon: args.power_dragon.unwrap_or(true),
         msg_mode: args.msg_mode.unwrap_or(true),
         monolith_density_map,
+        density_mask,
+        density_mask_floor,
         config_path_for_watcher: {
             // Termux fix: multi-candidate path resolution so the
             // watcher watches the file the user is ACTUALLY editing. On
@@ -1303,6 +1353,15 @@ fn main() -> std::io::Result<()> {
         return bench::run_benchmark(&cloud_cfg);
     }
 
+    if let (Some(frames), Some(out)) = (args.render_frames, args.out.clone()) {
+        let job = headless::RenderJob {
+            frames,
+            out,
+            format: args.render_format,
+        };
+        return headless::run_render(&cloud_cfg, &job);
+    }
+
     let result = interactive::run_interactive(&cloud_cfg);
 
     // v16 audit: Explicitly print run_interactive errors to stderr (after
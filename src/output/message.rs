@@ -21,6 +21,20 @@ use unicode_width::UnicodeWidthChar;
 /// A stderr warning is emitted if any chars were replaced or stripped, so
 /// the user knows their input was modified.
 pub(crate) fn sanitize_message_text(input: &str) -> String {
+    let (out, skipped_wide, skipped_ctrl) = sanitize_message_text_quiet(input);
+    if skipped_wide > 0 || skipped_ctrl > 0 {
+        crate::output::eprintln_warn_labeled(&format!(
+            "--message contained {} wide/zero-width char(s) (replaced with '?') and {} control char(s) (removed). Wide chars (CJK, emoji) break cell alignment — see Bug #11.",
+            skipped_wide, skipped_ctrl
+        ));
+    }
+    out
+}
+
+/// [`sanitize_message_text`] without the stderr warning, for callers that
+/// run inside the alt screen (`--control-socket`). Returns the text plus
+/// the replaced-wide and removed-control counts.
+pub(crate) fn sanitize_message_text_quiet(input: &str) -> (String, u32, u32) {
     let mut out = String::with_capacity(input.len());
     let mut skipped_wide = 0u32;
     let mut skipped_ctrl = 0u32;
@@ -58,13 +72,7 @@ pub(crate) fn sanitize_message_text(input: &str) -> String {
             }
         }
     }
-    if skipped_wide > 0 || skipped_ctrl > 0 {
-        crate::output::eprintln_warn_labeled(&format!(
-            "--message contained {} wide/zero-width char(s) (replaced with '?') and {} control char(s) (removed). Wide chars (CJK, emoji) break cell alignment — see Bug #11.",
-            skipped_wide, skipped_ctrl
-        ));
-    }
-    out
+    (out, skipped_wide, skipped_ctrl)
 }
 
 #[cfg(test)]
@@ -31,8 +31,11 @@ pub(crate) struct VerboseCtx<'a> {
     pub rain_style: RainStyle,
     pub color_scheme: crate::runtime::ColorScheme,
     pub color_mode: ColorMode,
+    pub color_dither: crate::config::ColorDither,
     pub color_tune: ColorTune,
     pub color_bg: ColorBg,
+    /// Background reported by the terminal (default-background only).
+    pub terminal_bg: Option<crate::termdetect::TerminalBackground>,
     pub custom_palette_bg: Option<Color>,
     pub charset_preset: &'a str,
     pub chars: &'a [char],
@@ -125,8 +128,10 @@ pub(crate) fn print_verbose(ctx: &VerboseCtx) {
         rain_style,
         color_scheme,
         color_mode,
+        color_dither,
         color_tune,
         color_bg,
+        terminal_bg,
         custom_palette_bg,
         charset_preset,
         chars,
@@ -210,6 +215,20 @@ pub(crate) fn print_verbose(ctx: &VerboseCtx) {
                 " oklab_gradient, perceptual_blend, climate_post_fx, head_halo(factor={halo_factor:.2}), l_smoothing, subpixel_jitter(amplitude={jitter_amp})"
             ),
         );
+        if color_mode.is_quantized() {
+            let target = if *color_mode == ColorMode::Color256 {
+                "xterm-256"
+            } else {
+                "ansi-16"
+            };
+            output::eprintln_verbose(
+                "  color_quantize:",
+                &format!(
+                    " oklab nearest -> {target}, dither={}",
+                    color_dither.as_str()
+                ),
+            );
+        }
     } else if let Some(reason) = pipeline.disable_reason(*color_mode) {
         output::eprintln_verbose(
             "  chroma_features:",
@@ -230,6 +249,13 @@ pub(crate) fn print_verbose(ctx: &VerboseCtx) {
     );
     let bg_label = describe_color_bg(*color_bg, *custom_palette_name, *custom_palette_bg);
     output::eprintln_verbose("color_bg:", &format!(" {bg_label}"));
+    if *color_bg == ColorBg::DefaultBackground {
+        let canvas = terminal_bg.map_or_else(
+            || "dark assumed (terminal did not report its background)".to_string(),
+            |bg| bg.describe(),
+        );
+        output::eprintln_verbose("canvas:", &format!(" {canvas}"));
+    }
 
     // ── Glyphs ────────────────────────────────────────────────────
     eprintln!("{}", output::brand_bold("  ── Glyphs ──"));
@@ -362,12 +388,38 @@ pub(crate) fn print_verbose(ctx: &VerboseCtx) {
     } else {
         let summary: Vec<String> = entries
             .iter()
-            .map(|e| format!("{:02}-{:02}→{}", e.hour, e.minute, e.scene))
+            .map(|e| format!("{}→{}", e.label(), e.scene))
             .collect();
         output::eprintln_verbose(
             "schedule:",
             &format!(" {} entries [{}]", entries.len(), summary.join(", ")),
         );
+        if !ambient_schedule.transition.is_zero() {
+            output::eprintln_verbose(
+                "transition:",
+                &format!(
+                    " {}s crossfade between phases",
+                    ambient_schedule.transition.as_secs()
+                ),
+            );
+        }
+        if let Some(location) = ambient_schedule.location {
+            let today = crate::crystal_dragon_engine::ambient::current_local_date();
+            let solar: Vec<String> = entries
+                .iter()
+                .filter(|e| e.solar.is_some())
+                .map(|e| match ambient_schedule.entry_minute(e, today) {
+                    Some(m) => format!("{}={:02}:{:02}", e.label(), m / 60, m % 60),
+                    None => format!("{}=(not today)", e.label()),
+                })
+                .collect();
+            let today_times = if solar.is_empty() {
+                "no solar rules".to_string()
+            } else {
+                format!("today: {}", solar.join(", "))
+            };
+            output::eprintln_verbose("location:", &format!(" {location} ({today_times})"));
+        }
         let idle_secs = crate::central_control_dragon_power::IDLE_THRESHOLD_SECS;
         let snapback_secs = crate::central_control_dragon_power::AUTO_SNAPBACK_DELAY_SECS;
         output::eprintln_verbose(
@@ -466,6 +466,13 @@ fn expand_tilde(path: &str) -> PathBuf {
     PathBuf::from(path)
 }
 
+/// Expand `%VAR%` (Windows) and a leading `~` in a user-written path, the
+/// same way [`is_safe_path`] does before checking it. Used for `include`
+/// targets in config files, which are read after the check.
+pub(crate) fn expand_user_path(path: &str) -> PathBuf {
+    expand_tilde(&expand_windows_env_vars(path))
+}
+
 /// Validate a `--config <path>` argument: must be inside the strict
 /// whitelist AND have a `.toml` extension. Returns `Ok(resolved_path)` if
 /// valid (with Windows `%VAR%` env vars expanded), or
@@ -48,7 +48,9 @@
 //! field. The `rebuild_cloud_config` function in `live_config.rs` checks
 //! `charset-custom.<name>` first when applying the `charset` config key.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::BTreeMap;
+
+use crate::config_schema::{get_str, ConfigMap};
 
 use unicode_width::UnicodeWidthChar;
 
@@ -75,9 +77,7 @@ pub(crate) struct CharsetCustomDef {
 ///
 /// Names are normalized to lowercase for case-insensitive matching.
 #[must_use]
-pub(crate) fn collect_charset_custom(
-    cfg: &HashMap<String, String>,
-) -> BTreeMap<String, CharsetCustomDef> {
+pub(crate) fn collect_charset_custom(cfg: &ConfigMap) -> BTreeMap<String, CharsetCustomDef> {
     let mut out: BTreeMap<String, CharsetCustomDef> = BTreeMap::new();
 
     for (key, value) in cfg {
@@ -102,7 +102,7 @@ pub(crate) fn collect_charset_custom(
         // caller's `load_custom_charset` will re-parse + re-validate and
         // surface the actual error.
         let def = out.entry(name).or_default();
-        if let Ok(chars) = parse_charset_value(value) {
+        if let Some(Ok(chars)) = value.as_str().map(parse_charset_value) {
             def.chars = chars;
         }
     }
@@ -191,10 +191,7 @@ pub(crate) fn parse_charset_value(value: &str) -> Result<Vec<char>, String> {
 /// Callers should fall back to `charset::charset_from_str` when this
 /// returns `Err` — a "not found" error for a custom charset is normal
 /// and means the user is asking for a built-in preset.
-pub(crate) fn load_custom_charset(
-    cfg: &HashMap<String, String>,
-    name: &str,
-) -> Result<Vec<char>, String> {
+pub(crate) fn load_custom_charset(cfg: &ConfigMap, name: &str) -> Result<Vec<char>, String> {
     let palettes = collect_charset_custom(cfg);
     let normalized = name.trim().to_ascii_lowercase();
     let def = palettes.get(&normalized).ok_or_else(|| {
@@ -212,10 +209,7 @@ pub(crate) fn load_custom_charset(
     if def.chars.is_empty() {
         // The block exists but its `set` value failed to parse during
         // collect. Re-parse now to surface the actual error to the user.
-        let raw = cfg
-            .get(&format!("charset-custom.{normalized}.set"))
-            .map(String::as_str)
-            .unwrap_or("");
+        let raw = get_str(cfg, &format!("charset-custom.{normalized}.set")).unwrap_or("");
         return parse_charset_value(raw);
     }
     Ok(def.chars.clone())
@@ -233,10 +227,7 @@ pub(crate) fn load_custom_charset(
 /// (`testconf::validate_config_strictly`) already reported it at
 /// startup / on live reload. Surfacing it again here would be noise.
 #[must_use]
-pub(crate) fn load_custom_charset_if_matches(
-    cfg: &HashMap<String, String>,
-    name: &str,
-) -> Option<Vec<char>> {
+pub(crate) fn load_custom_charset_if_matches(cfg: &ConfigMap, name: &str) -> Option<Vec<char>> {
     let normalized = name.trim().to_ascii_lowercase();
     if normalized.is_empty() {
         return None;
@@ -330,8 +321,8 @@ mod tests {
 
     #[test]
     fn collect_finds_single_block() {
-        let mut cfg = HashMap::new();
-        cfg.insert("charset-custom.zen.set".to_string(), "|".to_string());
+        let mut cfg = ConfigMap::new();
+        cfg.insert("charset-custom.zen.set".to_string(), "|".into());
         let map = collect_charset_custom(&cfg);
         assert!(map.contains_key("zen"));
         assert_eq!(map["zen"].chars, vec!['|']);
@@ -339,21 +330,21 @@ mod tests {
 
     #[test]
     fn collect_is_case_insensitive_on_name() {
-        let mut cfg = HashMap::new();
-        cfg.insert("charset-custom.MySet.set".to_string(), "ab".to_string());
+        let mut cfg = ConfigMap::new();
+        cfg.insert("charset-custom.MySet.set".to_string(), "ab".into());
         let map = collect_charset_custom(&cfg);
         assert!(map.contains_key("myset"));
     }
 
     #[test]
     fn collect_ignores_unknown_fields() {
-        let mut cfg = HashMap::new();
-        cfg.insert("charset-custom.zen.set".to_string(), "ab".to_string());
+        let mut cfg = ConfigMap::new();
+        cfg.insert("charset-custom.zen.set".to_string(), "ab".into());
         // Unknown field — should be skipped (and is_known_key in
         // configfile.rs would have already rejected it as unknown).
         cfg.insert(
             "charset-custom.zen.unknownfield".to_string(),
-            "ignored".to_string(),
+            "ignored".into(),
         );
         let map = collect_charset_custom(&cfg);
         assert_eq!(map["zen"].chars, vec!['a', 'b']);
@@ -361,9 +352,9 @@ mod tests {
 
     #[test]
     fn collect_handles_multiple_blocks() {
-        let mut cfg = HashMap::new();
-        cfg.insert("charset-custom.a.set".to_string(), "12".to_string());
-        cfg.insert("charset-custom.b.set".to_string(), "xy".to_string());
+        let mut cfg = ConfigMap::new();
+        cfg.insert("charset-custom.a.set".to_string(), "12".into());
+        cfg.insert("charset-custom.b.set".to_string(), "xy".into());
         let map = collect_charset_custom(&cfg);
         assert_eq!(map.len(), 2);
         assert_eq!(map["a"].chars, vec!['1', '2']);
@@ -372,25 +363,25 @@ mod tests {
 
     #[test]
     fn load_custom_charset_found() {
-        let mut cfg = HashMap::new();
-        cfg.insert("charset-custom.zen.set".to_string(), "|".to_string());
+        let mut cfg = ConfigMap::new();
+        cfg.insert("charset-custom.zen.set".to_string(), "|".into());
         let v = load_custom_charset(&cfg, "zen").unwrap();
         assert_eq!(v, vec!['|']);
     }
 
     #[test]
     fn load_custom_charset_case_insensitive() {
-        let mut cfg = HashMap::new();
-        cfg.insert("charset-custom.zen.set".to_string(), "|".to_string());
+        let mut cfg = ConfigMap::new();
+        cfg.insert("charset-custom.zen.set".to_string(), "|".into());
         let v = load_custom_charset(&cfg, "zen").unwrap();
         assert_eq!(v, vec!['|']);
     }
 
     #[test]
     fn load_custom_charset_not_found_lists_available() {
-        let mut cfg = HashMap::new();
-        cfg.insert("charset-custom.alpha.set".to_string(), "ab".to_string());
-        cfg.insert("charset-custom.beta.set".to_string(), "cd".to_string());
+        let mut cfg = ConfigMap::new();
+        cfg.insert("charset-custom.alpha.set".to_string(), "ab".into());
+        cfg.insert("charset-custom.beta.set".to_string(), "cd".into());
         let err = load_custom_charset(&cfg, "gamma").unwrap_err();
         assert!(err.contains("not found"), "got: {err}");
         assert!(err.contains("alpha"), "got: {err}");
@@ -399,8 +390,8 @@ mod tests {
 
     #[test]
     fn load_custom_charset_if_matches_returns_some_when_present() {
-        let mut cfg = HashMap::new();
-        cfg.insert("charset-custom.zen.set".to_string(), "|".to_string());
+        let mut cfg = ConfigMap::new();
+        cfg.insert("charset-custom.zen.set".to_string(), "|".into());
         let v = load_custom_charset_if_matches(&cfg, "zen");
         assert!(v.is_some());
         assert_eq!(v.unwrap(), vec!['|']);
@@ -408,14 +399,14 @@ mod tests {
 
     #[test]
     fn load_custom_charset_if_matches_returns_none_when_absent() {
-        let cfg = HashMap::new();
+        let cfg = ConfigMap::new();
         assert!(load_custom_charset_if_matches(&cfg, "zen").is_none());
     }
 
     #[test]
     fn load_custom_charset_if_matches_returns_none_for_empty_name() {
-        let mut cfg = HashMap::new();
-        cfg.insert("charset-custom..set".to_string(), "|".to_string());
+        let mut cfg = ConfigMap::new();
+        cfg.insert("charset-custom..set".to_string(), "|".into());
         assert!(load_custom_charset_if_matches(&cfg, "").is_none());
     }
 
@@ -0,0 +1,362 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! 2D density masks for `scene-custom.<name>.density-mask`.
+//!
+//! Where `density-map` weights monolith lanes along one axis, a mask
+//! weights every cell: bright mask areas get more rain and full
+//! brightness, dark areas get little (down to `density-mask-floor`), so an
+//! image or word emerges from the rain. Three sources:
+//!
+//! | Value | Source |
+//! |-------|--------|
+//! | `"text:ACME"` | built-in 5×7 font ([`super::mask_font`]); `\n` starts a new line |
+//! | a multi-line string | ASCII art, one character per cell: space and `.` are empty, `0`–`9` are weights 0–1, anything else is full |
+//! | `"~/.config/cosmostrix/logo.pgm"` | PGM (`P2`/`P5`, gray = weight) or PBM (`P1`/`P4`, set bit = full) |
+//!
+//! The mask is fitted to [`DENSITY_MASK_FIT`] of the screen with its aspect
+//! ratio kept and centered; image and text pixels are square, so they take
+//! half as many rows as columns, while ASCII art maps one character to one
+//! cell. [`DensityMask::bake`] resamples it (box filter) to `cols × lines`
+//! on every resize.
+
+use std::fmt;
+
+use super::mask_font;
+
+/// Weight of empty mask areas unless `density-mask-floor` says otherwise:
+/// enough rain that the screen never looks dead around the shape.
+pub(crate) const DENSITY_MASK_DEFAULT_FLOOR: f32 = 0.15;
+/// Share of the screen the fitted mask may use on its limiting axis.
+pub(crate) const DENSITY_MASK_FIT: f32 = 0.9;
+/// Largest accepted image side, in pixels.
+pub(crate) const DENSITY_MASK_MAX_SIDE: usize = 2048;
+/// Largest accepted image area, in pixels (4 MiB of weights).
+pub(crate) const DENSITY_MASK_MAX_PIXELS: usize = 1 << 20;
+/// Prefix selecting the rendered-text source.
+const TEXT_PREFIX: &str = "text:";
+
+/// A grayscale weight grid, row-major, values in `0.0..=1.0`.
+#[derive(Clone, PartialEq)]
+pub(crate) struct DensityMask {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    pub(crate) weights: Vec<f32>,
+    /// `true` for image and text sources (square pixels), `false` for
+    /// ASCII art (one source cell per terminal cell).
+    pub(crate) square_pixels: bool,
+}
+
+impl fmt::Debug for DensityMask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "DensityMask({}x{}, {})",
+            self.width,
+            self.height,
+            if self.square_pixels {
+                "pixels"
+            } else {
+                "cells"
+            }
+        )
+    }
+}
+
+impl DensityMask {
+    /// Parse a `density-mask` value, reading the image file for the path
+    /// form.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` for empty text or art, an unreadable or malformed
+    /// image, or one larger than [`DENSITY_MASK_MAX_SIDE`] /
+    /// [`DENSITY_MASK_MAX_PIXELS`].
+    pub(crate) fn from_config(v: &str) -> Result<Self, String> {
+        if let Some(text) = v.trim_start().strip_prefix(TEXT_PREFIX) {
+            let (width, height, weights) = mask_font::render_text(text);
+            if width == 0 {
+                return Err("text: needs some text to draw".to_string());
+            }
+            return Self::new(width, height, weights, true);
+        }
+        if v.contains('\n') {
+            return Self::from_art(v);
+        }
+        let raw = v.trim();
+        if raw.is_empty() {
+            return Err(
+                "expected text:<TEXT>, a multi-line ASCII-art block or a .pgm/.pbm path"
+                    .to_string(),
+            );
+        }
+        let path = crate::safepath::expand_user_path(raw);
+        if !path.is_absolute() {
+            return Err(format!(
+                "'{raw}' must be an absolute path (or start with ~/)"
+            ));
+        }
+        let bytes = std::fs::read(&path).map_err(|e| format!("cannot read '{raw}': {e}"))?;
+        Self::from_pnm(&bytes).map_err(|e| format!("'{raw}': {e}"))
+    }
+
+    /// ASCII art, one character per cell. Blank lines before and after
+    /// the block and indentation common to all rows are dropped; short
+    /// rows are padded with empty cells.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the block is empty or too large.
+    pub(crate) fn from_art(art: &str) -> Result<Self, String> {
+        let rows: Vec<&str> = art.lines().map(str::trim_end).collect();
+        let first = rows.iter().position(|r| !r.trim().is_empty());
+        let last = rows.iter().rposition(|r| !r.trim().is_empty());
+        let (Some(first), Some(last)) = (first, last) else {
+            return Err("ASCII-art mask is empty".to_string());
+        };
+        let indent = rows[first..=last]
+            .iter()
+            .filter(|r| !r.trim().is_empty())
+            .map(|r| r.len() - r.trim_start().len())
+            .min()
+            .unwrap_or(0);
+        let rows: Vec<&str> = rows[first..=last]
+            .iter()
+            .map(|r| r.get(indent..).unwrap_or(""))
+            .collect();
+        let width = rows.iter().map(|r| r.chars().count()).max().unwrap_or(0);
+        let height = rows.len();
+        let mut weights = vec![0.0; width * height];
+        for (y, row) in rows.iter().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                weights[y * width + x] = match c {
+                    ' ' | '.' => 0.0,
+                    '0'..='9' => f32::from(c as u8 - b'0') / 9.0,
+                    _ => 1.0,
+                };
+            }
+        }
+        Self::new(width, height, weights, false)
+    }
+
+    /// Netpbm bitmap (`P1`, `P4`) or graymap (`P2`, `P5`, 8 or 16 bit).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` for any other format or a truncated / malformed file.
+    pub(crate) fn from_pnm(bytes: &[u8]) -> Result<Self, String> {
+        let mut pos = 0;
+        let magic = pnm_token(bytes, &mut pos).ok_or("empty file")?;
+        let kind = match magic {
+            b"P1" | b"P2" | b"P4" | b"P5" => magic[1],
+            _ => return Err("not a PGM (P2/P5) or PBM (P1/P4) image".to_string()),
+        };
+        let mut header_num = |what: &str| -> Result<usize, String> {
+            pnm_token(bytes, &mut pos)
+                .and_then(|t| std::str::from_utf8(t).ok()?.parse().ok())
+                .ok_or_else(|| format!("bad or missing {what} in header"))
+        };
+        let width = header_num("width")?;
+        let height = header_num("height")?;
+        let max = if matches!(kind, b'2' | b'5') {
+            header_num("maxval")?
+        } else {
+            1
+        };
+        if !(1..=65_535).contains(&max) {
+            return Err(format!("maxval {max} is outside 1..=65535"));
+        }
+        check_size(width, height)?;
+        let n = width * height;
+        let mut weights = Vec::with_capacity(n);
+        match kind {
+            b'1' | b'2' => {
+                for _ in 0..n {
+                    let value = if kind == b'1' {
+                        // Plain PBM digits may be written without spaces.
+                        skip_space(bytes, &mut pos);
+                        let digit = bytes.get(pos).ok_or("pixel data ends early")?;
+                        pos += 1;
+                        match digit {
+                            b'0' => 0,
+                            b'1' => 1,
+                            _ => return Err("bad pixel value".to_string()),
+                        }
+                    } else {
+                        pnm_token(bytes, &mut pos)
+                            .and_then(|t| std::str::from_utf8(t).ok()?.parse::<usize>().ok())
+                            .ok_or("bad or missing pixel value")?
+                    };
+                    weights.push(value.min(max) as f32 / max as f32);
+                }
+            }
+            _ => {
+                // One whitespace byte separates the header from the raster.
+                pos += 1;
+                let raster = bytes.get(pos..).unwrap_or_default();
+                if kind == b'4' {
+                    let stride = width.div_ceil(8);
+                    if raster.len() < stride * height {
+                        return Err("pixel data ends early".to_string());
+                    }
+                    for y in 0..height {
+                        for x in 0..width {
+                            let bit = raster[y * stride + x / 8] & (0x80 >> (x % 8));
+                            weights.push(if bit != 0 { 1.0 } else { 0.0 });
+                        }
+                    }
+                } else {
+                    let wide = max > 255;
+                    let sample_len = if wide { 2 } else { 1 };
+                    if raster.len() < n * sample_len {
+                        return Err("pixel data ends early".to_string());
+                    }
+                    for i in 0..n {
+                        let value = if wide {
+                            usize::from(u16::from_be_bytes([raster[2 * i], raster[2 * i + 1]]))
+                        } else {
+                            usize::from(raster[i])
+                        };
+                        weights.push(value.min(max) as f32 / max as f32);
+                    }
+                }
+            }
+        }
+        Self::new(width, height, weights, true)
+    }
+
+    fn new(
+        width: usize,
+        height: usize,
+        weights: Vec<f32>,
+        square_pixels: bool,
+    ) -> Result<Self, String> {
+        check_size(width, height)?;
+        Ok(Self {
+            width,
+            height,
+            weights,
+            square_pixels,
+        })
+    }
+
+    /// Resample to a `cols × lines` weight grid (`line * cols + col`),
+    /// mapping mask weight `w` to `floor + (1 - floor) * w`.
+    #[must_use]
+    pub(crate) fn bake(&self, cols: u16, lines: u16, floor: f32) -> Vec<f32> {
+        let (cols_f, lines_f) = (f32::from(cols), f32::from(lines));
+        let (w, h) = (self.width as f32, self.height as f32);
+        // Terminal cells are about twice as tall as wide.
+        let row_aspect = if self.square_pixels { 0.5 } else { 1.0 };
+        let scale =
+            (cols_f * DENSITY_MASK_FIT / w).min(lines_f * DENSITY_MASK_FIT / (h * row_aspect));
+        let (sx, sy) = (scale, scale * row_aspect);
+        let (ox, oy) = ((cols_f - w * sx) / 2.0, (lines_f - h * sy) / 2.0);
+        // Sub-samples per axis: enough to cover the source pixels under one
+        // cell when shrinking, one when enlarging.
+        let steps = |cell_per_px: f32| (1.0 / cell_per_px).ceil().clamp(1.0, 4.0) as usize;
+        let (nx, ny) = (steps(sx), steps(sy));
+        let floor = floor.clamp(0.0, 1.0);
+        let mut out = Vec::with_capacity(usize::from(cols) * usize::from(lines));
+        for line in 0..lines {
+            for col in 0..cols {
+                let mut sum = 0.0;
+                for j in 0..ny {
+                    let y = (f32::from(line) + (j as f32 + 0.5) / ny as f32 - oy) / sy;
+                    for i in 0..nx {
+                        let x = (f32::from(col) + (i as f32 + 0.5) / nx as f32 - ox) / sx;
+                        sum += self.at(x, y);
+                    }
+                }
+                let m = sum / (nx * ny) as f32;
+                out.push(floor + (1.0 - floor) * m);
+            }
+        }
+        out
+    }
+
+    /// Weight at source coordinates, `0.0` outside the mask.
+    fn at(&self, x: f32, y: f32) -> f32 {
+        if x < 0.0 || y < 0.0 {
+            return 0.0;
+        }
+        let (x, y) = (x as usize, y as usize);
+        if x >= self.width || y >= self.height {
+            return 0.0;
+        }
+        self.weights[y * self.width + x]
+    }
+}
+
+/// Peak weight of each column of a baked grid: the spawn weight for rain
+/// that falls the full height of the column.
+#[must_use]
+pub(crate) fn column_peaks(baked: &[f32], cols: u16) -> Vec<f32> {
+    let cols = usize::from(cols);
+    let mut peaks = vec![0.0f32; cols];
+    if cols == 0 {
+        return peaks;
+    }
+    for row in baked.chunks_exact(cols) {
+        for (peak, &w) in peaks.iter_mut().zip(row) {
+            *peak = peak.max(w);
+        }
+    }
+    peaks
+}
+
+/// Parse `density-mask-floor`: a weight in `0.0..=1.0`.
+///
+/// # Errors
+///
+/// Returns `Err` when the value is not a number in range.
+pub(crate) fn parse_density_mask_floor(v: &str) -> Result<f32, String> {
+    v.trim()
+        .parse::<f32>()
+        .ok()
+        .filter(|f| (0.0..=1.0).contains(f))
+        .ok_or_else(|| format!("expected a number from 0.0 to 1.0, got '{v}'"))
+}
+
+fn check_size(width: usize, height: usize) -> Result<(), String> {
+    if width == 0 || height == 0 {
+        return Err("mask has no pixels".to_string());
+    }
+    if width > DENSITY_MASK_MAX_SIDE
+        || height > DENSITY_MASK_MAX_SIDE
+        || width * height > DENSITY_MASK_MAX_PIXELS
+    {
+        return Err(format!(
+            "{width}x{height} is too large (max {DENSITY_MASK_MAX_SIDE} per side, \
+             {DENSITY_MASK_MAX_PIXELS} pixels)"
+        ));
+    }
+    Ok(())
+}
+
+fn skip_space(bytes: &[u8], pos: &mut usize) {
+    while let Some(&b) = bytes.get(*pos) {
+        if b == b'#' {
+            while bytes.get(*pos).is_some_and(|&b| b != b'\n') {
+                *pos += 1;
+            }
+        } else if b.is_ascii_whitespace() {
+            *pos += 1;
+        } else {
+            break;
+        }
+    }
+}
+
+/// Next whitespace-separated header token, skipping `#` comments.
+fn pnm_token<'a>(bytes: &'a [u8], pos: &mut usize) -> Option<&'a [u8]> {
+    skip_space(bytes, pos);
+    let start = *pos;
+    while bytes
+        .get(*pos)
+        .is_some_and(|b| !b.is_ascii_whitespace() && *b != b'#')
+    {
+        *pos += 1;
+    }
+    (*pos > start).then(|| &bytes[start..*pos])
+}
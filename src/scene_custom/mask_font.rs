@@ -0,0 +1,304 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! 5×7 bitmap font for `density-mask = "text:<TEXT>"`.
+//!
+//! Uppercase Latin letters, digits and common punctuation; lowercase is
+//! drawn as uppercase and anything else as `?`. Each row is 5 bits, the
+//! leftmost pixel in bit 4.
+
+/// Glyph width in pixels.
+pub(crate) const GLYPH_W: usize = 5;
+/// Glyph height in pixels.
+pub(crate) const GLYPH_H: usize = 7;
+/// Horizontal advance per character (glyph plus one blank column).
+pub(crate) const ADVANCE: usize = GLYPH_W + 1;
+/// Blank pixel rows between text lines.
+pub(crate) const LINE_GAP: usize = 2;
+
+const GLYPHS: &[(char, [u8; GLYPH_H])] = &[
+    (' ', [0, 0, 0, 0, 0, 0, 0]),
+    (
+        'A',
+        [
+            0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001,
+        ],
+    ),
+    (
+        'B',
+        [
+            0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110,
+        ],
+    ),
+    (
+        'C',
+        [
+            0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110,
+        ],
+    ),
+    (
+        'D',
+        [
+            0b11100, 0b10010, 0b10001, 0b10001, 0b10001, 0b10010, 0b11100,
+        ],
+    ),
+    (
+        'E',
+        [
+            0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111,
+        ],
+    ),
+    (
+        'F',
+        [
+            0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000,
+        ],
+    ),
+    (
+        'G',
+        [
+            0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111,
+        ],
+    ),
+    (
+        'H',
+        [
+            0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001,
+        ],
+    ),
+    (
+        'I',
+        [
+            0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110,
+        ],
+    ),
+    (
+        'J',
+        [
+            0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100,
+        ],
+    ),
+    (
+        'K',
+        [
+            0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001,
+        ],
+    ),
+    (
+        'L',
+        [
+            0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111,
+        ],
+    ),
+    (
+        'M',
+        [
+            0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001,
+        ],
+    ),
+    (
+        'N',
+        [
+            0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001,
+        ],
+    ),
+    (
+        'O',
+        [
+            0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110,
+        ],
+    ),
+    (
+        'P',
+        [
+            0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000,
+        ],
+    ),
+    (
+        'Q',
+        [
+            0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101,
+        ],
+    ),
+    (
+        'R',
+        [
+            0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001,
+        ],
+    ),
+    (
+        'S',
+        [
+            0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110,
+        ],
+    ),
+    (
+        'T',
+        [
+            0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100,
+        ],
+    ),
+    (
+        'U',
+        [
+            0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110,
+        ],
+    ),
+    (
+        'V',
+        [
+            0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100,
+        ],
+    ),
+    (
+        'W',
+        [
+            0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010,
+        ],
+    ),
+    (
+        'X',
+        [
+            0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001,
+        ],
+    ),
+    (
+        'Y',
+        [
+            0b10001, 0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100,
+        ],
+    ),
+    (
+        'Z',
+        [
+            0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111,
+        ],
+    ),
+    (
+        '0',
+        [
+            0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110,
+        ],
+    ),
+    (
+        '1',
+        [
+            0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110,
+        ],
+    ),
+    (
+        '2',
+        [
+            0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111,
+        ],
+    ),
+    (
+        '3',
+        [
+            0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110,
+        ],
+    ),
+    (
+        '4',
+        [
+            0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010,
+        ],
+    ),
+    (
+        '5',
+        [
+            0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110,
+        ],
+    ),
+    (
+        '6',
+        [
+            0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110,
+        ],
+    ),
+    (
+        '7',
+        [
+            0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000,
+        ],
+    ),
+    (
+        '8',
+        [
+            0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110,
+        ],
+    ),
+    (
+        '9',
+        [
+            0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100,
+        ],
+    ),
+    ('-', [0, 0, 0, 0b11111, 0, 0, 0]),
+    ('_', [0, 0, 0, 0, 0, 0, 0b11111]),
+    ('+', [0, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0]),
+    ('.', [0, 0, 0, 0, 0, 0b01100, 0b01100]),
+    (',', [0, 0, 0, 0, 0b01100, 0b00100, 0b01000]),
+    (':', [0, 0b01100, 0b01100, 0, 0b01100, 0b01100, 0]),
+    ('\'', [0b01100, 0b00100, 0b01000, 0, 0, 0, 0]),
+    (
+        '!',
+        [0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0, 0b00100],
+    ),
+    (
+        '?',
+        [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0, 0b00100],
+    ),
+    ('/', [0, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0]),
+    (
+        '&',
+        [
+            0b01100, 0b10010, 0b10100, 0b01000, 0b10101, 0b10010, 0b01101,
+        ],
+    ),
+    (
+        '#',
+        [
+            0b01010, 0b01010, 0b11111, 0b01010, 0b11111, 0b01010, 0b01010,
+        ],
+    ),
+    (
+        '@',
+        [
+            0b01110, 0b10001, 0b00001, 0b01101, 0b10101, 0b10101, 0b01110,
+        ],
+    ),
+];
+
+/// Rows of `c`, falling back to `?` for characters the font lacks.
+#[must_use]
+pub(crate) fn glyph(c: char) -> [u8; GLYPH_H] {
+    let c = c.to_ascii_uppercase();
+    let find = |c: char| GLYPHS.iter().find(|(g, _)| *g == c).map(|(_, rows)| *rows);
+    find(c).or_else(|| find('?')).unwrap_or_default()
+}
+
+/// Render `text` (lines split on `\n`, each centered) into a row-major
+/// 0/1 bitmap. Returns `(width, height, pixels)`.
+#[must_use]
+pub(crate) fn render_text(text: &str) -> (usize, usize, Vec<f32>) {
+    let lines: Vec<Vec<char>> = text.lines().map(|l| l.trim().chars().collect()).collect();
+    let line_w = |l: &[char]| (l.len() * ADVANCE).saturating_sub(1);
+    let width = lines.iter().map(|l| line_w(l)).max().unwrap_or(0);
+    let height = (lines.len() * (GLYPH_H + LINE_GAP)).saturating_sub(LINE_GAP);
+    let mut pixels = vec![0.0; width * height];
+    for (i, line) in lines.iter().enumerate() {
+        let x0 = (width - line_w(line)) / 2;
+        let y0 = i * (GLYPH_H + LINE_GAP);
+        for (j, &c) in line.iter().enumerate() {
+            for (dy, row) in glyph(c).into_iter().enumerate() {
+                for dx in 0..GLYPH_W {
+                    if row & (1 << (GLYPH_W - 1 - dx)) != 0 {
+                        pixels[(y0 + dy) * width + x0 + j * ADVANCE + dx] = 1.0;
+                    }
+                }
+            }
+        }
+    }
+    (width, height, pixels)
+}
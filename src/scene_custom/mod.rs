@@ -43,7 +43,7 @@
 //! prefix to `scene-custom`.
 
 use std::collections::{BTreeMap, HashMap, HashSet};
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
 use clap::parser::ValueSource;
 use clap::ValueEnum;
@@ -52,12 +52,18 @@ use crate::charset::charset_from_str;
 use crate::cli::parse_color_scheme;
 use crate::colors_custom::is_colors_custom_name;
 use crate::config::{Args, ColorBg, GlitchLevel};
+use crate::config_schema::{get_str, ConfigMap};
 use crate::constants::{DENSITY_CLAMP_MAX, SPEED_MAX, SPEED_MIN};
 use crate::runtime::MonolithSize;
 use crate::validation::{
     parse_canonical_f32_range, parse_canonical_f64_range, parse_canonical_speed,
 };
 
+pub(crate) mod density_mask;
+pub(crate) mod mask_font;
+
+pub(crate) use density_mask::{parse_density_mask_floor, DensityMask};
+
 /// Canonical field list for `key=value` override blocks.
 ///
 /// Used by both scene-custom blocks and testconf validation to ensure
@@ -72,6 +78,8 @@ pub(crate) const PROFILE_FIELDS: &[&str] = &[
     "speed",
     "density",
     "density-map",
+    "density-mask",
+    "density-mask-floor",
     "glitch-level",
     "monolith-size",
     "color-bg",
@@ -100,6 +108,11 @@ pub(crate) struct UserProfile {
     /// Comma-separated f64 weights (0.0..=1.0) for monolith pillar placement.
     /// Parsed into a Vec<f64> and leaked to &'static for Cloud consumption.
     pub density_map: Option<String>,
+    /// 2D mask source (`text:…`, ASCII art or a PGM/PBM path); see
+    /// [`density_mask`].
+    pub density_mask: Option<String>,
+    /// Weight outside the mask, `0.0..=1.0`.
+    pub density_mask_floor: Option<String>,
     pub glitch_level: Option<String>,
     pub monolith_size: Option<String>,
     pub color_bg: Option<String>,
@@ -121,7 +134,7 @@ pub(crate) struct UserProfile {
 /// surface them in the "available scenes" list. They are NOT applied at
 /// runtime.
 #[must_use]
-pub(crate) fn collect_profiles(cfg: &HashMap<String, String>) -> BTreeMap<String, UserProfile> {
+pub(crate) fn collect_profiles(cfg: &ConfigMap) -> BTreeMap<String, UserProfile> {
     let mut profiles = BTreeMap::new();
     for (key, value) in cfg {
         if !is_profile_config_key(key) {
@@ -133,21 +146,23 @@ pub(crate) fn collect_profiles(cfg: &HashMap<String, String>) -> BTreeMap<String
             .entry(name.to_ascii_lowercase())
             .or_insert_with(UserProfile::default);
         match field {
-            "base-scene" => profile.base_scene = Some(value.clone()),
-            "color" => profile.color = Some(value.clone()),
-            "charset" => profile.charset = Some(value.clone()),
-            "fps" => profile.fps = Some(value.clone()),
-            "speed" => profile.speed = Some(value.clone()),
-            "density" => profile.density = Some(value.clone()),
-            "density-map" => profile.density_map = Some(value.clone()),
-            "glitch-level" => profile.glitch_level = Some(value.clone()),
-            "monolith-size" => profile.monolith_size = Some(value.clone()),
-            "color-bg" => profile.color_bg = Some(value.clone()),
-            "bold" => profile.bold = Some(value.clone()),
-            "colors-custom" => profile.colors_custom = Some(value.clone()),
-            "charset-custom" => profile.charset_custom = Some(value.clone()),
-            "shadingmode" => profile.shading_mode = Some(value.clone()),
-            "async-mode" => profile.async_mode = Some(value.clone()),
+            "base-scene" => profile.base_scene = Some(value.to_string()),
+            "color" => profile.color = Some(value.to_string()),
+            "charset" => profile.charset = Some(value.to_string()),
+            "fps" => profile.fps = Some(value.to_string()),
+            "speed" => profile.speed = Some(value.to_string()),
+            "density" => profile.density = Some(value.to_string()),
+            "density-map" => profile.density_map = Some(value.to_string()),
+            "density-mask" => profile.density_mask = Some(value.to_string()),
+            "density-mask-floor" => profile.density_mask_floor = Some(value.to_string()),
+            "glitch-level" => profile.glitch_level = Some(value.to_string()),
+            "monolith-size" => profile.monolith_size = Some(value.to_string()),
+            "color-bg" => profile.color_bg = Some(value.to_string()),
+            "bold" => profile.bold = Some(value.to_string()),
+            "colors-custom" => profile.colors_custom = Some(value.to_string()),
+            "charset-custom" => profile.charset_custom = Some(value.to_string()),
+            "shadingmode" => profile.shading_mode = Some(value.to_string()),
+            "async-mode" => profile.async_mode = Some(value.to_string()),
             _ => {}
         }
     }
@@ -198,7 +213,7 @@ pub(crate) fn apply_profile_layer(
     matches: &clap::ArgMatches,
     args: &mut Args,
     profiles: &BTreeMap<String, UserProfile>,
-    cfg: &HashMap<String, String>,
+    cfg: &ConfigMap,
     name: &str,
     strict_unknown: bool,
 ) -> Result<HashSet<&'static str>, String> {
@@ -305,7 +320,7 @@ fn apply_profile_overrides(
     args: &mut Args,
     name: &str,
     profile: &UserProfile,
-    cfg: &HashMap<String, String>,
+    cfg: &ConfigMap,
     modified: &mut HashSet<&'static str>,
 ) {
     if let Some(value) = profile
@@ -577,7 +592,7 @@ fn warn_invalid(profile: &str, field: &str, value: &str, expected: &str) {
 /// alt screen mid-rain.
 pub(crate) fn apply_scene_custom_to_cloud_config(
     new: &mut crate::app::CloudConfig,
-    cfg: &HashMap<String, String>,
+    cfg: &ConfigMap,
     name: &str,
 ) {
     let normalized = name.trim().to_ascii_lowercase();
@@ -595,6 +610,9 @@ pub(crate) fn apply_scene_custom_to_cloud_config(
         if field == "base-scene" || field == "preset" {
             continue;
         }
+        let Some(value) = value.as_str() else {
+            continue;
+        };
         if apply_scene_custom_field_to_cloud_config(new, cfg, field, value) {
             touched_any = true;
         }
@@ -615,7 +633,8 @@ pub(crate) const SCENE_CUSTOM_NAMESPACE: &str = "scene-custom";
 /// Owner contract (2026-08-07):
 /// - ALLOWED: `base-scene`, `color`, `charset`, `bold`, `colors-custom`,
 ///   `charset-custom`, `shadingmode`, `glitch-level`, `fps`, `speed`,
-///   `density`, `density-map`, `async-mode`.
+///   `density`, `density-map`, `density-mask`, `density-mask-floor`,
+///   `async-mode`.
 /// - FORBIDDEN (rejected as unknown key by `is_scene_custom_config_key`):
 ///   `ambient`, `crystal-dragon`, `color.tune`, `monolith-size`,
 ///   `intro`, `color-bg`.
@@ -628,6 +647,8 @@ pub(crate) const SCENE_CUSTOM_NAMESPACE: &str = "scene-custom";
 ///
 /// `density-map` is retained because it is tightly coupled to `density`
 /// for monolith pillar placement and was already supported.
+/// `density-mask` / `density-mask-floor` are its 2D counterpart for both
+/// rain styles.
 pub(crate) const SCENE_CUSTOM_FIELDS: &[&str] = &[
     "base-scene",
     "color",
@@ -641,6 +662,8 @@ pub(crate) const SCENE_CUSTOM_FIELDS: &[&str] = &[
     "speed",
     "density",
     "density-map",
+    "density-mask",
+    "density-mask-floor",
     "async-mode",
 ];
 
@@ -670,9 +693,7 @@ pub(crate) fn is_scene_custom_config_key(key: &str) -> bool {
 /// `monolith-size` and `color-bg` are silently dropped (the keys are
 /// flagged as unknown upstream by `is_scene_custom_config_key`).
 #[must_use]
-pub(crate) fn collect_custom_scenes(
-    cfg: &HashMap<String, String>,
-) -> BTreeMap<String, UserProfile> {
+pub(crate) fn collect_custom_scenes(cfg: &ConfigMap) -> BTreeMap<String, UserProfile> {
     let mut scenes = BTreeMap::new();
     for (key, value) in cfg {
         if !is_scene_custom_config_key(key) {
@@ -684,20 +705,22 @@ pub(crate) fn collect_custom_scenes(
             .entry(name.to_ascii_lowercase())
             .or_insert_with(UserProfile::default);
         match field {
-            "base-scene" => scene.base_scene = Some(value.clone()),
-            "color" => scene.color = Some(value.clone()),
-            "charset" => scene.charset = Some(value.clone()),
-            "fps" => scene.fps = Some(value.clone()),
-            "speed" => scene.speed = Some(value.clone()),
-            "density" => scene.density = Some(value.clone()),
-            "density-map" => scene.density_map = Some(value.clone()),
-            "glitch-level" => scene.glitch_level = Some(value.clone()),
+            "base-scene" => scene.base_scene = Some(value.to_string()),
+            "color" => scene.color = Some(value.to_string()),
+            "charset" => scene.charset = Some(value.to_string()),
+            "fps" => scene.fps = Some(value.to_string()),
+            "speed" => scene.speed = Some(value.to_string()),
+            "density" => scene.density = Some(value.to_string()),
+            "density-map" => scene.density_map = Some(value.to_string()),
+            "density-mask" => scene.density_mask = Some(value.to_string()),
+            "density-mask-floor" => scene.density_mask_floor = Some(value.to_string()),
+            "glitch-level" => scene.glitch_level = Some(value.to_string()),
             // new scene-custom fields per owner spec.
-            "bold" => scene.bold = Some(value.clone()),
-            "colors-custom" => scene.colors_custom = Some(value.clone()),
-            "charset-custom" => scene.charset_custom = Some(value.clone()),
-            "shadingmode" => scene.shading_mode = Some(value.clone()),
-            "async-mode" => scene.async_mode = Some(value.clone()),
+            "bold" => scene.bold = Some(value.to_string()),
+            "colors-custom" => scene.colors_custom = Some(value.to_string()),
+            "charset-custom" => scene.charset_custom = Some(value.to_string()),
+            "shadingmode" => scene.shading_mode = Some(value.to_string()),
+            "async-mode" => scene.async_mode = Some(value.to_string()),
             // monolith-size and color-bg are NOT in SCENE_CUSTOM_FIELDS,
             // so is_scene_custom_config_key already filtered them out.
             _ => {}
@@ -717,7 +740,7 @@ pub(crate) fn collect_custom_scenes(
 pub(crate) fn apply_scene_custom_layer(
     matches: &clap::ArgMatches,
     args: &mut Args,
-    cfg: &HashMap<String, String>,
+    cfg: &ConfigMap,
     name: &str,
     strict_unknown: bool,
 ) -> Result<HashSet<&'static str>, String> {
@@ -797,12 +820,12 @@ pub(crate) fn apply_scene_custom_layer(
 /// for rain rendering (Glyph vs Monolith).
 #[must_use]
 pub(crate) fn rain_style_for_custom_scene(
-    cfg: &HashMap<String, String>,
+    cfg: &ConfigMap,
     custom_name: &str,
 ) -> Option<crate::rain_style::RainStyle> {
     let normalized = custom_name.trim().to_ascii_lowercase();
     let key = format!("scene-custom.{normalized}.base-scene");
-    let base_name = cfg.get(&key)?.trim();
+    let base_name = get_str(cfg, &key)?.trim();
     crate::scene::rain_style_for_scene(base_name)
 }
 
@@ -817,7 +840,7 @@ pub(crate) fn rain_style_for_custom_scene(
 #[must_use]
 pub(crate) fn resolve_rain_style(
     name: Option<&str>,
-    cfg: &HashMap<String, String>,
+    cfg: &ConfigMap,
 ) -> crate::rain_style::RainStyle {
     name.and_then(|n| {
         crate::scene::rain_style_for_scene(n).or_else(|| rain_style_for_custom_scene(cfg, n))
@@ -885,11 +908,11 @@ pub(crate) fn apply_glitch_level_preset_to_cloud_config(
 /// found and applied (so the caller can track `touched_any`).
 pub(crate) fn apply_base_scene_to_cloud_config(
     new: &mut crate::app::CloudConfig,
-    cfg: &HashMap<String, String>,
+    cfg: &ConfigMap,
     normalized_name: &str,
 ) -> bool {
     let base_key = format!("scene-custom.{normalized_name}.base-scene");
-    let Some(base_name) = cfg.get(&base_key).map(|s| s.trim()) else {
+    let Some(base_name) = get_str(cfg, &base_key).map(str::trim) else {
         return false;
     };
     let Some(base_info) = crate::scene::get_scene(base_name) else {
@@ -944,7 +967,7 @@ pub(crate) fn apply_base_scene_to_cloud_config(
 #[must_use]
 pub(crate) fn apply_scene_custom_field_to_cloud_config(
     new: &mut crate::app::CloudConfig,
-    cfg: &HashMap<String, String>,
+    cfg: &ConfigMap,
     field: &str,
     value: &str,
 ) -> bool {
@@ -1035,6 +1058,20 @@ pub(crate) fn apply_scene_custom_field_to_cloud_config(
             }
             false
         }
+        "density-mask" => {
+            if let Ok(mask) = DensityMask::from_config(value) {
+                new.density_mask = Some(Arc::new(mask));
+                return true;
+            }
+            false
+        }
+        "density-mask-floor" => {
+            if let Ok(floor) = parse_density_mask_floor(value) {
+                new.density_mask_floor = floor;
+                return true;
+            }
+            false
+        }
         "bold" => {
             if let Ok(n) = value.trim().parse::<u8>() {
                 new.bold_mode = match n {
@@ -1155,6 +1192,45 @@ pub(crate) fn parse_density_map(csv: &str) -> Option<&'static [f64]> {
     }
 }
 
+/// Load scene-custom `name`'s 2D density mask and its floor for startup.
+/// A value that does not load is warned about like the other fields and
+/// left unset (no mask, default floor) instead of being dropped silently.
+pub(crate) fn load_density_mask(name: &str, scene: &UserProfile) -> (Option<DensityMask>, f32) {
+    let mask =
+        scene
+            .density_mask
+            .as_deref()
+            .and_then(|value| match DensityMask::from_config(value) {
+                Ok(mask) => Some(mask),
+                Err(e) => {
+                    warn_invalid(
+                        name,
+                        "density-mask",
+                        value,
+                        &format!("text:<TEXT>, ASCII art or a readable .pgm/.pbm path ({e})"),
+                    );
+                    None
+                }
+            });
+    let floor = scene
+        .density_mask_floor
+        .as_deref()
+        .and_then(|value| match parse_density_mask_floor(value) {
+            Ok(floor) => Some(floor),
+            Err(_) => {
+                warn_invalid(
+                    name,
+                    "density-mask-floor",
+                    value,
+                    "number in range 0.0..=1.0",
+                );
+                None
+            }
+        })
+        .unwrap_or(density_mask::DENSITY_MASK_DEFAULT_FLOOR);
+    (mask, floor)
+}
+
 /// Render a one-line-per-entry listing of custom scenes from config.
 ///
 /// Output is appended under the "CUSTOM SCENES (from config)" heading in
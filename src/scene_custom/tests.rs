@@ -29,12 +29,9 @@ fn scene_custom_keys_are_recognized() {
 
 #[test]
 fn collect_custom_scenes_groups_fields_by_name() {
-    let cfg = HashMap::from([
-        (
-            "scene-custom.hacker-mode.color".to_string(),
-            "green".to_string(),
-        ),
-        ("scene-custom.nightcore.speed".to_string(), "24".to_string()),
+    let cfg = ConfigMap::from([
+        ("scene-custom.hacker-mode.color".to_string(), "green".into()),
+        ("scene-custom.nightcore.speed".to_string(), "24".into()),
     ]);
     let scenes = collect_custom_scenes(&cfg);
     assert_eq!(scenes.len(), 2);
@@ -44,15 +41,9 @@ fn collect_custom_scenes_groups_fields_by_name() {
 
 #[test]
 fn collect_custom_scenes_ignores_profile_keys() {
-    let cfg = HashMap::from([
-        (
-            "profile.nightcore.color".to_string(),
-            "monolith".to_string(),
-        ),
-        (
-            "scene-custom.nightcore.color".to_string(),
-            "purple".to_string(),
-        ),
+    let cfg = ConfigMap::from([
+        ("profile.nightcore.color".to_string(), "monolith".into()),
+        ("scene-custom.nightcore.color".to_string(), "purple".into()),
     ]);
     let scenes = collect_custom_scenes(&cfg);
     assert_eq!(scenes.len(), 1);
@@ -88,9 +79,9 @@ fn scene_custom_namespace_constant_matches_prefix() {
 #[test]
 fn rain_style_for_custom_scene_returns_base_scene_rain_style() {
     // Custom scene with base-scene = monolith → RainStyle::Monolith.
-    let cfg = HashMap::from([(
+    let cfg = ConfigMap::from([(
         "scene-custom.afternoon.base-scene".to_string(),
-        "monolith".to_string(),
+        "monolith".into(),
     )]);
     let rs = rain_style_for_custom_scene(&cfg, "afternoon");
     assert_eq!(rs, Some(crate::rain_style::RainStyle::Monolith));
@@ -99,9 +90,9 @@ fn rain_style_for_custom_scene_returns_base_scene_rain_style() {
 #[test]
 fn rain_style_for_custom_scene_returns_glyph_for_signal_base() {
     // Custom scene with base-scene = signal → RainStyle::Glyph.
-    let cfg = HashMap::from([(
+    let cfg = ConfigMap::from([(
         "scene-custom.afternoon.base-scene".to_string(),
-        "signal".to_string(),
+        "signal".into(),
     )]);
     let rs = rain_style_for_custom_scene(&cfg, "afternoon");
     assert_eq!(rs, Some(crate::rain_style::RainStyle::Glyph));
@@ -110,17 +101,14 @@ fn rain_style_for_custom_scene_returns_glyph_for_signal_base() {
 #[test]
 fn rain_style_for_custom_scene_returns_none_when_no_base_scene() {
     // Custom scene with no base-scene → None (caller falls back to Glyph).
-    let cfg = HashMap::from([(
-        "scene-custom.bare.color".to_string(),
-        "neon-green".to_string(),
-    )]);
+    let cfg = ConfigMap::from([("scene-custom.bare.color".to_string(), "neon-green".into())]);
     let rs = rain_style_for_custom_scene(&cfg, "bare");
     assert!(rs.is_none());
 }
 
 #[test]
 fn rain_style_for_custom_scene_returns_none_for_unknown_custom_name() {
-    let cfg = HashMap::new();
+    let cfg = ConfigMap::new();
     let rs = rain_style_for_custom_scene(&cfg, "nonexistent");
     assert!(rs.is_none());
 }
@@ -128,9 +116,9 @@ fn rain_style_for_custom_scene_returns_none_for_unknown_custom_name() {
 #[test]
 fn rain_style_for_custom_scene_returns_none_for_unknown_base_scene() {
     // base-scene = "fake-scene" is not a built-in → None.
-    let cfg = HashMap::from([(
+    let cfg = ConfigMap::from([(
         "scene-custom.broken.base-scene".to_string(),
-        "fake-scene".to_string(),
+        "fake-scene".into(),
     )]);
     let rs = rain_style_for_custom_scene(&cfg, "broken");
     assert!(rs.is_none());
@@ -140,9 +128,9 @@ fn rain_style_for_custom_scene_returns_none_for_unknown_base_scene() {
 fn rain_style_for_custom_scene_is_case_insensitive_on_custom_name() {
     // Custom scene names are stored lowercase by collect_custom_scenes;
     // rain_style_for_custom_scene normalizes its input to match.
-    let cfg = HashMap::from([(
+    let cfg = ConfigMap::from([(
         "scene-custom.afternoon.base-scene".to_string(),
-        "monolith".to_string(),
+        "monolith".into(),
     )]);
     let rs = rain_style_for_custom_scene(&cfg, "AFTERNOON");
     assert_eq!(rs, Some(crate::rain_style::RainStyle::Monolith));
@@ -175,13 +163,10 @@ fn list_custom_scenes_text_shows_base_annotation_when_set() {
     // when a custom scene sets `base-scene`, the listing
     // annotates it as `name (base: <base-scene>)`. Custom scenes
     // without `base-scene` render as just `name`.
-    let cfg = HashMap::from([
-        (
-            "scene-custom.alpha.base-scene".to_string(),
-            "signal".to_string(),
-        ),
-        ("scene-custom.alpha.color".to_string(), "storm".to_string()),
-        ("scene-custom.beta.color".to_string(), "neon".to_string()),
+    let cfg = ConfigMap::from([
+        ("scene-custom.alpha.base-scene".to_string(), "signal".into()),
+        ("scene-custom.alpha.color".to_string(), "storm".into()),
+        ("scene-custom.beta.color".to_string(), "neon".into()),
     ]);
     let scenes = collect_custom_scenes(&cfg);
     let text = list_custom_scenes_text(&scenes);
@@ -199,19 +184,13 @@ fn list_custom_scenes_text_shows_base_annotation_when_set() {
 
 #[test]
 fn show_custom_scene_text_includes_fields_and_usage() {
-    let cfg = HashMap::from([
+    let cfg = ConfigMap::from([
         (
             "scene-custom.hacker-mode.base-scene".to_string(),
-            "monolith".to_string(),
-        ),
-        (
-            "scene-custom.hacker-mode.color".to_string(),
-            "green".to_string(),
-        ),
-        (
-            "scene-custom.hacker-mode.speed".to_string(),
-            "24".to_string(),
+            "monolith".into(),
         ),
+        ("scene-custom.hacker-mode.color".to_string(), "green".into()),
+        ("scene-custom.hacker-mode.speed".to_string(), "24".into()),
     ]);
     let scenes = collect_custom_scenes(&cfg);
     let scene = &scenes["hacker-mode"];
@@ -432,21 +411,15 @@ fn is_scene_custom_config_key_rejects_forbidden_fields() {
 
 #[test]
 fn collect_custom_scenes_parses_v30_3_fields() {
-    let cfg = HashMap::from([
-        ("scene-custom.test.bold".to_string(), "1".to_string()),
+    let cfg = ConfigMap::from([
+        ("scene-custom.test.bold".to_string(), "1".into()),
         (
             "scene-custom.test.colors-custom".to_string(),
-            "sunset".to_string(),
-        ),
-        (
-            "scene-custom.test.charset-custom".to_string(),
-            "zen".to_string(),
-        ),
-        ("scene-custom.test.shadingmode".to_string(), "1".to_string()),
-        (
-            "scene-custom.test.async-mode".to_string(),
-            "true".to_string(),
+            "sunset".into(),
         ),
+        ("scene-custom.test.charset-custom".to_string(), "zen".into()),
+        ("scene-custom.test.shadingmode".to_string(), "1".into()),
+        ("scene-custom.test.async-mode".to_string(), "true".into()),
     ]);
     let scenes = collect_custom_scenes(&cfg);
     let scene = &scenes["test"];
@@ -462,16 +435,13 @@ fn collect_custom_scenes_silently_drops_forbidden_fields() {
     // monolith-size and color-bg are filtered out by
     // is_scene_custom_config_key, so collect_custom_scenes never sees
     // them. Verify they don't appear in the parsed UserProfile.
-    let cfg = HashMap::from([
-        ("scene-custom.test.color".to_string(), "green".to_string()),
+    let cfg = ConfigMap::from([
+        ("scene-custom.test.color".to_string(), "green".into()),
         (
             "scene-custom.test.monolith-size".to_string(),
-            "large".to_string(),
-        ),
-        (
-            "scene-custom.test.color-bg".to_string(),
-            "black".to_string(),
+            "large".into(),
         ),
+        ("scene-custom.test.color-bg".to_string(), "black".into()),
     ]);
     let scenes = collect_custom_scenes(&cfg);
     let scene = &scenes["test"];
@@ -480,4 +450,171 @@ fn collect_custom_scenes_silently_drops_forbidden_fields() {
     assert!(scene.monolith_size.is_none());
     assert!(scene.color_bg.is_none());
 }
+
+// ── density-mask tests ──
+
+#[test]
+fn density_mask_art_drops_blank_rows_and_common_indent() {
+    let mask = DensityMask::from_art("\n    .##.\n    #9.#\n\n").unwrap();
+    assert_eq!((mask.width, mask.height), (4, 2));
+    assert!(!mask.square_pixels);
+    assert_eq!(mask.weights, vec![0.0, 1.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0]);
+    let mask = DensityMask::from_art("0\n5").unwrap();
+    assert_eq!(mask.weights[0], 0.0);
+    assert!((mask.weights[1] - 5.0 / 9.0).abs() < 1e-6);
+    assert!(DensityMask::from_art("\n  \n").is_err());
+}
+
+#[test]
+fn density_mask_text_renders_bitmap_font() {
+    let mask = DensityMask::from_config("text:Hi").unwrap();
+    // Two 5-px glyphs plus one gap column, 7 rows.
+    assert_eq!((mask.width, mask.height), (11, 7));
+    assert!(mask.square_pixels);
+    // 'H' top-left pixel is set, the gap column is empty.
+    assert_eq!(mask.weights[0], 1.0);
+    assert!((0..7).all(|y| mask.weights[y * 11 + 5] == 0.0));
+    // Two lines: 7 + gap 2 + 7 rows, each line centered.
+    let two = DensityMask::from_config("text:AB\nC").unwrap();
+    assert_eq!((two.width, two.height), (11, 16));
+    assert!(DensityMask::from_config("text:").is_err());
+}
+
+#[test]
+fn density_mask_parses_plain_and_raw_pnm() {
+    let p2 = DensityMask::from_pnm(b"P2\n# logo\n2 2\n255\n0 255\n51 102\n").unwrap();
+    assert_eq!((p2.width, p2.height), (2, 2));
+    assert_eq!(p2.weights, vec![0.0, 1.0, 0.2, 0.4]);
+
+    let mut p5 = b"P5 3 1 255\n".to_vec();
+    p5.extend_from_slice(&[0, 128, 255]);
+    let p5 = DensityMask::from_pnm(&p5).unwrap();
+    assert_eq!(p5.weights[2], 1.0);
+
+    let p1 = DensityMask::from_pnm(b"P1\n3 2\n101\n0 1 0\n").unwrap();
+    assert_eq!(p1.weights, vec![1.0, 0.0, 1.0, 0.0, 1.0, 0.0]);
+
+    let mut p4 = b"P4\n10 1\n".to_vec();
+    p4.extend_from_slice(&[0b1000_0000, 0b0100_0000]);
+    let p4 = DensityMask::from_pnm(&p4).unwrap();
+    assert_eq!(p4.weights[0], 1.0);
+    assert_eq!(p4.weights[9], 1.0);
+    assert_eq!(p4.weights.iter().filter(|w| **w > 0.0).count(), 2);
+}
+
+#[test]
+fn density_mask_rejects_bad_pnm() {
+    assert!(DensityMask::from_pnm(b"P6\n1 1\n255\n\0\0\0").is_err());
+    assert!(DensityMask::from_pnm(b"P5\n4 4\n255\n\x01").is_err());
+    assert!(DensityMask::from_pnm(b"P2\n1 1\n").is_err());
+    let err = DensityMask::from_pnm(b"P5\n5000 1\n255\n").unwrap_err();
+    assert!(err.contains("too large"), "{err}");
+}
+
+#[test]
+fn density_mask_file_path_must_be_absolute_and_readable() {
+    assert!(DensityMask::from_config("logo.pgm")
+        .unwrap_err()
+        .contains("absolute"));
+    let path = std::env::temp_dir().join(format!("cosmostrix-mask-{}.pbm", std::process::id()));
+    std::fs::write(&path, b"P1 2 1 1 0").unwrap();
+    let mask = DensityMask::from_config(path.to_str().unwrap());
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(mask.unwrap().weights, vec![1.0, 0.0]);
+    assert!(DensityMask::from_config("/nonexistent/cosmostrix/logo.pgm")
+        .unwrap_err()
+        .contains("cannot read"));
+}
+
+#[test]
+fn density_mask_bake_fits_centers_and_applies_floor() {
+    // One cell of ASCII art on a 20x10 grid is limited by the height:
+    // 9 cells square (90%), centered, so columns 5..=13 are covered.
+    let mask = DensityMask::from_art("#\n").unwrap();
+    let baked = mask.bake(20, 10, 0.25);
+    assert_eq!(baked.len(), 200);
+    let row: Vec<f32> = baked[5 * 20..6 * 20].to_vec();
+    assert_eq!(row.iter().filter(|w| **w == 1.0).count(), 9);
+    assert_eq!(row[5], 1.0);
+    assert_eq!(row[4], 0.25);
+    assert_eq!(row[14], 0.25);
+    // Without a floor, uncovered weight stays 0 and peaks follow columns.
+    let baked = mask.bake(20, 10, 0.0);
+    let peaks = density_mask::column_peaks(&baked, 20);
+    assert_eq!(peaks[9], 1.0);
+    assert_eq!(peaks[2], 0.0);
+}
+
+#[test]
+fn density_mask_bake_averages_when_shrinking() {
+    // A 2x2 checkerboard squeezed into one cell blends to a middle weight.
+    let mask = DensityMask::from_art("#.\n.#").unwrap();
+    let baked = mask.bake(1, 1, 0.0);
+    assert!(baked[0] > 0.0 && baked[0] < 1.0, "{}", baked[0]);
+}
+
+#[test]
+fn density_mask_square_pixels_use_half_the_rows() {
+    // A 4x4 image on an 80x40 grid: pixels are square, so the fitted mask
+    // is twice as wide (in cells) as it is tall.
+    let mut pgm = b"P5 4 4 255\n".to_vec();
+    pgm.extend_from_slice(&[255; 16]);
+    let mask = DensityMask::from_pnm(&pgm).unwrap();
+    let baked = mask.bake(80, 40, 0.0);
+    let full_row = |line: usize| {
+        baked[line * 80..(line + 1) * 80]
+            .iter()
+            .filter(|w| **w == 1.0)
+            .count()
+    };
+    let full_col = |col: usize| (0..40).filter(|l| baked[l * 80 + col] == 1.0).count();
+    assert_eq!(full_row(20), 2 * full_col(40));
+}
+
+#[test]
+fn density_mask_floor_parses_unit_range() {
+    assert_eq!(parse_density_mask_floor("0.3"), Ok(0.3));
+    assert_eq!(parse_density_mask_floor(" 1 "), Ok(1.0));
+    assert!(parse_density_mask_floor("1.5").is_err());
+    assert!(parse_density_mask_floor("dim").is_err());
+}
+
+#[test]
+fn density_mask_fields_are_collected() {
+    let cfg = ConfigMap::from([
+        (
+            "scene-custom.lobby.density-mask".to_string(),
+            "text:ACME".into(),
+        ),
+        (
+            "scene-custom.lobby.density-mask-floor".to_string(),
+            "0.1".into(),
+        ),
+    ]);
+    let scenes = collect_custom_scenes(&cfg);
+    let scene = &scenes["lobby"];
+    assert_eq!(scene.density_mask.as_deref(), Some("text:ACME"));
+    assert_eq!(scene.density_mask_floor.as_deref(), Some("0.1"));
+}
 use super::*;
+
+#[test]
+fn load_density_mask_leaves_unloadable_values_unset() {
+    let scene = UserProfile {
+        density_mask: Some("/nonexistent/cosmostrix-mask.pgm".to_string()),
+        density_mask_floor: Some("2.5".to_string()),
+        ..UserProfile::default()
+    };
+    let (mask, floor) = load_density_mask("bad", &scene);
+    assert!(mask.is_none());
+    assert_eq!(floor, density_mask::DENSITY_MASK_DEFAULT_FLOOR);
+
+    let scene = UserProfile {
+        density_mask: Some("text:HI".to_string()),
+        density_mask_floor: Some("0.4".to_string()),
+        ..UserProfile::default()
+    };
+    let (mask, floor) = load_density_mask("good", &scene);
+    assert!(mask.is_some());
+    assert_eq!(floor, 0.4);
+}
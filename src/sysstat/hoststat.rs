@@ -0,0 +1,227 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Host-wide load sampling for the Crystal Dragon system sensors.
+//!
+//! Unlike `cpustat` / `memstat` (this process only), these readers look at
+//! the whole machine. Every reader returns a percentage where 0 is idle /
+//! healthy and 100 is saturated, so the sensor can map all of them onto
+//! the same 1–99 point scale:
+//!
+//! | Reader | Source | 100% means |
+//! |--------|--------|------------|
+//! | [`loadavg_percent`] | `/proc/loadavg` (1-minute) | load == online CPUs |
+//! | [`psi_percent`] | `/proc/pressure/{cpu,memory,io}` (`some avg60`) | [`PSI_FULL_SCALE_PCT`] stall |
+//! | [`memory_used_percent`] | `/proc/meminfo` (`MemAvailable`) | no memory available |
+//! | [`battery_drain_percent`] | `/sys/class/power_supply/*/capacity` | battery empty |
+//!
+//! ## Platform support
+//! - **Linux**: all four. PSI needs kernel 4.20+ with `CONFIG_PSI`;
+//!   battery needs a `type = Battery` supply.
+//! - **Other**: every reader returns `None` and the sensor skips it.
+//!
+//! Each read is one small file (~1µs); the sensor polls once a minute.
+
+/// `some avg60` PSI stall percentage that maps to 100%. A quarter of the
+/// last minute with at least one task stalled is already a struggling
+/// host; linear scaling against 100% would keep every real incident in
+/// the Cold group.
+pub(crate) const PSI_FULL_SCALE_PCT: f32 = 25.0;
+
+/// PSI resource under `/proc/pressure/`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum PsiResource {
+    Cpu,
+    Memory,
+    Io,
+}
+
+impl PsiResource {
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    fn file_name(self) -> &'static str {
+        match self {
+            PsiResource::Cpu => "cpu",
+            PsiResource::Memory => "memory",
+            PsiResource::Io => "io",
+        }
+    }
+}
+
+/// 1-minute load average relative to the online CPU count, in percent
+/// (capped at 100).
+#[must_use]
+pub(crate) fn loadavg_percent() -> Option<f32> {
+    #[cfg(target_os = "linux")]
+    {
+        let text = std::fs::read_to_string("/proc/loadavg").ok()?;
+        let cpus = std::thread::available_parallelism().map_or(1, usize::from);
+        parse_loadavg_percent(&text, cpus)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// PSI `some avg60` for `resource`, scaled so [`PSI_FULL_SCALE_PCT`] is
+/// 100%.
+#[must_use]
+pub(crate) fn psi_percent(resource: PsiResource) -> Option<f32> {
+    #[cfg(target_os = "linux")]
+    {
+        let path = format!("/proc/pressure/{}", resource.file_name());
+        let text = std::fs::read_to_string(path).ok()?;
+        parse_psi_percent(&text)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = resource;
+        None
+    }
+}
+
+/// Share of physical memory not available to new work, in percent.
+#[must_use]
+pub(crate) fn memory_used_percent() -> Option<f32> {
+    #[cfg(target_os = "linux")]
+    {
+        let text = std::fs::read_to_string("/proc/meminfo").ok()?;
+        parse_meminfo_used_percent(&text)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// Charge missing from the emptiest system battery, in percent
+/// (`100 - capacity`). Peripheral batteries (`scope = Device`, e.g. a
+/// wireless mouse) are ignored.
+#[must_use]
+pub(crate) fn battery_drain_percent() -> Option<f32> {
+    #[cfg(target_os = "linux")]
+    {
+        let mut lowest: Option<u8> = None;
+        for entry in std::fs::read_dir("/sys/class/power_supply").ok()?.flatten() {
+            let dir = entry.path();
+            let read =
+                |name: &str| crate::platform::sysfs_read_line(&dir.join(name).to_string_lossy());
+            if read("type").as_deref() != Some("Battery")
+                || read("scope").as_deref() == Some("Device")
+            {
+                continue;
+            }
+            if let Some(capacity) = read("capacity").and_then(|c| c.parse::<u8>().ok()) {
+                let capacity = capacity.min(100);
+                lowest = Some(lowest.map_or(capacity, |l| l.min(capacity)));
+            }
+        }
+        lowest.map(|capacity| f32::from(100 - capacity))
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+// ── Parsers ────────────────────────────────────────────────────────────────
+
+/// `/proc/loadavg` body → 1-minute load per CPU, in percent.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_loadavg_percent(text: &str, cpus: usize) -> Option<f32> {
+    let load: f32 = text.split_whitespace().next()?.parse().ok()?;
+    if !load.is_finite() || load < 0.0 {
+        return None;
+    }
+    Some((load / cpus.max(1) as f32 * 100.0).min(100.0))
+}
+
+/// `/proc/pressure/*` body → scaled `some avg60`.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_psi_percent(text: &str) -> Option<f32> {
+    let some = text.lines().find(|l| l.starts_with("some "))?;
+    let avg60: f32 = some
+        .split_whitespace()
+        .find_map(|field| field.strip_prefix("avg60="))?
+        .parse()
+        .ok()?;
+    if !avg60.is_finite() || avg60 < 0.0 {
+        return None;
+    }
+    Some((avg60 / PSI_FULL_SCALE_PCT * 100.0).min(100.0))
+}
+
+/// `/proc/meminfo` body → `1 - MemAvailable / MemTotal`, in percent.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_meminfo_used_percent(text: &str) -> Option<f32> {
+    let field = |prefix: &str| -> Option<u64> {
+        let rest = text.lines().find_map(|l| l.strip_prefix(prefix))?;
+        rest.split_whitespace().next()?.parse().ok()
+    };
+    let total = field("MemTotal:")?;
+    let available = field("MemAvailable:")?;
+    if total == 0 {
+        return None;
+    }
+    let used = total.saturating_sub(available) as f64 / total as f64;
+    Some((used * 100.0) as f32)
+}
+
+// ── Tests ──────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loadavg_is_relative_to_cpu_count() {
+        let text = "2.00 1.50 1.00 3/512 12345\n";
+        assert_eq!(parse_loadavg_percent(text, 4), Some(50.0));
+        assert_eq!(parse_loadavg_percent(text, 1), Some(100.0), "capped");
+        assert_eq!(parse_loadavg_percent(text, 0), Some(100.0));
+        assert_eq!(parse_loadavg_percent("", 4), None);
+        assert_eq!(parse_loadavg_percent("nan 0 0", 4), None);
+    }
+
+    #[test]
+    fn psi_reads_some_avg60_and_scales() {
+        let text = "some avg10=1.00 avg60=5.00 avg300=0.50 total=123\n\
+                    full avg10=0.00 avg60=20.00 avg300=0.00 total=45\n";
+        assert_eq!(parse_psi_percent(text), Some(20.0));
+        // /proc/pressure/cpu on older kernels has no `full` line.
+        assert_eq!(
+            parse_psi_percent("some avg10=0.00 avg60=50.00 avg300=0.00 total=0\n"),
+            Some(100.0)
+        );
+        assert_eq!(parse_psi_percent("full avg60=5.00\n"), None);
+    }
+
+    #[test]
+    fn meminfo_uses_available_not_free() {
+        let text = "MemTotal:       16000000 kB\n\
+                    MemFree:         1000000 kB\n\
+                    MemAvailable:   12000000 kB\n";
+        assert_eq!(parse_meminfo_used_percent(text), Some(25.0));
+        assert_eq!(
+            parse_meminfo_used_percent("MemTotal: 0 kB\nMemAvailable: 0 kB\n"),
+            None
+        );
+        assert_eq!(parse_meminfo_used_percent("MemTotal: 100 kB\n"), None);
+    }
+
+    #[test]
+    fn live_readers_stay_in_range() {
+        // Sandboxes may hide any of these; only the range is checked.
+        let readings = [
+            loadavg_percent(),
+            psi_percent(PsiResource::Cpu),
+            psi_percent(PsiResource::Memory),
+            psi_percent(PsiResource::Io),
+            memory_used_percent(),
+            battery_drain_percent(),
+        ];
+        for pct in readings.into_iter().flatten() {
+            assert!((0.0..=100.0).contains(&pct), "{pct}");
+        }
+    }
+}
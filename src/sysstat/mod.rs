@@ -6,7 +6,8 @@
 //! Aggregates CPU, memory, env-level, and usage sampling helpers used by
 //! the benchmark subsystem (`bench::*`), the HUD CPU/RSS lines
 //! (`interactive::hud`), and the Crystal Dragon adaptive engine
-//! (`crystal_dragon_engine::sensor`).
+//! (`crystal_dragon_engine::sensor`, which also reads the host-wide
+//! `hoststat` readers).
 //!
 //! All 4 submodules were relocated from src/ root as flat files (audit
 //! M3). Re-exported as `pub(crate)` so the 15 existing
@@ -17,5 +18,6 @@
 
 pub(crate) mod cpustat;
 pub(crate) mod envstat;
+pub(crate) mod hoststat;
 pub(crate) mod memstat;
 pub(crate) mod usagestat;
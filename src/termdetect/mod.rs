@@ -8,17 +8,21 @@
 //! - [`ancestor`] — Linux `/proc` ancestor process walk
 //! - [`detect`] — high-perf and kitty-keyboard detection logic
 //! - [`protocol`] — protocol constants (sync markers, FPS caps)
+//! - [`probe`] — optional active query round trip (`--probe-terminal`)
 //! - [`tests`] — full test suite
 
 mod ancestor;
 mod detect;
 mod hosts;
+mod probe;
 mod protocol;
 
 #[cfg(test)]
 mod tests;
 
 use std::env;
+use std::sync::OnceLock;
+use std::time::Duration;
 // Re-export test-only items so tests.rs can find them via super::*.
 #[cfg(test)]
 #[cfg(not(target_os = "linux"))]
@@ -37,10 +41,99 @@ pub(crate) use protocol::{known_xtermjs_hosts, RIS_RESET, VSCODE_FPS_CAP};
 // Re-export protocol constants at crate level.
 pub(crate) use protocol::{SYNC_END, SYNC_START};
 
+pub(crate) use probe::ProbeReport;
+#[cfg(test)]
+pub(crate) use probe::{parse_colorfgbg, parse_replies, BACKGROUND_QUERY, PROBE_QUERY};
+
 // Items used by detect() below.
 use detect::{high_perf_detection_source, kitty_keyboard_supported};
 use hosts::XTERMJS_HOSTS;
-use protocol::{HIGH_PERF_DEFAULT_FPS, STANDARD_DEFAULT_FPS, XTERMJS_FPS_CAP};
+use protocol::{HIGH_PERF_DEFAULT_FPS, PROBE_TIMEOUT_MS, STANDARD_DEFAULT_FPS, XTERMJS_FPS_CAP};
+
+/// Outcome of the `--probe-terminal` exchange; unset when the probe is off.
+static PROBE: OnceLock<Result<ProbeReport, String>> = OnceLock::new();
+
+/// Query the terminal once (see [`probe`]). Every later [`detect`] call
+/// applies the answers over the environment heuristics. Must run before
+/// `Terminal::init` takes the tty.
+pub(crate) fn run_probe() {
+    PROBE.get_or_init(|| probe::run(probe::PROBE_QUERY, Duration::from_millis(PROBE_TIMEOUT_MS)));
+}
+
+/// The probe outcome: `None` when it did not run, `Err` with the reason
+/// it could not.
+#[must_use]
+pub(crate) fn probe_outcome() -> Option<&'static Result<ProbeReport, String>> {
+    PROBE.get()
+}
+
+/// The probe's replies, when it ran.
+#[must_use]
+pub(crate) fn probe_report() -> Option<&'static ProbeReport> {
+    PROBE.get()?.as_ref().ok()
+}
+
+/// The terminal's default background and where it came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct TerminalBackground {
+    pub rgb: (u8, u8, u8),
+    /// `"OSC 11"` or `"COLORFGBG"`.
+    pub source: &'static str,
+}
+
+impl TerminalBackground {
+    /// `light (#fdf6e3 from OSC 11)` — for `--doctor` and `--verbose`.
+    #[must_use]
+    pub(crate) fn describe(self) -> String {
+        let (r, g, b) = self.rgb;
+        let tone = if crate::chroma_dragon_engine::canvas::is_light_background(self.rgb) {
+            "light"
+        } else {
+            "dark"
+        };
+        format!("{tone} (#{r:02x}{g:02x}{b:02x} from {})", self.source)
+    }
+}
+
+/// Outcome of [`query_background`]; unset until it runs.
+static BACKGROUND: OnceLock<Option<TerminalBackground>> = OnceLock::new();
+
+/// Find the terminal's default background once, for
+/// `color-bg = default-background`: the probe's OSC 11 reply when
+/// `--probe-terminal` already ran, else a standalone OSC 11 round trip,
+/// else `COLORFGBG`. Must run before `Terminal::init` takes the tty.
+pub(crate) fn query_background() -> Option<TerminalBackground> {
+    *BACKGROUND.get_or_init(|| {
+        let osc11 = match probe_outcome() {
+            // The probe already asked; a missing answer will not change.
+            Some(outcome) => outcome.as_ref().ok().and_then(|r| r.background),
+            None => probe::run(
+                probe::BACKGROUND_QUERY,
+                Duration::from_millis(PROBE_TIMEOUT_MS),
+            )
+            .ok()
+            .and_then(|r| r.background),
+        };
+        osc11
+            .map(|rgb| TerminalBackground {
+                rgb,
+                source: "OSC 11",
+            })
+            .or_else(|| {
+                let rgb = probe::parse_colorfgbg(&env::var("COLORFGBG").ok()?)?;
+                Some(TerminalBackground {
+                    rgb,
+                    source: "COLORFGBG",
+                })
+            })
+    })
+}
+
+/// The background [`query_background`] found, if it ran and found one.
+#[must_use]
+pub(crate) fn terminal_background() -> Option<TerminalBackground> {
+    BACKGROUND.get().copied().flatten()
+}
 
 // Items used only by the test suite (tests.rs uses `use super::*;`).
 
@@ -171,11 +264,26 @@ pub(crate) struct TerminalCaps {
     /// Shown in `-v` verbose output so the user can verify the detection
     /// chain. Essential for debugging "why is my fps 60 not 144?".
     pub dynamic_fps_source: &'static str,
+    /// True when the terminal implements REP (`CSI n b`, repeat the
+    /// preceding glyph). The render path collapses runs of identical
+    /// glyphs into one copy plus REP when that is fewer bytes (see
+    /// `terminal/cursor_cost.rs`). False on the Linux console (vt.c has
+    /// no REP and would drop the repeats) and on `dumb` / unset TERM.
+    pub repeat_char: bool,
 }
 
-/// Run detection from environment variables. Safe to call before any
-/// terminal initialization.
+/// Run detection from environment variables, then apply the probe's
+/// answers if [`run_probe`] ran. Safe to call before any terminal
+/// initialization.
 pub(crate) fn detect() -> TerminalCaps {
+    let mut caps = detect_from_env();
+    if let Some(report) = probe_report() {
+        report.apply(&mut caps);
+    }
+    caps
+}
+
+fn detect_from_env() -> TerminalCaps {
     let term = env::var("TERM").unwrap_or_default();
     let term_program = env::var("TERM_PROGRAM").unwrap_or_default();
 
@@ -257,6 +365,11 @@ pub(crate) fn detect() -> TerminalCaps {
     // correctly reject Super+C.
     let kitty_keyboard = kitty_keyboard_supported(&term_program, &term, xtermjs_host);
 
+    // REP is an ECMA-48 sequence every xterm-compatible emulator (incl.
+    // xterm.js, VTE, kitty, WezTerm, Windows Terminal) implements; the
+    // Linux console is the one common exception.
+    let repeat_char = has_alternate_screen && !term.eq_ignore_ascii_case("linux");
+
     TerminalCaps {
         sync_output: sync_ok,
         kitty_keyboard,
@@ -266,5 +379,6 @@ pub(crate) fn detect() -> TerminalCaps {
         default_fps_cap,
         dynamic_default_fps,
         dynamic_fps_source,
+        repeat_char,
     }
 }
@@ -0,0 +1,494 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Active terminal probe (`--probe-terminal true`).
+//!
+//! The rest of `termdetect` guesses from `TERM`, `TERM_PROGRAM`, host
+//! tables and the `/proc` ancestor walk. Those guesses misfire wherever
+//! the environment lies about the terminal: inside tmux/screen (`TERM`
+//! names the multiplexer), over mosh, and in containers that inherit a
+//! stock `TERM=xterm`. The probe asks the terminal directly instead:
+//!
+//! | Query | Sequence | Answers |
+//! |-------|----------|---------|
+//! | XTVERSION | `CSI > 0 q` | terminal name and version (`DCS > \| kitty(0.35.2) ST`) |
+//! | DA2 | `CSI > c` | terminal class; 84 = tmux, 83 = screen |
+//! | DECRQM 2026 | `CSI ? 2026 $ p` | synchronized output support |
+//! | kitty keyboard | `CSI ? u` | current kitty keyboard flags, if supported |
+//! | DECRQSS SGR | `DCS $ q m ST` after a `48;2` SGR | whether the truecolor background stuck |
+//! | OSC 11 / OSC 4 | `OSC 11 ; ? ST`, `OSC 4 ; n ; ? ST` | background and palette 0–15 |
+//! | DA1 | `CSI c` | sent last as the sentinel |
+//!
+//! Every VT-compatible terminal answers DA1 and answers queries in order,
+//! so once the DA1 reply arrives every other reply that is coming has
+//! arrived too; a missing reply then means "unsupported", not "slow".
+//! Without DA1 the exchange gives up after `PROBE_TIMEOUT_MS` and the
+//! heuristics stand.
+//!
+//! Answers override the heuristics in [`super::detect`] (sync output,
+//! kitty keyboard, FPS tier, xterm.js detection) and in
+//! `cli::detect_color_mode_auto` (truecolor). `--doctor` lists them.
+
+use std::time::Duration;
+
+use crossterm::style::Color;
+
+use crate::runtime::ColorMode;
+
+use super::hosts::HIGH_PERF_TERM_HINTS;
+use super::protocol::{HIGH_PERF_DEFAULT_FPS, STANDARD_DEFAULT_FPS, XTERMJS_FPS_CAP};
+use super::TerminalCaps;
+
+/// Every query in one write, DA1 last. The DECRQSS check sets a truecolor
+/// background, asks for the current SGR, and resets; nothing is printed
+/// in between, so the terminal never shows the color.
+pub(crate) const PROBE_QUERY: &[u8] = concat!(
+    "\x1b[>0q",
+    "\x1b[>c",
+    "\x1b[?2026$p",
+    "\x1b[?u",
+    "\x1b[48;2;1;2;3m\x1bP$qm\x1b\\\x1b[m",
+    "\x1b]11;?\x1b\\",
+    "\x1b]4;0;?\x1b\\\x1b]4;1;?\x1b\\\x1b]4;2;?\x1b\\\x1b]4;3;?\x1b\\",
+    "\x1b]4;4;?\x1b\\\x1b]4;5;?\x1b\\\x1b]4;6;?\x1b\\\x1b]4;7;?\x1b\\",
+    "\x1b]4;8;?\x1b\\\x1b]4;9;?\x1b\\\x1b]4;10;?\x1b\\\x1b]4;11;?\x1b\\",
+    "\x1b]4;12;?\x1b\\\x1b]4;13;?\x1b\\\x1b]4;14;?\x1b\\\x1b]4;15;?\x1b\\",
+    "\x1b[c",
+)
+.as_bytes();
+
+/// The background query alone, for `color-bg = default-background` when
+/// the full probe is off: OSC 11, then the DA1 sentinel.
+pub(crate) const BACKGROUND_QUERY: &[u8] = b"\x1b]11;?\x1b\\\x1b[c";
+
+/// Replies collected by one probe exchange. `None` fields got no reply.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct ProbeReport {
+    /// DA1 attributes (`CSI ? 62 ; 22 c` → `[62, 22]`). `Some` means the
+    /// terminal answered the sentinel, so the other `None`s are final.
+    pub da1: Option<Vec<u16>>,
+    /// DA2 parameters: terminal class, firmware version, ROM cartridge.
+    pub da2: Option<Vec<u16>>,
+    /// XTVERSION text, e.g. `kitty(0.35.2)` or `tmux 3.4`.
+    pub xtversion: Option<String>,
+    /// DECRQM state for mode 2026: 0 unknown, 1 set, 2 reset,
+    /// 3 permanently set, 4 permanently reset.
+    pub sync_2026: Option<u16>,
+    /// Kitty keyboard flags currently pushed (`CSI ? flags u`).
+    pub kitty_flags: Option<u16>,
+    /// Whether the DECRQSS SGR reply still carried the `48;2` background.
+    pub truecolor: Option<bool>,
+    /// OSC 11 background color.
+    pub background: Option<(u8, u8, u8)>,
+    /// OSC 4 palette entries 0–15.
+    pub palette: [Option<(u8, u8, u8)>; 16],
+    /// Wall time of the exchange.
+    pub elapsed_ms: u64,
+}
+
+impl ProbeReport {
+    /// True once the DA1 sentinel came back.
+    #[must_use]
+    pub(crate) fn answered(&self) -> bool {
+        self.da1.is_some()
+    }
+
+    /// Synchronized output from DECRQM 2026. A terminal that answered DA1
+    /// but not DECRQM gives no verdict — DECRQM itself may be missing.
+    #[must_use]
+    pub(crate) fn sync_output(&self) -> Option<bool> {
+        self.sync_2026.map(|ps| matches!(ps, 1..=3))
+    }
+
+    /// Kitty keyboard support: a `CSI ? u` reply, or its absence before
+    /// the DA1 sentinel.
+    #[must_use]
+    pub(crate) fn kitty_keyboard(&self) -> Option<bool> {
+        if self.kitty_flags.is_some() {
+            Some(true)
+        } else {
+            self.answered().then_some(false)
+        }
+    }
+
+    /// Terminal name from XTVERSION, without the version
+    /// (`kitty(0.35.2)` → `kitty`, `tmux 3.4` → `tmux`).
+    #[must_use]
+    pub(crate) fn terminal_name(&self) -> Option<&str> {
+        let v = self.xtversion.as_deref()?;
+        let name = v.split(['(', ' ']).next().unwrap_or(v).trim();
+        (!name.is_empty()).then_some(name)
+    }
+
+    /// A multiplexer between cosmostrix and the real terminal. tmux and
+    /// screen answer the queries themselves, so the name they report is
+    /// their own.
+    #[must_use]
+    pub(crate) fn multiplexer(&self) -> Option<&'static str> {
+        let name = self.terminal_name().map(str::to_ascii_lowercase);
+        match (
+            self.da2.as_deref().and_then(<[u16]>::first),
+            name.as_deref(),
+        ) {
+            (Some(84), _) | (_, Some("tmux")) => Some("tmux"),
+            (Some(83), _) | (_, Some("screen")) => Some("screen"),
+            _ => None,
+        }
+    }
+
+    /// True when XTVERSION names an xterm.js host (VSCode, Hyper, …),
+    /// whatever `TERM_PROGRAM` says.
+    #[must_use]
+    pub(crate) fn is_xtermjs(&self) -> bool {
+        self.terminal_name()
+            .is_some_and(|n| n.eq_ignore_ascii_case("xterm.js"))
+    }
+
+    /// FPS tier from the reported name: high-perf for the same names the
+    /// `TERM` hints list, standard for anything else, including a
+    /// multiplexer. `None` without an XTVERSION or DA2 identity.
+    #[must_use]
+    pub(crate) fn high_perf(&self) -> Option<bool> {
+        if self.multiplexer().is_some() {
+            return Some(false);
+        }
+        let name = self.terminal_name()?.to_ascii_lowercase();
+        Some(HIGH_PERF_TERM_HINTS.iter().any(|h| name.contains(h)) || name == "iterm2")
+    }
+
+    /// Overwrite the env-derived capabilities with the probed ones.
+    pub(crate) fn apply(&self, caps: &mut TerminalCaps) {
+        if self.is_xtermjs() && !caps.xtermjs_host {
+            caps.xtermjs_host = true;
+            caps.sync_output = false;
+            caps.kitty_keyboard = false;
+            caps.default_fps_cap = XTERMJS_FPS_CAP;
+            caps.dynamic_default_fps = XTERMJS_FPS_CAP;
+            caps.dynamic_fps_source = "probe: XTVERSION xterm.js (capped)";
+            return;
+        }
+        // xterm.js hosts keep their protective defaults: sync output and
+        // high frame rates are off for its buffer growth, not for lack of
+        // support, so a positive answer must not re-enable them.
+        if caps.xtermjs_host {
+            return;
+        }
+        if let Some(sync) = self.sync_output() {
+            caps.sync_output = sync;
+        }
+        if let Some(kitty) = self.kitty_keyboard() {
+            caps.kitty_keyboard = kitty;
+        }
+        if let Some(high) = self.high_perf() {
+            let (fps, source) = match (high, self.multiplexer()) {
+                (true, _) => (HIGH_PERF_DEFAULT_FPS, "probe: XTVERSION"),
+                (false, Some(_)) => (STANDARD_DEFAULT_FPS, "probe: multiplexer"),
+                (false, None) => (STANDARD_DEFAULT_FPS, "probe: XTVERSION"),
+            };
+            caps.dynamic_default_fps = fps;
+            caps.dynamic_fps_source = source;
+        }
+    }
+
+    /// Auto-detected color mode with the DECRQSS verdict applied: a kept
+    /// `48;2` confirms truecolor; a dropped one caps it at 256 colors.
+    #[must_use]
+    pub(crate) fn color_mode(&self, env_mode: ColorMode) -> ColorMode {
+        match self.truecolor {
+            Some(true) => ColorMode::TrueColor,
+            Some(false) if env_mode == ColorMode::TrueColor => ColorMode::Color256,
+            _ => env_mode,
+        }
+    }
+
+    /// `(key, value)` rows for the `--doctor` TERMINAL PROBE section.
+    #[must_use]
+    pub(crate) fn doctor_fields(&self) -> Vec<(&'static str, String)> {
+        let or_none =
+            |v: Option<String>, what: &str| v.unwrap_or_else(|| format!("(no {what} reply)"));
+        let list = |v: &[u16]| v.iter().map(u16::to_string).collect::<Vec<_>>().join(";");
+        let reported = self.palette.iter().filter(|c| c.is_some()).count();
+        vec![
+            ("terminal", or_none(self.xtversion.clone(), "XTVERSION")),
+            (
+                "multiplexer",
+                self.multiplexer().unwrap_or("none").to_string(),
+            ),
+            ("da1", or_none(self.da1.as_deref().map(list), "DA1")),
+            ("da2", or_none(self.da2.as_deref().map(list), "DA2")),
+            (
+                "sync_2026",
+                or_none(
+                    self.sync_2026.map(|ps| decrqm_label(ps).to_string()),
+                    "DECRQM",
+                ),
+            ),
+            (
+                "kitty_keyboard",
+                match self.kitty_flags {
+                    Some(flags) => format!("supported (flags {flags})"),
+                    None => "no reply (unsupported)".to_string(),
+                },
+            ),
+            (
+                "truecolor",
+                or_none(
+                    self.truecolor.map(|t| {
+                        if t {
+                            "confirmed"
+                        } else {
+                            "not kept (256-color)"
+                        }
+                        .to_string()
+                    }),
+                    "DECRQSS",
+                ),
+            ),
+            ("background", or_none(self.background.map(hex), "OSC 11")),
+            (
+                "palette",
+                if reported == 0 {
+                    "(no OSC 4 reply)".to_string()
+                } else {
+                    let colors: Vec<String> = self
+                        .palette
+                        .iter()
+                        .map(|c| c.map_or_else(|| "-".to_string(), hex))
+                        .collect();
+                    format!("{reported}/16: {}", colors.join(" "))
+                },
+            ),
+        ]
+    }
+}
+
+fn decrqm_label(ps: u16) -> &'static str {
+    match ps {
+        0 => "not recognized",
+        1 => "set",
+        2 => "reset (supported)",
+        3 => "permanently set",
+        4 => "permanently reset",
+        _ => "unknown state",
+    }
+}
+
+fn hex((r, g, b): (u8, u8, u8)) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+/// Parse everything the terminal sent back. Unrelated bytes (a key the
+/// user pressed mid-probe) are skipped; a truncated trailing sequence is
+/// ignored.
+#[must_use]
+pub(crate) fn parse_replies(buf: &[u8]) -> ProbeReport {
+    let mut report = ProbeReport::default();
+    let mut i = 0;
+    while i + 1 < buf.len() {
+        if buf[i] != 0x1b {
+            i += 1;
+            continue;
+        }
+        let start = i + 2;
+        match buf[i + 1] {
+            b'[' => {
+                let Some(len) = buf[start..].iter().position(|b| (0x40..=0x7e).contains(b)) else {
+                    break;
+                };
+                let end = start + len;
+                report.csi(&buf[start..end], buf[end]);
+                i = end + 1;
+            }
+            kind @ (b'P' | b']') => {
+                let Some((end, next)) = string_end(buf, start) else {
+                    break;
+                };
+                let body = String::from_utf8_lossy(&buf[start..end]);
+                if kind == b'P' {
+                    report.dcs(&body);
+                } else {
+                    report.osc(&body);
+                }
+                i = next;
+            }
+            _ => i += 1,
+        }
+    }
+    report
+}
+
+/// End of a DCS/OSC string body and the index after its terminator
+/// (`BEL` or `ESC \`).
+fn string_end(buf: &[u8], start: usize) -> Option<(usize, usize)> {
+    let mut j = start;
+    while j < buf.len() {
+        match buf[j] {
+            0x07 => return Some((j, j + 1)),
+            0x1b if buf.get(j + 1) == Some(&b'\\') => return Some((j, j + 2)),
+            _ => j += 1,
+        }
+    }
+    None
+}
+
+fn numbers(params: &str) -> Vec<u16> {
+    params.split(';').filter_map(|p| p.parse().ok()).collect()
+}
+
+impl ProbeReport {
+    fn csi(&mut self, body: &[u8], final_byte: u8) {
+        let text = std::str::from_utf8(body).unwrap_or("");
+        let (marker, params) = match text.as_bytes().first() {
+            Some(&m @ (b'?' | b'>')) => (m, &text[1..]),
+            _ => return,
+        };
+        match (marker, final_byte) {
+            (b'?', b'c') => self.da1 = Some(numbers(params)),
+            (b'>', b'c') => self.da2 = Some(numbers(params)),
+            (b'?', b'u') => self.kitty_flags = params.parse().ok(),
+            (b'?', b'y') => {
+                if let Some(rest) = params.strip_suffix('$') {
+                    if let [2026, ps] = numbers(rest)[..] {
+                        self.sync_2026 = Some(ps);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn dcs(&mut self, body: &str) {
+        if let Some(version) = body.strip_prefix(">|") {
+            self.xtversion = Some(version.trim().to_string());
+        } else if let Some((_, sgr)) = body.split_once("$r") {
+            // The validity digit before `$r` is inverted on older xterm
+            // builds, so judge by the payload: an SGR reply ends in `m`.
+            if let Some(sgr) = sgr.strip_suffix('m') {
+                let norm = format!(";{};", sgr.replace(':', ";"));
+                self.truecolor = Some(norm.contains(";48;2;"));
+            }
+        }
+    }
+
+    fn osc(&mut self, body: &str) {
+        if let Some(spec) = body.strip_prefix("11;") {
+            self.background = parse_color_spec(spec);
+        } else if let Some((index, spec)) = body.strip_prefix("4;").and_then(|r| r.split_once(';'))
+        {
+            if let Some(slot) = index
+                .parse::<usize>()
+                .ok()
+                .and_then(|n| self.palette.get_mut(n))
+            {
+                *slot = parse_color_spec(spec);
+            }
+        }
+    }
+}
+
+/// Background from `COLORFGBG` (`"15;0"`, `"default;default;0"`; set by
+/// rxvt, Konsole and a few others): the last field is an ANSI index,
+/// decoded at the xterm defaults. `None` for `default` or anything else.
+pub(crate) fn parse_colorfgbg(value: &str) -> Option<(u8, u8, u8)> {
+    let index: u8 = value.rsplit(';').next()?.trim().parse().ok()?;
+    (index < 16).then(|| crate::palette::color_to_rgb(Color::AnsiValue(index)))
+}
+
+/// `rgb:RRRR/GGGG/BBBB` (1–4 hex digits per channel; `rgba:` alpha
+/// ignored) scaled to 8 bits.
+fn parse_color_spec(spec: &str) -> Option<(u8, u8, u8)> {
+    let body = spec
+        .strip_prefix("rgb:")
+        .or_else(|| spec.strip_prefix("rgba:"))?;
+    let mut channels = body.split('/').map(|c| {
+        if c.is_empty() || c.len() > 4 {
+            return None;
+        }
+        let v = u32::from_str_radix(c, 16).ok()?;
+        let max = (1u32 << (4 * c.len())) - 1;
+        Some(((v * 255 + max / 2) / max) as u8)
+    });
+    Some((channels.next()??, channels.next()??, channels.next()??))
+}
+
+/// Send `query` ([`PROBE_QUERY`] or [`BACKGROUND_QUERY`]) to the
+/// controlling terminal and collect replies until the DA1 sentinel or
+/// `timeout`. Runs before `Terminal::init`, so
+/// it takes raw mode for itself and hands the tty back cooked.
+#[cfg(unix)]
+pub(super) fn run(query: &[u8], timeout: Duration) -> Result<ProbeReport, String> {
+    use std::fs::OpenOptions;
+
+    let term = std::env::var("TERM").unwrap_or_default();
+    // The Linux console reads `ESC ]` as its own palette commands, and a
+    // dumb terminal would print the queries.
+    if term.eq_ignore_ascii_case("linux") || term.eq_ignore_ascii_case("dumb") {
+        return Err(format!("not run on TERM={term}"));
+    }
+    let mut tty = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .map_err(|e| format!("no controlling terminal ({e})"))?;
+    crossterm::terminal::enable_raw_mode().map_err(|e| format!("raw mode unavailable ({e})"))?;
+    let result = exchange(&mut tty, query, timeout);
+    let _ = crossterm::terminal::disable_raw_mode();
+    result.map_err(|e| format!("tty i/o failed ({e})"))
+}
+
+#[cfg(not(unix))]
+pub(super) fn run(_query: &[u8], _timeout: Duration) -> Result<ProbeReport, String> {
+    Err("not supported on this platform".to_string())
+}
+
+#[cfg(unix)]
+fn exchange(
+    tty: &mut std::fs::File,
+    query: &[u8],
+    timeout: Duration,
+) -> std::io::Result<ProbeReport> {
+    use std::io::{Read, Write};
+    use std::os::fd::AsRawFd;
+    use std::time::Instant;
+
+    let started = Instant::now();
+    tty.write_all(query)?;
+    tty.flush()?;
+    let deadline = started + timeout;
+    let mut buf = Vec::with_capacity(1024);
+    let mut chunk = [0u8; 512];
+    loop {
+        let mut report = parse_replies(&buf);
+        let left = deadline.saturating_duration_since(Instant::now());
+        if report.answered() || left.is_zero() {
+            report.elapsed_ms = started.elapsed().as_millis() as u64;
+            return Ok(report);
+        }
+        let mut pfd = libc::pollfd {
+            fd: tty.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let wait_ms = left.as_millis().clamp(1, i32::MAX as u128) as libc::c_int;
+        // SAFETY: `pfd` is a valid pollfd for the open tty and outlives
+        // the call; nfds is 1.
+        let ready = unsafe { libc::poll(&mut pfd, 1, wait_ms) };
+        if ready < 0 {
+            let err = std::io::Error::last_os_error();
+            if err.kind() == std::io::ErrorKind::Interrupted {
+                continue;
+            }
+            return Err(err);
+        }
+        if ready == 0 {
+            continue;
+        }
+        let n = tty.read(&mut chunk)?;
+        if n == 0 {
+            let mut report = parse_replies(&buf);
+            report.elapsed_ms = started.elapsed().as_millis() as u64;
+            return Ok(report);
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+}
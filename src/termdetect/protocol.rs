@@ -69,3 +69,9 @@ pub(super) const HIGH_PERF_DEFAULT_FPS: f64 = 144.0;
 /// doesn't specify `--fps` or `fps =`. 60 FPS is the universal safe
 /// default that every terminal can sustain.
 pub(super) const STANDARD_DEFAULT_FPS: f64 = 60.0;
+
+/// How long the `--probe-terminal` exchange waits for the DA1 sentinel
+/// reply. Local terminals answer in well under 10 ms; the headroom is for
+/// SSH round trips. A terminal that never answers costs the full wait once
+/// at startup.
+pub(super) const PROBE_TIMEOUT_MS: u64 = 300;
@@ -111,6 +111,7 @@ fn sync_output_disabled_for_linux_console() {
         default_fps_cap: 240.0,
         dynamic_default_fps: 60.0,
         dynamic_fps_source: "test",
+        repeat_char: false,
     };
     assert!(!caps.sync_output);
     assert!(
@@ -146,6 +147,12 @@ fn alternate_screen_enabled_for_linux_console() {
         !caps.kitty_keyboard,
         "kitty_keyboard must stay disabled for Linux console (vt.c doesn't understand kitty protocol)"
     );
+    // repeat_char must stay OFF for Linux console — vt.c has no REP, so
+    // a collapsed run would render as a single glyph.
+    assert!(
+        !caps.repeat_char,
+        "repeat_char must stay disabled for Linux console"
+    );
 }
 
 #[test]
@@ -185,6 +192,7 @@ fn alternate_screen_enabled_for_xterm() {
         !caps.kitty_keyboard,
         "kitty_keyboard must stay disabled for generic xterm (conservative skip — support is version-dependent and TERM=xterm is the default for many non-xterm setups)"
     );
+    assert!(caps.repeat_char, "xterm-256color implements REP");
 }
 
 #[test]
@@ -864,3 +872,190 @@ fn dynamic_fps_source_records_fallback() {
         "non-high-perf terminal must record fallback source"
     );
 }
+
+// ── --probe-terminal: reply parsing and overrides ──
+
+fn probe_caps(kitty: bool, fps: f64) -> TerminalCaps {
+    TerminalCaps {
+        sync_output: true,
+        kitty_keyboard: kitty,
+        has_alternate_screen: true,
+        xtermjs_host: false,
+        vscode_integrated: false,
+        default_fps_cap: 240.0,
+        dynamic_default_fps: fps,
+        dynamic_fps_source: "TERM substring",
+        repeat_char: true,
+    }
+}
+
+#[test]
+fn probe_query_ends_with_da1_sentinel() {
+    assert!(PROBE_QUERY.ends_with(b"\x1b[c"));
+    for query in [
+        &b"\x1b[>0q"[..],
+        b"\x1b[>c",
+        b"\x1b[?2026$p",
+        b"\x1b[?u",
+        b"\x1bP$qm\x1b\\",
+        b"\x1b]11;?\x1b\\",
+        b"\x1b]4;15;?\x1b\\",
+    ] {
+        let at = PROBE_QUERY
+            .windows(query.len())
+            .position(|w| w == query)
+            .unwrap_or_else(|| panic!("missing {query:?}"));
+        assert!(at < PROBE_QUERY.len() - 3, "{query:?} must precede DA1");
+    }
+}
+
+#[test]
+fn probe_parses_a_full_reply_set() {
+    let replies = concat!(
+        "\x1bP>|kitty(0.35.2)\x1b\\",
+        "\x1b[>1;4000;29c",
+        "\x1b[?2026;2$y",
+        "\x1b[?0u",
+        "\x1bP1$r0;48:2:1:2:3m\x1b\\",
+        "\x1b]11;rgb:1e1e/1e1e/2e2e\x07",
+        "\x1b]4;0;rgb:0000/0000/0000\x1b\\",
+        "\x1b]4;9;rgb:f/0/8\x1b\\",
+        "\x1b[?62;22;52c",
+    );
+    let r = parse_replies(replies.as_bytes());
+    assert!(r.answered());
+    assert_eq!(r.xtversion.as_deref(), Some("kitty(0.35.2)"));
+    assert_eq!(r.terminal_name(), Some("kitty"));
+    assert_eq!(r.da1, Some(vec![62, 22, 52]));
+    assert_eq!(r.da2, Some(vec![1, 4000, 29]));
+    assert_eq!(r.sync_2026, Some(2));
+    assert_eq!(r.sync_output(), Some(true));
+    assert_eq!(r.kitty_flags, Some(0));
+    assert_eq!(r.kitty_keyboard(), Some(true));
+    assert_eq!(r.truecolor, Some(true));
+    assert_eq!(r.background, Some((0x1e, 0x1e, 0x2e)));
+    assert_eq!(r.palette[0], Some((0, 0, 0)));
+    // 1-digit channels scale to the full range: 8/15 → 136.
+    assert_eq!(r.palette[9], Some((255, 0, 136)));
+    assert_eq!(r.palette.iter().flatten().count(), 2);
+    assert_eq!(r.multiplexer(), None);
+    assert_eq!(r.high_perf(), Some(true));
+}
+
+#[test]
+fn probe_skips_noise_and_truncated_replies() {
+    // A keypress mid-probe, an unrelated CSI, then a DA1 reply and a
+    // half-received OSC at the end of the buffer.
+    let r = parse_replies(b"j\x1b[A\x1b[?1;2c\x1b]11;rgb:ff");
+    assert_eq!(r.da1, Some(vec![1, 2]));
+    assert_eq!(r.background, None);
+    assert_eq!(parse_replies(b""), ProbeReport::default());
+    assert!(!parse_replies(b"\x1b[>0;95;0c").answered());
+}
+
+#[test]
+fn probe_decrqm_and_decrqss_verdicts() {
+    let unknown = parse_replies(b"\x1b[?2026;0$y\x1b[?62c");
+    assert_eq!(unknown.sync_output(), Some(false));
+    // Answered DA1 but not DECRQM: no verdict on sync output.
+    let silent = parse_replies(b"\x1b[?62c");
+    assert_eq!(silent.sync_output(), None);
+    assert_eq!(silent.kitty_keyboard(), Some(false));
+    assert_eq!(silent.truecolor, None);
+    // The terminal dropped the 48;2 background it was given.
+    let dropped = parse_replies(b"\x1bP1$r0m\x1b\\\x1b[?62c");
+    assert_eq!(dropped.truecolor, Some(false));
+    // Semicolon form, and the inverted validity digit of older xterm.
+    let semi = parse_replies(b"\x1bP0$r0;48;2;1;2;3m\x1b\\");
+    assert_eq!(semi.truecolor, Some(true));
+}
+
+#[test]
+fn probe_overrides_heuristics_inside_tmux() {
+    // TERM=xterm-kitty leaked into a tmux pane: the env says kitty at
+    // 144 FPS, but tmux answers the queries itself.
+    let r = parse_replies(b"\x1bP>|tmux 3.4\x1b\\\x1b[>84;0;0c\x1b[?2026;2$y\x1b[?1;2;4c");
+    assert_eq!(r.multiplexer(), Some("tmux"));
+    let mut caps = probe_caps(true, HIGH_PERF_DEFAULT_FPS);
+    r.apply(&mut caps);
+    assert!(!caps.kitty_keyboard, "tmux did not answer CSI ? u");
+    assert!(caps.sync_output);
+    assert_eq!(caps.dynamic_default_fps, STANDARD_DEFAULT_FPS);
+    assert_eq!(caps.dynamic_fps_source, "probe: multiplexer");
+}
+
+#[test]
+fn probe_promotes_a_terminal_behind_a_generic_term() {
+    // Container shell with TERM=xterm inside WezTerm.
+    let r = parse_replies(b"\x1bP>|WezTerm 20240203\x1b\\\x1b[?1u\x1b[?65;4c");
+    let mut caps = probe_caps(false, STANDARD_DEFAULT_FPS);
+    caps.sync_output = false;
+    r.apply(&mut caps);
+    assert!(caps.kitty_keyboard);
+    assert!(!caps.sync_output, "no DECRQM reply keeps the heuristic");
+    assert_eq!(caps.dynamic_default_fps, HIGH_PERF_DEFAULT_FPS);
+    assert_eq!(caps.dynamic_fps_source, "probe: XTVERSION");
+}
+
+#[test]
+fn probe_detects_xtermjs_and_keeps_its_caps() {
+    let r = parse_replies(b"\x1bP>|xterm.js(5.5.0)\x1b\\\x1b[?2026;2$y\x1b[?1;2c");
+    let mut caps = probe_caps(true, HIGH_PERF_DEFAULT_FPS);
+    r.apply(&mut caps);
+    assert!(caps.xtermjs_host);
+    assert!(!caps.sync_output && !caps.kitty_keyboard);
+    assert_eq!(caps.default_fps_cap, XTERMJS_FPS_CAP);
+    assert_eq!(caps.dynamic_default_fps, XTERMJS_FPS_CAP);
+
+    // Already known from TERM_PROGRAM: a positive DECRQM must not turn
+    // sync output back on.
+    let mut host = probe_caps(false, XTERMJS_FPS_CAP);
+    host.xtermjs_host = true;
+    host.sync_output = false;
+    parse_replies(b"\x1b[?2026;2$y\x1b[?1u\x1b[?1;2c").apply(&mut host);
+    assert!(!host.sync_output && !host.kitty_keyboard);
+}
+
+#[test]
+fn probe_without_reply_keeps_heuristics() {
+    let mut caps = probe_caps(true, HIGH_PERF_DEFAULT_FPS);
+    ProbeReport::default().apply(&mut caps);
+    assert!(caps.kitty_keyboard && caps.sync_output);
+    assert_eq!(caps.dynamic_fps_source, "TERM substring");
+}
+
+#[test]
+fn probe_truecolor_verdict_adjusts_color_mode() {
+    use crate::runtime::ColorMode;
+    let kept = parse_replies(b"\x1bP1$r48:2::1:2:3m\x1b\\");
+    assert_eq!(kept.color_mode(ColorMode::Color256), ColorMode::TrueColor);
+    let dropped = parse_replies(b"\x1bP1$r0m\x1b\\");
+    assert_eq!(
+        dropped.color_mode(ColorMode::TrueColor),
+        ColorMode::Color256
+    );
+    assert_eq!(dropped.color_mode(ColorMode::Color16), ColorMode::Color16);
+    let none = ProbeReport::default();
+    assert_eq!(none.color_mode(ColorMode::Color16), ColorMode::Color16);
+}
+
+// ── default-background: terminal background query ──
+
+#[test]
+fn background_query_asks_osc11_then_da1() {
+    assert!(BACKGROUND_QUERY.starts_with(b"\x1b]11;?\x1b\\"));
+    assert!(BACKGROUND_QUERY.ends_with(b"\x1b[c"));
+    let report = parse_replies(b"\x1b]11;rgb:fdfd/f6f6/e3e3\x07\x1b[?62;22c");
+    assert!(report.answered());
+    assert_eq!(report.background, Some((253, 246, 227)));
+}
+
+#[test]
+fn colorfgbg_background_index_decodes_to_ansi_rgb() {
+    assert_eq!(parse_colorfgbg("0;15"), Some((255, 255, 255)));
+    assert_eq!(parse_colorfgbg("15;0"), Some((0, 0, 0)));
+    assert_eq!(parse_colorfgbg("default;default;7"), Some((192, 192, 192)));
+    assert_eq!(parse_colorfgbg("15;default"), None);
+    assert_eq!(parse_colorfgbg("0;16"), None);
+    assert_eq!(parse_colorfgbg(""), None);
+}
@@ -3,7 +3,8 @@
 
 //! Config file validation (`--testconf` command).
 //!
-//! Reads `~/.config/cosmostrix/config` (or `--config PATH`) and reports:
+//! Reads `~/.config/cosmostrix/config` (or `--config PATH`), with its
+//! includes and `conf.d/` drop-ins, and reports:
 //!   - Unknown keys (likely typos)
 //!   - Malformed scene-custom keys
 //!   - Out-of-range values for known numeric keys
@@ -11,6 +12,7 @@
 //!
 //! Exit code 0 = PASS, 2 = FAIL (errors found).
 
+use crate::config_schema::ConfigValue;
 use crate::configfile;
 use crate::theme;
 use crate::Args;
@@ -109,23 +111,41 @@ pub(crate) fn run(args: &Args) -> std::io::Result<()> {
         }
     }
 
-    let parsed = configfile::parse_config_text(&content);
+    // The file plus its includes and conf.d drop-ins, merged the same way
+    // startup and live reload merge them.
+    let layers = configfile::load_config_layers(Some(&path));
+    for layer in layers.files.iter().filter(|f| **f != path) {
+        println!("testconf: layer: {}", layer.display());
+    }
+    let parsed = layers.parsed;
     let mut errors = 0usize;
     let mut warnings = 0usize;
 
-    // Check for malformed lines (non-empty, non-comment lines without 'key = value')
+    // Includes / drop-ins that could not be merged in.
+    for msg in &parsed.include_errors {
+        crate::output::eprintln_error_labeled(&format!("testconf: {msg}"));
+        errors += 1;
+    }
+
+    // Check for malformed lines (lines the TOML parser rejected)
     if !parsed.malformed_lines.is_empty() {
         for line in &parsed.malformed_lines {
             crate::output::eprintln_error_labeled(&format!(
-                "testconf: malformed line '{line}' (expected 'key = value' syntax)"
+                "testconf: malformed line '{line}' (not valid TOML)"
             ));
             errors += 1;
         }
         eprintln!(
-            "testconf: hint: comment lines start with '#', blank lines are ignored, all other lines must be 'key = value'"
+            "testconf: hint: comment lines start with '#', blank lines are ignored, all other lines must be 'key = value' or a [section] header"
         );
     }
 
+    // Known keys holding the wrong TOML type (e.g. `fps = true`).
+    for (key, msg) in &parsed.type_errors {
+        crate::output::eprintln_error_labeled(&format!("testconf: wrong type for '{key}': {msg}"));
+        errors += 1;
+    }
+
     // Check for unknown keys (likely typos)
     if !parsed.unknown_keys.is_empty() {
         for key in &parsed.unknown_keys {
@@ -143,7 +163,7 @@ pub(crate) fn run(args: &Args) -> std::io::Result<()> {
         }
         eprintln!(
             "testconf: known keys: {}",
-            configfile::known_keys().join(", ")
+            crate::config_schema::known_keys().join(", ")
         );
     }
 
@@ -164,6 +184,18 @@ pub(crate) fn run(args: &Args) -> std::io::Result<()> {
         );
     }
 
+    // Info-only notice for legacy syntax rewritten before parsing (e.g. an
+    // unquoted `scene = calm`). Still accepted; quoting makes it plain TOML.
+    if !parsed.legacy_notes.is_empty() {
+        println!(
+            "testconf: info: {} line(s) use pre-TOML syntax (accepted):",
+            parsed.legacy_notes.len()
+        );
+        for note in &parsed.legacy_notes {
+            println!("testconf:   {note}");
+        }
+    }
+
     // Check scene-custom block keys for correct format AND field value validity
     let block_keys: Vec<_> = parsed
         .values
@@ -180,7 +212,9 @@ pub(crate) fn run(args: &Args) -> std::io::Result<()> {
             errors += 1;
         } else {
             let field = parts[2];
-            let value = parsed.values.get(*pk).map(String::as_str).unwrap_or("");
+            let Some(value) = parsed.values.get(*pk) else {
+                continue;
+            };
             // Use the canonical PROFILE_FIELDS list so testconf never drifts
             // from the actual config parser. Previously this was a hardcoded
             // copy that missed 'density-map' when it was added to PROFILE_FIELDS.
@@ -258,7 +292,7 @@ pub(crate) fn run(args: &Args) -> std::io::Result<()> {
         // chars, wide-char filter). Mirrors the strict-validation path.
         // Cosmic Dragon principle: wide-char rejection is permanent.
         if key.starts_with("charset-custom.") {
-            if let Some(msg) = crate::charset_custom::validate_charset_custom_value(value) {
+            if let Some(msg) = validate_charset_custom(value) {
                 crate::output::eprintln_error_labeled(&format!("testconf: {key} = {value}: {msg}"));
                 errors += 1;
             }
@@ -267,8 +301,8 @@ pub(crate) fn run(args: &Args) -> std::io::Result<()> {
         // v25: top-level `charset` may name a custom block. Accept if the
         // block exists in this same config — the block's content was
         // validated in the branch above.
-        if key == "charset" {
-            let normalized = value.trim().to_ascii_lowercase();
+        if let (true, Some(name)) = (key == "charset", value.as_str()) {
+            let normalized = name.trim().to_ascii_lowercase();
             let custom_key = format!("charset-custom.{normalized}.set");
             if parsed.values.contains_key(&custom_key) {
                 continue;
@@ -317,7 +351,7 @@ pub(crate) fn run(args: &Args) -> std::io::Result<()> {
 /// - Live reload: watcher rejects invalid config edits (exit code 2).
 /// - --testconf: validates and reports errors.
 pub(crate) fn validate_config_strictly(
-    cfg: &std::collections::HashMap<String, String>,
+    cfg: &crate::config_schema::ConfigMap,
 ) -> Result<(), String> {
     for (key, value) in cfg {
         if key.starts_with("scene-custom.") {
@@ -338,7 +372,7 @@ pub(crate) fn validate_config_strictly(
         // key pattern, we only need to validate the value.
         // Cosmic Dragon principle: wide-char rejection is permanent.
         if key.starts_with("charset-custom.") {
-            if let Some(msg) = crate::charset_custom::validate_charset_custom_value(value) {
+            if let Some(msg) = validate_charset_custom(value) {
                 return Err(format!("invalid value for '{key}': {msg}"));
             }
             continue;
@@ -358,8 +392,8 @@ pub(crate) fn validate_config_strictly(
         // block (charset-custom.<name>) instead of a built-in preset.
         // Accept the value if it matches a defined custom block — the
         // block's content was already validated above.
-        if key == "charset" {
-            let normalized = value.trim().to_ascii_lowercase();
+        if let (true, Some(name)) = (key == "charset", value.as_str()) {
+            let normalized = name.trim().to_ascii_lowercase();
             let custom_key = format!("charset-custom.{normalized}.set");
             if cfg.contains_key(&custom_key) {
                 continue;
@@ -372,37 +406,26 @@ pub(crate) fn validate_config_strictly(
     Ok(())
 }
 
-/// Validate a colors-custom value (hex color or comma-separated hex stops).
+/// Validate a colors-custom value: one hex color for `bg`, a list of them
+/// for `rain` / `stops`.
 ///
 /// Accepted formats:
 /// - `#rrggbb` (standard hex with #)
 /// - `rrggbb` (hex without #)
 /// - `#rgb` (short hex with #)
 /// - `rgb` (short hex without #)
-/// - `"#rrggbb"` (quoted — quotes stripped before parsing)
-///
-/// For `stops` field: comma-separated list of the above.
-fn validate_colors_custom_value(key: &str, value: &str) -> Option<String> {
-    let trimmed = value.trim();
-    if trimmed.is_empty() {
-        return Some("empty color value".to_string());
-    }
-
-    // stops/rain field: hex list (array or CSV format).
+fn validate_colors_custom_value(key: &str, value: &ConfigValue) -> Option<String> {
+    // stops/rain field: hex list (array or CSV format, split by config_schema).
     if key.ends_with(".stops") || key.ends_with(".rain") {
-        // v25: handle TOML array format. If value starts with '[', strip
-        // the brackets before splitting by comma. This matches the
-        // parse_rain_array logic in colors_custom.rs.
-        let inner = if trimmed.starts_with('[') {
-            let s = trimmed.strip_prefix('[').unwrap_or(trimmed);
-            let s = s.strip_suffix(']').unwrap_or(s);
-            s
-        } else {
-            trimmed
+        let Some(stops) = value.items() else {
+            return Some(format!("expected an array of hex colors, got {value}"));
         };
-        for stop in inner.split(',') {
-            let s = stop.trim().trim_matches('"').trim();
-            // Skip empty stops (trailing comma after ] strip).
+        if stops.iter().all(|stop| stop.trim().is_empty()) {
+            return Some("empty color value".to_string());
+        }
+        for stop in stops {
+            let s = stop.trim();
+            // Skip empty stops (trailing comma in the CSV form).
             if s.is_empty() {
                 continue;
             }
@@ -415,16 +438,28 @@ fn validate_colors_custom_value(key: &str, value: &str) -> Option<String> {
         return None;
     }
 
-    // single color field — strip quotes before validation
-    let unquoted = trimmed.trim_matches('"').trim();
-    if !is_valid_hex_color(unquoted) {
+    let Some(color) = value.as_str().map(str::trim) else {
+        return Some(format!("expected one hex color, got {value}"));
+    };
+    if color.is_empty() {
+        return Some("empty color value".to_string());
+    }
+    if !is_valid_hex_color(color) {
         return Some(format!(
-            "invalid hex color '{unquoted}' (expected #rrggbb or rrggbb)"
+            "invalid hex color '{color}' (expected #rrggbb or rrggbb)"
         ));
     }
     None
 }
 
+/// `charset-custom.<name>.set` content check on a typed value.
+fn validate_charset_custom(value: &ConfigValue) -> Option<String> {
+    match value.as_str() {
+        Some(set) => crate::charset_custom::validate_charset_custom_value(set),
+        None => Some(format!("expected a string of characters, got {value}")),
+    }
+}
+
 /// Check if a string is a valid hex color (#rrggbb, rrggbb, #rgb, or rgb).
 fn is_valid_hex_color(s: &str) -> bool {
     let s = s.strip_prefix('#').unwrap_or(s);
@@ -441,7 +476,36 @@ fn is_valid_hex_color(s: &str) -> bool {
 /// `scene-custom.<name>.<field>` block values. The caller is responsible
 /// for mapping block-specific field names (e.g. `base` -> `scene`) before
 /// calling this function.
-pub(crate) fn validate_field_value(key: &str, value: &str) -> Option<String> {
+pub(crate) fn validate_field_value(key: &str, value: &ConfigValue) -> Option<String> {
+    // Sections with list fields read the typed value themselves.
+    match key {
+        // [rain-tuning]: ranges live next to the constants they override
+        // (central_control_rains/tuning.rs), shared with config_apply.
+        k if crate::constants::is_rain_tuning_key(k) => {
+            return crate::constants::validate_rain_tuning_value(k, value)
+        }
+        // [events]: per-kind chance / cooldown, decrypt word list.
+        k if crate::constants::is_event_key(k) => {
+            return crate::constants::validate_event_value(k, value)
+        }
+        // [keys]: key spec syntax; conflicts between actions run in
+        // validate_field_value_with_cfg.
+        k if crate::interactive::keymap::is_keys_key(k) => {
+            return crate::interactive::keymap::validate_keys_value(k, value)
+        }
+        crate::crystal_dragon_engine::crystal_dragon_control::SENSOR_CONFIG_KEY => {
+            return crate::crystal_dragon_engine::crystal_dragon_control::validate_sensor_list(
+                value,
+            )
+        }
+        "ambient-location" => {
+            return crate::crystal_dragon_engine::ambient::GeoLocation::from_config(value).err()
+        }
+        _ => {}
+    }
+    let Some(value) = value.as_str() else {
+        return Some(format!("expected a single value, got {value}"));
+    };
     let v = value.trim();
     match key {
         // ── Numeric ranges ──
@@ -526,9 +590,21 @@ pub(crate) fn validate_field_value(key: &str, value: &str) -> Option<String> {
                     }
                 })
         }
+        // [power-dragon]: per-field ranges; the ordering rules between
+        // paired thresholds run in validate_field_value_with_cfg.
+        k if crate::constants::is_power_dragon_key(k) => {
+            crate::constants::validate_power_dragon_value(k, v)
+        }
         // v17 mastery: legacy advanced keys (glitchpct, shortpct, rippct,
         // maxdpc) are REMOVED — they fall into unknown_keys and are rejected
         // by --testconf and at startup. Use --glitch-level instead.
+        "seed" => match v.parse::<u64>() {
+            Ok(_) => None,
+            Err(_) => Some(format!(
+                "expected integer in range 0..={}, got '{v}'",
+                u64::MAX
+            )),
+        },
         "bold" => match v {
             "0" | "1" | "2" => None,
             _ => Some(format!("expected 0, 1, or 2, got '{v}'")),
@@ -576,6 +652,11 @@ pub(crate) fn validate_field_value(key: &str, value: &str) -> Option<String> {
             }
             None
         }
+        // 2D density mask: the file form is read here, so a missing or
+        // malformed image fails --testconf instead of silently dropping
+        // the mask at startup. Untrimmed: ASCII-art indentation is shape.
+        "density-mask" => crate::scene_custom::DensityMask::from_config(value).err(),
+        "density-mask-floor" => crate::scene_custom::parse_density_mask_floor(v).err(),
 
         // ── Enum-like string values ──
         "color" => {
@@ -608,7 +689,7 @@ pub(crate) fn validate_field_value(key: &str, value: &str) -> Option<String> {
             }
         }
         // (CLI-D-3): removed dead validators for `atmosphere-regime` /
-        // `atmosphere-mode` — these keys are NOT in USER_CONFIG_KEYS (eliminated
+        // `atmosphere-mode` — these keys are NOT in config_schema::ROOT_KEYS (eliminated
         // at commit 07b44b5), so they fall into `unknown_keys` at parse time
         // and never reach `validate_field_value`. The migration hints now live
         // in `config_hints::suggest_for_unknown_key` (which fires when the
@@ -642,10 +723,11 @@ pub(crate) fn validate_field_value(key: &str, value: &str) -> Option<String> {
                 _ => Some(format!("expected black/default-background, got '{v}'")),
             }
         }
+        "color-dither" => crate::config_apply::parse_color_dither(v).err(),
         // Phase D Bug #1 fix: accept the same lenient set as parse_bool_config
         // (true/yes/on/1/false/no/off/0, case-insensitive). (CLI-D-3):
         // removed dead `low-power` / `mouse` from this arm (no longer in
-        // USER_CONFIG_KEYS — caught as unknown_keys upstream).
+        // config_schema::ROOT_KEYS — caught as unknown_keys upstream).
         "async-mode" => {
             let lower = v.trim().to_ascii_lowercase();
             match lower.as_str() {
@@ -677,13 +759,29 @@ pub(crate) fn validate_field_value(key: &str, value: &str) -> Option<String> {
                 )),
             }
         }
+        "crystal-dragon-calc" => {
+            if crate::crystal_dragon_engine::crystal_dragon_control::CrystalDragonCalcMethod::from_config(v).is_some() {
+                None
+            } else {
+                Some(format!("expected v1/v2, got '{v}'"))
+            }
+        }
+        "crystal-dragon-sensor-mix" => {
+            crate::crystal_dragon_engine::crystal_dragon_control::validate_sensor_mix(v)
+        }
+        "crystal-dragon-external" => {
+            crate::crystal_dragon_engine::external::validate_external_value(v)
+        }
+        "ambient-transition" => {
+            crate::crystal_dragon_engine::ambient::parse_ambient_transition(v).err()
+        }
         // (CLI-V-2): scene-custom `async-mode` field validator — now unified
         // with the top-level `async-mode` match arm above (same validation).
         // Previously this was a separate `"async"` arm; renaming to `async-mode`
         // merged the two into one match arm.
         // Bool config keys: accept the same lenient set as parse_bool_config
         // (true/yes/on/1/false/no/off/0, case-insensitive).
-        "crystal-dragon" | "power-dragon" => {
+        "crystal-dragon" | "power-dragon" | "probe-terminal" => {
             let lower = v.trim().to_ascii_lowercase();
             match lower.as_str() {
                 "true" | "yes" | "on" | "1" | "false" | "no" | "off" | "0" => None,
@@ -731,9 +829,23 @@ pub(crate) fn validate_field_value(key: &str, value: &str) -> Option<String> {
 /// field is the canonical way to reference a custom block.
 pub(crate) fn validate_field_value_with_cfg(
     key: &str,
-    value: &str,
-    cfg: &std::collections::HashMap<String, String>,
+    value: &ConfigValue,
+    cfg: &crate::config_schema::ConfigMap,
 ) -> Option<String> {
+    // [keys] conflicts (one key bound to two actions) need the siblings.
+    if crate::interactive::keymap::is_keys_key(key) {
+        return crate::interactive::keymap::validate_keys_with_cfg(key, value, cfg);
+    }
+    // `external` in crystal-dragon-sensor needs crystal-dragon-external.
+    if key == crate::crystal_dragon_engine::crystal_dragon_control::SENSOR_CONFIG_KEY {
+        return crate::crystal_dragon_engine::crystal_dragon_control::validate_sensor_with_cfg(
+            value, cfg,
+        );
+    }
+    let typed = value;
+    let Some(value) = typed.as_str() else {
+        return validate_field_value(key, typed);
+    };
     // (CLI-V-2): scene-custom block-reference validators. These need
     // cfg to check whether the referenced [colors-custom.<name>] /
     // [charset-custom.<name>] block exists in this config. Previously fell
@@ -782,7 +894,12 @@ pub(crate) fn validate_field_value_with_cfg(
              Use --list-colors to see available themes."
         ));
     }
-    let base = validate_field_value(key, value)?;
+    // [power-dragon] ordering rules (pressure-low < pressure-high, ...)
+    // need the sibling keys.
+    if crate::constants::is_power_dragon_key(key) {
+        return crate::constants::validate_power_dragon_with_cfg(key, value, cfg);
+    }
+    let base = validate_field_value(key, typed)?;
     // Base validation FAILED — `base` holds the plain error message. Try to
     // enrich it with a context-aware hint before returning.
     let trimmed = value.trim();
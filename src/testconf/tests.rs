@@ -2,12 +2,13 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
 use super::*;
+use crate::config_schema::value_from_text;
 
 // ── Bug regression: charset = hackeres must error ──
 
 #[test]
 fn charset_typo_is_rejected() {
-    let msg = validate_field_value("charset", "hackeres");
+    let msg = validate_field_value("charset", &"hackeres".into());
     assert!(
         msg.is_some(),
         "'hackeres' (typo) must be rejected for charset"
@@ -29,7 +30,7 @@ fn charset_valid_values_pass() {
         "binary", "matrix", "katakana", "hacker", "minimal", "retro", "zen",
     ] {
         assert!(
-            validate_field_value("charset", v).is_none(),
+            validate_field_value("charset", &v.into()).is_none(),
             "'{v}' should be a valid charset"
         );
     }
@@ -39,7 +40,7 @@ fn charset_valid_values_pass() {
 
 #[test]
 fn intro_typo_is_rejected() {
-    let msg = validate_field_value("intro", "logoo");
+    let msg = validate_field_value("intro", &"logoo".into());
     assert!(msg.is_some(), "'logoo' (typo) must be rejected for intro");
     let msg = msg.expect("checked Some above");
     assert!(
@@ -56,7 +57,7 @@ fn intro_typo_is_rejected() {
 fn intro_valid_values_pass() {
     for v in ["cosmic", "logo", "none"] {
         assert!(
-            validate_field_value("intro", v).is_none(),
+            validate_field_value("intro", &v.into()).is_none(),
             "'{v}' should be a valid intro"
         );
     }
@@ -73,7 +74,7 @@ fn intro_case_insensitive_matches_cli_valueenum() {
         "cosmic", "Cosmic", "COSMIC", "logo", "Logo", "LOGO", "none", "None", "NONE",
     ] {
         assert!(
-            validate_field_value("intro", v).is_none(),
+            validate_field_value("intro", &v.into()).is_none(),
             "'{v}' should be accepted (case-insensitive, matching CLI)"
         );
     }
@@ -82,11 +83,11 @@ fn intro_case_insensitive_matches_cli_valueenum() {
 #[test]
 fn intro_empty_value_is_rejected() {
     assert!(
-        validate_field_value("intro", "").is_some(),
+        validate_field_value("intro", &"".into()).is_some(),
         "empty intro must be rejected"
     );
     assert!(
-        validate_field_value("intro", "   ").is_some(),
+        validate_field_value("intro", &"   ".into()).is_some(),
         "whitespace-only intro must be rejected"
     );
 }
@@ -95,7 +96,7 @@ fn intro_empty_value_is_rejected() {
 
 #[test]
 fn fps_out_of_range_is_rejected() {
-    assert!(validate_field_value("fps", "0").is_some());
+    assert!(validate_field_value("fps", &"0".into()).is_some());
     // cap reverted 300 -> 240. 241 is the new reject edge; 240
     // is the highest valid value. Rationale: 240 matches the most
     // common high-refresh monitor rate, aligns with the project's own
@@ -104,57 +105,59 @@ fn fps_out_of_range_is_rejected() {
     // (README.md:329: "--fps <1-240>"). The 300 cap (commit 12629eb)
     // matched no monitor refresh rate and exceeded the project's own
     // stated terminal ceiling.
-    assert!(validate_field_value("fps", "241").is_some());
-    assert!(validate_field_value("fps", "60").is_none());
-    assert!(validate_field_value("fps", "240").is_none());
+    assert!(validate_field_value("fps", &"241".into()).is_some());
+    assert!(validate_field_value("fps", &"60".into()).is_none());
+    assert!(validate_field_value("fps", &"240".into()).is_none());
 }
 
 #[test]
 fn fps_non_numeric_is_rejected() {
-    let msg = validate_field_value("fps", "fast");
+    let msg = validate_field_value("fps", &"fast".into());
     assert!(msg.is_some(), "'fast' must be rejected for fps");
 }
 
 #[test]
 fn speed_out_of_range_is_rejected() {
-    assert!(validate_field_value("speed", "0").is_some());
-    assert!(validate_field_value("speed", "101").is_some());
-    assert!(validate_field_value("speed", "30").is_none());
+    assert!(validate_field_value("speed", &"0".into()).is_some());
+    assert!(validate_field_value("speed", &"101".into()).is_some());
+    assert!(validate_field_value("speed", &"30".into()).is_none());
 }
 
 #[test]
 fn density_out_of_range_is_rejected() {
-    assert!(validate_field_value("density", "0.001").is_some());
-    assert!(validate_field_value("density", "5.5").is_some());
-    assert!(validate_field_value("density", "0.85").is_none());
+    assert!(validate_field_value("density", &"0.001".into()).is_some());
+    assert!(validate_field_value("density", &"5.5".into()).is_some());
+    assert!(validate_field_value("density", &"0.85".into()).is_none());
 }
 
 // v30 simplify: density-map validation at --testconf time.
 #[test]
 fn density_map_valid_csv_passes() {
-    assert!(validate_field_value("density-map", "1.0,0.5,0.0,0.8").is_none());
-    assert!(validate_field_value("density-map", "0.85").is_none()); // single entry
-    assert!(validate_field_value("density-map", "  0.1 , 0.2 , 0.3  ").is_none()); // whitespace
-    assert!(validate_field_value("density-map", "1.0,,0.5,,").is_none()); // empty entries skipped
+    assert!(validate_field_value("density-map", &"1.0,0.5,0.0,0.8".into()).is_none());
+    assert!(validate_field_value("density-map", &"0.85".into()).is_none()); // single entry
+    assert!(validate_field_value("density-map", &"  0.1 , 0.2 , 0.3  ".into()).is_none()); // whitespace
+    assert!(validate_field_value("density-map", &"1.0,,0.5,,".into()).is_none());
+    // empty entries skipped
 }
 
 #[test]
 fn density_map_non_numeric_is_rejected() {
-    let err = validate_field_value("density-map", "abc,def").expect("non-numeric should fail");
+    let err =
+        validate_field_value("density-map", &"abc,def".into()).expect("non-numeric should fail");
     assert!(err.contains("expected float"), "got: {err}");
     assert!(err.contains("abc"), "got: {err}");
 }
 
 #[test]
 fn density_map_out_of_range_is_rejected() {
-    let err = validate_field_value("density-map", "0.5,1.5,0.0").expect("oob should fail");
+    let err = validate_field_value("density-map", &"0.5,1.5,0.0".into()).expect("oob should fail");
     assert!(err.contains("out of range"), "got: {err}");
     assert!(err.contains("1.5"), "got: {err}");
 }
 
 #[test]
 fn density_map_empty_is_rejected() {
-    let err = validate_field_value("density-map", ",,,").expect("empty should fail");
+    let err = validate_field_value("density-map", &",,,".into()).expect("empty should fail");
     assert!(err.contains("at least one"), "got: {err}");
 }
 
@@ -164,17 +167,17 @@ fn density_map_empty_is_rejected() {
 fn density_map_quoted_csv_passes() {
     // Double-quoted form (most common user mistake).
     assert!(
-        validate_field_value("density-map", "\"0.05,0.3,1.0\"").is_none(),
+        validate_field_value("density-map", &"\"0.05,0.3,1.0\"".into()).is_none(),
         "double-quoted CSV should pass --testconf"
     );
     // Single-quoted form.
     assert!(
-        validate_field_value("density-map", "'0.1, 0.2, 0.3'").is_none(),
+        validate_field_value("density-map", &"'0.1, 0.2, 0.3'".into()).is_none(),
         "single-quoted CSV should pass --testconf"
     );
     // Quoted + outer whitespace.
     assert!(
-        validate_field_value("density-map", "  \"0.5,0.5\"  ").is_none(),
+        validate_field_value("density-map", &"  \"0.5,0.5\"  ".into()).is_none(),
         "quoted CSV with whitespace padding should pass --testconf"
     );
 }
@@ -182,11 +185,11 @@ fn density_map_quoted_csv_passes() {
 #[test]
 fn density_map_quoted_empty_is_rejected() {
     assert!(
-        validate_field_value("density-map", "\"\"").is_some(),
+        validate_field_value("density-map", &"\"\"".into()).is_some(),
         "quoted empty string should fail --testconf"
     );
     assert!(
-        validate_field_value("density-map", "''").is_some(),
+        validate_field_value("density-map", &"''".into()).is_some(),
         "single-quoted empty string should fail --testconf"
     );
 }
@@ -194,7 +197,7 @@ fn density_map_quoted_empty_is_rejected() {
 #[test]
 fn density_map_quoted_non_numeric_is_rejected() {
     // The error message should refer to the `unquoted` entry, not `"abc`.
-    let err = validate_field_value("density-map", "\"abc,def\"").expect("should fail");
+    let err = validate_field_value("density-map", &"\"abc,def\"".into()).expect("should fail");
     assert!(err.contains("expected float"), "got: {err}");
     assert!(err.contains("abc"), "got: {err}");
     // Make sure the error does NOT include a stray quote character.
@@ -208,7 +211,7 @@ fn density_map_quoted_non_numeric_is_rejected() {
 
 #[test]
 fn color_unknown_is_rejected() {
-    let msg = validate_field_value("color", "not-a-color");
+    let msg = validate_field_value("color", &"not-a-color".into());
     assert!(msg.is_some());
     assert!(msg.unwrap().contains("unknown color"));
 }
@@ -223,12 +226,12 @@ fn color_matching_custom_palette_gets_colors_custom_hint() {
     // is the name of a [colors-custom.z] block — not a built-in color.
     // The error must point them at the `colors-custom` field.
     let mut cfg = std::collections::HashMap::new();
-    cfg.insert("colors-custom.z.bg".to_string(), "#0a0a0a".to_string());
+    cfg.insert("colors-custom.z.bg".to_string(), "#0a0a0a".into());
     cfg.insert(
         "colors-custom.z.rain".to_string(),
-        "#111111,#1ee460".to_string(),
+        crate::config_schema::value_from_text("colors-custom.z.rain", "#111111,#1ee460"),
     );
-    let msg = validate_field_value_with_cfg("color", "z", &cfg)
+    let msg = validate_field_value_with_cfg("color", &"z".into(), &cfg)
         .expect("should still error — z is not a built-in color");
     assert!(
         msg.contains("custom palette"),
@@ -249,8 +252,8 @@ fn color_matching_custom_palette_only_bg_field_still_hinted() {
     // A partially-declared [colors-custom.<name>] block (only `bg`, no
     // `rain`) still counts as a custom palette for hint purposes.
     let mut cfg = std::collections::HashMap::new();
-    cfg.insert("colors-custom.sunset.bg".to_string(), "#1a0033".to_string());
-    let msg = validate_field_value_with_cfg("color", "sunset", &cfg)
+    cfg.insert("colors-custom.sunset.bg".to_string(), "#1a0033".into());
+    let msg = validate_field_value_with_cfg("color", &"sunset".into(), &cfg)
         .expect("should error — sunset is not a built-in color");
     assert!(
         msg.contains("colors-custom = sunset"),
@@ -266,9 +269,9 @@ fn color_matching_custom_palette_via_legacy_stops_field_still_hinted() {
     let mut cfg = std::collections::HashMap::new();
     cfg.insert(
         "colors-custom.legacy.stops".to_string(),
-        "#ff0000,#00ff00".to_string(),
+        crate::config_schema::value_from_text("colors-custom.legacy.stops", "#ff0000,#00ff00"),
     );
-    let msg = validate_field_value_with_cfg("color", "legacy", &cfg)
+    let msg = validate_field_value_with_cfg("color", &"legacy".into(), &cfg)
         .expect("should error — legacy is not a built-in color");
     assert!(
         msg.contains("colors-custom = legacy"),
@@ -281,7 +284,7 @@ fn color_unknown_with_no_matching_palette_keeps_plain_error() {
     // No [colors-custom.<name>] block exists for this value — the hint
     // must NOT fire. The plain "unknown color" error is returned.
     let cfg = std::collections::HashMap::new();
-    let msg = validate_field_value_with_cfg("color", "not-a-color", &cfg)
+    let msg = validate_field_value_with_cfg("color", &"not-a-color".into(), &cfg)
         .expect("should error — not-a-color is unknown");
     assert!(
         msg.contains("unknown color"),
@@ -299,8 +302,8 @@ fn color_matching_palette_is_case_insensitive() {
     // matching should also be case-insensitive so `color = Z` matches a
     // declared `[colors-custom.z]` block.
     let mut cfg = std::collections::HashMap::new();
-    cfg.insert("colors-custom.z.bg".to_string(), "#0a0a0a".to_string());
-    let msg = validate_field_value_with_cfg("color", "Z", &cfg)
+    cfg.insert("colors-custom.z.bg".to_string(), "#0a0a0a".into());
+    let msg = validate_field_value_with_cfg("color", &"Z".into(), &cfg)
         .expect("should error — Z is not a built-in color");
     assert!(
         msg.contains("colors-custom = Z"),
@@ -313,8 +316,8 @@ fn color_valid_built_in_passes_with_cfg_unchanged() {
     // A valid built-in color name must still pass — the wrapper must not
     // turn a passing validation into a failure.
     let cfg = std::collections::HashMap::new();
-    assert!(validate_field_value_with_cfg("color", "green", &cfg).is_none());
-    assert!(validate_field_value_with_cfg("color", "neon-purple", &cfg).is_none());
+    assert!(validate_field_value_with_cfg("color", &"green".into(), &cfg).is_none());
+    assert!(validate_field_value_with_cfg("color", &"neon-purple".into(), &cfg).is_none());
 }
 
 #[test]
@@ -322,8 +325,8 @@ fn validate_field_value_with_cfg_preserves_other_field_errors() {
     // The wrapper must NOT alter errors for non-color fields. Validate
     // that an out-of-range fps error passes through unchanged.
     let cfg = std::collections::HashMap::new();
-    let plain = validate_field_value("fps", "9999");
-    let wrapped = validate_field_value_with_cfg("fps", "9999", &cfg);
+    let plain = validate_field_value("fps", &"9999".into());
+    let wrapped = validate_field_value_with_cfg("fps", &"9999".into(), &cfg);
     assert_eq!(
         plain, wrapped,
         "wrapper must be transparent for non-color fields"
@@ -340,8 +343,8 @@ fn charset_matching_custom_block_gets_charset_custom_hint() {
     // `charset-custom` field. (Note: `pipes` is chosen because it is
     // NOT in the built-in charset list — see src/charset.rs.)
     let mut cfg = std::collections::HashMap::new();
-    cfg.insert("charset-custom.pipes.set".to_string(), "|".to_string());
-    let msg = validate_field_value_with_cfg("charset", "pipes", &cfg)
+    cfg.insert("charset-custom.pipes.set".to_string(), "|".into());
+    let msg = validate_field_value_with_cfg("charset", &"pipes".into(), &cfg)
         .expect("should still error — pipes is not a built-in charset");
     assert!(
         msg.contains("custom charset"),
@@ -363,8 +366,8 @@ fn charset_matching_custom_block_is_case_insensitive() {
     // matching should also be case-insensitive so `charset = PIPES`
     // matches a declared `[charset-custom.pipes]` block.
     let mut cfg = std::collections::HashMap::new();
-    cfg.insert("charset-custom.pipes.set".to_string(), "|".to_string());
-    let msg = validate_field_value_with_cfg("charset", "PIPES", &cfg)
+    cfg.insert("charset-custom.pipes.set".to_string(), "|".into());
+    let msg = validate_field_value_with_cfg("charset", &"PIPES".into(), &cfg)
         .expect("should error — PIPES is not a built-in charset");
     assert!(
         msg.contains("charset-custom = PIPES"),
@@ -377,7 +380,7 @@ fn charset_unknown_with_no_matching_block_keeps_plain_error() {
     // No [charset-custom.<name>] block exists for this value — the hint
     // must NOT fire. The plain "unknown charset" error is returned.
     let cfg = std::collections::HashMap::new();
-    let msg = validate_field_value_with_cfg("charset", "not-a-charset", &cfg)
+    let msg = validate_field_value_with_cfg("charset", &"not-a-charset".into(), &cfg)
         .expect("should error — not-a-charset is unknown");
     assert!(
         msg.contains("unknown charset"),
@@ -394,21 +397,21 @@ fn charset_valid_built_in_passes_with_cfg_unchanged() {
     // A valid built-in charset name must still pass — the wrapper must
     // not turn a passing validation into a failure.
     let cfg = std::collections::HashMap::new();
-    assert!(validate_field_value_with_cfg("charset", "matrix", &cfg).is_none());
-    assert!(validate_field_value_with_cfg("charset", "hacker", &cfg).is_none());
+    assert!(validate_field_value_with_cfg("charset", &"matrix".into(), &cfg).is_none());
+    assert!(validate_field_value_with_cfg("charset", &"hacker".into(), &cfg).is_none());
 }
 
 #[test]
 fn scene_unknown_is_rejected() {
-    let msg = validate_field_value("scene", "nonexistent");
+    let msg = validate_field_value("scene", &"nonexistent".into());
     assert!(msg.is_some());
     assert!(msg.unwrap().contains("unknown scene"));
 }
 
 #[test]
 fn monolith_size_invalid_is_rejected() {
-    assert!(validate_field_value("monolith-size", "huge").is_some());
-    assert!(validate_field_value("monolith-size", "normal").is_none());
+    assert!(validate_field_value("monolith-size", &"huge".into()).is_some());
+    assert!(validate_field_value("monolith-size", &"normal".into()).is_none());
 }
 
 #[test]
@@ -416,7 +419,7 @@ fn monolith_size_case_insensitive_matches_cli() {
     // Phase 5 closure (P1-#4 + P2-6)
     for v in ["Small", "SMALL", "Normal", "NORMAL", "Large", "LARGE"] {
         assert!(
-            validate_field_value("monolith-size", v).is_none(),
+            validate_field_value("monolith-size", &v.into()).is_none(),
             "'{v}' should be accepted (case-insensitive)"
         );
     }
@@ -424,8 +427,8 @@ fn monolith_size_case_insensitive_matches_cli() {
 
 #[test]
 fn glitch_level_invalid_is_rejected() {
-    assert!(validate_field_value("glitch-level", "extreme").is_some());
-    assert!(validate_field_value("glitch-level", "subtle").is_none());
+    assert!(validate_field_value("glitch-level", &"extreme".into()).is_some());
+    assert!(validate_field_value("glitch-level", &"subtle".into()).is_none());
 }
 
 #[test]
@@ -435,7 +438,7 @@ fn glitch_level_case_insensitive_matches_cli() {
         "None", "NONE", "Subtle", "SUBTLE", "Default", "DEFAULT", "Intense", "INTENSE",
     ] {
         assert!(
-            validate_field_value("glitch-level", v).is_none(),
+            validate_field_value("glitch-level", &v.into()).is_none(),
             "'{v}' should be accepted (case-insensitive)"
         );
     }
@@ -443,9 +446,9 @@ fn glitch_level_case_insensitive_matches_cli() {
 
 #[test]
 fn color_bg_invalid_is_rejected() {
-    assert!(validate_field_value("color-bg", "white").is_some());
-    assert!(validate_field_value("color-bg", "black").is_none());
-    assert!(validate_field_value("color-bg", "default-background").is_none());
+    assert!(validate_field_value("color-bg", &"white".into()).is_some());
+    assert!(validate_field_value("color-bg", &"black".into()).is_none());
+    assert!(validate_field_value("color-bg", &"default-background".into()).is_none());
 }
 
 #[test]
@@ -453,7 +456,7 @@ fn color_bg_case_insensitive_matches_cli() {
     // Phase 5 closure (P2-6)
     for v in ["Black", "BLACK", "Default-Background", "DEFAULT-BACKGROUND"] {
         assert!(
-            validate_field_value("color-bg", v).is_none(),
+            validate_field_value("color-bg", &v.into()).is_none(),
             "'{v}' should be accepted (case-insensitive)"
         );
     }
@@ -464,24 +467,24 @@ fn boolean_keys_reject_non_bool() {
     // Phase D Bug #1 fix: "yes"/"on"/"1"/"no"/"off"/"0" are now accepted
     // (matching parse_bool_config). Only truly invalid values are rejected.
     // (CLI-D-3): removed `mouse` assertions — mouse is no longer in
-    // USER_CONFIG_KEYS (caught as unknown_key upstream). The bool validator
+    // config_schema::ROOT_KEYS (caught as unknown_key upstream). The bool validator
     // arm now only covers `async-mode`.
-    assert!(validate_field_value("async-mode", "maybe").is_some());
-    assert!(validate_field_value("async-mode", "true").is_none());
+    assert!(validate_field_value("async-mode", &"maybe".into()).is_some());
+    assert!(validate_field_value("async-mode", &"true".into()).is_none());
 }
 
 #[test]
 fn block_field_base_uses_scene_validator() {
     // 'base' in scene-custom blocks is validated as a scene name.
     // The caller maps 'base' -> 'scene' before calling validate_field_value.
-    assert!(validate_field_value("scene", "nonexistent").is_some());
-    assert!(validate_field_value("scene", "monolith").is_none());
+    assert!(validate_field_value("scene", &"nonexistent".into()).is_some());
+    assert!(validate_field_value("scene", &"monolith".into()).is_none());
 }
 
 #[test]
 fn unknown_key_returns_none() {
     // Unknown keys are caught by the unknown_keys check, not here.
-    assert!(validate_field_value("unknown-key", "anything").is_none());
+    assert!(validate_field_value("unknown-key", &"anything".into()).is_none());
 }
 
 // ── v16: colors-custom hex validation ──
@@ -527,17 +530,26 @@ fn hex_color_invalid_wrong_length() {
 
 #[test]
 fn colors_custom_value_validates_single_hex() {
-    assert!(validate_colors_custom_value("colors-custom.mytheme.normal.red", "#ff0000").is_none());
     assert!(
-        validate_colors_custom_value("colors-custom.mytheme.normal.red", "\"#ff0000\"").is_none()
+        validate_colors_custom_value("colors-custom.mytheme.normal.red", &"#ff0000".into())
+            .is_none()
     );
+    assert!(validate_colors_custom_value(
+        "colors-custom.mytheme.normal.red",
+        &ConfigValue::List(vec!["#ff0000".to_string()])
+    )
+    .is_some());
 }
 
 #[test]
 fn colors_custom_value_rejects_invalid_hex() {
-    assert!(validate_colors_custom_value("colors-custom.mytheme.normal.red", "#gg0000").is_some());
     assert!(
-        validate_colors_custom_value("colors-custom.mytheme.normal.red", "notacolor").is_some()
+        validate_colors_custom_value("colors-custom.mytheme.normal.red", &"#gg0000".into())
+            .is_some()
+    );
+    assert!(
+        validate_colors_custom_value("colors-custom.mytheme.normal.red", &"notacolor".into())
+            .is_some()
     );
 }
 
@@ -545,7 +557,15 @@ fn colors_custom_value_rejects_invalid_hex() {
 fn colors_custom_stops_validates_each() {
     assert!(validate_colors_custom_value(
         "colors-custom.mytheme.stops",
-        "\"#1a0033\", \"#4d0080\", \"#9933ff\""
+        &value_from_text(
+            "colors-custom.mytheme.stops",
+            "[\"#1a0033\", \"#4d0080\", \"#9933ff\"]"
+        )
+    )
+    .is_none());
+    assert!(validate_colors_custom_value(
+        "colors-custom.mytheme.stops",
+        &value_from_text("colors-custom.mytheme.stops", "#1a0033, #4d0080, #9933ff")
     )
     .is_none());
 }
@@ -554,14 +574,21 @@ fn colors_custom_stops_validates_each() {
 fn colors_custom_stops_rejects_one_bad() {
     assert!(validate_colors_custom_value(
         "colors-custom.mytheme.stops",
-        "\"#1a0033\", \"#gg0080\", \"#9933ff\""
+        &value_from_text(
+            "colors-custom.mytheme.stops",
+            "[\"#1a0033\", \"#gg0080\", \"#9933ff\"]"
+        )
     )
     .is_some());
 }
 
 #[test]
 fn colors_custom_stops_rejects_empty() {
-    assert!(validate_colors_custom_value("colors-custom.mytheme.stops", "").is_some());
+    assert!(validate_colors_custom_value(
+        "colors-custom.mytheme.stops",
+        &value_from_text("colors-custom.mytheme.stops", "")
+    )
+    .is_some());
 }
 
 // ── (bug #6): color.tune.* range validation ──
@@ -575,19 +602,19 @@ fn colors_custom_stops_rejects_empty() {
 
 #[test]
 fn color_tune_brightness_out_of_range_is_rejected() {
-    assert!(validate_field_value("color.tune.brightness", "3.1").is_some());
-    assert!(validate_field_value("color.tune.brightness", "-0.1").is_some());
-    assert!(validate_field_value("color.tune.brightness", "999").is_some());
-    assert!(validate_field_value("color.tune.brightness", "1.5").is_none());
-    assert!(validate_field_value("color.tune.brightness", "0.0").is_none());
-    assert!(validate_field_value("color.tune.brightness", "3.0").is_none());
+    assert!(validate_field_value("color.tune.brightness", &"3.1".into()).is_some());
+    assert!(validate_field_value("color.tune.brightness", &"-0.1".into()).is_some());
+    assert!(validate_field_value("color.tune.brightness", &"999".into()).is_some());
+    assert!(validate_field_value("color.tune.brightness", &"1.5".into()).is_none());
+    assert!(validate_field_value("color.tune.brightness", &"0.0".into()).is_none());
+    assert!(validate_field_value("color.tune.brightness", &"3.0".into()).is_none());
 }
 
 #[test]
 fn color_tune_saturation_out_of_range_is_rejected() {
-    assert!(validate_field_value("color.tune.saturation", "3.5").is_some());
-    assert!(validate_field_value("color.tune.saturation", "-1.0").is_some());
-    assert!(validate_field_value("color.tune.saturation", "1.0").is_none());
+    assert!(validate_field_value("color.tune.saturation", &"3.5".into()).is_some());
+    assert!(validate_field_value("color.tune.saturation", &"-1.0".into()).is_some());
+    assert!(validate_field_value("color.tune.saturation", &"1.0".into()).is_none());
 }
 
 #[test]
@@ -595,15 +622,15 @@ fn color_tune_head_body_tail_out_of_range_is_rejected() {
     for field in &["head", "body", "tail"] {
         let key = format!("color.tune.{field}");
         assert!(
-            validate_field_value(&key, "5.0").is_some(),
+            validate_field_value(&key, &"5.0".into()).is_some(),
             "{key} = 5.0 should be rejected"
         );
         assert!(
-            validate_field_value(&key, "-0.01").is_some(),
+            validate_field_value(&key, &"-0.01".into()).is_some(),
             "{key} = -0.01 should be rejected"
         );
         assert!(
-            validate_field_value(&key, "2.0").is_none(),
+            validate_field_value(&key, &"2.0".into()).is_none(),
             "{key} = 2.0 should be accepted"
         );
     }
@@ -611,7 +638,7 @@ fn color_tune_head_body_tail_out_of_range_is_rejected() {
 
 #[test]
 fn color_tune_non_numeric_is_rejected() {
-    let msg = validate_field_value("color.tune.brightness", "bright");
+    let msg = validate_field_value("color.tune.brightness", &"bright".into());
     assert!(
         msg.is_some(),
         "'bright' must be rejected for color.tune.brightness"
@@ -621,14 +648,14 @@ fn color_tune_non_numeric_is_rejected() {
 
 #[test]
 fn color_tune_empty_value_is_rejected() {
-    assert!(validate_field_value("color.tune.brightness", "").is_some());
-    assert!(validate_field_value("color.tune.brightness", "   ").is_some());
+    assert!(validate_field_value("color.tune.brightness", &"".into()).is_some());
+    assert!(validate_field_value("color.tune.brightness", &"   ".into()).is_some());
 }
 
 #[test]
 fn color_tune_end_to_end_via_validate_config_strictly() {
     let mut cfg = std::collections::HashMap::new();
-    cfg.insert("color.tune.brightness".to_string(), "999".to_string());
+    cfg.insert("color.tune.brightness".to_string(), "999".into());
     let result = validate_config_strictly(&cfg);
     assert!(
         result.is_err(),
@@ -642,7 +669,7 @@ fn color_tune_end_to_end_via_validate_config_strictly() {
 
     // Valid value passes.
     let mut cfg2 = std::collections::HashMap::new();
-    cfg2.insert("color.tune.brightness".to_string(), "1.5".to_string());
+    cfg2.insert("color.tune.brightness".to_string(), "1.5".into());
     assert!(validate_config_strictly(&cfg2).is_ok());
 }
 
@@ -654,7 +681,7 @@ fn color_tune_end_to_end_via_validate_config_strictly() {
 #[test]
 fn intro_end_to_end_via_validate_config_strictly() {
     let mut cfg = std::collections::HashMap::new();
-    cfg.insert("intro".to_string(), "splash".to_string());
+    cfg.insert("intro".to_string(), "splash".into());
     let result = validate_config_strictly(&cfg);
     assert!(
         result.is_err(),
@@ -672,11 +699,229 @@ fn intro_end_to_end_via_validate_config_strictly() {
 
     // Each valid value passes end-to-end.
     for v in ["cosmic", "logo", "none"] {
-        let mut cfg2 = std::collections::HashMap::new();
-        cfg2.insert("intro".to_string(), v.to_string());
+        let mut cfg2 = crate::config_schema::ConfigMap::new();
+        cfg2.insert("intro".to_string(), v.into());
         assert!(
             validate_config_strictly(&cfg2).is_ok(),
             "intro={v} must pass strict validation"
         );
     }
 }
+
+#[test]
+fn seed_accepts_u64_and_rejects_garbage() {
+    assert!(validate_field_value("seed", &"0".into()).is_none());
+    assert!(validate_field_value("seed", &"18446744073709551615".into()).is_none());
+    for bad in ["-1", "abc", "1.5", "18446744073709551616"] {
+        assert!(
+            validate_field_value("seed", &bad.into()).is_some(),
+            "'{bad}' must be rejected for seed"
+        );
+    }
+}
+
+#[test]
+fn rain_tuning_fields_are_range_checked() {
+    assert!(validate_field_value("rain-tuning.phosphor-decay", &"3.5".into()).is_none());
+    assert!(validate_field_value(
+        "rain-tuning.parallax-speed",
+        &value_from_text("rain-tuning.parallax-speed", "[0.3, 1.0, 2.0]")
+    )
+    .is_none());
+    assert!(validate_field_value("rain-tuning.crt-vignette-height", &"0".into()).is_none());
+    for (key, bad) in [
+        ("rain-tuning.phosphor-decay", "0"),
+        ("rain-tuning.parallax-speed", "[0.3, 1.0]"),
+        ("rain-tuning.crt-vignette-height", "9"),
+        ("rain-tuning.vignette-inner-radius", "1.0"),
+    ] {
+        assert!(
+            validate_field_value(key, &bad.into()).is_some(),
+            "'{bad}' must be rejected for {key}"
+        );
+    }
+    let parsed = crate::configfile::parse_config_text(
+        "[rain-tuning]\nfog-rows = 4\nparallax-density = [0.5, 0.6, 0.7]\n",
+    );
+    assert!(parsed.unknown_keys.is_empty(), "{:?}", parsed.unknown_keys);
+    assert!(validate_config_strictly(&parsed.values).is_ok());
+}
+
+#[test]
+fn event_fields_are_range_checked() {
+    assert!(validate_field_value("events.cascade.chance", &"0.01".into()).is_none());
+    assert!(validate_field_value("events.tear.cooldown-secs", &"0".into()).is_none());
+    for (key, bad) in [
+        ("events.cascade.chance", "2"),
+        ("events.scanline.cooldown-secs", "7200"),
+        ("events.decrypt.words", "[\"雨\"]"),
+    ] {
+        assert!(
+            validate_field_value(key, &bad.into()).is_some(),
+            "'{bad}' must be rejected for {key}"
+        );
+    }
+    let parsed = crate::configfile::parse_config_text(
+        "[events.decrypt]\nwords = [\"WAKE UP\"]\ncooldown-secs = 20\n[events.tear]\nchance = 0\n",
+    );
+    assert!(parsed.unknown_keys.is_empty(), "{:?}", parsed.unknown_keys);
+    assert!(validate_config_strictly(&parsed.values).is_ok());
+}
+
+#[test]
+fn power_dragon_fields_are_range_and_order_checked() {
+    assert!(validate_field_value("power-dragon.thermal-one-c", &"80".into()).is_none());
+    assert!(validate_field_value("power-dragon.self-heal-downgrade", &"off".into()).is_none());
+    for (key, bad) in [
+        ("power-dragon.thermal-one-c", "80.5"),
+        ("power-dragon.idle-fps-factor", "0"),
+        ("power-dragon.self-heal-downgrade", "never"),
+        ("power-dragon.xtermjs-window-frames", "10"),
+    ] {
+        assert!(
+            validate_field_value(key, &bad.into()).is_some(),
+            "'{bad}' must be rejected for {key}"
+        );
+    }
+    let parsed = crate::configfile::parse_config_text(
+        "power-dragon = true\n[power-dragon]\nthermal-one-c = 80\nself-heal-downgrade = false\n",
+    );
+    assert!(parsed.unknown_keys.is_empty(), "{:?}", parsed.unknown_keys);
+    assert_eq!(
+        parsed
+            .values
+            .get("power-dragon")
+            .and_then(crate::config_schema::ConfigValue::as_str),
+        Some("true")
+    );
+    assert!(validate_config_strictly(&parsed.values).is_ok());
+
+    // 45 °C is fine on its own but not above the default 50 °C zero point.
+    let parsed = crate::configfile::parse_config_text("[power-dragon]\nthermal-one-c = 45\n");
+    assert!(validate_config_strictly(&parsed.values).is_err());
+}
+
+#[test]
+fn keys_section_is_syntax_and_conflict_checked() {
+    assert!(validate_field_value("keys.hud", &value_from_text("keys.hud", "f2")).is_none());
+    assert!(validate_field_value(
+        "keys.quit",
+        &value_from_text("keys.quit", "[\"q\", \"ctrl+q\"]")
+    )
+    .is_none());
+    assert!(validate_field_value("keys.hud", &value_from_text("keys.hud", "hyper+i")).is_some());
+    assert!(validate_field_value("keys.quit", &value_from_text("keys.quit", "[]")).is_some());
+
+    let parsed =
+        crate::configfile::parse_config_text("[keys]\nquit = [\"q\", \"ctrl+q\"]\nhud = \"f2\"\n");
+    assert!(parsed.unknown_keys.is_empty(), "{:?}", parsed.unknown_keys);
+    assert!(validate_config_strictly(&parsed.values).is_ok());
+
+    let parsed = crate::configfile::parse_config_text("[keys]\npause = \"k\"\nhud = \"k\"\n");
+    assert!(validate_config_strictly(&parsed.values).is_err());
+    let parsed = crate::configfile::parse_config_text("[keys]\njump = \"j\"\n");
+    assert_eq!(parsed.unknown_keys, vec!["keys.jump".to_string()]);
+}
+
+#[test]
+fn crystal_dragon_calc_accepts_v1_and_v2() {
+    assert!(validate_field_value("crystal-dragon-calc", &"v2".into()).is_none());
+    assert!(validate_field_value("crystal-dragon-calc", &"Calc-V1".into()).is_none());
+    let msg = validate_field_value("crystal-dragon-calc", &"v3".into()).unwrap();
+    assert!(msg.contains("expected v1/v2"), "{msg}");
+
+    let parsed = crate::configfile::parse_config_text("crystal-dragon-calc = \"v2\"\n");
+    assert!(parsed.unknown_keys.is_empty(), "{:?}", parsed.unknown_keys);
+    assert!(validate_config_strictly(&parsed.values).is_ok());
+}
+
+#[test]
+fn crystal_dragon_sensor_names_are_checked() {
+    assert!(validate_field_value(
+        "crystal-dragon-sensor",
+        &value_from_text("crystal-dragon-sensor", "[\"loadavg\", \"psi-io\"]")
+    )
+    .is_none());
+    assert!(validate_field_value(
+        "crystal-dragon-sensor",
+        &value_from_text("crystal-dragon-sensor", "gpu")
+    )
+    .is_some());
+    assert!(validate_field_value("crystal-dragon-sensor-mix", &"mean".into()).is_none());
+    assert!(validate_field_value("crystal-dragon-sensor-mix", &"sum".into()).is_some());
+
+    let parsed = crate::configfile::parse_config_text(
+        "crystal-dragon-sensor = [\"loadavg\", \"battery\"]\ncrystal-dragon-sensor-mix = \"mean\"\n",
+    );
+    assert!(parsed.unknown_keys.is_empty(), "{:?}", parsed.unknown_keys);
+    assert!(validate_config_strictly(&parsed.values).is_ok());
+}
+
+#[test]
+fn external_sensor_needs_a_source() {
+    assert!(validate_field_value("crystal-dragon-external", &"command:ci-status".into()).is_none());
+    assert!(validate_field_value("crystal-dragon-external", &"point.txt".into()).is_some());
+
+    let parsed = crate::configfile::parse_config_text("crystal-dragon-sensor = [\"external\"]\n");
+    let err = validate_config_strictly(&parsed.values).unwrap_err();
+    assert!(err.contains("crystal-dragon-external"), "{err}");
+
+    let parsed = crate::configfile::parse_config_text(
+        "crystal-dragon-sensor = [\"external\", \"loadavg\"]\n\
+         crystal-dragon-external = \"/run/ci/point\"\n",
+    );
+    assert!(validate_config_strictly(&parsed.values).is_ok());
+}
+
+#[test]
+fn calendar_ambient_keys_flatten_and_validate() {
+    let parsed = crate::configfile::parse_config_text(
+        "ambient.06-00 = \"signal\"\n\
+         ambient.\"12-24\".18-00 = \"cinematic\"\n\
+         ambient.\"12-24..01-01\".08-00 = \"monolith\"\n\
+         ambient.someday.09-00 = \"signal\"\n\
+         [ambient.sat]\n\
+         09-00 = \"monolith\"\n",
+    );
+    for key in [
+        "ambient.06-00",
+        "ambient.12-24.18-00",
+        "ambient.12-24..01-01.08-00",
+        "ambient.sat.09-00",
+    ] {
+        assert!(
+            parsed.values.contains_key(key),
+            "{key}: {:?}",
+            parsed.values
+        );
+    }
+    assert_eq!(parsed.unknown_keys, ["ambient.someday.09-00"]);
+    let hint = crate::config_hints::suggest_for_unknown_key("ambient.someday.09-00").unwrap();
+    assert!(hint.contains("unknown day selector 'someday'"), "{hint}");
+    assert!(validate_config_strictly(&parsed.values).is_ok());
+}
+
+#[test]
+fn solar_ambient_keys_need_a_location() {
+    let text = "ambient.\"sunset+30m\" = \"cinematic\"\n\
+                ambient.weekends.sunrise = \"signal\"\n";
+    let parsed = crate::configfile::parse_config_text(text);
+    assert!(parsed.unknown_keys.is_empty(), "{:?}", parsed.unknown_keys);
+    assert!(parsed.values.contains_key("ambient.sunset+30m"));
+    let err = validate_config_strictly(&parsed.values).unwrap_err();
+    assert!(err.contains("ambient-location"), "{err}");
+
+    let parsed =
+        crate::configfile::parse_config_text(&format!("ambient-location = [52.52, 13.40]\n{text}"));
+    assert!(validate_config_strictly(&parsed.values).is_ok());
+    assert!(validate_field_value(
+        "ambient-location",
+        &value_from_text("ambient-location", "52.52, 13.40")
+    )
+    .is_none());
+    assert!(validate_field_value(
+        "ambient-location",
+        &value_from_text("ambient-location", "52.52")
+    )
+    .is_some());
+}
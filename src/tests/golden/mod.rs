@@ -0,0 +1,461 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Golden-frame snapshot harness.
+//!
+//! The lock suites (`chroma_dragon_engine/tests/lock.rs`, `cloud/tests/`)
+//! assert properties; nothing caught "the rain looks different now". This
+//! harness renders every built-in scene in `scene::SCENES` at a fixed
+//! grid, `--seed` and `ManualClock` timestep, serializes the cells of a
+//! few checkpoint frames (`ch`, `fg`, `bg`, `bold`) into a compact text
+//! snapshot, and diffs the result against the checked-in file under
+//! `src/tests/golden/snapshots/<scene>.golden`.
+//!
+//! ## Snapshot format (`cosmostrix-golden v1`)
+//!
+//! ```text
+//! cosmostrix-golden v1
+//! scene matrix
+//! size 40x12
+//! seed 1
+//! step-us 16667
+//! style 0 fg=- bg=- bold=0          ← one line per distinct (fg, bg, bold)
+//! style 1 fg=#00ff41 bg=- bold=1
+//! frame 30
+//! |ｱ  0 ...|                         ← `lines` rows of exactly `cols` glyphs
+//! =00 01 ...                         ← `lines` rows of style ids (hex)
+//! ```
+//!
+//! Colors are `#rrggbb`, `a<n>` (ANSI 256) or the crossterm name; `-` is
+//! "no color". Glyphs are width-1 by construction (see `tests/width_guard`).
+//!
+//! ## Updating snapshots
+//!
+//! An intentional visual change is blessed with
+//!
+//! ```text
+//! COSMOSTRIX_BLESS=1 cargo test golden
+//! ```
+//!
+//! which rewrites the snapshot files instead of comparing; review the
+//! resulting `git diff` like any other code change. A missing snapshot is
+//! a failure (not an implicit bless) so a forgotten `git add` cannot make
+//! the gate pass vacuously.
+//!
+//! Snapshots are generated on Linux x86_64. Float-heavy shaders go through
+//! libm, so another platform may legitimately differ in a handful of
+//! low-order color bits.
+
+mod scenes;
+mod tests;
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crossterm::style::Color;
+
+use crate::cell::Cell;
+use crate::charset::{build_chars, charset_from_str};
+use crate::cli::parse_color_scheme;
+use crate::cloud::{Cloud, ManualClock};
+use crate::frame::Frame;
+use crate::runtime::{BoldMode, ColorMode, ShadingMode};
+use crate::scene::SceneInfo;
+
+/// Snapshot format header line.
+const FORMAT_HEADER: &str = "cosmostrix-golden v1";
+
+/// Set to `1` to rewrite snapshots instead of comparing.
+const BLESS_ENV: &str = "COSMOSTRIX_BLESS";
+
+/// Max changed cells listed per checkpoint in a failure report.
+const REPORT_MAX_CELLS: usize = 24;
+
+/// Fixed render parameters for one snapshot.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct GoldenSpec {
+    pub cols: u16,
+    pub lines: u16,
+    pub seed: u64,
+    /// Simulation timestep per frame (60 Hz by default).
+    pub step: Duration,
+    /// Frame numbers (1-based, ascending) whose grids are captured.
+    pub checkpoints: &'static [u32],
+}
+
+/// Default spec for the scene gate: small enough to keep the snapshot
+/// files reviewable, long enough for slow scenes (`calm`, 6 cps) to fill
+/// the grid. Checkpoints at 0.5 s (warm start) and 2 s (steady state).
+pub(crate) const SCENE_SPEC: GoldenSpec = GoldenSpec {
+    cols: 40,
+    lines: 12,
+    seed: 1,
+    step: Duration::from_micros(16_667),
+    checkpoints: &[30, 120],
+};
+
+/// One serialized cell: glyph + interned style key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct GoldenCell {
+    pub ch: char,
+    pub style: String,
+}
+
+/// Parsed (or freshly rendered) snapshot: header fields + checkpoint grids.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Snapshot {
+    pub scene: String,
+    pub cols: u16,
+    pub lines: u16,
+    pub seed: u64,
+    pub step_us: u64,
+    pub frames: Vec<(u32, Vec<GoldenCell>)>,
+}
+
+/// Build a cloud for a built-in scene the way startup does for
+/// `--scene <name>` with default flags (truecolor, cinematic shading,
+/// random bold, default background), on `clock`.
+pub(crate) fn scene_cloud(scene: &SceneInfo, spec: &GoldenSpec, clock: &ManualClock) -> Cloud {
+    let cfg = scene.config;
+    let scheme =
+        parse_color_scheme(cfg.color.unwrap_or("green")).expect("built-in scene color must parse");
+    let mut cloud = Cloud::with_clock(
+        Box::new(clock.clone()),
+        ColorMode::TrueColor,
+        ShadingMode::DistanceFromHead,
+        BoldMode::Random,
+        true,
+        true,
+        scheme,
+        cfg.rain_style,
+    );
+    cloud.set_seed(spec.seed);
+    if let Some(density) = cfg.density {
+        cloud.set_droplet_density(density);
+    }
+    if let Some(speed) = cfg.speed {
+        cloud.set_chars_per_sec(speed);
+    }
+    let charset = charset_from_str(cfg.charset.unwrap_or("auto"), false)
+        .expect("built-in scene charset must parse");
+    cloud.init_chars(build_chars(charset, &[], false));
+    cloud.reset(spec.cols, spec.lines);
+    if let Some(level) = cfg.glitch_level {
+        cloud.apply_glitch_level_runtime(level);
+    }
+    cloud
+}
+
+/// Render `scene` under `spec` and capture the checkpoint grids.
+pub(crate) fn render_scene(scene: &SceneInfo, spec: &GoldenSpec) -> Snapshot {
+    let clock = ManualClock::starting_at(Instant::now());
+    let mut cloud = scene_cloud(scene, spec, &clock);
+    let mut frame = Frame::new(spec.cols, spec.lines, cloud.palette.bg);
+    let last = spec.checkpoints.last().copied().unwrap_or(0);
+    let mut frames = Vec::with_capacity(spec.checkpoints.len());
+    for n in 1..=last {
+        clock.advance(spec.step);
+        cloud.rain(&mut frame);
+        if spec.checkpoints.contains(&n) {
+            frames.push((n, capture(&frame)));
+        }
+    }
+    Snapshot {
+        scene: scene.name.to_string(),
+        cols: spec.cols,
+        lines: spec.lines,
+        seed: spec.seed,
+        step_us: spec.step.as_micros() as u64,
+        frames,
+    }
+}
+
+/// Snapshot the *live* grid: cells from an older generation read as the
+/// frame's blank, exactly as the terminal renderer sees them.
+fn capture(frame: &Frame) -> Vec<GoldenCell> {
+    (0..frame.cells.len())
+        .map(|i| {
+            let c = frame.cell_at_index_ref(i);
+            GoldenCell {
+                ch: c.ch,
+                style: style_key(c),
+            }
+        })
+        .collect()
+}
+
+fn style_key(c: &Cell) -> String {
+    format!(
+        "fg={} bg={} bold={}",
+        color_str(c.fg),
+        color_str(c.bg),
+        u8::from(c.bold)
+    )
+}
+
+fn color_str(c: Option<Color>) -> String {
+    match c {
+        None => "-".to_string(),
+        Some(Color::Rgb { r, g, b }) => format!("#{r:02x}{g:02x}{b:02x}"),
+        Some(Color::AnsiValue(n)) => format!("a{n}"),
+        Some(other) => format!("{other:?}").to_lowercase(),
+    }
+}
+
+impl Snapshot {
+    /// Serialize to the `cosmostrix-golden v1` text format.
+    pub(crate) fn to_text(&self) -> String {
+        // Intern styles in first-seen order so ids are stable for a
+        // given render (and diffs of the snapshot file stay local).
+        let mut ids: HashMap<&str, usize> = HashMap::new();
+        let mut order: Vec<&str> = Vec::new();
+        for (_, cells) in &self.frames {
+            for c in cells {
+                ids.entry(c.style.as_str()).or_insert_with(|| {
+                    order.push(c.style.as_str());
+                    order.len() - 1
+                });
+            }
+        }
+        let width = format!("{:x}", order.len().saturating_sub(1)).len();
+
+        let mut out = String::new();
+        let _ = writeln!(out, "{FORMAT_HEADER}");
+        let _ = writeln!(out, "scene {}", self.scene);
+        let _ = writeln!(out, "size {}x{}", self.cols, self.lines);
+        let _ = writeln!(out, "seed {}", self.seed);
+        let _ = writeln!(out, "step-us {}", self.step_us);
+        for (i, style) in order.iter().enumerate() {
+            let _ = writeln!(out, "style {i} {style}");
+        }
+        let cols = usize::from(self.cols);
+        for (n, cells) in &self.frames {
+            let _ = writeln!(out, "frame {n}");
+            for row in cells.chunks(cols) {
+                out.push('|');
+                out.extend(row.iter().map(|c| c.ch));
+                out.push_str("|\n");
+            }
+            for row in cells.chunks(cols) {
+                out.push('=');
+                for (i, c) in row.iter().enumerate() {
+                    if i > 0 {
+                        out.push(' ');
+                    }
+                    let _ = write!(out, "{:0width$x}", ids[c.style.as_str()]);
+                }
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    /// Parse the `cosmostrix-golden v1` text format.
+    pub(crate) fn parse(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines().enumerate().peekable();
+        let mut next = |what: &str| -> Result<(usize, &str), String> {
+            lines
+                .next()
+                .ok_or_else(|| format!("unexpected end of snapshot (expected {what})"))
+        };
+        let (_, header) = next("header")?;
+        if header != FORMAT_HEADER {
+            return Err(format!("unsupported snapshot header '{header}'"));
+        }
+        let field = |line: (usize, &str), key: &str| -> Result<String, String> {
+            line.1
+                .strip_prefix(key)
+                .and_then(|v| v.strip_prefix(' '))
+                .map(str::to_string)
+                .ok_or_else(|| format!("line {}: expected '{key} ...'", line.0 + 1))
+        };
+        let scene = field(next("scene")?, "scene")?;
+        let size = field(next("size")?, "size")?;
+        let (cols, rows) = size
+            .split_once('x')
+            .and_then(|(c, l)| Some((c.parse::<u16>().ok()?, l.parse::<u16>().ok()?)))
+            .ok_or_else(|| format!("bad size '{size}'"))?;
+        let seed = field(next("seed")?, "seed")?
+            .parse::<u64>()
+            .map_err(|e| format!("bad seed: {e}"))?;
+        let step_us = field(next("step-us")?, "step-us")?
+            .parse::<u64>()
+            .map_err(|e| format!("bad step-us: {e}"))?;
+
+        let mut styles: Vec<String> = Vec::new();
+        let mut frames = Vec::new();
+        while let Ok(line) = next("style or frame") {
+            if let Ok(rest) = field(line, "style") {
+                let (id, style) = rest
+                    .split_once(' ')
+                    .ok_or_else(|| format!("line {}: bad style", line.0 + 1))?;
+                if id.parse::<usize>() != Ok(styles.len()) {
+                    return Err(format!("line {}: style ids must be dense", line.0 + 1));
+                }
+                styles.push(style.to_string());
+                continue;
+            }
+            let n = field(line, "frame")?
+                .parse::<u32>()
+                .map_err(|e| format!("line {}: bad frame number: {e}", line.0 + 1))?;
+            let mut glyphs: Vec<char> = Vec::with_capacity(usize::from(cols) * usize::from(rows));
+            for _ in 0..rows {
+                let (i, row) = next("glyph row")?;
+                let inner = row
+                    .strip_prefix('|')
+                    .and_then(|r| r.strip_suffix('|'))
+                    .ok_or_else(|| format!("line {}: glyph row must be |...|", i + 1))?;
+                let before = glyphs.len();
+                glyphs.extend(inner.chars());
+                if glyphs.len() - before != usize::from(cols) {
+                    return Err(format!("line {}: expected {cols} glyphs", i + 1));
+                }
+            }
+            let mut cells = Vec::with_capacity(glyphs.len());
+            let mut glyph_iter = glyphs.into_iter();
+            for _ in 0..rows {
+                let (i, row) = next("style row")?;
+                let ids = row
+                    .strip_prefix('=')
+                    .ok_or_else(|| format!("line {}: style row must start with '='", i + 1))?;
+                let mut count = 0;
+                for id in ids.split(' ') {
+                    let style = usize::from_str_radix(id, 16)
+                        .ok()
+                        .and_then(|id| styles.get(id))
+                        .ok_or_else(|| format!("line {}: unknown style id '{id}'", i + 1))?;
+                    let ch = glyph_iter
+                        .next()
+                        .ok_or_else(|| format!("line {}: too many style ids", i + 1))?;
+                    cells.push(GoldenCell {
+                        ch,
+                        style: style.clone(),
+                    });
+                    count += 1;
+                }
+                if count != usize::from(cols) {
+                    return Err(format!("line {}: expected {cols} style ids", i + 1));
+                }
+            }
+            frames.push((n, cells));
+        }
+        Ok(Self {
+            scene,
+            cols,
+            lines: rows,
+            seed,
+            step_us,
+            frames,
+        })
+    }
+
+    /// Human-readable cell diff against `expected`, or `None` when equal.
+    pub(crate) fn diff_report(&self, expected: &Snapshot) -> Option<String> {
+        if self == expected {
+            return None;
+        }
+        let mut out = String::new();
+        let header = (self.cols, self.lines, self.seed, self.step_us);
+        let expected_header = (
+            expected.cols,
+            expected.lines,
+            expected.seed,
+            expected.step_us,
+        );
+        if header != expected_header {
+            let _ = writeln!(
+                out,
+                "  spec changed: {}x{} seed {} step {}us (snapshot: {}x{} seed {} step {}us)",
+                self.cols,
+                self.lines,
+                self.seed,
+                self.step_us,
+                expected.cols,
+                expected.lines,
+                expected.seed,
+                expected.step_us
+            );
+            return Some(out);
+        }
+        let expected_frames: Vec<u32> = expected.frames.iter().map(|(n, _)| *n).collect();
+        let frames: Vec<u32> = self.frames.iter().map(|(n, _)| *n).collect();
+        if frames != expected_frames {
+            let _ = writeln!(
+                out,
+                "  checkpoints changed: {frames:?} (snapshot: {expected_frames:?})"
+            );
+            return Some(out);
+        }
+        let cols = usize::from(self.cols);
+        for ((n, got), (_, want)) in self.frames.iter().zip(&expected.frames) {
+            let changed: Vec<usize> = (0..got.len()).filter(|&i| got[i] != want[i]).collect();
+            if changed.is_empty() {
+                continue;
+            }
+            let _ = writeln!(
+                out,
+                "  frame {n}: {} of {} cells changed",
+                changed.len(),
+                got.len()
+            );
+            for &i in changed.iter().take(REPORT_MAX_CELLS) {
+                let _ = writeln!(
+                    out,
+                    "    ({:>3},{:>3}) {:?} {}  ->  {:?} {}",
+                    i % cols,
+                    i / cols,
+                    want[i].ch,
+                    want[i].style,
+                    got[i].ch,
+                    got[i].style
+                );
+            }
+            if changed.len() > REPORT_MAX_CELLS {
+                let _ = writeln!(out, "    ... {} more", changed.len() - REPORT_MAX_CELLS);
+            }
+        }
+        Some(out)
+    }
+}
+
+/// Snapshot file for `scene`.
+pub(crate) fn snapshot_path(scene: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/tests/golden/snapshots")
+        .join(format!("{scene}.golden"))
+}
+
+fn bless_requested() -> bool {
+    std::env::var(BLESS_ENV).is_ok_and(|v| v == "1")
+}
+
+/// Render `scene`, then compare against (or, when blessing, rewrite) its
+/// snapshot. Returns a failure report on mismatch.
+pub(crate) fn check_scene(scene: &SceneInfo, spec: &GoldenSpec) -> Result<(), String> {
+    let actual = render_scene(scene, spec);
+    let path = snapshot_path(scene.name);
+    if bless_requested() {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+        }
+        return std::fs::write(&path, actual.to_text())
+            .map_err(|e| format!("{}: {e}", path.display()));
+    }
+    let text = std::fs::read_to_string(&path).map_err(|e| {
+        format!(
+            "{}: {e} (run `{BLESS_ENV}=1 cargo test golden` to create it)",
+            path.display()
+        )
+    })?;
+    let expected = Snapshot::parse(&text).map_err(|e| format!("{}: {e}", path.display()))?;
+    match actual.diff_report(&expected) {
+        None => Ok(()),
+        Some(report) => Err(format!(
+            "scene '{}' no longer matches {}\n{report}",
+            scene.name,
+            path.display()
+        )),
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Scene gate: every built-in scene must match its golden snapshot.
+
+use super::{check_scene, render_scene, snapshot_path, SCENE_SPEC};
+use crate::scene::SCENES;
+
+/// All scenes are checked before failing so one run reports every
+/// regressed scene, not just the first.
+#[test]
+fn all_scenes_match_golden_snapshots() {
+    let failures: Vec<String> = SCENES
+        .iter()
+        .filter_map(|scene| check_scene(scene, &SCENE_SPEC).err())
+        .collect();
+    assert!(
+        failures.is_empty(),
+        "{} of {} scenes differ from their golden snapshots \
+         (bless intentional changes with COSMOSTRIX_BLESS=1 cargo test golden):\n{}",
+        failures.len(),
+        SCENES.len(),
+        failures.join("\n")
+    );
+}
+
+/// The harness is only useful if a render is a pure function of the spec.
+#[test]
+fn scene_render_is_reproducible() {
+    for scene in SCENES.iter().take(3) {
+        let a = render_scene(scene, &SCENE_SPEC);
+        let b = render_scene(scene, &SCENE_SPEC);
+        assert!(
+            a.diff_report(&b).is_none(),
+            "scene '{}' rendered twice with the same spec differs",
+            scene.name
+        );
+    }
+}
+
+/// No orphan snapshots left behind by a renamed or removed scene.
+#[test]
+fn every_snapshot_belongs_to_a_scene() {
+    let dir = snapshot_path("x")
+        .parent()
+        .expect("snapshot dir")
+        .to_path_buf();
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if let Some(scene) = name.strip_suffix(".golden") {
+            assert!(
+                SCENES.iter().any(|s| s.name == scene),
+                "orphan snapshot {name}: no scene named '{scene}'"
+            );
+        }
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright (C) 2026 rezky_nightky
+// SPDX-License-Identifier: GPL-3.0-only
+
+//! Harness self-tests: format round-trip and diff reporting.
+
+use super::*;
+
+fn tiny() -> Snapshot {
+    let cell = |ch: char, style: &str| GoldenCell {
+        ch,
+        style: style.to_string(),
+    };
+    Snapshot {
+        scene: "tiny".to_string(),
+        cols: 3,
+        lines: 2,
+        seed: 9,
+        step_us: 16_667,
+        frames: vec![(
+            5,
+            vec![
+                cell('ｱ', "fg=#00ff41 bg=- bold=1"),
+                cell(' ', "fg=- bg=- bold=0"),
+                cell('|', "fg=a34 bg=- bold=0"),
+                cell('0', "fg=#00ff41 bg=- bold=1"),
+                cell(' ', "fg=- bg=- bold=0"),
+                cell('=', "fg=- bg=#000000 bold=0"),
+            ],
+        )],
+    }
+}
+
+#[test]
+fn text_format_round_trips() {
+    let snap = tiny();
+    let text = snap.to_text();
+    assert!(text.starts_with("cosmostrix-golden v1\nscene tiny\nsize 3x2\n"));
+    assert!(text.contains("frame 5\n|ｱ ||\n|0 =|\n=0 1 2\n=0 1 3\n"));
+    assert_eq!(Snapshot::parse(&text).unwrap(), snap);
+}
+
+#[test]
+fn diff_report_names_changed_cells() {
+    let want = tiny();
+    let mut got = tiny();
+    got.frames[0].1[4] = GoldenCell {
+        ch: 'Z',
+        style: "fg=#ffffff bg=- bold=0".to_string(),
+    };
+    assert!(want.diff_report(&want).is_none());
+    let report = got.diff_report(&want).unwrap();
+    assert!(report.contains("frame 5: 1 of 6 cells changed"), "{report}");
+    assert!(report.contains("(  1,  1) ' '"), "{report}");
+    assert!(report.contains("'Z' fg=#ffffff"), "{report}");
+}
+
+#[test]
+fn parse_rejects_ragged_rows() {
+    let text = tiny().to_text().replace("|0 =|", "|0=|");
+    let err = Snapshot::parse(&text).unwrap_err();
+    assert!(err.contains("expected 3 glyphs"), "{err}");
+}
+
+#[test]
+fn diff_report_flags_spec_changes() {
+    let want = tiny();
+    let mut got = tiny();
+    got.seed = 10;
+    let report = got.diff_report(&want).unwrap();
+    assert!(report.contains("spec changed"), "{report}");
+}
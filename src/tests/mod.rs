@@ -12,7 +12,12 @@
 //! - `property`     <- `src/property_tests.rs`   (proptest config parser)
 //! - `terminal`     <- `src/terminal_tests.rs`   (terminal sequence tests)
 //! - `width_guard`  <- `src/width_guard_tests.rs` (Bug #11 width=1 invariant)
+//!
+//! Added after the consolidation:
+//! - `golden`       golden-frame snapshots for every built-in scene
 
+#[cfg(test)]
+mod golden;
 #[cfg(test)]
 mod loc;
 #[cfg(test)]
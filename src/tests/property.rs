@@ -54,7 +54,7 @@ proptest! {
         prop_assert!(parsed.values.contains_key("scene"));
         let value = parsed.values.get("scene").unwrap();
         // Parser strips quotes, so the stored value should match the input.
-        prop_assert_eq!(value, &scene);
+        prop_assert_eq!(value.as_str(), Some(scene.as_str()));
     }
 
     /// Valid ambient.HH-MM = "scene" lines must parse correctly.
@@ -97,8 +97,9 @@ proptest! {
                 "key '{full_key}' should be in values or unknown_keys"
             );
             // If it's in values, verify the value matches (quotes stripped).
-            if let Some(got) = parsed.values.get(&full_key) {
-                prop_assert_eq!(got, v, "value should match (quotes stripped)");
+            // A list key holds the string as its one item.
+            if let Some(got) = parsed.values.get(&full_key).and_then(|got| got.items()) {
+                prop_assert_eq!(got, [v.clone()], "value should match (quotes stripped)");
             }
         }
     }
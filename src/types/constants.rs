@@ -80,6 +80,11 @@ pub(crate) const MONOLITH_EFFECTIVE_SPEED_MAX: f32 = SPEED_MAX;
 /// Benefit: 7.5× byte reduction at 25% dirty frames (e.g. 200×60:
 /// 90KB → 48KB per frame). Zero visual change — same cells are drawn,
 /// just via the cheaper path.
+///
+/// No longer drives `Terminal::draw`: the path is now picked per frame by
+/// the byte-cost model in `terminal/redraw_cost.rs`. Kept as the reference
+/// rule the benchmark reports savings against, and for the benchmark's
+/// dirty-threshold metrics.
 pub(crate) const DIRTY_THRESHOLD_RATIO: usize = 8;
 
 /// Maximum allowed terminal width (columns) for interactive mode.
@@ -149,6 +154,9 @@ pub(crate) const CONFIG_DIR_NAME: &str = "cosmostrix";
 /// fallback — users upgrading from pre-v10 must rename their file.
 pub(crate) const CONFIG_FILE_NAME: &str = "config.toml";
 
+/// Drop-in directory merged on top of the config file (`conf.d/*.toml`).
+pub(crate) const CONF_D_DIR_NAME: &str = "conf.d";
+
 /// Default frame dirty capacity pre-allocation.  One Nth of total cells.
 /// 8 is conservative enough for 1024×500 terminals (≈64K pre-alloc) while
 /// still covering most frames without a heap spill.
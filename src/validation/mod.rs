@@ -37,6 +37,7 @@ pub(crate) const KNOWN_LONG_FLAGS: &[&str] = &[
     "density",
     "monolith-size",
     "async-mode",
+    "control-socket",
     "screensaver",
     "intro",
     "glitch-level",
@@ -60,6 +61,11 @@ pub(crate) const KNOWN_LONG_FLAGS: &[&str] = &[
     "bench-io",
     "bench-all",
     "bench-scene",
+    "record",
+    "seed",
+    "render-frames",
+    "out",
+    "render-format",
     "reset-terminal",
     "verbose",
     // DISCOVERY
@@ -284,6 +290,28 @@ pub(crate) fn validate_f64_range(name: &str, v: f64, min: f64, max: f64) -> Resu
     Ok(v)
 }
 
+/// A finite number in `[min, max]` for a config section field table
+/// (`[rain-tuning]`, `[power-dragon]`, `[events]`). Errors use the
+/// `--testconf` wording ("out of range [a, b], got n").
+pub(crate) fn parse_field_number(raw: &str, min: f64, max: f64) -> Result<f64, String> {
+    let n: f64 = raw
+        .trim()
+        .parse()
+        .ok()
+        .filter(|n: &f64| n.is_finite())
+        .ok_or_else(|| format!("expected number in [{min}, {max}], got '{raw}'"))?;
+    field_in_range(n, min, max)
+}
+
+/// The range half of [`parse_field_number`], for values that arrive as
+/// numbers (array items).
+pub(crate) fn field_in_range(n: f64, min: f64, max: f64) -> Result<f64, String> {
+    if !(min..=max).contains(&n) {
+        return Err(format!("out of range [{min}, {max}], got {n}"));
+    }
+    Ok(n)
+}
+
 /// Validate user-facing rain speed.
 pub(crate) fn validate_speed(v: f32) -> Result<f32, String> {
     validate_f32_range("--speed", v, SPEED_MIN, SPEED_MAX)